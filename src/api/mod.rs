@@ -3,8 +3,34 @@
 //! - TMDB: Movie/TV metadata and search
 //! - Torrentio: Stream sources via Stremio addon protocol
 
+pub mod retry;
 pub mod tmdb;
 pub mod torrentio;
 
+pub use retry::RetryPolicy;
 pub use tmdb::TmdbClient;
 pub use torrentio::TorrentioClient;
+
+use std::time::Duration;
+
+/// Request timeout when the config doesn't set `request_timeout_secs`
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Longest wait for a TCP/TLS connection, so a dead host fails fast
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// HTTP client whose requests give up after `timeout`
+pub fn http_client(timeout: Duration) -> reqwest::Client {
+    reqwest::Client::builder()
+        .connect_timeout(timeout.min(CONNECT_TIMEOUT))
+        .timeout(timeout)
+        .build()
+        .unwrap_or_default()
+}
+
+/// Whether a request failed by timing out (anywhere in the error chain)
+pub fn is_timeout(e: &anyhow::Error) -> bool {
+    e.chain()
+        .filter_map(|cause| cause.downcast_ref::<reqwest::Error>())
+        .any(|e| e.is_timeout())
+}
@@ -0,0 +1,123 @@
+//! Retry policy for idempotent GETs
+//!
+//! Connection errors, timeouts, HTTP 429 and 5xx are retried with exponential
+//! backoff, honoring `Retry-After` when the server sends one. Anything else
+//! (including the last failed attempt) is handed back to the caller.
+
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::time::Duration;
+
+/// How often and how patiently to retry a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Wait before the first retry; doubled for each one after
+    pub base_delay: Duration,
+    /// Upper bound for any single wait, including `Retry-After`
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default backoff with `max_retries` retries
+    pub fn with_max_retries(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    /// Wait before retry number `retry` (1-based)
+    pub fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        retry_after.unwrap_or(backoff).min(self.max_delay)
+    }
+
+    /// Send the request built by `build`, retrying transient failures
+    ///
+    /// `build` is called once per attempt since a `RequestBuilder` can only be
+    /// sent once.
+    pub async fn send(&self, build: impl Fn() -> RequestBuilder) -> reqwest::Result<Response> {
+        let mut retry = 0;
+        loop {
+            let result = build().send().await;
+            let retry_after = match &result {
+                Ok(response) if is_retryable_status(response.status()) => retry_after(response),
+                Err(e) if is_transient(e) => None,
+                _ => return result,
+            };
+            if retry >= self.max_retries {
+                return result;
+            }
+            retry += 1;
+            tokio::time::sleep(self.delay(retry, retry_after)).await;
+        }
+    }
+}
+
+/// 429 and 5xx are worth another try
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Errors from flaky networks rather than from the request itself
+fn is_transient(e: &reqwest::Error) -> bool {
+    e.is_connect() || e.is_timeout() || e.is_request()
+}
+
+/// `Retry-After` in seconds (HTTP dates are ignored)
+pub fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_backs_off_exponentially() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(1, None), Duration::from_millis(500));
+        assert_eq!(policy.delay(2, None), Duration::from_secs(1));
+        assert_eq!(policy.delay(3, None), Duration::from_secs(2));
+        assert_eq!(policy.delay(20, None), policy.max_delay);
+    }
+
+    #[test]
+    fn test_delay_honors_retry_after() {
+        let policy = RetryPolicy::default();
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(4))),
+            Duration::from_secs(4)
+        );
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(600))),
+            policy.max_delay
+        );
+    }
+
+    #[test]
+    fn test_retryable_statuses() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!is_retryable_status(StatusCode::OK));
+    }
+}
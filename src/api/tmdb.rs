@@ -2,14 +2,25 @@
 //!
 //! Provides search and metadata for movies and TV shows.
 //! API docs: https://developer.themoviedb.org/docs
+//!
+//! Responses can be cached on disk (`~/.cache/streamtui/tmdb/`) so repeated
+//! lookups skip the network until the TTL runs out.
 
 use anyhow::Result;
 use reqwest::StatusCode;
 use serde::Deserialize;
-use std::time::Duration;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 
-use crate::models::{Episode, MediaType, MovieDetail, SearchResult, SeasonSummary, TvDetail};
+use super::retry::{self, RetryPolicy};
+use crate::cli::TrendingWindow;
+use crate::config::Config;
+
+use crate::models::{
+    DiscoverSort, Episode, MediaType, MovieDetail, PersonResult, SearchPage, SearchResult,
+    SeasonSummary, TvDetail, Video,
+};
 
 /// TMDB API error types
 #[derive(Error, Debug)]
@@ -17,8 +28,10 @@ pub enum TmdbError {
     #[error("Resource not found (404)")]
     NotFound,
 
-    #[error("Rate limited (429), retries exhausted")]
-    RateLimited,
+    /// Still throttled after the retries; `retry_after` is TMDB's last
+    /// `Retry-After`, if it sent one
+    #[error("Rate limited by TMDB (429), retries exhausted{}", retry_hint(.retry_after))]
+    RateLimited { retry_after: Option<Duration> },
 
     #[error("Server error: {0}")]
     ServerError(u16),
@@ -30,12 +43,23 @@ pub enum TmdbError {
     RequestFailed(#[from] reqwest::Error),
 }
 
+/// "; try again in 30s" when TMDB said how long to wait
+fn retry_hint(retry_after: &Option<Duration>) -> String {
+    retry_after
+        .map(|wait| format!("; try again in {}s", wait.as_secs()))
+        .unwrap_or_default()
+}
+
 /// TMDB API client
 pub struct TmdbClient {
     api_key: String,
     base_url: String,
     client: reqwest::Client,
-    max_retries: u32,
+    retry: RetryPolicy,
+    /// On-disk response cache directory and entry lifetime
+    cache: Option<(PathBuf, Duration)>,
+    /// Keep results TMDB flags as adult
+    include_adult: bool,
 }
 
 impl TmdbClient {
@@ -44,11 +68,90 @@ impl TmdbClient {
         Self {
             api_key: api_key.into(),
             base_url: "https://api.themoviedb.org/3".to_string(),
-            client: reqwest::Client::builder()
-                .timeout(Duration::from_secs(30))
-                .build()
-                .unwrap_or_default(),
-            max_retries: 3,
+            client: super::http_client(super::DEFAULT_TIMEOUT),
+            retry: RetryPolicy::default(),
+            cache: None,
+            include_adult: false,
+        }
+    }
+
+    /// Create a client using the config's API key, cache and retry settings
+    pub fn from_config(config: &mut Config) -> Self {
+        let client = Self::new(config.get_tmdb_api_key())
+            .with_retry(config.retry_policy())
+            .with_timeout(config.request_timeout())
+            .with_include_adult(config.include_adult);
+        match config.tmdb_cache_ttl() {
+            Some(ttl) => client.with_cache(Self::cache_dir(), ttl),
+            None => client,
+        }
+    }
+
+    /// Cache responses in `dir` for `ttl`
+    pub fn with_cache(mut self, dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        self.cache = Some((dir.into(), ttl));
+        self
+    }
+
+    /// Retry transient failures according to `policy`
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Give up on a request after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client = super::http_client(timeout);
+        self
+    }
+
+    /// Keep adult results in searches and listings (dropped by default)
+    pub fn with_include_adult(mut self, include_adult: bool) -> Self {
+        self.include_adult = include_adult;
+        self
+    }
+
+    /// Default cache directory (~/.cache/streamtui/tmdb)
+    pub fn cache_dir() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(|| PathBuf::from("/tmp"))
+            .join("streamtui")
+            .join("tmdb")
+    }
+
+    /// Cache file for an endpoint (path + query, without the API key)
+    ///
+    /// Named by SHA-256, which unlike std's hasher stays the same across
+    /// Rust releases, so a toolchain upgrade keeps the cache.
+    fn cache_path(&self, endpoint: &str) -> Option<PathBuf> {
+        let (dir, _) = self.cache.as_ref()?;
+        Some(dir.join(format!("{}.json", cache_key(endpoint))))
+    }
+
+    /// Read a fresh, parseable cached response
+    ///
+    /// Missing, expired and corrupted entries all count as a miss.
+    fn read_cache<T: for<'de> Deserialize<'de>>(&self, endpoint: &str) -> Option<T> {
+        let (_, ttl) = self.cache.as_ref()?;
+        let path = self.cache_path(endpoint)?;
+        let age = std::fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| SystemTime::now().duration_since(t).ok())?;
+        if age >= *ttl {
+            return None;
+        }
+        let body = std::fs::read_to_string(&path).ok()?;
+        serde_json::from_str(&body).ok()
+    }
+
+    /// Store a response body (best effort)
+    fn write_cache(&self, endpoint: &str, body: &str) {
+        if let Some(path) = self.cache_path(endpoint) {
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let _ = std::fs::write(path, body);
         }
     }
 
@@ -57,89 +160,135 @@ impl TmdbClient {
         Self {
             api_key: api_key.into(),
             base_url: base_url.into(),
-            client: reqwest::Client::builder()
-                .timeout(Duration::from_secs(30))
-                .build()
-                .unwrap_or_default(),
-            max_retries: 3,
+            client: super::http_client(super::DEFAULT_TIMEOUT),
+            retry: RetryPolicy::default(),
+            cache: None,
+            include_adult: false,
         }
     }
 
-    /// Make an authenticated GET request with retry logic for rate limits
+    /// Drop adult results unless they're wanted
+    ///
+    /// TMDB is asked to leave them out already; this catches any that slip
+    /// through (e.g. trending lists, which ignore `include_adult`).
+    fn screen(&self, mut response: SearchResponse) -> SearchResponse {
+        if !self.include_adult {
+            response.results.retain(|r| !r.adult);
+        }
+        response
+    }
+
+    /// Make an authenticated GET request, retrying transient failures
     async fn get<T: for<'de> Deserialize<'de>>(&self, endpoint: &str) -> Result<T> {
+        if let Some(cached) = self.read_cache(endpoint) {
+            return Ok(cached);
+        }
+
         // Support both legacy API key (query param) and Bearer token (header)
         // Legacy keys are ~32 chars, Bearer tokens are much longer (~200 chars)
         let url = if self.api_key.len() < 64 {
             // Legacy API key - append as query parameter
             let separator = if endpoint.contains('?') { "&" } else { "?" };
-            format!("{}{}{}api_key={}", self.base_url, endpoint, separator, self.api_key)
+            format!(
+                "{}{}{}api_key={}",
+                self.base_url, endpoint, separator, self.api_key
+            )
         } else {
             format!("{}{}", self.base_url, endpoint)
         };
-        let mut retries = 0;
-
-        loop {
-            let mut request = self.client.get(&url).header("Accept", "application/json");
-
-            // Only add Bearer auth for longer tokens
-            if self.api_key.len() >= 64 {
-                request = request.header("Authorization", format!("Bearer {}", self.api_key));
-            }
-
-            let response = request.send().await?;
-
-            match response.status() {
-                StatusCode::OK => {
-                    let body = response.text().await?;
-                    let parsed: T = serde_json::from_str(&body).map_err(|e| {
-                        TmdbError::InvalidResponse(format!("JSON parse error: {}", e))
-                    })?;
-                    return Ok(parsed);
-                }
-                StatusCode::NOT_FOUND => {
-                    return Err(TmdbError::NotFound.into());
-                }
-                StatusCode::TOO_MANY_REQUESTS => {
-                    retries += 1;
-                    if retries >= self.max_retries {
-                        return Err(TmdbError::RateLimited.into());
-                    }
-
-                    // Get Retry-After header or default to exponential backoff
-                    let wait_secs = response
-                        .headers()
-                        .get("Retry-After")
-                        .and_then(|v| v.to_str().ok())
-                        .and_then(|s| s.parse::<u64>().ok())
-                        .unwrap_or(2u64.pow(retries));
-
-                    tokio::time::sleep(Duration::from_secs(wait_secs)).await;
-                    continue;
-                }
-                status if status.is_server_error() => {
-                    return Err(TmdbError::ServerError(status.as_u16()).into());
-                }
-                status => {
-                    return Err(TmdbError::ServerError(status.as_u16()).into());
+        let response = self
+            .retry
+            .send(|| {
+                let request = self.client.get(&url).header("Accept", "application/json");
+                // Only add Bearer auth for longer tokens
+                if self.api_key.len() >= 64 {
+                    request.header("Authorization", format!("Bearer {}", self.api_key))
+                } else {
+                    request
                 }
+            })
+            .await?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let body = response.text().await?;
+                let parsed: T = serde_json::from_str(&body)
+                    .map_err(|e| TmdbError::InvalidResponse(format!("JSON parse error: {}", e)))?;
+                self.write_cache(endpoint, &body);
+                Ok(parsed)
             }
+            StatusCode::NOT_FOUND => Err(TmdbError::NotFound.into()),
+            StatusCode::TOO_MANY_REQUESTS => Err(TmdbError::RateLimited {
+                retry_after: retry::retry_after(&response),
+            }
+            .into()),
+            status => Err(TmdbError::ServerError(status.as_u16()).into()),
         }
     }
 
-    /// Search for movies and TV shows
+    /// Search for movies and TV shows (first page only)
     pub async fn search(&self, query: &str) -> Result<Vec<SearchResult>> {
-        let endpoint = format!("/search/multi?query={}&page=1", urlencoding::encode(query));
+        Ok(self.search_paged(query, 1).await?.results)
+    }
+
+    /// Search for movies and TV shows, fetching a specific 1-based page
+    pub async fn search_paged(&self, query: &str, page: u32) -> Result<SearchPage> {
+        let endpoint = format!(
+            "/search/multi?query={}&page={}&include_adult={}",
+            urlencoding::encode(query),
+            page.max(1),
+            self.include_adult
+        );
 
         let response: SearchResponse = self.get(&endpoint).await?;
-        Ok(response.into_results())
+        Ok(self.screen(response).into_page())
     }
 
-    /// Get trending content
-    pub async fn trending(&self) -> Result<Vec<SearchResult>> {
-        let response: SearchResponse = self.get("/trending/all/week").await?;
+    /// Search for people (actors, directors) and the titles they're known for
+    pub async fn search_person(&self, name: &str) -> Result<Vec<PersonResult>> {
+        let endpoint = format!("/search/person?query={}&page=1", urlencoding::encode(name));
+
+        let response: PersonSearchResponse = self.get(&endpoint).await?;
         Ok(response.into_results())
     }
 
+    /// Get trending content for a day or week window
+    pub async fn trending(&self, window: TrendingWindow) -> Result<Vec<SearchResult>> {
+        let window = match window {
+            TrendingWindow::Day => "day",
+            TrendingWindow::Week => "week",
+        };
+        let endpoint = format!("/trending/all/{}", window);
+        let response: SearchResponse = self.get(&endpoint).await?;
+        Ok(self.screen(response).into_results())
+    }
+
+    /// Discover movies or TV shows in a genre (first page only)
+    pub async fn discover(
+        &self,
+        genre_id: u32,
+        media_type: MediaType,
+        sort: DiscoverSort,
+    ) -> Result<Vec<SearchResult>> {
+        let kind = match media_type {
+            MediaType::Movie => "movie",
+            MediaType::Tv => "tv",
+        };
+        let mut endpoint = format!(
+            "/discover/{}?with_genres={}&sort_by={}&page=1&include_adult={}",
+            kind,
+            genre_id,
+            sort.sort_by(),
+            self.include_adult
+        );
+        if let Some(min) = sort.min_votes() {
+            endpoint.push_str(&format!("&vote_count.gte={}", min));
+        }
+
+        let response: SearchResponse = self.get(&endpoint).await?;
+        Ok(self.screen(response).into_results_as(kind))
+    }
+
     /// Get movie details by ID
     pub async fn movie_detail(&self, id: u64) -> Result<MovieDetail> {
         let endpoint = format!("/movie/{}?append_to_response=external_ids", id);
@@ -154,6 +303,20 @@ impl TmdbClient {
         Ok(response.into_detail())
     }
 
+    /// Look up a movie or TV show by its IMDB ID
+    ///
+    /// Movie matches win when TMDB returns both kinds.
+    pub async fn find_by_imdb(&self, imdb_id: &str) -> Result<SearchResult> {
+        let endpoint = format!(
+            "/find/{}?external_source=imdb_id",
+            urlencoding::encode(imdb_id)
+        );
+        let response: FindResponse = self.get(&endpoint).await?;
+        response
+            .into_result()
+            .ok_or_else(|| TmdbError::NotFound.into())
+    }
+
     /// Get episodes for a TV season
     pub async fn tv_season(&self, id: u64, season: u8) -> Result<Vec<Episode>> {
         let endpoint = format!("/tv/{}/season/{}", id, season);
@@ -161,6 +324,55 @@ impl TmdbClient {
         Ok(response.into_episodes(season))
     }
 
+    /// Titles TMDB recommends to people who liked this one
+    pub async fn recommendations(
+        &self,
+        media_type: MediaType,
+        id: u64,
+    ) -> Result<Vec<SearchResult>> {
+        self.related(media_type, id, "recommendations").await
+    }
+
+    /// Titles similar to this one (shared genres and keywords)
+    pub async fn similar(&self, media_type: MediaType, id: u64) -> Result<Vec<SearchResult>> {
+        self.related(media_type, id, "similar").await
+    }
+
+    /// First page of a `/{movie|tv}/{id}/<list>` endpoint
+    async fn related(
+        &self,
+        media_type: MediaType,
+        id: u64,
+        list: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let kind = match media_type {
+            MediaType::Movie => "movie",
+            MediaType::Tv => "tv",
+        };
+        let endpoint = format!("/{}/{}/{}?page=1", kind, id, list);
+        let response: SearchResponse = self.get(&endpoint).await?;
+        // `similar` results don't say their type; they match the title's
+        Ok(self.screen(response).into_results_as(kind))
+    }
+
+    /// Get the movies of a collection (franchise), in release order
+    pub async fn collection(&self, id: u64) -> Result<Vec<SearchResult>> {
+        let endpoint = format!("/collection/{}", id);
+        let response: CollectionResponse = self.get(&endpoint).await?;
+        Ok(response.into_parts())
+    }
+
+    /// Get the YouTube videos (trailers, teasers, clips) for a title
+    pub async fn videos(&self, media_type: MediaType, id: u64) -> Result<Vec<Video>> {
+        let kind = match media_type {
+            MediaType::Movie => "movie",
+            MediaType::Tv => "tv",
+        };
+        let endpoint = format!("/{}/{}/videos", kind, id);
+        let response: VideosResponse = self.get(&endpoint).await?;
+        Ok(response.into_videos())
+    }
+
     // Legacy method names for backwards compatibility
 
     /// Get movie details (legacy name)
@@ -222,20 +434,55 @@ impl TmdbClient {
 #[derive(Debug, Deserialize)]
 struct SearchResponse {
     results: Vec<SearchResultRaw>,
+    #[serde(default)]
+    page: u32,
+    #[serde(default)]
+    total_pages: u32,
 }
 
 impl SearchResponse {
     fn into_results(self) -> Vec<SearchResult> {
-        self.results
+        self.into_media().0
+    }
+
+    /// Movie and TV rows, plus how many other rows (people, collections) were skipped
+    fn into_media(self) -> (Vec<SearchResult>, usize) {
+        let total = self.results.len();
+        let results: Vec<SearchResult> = self
+            .results
             .into_iter()
             .filter_map(|r| r.into_search_result())
-            .collect()
+            .collect();
+        let skipped = total - results.len();
+        (results, skipped)
+    }
+
+    /// Results from endpoints that don't tag each item (e.g. /discover)
+    fn into_results_as(mut self, media_type: &str) -> Vec<SearchResult> {
+        for raw in &mut self.results {
+            raw.media_type = media_type.to_string();
+        }
+        self.into_results()
+    }
+
+    fn into_page(self) -> SearchPage {
+        let page = self.page.max(1);
+        let total_pages = self.total_pages;
+        let (results, skipped) = self.into_media();
+        SearchPage {
+            results,
+            page,
+            total_pages,
+            skipped,
+        }
     }
 }
 
 #[derive(Debug, Deserialize)]
 struct SearchResultRaw {
     id: u64,
+    // Absent in /find and /discover results, where the list it came from says the type
+    #[serde(default)]
     media_type: String,
     // Movies use "title", TV uses "name"
     title: Option<String>,
@@ -246,6 +493,8 @@ struct SearchResultRaw {
     overview: Option<String>,
     poster_path: Option<String>,
     vote_average: Option<f32>,
+    #[serde(default)]
+    adult: bool,
 }
 
 impl SearchResultRaw {
@@ -256,7 +505,13 @@ impl SearchResultRaw {
             _ => return None, // Filter out "person" and other types
         };
 
-        let title = self.title.or(self.name).unwrap_or_default();
+        // Some rows carry neither, or an empty one
+        let title = self
+            .title
+            .into_iter()
+            .chain(self.name)
+            .find(|t| !t.trim().is_empty())
+            .unwrap_or_else(|| "Untitled".to_string());
         let date_str = self.release_date.or(self.first_air_date);
         let year = date_str.and_then(|d| extract_year(&d));
 
@@ -272,6 +527,62 @@ impl SearchResultRaw {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct FindResponse {
+    #[serde(default)]
+    movie_results: Vec<SearchResultRaw>,
+    #[serde(default)]
+    tv_results: Vec<SearchResultRaw>,
+}
+
+impl FindResponse {
+    fn into_result(self) -> Option<SearchResult> {
+        let (results, media_type) = if self.movie_results.is_empty() {
+            (self.tv_results, "tv")
+        } else {
+            (self.movie_results, "movie")
+        };
+        let mut raw = results.into_iter().next()?;
+        raw.media_type = media_type.to_string();
+        raw.into_search_result()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PersonSearchResponse {
+    results: Vec<PersonRaw>,
+}
+
+impl PersonSearchResponse {
+    fn into_results(self) -> Vec<PersonResult> {
+        self.results.into_iter().map(|p| p.into_person()).collect()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PersonRaw {
+    id: u64,
+    name: String,
+    profile_path: Option<String>,
+    #[serde(default)]
+    known_for: Vec<SearchResultRaw>,
+}
+
+impl PersonRaw {
+    fn into_person(self) -> PersonResult {
+        PersonResult {
+            id: self.id,
+            name: self.name,
+            known_for: self
+                .known_for
+                .into_iter()
+                .filter_map(|r| r.into_search_result())
+                .collect(),
+            profile_path: self.profile_path,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct MovieResponse {
     id: u64,
@@ -284,6 +595,13 @@ struct MovieResponse {
     vote_average: Option<f32>,
     poster_path: Option<String>,
     backdrop_path: Option<String>,
+    belongs_to_collection: Option<CollectionRef>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CollectionRef {
+    id: u64,
+    name: String,
 }
 
 impl MovieResponse {
@@ -305,10 +623,34 @@ impl MovieResponse {
             vote_average: self.vote_average.unwrap_or(0.0),
             poster_path: self.poster_path,
             backdrop_path: self.backdrop_path,
+            collection_id: self.belongs_to_collection.as_ref().map(|c| c.id),
+            collection_name: self.belongs_to_collection.map(|c| c.name),
         }
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct CollectionResponse {
+    parts: Vec<SearchResultRaw>,
+}
+
+impl CollectionResponse {
+    /// Parts in release order (unreleased ones, without a date, last)
+    fn into_parts(mut self) -> Vec<SearchResult> {
+        self.parts.sort_by_key(|p| {
+            let date = p.release_date.clone().filter(|d| !d.is_empty());
+            (date.is_none(), date)
+        });
+        for part in &mut self.parts {
+            part.media_type = "movie".to_string();
+        }
+        self.parts
+            .into_iter()
+            .filter_map(|p| p.into_search_result())
+            .collect()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct TvResponse {
     id: u64,
@@ -418,11 +760,54 @@ impl EpisodeRaw {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct VideosResponse {
+    results: Vec<VideoRaw>,
+}
+
+impl VideosResponse {
+    /// YouTube videos only (the rest can't be linked to)
+    fn into_videos(self) -> Vec<Video> {
+        self.results
+            .into_iter()
+            .filter(|v| v.site.eq_ignore_ascii_case("youtube"))
+            .map(|v| Video {
+                key: v.key,
+                name: v.name,
+                kind: v.kind,
+                official: v.official,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoRaw {
+    key: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    site: String,
+    #[serde(rename = "type", default)]
+    kind: String,
+    #[serde(default)]
+    official: bool,
+}
+
 #[derive(Debug, Deserialize)]
 struct ExternalIds {
     imdb_id: Option<String>,
 }
 
+/// Hex SHA-256 of an endpoint, the cache file's name
+fn cache_key(endpoint: &str) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(endpoint.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
 /// Extract year from a date string like "2022-03-04"
 fn extract_year(date: &str) -> Option<u16> {
     if date.len() >= 4 {
@@ -444,6 +829,15 @@ mod tests {
         assert_eq!(extract_year("abc"), None);
     }
 
+    #[test]
+    fn test_cache_key_is_stable() {
+        // Fixed digest: cache files must keep their names across toolchains
+        assert_eq!(
+            cache_key("/search/multi?query=matrix"),
+            "284b66c61141c8f472d921d1b947bf5be06d51f28e88631acc08d8f38f7ed018"
+        );
+    }
+
     #[test]
     fn test_media_type_filter() {
         let movie = SearchResultRaw {
@@ -456,6 +850,7 @@ mod tests {
             overview: None,
             poster_path: None,
             vote_average: None,
+            adult: false,
         };
 
         let person = SearchResultRaw {
@@ -468,6 +863,7 @@ mod tests {
             overview: None,
             poster_path: None,
             vote_average: None,
+            adult: false,
         };
 
         assert!(movie.into_search_result().is_some());
@@ -2,11 +2,21 @@
 //!
 //! Fetches stream sources from the Torrentio Stremio addon.
 //! Provides magnet links with quality, size, and seed info.
+//!
+//! With a RealDebrid key (`debrid_api_key` in config.toml) Torrentio
+//! resolves torrents server-side and returns direct HTTP links instead.
+//!
+//! Addon options (providers, sort order, quality and size filters) go in a
+//! path segment before `/stream`, as Torrentio's configure page writes them:
+//! `/providers=yts,eztv|sort=qualitysize|realdebrid=KEY/stream/...`.
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
+use std::time::Duration;
 
-use crate::models::{Quality, StreamSource};
+use super::RetryPolicy;
+use crate::config::Config;
+use crate::models::{HdrType, Quality, StreamSource, VideoCodec};
 
 /// Torrentio API response
 #[derive(Debug, Deserialize)]
@@ -19,27 +29,145 @@ struct TorrentioResponse {
 struct TorrentioStream {
     name: String,
     title: String,
+    /// Torrent hash (absent for debrid-resolved streams)
     #[serde(rename = "infoHash")]
-    info_hash: String,
+    info_hash: Option<String>,
     #[serde(rename = "fileIdx")]
     file_idx: Option<u32>,
+    /// Direct HTTP link (debrid-resolved streams only)
+    url: Option<String>,
 }
 
 impl TorrentioStream {
     /// Convert API response to our StreamSource model
-    fn into_stream_source(self) -> StreamSource {
-        let quality = Quality::from_str_loose(&self.name);
+    ///
+    /// Returns None for streams that carry neither a hash nor a URL.
+    fn into_stream_source(self) -> Option<StreamSource> {
+        if self.info_hash.is_none() && self.url.is_none() {
+            return None;
+        }
+
+        // "Torrentio\n4k": the provider, then the quality tag
+        let (provider, tag) = self
+            .name
+            .split_once('\n')
+            .unwrap_or((&self.name, &self.name));
+        let provider = provider.trim().to_string();
+        let quality = Quality::from_str_loose(tag);
         let seeds = StreamSource::parse_seeds(&self.title);
         let size_bytes = StreamSource::parse_size(&self.title);
+        // HDR tags can appear in either the addon name or the release title
+        let hdr = HdrType::from_title(&format!("{} {}", self.name, self.title));
+        let codec = VideoCodec::from_title(&self.title);
+        let release_group = StreamSource::parse_release_group(&self.title);
 
-        StreamSource {
+        Some(StreamSource {
             name: self.name,
+            provider,
             title: self.title,
-            info_hash: self.info_hash,
+            info_hash: self.info_hash.unwrap_or_default(),
             file_idx: self.file_idx,
             seeds,
             quality,
             size_bytes,
+            hdr,
+            codec,
+            release_group,
+            direct_url: self.url,
+        })
+    }
+}
+
+/// Torrentio's sort orders (`sort=` option)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TorrentioSort {
+    /// By quality, then seeders (Torrentio's default)
+    #[default]
+    Quality,
+    /// By quality, then size
+    QualitySize,
+    /// By seeders only
+    Seeders,
+    /// By size only
+    Size,
+}
+
+impl TorrentioSort {
+    /// Parse a config or flag value (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "quality" => Some(Self::Quality),
+            "qualitysize" => Some(Self::QualitySize),
+            "seeders" | "seeds" => Some(Self::Seeders),
+            "size" => Some(Self::Size),
+            _ => None,
+        }
+    }
+
+    /// Value in the URL
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Quality => "quality",
+            Self::QualitySize => "qualitysize",
+            Self::Seeders => "seeders",
+            Self::Size => "size",
+        }
+    }
+}
+
+/// Addon options sent with every request
+///
+/// Empty fields are left out, so the default asks for Torrentio's defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TorrentioConfig {
+    /// Only search these providers (e.g. "yts", "eztv", "1337x")
+    pub providers: Vec<String>,
+    pub sort: Option<TorrentioSort>,
+    /// Qualities Torrentio should drop (e.g. "cam", "scr", "480p")
+    pub quality_filter: Vec<String>,
+    /// Largest file size, e.g. "5GB" (or "5GB,2GB": movies, then episodes)
+    pub size_filter: Option<String>,
+}
+
+impl TorrentioConfig {
+    /// `key=value` options joined with `|`; empty when all are defaults
+    pub fn options(&self) -> Vec<String> {
+        let mut options = Vec::new();
+        if !self.providers.is_empty() {
+            options.push(format!("providers={}", self.providers.join(",")));
+        }
+        if let Some(sort) = self.sort.filter(|s| *s != TorrentioSort::Quality) {
+            options.push(format!("sort={}", sort.as_str()));
+        }
+        if !self.quality_filter.is_empty() {
+            options.push(format!("qualityfilter={}", self.quality_filter.join(",")));
+        }
+        if let Some(size) = self.size_filter.as_deref().filter(|s| !s.is_empty()) {
+            options.push(format!("sizefilter={}", size));
+        }
+        options
+    }
+}
+
+/// What to ask Torrentio for: a movie or an episode of a series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamKind {
+    Movie,
+    /// An episode (season packs are listed under each of their episodes)
+    Series {
+        season: u16,
+        episode: u16,
+    },
+}
+
+impl StreamKind {
+    /// Addon path for a title, e.g. `stream/series/tt0903747:1:2.json`
+    pub fn path(&self, imdb_id: &str) -> String {
+        match self {
+            StreamKind::Movie => format!("stream/movie/{}.json", imdb_id),
+            StreamKind::Series { season, episode } => {
+                format!("stream/series/{}:{}:{}.json", imdb_id, season, episode)
+            }
         }
     }
 }
@@ -48,29 +176,114 @@ impl TorrentioStream {
 pub struct TorrentioClient {
     base_url: String,
     client: reqwest::Client,
+    /// Collapse the same torrent listed by several providers
+    dedup: bool,
+    retry: RetryPolicy,
+    config: TorrentioConfig,
+    debrid_key: Option<String>,
 }
 
 impl TorrentioClient {
     /// Create a new Torrentio client with default settings
     pub fn new() -> Self {
-        Self {
-            base_url: "https://torrentio.strem.fun".to_string(),
-            client: reqwest::Client::new(),
-        }
+        Self::with_base_url("https://torrentio.strem.fun")
     }
 
     /// Create a client with a custom base URL (for testing)
     pub fn with_base_url(base_url: impl Into<String>) -> Self {
         Self {
             base_url: base_url.into(),
-            client: reqwest::Client::new(),
+            client: super::http_client(super::DEFAULT_TIMEOUT),
+            dedup: true,
+            retry: RetryPolicy::default(),
+            config: TorrentioConfig::default(),
+            debrid_key: None,
+        }
+    }
+
+    /// Create a client that resolves streams through RealDebrid
+    pub fn with_debrid(api_key: &str) -> Self {
+        Self::new().with_debrid_key(api_key)
+    }
+
+    /// Resolve streams through RealDebrid with this key
+    pub fn with_debrid_key(mut self, api_key: &str) -> Self {
+        self.debrid_key = Some(api_key.to_string());
+        self
+    }
+
+    /// Create a client from an optional RealDebrid key (plain Torrentio without one)
+    pub fn with_optional_debrid(api_key: Option<&str>) -> Self {
+        match api_key {
+            Some(key) if !key.is_empty() => Self::with_debrid(key),
+            _ => Self::new(),
+        }
+    }
+
+    /// Create a client using the config's RealDebrid key, addon options,
+    /// retry and timeout settings
+    pub fn from_config(config: &Config) -> Self {
+        Self::with_optional_debrid(config.debrid_api_key.as_deref())
+            .with_config(config.torrentio_config())
+            .with_retry(config.retry_policy())
+            .with_timeout(config.request_timeout())
+    }
+
+    /// Send these addon options with every request
+    pub fn with_config(mut self, config: TorrentioConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Retry transient failures according to `policy`
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Give up on a request after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client = super::http_client(timeout);
+        self
+    }
+
+    /// Enable or disable merging of duplicate sources (on by default)
+    pub fn with_dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Path segment carrying the addon options and debrid key ("" for none)
+    pub fn options_segment(&self) -> String {
+        let mut options = self.config.options();
+        if let Some(key) = &self.debrid_key {
+            options.push(format!("realdebrid={}", key));
         }
+        if options.is_empty() {
+            String::new()
+        } else {
+            format!("/{}", options.join("|"))
+        }
+    }
+
+    /// Full URL for a title's streams, options segment included
+    pub fn stream_url(&self, imdb_id: &str, kind: StreamKind) -> String {
+        format!(
+            "{}{}/{}",
+            self.base_url,
+            self.options_segment(),
+            kind.path(imdb_id)
+        )
+    }
+
+    /// Get streams for a movie or an episode
+    pub async fn streams(&self, imdb_id: &str, kind: StreamKind) -> Result<Vec<StreamSource>> {
+        self.fetch_streams(&self.stream_url(imdb_id, kind)).await
     }
 
     /// Get streams for a movie by IMDB ID
     pub async fn movie_streams(&self, imdb_id: &str) -> Result<Vec<StreamSource>> {
-        let url = format!("{}/stream/movie/{}.json", self.base_url, imdb_id);
-        self.fetch_streams(&url).await
+        self.streams(imdb_id, StreamKind::Movie).await
     }
 
     /// Get streams for a TV episode by IMDB ID and episode info
@@ -80,19 +293,30 @@ impl TorrentioClient {
         season: u16,
         episode: u16,
     ) -> Result<Vec<StreamSource>> {
-        let url = format!(
-            "{}/stream/series/{}:{}:{}.json",
-            self.base_url, imdb_id, season, episode
-        );
-        self.fetch_streams(&url).await
+        self.streams(imdb_id, StreamKind::Series { season, episode })
+            .await
+    }
+
+    /// Get streams for an episode by its absolute number
+    ///
+    /// Long-running anime are often listed as one season numbered straight
+    /// through, so S03E05 only turns up as `:1:<n>` with `n` counted from the
+    /// first episode (see [`TvDetail::absolute_episode`]).
+    ///
+    /// [`TvDetail::absolute_episode`]: crate::models::TvDetail::absolute_episode
+    pub async fn absolute_episode_streams(
+        &self,
+        imdb_id: &str,
+        absolute_ep: u16,
+    ) -> Result<Vec<StreamSource>> {
+        self.episode_streams(imdb_id, 1, absolute_ep).await
     }
 
     /// Fetch and parse streams from a Torrentio URL
     async fn fetch_streams(&self, url: &str) -> Result<Vec<StreamSource>> {
         let response = self
-            .client
-            .get(url)
-            .send()
+            .retry
+            .send(|| self.client.get(url))
             .await
             .context("Failed to fetch from Torrentio")?;
 
@@ -114,9 +338,13 @@ impl TorrentioClient {
         let mut streams: Vec<StreamSource> = data
             .streams
             .into_iter()
-            .map(|s| s.into_stream_source())
+            .filter_map(|s| s.into_stream_source())
             .collect();
 
+        if self.dedup {
+            streams = dedup_streams(streams);
+        }
+
         // Sort: quality descending, then seeds descending within same quality
         streams.sort_by(|a, b| match b.quality.cmp(&a.quality) {
             std::cmp::Ordering::Equal => b.seeds.cmp(&a.seeds),
@@ -127,6 +355,28 @@ impl TorrentioClient {
     }
 }
 
+/// Collapse entries that point at the same file of the same torrent
+///
+/// Debrid-resolved streams have no hash and are always kept as-is.
+/// First-seen order is preserved.
+pub fn dedup_streams(streams: Vec<StreamSource>) -> Vec<StreamSource> {
+    let mut deduped: Vec<StreamSource> = Vec::with_capacity(streams.len());
+    for stream in streams {
+        let existing = if stream.info_hash.is_empty() {
+            None
+        } else {
+            deduped.iter_mut().find(|s| {
+                s.info_hash.eq_ignore_ascii_case(&stream.info_hash) && s.file_idx == stream.file_idx
+            })
+        };
+        match existing {
+            Some(s) => s.merge(&stream),
+            None => deduped.push(stream),
+        }
+    }
+    deduped
+}
+
 impl Default for TorrentioClient {
     fn default() -> Self {
         Self::new()
@@ -3,9 +3,24 @@
 //! Manages the application state machine, navigation stack,
 //! and coordinates between UI and backend services.
 
-use crate::config::save_settings_sync;
+use crate::config::{
+    arrange_devices, push_search, save_settings_sync, HistoryEntry, ResumeEntry, SavedDevice,
+    WatchlistEntry, DEFAULT_HOME_LIMIT, DEFAULT_SEEK_STEP,
+};
+use crate::keymap::{Action, KeyMap};
 use crate::models::*;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::session::{SavedDetail, SavedSession};
+use crate::stream::PlayerType;
+use crate::ui::poster::GraphicsProtocol;
+use crate::ui::{Glyphs, Palette, Theme};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
 // =============================================================================
@@ -15,20 +30,41 @@ use tokio::sync::mpsc;
 /// Commands sent from UI to async task spawner
 #[derive(Debug, Clone)]
 pub enum AppCommand {
-    /// Fetch trending content
+    /// Fetch this week's trending content
     FetchTrending,
     /// Search for content
-    Search(String),
+    ///
+    /// `generation` is echoed back in `SearchResults` so late replies to an
+    /// older query can be dropped.
+    Search { query: String, generation: u64 },
+    /// Fetch a specific page of search results
+    SearchPage {
+        query: String,
+        page: u32,
+        generation: u64,
+    },
     /// Fetch movie detail
     FetchMovieDetail(u64),
     /// Fetch TV detail
     FetchTvDetail(u64),
     /// Fetch episodes for a TV season
     FetchEpisodes { tv_id: u64, season: u8 },
-    /// Fetch streams for content
-    FetchStreams { imdb_id: String, season: Option<u8>, episode: Option<u8> },
+    /// Fetch streams for content (`absolute` is retried if the episode has none)
+    FetchStreams {
+        imdb_id: String,
+        season: Option<u8>,
+        episode: Option<u8>,
+        absolute: Option<u16>,
+    },
     /// Fetch subtitles (for TV: season/episode needed)
-    FetchSubtitles { imdb_id: String, season: Option<u16>, episode: Option<u16>, lang: String },
+    FetchSubtitles {
+        imdb_id: String,
+        season: Option<u16>,
+        episode: Option<u16>,
+        lang: String,
+    },
+    /// Download a subtitle and parse its first cues for the preview modal
+    PreviewSubtitle { url: String },
     /// Discover Chromecast devices
     DiscoverDevices,
     /// Start playback (webtorrent + cast)
@@ -38,9 +74,17 @@ pub enum AppCommand {
         device: String,
         subtitle_url: Option<String>,
         file_idx: Option<u32>,
+        /// Direct HTTP link for debrid-resolved sources (skips webtorrent)
+        direct_url: Option<String>,
+        /// Start offset in seconds (resume)
+        start: Option<u64>,
+        /// More devices to cast the same stream to
+        also: Vec<String>,
+        /// Chromecast to check is on before starting (None for local players)
+        preflight: Option<CastDevice>,
     },
-    /// Stop playback
-    StopPlayback,
+    /// Stop playback (and only this torrent's stream when the hash is known)
+    StopPlayback { info_hash: Option<String> },
     /// Restart playback with subtitles at position
     RestartWithSubtitles {
         magnet: String,
@@ -49,17 +93,100 @@ pub enum AppCommand {
         subtitle_url: String,
         seek_seconds: u32,
         file_idx: Option<u32>,
+        /// Subtitle timing shift in milliseconds
+        offset_ms: i64,
     },
     /// Playback control (pause, volume, seek)
     PlaybackControl {
         action: String,
         device: String,
+        /// Argument for actions that take one (e.g. target volume for "volume_set")
+        value: Option<u32>,
     },
     /// Save settings to config file
     SaveSettings {
         subtitle_lang: String,
         device_name: Option<String>,
     },
+    /// Read torrent progress from the webtorrent log
+    PollTorrentStatus,
+    /// Query the Chromecast for its playback status (catt status)
+    FetchCastStatus { device: String },
+    /// Persist the watchlist to config
+    SaveWatchlist(Vec<WatchlistEntry>),
+    /// Persist recent search queries to config
+    SaveSearchHistory(Vec<String>),
+    /// Persist the color palette to config
+    SaveTheme(Palette),
+    /// Persist the custom subtitle language list to config
+    SaveSubtitleLang(String),
+    /// Persist the last scan and the device picker order to config
+    SaveDevices {
+        known: Vec<SavedDevice>,
+        order: Vec<String>,
+    },
+    /// Persist a resume position (zero position clears it)
+    SaveResume {
+        info_hash: String,
+        entry: ResumeEntry,
+    },
+    /// Download a TMDB poster into the cache
+    FetchPoster(String),
+    /// Fetch titles TMDB recommends after this one into the search list
+    FetchRecommendations {
+        id: u64,
+        media_type: MediaType,
+        generation: u64,
+    },
+    /// Fetch the movies of a collection (franchise) into the search list
+    FetchCollection { id: u64, generation: u64 },
+    /// Look up a title's trailer and open it in the browser
+    OpenTrailer {
+        id: u64,
+        media_type: MediaType,
+        title: String,
+    },
+    /// Put a magnet link on the system clipboard
+    CopyMagnet(String),
+    /// Pick streams for a season's episodes and cast them back to back
+    CastSeason {
+        imdb_id: String,
+        /// Show name (queued items are titled "<name> S1E4")
+        title: String,
+        season: u8,
+        episodes: Vec<u8>,
+    },
+    /// Browse a genre (results arrive as a single page of search results)
+    Discover {
+        genre_id: u32,
+        media_type: MediaType,
+        sort: DiscoverSort,
+        generation: u64,
+    },
+}
+
+impl AppCommand {
+    /// Whether the command is answered by exactly one message (a result,
+    /// `Notice` or `Error`), so the status bar can show it's in flight
+    ///
+    /// Saves, playback controls and the polls that may stay silent aren't
+    /// counted.
+    pub fn awaits_reply(&self) -> bool {
+        !matches!(
+            self,
+            AppCommand::PlaybackControl { .. }
+                | AppCommand::SaveSettings { .. }
+                | AppCommand::PollTorrentStatus
+                | AppCommand::FetchCastStatus { .. }
+                | AppCommand::SaveWatchlist(_)
+                | AppCommand::SaveSearchHistory(_)
+                | AppCommand::SaveTheme(_)
+                | AppCommand::SaveSubtitleLang(_)
+                | AppCommand::SaveDevices { .. }
+                | AppCommand::SaveResume { .. }
+                | AppCommand::FetchPoster(_)
+        )
+    }
 }
 
 /// Results sent from async tasks back to UI
@@ -67,8 +194,14 @@ pub enum AppCommand {
 pub enum AppMessage {
     /// Trending results loaded
     TrendingLoaded(Vec<SearchResult>),
-    /// Search results loaded
-    SearchResults(Vec<SearchResult>),
+    /// Search results loaded for a page
+    SearchResults {
+        results: Vec<SearchResult>,
+        page: u32,
+        total_pages: u32,
+        /// `search_generation` of the request these answer
+        generation: u64,
+    },
     /// Movie detail loaded
     MovieDetailLoaded(MovieDetail),
     /// TV detail loaded
@@ -79,6 +212,8 @@ pub enum AppMessage {
     StreamsLoaded(Vec<StreamSource>),
     /// Subtitles loaded
     SubtitlesLoaded(Vec<SubtitleResult>),
+    /// First cues of a subtitle being previewed
+    SubtitlePreviewLoaded { url: String, cues: Vec<Cue> },
     /// Chromecast devices discovered
     DevicesLoaded(Vec<CastDevice>),
     /// Playback started with stream URL
@@ -94,16 +229,57 @@ pub enum AppMessage {
         downloaded: u64,
         total: u64,
     },
+    /// Torrent progress polled from the webtorrent log
+    TorrentProgress {
+        /// Fraction downloaded (0.0 - 1.0)
+        progress: f32,
+        /// Download speed in bytes/sec
+        speed: u64,
+        peers: u32,
+        /// Connection phase, when the log reports one
+        state: Option<TorrentState>,
+    },
+    /// Chromecast playback status polled
+    CastStatusLoaded(PlaybackStatus),
+    /// `catt status` failed or timed out
+    CastStatusUnavailable,
+    /// Season episodes resolved to streams, in play order
+    SeasonQueued(Vec<QueuedItem>),
+    /// Poster downloaded (TMDB poster_path, local file)
+    PosterLoaded { poster_path: String, file: PathBuf },
+    /// Something worth telling the user that isn't an error
+    Notice(String),
+    /// The clipboard couldn't be used; the magnet is shown instead
+    ClipboardUnavailable(String),
     /// Error occurred
     Error(String),
 }
 
+impl AppMessage {
+    /// Whether this answers a command counted by [`AppCommand::awaits_reply`]
+    ///
+    /// Errors always count, so a failed request can't leave the spinner
+    /// running.
+    pub fn is_reply(&self) -> bool {
+        !matches!(
+            self,
+            AppMessage::TorrentStateUpdate(_)
+                | AppMessage::TorrentStatsUpdate { .. }
+                | AppMessage::TorrentProgress { .. }
+                | AppMessage::CastStatusLoaded(_)
+                | AppMessage::CastStatusUnavailable
+                | AppMessage::PosterLoaded { .. }
+        )
+    }
+}
+
 // =============================================================================
 // App State Enum
 // =============================================================================
 
 /// Application state enum representing current screen
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AppState {
     /// Home screen with search box and trending content
     #[default]
@@ -118,6 +294,8 @@ pub enum AppState {
     Subtitles,
     /// Now playing overlay (cast in progress)
     Playing,
+    /// Saved titles (watchlist)
+    Watchlist,
 }
 
 // =============================================================================
@@ -265,6 +443,59 @@ impl ListState {
     }
 }
 
+/// Clickable lists (one per list widget the renderer draws)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListRegion {
+    Trending,
+    ContinueWatching,
+    Search,
+    Watchlist,
+    Seasons,
+    Episodes,
+    Sources,
+    Subtitles,
+}
+
+/// Where a list was last drawn, used to map mouse clicks to rows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderedList {
+    pub region: ListRegion,
+    /// Area the rows were drawn in (one row per line)
+    pub area: Rect,
+    /// Index of the first visible row
+    pub offset: usize,
+}
+
+impl RenderedList {
+    /// Row index under a screen position, if inside the list area
+    pub fn index_at(&self, column: u16, row: u16) -> Option<usize> {
+        let inside = column >= self.area.x
+            && column < self.area.x + self.area.width
+            && row >= self.area.y
+            && row < self.area.y + self.area.height;
+        inside.then(|| self.offset + (row - self.area.y) as usize)
+    }
+}
+
+/// Two clicks on the same row within this window count as a double-click
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Shown when casting is attempted before a device is picked
+const NO_DEVICE: &str = "No Chromecast device selected. Press 'd' to discover devices.";
+
+/// Shown when the picked Chromecast doesn't answer before playback
+pub const DEVICE_UNREACHABLE: &str = "Device unreachable: is the TV on?";
+
+/// The device to check before casting (local players need no check)
+fn preflight(device: &CastDevice) -> Option<CastDevice> {
+    PlayerType::from_device_name(&device.name)
+        .is_none()
+        .then(|| device.clone())
+}
+
+/// Shift+`←`/`→` jumps this many seek steps
+const SHIFT_SEEK_MULTIPLIER: u32 = 5;
+
 // =============================================================================
 // View-Specific State
 // =============================================================================
@@ -274,10 +505,18 @@ impl ListState {
 pub struct HomeState {
     /// Trending content results
     pub results: Vec<SearchResult>,
+    /// Trending results past `home_limit`, listed after `m`
+    pub more: Vec<SearchResult>,
     /// Trending content list state
     pub list: ListState,
     /// Loading state
     pub loading: LoadingState,
+    /// Titles with a saved position (from config `resume`)
+    pub continue_watching: Vec<HistoryEntry>,
+    /// Continue Watching list state
+    pub continue_list: ListState,
+    /// Selection is in the Continue Watching row rather than trending
+    pub continue_focused: bool,
 }
 
 impl HomeState {
@@ -285,6 +524,61 @@ impl HomeState {
     pub fn selected_result(&self) -> Option<&SearchResult> {
         self.results.get(self.list.selected)
     }
+
+    /// Show trending results, keeping those past `limit` for `show_more`
+    pub fn set_trending(&mut self, mut results: Vec<SearchResult>, limit: Option<usize>) {
+        self.more = match limit {
+            Some(limit) if results.len() > limit => results.split_off(limit),
+            _ => Vec::new(),
+        };
+        self.results = results;
+        self.list.set_len(self.results.len());
+    }
+
+    /// List the trending results held back by the limit
+    pub fn show_more(&mut self) {
+        self.results.append(&mut self.more);
+        self.list.set_len(self.results.len());
+    }
+
+    /// Replace Continue Watching entries (focus starts there when non-empty)
+    pub fn set_continue_watching(&mut self, entries: Vec<HistoryEntry>) {
+        self.continue_watching = entries;
+        self.continue_list.set_len(self.continue_watching.len());
+        self.continue_focused = !self.continue_watching.is_empty();
+    }
+
+    /// Get currently selected Continue Watching entry
+    pub fn selected_continue(&self) -> Option<&HistoryEntry> {
+        self.continue_watching.get(self.continue_list.selected)
+    }
+}
+
+/// Watchlist view state
+#[derive(Debug, Clone, Default)]
+pub struct WatchlistState {
+    /// Saved titles (mirrors config `watchlist`)
+    pub entries: Vec<WatchlistEntry>,
+    /// Watchlist list state
+    pub list: ListState,
+}
+
+impl WatchlistState {
+    /// Replace entries (e.g., loaded from config)
+    pub fn set_entries(&mut self, entries: Vec<WatchlistEntry>) {
+        self.entries = entries;
+        self.list.set_len(self.entries.len());
+    }
+
+    /// Get currently selected entry
+    pub fn selected_entry(&self) -> Option<&WatchlistEntry> {
+        self.entries.get(self.list.selected)
+    }
+
+    /// Whether a title is saved
+    pub fn contains(&self, id: u64, media_type: MediaType) -> bool {
+        self.entries.iter().any(|e| e.matches(id, media_type))
+    }
 }
 
 /// Search view state
@@ -300,6 +594,16 @@ pub struct SearchState {
     pub list: ListState,
     /// Loading state
     pub loading: LoadingState,
+    /// Current results page (1-based, 0 before the first search)
+    pub page: u32,
+    /// Total pages reported by TMDB
+    pub total_pages: u32,
+    /// Recent queries, most recent first
+    pub history: Vec<String>,
+    /// Recent searches list (shown while the query is empty)
+    pub history_list: ListState,
+    /// History entry recalled into the input with Up/Down
+    pub history_pos: Option<usize>,
 }
 
 impl SearchState {
@@ -379,6 +683,62 @@ impl SearchState {
     pub fn selected_result(&self) -> Option<&SearchResult> {
         self.results.get(self.list.selected)
     }
+
+    /// Replace the recent searches
+    pub fn set_history(&mut self, history: Vec<String>) {
+        self.history_list.set_len(history.len());
+        self.history = history;
+    }
+
+    /// Add a submitted query to the recent searches
+    pub fn record_history(&mut self, query: &str) {
+        push_search(&mut self.history, query);
+        self.history_list.set_len(self.history.len());
+        self.history_pos = None;
+    }
+
+    /// Whether the view lists recent searches instead of results
+    pub fn showing_history(&self) -> bool {
+        self.query.is_empty() && self.results.is_empty() && !self.history.is_empty()
+    }
+
+    /// Currently selected recent search
+    pub fn selected_history(&self) -> Option<&str> {
+        self.history
+            .get(self.history_list.selected)
+            .map(String::as_str)
+    }
+
+    /// Step through the history into the input (older with Up, newer with Down)
+    ///
+    /// Stepping past the newest entry empties the input again.
+    pub fn recall(&mut self, older: bool) {
+        let pos = match (self.history_pos, older) {
+            (None, true) if !self.history.is_empty() => Some(0),
+            (None, _) => None,
+            (Some(i), true) => Some((i + 1).min(self.history.len() - 1)),
+            (Some(i), false) => i.checked_sub(1),
+        };
+        self.history_pos = pos;
+        self.query = pos.map(|i| self.history[i].clone()).unwrap_or_default();
+        self.cursor_end();
+    }
+
+    /// Reset pagination for a fresh query
+    pub fn reset_pages(&mut self) {
+        self.page = 0;
+        self.total_pages = 0;
+    }
+
+    /// Next page number, if there is one
+    pub fn next_page(&self) -> Option<u32> {
+        (self.page < self.total_pages).then_some(self.page + 1)
+    }
+
+    /// Previous page number, if there is one
+    pub fn prev_page(&self) -> Option<u32> {
+        (self.page > 1).then(|| self.page - 1)
+    }
 }
 
 /// Focus state for TV detail view (which panel is focused)
@@ -438,6 +798,14 @@ impl DetailState {
         }
     }
 
+    /// Get TMDB poster path for the current content
+    pub fn poster_path(&self) -> Option<&str> {
+        match self {
+            DetailState::Movie { detail, .. } => detail.poster_path.as_deref(),
+            DetailState::Tv { detail, .. } => detail.poster_path.as_deref(),
+        }
+    }
+
     /// Get title for the current content
     pub fn title(&self) -> &str {
         match self {
@@ -447,6 +815,38 @@ impl DetailState {
     }
 }
 
+/// Sort order for the sources list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SourceSort {
+    /// Most seeders first
+    #[default]
+    Seeds,
+    /// Highest resolution first
+    Quality,
+    /// Largest file first
+    Size,
+}
+
+impl SourceSort {
+    /// Cycle to next sort order
+    pub fn next(self) -> Self {
+        match self {
+            Self::Seeds => Self::Quality,
+            Self::Quality => Self::Size,
+            Self::Size => Self::Seeds,
+        }
+    }
+
+    /// Display name
+    pub fn display(&self) -> &'static str {
+        match self {
+            Self::Seeds => "seeds",
+            Self::Quality => "quality",
+            Self::Size => "size",
+        }
+    }
+}
+
 /// Sources view state
 #[derive(Debug, Clone, Default)]
 pub struct SourcesState {
@@ -458,6 +858,25 @@ pub struct SourcesState {
     pub loading: LoadingState,
     /// Content title (for display)
     pub title: String,
+    /// Title filter typed after '/' (case-insensitive substring)
+    pub filter: String,
+    /// Whether the filter input has focus
+    pub filtering: bool,
+    /// Active sort order (None keeps Torrentio's order)
+    pub sort: Option<SourceSort>,
+    /// File index being typed for a season pack (Some while the picker is open)
+    pub file_idx_input: Option<String>,
+    /// Sources with fewer seeds are hidden (`h`; None shows them all)
+    pub seed_floor: Option<u32>,
+    /// Sources below this quality are hidden (`Q`; set from the detail view)
+    pub quality_floor: Option<Quality>,
+    /// Title the sources were fetched for (None for a season queue)
+    pub imdb_id: Option<String>,
+    /// Season/episode the sources were fetched for (TV)
+    pub season: Option<u8>,
+    pub episode: Option<u8>,
+    /// Absolute episode number sent along for anime
+    pub absolute: Option<u16>,
 }
 
 impl SourcesState {
@@ -467,17 +886,130 @@ impl SourcesState {
             list: ListState::new(0),
             loading: LoadingState::Loading(Some("Fetching sources...".into())),
             title,
+            filter: String::new(),
+            filtering: false,
+            sort: None,
+            file_idx_input: None,
+            seed_floor: None,
+            quality_floor: None,
+            imdb_id: None,
+            season: None,
+            episode: None,
+            absolute: None,
         }
     }
 
     pub fn set_sources(&mut self, sources: Vec<StreamSource>) {
-        self.list.set_len(sources.len());
         self.sources = sources;
+        self.filter.clear();
+        self.filtering = false;
+        let len = self.filtered_sources().len();
+        self.list.set_len(len);
         self.loading = LoadingState::Idle;
+        if let Some(sort) = self.sort {
+            self.sort_by(sort);
+        }
+    }
+
+    /// Re-sort sources in place, keeping the same source selected
+    ///
+    /// The sort is stable, so ties keep their previous relative order.
+    pub fn sort_by(&mut self, sort: SourceSort) {
+        let selected = self
+            .selected_source()
+            .map(|s| (s.info_hash.clone(), s.file_idx, s.direct_url.clone()));
+
+        match sort {
+            SourceSort::Seeds => self.sources.sort_by_key(|s| std::cmp::Reverse(s.seeds)),
+            SourceSort::Quality => self
+                .sources
+                .sort_by_key(|s| std::cmp::Reverse(s.quality.rank())),
+            SourceSort::Size => self
+                .sources
+                .sort_by_key(|s| std::cmp::Reverse(s.size_bytes.unwrap_or(0))),
+        }
+        self.sort = Some(sort);
+
+        if let Some((hash, file_idx, url)) = selected {
+            if let Some(idx) = self
+                .filtered_sources()
+                .iter()
+                .position(|s| s.info_hash == hash && s.file_idx == file_idx && s.direct_url == url)
+            {
+                self.list.selected = idx;
+            }
+        }
+    }
+
+    /// Sources matching the filter, seed floor and quality floor (all of them when none is set)
+    ///
+    /// The list selection and number quick-select index into this view.
+    pub fn filtered_sources(&self) -> Vec<&StreamSource> {
+        let needle = self.filter.to_lowercase();
+        self.sources
+            .iter()
+            .filter(|s| needle.is_empty() || s.title.to_lowercase().contains(&needle))
+            .filter(|s| self.seed_floor.is_none_or(|min| s.has_seeds(min)))
+            .filter(|s| {
+                self.quality_floor
+                    .is_none_or(|min| s.quality.rank() >= min.rank())
+            })
+            .collect()
+    }
+
+    /// Whether the filter or a floor hides any sources
+    pub fn is_narrowed(&self) -> bool {
+        !self.filter.is_empty() || self.seed_floor.is_some() || self.quality_floor.is_some()
+    }
+
+    /// Select the first shown source closest to `quality`
+    pub fn select_quality(&mut self, quality: Quality) {
+        let best = self
+            .filtered_sources()
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, s)| s.quality.distance(quality))
+            .map(|(i, _)| i);
+        if let Some(index) = best {
+            self.list.selected = index;
+        }
+    }
+
+    /// Hide sources under `min_seeds`, or show them again
+    pub fn toggle_seed_floor(&mut self, min_seeds: u32) {
+        self.seed_floor = match self.seed_floor {
+            Some(_) => None,
+            None => Some(min_seeds),
+        };
+        let len = self.filtered_sources().len();
+        self.list.set_len(len);
+    }
+
+    /// Hide sources below `quality`, or show them again
+    pub fn toggle_quality_floor(&mut self, quality: Quality) {
+        self.quality_floor = match self.quality_floor {
+            Some(_) => None,
+            None => Some(quality),
+        };
+        let len = self.filtered_sources().len();
+        self.list.set_len(len);
+    }
+
+    /// Update the filter and clamp the selection to the narrowed list
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+        let len = self.filtered_sources().len();
+        self.list.set_len(len);
+    }
+
+    /// Drop the filter and restore the full list
+    pub fn clear_filter(&mut self) {
+        self.filtering = false;
+        self.set_filter(String::new());
     }
 
     pub fn selected_source(&self) -> Option<&StreamSource> {
-        self.sources.get(self.list.selected)
+        self.filtered_sources().get(self.list.selected).copied()
     }
 }
 
@@ -485,10 +1017,11 @@ impl SourcesState {
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub enum SubLangFilter {
     #[default]
-    EngSpa,  // English + Spanish (default)
+    EngSpa, // English + Spanish (default)
     English, // English only
     Spanish, // Spanish only
     All,     // All languages
+    Custom,  // Codes typed with `c` (SubtitlesState::custom_lang)
 }
 
 impl SubLangFilter {
@@ -498,7 +1031,8 @@ impl SubLangFilter {
             Self::EngSpa => Self::English,
             Self::English => Self::Spanish,
             Self::Spanish => Self::All,
-            Self::All => Self::EngSpa,
+            Self::All => Self::Custom,
+            Self::Custom => Self::EngSpa,
         }
     }
 
@@ -508,21 +1042,38 @@ impl SubLangFilter {
             Self::EngSpa => "eng,spa",
             Self::English => "eng",
             Self::Spanish => "spa",
-            Self::All => "",
+            Self::All | Self::Custom => "",
         }
     }
 
-    /// Display name
-    pub fn display(&self) -> &'static str {
+    /// Display name, e.g. "English+Spanish"
+    pub fn display(&self) -> String {
         match self {
-            Self::EngSpa => "🇬🇧+🇪🇸",
-            Self::English => "🇬🇧 English",
-            Self::Spanish => "🇪🇸 Spanish",
-            Self::All => "🌍 All",
+            Self::All | Self::Custom => "All languages".to_string(),
+            _ => language_names(self.lang_code()),
         }
     }
 }
 
+/// "fre,jpn" as "French+Japanese" (unknown codes as typed)
+fn language_names(codes: &str) -> String {
+    codes
+        .split(',')
+        .map(|code| crate::models::language_name(code).unwrap_or(code))
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+/// Tidy a typed language list: lowercase, no blanks, comma separated
+pub fn normalize_lang_list(typed: &str) -> Option<String> {
+    let codes: Vec<String> = typed
+        .split([',', ' '])
+        .map(|code| code.trim().to_lowercase())
+        .filter(|code| !code.is_empty())
+        .collect();
+    (!codes.is_empty()).then(|| codes.join(","))
+}
+
 /// Subtitles view state
 #[derive(Debug, Clone, Default)]
 pub struct SubtitlesState {
@@ -536,21 +1087,162 @@ pub struct SubtitlesState {
     pub selected: Option<SubtitleResult>,
     /// Language filter
     pub lang_filter: SubLangFilter,
+    /// Language codes typed with `c` (e.g. "fre,jpn"), used by the Custom filter
+    pub custom_lang: Option<String>,
+    /// Language codes being typed after `c` (Some while entering them)
+    pub lang_input: Option<String>,
+    /// Local subtitle path being typed after `l` (Some while entering a path)
+    pub path_input: Option<String>,
+    /// Preview modal opened with `space` (Some while shown)
+    pub preview: Option<SubtitlePreview>,
+    /// Title the subtitles were fetched for
+    pub imdb_id: Option<String>,
+    /// Season/episode the subtitles were fetched for (TV)
+    pub season: Option<u16>,
+    pub episode: Option<u16>,
+}
+
+/// A row of the subtitle list: a language heading or an index into `subtitles`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubtitleListRow {
+    Language(String),
+    Subtitle(usize),
+}
+
+/// First cues of a subtitle, shown before committing to it
+#[derive(Debug, Clone, Default)]
+pub struct SubtitlePreview {
+    /// Download URL of the previewed subtitle
+    pub url: String,
+    /// Release name shown in the modal title
+    pub release: String,
+    pub cues: Vec<Cue>,
+    pub loading: LoadingState,
+    /// Index of the first visible cue
+    pub scroll: usize,
 }
 
 impl SubtitlesState {
-    pub fn set_subtitles(&mut self, subtitles: Vec<SubtitleResult>) {
+    /// Show `subtitles` grouped by language, best first within each language
+    ///
+    /// Languages the filter asks for come first, in its order ("eng,spa"
+    /// lists English before Spanish); the rest follow alphabetically. The
+    /// list is stored in that order so `list.selected` indexes it directly.
+    pub fn set_subtitles(&mut self, mut subtitles: Vec<SubtitleResult>) {
+        let codes = self.lang_code();
+        let rank = |sub: &SubtitleResult| {
+            codes
+                .split(',')
+                .filter(|code| !code.is_empty())
+                .position(|code| {
+                    code.eq_ignore_ascii_case(&sub.language)
+                        || language_name(code) == Some(sub.language_name.as_str())
+                })
+                .unwrap_or(usize::MAX)
+        };
+        subtitles.sort_by_cached_key(|s| {
+            (
+                rank(s),
+                s.language_name.clone(),
+                std::cmp::Reverse(s.trust_score()),
+            )
+        });
         self.list.set_len(subtitles.len());
         self.subtitles = subtitles;
         self.loading = LoadingState::Idle;
     }
 
+    /// Rows to draw: a heading before each language's subtitles
+    pub fn sorted_grouped(&self) -> Vec<SubtitleListRow> {
+        let mut rows = Vec::new();
+        for (i, sub) in self.subtitles.iter().enumerate() {
+            if i == 0 || self.subtitles[i - 1].language_name != sub.language_name {
+                rows.push(SubtitleListRow::Language(sub.language_name.clone()));
+            }
+            rows.push(SubtitleListRow::Subtitle(i));
+        }
+        rows
+    }
+
     pub fn selected_subtitle(&self) -> Option<&SubtitleResult> {
         self.subtitles.get(self.list.selected)
     }
+
+    /// Language code(s) to search for; the custom list when that filter is on
+    pub fn lang_code(&self) -> String {
+        match (self.lang_filter, &self.custom_lang) {
+            (SubLangFilter::Custom, Some(custom)) => custom.clone(),
+            (filter, _) => filter.lang_code().to_string(),
+        }
+    }
+
+    /// Next filter for Tab; the custom slot is skipped until one is typed
+    pub fn next_filter(&self) -> SubLangFilter {
+        match self.lang_filter.next() {
+            SubLangFilter::Custom if self.custom_lang.is_none() => SubLangFilter::EngSpa,
+            next => next,
+        }
+    }
+
+    /// Name of the active filter, e.g. "French+Japanese"
+    pub fn filter_display(&self) -> String {
+        match (self.lang_filter, &self.custom_lang) {
+            (SubLangFilter::Custom, Some(custom)) => language_names(custom),
+            (filter, _) => filter.display(),
+        }
+    }
 }
 
 /// Playing view state
+/// Countdown before the next episode starts (config `autoplay_next`)
+#[derive(Debug, Clone)]
+pub struct Autoplay {
+    pub season: u8,
+    pub episode: u8,
+    pub starts_at: Instant,
+}
+
+impl Autoplay {
+    /// Whole seconds left, rounded up
+    pub fn remaining_secs(&self) -> u64 {
+        let left = self.starts_at.saturating_duration_since(Instant::now());
+        left.as_millis().div_ceil(1000) as u64
+    }
+}
+
+/// How long the "Playing next in…" countdown runs
+pub const AUTOPLAY_DELAY: Duration = Duration::from_secs(10);
+
+/// Countdown before switching a stalled stream to a lower quality
+/// (config `auto_downgrade`)
+#[derive(Debug, Clone)]
+pub struct Downgrade {
+    pub source: StreamSource,
+    pub starts_at: Instant,
+}
+
+impl Downgrade {
+    /// Whole seconds left, rounded up
+    pub fn remaining_secs(&self) -> u64 {
+        let left = self.starts_at.saturating_duration_since(Instant::now());
+        left.as_millis().div_ceil(1000) as u64
+    }
+}
+
+/// Buffering stall before a lower quality is offered (config `stall_secs`)
+pub const DEFAULT_STALL: Duration = Duration::from_secs(30);
+
+/// How long the "Switching to 1080p in…" countdown runs
+pub const DOWNGRADE_DELAY: Duration = Duration::from_secs(10);
+
+/// Buffering progress last seen, and since when it hasn't moved
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StallWatch {
+    pub progress: f32,
+    pub downloaded: u64,
+    pub since: Instant,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PlayingState {
     /// Current torrent session
@@ -567,6 +1259,36 @@ pub struct PlayingState {
     pub magnet: Option<String>,
     /// Pending subtitle change (triggers restart on return to Playing)
     pub pending_subtitle_url: Option<String>,
+    /// Volume percentage being typed (Some while entering a value)
+    pub volume_input: Option<String>,
+    /// Seek timestamp being typed after `g` (Some while entering a value)
+    pub seek_input: Option<String>,
+    /// Info hash of the playing torrent (resume key)
+    pub info_hash: Option<String>,
+    /// IMDB ID of the playing title
+    pub imdb_id: Option<String>,
+    /// Saved position offered to the user ("Resume at 42:10? (y/n)")
+    pub resume_prompt: Option<u64>,
+    /// StartPlayback held back until the resume prompt is answered
+    pub pending_start: Option<AppCommand>,
+    /// Last position persisted to the resume table
+    pub resume_saved_at: u64,
+    /// Subtitle timing shift applied to the active track (`[` / `]`)
+    pub subtitle_offset_ms: i64,
+    /// Season number of the playing episode (TV only)
+    pub season: Option<u8>,
+    /// Episode number of the playing episode (TV only)
+    pub episode: Option<u8>,
+    /// Episodes still to play after this one (season cast)
+    pub playback_queue: Vec<QueuedItem>,
+    /// Pending autoplay of the next episode (Esc cancels)
+    pub autoplay: Option<Autoplay>,
+    /// Buffering progress tracker for stall detection
+    pub stall: Option<StallWatch>,
+    /// Pending switch to a lower-quality source (Esc cancels)
+    pub downgrade: Option<Downgrade>,
+    /// The user kept this source (or there's nothing lower); don't ask again
+    pub downgrade_declined: bool,
 }
 
 // =============================================================================
@@ -585,36 +1307,114 @@ pub struct App {
     pub input_mode: InputMode,
     /// Global error message
     pub error: Option<String>,
+    /// Details left for a recommendations list, restored on the way back
+    pub detail_stack: Vec<DetailState>,
+    /// Saved session still being restored (selections wait for their data)
+    pub restoring: Option<SavedSession>,
+    /// Commands waiting for their reply (status bar spinner)
+    pub pending_commands: usize,
+    /// A `catt status` poll is out; the next waits for its reply
+    pub cast_status_in_flight: bool,
+    /// Status bar message, cleared on the next key press
+    pub notice: Option<String>,
+    /// Magnet shown in a popup when it couldn't be copied (until a key press)
+    pub magnet_popup: Option<String>,
 
     // View-specific states
     pub home: HomeState,
     pub search: SearchState,
+    /// Bumped for every search request; older results are stale
+    pub search_generation: u64,
     pub detail: Option<DetailState>,
     pub sources: SourcesState,
     pub subtitles: SubtitlesState,
     pub playing: PlayingState,
+    pub watchlist: WatchlistState,
 
     // Shared state
     /// Available cast devices
     pub cast_devices: Vec<CastDevice>,
+    /// Installed local players, offered as devices before the Chromecasts
+    pub local_players: Vec<PlayerType>,
     /// Selected cast device index
     pub selected_device: Option<usize>,
+    /// Devices marked with Space in the device modal; playback is cast to
+    /// them too, alongside the selected one
+    pub selected_devices: Vec<usize>,
     /// Show device selection modal
     pub show_device_modal: bool,
     /// Device modal selection index (separate from selected_device until confirmed)
     pub device_modal_index: usize,
+    /// Address being typed in the device modal (Some while adding by IP)
+    pub device_ip_input: Option<String>,
+    /// Devices from the last scan (config `known_devices`)
+    pub known_devices: Vec<SavedDevice>,
+    /// User-arranged device order (config `device_order`)
+    pub device_order: Vec<String>,
+    /// Show genre picker modal (`g` on Home)
+    pub show_genre_modal: bool,
+    /// Genre picker selection index (into `genre_choices()`)
+    pub genre_modal_index: usize,
+    /// Media type the genre picker discovers
+    pub genre_media_type: MediaType,
+    /// Ordering for genre discovery
+    pub genre_sort: DiscoverSort,
 
     // Settings
     /// Default subtitle language (ISO 639-1 code, e.g., "en", "es", "fr")
     pub default_subtitle_lang: String,
     /// Default device name (from config, used to auto-select on discovery)
     pub default_device_name: Option<String>,
+    /// Seed floor for hiding sources (config `min_seeds`)
+    pub min_seeds: u32,
+    /// Trending titles on the home screen before `m` (config `home_limit`)
+    pub home_limit: Option<usize>,
+    /// Source quality selected when streams load (config `preferred_quality`)
+    pub preferred_quality: Option<Quality>,
+    /// Lowest quality shown when sources open from the detail view (`+`/`-`)
+    pub quality_floor: Option<Quality>,
+    /// Seconds `←`/`→` seek while playing (config `seek_step_secs`)
+    pub seek_step: u32,
     /// Show settings modal
     pub show_settings_modal: bool,
     /// Settings modal field index (0=language, 1=device)
     pub settings_field_index: usize,
+    /// Show keybinding help overlay (`?`)
+    pub show_help_modal: bool,
+    /// First visible line of the help overlay
+    pub help_scroll: usize,
     /// Temporary language input while editing
     pub settings_lang_input: String,
+    /// Remember playback positions (config `enable_resume`)
+    pub enable_resume: bool,
+    /// Start the next episode when one ends (config `autoplay_next`)
+    pub autoplay_next: bool,
+    /// Offer a lower quality when buffering stalls (config `auto_downgrade`)
+    pub auto_downgrade: bool,
+    /// How long buffering may stand still first (config `stall_secs`)
+    pub stall_after: Duration,
+    /// Trackers put in magnet links (config `trackers`)
+    pub trackers: Vec<String>,
+    /// Saved positions keyed by info_hash (config `resume` table)
+    pub resume: BTreeMap<String, ResumeEntry>,
+    /// Continue Watching entry that opened Sources (stands in for `detail`)
+    pub resume_from: Option<HistoryEntry>,
+    /// Lists drawn in the last frame (filled by the renderer)
+    pub rendered_lists: RefCell<Vec<RenderedList>>,
+    /// Last left click (for double-click detection)
+    pub last_click: Option<(Instant, ListRegion, usize)>,
+    /// Remappable shortcuts (config `[keybindings]`)
+    pub keymap: KeyMap,
+    /// Colors for rendering (config `theme`, cycled with `t`)
+    pub theme: Theme,
+    /// Symbols for rendering (config `ascii_mode`, `--ascii`)
+    pub glyphs: Glyphs,
+    /// Graphics protocol for posters (None keeps the text-only detail view)
+    pub graphics: Option<GraphicsProtocol>,
+    /// Last downloaded poster (TMDB poster_path, local file)
+    pub poster: Option<(String, PathBuf)>,
+    /// Where the detail view left room for the poster (set by the renderer)
+    pub poster_area: Cell<Option<Rect>>,
 
     // Async communication
     /// Channel to send commands to async task spawner
@@ -637,31 +1437,64 @@ impl App {
             running: true,
             input_mode: InputMode::Normal,
             error: None,
+            detail_stack: Vec::new(),
+            restoring: None,
+            pending_commands: 0,
+            cast_status_in_flight: false,
+            notice: None,
+            magnet_popup: None,
 
             home: HomeState::default(),
             search: SearchState::default(),
+            search_generation: 0,
             detail: None,
             sources: SourcesState::default(),
             subtitles: SubtitlesState::default(),
             playing: PlayingState::default(),
+            watchlist: WatchlistState::default(),
 
-            // Initialize with VLC as default device (always available)
-            cast_devices: vec![CastDevice {
-                id: "vlc-local".to_string(),
-                name: "VLC (Local)".to_string(),
-                address: std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
-                port: 0,
-                model: Some("Local Playback".to_string()),
-            }],
+            // Initialize with VLC as default device (run_tui swaps in the installed players)
+            cast_devices: vec![PlayerType::Vlc.device()],
+            local_players: vec![PlayerType::Vlc],
             selected_device: Some(0), // VLC selected by default
+            selected_devices: Vec::new(),
             show_device_modal: false,
             device_modal_index: 0,
+            device_ip_input: None,
+            known_devices: Vec::new(),
+            device_order: Vec::new(),
+            show_genre_modal: false,
+            genre_modal_index: 0,
+            genre_media_type: MediaType::Movie,
+            genre_sort: DiscoverSort::default(),
 
             default_subtitle_lang: "eng,spa".to_string(), // English + Spanish by default
             default_device_name: None,
+            min_seeds: HEALTHY_SEEDS,
+            preferred_quality: None,
+            quality_floor: None,
+            seek_step: DEFAULT_SEEK_STEP,
+            home_limit: Some(DEFAULT_HOME_LIMIT),
             show_settings_modal: false,
             settings_field_index: 0,
+            show_help_modal: false,
+            help_scroll: 0,
             settings_lang_input: String::new(),
+            enable_resume: false,
+            autoplay_next: false,
+            auto_downgrade: false,
+            stall_after: DEFAULT_STALL,
+            trackers: DEFAULT_TRACKERS.iter().map(|t| t.to_string()).collect(),
+            resume: BTreeMap::new(),
+            resume_from: None,
+            rendered_lists: RefCell::new(Vec::new()),
+            last_click: None,
+            keymap: KeyMap::default(),
+            theme: Theme::default(),
+            glyphs: Glyphs::default(),
+            graphics: None,
+            poster: None,
+            poster_area: Cell::new(None),
 
             cmd_tx,
         }
@@ -678,31 +1511,64 @@ impl App {
             running: true,
             input_mode: InputMode::Normal,
             error: None,
+            detail_stack: Vec::new(),
+            restoring: None,
+            pending_commands: 0,
+            cast_status_in_flight: false,
+            notice: None,
+            magnet_popup: None,
 
             home: HomeState::default(),
             search: SearchState::default(),
+            search_generation: 0,
             detail: None,
             sources: SourcesState::default(),
             subtitles: SubtitlesState::default(),
             playing: PlayingState::default(),
+            watchlist: WatchlistState::default(),
 
-            // Initialize with VLC as default device (always available)
-            cast_devices: vec![CastDevice {
-                id: "vlc-local".to_string(),
-                name: "VLC (Local)".to_string(),
-                address: std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
-                port: 0,
-                model: Some("Local Playback".to_string()),
-            }],
+            // Initialize with VLC as default device (run_tui swaps in the installed players)
+            cast_devices: vec![PlayerType::Vlc.device()],
+            local_players: vec![PlayerType::Vlc],
             selected_device: Some(0), // VLC selected by default
+            selected_devices: Vec::new(),
             show_device_modal: false,
             device_modal_index: 0,
+            device_ip_input: None,
+            known_devices: Vec::new(),
+            device_order: Vec::new(),
+            show_genre_modal: false,
+            genre_modal_index: 0,
+            genre_media_type: MediaType::Movie,
+            genre_sort: DiscoverSort::default(),
 
             default_subtitle_lang: "eng,spa".to_string(), // English + Spanish by default
             default_device_name: None,
+            min_seeds: HEALTHY_SEEDS,
+            preferred_quality: None,
+            quality_floor: None,
+            seek_step: DEFAULT_SEEK_STEP,
+            home_limit: Some(DEFAULT_HOME_LIMIT),
             show_settings_modal: false,
             settings_field_index: 0,
+            show_help_modal: false,
+            help_scroll: 0,
             settings_lang_input: String::new(),
+            enable_resume: false,
+            autoplay_next: false,
+            auto_downgrade: false,
+            stall_after: DEFAULT_STALL,
+            trackers: DEFAULT_TRACKERS.iter().map(|t| t.to_string()).collect(),
+            resume: BTreeMap::new(),
+            resume_from: None,
+            rendered_lists: RefCell::new(Vec::new()),
+            last_click: None,
+            keymap: KeyMap::default(),
+            theme: Theme::default(),
+            glyphs: Glyphs::default(),
+            graphics: None,
+            poster: None,
+            poster_area: Cell::new(None),
 
             cmd_tx,
         };
@@ -710,64 +1576,220 @@ impl App {
         (app, cmd_rx)
     }
 
+    /// Periodic work driven by the event loop (called about once a second)
+    pub fn tick(&mut self) {
+        if self
+            .playing
+            .autoplay
+            .as_ref()
+            .is_some_and(|a| Instant::now() >= a.starts_at)
+        {
+            self.start_autoplay();
+        }
+        if self
+            .playing
+            .downgrade
+            .as_ref()
+            .is_some_and(|d| Instant::now() >= d.starts_at)
+        {
+            self.start_downgrade();
+        }
+        self.watch_buffering();
+
+        // Poll torrent progress until the stream is up
+        let buffering = self
+            .playing
+            .torrent
+            .as_ref()
+            .is_some_and(|t| !matches!(t.state, TorrentState::Streaming));
+        if self.state == AppState::Playing && buffering {
+            self.send_command(AppCommand::PollTorrentStatus);
+        }
+
+        // Follow the TV's real state (physical remote, other apps); catt can't query local players.
+        // One poll at a time: a slow TV takes up to 3s to answer
+        if self.state == AppState::Playing && !self.cast_status_in_flight {
+            if let Some(device) = self
+                .playing
+                .device
+                .as_ref()
+                .filter(|d| PlayerType::from_device_name(&d.name).is_none())
+            {
+                self.cast_status_in_flight = true;
+                self.send_command(AppCommand::FetchCastStatus {
+                    device: device.name.clone(),
+                });
+            }
+        }
+    }
+
     /// Send a command to the async task spawner
-    pub fn send_command(&self, cmd: AppCommand) {
+    pub fn send_command(&mut self, cmd: AppCommand) {
+        if cmd.awaits_reply() {
+            self.pending_commands += 1;
+        }
         let _ = self.cmd_tx.send(cmd);
     }
 
     /// Handle an incoming async message
     pub fn handle_message(&mut self, msg: AppMessage) {
+        if msg.is_reply() {
+            self.pending_commands = self.pending_commands.saturating_sub(1);
+        }
         match msg {
             AppMessage::TrendingLoaded(results) => {
-                self.home.results = results;
-                self.home.list.set_len(self.home.results.len());
+                self.home.set_trending(results, self.home_limit);
                 self.home.loading = LoadingState::Idle;
             }
-            AppMessage::SearchResults(results) => {
+            AppMessage::SearchResults { generation, .. }
+                if generation != self.search_generation =>
+            {
+                // A reply to a query that has since been replaced
+            }
+            AppMessage::SearchResults {
+                results,
+                page,
+                total_pages,
+                ..
+            } => {
                 self.search.set_results(results);
+                self.search.page = page;
+                self.search.total_pages = total_pages;
+                if let Some(index) = self
+                    .restoring
+                    .as_mut()
+                    .and_then(|r| r.search_selected.take())
+                {
+                    self.search.list.selected = index.min(self.search.list.len.saturating_sub(1));
+                    self.settle_restore();
+                }
             }
             AppMessage::MovieDetailLoaded(detail) => {
+                if self.restoring_detail(detail.id).is_some() {
+                    if let Some(restoring) = self.restoring.as_mut() {
+                        restoring.detail = None;
+                    }
+                    self.settle_restore();
+                }
                 self.detail = Some(DetailState::movie(detail));
+                self.resume_from = None;
+                self.request_poster();
                 self.navigate(AppState::Detail);
             }
             AppMessage::TvDetailLoaded(detail) => {
                 // Store the TV ID for episode fetching
                 let tv_id = detail.id;
-                self.detail = Some(DetailState::tv(detail));
+                // A restored session goes back to its season, others start at 1
+                let season = self
+                    .restoring_detail(tv_id)
+                    .and_then(|d| d.season)
+                    .unwrap_or(1);
+                let mut state = DetailState::tv(detail);
+                if let DetailState::Tv {
+                    detail,
+                    season_list,
+                    ..
+                } = &mut state
+                {
+                    if let Some(index) = detail
+                        .seasons
+                        .iter()
+                        .position(|s| s.season_number == season)
+                    {
+                        season_list.selected = index;
+                    }
+                }
+                self.detail = Some(state);
+                self.resume_from = None;
+                self.request_poster();
                 self.navigate(AppState::Detail);
-                // Auto-fetch first season's episodes
-                self.send_command(AppCommand::FetchEpisodes { tv_id, season: 1 });
+                self.send_command(AppCommand::FetchEpisodes { tv_id, season });
+            }
+            AppMessage::PosterLoaded { poster_path, file } => {
+                self.poster = Some((poster_path, file));
             }
             AppMessage::EpisodesLoaded { season, episodes } => {
                 // Update the TV detail state with loaded episodes
-                if let Some(DetailState::Tv { episodes: eps, episode_list, selected_season, .. }) = &mut self.detail {
+                if let Some(DetailState::Tv {
+                    detail,
+                    episodes: eps,
+                    episode_list,
+                    selected_season,
+                    focus,
+                    ..
+                }) = &mut self.detail
+                {
                     *eps = episodes;
                     episode_list.set_len(eps.len());
                     *selected_season = season;
+
+                    let restored = self
+                        .restoring
+                        .as_mut()
+                        .filter(|r| {
+                            r.detail
+                                .as_ref()
+                                .is_some_and(|d| d.id == detail.id && d.season == Some(season))
+                        })
+                        .and_then(|r| r.detail.take())
+                        .and_then(|d| d.episode_index);
+                    if let Some(index) = restored {
+                        episode_list.selected = index.min(episode_list.len.saturating_sub(1));
+                        *focus = TvFocus::Episodes;
+                    }
                 }
+                self.settle_restore();
             }
             AppMessage::StreamsLoaded(streams) => {
                 self.sources.set_sources(streams);
+                if let Some(quality) = self.preferred_quality {
+                    self.sources.select_quality(quality);
+                }
             }
             AppMessage::SubtitlesLoaded(subs) => {
                 self.subtitles.set_subtitles(subs);
             }
+            AppMessage::SubtitlePreviewLoaded { url, cues } => {
+                // Ignore results for a preview that was closed or replaced
+                if let Some(preview) = self.subtitles.preview.as_mut().filter(|p| p.url == url) {
+                    preview.loading = if cues.is_empty() {
+                        LoadingState::Error("No cues found in this subtitle".into())
+                    } else {
+                        LoadingState::Idle
+                    };
+                    preview.cues = cues;
+                }
+            }
             AppMessage::DevicesLoaded(devices) => {
-                // Start with VLC as first option (always available for local playback)
-                let mut all_devices = vec![CastDevice {
-                    id: "vlc-local".to_string(),
-                    name: "VLC (Local)".to_string(),
-                    address: std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
-                    port: 0,
-                    model: Some("Local Playback".to_string()),
-                }];
+                // Start with the local players (always available for local playback)
+                let mut all_devices: Vec<CastDevice> =
+                    self.local_players.iter().map(PlayerType::device).collect();
+                let devices = arrange_devices(devices, &self.known_devices, &self.device_order);
+                self.known_devices = devices.iter().map(SavedDevice::from).collect();
+                // Devices added by IP never show up in a scan; keep them
+                let manual: Vec<CastDevice> = self
+                    .cast_devices
+                    .iter()
+                    .filter(|d| {
+                        d.is_manual() && !devices.iter().any(|found| found.address == d.address)
+                    })
+                    .cloned()
+                    .collect();
                 all_devices.extend(devices);
+                all_devices.extend(manual);
                 self.cast_devices = all_devices;
+                // Marks are positions in the old list
+                self.selected_devices.clear();
+                self.save_devices();
                 // Always try to match default device when devices are loaded
                 if !self.cast_devices.is_empty() {
                     if let Some(ref default_name) = self.default_device_name {
                         // Try to find and select the saved default device
-                        if let Some(idx) = self.cast_devices.iter().position(|d| &d.name == default_name) {
+                        if let Some(idx) = self
+                            .cast_devices
+                            .iter()
+                            .position(|d| &d.name == default_name)
+                        {
                             self.selected_device = Some(idx);
                         } else if self.selected_device.is_none() {
                             // Fallback to first device if default not found
@@ -795,18 +1817,83 @@ impl App {
                     session.state = state;
                 }
             }
-            AppMessage::TorrentStatsUpdate { download_speed, peers, downloaded, total } => {
+            AppMessage::TorrentProgress {
+                progress,
+                speed,
+                peers,
+                state,
+            } => {
+                if let Some(ref mut session) = self.playing.torrent {
+                    session.progress = progress;
+                    session.download_speed = speed;
+                    session.peers = peers;
+                    // The log monitor may already have seen the stream start
+                    if let Some(state) = state.filter(|_| session.state != TorrentState::Streaming)
+                    {
+                        session.state = state;
+                    }
+                }
+            }
+            AppMessage::CastStatusUnavailable => self.cast_status_in_flight = false,
+            AppMessage::CastStatusLoaded(status) => {
+                self.cast_status_in_flight = false;
+                // An idle TV before our media loads says nothing useful yet
+                let nothing_loaded = status.duration.is_zero()
+                    && matches!(status.state, CastState::Idle | CastState::Stopped);
+                if self.playing.playback.is_none() && nothing_loaded {
+                    return;
+                }
+                // The episode ended: move on to the next queued one
+                let was_active = self
+                    .playing
+                    .playback
+                    .as_ref()
+                    .is_some_and(|p| p.state.is_active());
+                if was_active
+                    && status.state.is_finished()
+                    && !self.playing.playback_queue.is_empty()
+                {
+                    self.play_next_queued();
+                    return;
+                }
+                if was_active && status.state.is_finished() {
+                    self.schedule_autoplay();
+                }
+                self.record_resume_position(&status);
+                self.playing.playback = Some(status);
+            }
+            AppMessage::TorrentStatsUpdate {
+                download_speed,
+                peers,
+                downloaded,
+                total,
+            } => {
                 // Update torrent stats for UI display
                 if let Some(ref mut session) = self.playing.torrent {
                     session.download_speed = download_speed;
                     session.peers = peers;
                     session.downloaded = downloaded;
-                    session.total_size = total;
                     if total > 0 {
+                        session.total_size = total;
                         session.progress = (downloaded as f32 / total as f32) * 100.0;
                     }
                 }
             }
+            AppMessage::SeasonQueued(items) => {
+                self.sources.loading = LoadingState::Idle;
+                if items.is_empty() {
+                    self.set_error("No streams found for the queued episodes");
+                    return;
+                }
+                self.playing.playback_queue = items;
+                self.play_next_queued();
+            }
+            AppMessage::Notice(msg) => {
+                self.notice = Some(msg);
+            }
+            AppMessage::ClipboardUnavailable(magnet) => {
+                self.magnet_popup = Some(magnet);
+            }
             AppMessage::Error(msg) => {
                 self.set_error(msg);
                 // Reset loading states
@@ -814,7 +1901,106 @@ impl App {
                 self.search.loading = LoadingState::Idle;
                 self.sources.loading = LoadingState::Idle;
                 self.subtitles.loading = LoadingState::Idle;
+                self.subtitles.preview = None;
+            }
+        }
+    }
+
+    /// Snapshot of where the user is, for `restore_session` on the next start
+    pub fn session(&self) -> SavedSession {
+        let in_detail = matches!(
+            self.state,
+            AppState::Detail | AppState::Sources | AppState::Subtitles
+        );
+        let detail = self
+            .detail
+            .as_ref()
+            .filter(|_| in_detail)
+            .map(|detail| match detail {
+                DetailState::Movie { detail, .. } => SavedDetail {
+                    id: detail.id,
+                    media_type: MediaType::Movie,
+                    season: None,
+                    episode_index: None,
+                },
+                DetailState::Tv {
+                    detail,
+                    selected_season,
+                    episode_list,
+                    ..
+                } => SavedDetail {
+                    id: detail.id,
+                    media_type: MediaType::Tv,
+                    season: Some(*selected_season),
+                    episode_index: Some(episode_list.selected),
+                },
+            });
+        let query = self.search.query.clone();
+        SavedSession {
+            state: self.state.clone(),
+            detail,
+            search_selected: (!query.is_empty()).then_some(self.search.list.selected),
+            query,
+        }
+    }
+
+    /// Replay a saved session: re-run the search and re-fetch the detail
+    ///
+    /// Selections are applied as the results come in. Sources and subtitles
+    /// come back as their title's detail view; a session saved while playing
+    /// isn't restored.
+    pub fn restore_session(&mut self, saved: SavedSession) {
+        if saved.state == AppState::Playing {
+            return;
+        }
+
+        if !saved.query.is_empty() {
+            self.search.query = saved.query.clone();
+            self.search.cursor = self.search.query.len();
+            self.search.loading = LoadingState::Loading(Some("Searching...".into()));
+            let generation = self.next_search_generation();
+            self.send_command(AppCommand::Search {
+                query: saved.query.clone(),
+                generation,
+            });
+            if saved.state != AppState::Watchlist {
+                self.navigate(AppState::Search);
+            }
+        }
+        if saved.state == AppState::Watchlist {
+            self.watchlist.list.set_len(self.watchlist.entries.len());
+            self.navigate(AppState::Watchlist);
+        }
+        // The detail view opens when the title arrives
+        match &saved.detail {
+            Some(detail) if detail.media_type == MediaType::Movie => {
+                self.send_command(AppCommand::FetchMovieDetail(detail.id))
             }
+            Some(detail) => self.send_command(AppCommand::FetchTvDetail(detail.id)),
+            None => {}
+        }
+
+        self.restoring = Some(saved);
+        self.settle_restore();
+    }
+
+    /// The title a restore is waiting for, if `id` is it
+    fn restoring_detail(&self, id: u64) -> Option<&SavedDetail> {
+        self.restoring
+            .as_ref()?
+            .detail
+            .as_ref()
+            .filter(|d| d.id == id)
+    }
+
+    /// Forget the restore once nothing is left to apply
+    fn settle_restore(&mut self) {
+        if self
+            .restoring
+            .as_ref()
+            .is_some_and(|r| r.detail.is_none() && r.search_selected.is_none())
+        {
+            self.restoring = None;
         }
     }
 
@@ -838,6 +2024,13 @@ impl App {
         }
 
         if let Some(prev) = self.nav_stack.pop() {
+            // Leaving a recommendations list returns to the title it came from
+            if self.state == AppState::Search && prev == AppState::Detail {
+                if let Some(detail) = self.detail_stack.pop() {
+                    self.detail = Some(detail);
+                    self.request_poster();
+                }
+            }
             self.state = prev.clone();
 
             // If returning to Playing with a pending subtitle, trigger restart
@@ -855,11 +2048,17 @@ impl App {
 
     /// Trigger playback restart with new subtitle at current position
     fn trigger_subtitle_restart(&mut self, subtitle_url: String) {
-        let Some(magnet) = self.playing.magnet.clone() else { return };
-        let Some(device) = self.playing.device.clone() else { return };
+        let Some(magnet) = self.playing.magnet.clone() else {
+            return;
+        };
+        let Some(device) = self.playing.device.clone() else {
+            return;
+        };
 
         // Get current position (default to 0 if unknown)
-        let seek_seconds = self.playing.playback
+        let seek_seconds = self
+            .playing
+            .playback
             .as_ref()
             .map(|p| p.position.as_secs() as u32)
             .unwrap_or(0);
@@ -874,9 +2073,20 @@ impl App {
             subtitle_url,
             seek_seconds,
             file_idx,
+            offset_ms: self.playing.subtitle_offset_ms,
         });
     }
 
+    /// Nudge subtitle timing and reload the active track with the new offset
+    fn shift_subtitles(&mut self, delta_ms: i64) {
+        let Some(subtitle_url) = self.subtitles.selected.as_ref().map(|s| s.url.clone()) else {
+            self.set_error("No subtitles loaded. Press 'u' to pick one.");
+            return;
+        };
+        self.playing.subtitle_offset_ms += delta_ms;
+        self.trigger_subtitle_restart(subtitle_url);
+    }
+
     /// Quit the application
     pub fn quit(&mut self) {
         self.running = false;
@@ -908,6 +2118,39 @@ impl App {
         self.selected_device.and_then(|i| self.cast_devices.get(i))
     }
 
+    /// Names of the marked devices other than the selected one
+    ///
+    /// Local players play on their own, so they're never cast to alongside.
+    pub fn also_cast_to(&self) -> Vec<String> {
+        if self
+            .selected_cast_device()
+            .is_none_or(|d| PlayerType::from_device_name(&d.name).is_some())
+        {
+            return Vec::new();
+        }
+        self.selected_devices
+            .iter()
+            .filter(|&&i| Some(i) != self.selected_device)
+            .filter_map(|&i| self.cast_devices.get(i))
+            .map(|d| d.name.clone())
+            .collect()
+    }
+
+    /// Mark or unmark the highlighted device for multi-device playback
+    fn toggle_device_mark(&mut self) {
+        let index = self.device_modal_index;
+        let Some(device) = self.cast_devices.get(index) else {
+            return;
+        };
+        if PlayerType::from_device_name(&device.name).is_some() {
+            self.set_error("Local players can't play alongside other devices");
+        } else if let Some(pos) = self.selected_devices.iter().position(|&i| i == index) {
+            self.selected_devices.remove(pos);
+        } else {
+            self.selected_devices.push(index);
+        }
+    }
+
     // -------------------------------------------------------------------------
     // Keyboard Event Handling
     // -------------------------------------------------------------------------
@@ -916,6 +2159,8 @@ impl App {
     pub fn handle_key(&mut self, key: KeyEvent) -> bool {
         // Clear error on any keypress
         self.error = None;
+        self.notice = None;
+        self.magnet_popup = None;
 
         // Global quit shortcut (Ctrl+C or q in normal mode)
         if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
@@ -928,21 +2173,277 @@ impl App {
             return self.handle_device_modal_key(key);
         }
 
+        // Handle genre picker if open
+        if self.show_genre_modal {
+            return self.handle_genre_modal_key(key);
+        }
+
         // Handle settings modal if open
         if self.show_settings_modal {
             return self.handle_settings_modal_key(key);
         }
 
-        // Route to appropriate handler based on mode and state
-        if self.input_mode == InputMode::Editing {
-            self.handle_editing_key(key)
-        } else {
-            self.handle_normal_key(key)
+        // Handle help overlay if open
+        if self.show_help_modal {
+            return self.handle_help_modal_key(key);
         }
-    }
+
+        // Typed volume entry captures all keys until confirmed or cancelled
+        if self.state == AppState::Playing && self.playing.volume_input.is_some() {
+            return self.handle_volume_input_key(key);
+        }
+        if self.state == AppState::Playing && self.playing.seek_input.is_some() {
+            return self.handle_seek_input_key(key);
+        }
+        if self.state == AppState::Playing && self.playing.resume_prompt.is_some() {
+            return self.handle_resume_prompt_key(key);
+        }
+        if self.state == AppState::Playing
+            && self.playing.autoplay.is_some()
+            && key.code == KeyCode::Esc
+        {
+            self.playing.autoplay = None;
+            return true;
+        }
+        if self.state == AppState::Playing
+            && self.playing.downgrade.is_some()
+            && key.code == KeyCode::Esc
+        {
+            self.playing.downgrade = None;
+            self.playing.downgrade_declined = true;
+            return true;
+        }
+        if self.state == AppState::Sources && self.sources.filtering {
+            return self.handle_sources_filter_key(key);
+        }
+        if self.state == AppState::Sources && self.sources.file_idx_input.is_some() {
+            return self.handle_file_idx_key(key);
+        }
+        if self.state == AppState::Subtitles && self.subtitles.path_input.is_some() {
+            return self.handle_subtitle_path_key(key);
+        }
+        if self.state == AppState::Subtitles && self.subtitles.lang_input.is_some() {
+            return self.handle_subtitle_lang_key(key);
+        }
+        if self.state == AppState::Subtitles && self.subtitles.preview.is_some() {
+            return self.handle_subtitle_preview_key(key);
+        }
+
+        // Route to appropriate handler based on mode and state
+        if self.input_mode == InputMode::Editing {
+            self.handle_editing_key(key)
+        } else {
+            self.handle_normal_key(key)
+        }
+    }
+
+    /// Download the current detail's poster if posters are enabled
+    fn request_poster(&mut self) {
+        if self.graphics.is_none() {
+            return;
+        }
+        let Some(poster_path) = self.detail.as_ref().and_then(|d| d.poster_path()) else {
+            return;
+        };
+        if self
+            .poster
+            .as_ref()
+            .is_none_or(|(loaded, _)| loaded != poster_path)
+        {
+            self.send_command(AppCommand::FetchPoster(poster_path.to_string()));
+        }
+    }
+
+    /// Poster file to show right now
+    ///
+    /// Only in the detail view, and never under a modal or error popup,
+    /// since terminal graphics would be drawn on top of them.
+    pub fn current_poster(&self) -> Option<&Path> {
+        let overlay = self.error.is_some()
+            || self.magnet_popup.is_some()
+            || self.show_device_modal
+            || self.show_genre_modal
+            || self.show_settings_modal
+            || self.show_help_modal;
+        if self.graphics.is_none() || overlay || self.state != AppState::Detail {
+            return None;
+        }
+        let (loaded, file) = self.poster.as_ref()?;
+        let wanted = self.detail.as_ref()?.poster_path()?;
+        (loaded == wanted).then_some(file.as_path())
+    }
+
+    /// Record where a list was drawn this frame
+    pub fn record_list(&self, region: ListRegion, area: Rect, offset: usize) {
+        self.rendered_lists.borrow_mut().push(RenderedList {
+            region,
+            area,
+            offset,
+        });
+    }
+
+    /// Handle a mouse event
+    ///
+    /// A left click selects the row under the cursor, a second click on the
+    /// same row activates it (like Enter). The wheel moves the selection in
+    /// the focused list. Returns true if the event was handled.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        let typing = self.input_mode == InputMode::Editing
+            || self.sources.filtering
+            || self.subtitles.path_input.is_some()
+            || self.subtitles.lang_input.is_some();
+        if self.show_device_modal
+            || self.show_genre_modal
+            || self.show_settings_modal
+            || self.show_help_modal
+            || self.subtitles.preview.is_some()
+            || self.sources.file_idx_input.is_some()
+            || self.state == AppState::Playing
+        {
+            return false;
+        }
+
+        match mouse.kind {
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown if !typing => {
+                if self.rendered_lists.borrow().is_empty() {
+                    return false;
+                }
+                let code = if mouse.kind == MouseEventKind::ScrollUp {
+                    KeyCode::Up
+                } else {
+                    KeyCode::Down
+                };
+                self.handle_key(KeyEvent::new(code, KeyModifiers::empty()))
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let hit = self
+                    .rendered_lists
+                    .borrow()
+                    .iter()
+                    .find_map(|l| l.index_at(mouse.column, mouse.row).map(|i| (l.region, i)));
+                let Some((region, index)) = hit else {
+                    return false;
+                };
+                if !self.select_list_row(region, index) {
+                    return false;
+                }
+                self.error = None;
+                self.input_mode = InputMode::Normal;
+                self.sources.filtering = false;
+                self.subtitles.path_input = None;
+                self.subtitles.lang_input = None;
+
+                let now = Instant::now();
+                let double = self.last_click.is_some_and(|(at, r, i)| {
+                    r == region && i == index && now.duration_since(at) <= DOUBLE_CLICK
+                });
+                if double {
+                    self.last_click = None;
+                    self.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+                } else {
+                    self.last_click = Some((now, region, index));
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Select a row in a list (and focus that list); false if out of range
+    fn select_list_row(&mut self, region: ListRegion, index: usize) -> bool {
+        let (list, tv_focus) = match region {
+            ListRegion::Trending => (&mut self.home.list, None),
+            ListRegion::ContinueWatching => (&mut self.home.continue_list, None),
+            ListRegion::Search => (&mut self.search.list, None),
+            ListRegion::Watchlist => (&mut self.watchlist.list, None),
+            ListRegion::Sources => (&mut self.sources.list, None),
+            ListRegion::Subtitles => {
+                // Rows include the language headings; map back to a subtitle
+                let Some(SubtitleListRow::Subtitle(i)) =
+                    self.subtitles.sorted_grouped().get(index).cloned()
+                else {
+                    return false;
+                };
+                self.subtitles.list.selected = i;
+                return true;
+            }
+            ListRegion::Seasons | ListRegion::Episodes => {
+                let Some(DetailState::Tv {
+                    season_list,
+                    episode_list,
+                    focus,
+                    ..
+                }) = &mut self.detail
+                else {
+                    return false;
+                };
+                if region == ListRegion::Seasons {
+                    (season_list, Some((focus, TvFocus::Seasons)))
+                } else {
+                    (episode_list, Some((focus, TvFocus::Episodes)))
+                }
+            }
+        };
+        if index >= list.len {
+            return false;
+        }
+        list.selected = index;
+        if let Some((focus, target)) = tv_focus {
+            *focus = target;
+        }
+        match region {
+            ListRegion::Trending => self.home.continue_focused = false,
+            ListRegion::ContinueWatching => self.home.continue_focused = true,
+            _ => {}
+        }
+        true
+    }
+
+    /// Add a Chromecast by address and select it
+    ///
+    /// An address already in the list is selected rather than added twice.
+    pub fn add_manual_device(&mut self, address: IpAddr) {
+        let idx = match self.cast_devices.iter().position(|d| d.address == address) {
+            Some(idx) => idx,
+            None => {
+                self.cast_devices.push(CastDevice::manual(address));
+                self.cast_devices.len() - 1
+            }
+        };
+        self.selected_device = Some(idx);
+        self.device_modal_index = idx;
+    }
+
+    /// Handle keys while typing an address in the device modal
+    fn handle_device_ip_key(&mut self, key: KeyEvent) -> bool {
+        let input = self.device_ip_input.get_or_insert_with(String::new);
+        match key.code {
+            KeyCode::Char(c)
+                if (c.is_ascii_hexdigit() || c == '.' || c == ':') && input.len() < 39 =>
+            {
+                input.push(c)
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.device_ip_input = None,
+            KeyCode::Enter => {
+                let typed = self.device_ip_input.take().unwrap_or_default();
+                match typed.trim().parse::<IpAddr>() {
+                    Ok(address) => self.add_manual_device(address),
+                    Err(_) => self.set_error(format!("Not an IP address: {}", typed.trim())),
+                }
+            }
+            _ => {}
+        }
+        true
+    }
 
     /// Handle keys when device selection modal is open
     fn handle_device_modal_key(&mut self, key: KeyEvent) -> bool {
+        if self.device_ip_input.is_some() {
+            return self.handle_device_ip_key(key);
+        }
         match key.code {
             KeyCode::Esc | KeyCode::Char('d') => {
                 self.show_device_modal = false;
@@ -954,7 +2455,8 @@ impl App {
                     self.selected_device = Some(self.device_modal_index);
 
                     // Save as default device
-                    let device_name = self.cast_devices
+                    let device_name = self
+                        .cast_devices
                         .get(self.device_modal_index)
                         .map(|d| d.name.clone());
                     self.default_device_name = device_name.clone();
@@ -977,20 +2479,185 @@ impl App {
             }
             KeyCode::Down | KeyCode::Char('j') => {
                 if !self.cast_devices.is_empty() {
-                    self.device_modal_index = (self.device_modal_index + 1) % self.cast_devices.len();
+                    self.device_modal_index =
+                        (self.device_modal_index + 1) % self.cast_devices.len();
                 }
                 true
             }
+            KeyCode::Char(' ') => {
+                self.toggle_device_mark();
+                true
+            }
+            KeyCode::Char('K') => {
+                self.move_device(-1);
+                true
+            }
+            KeyCode::Char('J') => {
+                self.move_device(1);
+                true
+            }
             KeyCode::Char('r') => {
                 // Refresh devices
                 self.send_command(AppCommand::DiscoverDevices);
                 true
             }
+            KeyCode::Char('a') => {
+                // Add a device by IP when discovery can't find it
+                self.device_ip_input = Some(String::new());
+                true
+            }
             _ => true, // Consume all other keys when modal is open
         }
     }
 
+    /// Move the highlighted device up (-1) or down (1) and save the order
+    ///
+    /// Local players always stay first; only cast devices are reordered.
+    fn move_device(&mut self, delta: isize) {
+        let from = self.device_modal_index;
+        let Some(to) = from.checked_add_signed(delta) else {
+            return;
+        };
+        let is_local = |d: &CastDevice| PlayerType::from_device_name(&d.name).is_some();
+        let movable = |i: usize| self.cast_devices.get(i).is_some_and(|d| !is_local(d));
+        if !movable(from) || !movable(to) {
+            return;
+        }
+
+        self.cast_devices.swap(from, to);
+        self.device_modal_index = to;
+        if self.selected_device == Some(from) {
+            self.selected_device = Some(to);
+        } else if self.selected_device == Some(to) {
+            self.selected_device = Some(from);
+        }
+        for mark in &mut self.selected_devices {
+            if *mark == from {
+                *mark = to;
+            } else if *mark == to {
+                *mark = from;
+            }
+        }
+
+        self.device_order = self
+            .cast_devices
+            .iter()
+            .filter(|d| !is_local(d))
+            .map(|d| d.name.clone())
+            .collect();
+        self.save_devices();
+    }
+
+    fn save_devices(&mut self) {
+        self.send_command(AppCommand::SaveDevices {
+            known: self.known_devices.clone(),
+            order: self.device_order.clone(),
+        });
+    }
+
+    /// Genres the picker offers for the selected media type
+    pub fn genre_choices(&self) -> Vec<&'static Genre> {
+        Genre::available_for(self.genre_media_type).collect()
+    }
+
+    /// Handle keys when the genre picker is open
+    fn handle_genre_modal_key(&mut self, key: KeyEvent) -> bool {
+        let count = self.genre_choices().len();
+        match key.code {
+            KeyCode::Esc => {
+                self.show_genre_modal = false;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.genre_modal_index = self.genre_modal_index.checked_sub(1).unwrap_or(count - 1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.genre_modal_index = (self.genre_modal_index + 1) % count;
+            }
+            KeyCode::Tab => {
+                // Keep the same genre selected if the other type has it
+                let current = self.genre_choices()[self.genre_modal_index];
+                self.genre_media_type = match self.genre_media_type {
+                    MediaType::Movie => MediaType::Tv,
+                    MediaType::Tv => MediaType::Movie,
+                };
+                let choices = self.genre_choices();
+                self.genre_modal_index = choices.iter().position(|g| *g == current).unwrap_or(0);
+            }
+            KeyCode::Char('s') => {
+                self.genre_sort = self.genre_sort.toggle();
+            }
+            KeyCode::Enter => {
+                self.show_genre_modal = false;
+                self.discover_selected_genre();
+            }
+            _ => {}
+        }
+        true // Consume all keys when modal is open
+    }
+
+    /// Fetch the picked genre into the search results list
+    fn discover_selected_genre(&mut self) {
+        let media_type = self.genre_media_type;
+        let Some(genre) = self.genre_choices().get(self.genre_modal_index).copied() else {
+            return;
+        };
+        let Some(genre_id) = genre.id_for(media_type) else {
+            return;
+        };
+        let kind = match media_type {
+            MediaType::Movie => "movies",
+            MediaType::Tv => "TV",
+        };
+
+        // Show what's listed in the search box; there's only one page
+        self.search.query = format!("{} {}", genre.name, kind);
+        self.search.cursor = self.search.query.len();
+        self.search.reset_pages();
+        self.search.loading = LoadingState::Loading(Some(format!("Discovering {}...", genre.name)));
+        let generation = self.next_search_generation();
+        self.send_command(AppCommand::Discover {
+            genre_id,
+            media_type,
+            sort: self.genre_sort,
+            generation,
+        });
+        self.navigate(AppState::Search);
+    }
+
     /// Handle keys when settings modal is open
+    /// Handle keys while the help overlay is open (scroll or close)
+    fn handle_help_modal_key(&mut self, key: KeyEvent) -> bool {
+        let actions = self.keymap.actions(&key);
+        if key.code == KeyCode::Esc || actions.contains(&Action::Help) {
+            self.show_help_modal = false;
+            return true;
+        }
+
+        // Keep at least one line on screen; the renderer clamps to the height
+        let last_line = self.help_line_count().saturating_sub(1);
+        let scroll = match key.code {
+            KeyCode::Up => self.help_scroll.saturating_sub(1),
+            KeyCode::Down => self.help_scroll + 1,
+            KeyCode::PageUp => self.help_scroll.saturating_sub(10),
+            KeyCode::PageDown => self.help_scroll + 10,
+            KeyCode::Home => 0,
+            _ if actions.contains(&Action::Up) => self.help_scroll.saturating_sub(1),
+            _ if actions.contains(&Action::Down) => self.help_scroll + 1,
+            _ => return true,
+        };
+        self.help_scroll = scroll.min(last_line);
+        true
+    }
+
+    /// Number of lines the help overlay renders (a header and blank line per section)
+    pub fn help_line_count(&self) -> usize {
+        self.keymap
+            .help_sections()
+            .iter()
+            .map(|s| s.entries.len() + 2)
+            .sum()
+    }
+
     fn handle_settings_modal_key(&mut self, key: KeyEvent) -> bool {
         match key.code {
             KeyCode::Esc | KeyCode::Char('o') => {
@@ -1052,6 +2719,10 @@ impl App {
 
     /// Handle keys in editing (text input) mode
     fn handle_editing_key(&mut self, key: KeyEvent) -> bool {
+        // Typing after a recall edits that query rather than cycling on
+        if !matches!(key.code, KeyCode::Up | KeyCode::Down) {
+            self.search.history_pos = None;
+        }
         match key.code {
             KeyCode::Esc => {
                 self.input_mode = InputMode::Normal;
@@ -1060,10 +2731,13 @@ impl App {
             KeyCode::Enter => {
                 // Submit search
                 self.input_mode = InputMode::Normal;
-                if !self.search.query.is_empty() {
-                    self.search.loading = LoadingState::Loading(Some("Searching...".into()));
-                    self.send_command(AppCommand::Search(self.search.query.clone()));
-                }
+                self.submit_search();
+                true
+            }
+            KeyCode::Up | KeyCode::Down
+                if self.search.query.is_empty() || self.search.history_pos.is_some() =>
+            {
+                self.search.recall(key.code == KeyCode::Up);
                 true
             }
             KeyCode::Char(c) => {
@@ -1100,42 +2774,83 @@ impl App {
 
     /// Handle keys in normal navigation mode
     fn handle_normal_key(&mut self, key: KeyEvent) -> bool {
-        // Global shortcuts
-        match key.code {
-            KeyCode::Char('q') => {
-                self.quit();
-                return true;
-            }
-            KeyCode::Char('/') => {
-                self.focus_search();
-                return true;
-            }
-            // 's' focuses search except in Playing state where it stops playback
-            KeyCode::Char('s') if self.state != AppState::Playing => {
-                self.focus_search();
-                return true;
-            }
-            // 'd' opens device selection modal globally
-            KeyCode::Char('d') => {
-                self.show_device_modal = true;
-                self.device_modal_index = self.selected_device.unwrap_or(0);
-                self.send_command(AppCommand::DiscoverDevices);
-                return true;
-            }
-            // 'o' opens settings modal
-            KeyCode::Char('o') => {
-                self.show_settings_modal = true;
-                self.settings_field_index = 0;
-                self.settings_lang_input = self.default_subtitle_lang.clone();
-                return true;
-            }
-            KeyCode::Esc => {
-                return self.back();
+        // Remappable shortcuts (view-specific actions are listed first, so a
+        // shared key like '/' filters in Sources and searches elsewhere)
+        for action in self.keymap.actions(&key) {
+            match action {
+                Action::Quit => {
+                    self.quit();
+                    return true;
+                }
+                Action::Filter if self.state == AppState::Sources => {
+                    self.sources.filtering = true;
+                    return true;
+                }
+                Action::Stop if self.state == AppState::Playing => {
+                    return self.stop_playback();
+                }
+                Action::Trailer if self.state == AppState::Detail => {
+                    self.open_trailer();
+                    return true;
+                }
+                Action::Search => {
+                    self.focus_search();
+                    return true;
+                }
+                Action::Device => {
+                    self.show_device_modal = true;
+                    self.device_modal_index = self.selected_device.unwrap_or(0);
+                    self.send_command(AppCommand::DiscoverDevices);
+                    return true;
+                }
+                Action::Watchlist if self.state != AppState::Watchlist => {
+                    self.watchlist.list.set_len(self.watchlist.entries.len());
+                    self.navigate(AppState::Watchlist);
+                    return true;
+                }
+                Action::Settings => {
+                    self.show_settings_modal = true;
+                    self.settings_field_index = 0;
+                    self.settings_lang_input = self.default_subtitle_lang.clone();
+                    return true;
+                }
+                Action::Theme => {
+                    self.theme = Theme::new(self.theme.palette.next());
+                    self.send_command(AppCommand::SaveTheme(self.theme.palette));
+                    return true;
+                }
+                Action::Help => {
+                    self.show_help_modal = true;
+                    self.help_scroll = 0;
+                    return true;
+                }
+                Action::Back
+                    if self.state == AppState::Sources && !self.sources.filter.is_empty() =>
+                {
+                    self.sources.clear_filter();
+                    return true;
+                }
+                Action::Back => {
+                    return self.back();
+                }
+                // Up/Down adjust volume while playing, so only lists are remapped
+                Action::Up | Action::Down if self.state != AppState::Playing => {
+                    let code = if action == Action::Up {
+                        KeyCode::Up
+                    } else {
+                        KeyCode::Down
+                    };
+                    return self.handle_view_key(KeyEvent::new(code, KeyModifiers::empty()));
+                }
+                _ => {}
             }
-            _ => {}
         }
 
-        // State-specific handling
+        self.handle_view_key(key)
+    }
+
+    /// Route a key to the handler for the current view
+    fn handle_view_key(&mut self, key: KeyEvent) -> bool {
         match &self.state {
             AppState::Home => self.handle_home_key(key),
             AppState::Search => self.handle_search_key(key),
@@ -1143,17 +2858,37 @@ impl App {
             AppState::Sources => self.handle_sources_key(key),
             AppState::Subtitles => self.handle_subtitles_key(key),
             AppState::Playing => self.handle_playing_key(key),
+            AppState::Watchlist => self.handle_watchlist_key(key),
         }
     }
 
     fn handle_home_key(&mut self, key: KeyEvent) -> bool {
         match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                self.home.list.up();
+            KeyCode::Up => {
+                if self.home.continue_focused {
+                    self.home.continue_list.up();
+                } else if self.home.list.selected == 0 && !self.home.continue_watching.is_empty() {
+                    // Move up from the top of trending into Continue Watching
+                    self.home.continue_focused = true;
+                } else {
+                    self.home.list.up();
+                }
                 true
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                self.home.list.down();
+            KeyCode::Down => {
+                let last_continue =
+                    self.home.continue_list.selected + 1 >= self.home.continue_list.len;
+                if !self.home.continue_focused {
+                    self.home.list.down();
+                } else if last_continue && !self.home.results.is_empty() {
+                    self.home.continue_focused = false;
+                } else {
+                    self.home.continue_list.down();
+                }
+                true
+            }
+            KeyCode::Enter | KeyCode::Char('i') if self.home.continue_focused => {
+                self.resume_continue_watching();
                 true
             }
             KeyCode::Enter | KeyCode::Char('i') => {
@@ -1171,17 +2906,78 @@ impl App {
                 }
                 true
             }
+            KeyCode::Char('f') if !self.home.continue_focused => {
+                if let Some(result) = self.home.selected_result() {
+                    let (id, media_type, title) =
+                        (result.id, result.media_type, result.title.clone());
+                    self.toggle_watchlist(id, media_type, title);
+                }
+                true
+            }
+            KeyCode::Char('g') => {
+                self.show_genre_modal = true;
+                true
+            }
+            KeyCode::Char('m') if !self.home.more.is_empty() => {
+                self.home.show_more();
+                true
+            }
             _ => false,
         }
     }
 
+    /// Jump straight to sources for the selected Continue Watching entry
+    fn resume_continue_watching(&mut self) {
+        let Some(entry) = self.home.selected_continue().cloned() else {
+            return;
+        };
+        self.detail = None;
+        self.sources.title = entry.title.clone();
+        self.fetch_streams(entry.imdb_id.clone(), entry.season, entry.episode, None);
+        self.resume_from = Some(entry);
+        self.navigate(AppState::Sources);
+    }
+
+    /// Search for the current query and remember it
+    fn submit_search(&mut self) {
+        if self.search.query.is_empty() {
+            return;
+        }
+        self.search.reset_pages();
+        self.search.loading = LoadingState::Loading(Some("Searching...".into()));
+        let generation = self.next_search_generation();
+        self.send_command(AppCommand::Search {
+            query: self.search.query.clone(),
+            generation,
+        });
+        self.search.record_history(&self.search.query.clone());
+        self.send_command(AppCommand::SaveSearchHistory(self.search.history.clone()));
+    }
+
     fn handle_search_key(&mut self, key: KeyEvent) -> bool {
+        if self.search.showing_history() {
+            match key.code {
+                KeyCode::Up => self.search.history_list.up(),
+                KeyCode::Down => self.search.history_list.down(),
+                KeyCode::Home => self.search.history_list.first(),
+                KeyCode::End => self.search.history_list.last(),
+                KeyCode::Enter => {
+                    if let Some(query) = self.search.selected_history() {
+                        self.search.query = query.to_string();
+                        self.search.cursor_end();
+                        self.submit_search();
+                    }
+                }
+                _ => return false,
+            }
+            return true;
+        }
         match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+            KeyCode::Up => {
                 self.search.list.up();
                 true
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            KeyCode::Down => {
                 self.search.list.down();
                 true
             }
@@ -1200,6 +2996,18 @@ impl App {
                 }
                 true
             }
+            KeyCode::Char('n') => {
+                if let Some(page) = self.search.next_page() {
+                    self.fetch_search_page(page);
+                }
+                true
+            }
+            KeyCode::Char('p') => {
+                if let Some(page) = self.search.prev_page() {
+                    self.fetch_search_page(page);
+                }
+                true
+            }
             KeyCode::PageUp => {
                 self.search.list.page_up(10);
                 true
@@ -1216,14 +3024,28 @@ impl App {
                 self.search.list.last();
                 true
             }
+            KeyCode::Char('f') => {
+                if let Some(result) = self.search.selected_result() {
+                    let (id, media_type, title) =
+                        (result.id, result.media_type, result.title.clone());
+                    self.toggle_watchlist(id, media_type, title);
+                }
+                true
+            }
             _ => false,
         }
     }
 
     fn handle_detail_key(&mut self, key: KeyEvent) -> bool {
         match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                if let Some(DetailState::Tv { season_list, episode_list, focus, .. }) = &mut self.detail {
+            KeyCode::Up => {
+                if let Some(DetailState::Tv {
+                    season_list,
+                    episode_list,
+                    focus,
+                    ..
+                }) = &mut self.detail
+                {
                     match focus {
                         TvFocus::Seasons => season_list.up(),
                         TvFocus::Episodes => episode_list.up(),
@@ -1231,8 +3053,14 @@ impl App {
                 }
                 true
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if let Some(DetailState::Tv { season_list, episode_list, focus, .. }) = &mut self.detail {
+            KeyCode::Down => {
+                if let Some(DetailState::Tv {
+                    season_list,
+                    episode_list,
+                    focus,
+                    ..
+                }) = &mut self.detail
+                {
                     match focus {
                         TvFocus::Seasons => season_list.down(),
                         TvFocus::Episodes => episode_list.down(),
@@ -1240,7 +3068,11 @@ impl App {
                 }
                 true
             }
-            KeyCode::Tab | KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l') => {
+            KeyCode::Tab
+            | KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::Char('h')
+            | KeyCode::Char('l') => {
                 // Switch between seasons/episodes panel
                 if let Some(DetailState::Tv { focus, .. }) = &mut self.detail {
                     *focus = match focus {
@@ -1253,14 +3085,23 @@ impl App {
             KeyCode::Enter => {
                 // For TV: if on seasons panel, load episodes for selected season
                 // If on episodes panel (or movie), go to sources
-                if let Some(DetailState::Tv { detail, season_list, focus, .. }) = &self.detail {
+                if let Some(DetailState::Tv {
+                    detail,
+                    season_list,
+                    focus,
+                    ..
+                }) = &self.detail
+                {
                     if *focus == TvFocus::Seasons {
                         // Load episodes for selected season
                         let tv_id = detail.id;
                         let selected_season_idx = season_list.selected;
                         if let Some(season) = detail.seasons.get(selected_season_idx) {
                             let season_num = season.season_number;
-                            self.send_command(AppCommand::FetchEpisodes { tv_id, season: season_num });
+                            self.send_command(AppCommand::FetchEpisodes {
+                                tv_id,
+                                season: season_num,
+                            });
                             // Switch focus to episodes
                             if let Some(DetailState::Tv { focus, .. }) = &mut self.detail {
                                 *focus = TvFocus::Episodes;
@@ -1283,82 +3124,362 @@ impl App {
                 if let Some(detail) = &self.detail {
                     let (imdb_id, season, episode) = match detail {
                         DetailState::Movie { detail, .. } => (detail.imdb_id.clone(), None, None),
-                        DetailState::Tv { detail, selected_season, episode_list, episodes, .. } => {
+                        DetailState::Tv {
+                            detail,
+                            selected_season,
+                            episode_list,
+                            episodes,
+                            ..
+                        } => {
                             // Get selected episode number for TV
-                            let season_num = detail.seasons.get(*selected_season as usize)
+                            let season_num = detail
+                                .seasons
+                                .get(*selected_season as usize)
                                 .map(|s| s.season_number as u16);
-                            let episode_num = episodes.get(episode_list.selected)
+                            let episode_num = episodes
+                                .get(episode_list.selected)
                                 .map(|e| e.episode as u16);
                             (detail.imdb_id.clone(), season_num, episode_num)
                         }
                     };
-                    self.subtitles.loading = LoadingState::Loading(Some("Fetching subtitles...".into()));
-                    self.send_command(AppCommand::FetchSubtitles { imdb_id, season, episode, lang: self.subtitles.lang_filter.lang_code().to_string() });
+                    self.fetch_subtitles(imdb_id, season, episode);
                     self.navigate(AppState::Subtitles);
                 }
                 true
             }
-            _ => false,
-        }
-    }
-
-    /// Fetch sources for current selection (movie or TV episode)
-    fn fetch_sources_for_current(&mut self) {
-        if let Some(detail) = &self.detail {
-            let (imdb_id, season, episode, title) = match detail {
-                DetailState::Movie { detail, .. } => {
-                    (detail.imdb_id.clone(), None, None, detail.title.clone())
-                }
-                DetailState::Tv { detail, selected_season, episode_list, episodes, .. } => {
-                    let ep = episodes.get(episode_list.selected);
-                    let ep_num = ep.map(|e| e.episode);
-                    let title = ep.map(|e| format!("{} S{}E{}", detail.name, selected_season, e.episode))
-                        .unwrap_or_else(|| detail.name.clone());
-                    (detail.imdb_id.clone(), Some(*selected_season), ep_num, title)
+            KeyCode::Char('C') => {
+                // Movies list their franchise, shows cast the season
+                if !self.show_collection() {
+                    self.cast_season();
                 }
-            };
-            self.sources.title = title;
-            self.sources.loading = LoadingState::Loading(Some("Fetching streams...".into()));
-            self.send_command(AppCommand::FetchStreams { imdb_id, season, episode });
-            self.navigate(AppState::Sources);
-        }
-    }
-
-    fn handle_sources_key(&mut self, key: KeyEvent) -> bool {
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                self.sources.list.up();
                 true
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                self.sources.list.down();
+            KeyCode::Char('r') => {
+                self.show_recommendations();
                 true
             }
-            KeyCode::Enter | KeyCode::Char('c') => {
-                // Start streaming selected source
-                self.start_playback();
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.step_quality_floor(true);
                 true
             }
-            KeyCode::Char(c @ '1'..='9') => {
-                // Quick select source by number
-                let idx = (c as usize) - ('1' as usize);
-                if idx < self.sources.sources.len() {
-                    self.sources.list.selected = idx;
-                }
+            KeyCode::Char('-') => {
+                self.step_quality_floor(false);
                 true
             }
-            KeyCode::Char('u') => {
-                // Go to subtitles and trigger fetch
-                self.navigate(AppState::Subtitles);
-                // Auto-fetch subtitles if we have an IMDB ID
-                if let Some(imdb_id) = self.get_imdb_id() {
-                    let (season, episode) = self.get_season_episode();
-                    self.subtitles.loading = LoadingState::Loading(Some("Fetching subtitles...".into()));
-                    self.send_command(AppCommand::FetchSubtitles { imdb_id, season, episode, lang: self.subtitles.lang_filter.lang_code().to_string() });
+            KeyCode::Char('f') => {
+                if let Some(detail) = &self.detail {
+                    let (id, media_type, title) = match detail {
+                        DetailState::Movie { detail, .. } => {
+                            (detail.id, MediaType::Movie, detail.title.clone())
+                        }
+                        DetailState::Tv { detail, .. } => {
+                            (detail.id, MediaType::Tv, detail.name.clone())
+                        }
+                    };
+                    self.toggle_watchlist(id, media_type, title);
                 }
                 true
             }
-            KeyCode::Tab => {
+            _ => false,
+        }
+    }
+
+    fn handle_watchlist_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up => {
+                self.watchlist.list.up();
+                true
+            }
+            KeyCode::Down => {
+                self.watchlist.list.down();
+                true
+            }
+            KeyCode::Enter | KeyCode::Char('i') => {
+                // Re-open the saved title
+                if let Some(entry) = self.watchlist.selected_entry() {
+                    let id = entry.id;
+                    match entry.media_type {
+                        MediaType::Movie => self.send_command(AppCommand::FetchMovieDetail(id)),
+                        MediaType::Tv => self.send_command(AppCommand::FetchTvDetail(id)),
+                    }
+                }
+                true
+            }
+            KeyCode::Char('f') | KeyCode::Char('x') | KeyCode::Delete => {
+                if let Some(entry) = self.watchlist.selected_entry() {
+                    let (id, media_type, title) = (entry.id, entry.media_type, entry.title.clone());
+                    self.toggle_watchlist(id, media_type, title);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Star or un-star a title ('f') and persist the watchlist
+    fn toggle_watchlist(&mut self, id: u64, media_type: MediaType, title: String) {
+        let entries = &mut self.watchlist.entries;
+        if let Some(pos) = entries.iter().position(|e| e.matches(id, media_type)) {
+            entries.remove(pos);
+        } else {
+            entries.push(WatchlistEntry::new(id, media_type, title));
+        }
+        self.watchlist.list.set_len(self.watchlist.entries.len());
+        self.send_command(AppCommand::SaveWatchlist(self.watchlist.entries.clone()));
+    }
+
+    /// Fetch another page of results for the current search query
+    fn fetch_search_page(&mut self, page: u32) {
+        if self.search.query.is_empty() {
+            return;
+        }
+        self.search.loading = LoadingState::Loading(Some(format!("Loading page {}...", page)));
+        let generation = self.next_search_generation();
+        self.send_command(AppCommand::SearchPage {
+            query: self.search.query.clone(),
+            page,
+            generation,
+        });
+    }
+
+    /// Start a new search request, making earlier ones stale
+    fn next_search_generation(&mut self) -> u64 {
+        self.search_generation += 1;
+        self.search_generation
+    }
+
+    /// List titles like the one in the detail view, in the search results
+    fn show_recommendations(&mut self) {
+        let Some(detail) = &self.detail else {
+            return;
+        };
+        let (id, media_type, title) = match detail {
+            DetailState::Movie { detail, .. } => {
+                (detail.id, MediaType::Movie, detail.title.clone())
+            }
+            DetailState::Tv { detail, .. } => (detail.id, MediaType::Tv, detail.name.clone()),
+        };
+        self.list_from_detail(
+            format!("More like {}", title),
+            format!("Finding titles like {}...", title),
+            |generation| AppCommand::FetchRecommendations {
+                id,
+                media_type,
+                generation,
+            },
+        );
+    }
+
+    /// List the franchise of the movie in the detail view
+    ///
+    /// Returns false when the movie isn't part of a collection.
+    fn show_collection(&mut self) -> bool {
+        let Some(DetailState::Movie { detail, .. }) = &self.detail else {
+            return false;
+        };
+        let (Some(id), Some(name)) = (detail.collection_id, detail.collection_name.clone()) else {
+            return false;
+        };
+        self.list_from_detail(name.clone(), format!("Loading {}...", name), |generation| {
+            AppCommand::FetchCollection { id, generation }
+        });
+        true
+    }
+
+    /// Show a one-page list in the search results, returning to this detail on back
+    fn list_from_detail(
+        &mut self,
+        label: String,
+        loading: String,
+        command: impl FnOnce(u64) -> AppCommand,
+    ) {
+        let Some(detail) = self.detail.clone() else {
+            return;
+        };
+        self.detail_stack.push(detail);
+
+        // Only one page, like genre browsing
+        self.search.query = label;
+        self.search.cursor = self.search.query.len();
+        self.search.reset_pages();
+        self.search.loading = LoadingState::Loading(Some(loading));
+        let generation = self.next_search_generation();
+        self.send_command(command(generation));
+        self.navigate(AppState::Search);
+    }
+
+    /// Open the trailer for the title in the detail view
+    fn open_trailer(&mut self) {
+        let Some(detail) = &self.detail else {
+            return;
+        };
+        let (id, media_type, title) = match detail {
+            DetailState::Movie { detail, .. } => {
+                (detail.id, MediaType::Movie, detail.title.clone())
+            }
+            DetailState::Tv { detail, .. } => (detail.id, MediaType::Tv, detail.name.clone()),
+        };
+        self.notice = Some(format!("Looking for a {} trailer...", title));
+        self.send_command(AppCommand::OpenTrailer {
+            id,
+            media_type,
+            title,
+        });
+    }
+
+    /// Raise or lower the quality floor for sources (`+`/`-` on the detail view)
+    ///
+    /// Steps through 480p, 720p, 1080p and 4K; lowering past 480p clears it.
+    fn step_quality_floor(&mut self, up: bool) {
+        const LADDER: [Quality; 4] = [
+            Quality::SD480p,
+            Quality::HD720p,
+            Quality::FHD1080p,
+            Quality::UHD4K,
+        ];
+        self.quality_floor = match (self.quality_floor, up) {
+            (None, true) => Some(Quality::SD480p),
+            (None, false) => None,
+            (Some(floor), true) => LADDER.into_iter().find(|q| *q > floor).or(Some(floor)),
+            (Some(floor), false) => LADDER.into_iter().rev().find(|q| *q < floor),
+        };
+    }
+
+    /// Fetch sources for current selection (movie or TV episode)
+    fn fetch_sources_for_current(&mut self) {
+        if let Some(detail) = &self.detail {
+            let (imdb_id, season, episode, absolute, title) = match detail {
+                DetailState::Movie { detail, .. } => (
+                    detail.imdb_id.clone(),
+                    None,
+                    None,
+                    None,
+                    detail.title.clone(),
+                ),
+                DetailState::Tv {
+                    detail,
+                    selected_season,
+                    episode_list,
+                    episodes,
+                    ..
+                } => {
+                    let ep = episodes.get(episode_list.selected);
+                    let ep_num = ep.map(|e| e.episode);
+                    let title = ep
+                        .map(|e| format!("{} S{}E{}", detail.name, selected_season, e.episode))
+                        .unwrap_or_else(|| detail.name.clone());
+                    let absolute =
+                        ep_num.and_then(|e| detail.absolute_episode(*selected_season, e));
+                    (
+                        detail.imdb_id.clone(),
+                        Some(*selected_season),
+                        ep_num,
+                        absolute,
+                        title,
+                    )
+                }
+            };
+            self.sources.title = title;
+            self.sources.quality_floor = self.quality_floor;
+            self.fetch_streams(imdb_id, season, episode, absolute);
+            self.navigate(AppState::Sources);
+        }
+    }
+
+    /// Fetch streams, remembering what for so `r` can ask again
+    fn fetch_streams(
+        &mut self,
+        imdb_id: String,
+        season: Option<u8>,
+        episode: Option<u8>,
+        absolute: Option<u16>,
+    ) {
+        self.sources.imdb_id = Some(imdb_id.clone());
+        self.sources.season = season;
+        self.sources.episode = episode;
+        self.sources.absolute = absolute;
+        self.sources.loading = LoadingState::Loading(Some("Fetching streams...".into()));
+        self.send_command(AppCommand::FetchStreams {
+            imdb_id,
+            season,
+            episode,
+            absolute,
+        });
+    }
+
+    /// Fetch the current sources again (`r`)
+    fn refresh_sources(&mut self) {
+        let Some(imdb_id) = self.sources.imdb_id.clone() else {
+            self.set_error("Nothing to refresh here");
+            return;
+        };
+        let (season, episode, absolute) = (
+            self.sources.season,
+            self.sources.episode,
+            self.sources.absolute,
+        );
+        self.fetch_streams(imdb_id, season, episode, absolute);
+    }
+
+    fn handle_sources_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up => {
+                self.sources.list.up();
+                true
+            }
+            KeyCode::Down => {
+                self.sources.list.down();
+                true
+            }
+            KeyCode::Enter | KeyCode::Char('c') => {
+                // Start streaming selected source
+                self.start_playback();
+                true
+            }
+            KeyCode::Char('S') => {
+                // Cycle sort order: seeds -> quality -> size
+                let sort = self.sources.sort.map(SourceSort::next).unwrap_or_default();
+                self.sources.sort_by(sort);
+                true
+            }
+            KeyCode::Char('h') => {
+                self.sources.toggle_seed_floor(self.min_seeds);
+                true
+            }
+            KeyCode::Char('Q') => {
+                match self.sources.quality_floor.or(self.quality_floor) {
+                    Some(quality) => self.sources.toggle_quality_floor(quality),
+                    None => self.set_error("No quality floor set (+/- on the detail screen)"),
+                }
+                true
+            }
+            KeyCode::Char('y') => {
+                self.copy_magnet();
+                true
+            }
+            KeyCode::Char('r') => {
+                self.refresh_sources();
+                true
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                // Quick select source by number
+                let idx = (c as usize) - ('1' as usize);
+                if idx < self.sources.list.len {
+                    self.sources.list.selected = idx;
+                }
+                true
+            }
+            KeyCode::Char('u') => {
+                // Go to subtitles and trigger fetch
+                self.navigate(AppState::Subtitles);
+                // Auto-fetch subtitles if we have an IMDB ID
+                if let Some(imdb_id) = self.get_imdb_id() {
+                    let (season, episode) = self.get_season_episode();
+                    self.fetch_subtitles(imdb_id, season, episode);
+                }
+                true
+            }
+            KeyCode::Tab => {
                 // Cycle to next device
                 if !self.cast_devices.is_empty() {
                     let current = self.selected_device.unwrap_or(0);
@@ -1379,107 +3500,470 @@ impl App {
         }
     }
 
+    /// Handle keys while typing a sources filter
+    fn handle_sources_filter_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(c) => {
+                let mut filter = self.sources.filter.clone();
+                filter.push(c);
+                self.sources.set_filter(filter);
+            }
+            KeyCode::Backspace => {
+                let mut filter = self.sources.filter.clone();
+                filter.pop();
+                self.sources.set_filter(filter);
+            }
+            KeyCode::Enter | KeyCode::Down | KeyCode::Up => {
+                // Keep the filter, hand focus back to the list
+                self.sources.filtering = false;
+            }
+            KeyCode::Esc => self.sources.clear_filter(),
+            _ => {}
+        }
+        true
+    }
+
     /// Start playback of selected source on selected device
+    ///
+    /// Season packs first ask which file to play, prefilled with the
+    /// addon's pick.
     fn start_playback(&mut self) {
-        // Check we have a device selected
-        let device = match self.selected_device {
-            Some(idx) if idx < self.cast_devices.len() => &self.cast_devices[idx],
-            _ => {
-                self.set_error("No Chromecast device selected. Press 'd' to discover devices.");
-                return;
+        if self.selected_cast_device().is_none() {
+            self.set_error(NO_DEVICE);
+            return;
+        }
+        match self.sources.selected_source() {
+            Some(source) if source.is_pack() => {
+                self.sources.file_idx_input =
+                    Some(source.file_idx.map(|i| i.to_string()).unwrap_or_default());
             }
-        };
+            Some(source) => {
+                let source = source.clone();
+                self.play_source(source);
+            }
+            None => self.set_error("No stream source selected."),
+        }
+    }
 
-        // Check we have a source selected
-        let source = match self.sources.selected_source() {
-            Some(s) => s.clone(),
-            None => {
-                self.set_error("No stream source selected.");
-                return;
+    /// Handle keys in the season pack file picker
+    fn handle_file_idx_key(&mut self, key: KeyEvent) -> bool {
+        let input = self.sources.file_idx_input.get_or_insert_with(String::new);
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() && input.len() < 4 => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
             }
-        };
+            KeyCode::Esc => self.sources.file_idx_input = None,
+            KeyCode::Enter => {
+                let typed = self.sources.file_idx_input.take().unwrap_or_default();
+                let Ok(file_idx) = typed.parse::<u32>() else {
+                    self.set_error("Enter the file index to play from the pack.");
+                    return true;
+                };
+                if let Some(source) = self.sources.selected_source() {
+                    let mut source = source.clone();
+                    source.file_idx = Some(file_idx);
+                    self.play_source(source);
+                }
+            }
+            _ => {}
+        }
+        true
+    }
 
-        // Generate magnet link
-        let magnet = source.to_magnet(&self.sources.title);
+    /// Cast `source` to the selected device
+    fn play_source(&mut self, source: StreamSource) {
+        let Some(device) = self.selected_cast_device().cloned() else {
+            self.set_error(NO_DEVICE);
+            return;
+        };
 
         // Get subtitle URL if selected
         let subtitle_url = self.subtitles.selected.as_ref().map(|s| s.url.clone());
 
         // Set up playing state
-        self.playing.title = self.sources.title.clone();
+        let title = self.sources.title.clone();
+        let magnet = self.prepare_playing(&source, &title, &device);
+        self.playing.imdb_id = self.get_imdb_id();
+        (self.playing.season, self.playing.episode) = self.current_episode();
+        // Picking a source by hand replaces any season queue
+        self.playing.playback_queue.clear();
+
+        let command = AppCommand::StartPlayback {
+            magnet,
+            title: self.sources.title.clone(),
+            device: device.name.clone(),
+            subtitle_url,
+            file_idx: source.file_idx,
+            direct_url: source.direct_url.clone(),
+            start: None,
+            also: self.also_cast_to(),
+            preflight: preflight(&device),
+        };
+
+        // Offer to resume a saved position before starting
+        let saved = self
+            .playing
+            .info_hash
+            .as_ref()
+            .filter(|_| self.enable_resume)
+            .and_then(|h| self.resume.get(h))
+            .map(|e| e.position)
+            .filter(|&p| p > 0);
+        if let Some(position) = saved {
+            self.playing.resume_prompt = Some(position);
+            self.playing.pending_start = Some(command);
+        } else {
+            self.send_command(command);
+        }
+
+        // Navigate to Playing state
+        self.navigate(AppState::Playing);
+    }
+
+    /// Copy the selected source's magnet link to the clipboard
+    fn copy_magnet(&mut self) {
+        let Some(source) = self.sources.selected_source() else {
+            return;
+        };
+        if source.normalized_hash().is_none() {
+            self.set_error("This source has no magnet link");
+            return;
+        }
+        let magnet = source.to_magnet_with_trackers(&self.sources.title, &self.trackers);
+        self.send_command(AppCommand::CopyMagnet(magnet));
+    }
+
+    /// Reset the playing state for a new source, returning its magnet link
+    fn prepare_playing(
+        &mut self,
+        source: &StreamSource,
+        title: &str,
+        device: &CastDevice,
+    ) -> String {
+        let magnet = source.to_magnet_with_trackers(title, &self.trackers);
+        self.playing.title = title.to_string();
         self.playing.device = Some(device.clone());
-        self.playing.torrent = Some(TorrentSession::new(magnet.clone(), source.file_idx));
+        let mut torrent = TorrentSession::new(magnet.clone(), source.file_idx);
+        // Torrentio's size stands in until webtorrent reports one
+        torrent.total_size = source.size_bytes.unwrap_or(0);
+        self.playing.torrent = Some(torrent);
         self.playing.magnet = Some(magnet.clone()); // Store for subtitle restart
         self.playing.pending_subtitle_url = None;
+        self.playing.info_hash = Some(source.info_hash.to_lowercase()).filter(|h| !h.is_empty());
+        self.playing.playback = None;
+        self.playing.autoplay = None;
+        self.playing.resume_saved_at = 0;
+        self.playing.subtitle_offset_ms = 0;
+        self.playing.stall = None;
+        self.playing.downgrade = None;
+        self.playing.downgrade_declined = false;
+        magnet
+    }
+
+    /// Queue every episode of the selected season for casting
+    fn cast_season(&mut self) {
+        let Some(DetailState::Tv {
+            detail,
+            selected_season,
+            episodes,
+            ..
+        }) = &self.detail
+        else {
+            return;
+        };
+        if episodes.is_empty() {
+            return;
+        }
+        let command = AppCommand::CastSeason {
+            imdb_id: detail.imdb_id.clone(),
+            title: detail.name.clone(),
+            season: *selected_season,
+            episodes: episodes.iter().map(|e| e.episode).collect(),
+        };
+        let title = format!("{} season {}", detail.name, selected_season);
+        let count = episodes.len();
+
+        match self.selected_device.and_then(|i| self.cast_devices.get(i)) {
+            None => {
+                self.set_error("No Chromecast device selected. Press 'd' to discover devices.");
+                return;
+            }
+            // Advancing relies on the Chromecast reporting when an episode ends
+            Some(device) if PlayerType::from_device_name(&device.name).is_some() => {
+                self.set_error("Season casting needs a Chromecast device.");
+                return;
+            }
+            Some(_) => {}
+        }
+
+        self.sources = SourcesState::new(title);
+        self.sources.loading =
+            LoadingState::Loading(Some(format!("Finding streams for {} episodes...", count)));
+        self.send_command(command);
+        self.navigate(AppState::Sources);
+    }
+
+    /// Start the next episode of the season queue
+    fn play_next_queued(&mut self) {
+        if self.playing.playback_queue.is_empty() {
+            return;
+        }
+        let Some(device) = self
+            .selected_device
+            .and_then(|i| self.cast_devices.get(i))
+            .cloned()
+        else {
+            self.playing.playback_queue.clear();
+            self.set_error("No Chromecast device selected. Press 'd' to discover devices.");
+            return;
+        };
+        let item = self.playing.playback_queue.remove(0);
+
+        let magnet = self.prepare_playing(&item.source, &item.title, &device);
+        self.playing.imdb_id = Some(item.imdb_id.clone());
+        self.playing.season = Some(item.season);
+        self.playing.episode = Some(item.episode);
 
-        // Send command to start playback
         self.send_command(AppCommand::StartPlayback {
             magnet,
-            title: self.sources.title.clone(),
+            title: item.title.clone(),
             device: device.name.clone(),
-            subtitle_url,
-            file_idx: source.file_idx,
+            subtitle_url: None,
+            file_idx: item.source.file_idx,
+            direct_url: item.source.direct_url.clone(),
+            start: None,
+            also: self.also_cast_to(),
+            preflight: preflight(&device),
         });
-
-        // Navigate to Playing state
         self.navigate(AppState::Playing);
     }
 
+    /// Start the countdown to the episode after the one that just ended
+    fn schedule_autoplay(&mut self) {
+        if !self.autoplay_next || self.playing.autoplay.is_some() {
+            return;
+        }
+        let (Some(season), Some(episode)) = (self.playing.season, self.playing.episode) else {
+            return;
+        };
+        // Season episode counts come from the show's detail
+        let Some(DetailState::Tv { detail, .. }) = &self.detail else {
+            return;
+        };
+        if self.playing.imdb_id.as_deref() != Some(detail.imdb_id.as_str()) {
+            return;
+        }
+        if let Some((season, episode)) = detail.next_episode(season, episode) {
+            self.playing.autoplay = Some(Autoplay {
+                season,
+                episode,
+                starts_at: Instant::now() + AUTOPLAY_DELAY,
+            });
+        }
+    }
+
+    /// Countdown finished: find a stream for the next episode and play it
+    fn start_autoplay(&mut self) {
+        let Some(next) = self.playing.autoplay.take() else {
+            return;
+        };
+        let Some(DetailState::Tv { detail, .. }) = &self.detail else {
+            return;
+        };
+        self.send_command(AppCommand::CastSeason {
+            imdb_id: detail.imdb_id.clone(),
+            title: detail.name.clone(),
+            season: next.season,
+            episodes: vec![next.episode],
+        });
+    }
+
+    /// Offer a lower quality once buffering has stood still for `stall_after`
+    fn watch_buffering(&mut self) {
+        if !self.auto_downgrade
+            || self.state != AppState::Playing
+            || self.playing.downgrade.is_some()
+            || self.playing.downgrade_declined
+        {
+            return;
+        }
+        let Some(session) = self.playing.torrent.as_ref() else {
+            return;
+        };
+        if !matches!(session.state, TorrentState::Buffering { .. }) {
+            self.playing.stall = None;
+            return;
+        }
+
+        let (progress, downloaded) = (session.progress, session.downloaded);
+        let now = Instant::now();
+        let stalled_since = match self.playing.stall {
+            Some(watch) if watch.progress == progress && watch.downloaded == downloaded => {
+                watch.since
+            }
+            _ => {
+                self.playing.stall = Some(StallWatch {
+                    progress,
+                    downloaded,
+                    since: now,
+                });
+                return;
+            }
+        };
+        if now.duration_since(stalled_since) < self.stall_after {
+            return;
+        }
+
+        match self.lower_quality_source() {
+            Some(source) => {
+                self.playing.downgrade = Some(Downgrade {
+                    source,
+                    starts_at: now + DOWNGRADE_DELAY,
+                });
+            }
+            // Already at the bottom of the ladder
+            None => self.playing.downgrade_declined = true,
+        }
+    }
+
+    /// Best source one quality step below the playing one, for the same title
+    ///
+    /// The playing torrent must be in the sources list, which rules out
+    /// queued episodes whose sources were never listed.
+    fn lower_quality_source(&self) -> Option<StreamSource> {
+        let hash = self.playing.info_hash.as_deref()?;
+        let current = self
+            .sources
+            .sources
+            .iter()
+            .find(|s| s.info_hash.eq_ignore_ascii_case(hash))?;
+        let rank = current.quality.rank();
+        self.sources
+            .sources
+            .iter()
+            .filter(|s| s.quality.rank() > 0 && s.quality.rank() < rank)
+            .max_by_key(|s| (s.quality.rank(), s.seeds))
+            .cloned()
+    }
+
+    /// Countdown finished: restart with the lower-quality source
+    fn start_downgrade(&mut self) {
+        if let Some(downgrade) = self.playing.downgrade.take() {
+            self.play_source(downgrade.source);
+        }
+    }
+
     /// Get IMDB ID from current detail
     fn get_imdb_id(&self) -> Option<String> {
-        self.detail.as_ref().map(|d| match d {
-            DetailState::Movie { detail, .. } => detail.imdb_id.clone(),
-            DetailState::Tv { detail, .. } => detail.imdb_id.clone(),
-        })
+        match &self.detail {
+            Some(DetailState::Movie { detail, .. }) => Some(detail.imdb_id.clone()),
+            Some(DetailState::Tv { detail, .. }) => Some(detail.imdb_id.clone()),
+            None => self.resume_from.as_ref().map(|e| e.imdb_id.clone()),
+        }
+    }
+
+    /// Season/episode numbers streams were fetched for (None for movies)
+    fn current_episode(&self) -> (Option<u8>, Option<u8>) {
+        match &self.detail {
+            Some(DetailState::Tv {
+                selected_season,
+                episode_list,
+                episodes,
+                ..
+            }) => (
+                Some(*selected_season),
+                episodes.get(episode_list.selected).map(|e| e.episode),
+            ),
+            Some(DetailState::Movie { .. }) => (None, None),
+            None => self
+                .resume_from
+                .as_ref()
+                .map(|e| (e.season, e.episode))
+                .unwrap_or_default(),
+        }
     }
 
     /// Get season/episode from current TV detail (if applicable)
     fn get_season_episode(&self) -> (Option<u16>, Option<u16>) {
         match &self.detail {
-            Some(DetailState::Tv { detail, selected_season, episode_list, episodes, .. }) => {
-                let season_num = detail.seasons.get(*selected_season as usize)
+            Some(DetailState::Tv {
+                detail,
+                selected_season,
+                episode_list,
+                episodes,
+                ..
+            }) => {
+                let season_num = detail
+                    .seasons
+                    .get(*selected_season as usize)
                     .map(|s| s.season_number as u16);
-                let episode_num = episodes.get(episode_list.selected)
+                let episode_num = episodes
+                    .get(episode_list.selected)
                     .map(|e| e.episode as u16);
                 (season_num, episode_num)
             }
-            _ => (None, None),
+            Some(DetailState::Movie { .. }) => (None, None),
+            None => {
+                let (season, episode) = self.current_episode();
+                (season.map(u16::from), episode.map(u16::from))
+            }
         }
     }
 
     fn handle_subtitles_key(&mut self, key: KeyEvent) -> bool {
         match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+            KeyCode::Up => {
                 self.subtitles.list.up();
                 true
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            KeyCode::Down => {
                 self.subtitles.list.down();
                 true
             }
             KeyCode::Tab => {
                 // Toggle language filter and refetch
-                self.subtitles.lang_filter = self.subtitles.lang_filter.next();
-                if let Some(imdb_id) = self.get_imdb_id() {
-                    let (season, episode) = self.get_season_episode();
-                    self.subtitles.loading = LoadingState::Loading(Some("Fetching subtitles...".into()));
-                    let lang = self.subtitles.lang_filter.lang_code().to_string();
-                    self.send_command(AppCommand::FetchSubtitles { imdb_id, season, episode, lang });
-                }
+                self.subtitles.lang_filter = self.subtitles.next_filter();
+                self.refetch_subtitles();
+                true
+            }
+            KeyCode::Char('c') => {
+                // Type any language code(s), e.g. "fre" or "fre,jpn"
+                self.subtitles.lang_input =
+                    Some(self.subtitles.custom_lang.clone().unwrap_or_default());
                 true
             }
             KeyCode::Enter => {
                 // Select subtitle (clone first to avoid borrow issues)
                 if let Some(sub) = self.subtitles.selected_subtitle().cloned() {
-                    // If coming from Playing state, set pending subtitle for restart
-                    if self.nav_stack.last() == Some(&AppState::Playing) {
-                        self.playing.pending_subtitle_url = Some(sub.url.clone());
-                    }
-                    self.subtitles.selected = Some(sub);
+                    self.use_subtitle(sub);
                 }
                 self.back();
                 true
             }
+            KeyCode::Char(' ') => {
+                // Preview the first cues before picking this subtitle
+                if let Some(sub) = self.subtitles.selected_subtitle() {
+                    let url = sub.url.clone();
+                    self.subtitles.preview = Some(SubtitlePreview {
+                        url: url.clone(),
+                        release: sub.release.clone(),
+                        loading: LoadingState::Loading(Some("Downloading subtitle...".into())),
+                        ..Default::default()
+                    });
+                    self.send_command(AppCommand::PreviewSubtitle { url });
+                }
+                true
+            }
+            KeyCode::Char('l') => {
+                // Type a path to a subtitle file on disk
+                self.subtitles.path_input = Some(String::new());
+                true
+            }
+            KeyCode::Char('r') => {
+                self.refresh_subtitles();
+                true
+            }
             KeyCode::Char('n') => {
                 // No subtitles
                 self.subtitles.selected = None;
@@ -1490,6 +3974,154 @@ impl App {
         }
     }
 
+    /// Handle keys while the subtitle preview modal is open
+    fn handle_subtitle_preview_key(&mut self, key: KeyEvent) -> bool {
+        let Some(preview) = self.subtitles.preview.as_mut() else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Esc | KeyCode::Char(' ') | KeyCode::Char('q') => self.subtitles.preview = None,
+            KeyCode::Up | KeyCode::Char('k') => preview.scroll = preview.scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') if preview.scroll + 1 < preview.cues.len() => {
+                preview.scroll += 1
+            }
+            KeyCode::Enter => {
+                // Pick the previewed subtitle straight from the modal
+                self.subtitles.preview = None;
+                if let Some(sub) = self.subtitles.selected_subtitle().cloned() {
+                    self.use_subtitle(sub);
+                }
+                self.back();
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Search subtitles again with the current language filter
+    fn refetch_subtitles(&mut self) {
+        if let Some(imdb_id) = self.get_imdb_id() {
+            let (season, episode) = self.get_season_episode();
+            self.fetch_subtitles(imdb_id, season, episode);
+        }
+    }
+
+    /// Fetch subtitles in the filter's languages, remembering what for so `r` can ask again
+    fn fetch_subtitles(&mut self, imdb_id: String, season: Option<u16>, episode: Option<u16>) {
+        self.subtitles.imdb_id = Some(imdb_id.clone());
+        self.subtitles.season = season;
+        self.subtitles.episode = episode;
+        self.subtitles.loading = LoadingState::Loading(Some("Fetching subtitles...".into()));
+        let lang = self.subtitles.lang_code();
+        self.send_command(AppCommand::FetchSubtitles {
+            imdb_id,
+            season,
+            episode,
+            lang,
+        });
+    }
+
+    /// Fetch the current subtitles again (`r`)
+    fn refresh_subtitles(&mut self) {
+        let Some(imdb_id) = self.subtitles.imdb_id.clone() else {
+            self.set_error("Nothing to refresh here");
+            return;
+        };
+        let (season, episode) = (self.subtitles.season, self.subtitles.episode);
+        self.fetch_subtitles(imdb_id, season, episode);
+    }
+
+    /// Handle keys while typing custom language codes in Subtitles
+    fn handle_subtitle_lang_key(&mut self, key: KeyEvent) -> bool {
+        let input = self.subtitles.lang_input.get_or_insert_with(String::new);
+        match key.code {
+            KeyCode::Char(c)
+                if (c.is_ascii_alphabetic() || matches!(c, ',' | '-' | ' '))
+                    && input.len() < 40 =>
+            {
+                input.push(c)
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.subtitles.lang_input = None,
+            KeyCode::Enter => {
+                let typed = self.subtitles.lang_input.take().unwrap_or_default();
+                let Some(codes) = normalize_lang_list(&typed) else {
+                    self.set_error("Type a language code, e.g. fre or fre,jpn");
+                    return true;
+                };
+                self.subtitles.custom_lang = Some(codes.clone());
+                self.subtitles.lang_filter = SubLangFilter::Custom;
+                self.send_command(AppCommand::SaveSubtitleLang(codes));
+                self.refetch_subtitles();
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Handle keys while typing a local subtitle path in Subtitles
+    fn handle_subtitle_path_key(&mut self, key: KeyEvent) -> bool {
+        let input = self.subtitles.path_input.get_or_insert_with(String::new);
+        match key.code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.subtitles.path_input = None,
+            KeyCode::Enter => {
+                let typed = self.subtitles.path_input.take().unwrap_or_default();
+                let typed = typed.trim();
+                let path = match typed.strip_prefix("~/") {
+                    Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+                    None => std::path::PathBuf::from(typed),
+                };
+                if path.is_file() {
+                    self.use_subtitle(SubtitleResult::local_file(&path));
+                    self.back();
+                } else {
+                    self.set_error(format!("Subtitle file not found: {}", path.display()));
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Make `sub` the active subtitle, restarting playback if we came from Playing
+    fn use_subtitle(&mut self, sub: SubtitleResult) {
+        if self.nav_stack.last() == Some(&AppState::Playing) {
+            self.playing.pending_subtitle_url = Some(sub.url.clone());
+            self.playing.subtitle_offset_ms = 0;
+        }
+        self.subtitles.selected = Some(sub);
+    }
+
+    /// Stop playback (the `stop` action, `s` by default)
+    fn stop_playback(&mut self) -> bool {
+        if self.playing.device.is_none() {
+            return false;
+        }
+        self.send_command(AppCommand::StopPlayback {
+            info_hash: self.playing.info_hash.clone(),
+        });
+        // Update local state
+        if let Some(ref mut playback) = self.playing.playback {
+            playback.state = CastState::Stopped;
+        }
+        true
+    }
+
+    /// Seconds an arrow-key seek jumps (shift for a larger jump)
+    fn seek_jump(&self, modifiers: KeyModifiers) -> u32 {
+        if modifiers.contains(KeyModifiers::SHIFT) {
+            self.seek_step.saturating_mul(SHIFT_SEEK_MULTIPLIER)
+        } else {
+            self.seek_step
+        }
+    }
+
     fn handle_playing_key(&mut self, key: KeyEvent) -> bool {
         // Get device name for commands
         let device_name = match &self.playing.device {
@@ -1503,6 +4135,7 @@ impl App {
                 self.send_command(AppCommand::PlaybackControl {
                     action: "play_toggle".into(),
                     device: device_name,
+                    value: None,
                 });
                 // Also update local state
                 if let Some(ref mut playback) = self.playing.playback {
@@ -1514,28 +4147,21 @@ impl App {
                 }
                 true
             }
-            KeyCode::Char('s') => {
-                // Stop playback
-                self.send_command(AppCommand::StopPlayback);
-                // Update local state
-                if let Some(ref mut playback) = self.playing.playback {
-                    playback.state = CastState::Stopped;
-                }
-                true
-            }
             KeyCode::Left => {
-                // Seek backward 30 seconds
+                // Seek backward one step (shift: five)
                 self.send_command(AppCommand::PlaybackControl {
                     action: "rewind".into(),
                     device: device_name,
+                    value: Some(self.seek_jump(key.modifiers)),
                 });
                 true
             }
             KeyCode::Right => {
-                // Seek forward 30 seconds
+                // Seek forward one step (shift: five)
                 self.send_command(AppCommand::PlaybackControl {
                     action: "ffwd".into(),
                     device: device_name,
+                    value: Some(self.seek_jump(key.modifiers)),
                 });
                 true
             }
@@ -1544,6 +4170,7 @@ impl App {
                 self.send_command(AppCommand::PlaybackControl {
                     action: "volumeup".into(),
                     device: device_name,
+                    value: None,
                 });
                 if let Some(ref mut playback) = self.playing.playback {
                     playback.volume = (playback.volume + 0.1).min(1.0);
@@ -1555,23 +4182,38 @@ impl App {
                 self.send_command(AppCommand::PlaybackControl {
                     action: "volumedown".into(),
                     device: device_name,
+                    value: None,
                 });
                 if let Some(ref mut playback) = self.playing.playback {
                     playback.volume = (playback.volume - 0.1).max(0.0);
                 }
                 true
             }
+            KeyCode::Char('[') => {
+                // Subtitles 0.5s earlier
+                self.shift_subtitles(-500);
+                true
+            }
+            KeyCode::Char(']') => {
+                // Subtitles 0.5s later
+                self.shift_subtitles(500);
+                true
+            }
+            KeyCode::Char('g') => {
+                // Start typing a timestamp to seek to
+                self.playing.seek_input = Some(String::new());
+                true
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                // Start typing an exact volume percentage
+                self.playing.volume_input = Some(c.to_string());
+                true
+            }
             KeyCode::Char('u') => {
                 // Open subtitle selector
                 if let Some(imdb_id) = self.get_imdb_id() {
                     let (season, episode) = self.get_season_episode();
-                    self.subtitles.loading = LoadingState::Loading(Some("Fetching subtitles...".into()));
-                    self.send_command(AppCommand::FetchSubtitles {
-                        imdb_id,
-                        season,
-                        episode,
-                        lang: self.subtitles.lang_filter.lang_code().to_string()
-                    });
+                    self.fetch_subtitles(imdb_id, season, episode);
                     self.navigate(AppState::Subtitles);
                 }
                 true
@@ -1579,6 +4221,138 @@ impl App {
             _ => false,
         }
     }
+
+    /// Handle keys while typing a volume percentage in Playing
+    fn handle_volume_input_key(&mut self, key: KeyEvent) -> bool {
+        let input = self.playing.volume_input.get_or_insert_with(String::new);
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() && input.len() < 3 => {
+                input.push(c);
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => {
+                self.playing.volume_input = None;
+            }
+            KeyCode::Enter => {
+                let typed = self.playing.volume_input.take().unwrap_or_default();
+                if let (Ok(level), Some(device)) = (typed.parse::<u32>(), &self.playing.device) {
+                    let level = level.min(100);
+                    let device = device.name.clone();
+                    self.send_command(AppCommand::PlaybackControl {
+                        action: "volume_set".into(),
+                        device,
+                        value: Some(level),
+                    });
+                    if let Some(ref mut playback) = self.playing.playback {
+                        playback.volume = level as f32 / 100.0;
+                    }
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Handle the "Resume at 42:10? (y/n)" prompt in Playing
+    fn handle_resume_prompt_key(&mut self, key: KeyEvent) -> bool {
+        let resume = match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => true,
+            KeyCode::Char('n') | KeyCode::Esc => false,
+            _ => return true,
+        };
+        let position = self.playing.resume_prompt.take();
+        if let Some(mut command) = self.playing.pending_start.take() {
+            if let AppCommand::StartPlayback { ref mut start, .. } = command {
+                *start = position.filter(|_| resume);
+            }
+            self.send_command(command);
+        }
+        true
+    }
+
+    /// Update the resume table from a polled playback status
+    ///
+    /// Positions are persisted every 10s of progress; finishing (>95%) clears the entry.
+    fn record_resume_position(&mut self, status: &PlaybackStatus) {
+        if !self.enable_resume {
+            return;
+        }
+        let Some(info_hash) = self.playing.info_hash.clone() else {
+            return;
+        };
+
+        let position = status.position.as_secs();
+        let duration = status.duration.as_secs();
+        let finished = duration > 0 && position * 100 >= duration * 95;
+        let position = if finished { 0 } else { position };
+
+        if !finished && position.abs_diff(self.playing.resume_saved_at) < 10 {
+            return;
+        }
+        self.playing.resume_saved_at = position;
+
+        let entry = ResumeEntry {
+            position,
+            imdb_id: self.playing.imdb_id.clone(),
+            title: self.playing.title.clone(),
+            file_idx: self.playing.torrent.as_ref().and_then(|t| t.file_idx),
+            updated_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            duration,
+            season: self.playing.season,
+            episode: self.playing.episode,
+        };
+        if position == 0 {
+            self.resume.remove(&info_hash);
+        } else {
+            self.resume.insert(info_hash.clone(), entry.clone());
+        }
+        self.send_command(AppCommand::SaveResume { info_hash, entry });
+    }
+
+    /// Handle keys while typing a seek timestamp (HH:MM:SS) in Playing
+    fn handle_seek_input_key(&mut self, key: KeyEvent) -> bool {
+        let input = self.playing.seek_input.get_or_insert_with(String::new);
+        match key.code {
+            KeyCode::Char(c) if (c.is_ascii_digit() || c == ':') && input.len() < 8 => {
+                input.push(c);
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => {
+                self.playing.seek_input = None;
+            }
+            KeyCode::Enter => {
+                let typed = self.playing.seek_input.take().unwrap_or_default();
+                match crate::cli::parse_timestamp(&typed) {
+                    Some(secs) => {
+                        if let Some(device) = &self.playing.device {
+                            let device = device.name.clone();
+                            self.send_command(AppCommand::PlaybackControl {
+                                action: "seek_to".into(),
+                                device,
+                                value: Some(secs as u32),
+                            });
+                        }
+                        if let Some(ref mut playback) = self.playing.playback {
+                            playback.position = std::time::Duration::from_secs(secs);
+                        }
+                    }
+                    None if !typed.is_empty() => {
+                        self.error = Some(format!("Invalid timestamp: {}", typed));
+                    }
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+        true
+    }
 }
 
 // =============================================================================
@@ -1634,58 +4408,517 @@ mod tests {
         let mut list = ListState::new(10);
         list.selected = 8;
 
-        // Shrinking should clamp selection
-        list.set_len(5);
-        assert_eq!(list.selected, 4);
+        // Shrinking should clamp selection
+        list.set_len(5);
+        assert_eq!(list.selected, 4);
+
+        // Growing shouldn't change selection
+        list.set_len(10);
+        assert_eq!(list.selected, 4);
+    }
+
+    #[test]
+    fn test_list_state_scroll_into_view() {
+        let mut list = ListState::new(100);
+        list.selected = 40;
+
+        // Selection past the viewport scrolls it to the last row
+        list.scroll_into_view(10);
+        assert_eq!(list.offset, 31);
+        assert!((list.offset..list.offset + 10).contains(&list.selected));
+
+        // Moving within the window keeps it still
+        list.up();
+        list.scroll_into_view(10);
+        assert_eq!(list.offset, 31);
+
+        // Moving above it scrolls up to the selection
+        list.page_up(15);
+        list.scroll_into_view(10);
+        assert_eq!((list.selected, list.offset), (24, 24));
+    }
+
+    // -------------------------------------------------------------------------
+    // SearchState Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_search_state_editing() {
+        let mut search = SearchState::default();
+
+        search.insert('h');
+        search.insert('e');
+        search.insert('l');
+        search.insert('l');
+        search.insert('o');
+        assert_eq!(search.query, "hello");
+        assert_eq!(search.cursor, 5);
+
+        search.cursor_left();
+        search.cursor_left();
+        assert_eq!(search.cursor, 3);
+
+        search.insert('X');
+        assert_eq!(search.query, "helXlo");
+        assert_eq!(search.cursor, 4);
+
+        search.backspace();
+        assert_eq!(search.query, "hello");
+
+        search.cursor_home();
+        assert_eq!(search.cursor, 0);
+
+        search.cursor_end();
+        assert_eq!(search.cursor, 5);
+    }
+
+    #[test]
+    fn test_search_state_clear() {
+        let mut search = SearchState {
+            query: "test".into(),
+            cursor: 4,
+            ..Default::default()
+        };
+
+        search.clear();
+        assert_eq!(search.query, "");
+        assert_eq!(search.cursor, 0);
+    }
+
+    #[test]
+    fn test_restore_session_replays_search_and_detail() {
+        let season = |season_number, episode_count| SeasonSummary {
+            season_number,
+            episode_count,
+            name: None,
+            air_date: None,
+        };
+        let episode = |n| Episode {
+            season: 2,
+            episode: n,
+            name: format!("Episode {}", n),
+            overview: String::new(),
+            runtime: None,
+            imdb_id: None,
+        };
+        let saved = SavedSession {
+            state: AppState::Sources,
+            detail: Some(SavedDetail {
+                id: 1396,
+                media_type: MediaType::Tv,
+                season: Some(2),
+                episode_index: Some(3),
+            }),
+            query: "breaking bad".into(),
+            search_selected: Some(1),
+        };
+
+        let (mut app, mut rx) = App::with_channels();
+        app.restore_session(saved);
+        assert_eq!(app.state, AppState::Search);
+        assert!(
+            matches!(rx.try_recv(), Ok(AppCommand::Search { query, .. }) if query == "breaking bad")
+        );
+        assert!(matches!(rx.try_recv(), Ok(AppCommand::FetchTvDetail(1396))));
+
+        // The detail can arrive before the search results
+        app.handle_message(AppMessage::TvDetailLoaded(TvDetail {
+            id: 1396,
+            imdb_id: "tt0903747".into(),
+            name: "Breaking Bad".into(),
+            year: 2008,
+            seasons: vec![season(1, 7), season(2, 13)],
+            genres: vec![],
+            overview: String::new(),
+            vote_average: 8.9,
+            poster_path: None,
+            backdrop_path: None,
+        }));
+        assert_eq!(app.state, AppState::Detail);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppCommand::FetchEpisodes {
+                tv_id: 1396,
+                season: 2
+            })
+        ));
+        app.handle_message(AppMessage::EpisodesLoaded {
+            season: 2,
+            episodes: (1..=13).map(episode).collect(),
+        });
+        let generation = app.search_generation;
+        app.handle_message(AppMessage::SearchResults {
+            results: ["Breaking Bad", "El Camino"]
+                .into_iter()
+                .map(|title| SearchResult {
+                    id: 1,
+                    media_type: MediaType::Tv,
+                    title: title.into(),
+                    year: None,
+                    overview: String::new(),
+                    poster_path: None,
+                    vote_average: 0.0,
+                })
+                .collect(),
+            page: 1,
+            total_pages: 1,
+            generation,
+        });
+
+        match &app.detail {
+            Some(DetailState::Tv {
+                season_list,
+                episode_list,
+                focus,
+                ..
+            }) => {
+                assert_eq!(season_list.selected, 1);
+                assert_eq!(episode_list.selected, 3);
+                assert_eq!(*focus, TvFocus::Episodes);
+            }
+            other => panic!("Expected TV detail, got {:?}", other),
+        }
+        assert_eq!(app.search.list.selected, 1);
+        assert!(app.restoring.is_none());
+
+        // Saving again gives back the same place (sources come back as the detail)
+        let session = app.session();
+        assert_eq!(session.state, AppState::Detail);
+        assert_eq!(
+            session.detail.as_ref().map(|d| (d.season, d.episode_index)),
+            Some((Some(2), Some(3)))
+        );
+        assert_eq!(session.search_selected, Some(1));
+
+        // Back still leads to the search results
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        assert_eq!(app.state, AppState::Search);
+    }
+
+    #[test]
+    fn test_restore_session_skips_playing() {
+        let (mut app, mut rx) = App::with_channels();
+        app.restore_session(SavedSession {
+            state: AppState::Playing,
+            detail: Some(SavedDetail {
+                id: 414906,
+                media_type: MediaType::Movie,
+                season: None,
+                episode_index: None,
+            }),
+            ..Default::default()
+        });
+        assert_eq!(app.state, AppState::Home);
+        assert!(rx.try_recv().is_err());
+        assert!(app.restoring.is_none());
+    }
+
+    #[test]
+    fn test_recommendations_return_to_original_detail() {
+        let movie = |id, title: &str| MovieDetail {
+            id,
+            imdb_id: format!("tt{}", id),
+            title: title.into(),
+            year: 2022,
+            runtime: 120,
+            genres: vec![],
+            overview: String::new(),
+            vote_average: 7.0,
+            poster_path: None,
+            backdrop_path: None,
+            collection_id: None,
+            collection_name: None,
+        };
+        let detail_title = |app: &App| match &app.detail {
+            Some(DetailState::Movie { detail, .. }) => detail.title.clone(),
+            other => panic!("Expected movie detail, got {:?}", other),
+        };
+        let (mut app, mut rx) = App::with_channels();
+        app.handle_message(AppMessage::MovieDetailLoaded(movie(414906, "The Batman")));
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()));
+        assert_eq!(app.state, AppState::Search);
+        assert_eq!(app.search.query, "More like The Batman");
+        let generation = match rx.try_recv() {
+            Ok(AppCommand::FetchRecommendations {
+                id,
+                media_type,
+                generation,
+            }) => {
+                assert_eq!(id, 414906);
+                assert_eq!(media_type, MediaType::Movie);
+                generation
+            }
+            other => panic!("Expected FetchRecommendations, got {:?}", other),
+        };
+        app.handle_message(AppMessage::SearchResults {
+            results: vec![SearchResult {
+                id: 155,
+                media_type: MediaType::Movie,
+                title: "The Dark Knight".into(),
+                year: Some(2008),
+                overview: String::new(),
+                poster_path: None,
+                vote_average: 8.5,
+            }],
+            page: 1,
+            total_pages: 1,
+            generation,
+        });
+        assert_eq!(app.search.results.len(), 1);
+
+        // Open a recommendation, then back out twice
+        app.handle_message(AppMessage::MovieDetailLoaded(movie(155, "The Dark Knight")));
+        assert_eq!(detail_title(&app), "The Dark Knight");
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        assert_eq!(app.state, AppState::Search);
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        assert_eq!(app.state, AppState::Detail);
+        assert_eq!(detail_title(&app), "The Batman");
+        assert!(app.detail_stack.is_empty());
+    }
+
+    #[test]
+    fn test_collection_key_only_with_collection() {
+        let mut movie = MovieDetail {
+            id: 155,
+            imdb_id: "tt0468569".into(),
+            title: "The Dark Knight".into(),
+            year: 2008,
+            runtime: 152,
+            genres: vec![],
+            overview: String::new(),
+            vote_average: 8.5,
+            poster_path: None,
+            backdrop_path: None,
+            collection_id: None,
+            collection_name: None,
+        };
+        let (mut app, mut rx) = App::with_channels();
+        app.handle_message(AppMessage::MovieDetailLoaded(movie.clone()));
+        app.handle_key(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT));
+        assert_eq!(app.state, AppState::Detail);
+        assert!(rx.try_recv().is_err());
+
+        movie.collection_id = Some(263);
+        movie.collection_name = Some("The Dark Knight Collection".into());
+        app.handle_message(AppMessage::MovieDetailLoaded(movie));
+        app.handle_key(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT));
+        assert_eq!(app.state, AppState::Search);
+        assert_eq!(app.search.query, "The Dark Knight Collection");
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppCommand::FetchCollection { id: 263, .. })
+        ));
+
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        assert_eq!(app.state, AppState::Detail);
+    }
+
+    #[test]
+    fn test_trailer_key_in_detail_view() {
+        let movie = MovieDetail {
+            id: 414906,
+            imdb_id: "tt1877830".into(),
+            title: "The Batman".into(),
+            year: 2022,
+            runtime: 176,
+            genres: vec![],
+            overview: String::new(),
+            vote_average: 7.8,
+            poster_path: None,
+            backdrop_path: None,
+            collection_id: None,
+            collection_name: None,
+        };
+        let (mut app, mut rx) = App::with_channels();
+        app.handle_message(AppMessage::MovieDetailLoaded(movie));
+        assert_eq!(app.state, AppState::Detail);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::empty()));
+        match rx.try_recv() {
+            Ok(AppCommand::OpenTrailer {
+                id,
+                media_type,
+                title,
+            }) => {
+                assert_eq!(id, 414906);
+                assert_eq!(media_type, MediaType::Movie);
+                assert_eq!(title, "The Batman");
+            }
+            other => panic!("Expected OpenTrailer, got {:?}", other),
+        }
+        // The detail view's `t` doesn't also cycle the theme
+        assert_eq!(app.theme.palette, Palette::Neon);
+
+        // No trailer is a notice, not an error, and goes away on the next key
+        app.handle_message(AppMessage::Notice("No trailer for The Batman".into()));
+        assert_eq!(app.notice.as_deref(), Some("No trailer for The Batman"));
+        assert!(app.error.is_none());
+        app.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()));
+        assert!(app.notice.is_none());
+    }
+
+    #[test]
+    fn test_poster_fetched_and_shown_only_with_graphics() {
+        let movie = MovieDetail {
+            id: 414906,
+            imdb_id: "tt1877830".into(),
+            title: "The Batman".into(),
+            year: 2022,
+            runtime: 176,
+            genres: vec![],
+            overview: String::new(),
+            vote_average: 7.8,
+            poster_path: Some("/poster.jpg".into()),
+            backdrop_path: None,
+            collection_id: None,
+            collection_name: None,
+        };
+
+        // Text-only terminals never download posters
+        let (mut app, mut rx) = App::with_channels();
+        app.handle_message(AppMessage::MovieDetailLoaded(movie.clone()));
+        assert!(rx.try_recv().is_err());
+
+        let (mut app, mut rx) = App::with_channels();
+        app.graphics = Some(GraphicsProtocol::Kitty);
+        app.handle_message(AppMessage::MovieDetailLoaded(movie));
+        assert!(matches!(rx.try_recv(), Ok(AppCommand::FetchPoster(p)) if p == "/poster.jpg"));
 
-        // Growing shouldn't change selection
-        list.set_len(10);
-        assert_eq!(list.selected, 4);
-    }
+        app.handle_message(AppMessage::PosterLoaded {
+            poster_path: "/poster.jpg".into(),
+            file: PathBuf::from("/cache/poster.jpg"),
+        });
+        assert_eq!(app.current_poster(), Some(Path::new("/cache/poster.jpg")));
 
-    // -------------------------------------------------------------------------
-    // SearchState Tests
-    // -------------------------------------------------------------------------
+        // Hidden under modals so graphics don't cover them
+        app.show_help_modal = true;
+        assert_eq!(app.current_poster(), None);
+    }
 
     #[test]
-    fn test_search_state_editing() {
-        let mut search = SearchState::default();
+    fn test_stale_search_results_are_dropped() {
+        let (mut app, mut rx) = App::with_channels();
+        let key = |code| KeyEvent::new(code, KeyModifiers::empty());
+        let mut submit = |app: &mut App, query: &str| {
+            app.handle_key(key(KeyCode::Char('/')));
+            app.search.query = query.into();
+            app.handle_key(key(KeyCode::Enter));
+            let generation = match rx.try_recv() {
+                Ok(AppCommand::Search { generation, .. }) => generation,
+                other => panic!("Expected Search command, got {:?}", other),
+            };
+            assert!(matches!(
+                rx.try_recv(),
+                Ok(AppCommand::SaveSearchHistory(_))
+            ));
+            generation
+        };
+        let result = |title: &str| SearchResult {
+            id: 1,
+            media_type: MediaType::Movie,
+            title: title.into(),
+            year: None,
+            overview: String::new(),
+            poster_path: None,
+            vote_average: 0.0,
+        };
 
-        search.insert('h');
-        search.insert('e');
-        search.insert('l');
-        search.insert('l');
-        search.insert('o');
-        assert_eq!(search.query, "hello");
-        assert_eq!(search.cursor, 5);
+        let first = submit(&mut app, "bat");
+        let second = submit(&mut app, "batman");
+        assert!(second > first);
 
-        search.cursor_left();
-        search.cursor_left();
-        assert_eq!(search.cursor, 3);
+        // The newer query answers first, then the slow older one arrives
+        app.handle_message(AppMessage::SearchResults {
+            results: vec![result("The Batman")],
+            page: 1,
+            total_pages: 1,
+            generation: second,
+        });
+        app.handle_message(AppMessage::SearchResults {
+            results: vec![result("Bat Thumb")],
+            page: 1,
+            total_pages: 1,
+            generation: first,
+        });
 
-        search.insert('X');
-        assert_eq!(search.query, "helXlo");
-        assert_eq!(search.cursor, 4);
+        assert_eq!(app.search.results.len(), 1);
+        assert_eq!(app.search.results[0].title, "The Batman");
+    }
 
-        search.backspace();
-        assert_eq!(search.query, "hello");
+    #[test]
+    fn test_search_history_recall() {
+        let (mut app, mut rx) = App::with_channels();
+        let key = |code| KeyEvent::new(code, KeyModifiers::empty());
+        app.search.set_history(vec!["alien".into(), "heat".into()]);
 
-        search.cursor_home();
-        assert_eq!(search.cursor, 0);
+        // Up/Down on an empty input cycles through history
+        app.focus_search();
+        app.handle_key(key(KeyCode::Up));
+        assert_eq!(app.search.query, "alien");
+        app.handle_key(key(KeyCode::Up));
+        assert_eq!(app.search.query, "heat");
+        assert_eq!(app.search.cursor, 4);
+        app.handle_key(key(KeyCode::Up));
+        assert_eq!(app.search.query, "heat");
+        app.handle_key(key(KeyCode::Down));
+        app.handle_key(key(KeyCode::Down));
+        assert_eq!(app.search.query, "");
+
+        // Submitting moves the query to the front and persists the list
+        app.handle_key(key(KeyCode::Up));
+        app.handle_key(key(KeyCode::Up));
+        app.handle_key(key(KeyCode::Enter));
+        assert!(matches!(rx.try_recv(), Ok(AppCommand::Search { query, .. }) if query == "heat"));
+        match rx.try_recv() {
+            Ok(AppCommand::SaveSearchHistory(history)) => {
+                assert_eq!(history, vec!["heat", "alien"])
+            }
+            other => panic!("Expected SaveSearchHistory, got {:?}", other),
+        }
 
-        search.cursor_end();
-        assert_eq!(search.cursor, 5);
+        // With an empty query the view lists recent searches to pick from
+        app.search.clear();
+        app.search.set_results(Vec::new());
+        assert!(app.search.showing_history());
+        app.handle_key(key(KeyCode::Down));
+        assert_eq!(app.search.selected_history(), Some("alien"));
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.search.query, "alien");
+        assert!(matches!(rx.try_recv(), Ok(AppCommand::Search { query, .. }) if query == "alien"));
     }
 
     #[test]
-    fn test_search_state_clear() {
-        let mut search = SearchState::default();
-        search.query = "test".into();
-        search.cursor = 4;
+    fn test_search_pagination_keys() {
+        let (mut app, mut rx) = App::with_channels();
+        app.state = AppState::Search;
+        app.search.query = "batman".into();
+        app.handle_message(AppMessage::SearchResults {
+            results: vec![],
+            page: 1,
+            total_pages: 3,
+            generation: 0,
+        });
 
-        search.clear();
-        assert_eq!(search.query, "");
-        assert_eq!(search.cursor, 0);
+        // 'p' on the first page does nothing
+        app.handle_key(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::empty()));
+        assert!(rx.try_recv().is_err());
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::empty()));
+        match rx.try_recv() {
+            Ok(AppCommand::SearchPage { query, page, .. }) => {
+                assert_eq!(query, "batman");
+                assert_eq!(page, 2);
+            }
+            other => panic!("Expected SearchPage command, got {:?}", other),
+        }
+
+        // Fresh query resets paging
+        app.search.reset_pages();
+        assert_eq!(app.search.page, 0);
+        assert_eq!(app.search.total_pages, 0);
+        assert_eq!(app.search.next_page(), None);
     }
 
     // -------------------------------------------------------------------------
@@ -1749,6 +4982,417 @@ mod tests {
         assert!(!app.running);
     }
 
+    #[test]
+    fn test_keymap_remaps_navigation() {
+        let mut app = App::new();
+        let table = BTreeMap::from([("down".to_string(), "n".to_string())]);
+        app.keymap = KeyMap::from_config(&table).0;
+        let result = SearchResult {
+            id: 1,
+            media_type: MediaType::Movie,
+            title: "Heat".into(),
+            year: Some(1995),
+            overview: String::new(),
+            poster_path: None,
+            vote_average: 8.0,
+        };
+        app.search.set_results(vec![result.clone(), result]);
+        app.navigate(AppState::Search);
+
+        // The default 'j' no longer moves, the mapped key and arrows do
+        app.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty()));
+        assert_eq!(app.search.list.selected, 0);
+        app.handle_key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::empty()));
+        assert_eq!(app.search.list.selected, 1);
+        app.handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::empty()));
+        assert_eq!(app.search.list.selected, 0);
+    }
+
+    #[test]
+    fn test_theme_key_cycles_palette() {
+        let (mut app, mut rx) = App::with_channels();
+        assert_eq!(app.theme.palette, Palette::Neon);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::empty()));
+        assert_eq!(app.theme, Theme::SOLARIZED);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppCommand::SaveTheme(Palette::Solarized))
+        ));
+
+        // Typing in the search box doesn't switch themes
+        app.focus_search();
+        app.handle_key(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::empty()));
+        assert_eq!(app.theme.palette, Palette::Solarized);
+        assert_eq!(app.search.query, "t");
+    }
+
+    #[test]
+    fn test_sub_lang_filter_display_names() {
+        assert_eq!(SubLangFilter::EngSpa.display(), "English+Spanish");
+        assert_eq!(SubLangFilter::Spanish.display(), "Spanish");
+        assert_eq!(SubLangFilter::All.display(), "All languages");
+    }
+
+    #[test]
+    fn test_add_device_by_ip_from_modal() {
+        let mut app = App::new();
+        app.show_device_modal = true;
+        let players = app.cast_devices.len();
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty());
+        let typed = |app: &mut App, text: &str| {
+            app.handle_key(key('a'));
+            for c in text.chars() {
+                app.handle_key(key(c));
+            }
+            app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+        };
+
+        // Garbage is rejected without touching the list
+        typed(&mut app, "192.168.1");
+        assert!(app.error.is_some());
+        assert_eq!(app.cast_devices.len(), players);
+
+        typed(&mut app, "192.168.1.36");
+        assert!(app.show_device_modal);
+        let device = app.selected_cast_device().unwrap();
+        assert_eq!(device.name, "192.168.1.36");
+        assert_eq!(device.model.as_deref(), Some("Manual"));
+
+        // Adding it again reselects it, and a rescan keeps it
+        typed(&mut app, "192.168.1.36");
+        assert_eq!(app.cast_devices.len(), players + 1);
+        app.handle_message(AppMessage::DevicesLoaded(Vec::new()));
+        assert!(app.cast_devices.iter().any(CastDevice::is_manual));
+    }
+
+    #[test]
+    fn test_help_modal_toggle_leaves_other_modals_alone() {
+        let mut app = App::new();
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty());
+
+        // '?' opens help; other shortcuts are swallowed while it's open
+        app.handle_key(key('?'));
+        assert!(app.show_help_modal);
+        app.handle_key(key('d'));
+        app.handle_key(key('o'));
+        assert!(!app.show_device_modal);
+        assert!(!app.show_settings_modal);
+
+        // Scrolling is bounded and '?' closes it again
+        for _ in 0..500 {
+            app.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()));
+        }
+        assert_eq!(app.help_scroll, app.help_line_count() - 1);
+        app.handle_key(key('?'));
+        assert!(!app.show_help_modal);
+
+        // With the device modal open '?' doesn't stack help on top
+        app.handle_key(key('d'));
+        assert!(app.show_device_modal);
+        app.handle_key(key('?'));
+        assert!(!app.show_help_modal);
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        assert!(!app.show_device_modal);
+
+        // Esc closes help without navigating back
+        app.navigate(AppState::Watchlist);
+        app.handle_key(key('?'));
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        assert!(!app.show_help_modal);
+        assert_eq!(app.state, AppState::Watchlist);
+    }
+
+    #[test]
+    fn test_device_modal_reorders_and_saves() {
+        let (mut app, mut rx) = App::with_channels();
+        let device = |name: &str| CastDevice {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 20)),
+            port: 8009,
+            model: None,
+        };
+        app.device_order = vec!["Bedroom".into()];
+        app.handle_message(AppMessage::DevicesLoaded(vec![
+            device("Kitchen"),
+            device("Bedroom"),
+        ]));
+        let names = |app: &App| {
+            app.cast_devices
+                .iter()
+                .map(|d| d.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&app), vec!["VLC (Local)", "Bedroom", "Kitchen"]);
+        assert!(
+            matches!(rx.try_recv(), Ok(AppCommand::SaveDevices { known, .. }) if known.len() == 2)
+        );
+
+        // Shift+K on Kitchen moves it above Bedroom; VLC stays pinned first
+        app.handle_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::empty()));
+        assert!(matches!(rx.try_recv(), Ok(AppCommand::DiscoverDevices)));
+        app.device_modal_index = 2;
+        app.handle_key(KeyEvent::new(KeyCode::Char('K'), KeyModifiers::SHIFT));
+        assert_eq!(names(&app), vec!["VLC (Local)", "Kitchen", "Bedroom"]);
+        assert_eq!(app.device_modal_index, 1);
+        match rx.try_recv() {
+            Ok(AppCommand::SaveDevices { order, .. }) => {
+                assert_eq!(order, vec!["Kitchen", "Bedroom"])
+            }
+            other => panic!("Expected SaveDevices, got {:?}", other),
+        }
+        app.handle_key(KeyEvent::new(KeyCode::Char('K'), KeyModifiers::SHIFT));
+        assert_eq!(names(&app), vec!["VLC (Local)", "Kitchen", "Bedroom"]);
+    }
+
+    #[test]
+    fn test_marked_devices_cast_together() {
+        let (mut app, _rx) = App::with_channels();
+        let device = |name: &str, last: u8| CastDevice {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, last)),
+            port: 8009,
+            model: None,
+        };
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty());
+        app.handle_message(AppMessage::DevicesLoaded(vec![
+            device("TV", 20),
+            device("Kitchen", 21),
+        ]));
+        app.handle_key(key('d'));
+
+        // Local players can't be marked
+        app.device_modal_index = 0;
+        app.handle_key(key(' '));
+        assert!(app.selected_devices.is_empty());
+        assert!(app.error.is_some());
+
+        app.device_modal_index = 2;
+        app.handle_key(key(' '));
+        assert_eq!(app.selected_devices, vec![2]);
+        app.selected_device = Some(1);
+        assert_eq!(app.also_cast_to(), vec!["Kitchen"]);
+
+        // Marks follow their device when the list is reordered
+        app.handle_key(KeyEvent::new(KeyCode::Char('K'), KeyModifiers::SHIFT));
+        assert_eq!(app.selected_devices, vec![1]);
+        assert_eq!(app.selected_cast_device().unwrap().name, "TV");
+        assert_eq!(app.also_cast_to(), vec!["Kitchen"]);
+
+        // Playing locally casts nowhere else; a new scan drops the marks
+        app.selected_device = Some(0);
+        assert!(app.also_cast_to().is_empty());
+        app.handle_message(AppMessage::DevicesLoaded(vec![device("TV", 20)]));
+        assert!(app.selected_devices.is_empty());
+    }
+
+    #[test]
+    fn test_device_list_offers_installed_players() {
+        let (mut app, _rx) = App::with_channels();
+        let tv = CastDevice {
+            id: "tv".into(),
+            name: "TV".into(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 20)),
+            port: 8009,
+            model: None,
+        };
+        let names = |app: &App| {
+            app.cast_devices
+                .iter()
+                .map(|d| d.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        app.local_players = vec![PlayerType::Mpv];
+        app.handle_message(AppMessage::DevicesLoaded(vec![tv.clone()]));
+        assert_eq!(names(&app), vec!["mpv (Local)", "TV"]);
+
+        // No local players installed: only the Chromecasts are offered
+        app.local_players.clear();
+        app.handle_message(AppMessage::DevicesLoaded(vec![tv]));
+        assert_eq!(names(&app), vec!["TV"]);
+    }
+
+    #[test]
+    fn test_genre_picker_discovers_into_search() {
+        let (mut app, mut rx) = App::with_channels();
+        let key = |code| KeyEvent::new(code, KeyModifiers::empty());
+
+        app.handle_key(key(KeyCode::Char('g')));
+        assert!(app.show_genre_modal);
+
+        // Pick Fantasy, then switch to TV: Fantasy stays selected with its TV ID
+        let fantasy = app
+            .genre_choices()
+            .iter()
+            .position(|g| g.name == "Fantasy")
+            .unwrap();
+        for _ in 0..fantasy {
+            app.handle_key(key(KeyCode::Down));
+        }
+        app.handle_key(key(KeyCode::Tab));
+        app.handle_key(key(KeyCode::Char('s')));
+        assert_eq!(app.genre_choices()[app.genre_modal_index].name, "Fantasy");
+
+        app.handle_key(key(KeyCode::Enter));
+        assert!(!app.show_genre_modal);
+        assert_eq!(app.state, AppState::Search);
+        assert_eq!(app.search.query, "Fantasy TV");
+        match rx.try_recv() {
+            Ok(AppCommand::Discover {
+                genre_id,
+                media_type,
+                sort,
+                ..
+            }) => {
+                assert_eq!(genre_id, 10765);
+                assert_eq!(media_type, MediaType::Tv);
+                assert_eq!(sort, DiscoverSort::Rating);
+            }
+            other => panic!("Expected Discover, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_subtitle_preview_modal() {
+        let (mut app, mut rx) = App::with_channels();
+        let key = |code| KeyEvent::new(code, KeyModifiers::empty());
+        let sub = SubtitleResult::local_file(std::path::Path::new("/tmp/movie.srt"));
+        app.navigate(AppState::Subtitles);
+        app.subtitles.set_subtitles(vec![sub.clone()]);
+
+        app.handle_key(key(KeyCode::Char(' ')));
+        assert!(app.subtitles.preview.as_ref().unwrap().loading.is_loading());
+        match rx.try_recv() {
+            Ok(AppCommand::PreviewSubtitle { url }) => assert_eq!(url, sub.url),
+            other => panic!("Expected PreviewSubtitle, got {:?}", other),
+        }
+
+        // A stale result for another subtitle is ignored
+        let cue = |text: &str| Cue {
+            start_ms: 0,
+            end_ms: 1000,
+            text: text.to_string(),
+        };
+        app.handle_message(AppMessage::SubtitlePreviewLoaded {
+            url: "other".into(),
+            cues: vec![cue("x")],
+        });
+        assert!(app.subtitles.preview.as_ref().unwrap().cues.is_empty());
+
+        app.handle_message(AppMessage::SubtitlePreviewLoaded {
+            url: sub.url.clone(),
+            cues: vec![cue("a"), cue("b")],
+        });
+        app.handle_key(key(KeyCode::Down));
+        app.handle_key(key(KeyCode::Down));
+        let preview = app.subtitles.preview.as_ref().unwrap();
+        assert_eq!(preview.scroll, 1);
+        assert!(!preview.loading.is_loading());
+
+        // Space closes the preview and leaves the subtitle unselected
+        app.handle_key(key(KeyCode::Char(' ')));
+        assert!(app.subtitles.preview.is_none());
+        assert_eq!(app.state, AppState::Subtitles);
+        assert!(app.subtitles.selected.is_none());
+    }
+
+    #[test]
+    fn test_subtitles_grouped_by_language_then_trust() {
+        let sub = |id: &str, language: &str, downloads: u32, trusted: bool| {
+            let mut sub = SubtitleResult::local_file(std::path::Path::new("/tmp/x.srt"));
+            sub.id = id.to_string();
+            sub.language = language.to_string();
+            sub.language_name = String::new();
+            sub.fill_language_name();
+            sub.downloads = downloads;
+            sub.from_trusted = trusted;
+            sub
+        };
+        let subs = vec![
+            sub("fre-low", "fre", 10, false),
+            sub("spa-low", "spa", 5, false),
+            sub("eng-low", "eng", 50, false),
+            sub("fre-top", "fre", 900, true),
+            sub("spa-top", "spa", 20, true),
+            sub("eng-top", "eng", 100, true),
+        ];
+        let ids = |state: &SubtitlesState| -> Vec<String> {
+            state.subtitles.iter().map(|s| s.id.clone()).collect()
+        };
+
+        // English+Spanish lists those two first, in that order; French trails
+        let mut state = SubtitlesState::default();
+        state.set_subtitles(subs.clone());
+        assert_eq!(
+            ids(&state),
+            ["eng-top", "eng-low", "spa-top", "spa-low", "fre-top", "fre-low"]
+        );
+        let rows = state.sorted_grouped();
+        assert_eq!(rows.len(), 9);
+        assert_eq!(rows[0], SubtitleListRow::Language("English".into()));
+        assert_eq!(rows[1], SubtitleListRow::Subtitle(0));
+        assert_eq!(rows[3], SubtitleListRow::Language("Spanish".into()));
+        assert_eq!(rows[6], SubtitleListRow::Language("French".into()));
+        assert_eq!(rows[8], SubtitleListRow::Subtitle(5));
+
+        // With every language shown the groups go alphabetically
+        state.lang_filter = SubLangFilter::All;
+        state.set_subtitles(subs);
+        assert_eq!(
+            ids(&state),
+            ["eng-top", "eng-low", "fre-top", "fre-low", "spa-top", "spa-low"]
+        );
+
+        // Navigation still runs over the subtitles alone
+        state.list.down();
+        assert_eq!(state.selected_subtitle().unwrap().id, "eng-low");
+    }
+
+    #[test]
+    fn test_custom_subtitle_language() {
+        let (mut app, mut rx) = App::with_channels();
+        let key = |code| KeyEvent::new(code, KeyModifiers::empty());
+        app.navigate(AppState::Subtitles);
+
+        // The custom slot isn't in the Tab cycle until a language is typed
+        for _ in 0..4 {
+            app.handle_key(key(KeyCode::Tab));
+        }
+        assert_eq!(app.subtitles.lang_filter, SubLangFilter::EngSpa);
+
+        app.handle_key(key(KeyCode::Char('c')));
+        for c in "Fre, JPN".chars() {
+            app.handle_key(key(KeyCode::Char(c)));
+        }
+        app.handle_key(key(KeyCode::Enter));
+        assert!(app.subtitles.lang_input.is_none());
+        assert_eq!(app.subtitles.lang_filter, SubLangFilter::Custom);
+        assert_eq!(app.subtitles.lang_code(), "fre,jpn");
+        assert_eq!(app.subtitles.filter_display(), "French+Japanese");
+        match rx.try_recv() {
+            Ok(AppCommand::SaveSubtitleLang(codes)) => assert_eq!(codes, "fre,jpn"),
+            other => panic!("Expected SaveSubtitleLang, got {:?}", other),
+        }
+
+        // Now Tab comes back around to it
+        for _ in 0..5 {
+            app.handle_key(key(KeyCode::Tab));
+        }
+        assert_eq!(app.subtitles.lang_filter, SubLangFilter::Custom);
+
+        // A blank entry is rejected and keeps the old list
+        app.handle_key(key(KeyCode::Char('c')));
+        for _ in 0..10 {
+            app.handle_key(key(KeyCode::Backspace));
+        }
+        app.handle_key(key(KeyCode::Enter));
+        assert!(app.error.is_some());
+        assert_eq!(app.subtitles.custom_lang.as_deref(), Some("fre,jpn"));
+    }
+
     #[test]
     fn test_app_focus_search() {
         let mut app = App::new();
@@ -1822,21 +5466,31 @@ mod tests {
         app.sources.sources = vec![
             StreamSource {
                 name: "1".into(),
+                provider: "1".into(),
                 title: "Source 1".into(),
                 info_hash: "hash1".into(),
                 file_idx: None,
                 seeds: 100,
                 quality: Quality::FHD1080p,
                 size_bytes: None,
+                hdr: HdrType::None,
+                codec: VideoCodec::Unknown,
+                release_group: None,
+                direct_url: None,
             },
             StreamSource {
                 name: "2".into(),
+                provider: "2".into(),
                 title: "Source 2".into(),
                 info_hash: "hash2".into(),
                 file_idx: None,
                 seeds: 200,
                 quality: Quality::UHD4K,
                 size_bytes: None,
+                hdr: HdrType::None,
+                codec: VideoCodec::Unknown,
+                release_group: None,
+                direct_url: None,
             },
         ];
         app.sources.list.set_len(2);
@@ -1846,10 +5500,542 @@ mod tests {
         assert_eq!(app.sources.list.selected, 1);
     }
 
+    #[test]
+    fn test_pack_source_asks_for_file_index() {
+        let (mut app, mut rx) = App::with_channels();
+        let key = |code| KeyEvent::new(code, KeyModifiers::empty());
+        app.cast_devices = vec![CastDevice {
+            id: "tv".into(),
+            name: "TV".into(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 20)),
+            port: 8009,
+            model: None,
+        }];
+        app.selected_device = Some(0);
+        app.navigate(AppState::Sources);
+        app.sources.set_sources(vec![StreamSource {
+            name: "Torrentio".into(),
+            provider: "Torrentio".into(),
+            title: "Show.S01.1080p.WEB\nShow.S01E03.mkv".into(),
+            info_hash: "hash1".into(),
+            file_idx: Some(2),
+            seeds: 100,
+            quality: Quality::FHD1080p,
+            size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
+        }]);
+
+        // Enter opens the picker with the addon's file index instead of casting
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.sources.file_idx_input.as_deref(), Some("2"));
+        assert!(rx.try_recv().is_err());
+
+        // Letters are ignored; the typed index is cast
+        app.handle_key(key(KeyCode::Backspace));
+        app.handle_key(key(KeyCode::Char('x')));
+        app.handle_key(key(KeyCode::Char('5')));
+        app.handle_key(key(KeyCode::Enter));
+        assert!(app.sources.file_idx_input.is_none());
+        assert_eq!(app.state, AppState::Playing);
+        match rx.try_recv() {
+            Ok(AppCommand::StartPlayback { file_idx, .. }) => assert_eq!(file_idx, Some(5)),
+            other => panic!("Expected StartPlayback, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_stalled_buffering_downgrades_quality() {
+        let (mut app, mut rx) = App::with_channels();
+        app.cast_devices = vec![CastDevice {
+            id: "tv".into(),
+            name: "TV".into(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 20)),
+            port: 8009,
+            model: None,
+        }];
+        app.selected_device = Some(0);
+        app.auto_downgrade = true;
+        app.stall_after = Duration::ZERO;
+        let source = |hash: &str, quality, seeds| StreamSource {
+            name: "Torrentio".into(),
+            provider: "Torrentio".into(),
+            title: hash.into(),
+            info_hash: hash.into(),
+            file_idx: None,
+            seeds,
+            quality,
+            size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
+        };
+        app.sources.set_sources(vec![
+            source("uhd", Quality::UHD4K, 50),
+            source("hd720", Quality::HD720p, 900),
+            source("fhd-few", Quality::FHD1080p, 10),
+            source("fhd", Quality::FHD1080p, 300),
+        ]);
+        app.navigate(AppState::Sources);
+        app.play_source(app.sources.sources[0].clone());
+        while rx.try_recv().is_ok() {}
+
+        let buffering = TorrentState::Buffering {
+            peers: 3,
+            progress: 4,
+        };
+        app.playing.torrent.as_mut().unwrap().state = buffering;
+        app.tick();
+        assert!(app.playing.stall.is_some());
+        assert!(app.playing.downgrade.is_none());
+
+        // No progress since the last tick: offer the best 1080p source
+        app.tick();
+        let downgrade = app
+            .playing
+            .downgrade
+            .as_ref()
+            .expect("Should offer a lower quality");
+        assert_eq!(downgrade.source.info_hash, "fhd");
+
+        // Countdown over: the lower quality is cast
+        app.playing.downgrade.as_mut().unwrap().starts_at = Instant::now();
+        while rx.try_recv().is_ok() {}
+        app.tick();
+        let started = std::iter::from_fn(|| rx.try_recv().ok())
+            .find(|c| matches!(c, AppCommand::StartPlayback { .. }));
+        match started {
+            Some(AppCommand::StartPlayback { magnet, .. }) => assert!(magnet.contains("btih:fhd&")),
+            other => panic!("Expected StartPlayback, got {:?}", other),
+        }
+        assert_eq!(app.playing.info_hash.as_deref(), Some("fhd"));
+
+        // Esc keeps the current source and stops asking
+        app.playing.torrent.as_mut().unwrap().state = TorrentState::Buffering {
+            peers: 3,
+            progress: 4,
+        };
+        app.tick();
+        app.tick();
+        assert!(app.playing.downgrade.is_some());
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        assert!(app.playing.downgrade.is_none());
+        app.tick();
+        app.tick();
+        assert!(app.playing.downgrade.is_none());
+    }
+
+    // -------------------------------------------------------------------------
+    // Sources Sort Tests
+    // -------------------------------------------------------------------------
+
+    fn sort_source(hash: &str, seeds: u32, quality: Quality, size: u64) -> StreamSource {
+        StreamSource {
+            name: hash.into(),
+            provider: String::new(),
+            title: format!("Source {}", hash),
+            info_hash: hash.into(),
+            file_idx: None,
+            seeds,
+            quality,
+            size_bytes: Some(size),
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
+        }
+    }
+
+    fn sorted_hashes(sources: &SourcesState) -> Vec<&str> {
+        sources
+            .sources
+            .iter()
+            .map(|s| s.info_hash.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_sources_sort_is_stable() {
+        let mut sources = SourcesState::new("Test".into());
+        sources.set_sources(vec![
+            sort_source("a", 50, Quality::FHD1080p, 2_000),
+            sort_source("b", 300, Quality::UHD4K, 2_000),
+            sort_source("c", 50, Quality::HD720p, 1_000),
+            sort_source("d", 300, Quality::FHD1080p, 8_000),
+        ]);
+
+        sources.sort_by(SourceSort::Seeds);
+        assert_eq!(sorted_hashes(&sources), vec!["b", "d", "a", "c"]);
+
+        sources.sort_by(SourceSort::Quality);
+        assert_eq!(sorted_hashes(&sources), vec!["b", "d", "a", "c"]);
+
+        // Equal sizes keep the order from the previous sort
+        sources.sort_by(SourceSort::Size);
+        assert_eq!(sorted_hashes(&sources), vec!["d", "b", "a", "c"]);
+        assert_eq!(sources.sort, Some(SourceSort::Size));
+    }
+
+    #[test]
+    fn test_trending_truncated_to_home_limit() {
+        let mut app = App::new();
+        let trending = (1..=45)
+            .map(|id| SearchResult {
+                id,
+                media_type: MediaType::Movie,
+                title: format!("Movie {}", id),
+                year: None,
+                overview: String::new(),
+                poster_path: None,
+                vote_average: 0.0,
+            })
+            .collect::<Vec<_>>();
+
+        app.handle_message(AppMessage::TrendingLoaded(trending.clone()));
+        assert_eq!(app.home.list.len, 20);
+        assert_eq!(app.home.more.len(), 25);
+        app.home.list.last();
+        assert_eq!(app.home.selected_result().map(|r| r.id), Some(20));
+
+        // `m` lists the rest, keeping the selection
+        app.handle_key(KeyEvent::new(KeyCode::Char('m'), KeyModifiers::empty()));
+        assert_eq!(app.home.list.len, 45);
+        assert!(app.home.more.is_empty());
+        assert_eq!(app.home.selected_result().map(|r| r.id), Some(20));
+
+        // A limit past the list length keeps everything, as does no limit
+        app.home_limit = Some(50);
+        app.handle_message(AppMessage::TrendingLoaded(trending.clone()));
+        assert_eq!(app.home.list.len, 45);
+        app.home_limit = None;
+        app.handle_message(AppMessage::TrendingLoaded(trending[..3].to_vec()));
+        assert_eq!((app.home.list.len, app.home.list.selected), (3, 2));
+    }
+
+    #[test]
+    fn test_pending_commands_track_replies() {
+        let (mut app, _rx) = App::with_channels();
+        app.send_command(AppCommand::FetchTrending);
+        app.send_command(AppCommand::FetchMovieDetail(603));
+        // Polls and saves don't wait on anything
+        app.send_command(AppCommand::PollTorrentStatus);
+        app.send_command(AppCommand::SaveTheme(Palette::default()));
+        assert_eq!(app.pending_commands, 2);
+
+        // Progress updates aren't replies
+        app.handle_message(AppMessage::TorrentProgress {
+            progress: 0.1,
+            speed: 0,
+            peers: 3,
+            state: None,
+        });
+        assert_eq!(app.pending_commands, 2);
+
+        app.handle_message(AppMessage::TrendingLoaded(Vec::new()));
+        assert_eq!(app.pending_commands, 1);
+        // A failure settles its request too, and extra replies can't underflow
+        app.handle_message(AppMessage::Error("Failed to fetch movie".into()));
+        app.handle_message(AppMessage::PlaybackStopped);
+        assert_eq!(app.pending_commands, 0);
+    }
+
+    #[test]
+    fn test_copy_magnet_from_sources() {
+        let (mut app, mut rx) = App::with_channels();
+        app.state = AppState::Sources;
+        app.sources.title = "Ocean's Eleven".into();
+        let hash = "c9e15763f722f23e98a29decdfae341b98d53056";
+        app.sources
+            .set_sources(vec![sort_source(hash, 80, Quality::FHD1080p, 4_000)]);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::empty()));
+        match rx.try_recv() {
+            Ok(AppCommand::CopyMagnet(magnet)) => {
+                assert!(magnet.starts_with(&format!("magnet:?xt=urn:btih:{}", hash)));
+            }
+            other => panic!("Expected CopyMagnet, got {:?}", other),
+        }
+
+        // Without a clipboard the magnet is shown until the next key
+        app.handle_message(AppMessage::ClipboardUnavailable(
+            "magnet:?xt=urn:btih:c9e1".into(),
+        ));
+        assert_eq!(
+            app.magnet_popup.as_deref(),
+            Some("magnet:?xt=urn:btih:c9e1")
+        );
+        app.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()));
+        assert!(app.magnet_popup.is_none());
+
+        // Debrid links have nothing to copy
+        app.sources
+            .set_sources(vec![sort_source("rd", 0, Quality::FHD1080p, 4_000)]);
+        app.handle_key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::empty()));
+        assert!(rx.try_recv().is_err());
+        assert!(app.error.is_some());
+    }
+
+    #[test]
+    fn test_refresh_sources_and_subtitles() {
+        let movie = MovieDetail {
+            id: 414906,
+            imdb_id: "tt1877830".into(),
+            title: "The Batman".into(),
+            year: 2022,
+            runtime: 176,
+            genres: vec![],
+            overview: String::new(),
+            vote_average: 7.8,
+            poster_path: None,
+            backdrop_path: None,
+            collection_id: None,
+            collection_name: None,
+        };
+        let (mut app, mut rx) = App::with_channels();
+        app.handle_message(AppMessage::MovieDetailLoaded(movie));
+        let mut sent = || std::iter::from_fn(|| rx.try_recv().ok()).collect::<Vec<_>>();
+        sent();
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::empty()));
+        assert_eq!(app.state, AppState::Sources);
+        assert_eq!(app.sources.imdb_id.as_deref(), Some("tt1877830"));
+        sent();
+        app.handle_message(AppMessage::StreamsLoaded(vec![]));
+        assert_eq!(app.sources.loading, LoadingState::Idle);
+
+        // `r` asks for the same title again
+        app.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()));
+        assert!(matches!(app.sources.loading, LoadingState::Loading(_)));
+        assert!(sent().iter().any(|cmd| matches!(
+            cmd,
+            AppCommand::FetchStreams { imdb_id, season: None, episode: None, absolute: None } if imdb_id == "tt1877830"
+        )));
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::empty()));
+        assert_eq!(app.state, AppState::Subtitles);
+        sent();
+        app.handle_message(AppMessage::SubtitlesLoaded(vec![]));
+        app.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()));
+        assert!(matches!(app.subtitles.loading, LoadingState::Loading(_)));
+        assert!(sent().iter().any(|cmd| matches!(
+            cmd,
+            AppCommand::FetchSubtitles { imdb_id, season: None, episode: None, .. } if imdb_id == "tt1877830"
+        )));
+
+        // A season queue has no single title to refresh
+        app.state = AppState::Sources;
+        app.sources = SourcesState::new("Breaking Bad".into());
+        app.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()));
+        assert!(sent().is_empty());
+        assert!(app.error.is_some());
+    }
+
+    #[test]
+    fn test_quality_floor_from_detail() {
+        let movie = MovieDetail {
+            id: 414906,
+            imdb_id: "tt1877830".into(),
+            title: "The Batman".into(),
+            year: 2022,
+            runtime: 176,
+            genres: vec![],
+            overview: String::new(),
+            vote_average: 7.8,
+            poster_path: None,
+            backdrop_path: None,
+            collection_id: None,
+            collection_name: None,
+        };
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty());
+        let mut app = App::new();
+        app.handle_message(AppMessage::MovieDetailLoaded(movie));
+        app.state = AppState::Detail;
+
+        // `-` with no floor does nothing; `+` climbs and stops at 4K
+        app.handle_key(key('-'));
+        assert_eq!(app.quality_floor, None);
+        app.handle_key(key('+'));
+        assert_eq!(app.quality_floor, Some(Quality::SD480p));
+        for _ in 0..4 {
+            app.handle_key(key('+'));
+        }
+        assert_eq!(app.quality_floor, Some(Quality::UHD4K));
+        app.handle_key(key('-'));
+        assert_eq!(app.quality_floor, Some(Quality::FHD1080p));
+
+        app.handle_key(key('c'));
+        assert_eq!(app.state, AppState::Sources);
+        assert_eq!(app.sources.quality_floor, Some(Quality::FHD1080p));
+        app.handle_message(AppMessage::StreamsLoaded(vec![
+            sort_source("a", 90, Quality::HD720p, 1_000),
+            sort_source("b", 50, Quality::UHD4K, 9_000),
+            sort_source("c", 10, Quality::FHD1080p, 5_000),
+            sort_source("d", 10, Quality::Unknown, 5_000),
+        ]));
+        let visible: Vec<&str> = app
+            .sources
+            .filtered_sources()
+            .iter()
+            .map(|s| s.info_hash.as_str())
+            .collect();
+        assert_eq!(visible, vec!["b", "c"]);
+        assert!(app.sources.is_narrowed());
+
+        // `Q` shows everything again, and back
+        app.handle_key(key('Q'));
+        assert_eq!(app.sources.quality_floor, None);
+        assert_eq!(app.sources.list.len, 4);
+        app.handle_key(key('Q'));
+        assert_eq!(app.sources.quality_floor, Some(Quality::FHD1080p));
+        assert_eq!(app.sources.list.len, 2);
+
+        // The target outlives the sources view
+        app.handle_key(key('Q'));
+        app.state = AppState::Detail;
+        assert_eq!(app.quality_floor, Some(Quality::FHD1080p));
+        app.handle_key(key('c'));
+        assert_eq!(app.sources.quality_floor, Some(Quality::FHD1080p));
+    }
+
+    #[test]
+    fn test_hide_low_seed_sources() {
+        let mut app = App::new();
+        app.state = AppState::Sources;
+        app.min_seeds = 20;
+        let mut debrid = sort_source("rd", 0, Quality::FHD1080p, 4_000);
+        debrid.direct_url = Some("https://debrid.example/file.mkv".into());
+        app.sources.set_sources(vec![
+            sort_source("a", 3, Quality::UHD4K, 1_000),
+            sort_source("b", 500, Quality::HD720p, 9_000),
+            sort_source("c", 19, Quality::FHD1080p, 5_000),
+            debrid,
+            sort_source("d", 20, Quality::FHD1080p, 5_000),
+        ]);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::empty()));
+        assert_eq!(app.sources.seed_floor, Some(20));
+        let visible: Vec<&str> = app
+            .sources
+            .filtered_sources()
+            .iter()
+            .map(|s| s.info_hash.as_str())
+            .collect();
+        assert_eq!(visible, vec!["b", "rd", "d"]);
+        assert_eq!(app.sources.list.len, 3);
+
+        // Number keys pick from what's shown
+        app.handle_key(KeyEvent::new(KeyCode::Char('3'), KeyModifiers::empty()));
+        assert_eq!(app.sources.selected_source().unwrap().info_hash, "d");
+        app.handle_key(KeyEvent::new(KeyCode::Char('4'), KeyModifiers::empty()));
+        assert_eq!(app.sources.selected_source().unwrap().info_hash, "d");
+
+        // New results keep the floor
+        app.sources
+            .set_sources(vec![sort_source("e", 1, Quality::UHD4K, 1_000)]);
+        assert_eq!(app.sources.list.len, 0);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::empty()));
+        assert_eq!(app.sources.seed_floor, None);
+        assert_eq!(app.sources.list.len, 1);
+    }
+
+    #[test]
+    fn test_streams_loaded_selects_preferred_quality() {
+        let mut app = App::new();
+        app.state = AppState::Sources;
+        app.preferred_quality = Some(Quality::FHD1080p);
+        app.handle_message(AppMessage::StreamsLoaded(vec![
+            sort_source("a", 900, Quality::UHD4K, 9_000),
+            sort_source("b", 500, Quality::HD720p, 1_000),
+            sort_source("c", 200, Quality::FHD1080p, 4_000),
+            sort_source("d", 100, Quality::FHD1080p, 3_000),
+        ]));
+        assert_eq!(app.sources.list.selected, 2);
+        assert_eq!(app.sources.selected_source().unwrap().info_hash, "c");
+
+        // Without a match the closest quality wins, first in list order
+        app.preferred_quality = Some(Quality::SD480p);
+        app.handle_message(AppMessage::StreamsLoaded(vec![
+            sort_source("a", 900, Quality::UHD4K, 9_000),
+            sort_source("b", 500, Quality::FHD1080p, 4_000),
+            sort_source("c", 200, Quality::HD720p, 1_000),
+        ]));
+        assert_eq!(app.sources.selected_source().unwrap().info_hash, "c");
+    }
+
+    #[test]
+    fn test_sources_sort_keeps_selection() {
+        let mut app = App::new();
+        app.state = AppState::Sources;
+        app.sources.set_sources(vec![
+            sort_source("a", 10, Quality::UHD4K, 1_000),
+            sort_source("b", 500, Quality::HD720p, 9_000),
+            sort_source("c", 100, Quality::FHD1080p, 5_000),
+        ]);
+        app.sources.list.selected = 2; // "c"
+
+        // 'S' starts at seeds: b, c, a
+        app.handle_key(KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT));
+        assert_eq!(app.sources.sort, Some(SourceSort::Seeds));
+        assert_eq!(app.sources.selected_source().unwrap().info_hash, "c");
+        assert_eq!(app.sources.list.selected, 1);
+
+        // Then quality: a, c, b
+        app.handle_key(KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT));
+        assert_eq!(app.sources.sort, Some(SourceSort::Quality));
+        assert_eq!(app.sources.selected_source().unwrap().info_hash, "c");
+
+        // Then size: b, c, a
+        app.handle_key(KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT));
+        assert_eq!(app.sources.sort, Some(SourceSort::Size));
+        assert_eq!(sorted_hashes(&app.sources), vec!["b", "c", "a"]);
+        assert_eq!(app.sources.selected_source().unwrap().info_hash, "c");
+    }
+
     // -------------------------------------------------------------------------
     // Playing Controls Tests
     // -------------------------------------------------------------------------
 
+    #[test]
+    fn test_one_cast_status_poll_at_a_time() {
+        let (mut app, mut rx) = App::with_channels();
+        app.state = AppState::Playing;
+        app.playing.device = Some(CastDevice {
+            id: "test".into(),
+            name: "Slow TV".into(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 50)),
+            port: 8009,
+            model: None,
+        });
+
+        app.tick();
+        app.tick();
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppCommand::FetchCastStatus { device }) if device == "Slow TV"
+        ));
+        assert!(rx.try_recv().is_err());
+
+        // A failed poll frees the slot as well as a status
+        app.handle_message(AppMessage::CastStatusUnavailable);
+        app.tick();
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppCommand::FetchCastStatus { .. })
+        ));
+        app.handle_message(AppMessage::CastStatusLoaded(PlaybackStatus {
+            state: CastState::Playing,
+            position: std::time::Duration::from_secs(10),
+            duration: std::time::Duration::from_secs(3600),
+            volume: 0.8,
+            title: None,
+        }));
+        assert!(!app.cast_status_in_flight);
+    }
+
     #[test]
     fn test_playing_pause_toggle() {
         let mut app = App::new();
@@ -1912,4 +6098,31 @@ mod tests {
         app.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()));
         assert!((app.playing.playback.as_ref().unwrap().volume - 0.5).abs() < 0.01);
     }
+
+    #[test]
+    fn test_playing_seek_uses_configured_step() {
+        let (mut app, mut rx) = App::with_channels();
+        app.state = AppState::Playing;
+        app.seek_step = 10;
+        app.playing.device = Some(CastDevice {
+            id: "test".into(),
+            name: "Test TV".into(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 50)),
+            port: 8009,
+            model: None,
+        });
+
+        app.handle_key(KeyEvent::new(KeyCode::Right, KeyModifiers::empty()));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppCommand::PlaybackControl { action, value: Some(10), .. }) if action == "ffwd"
+        ));
+
+        // Shift jumps five steps
+        app.handle_key(KeyEvent::new(KeyCode::Left, KeyModifiers::SHIFT));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppCommand::PlaybackControl { action, value: Some(50), .. }) if action == "rewind"
+        ));
+    }
 }
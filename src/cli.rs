@@ -8,6 +8,7 @@
 //! ```bash
 //! # Search for content
 //! streamtui search "the batman" --json
+//! streamtui person "cillian murphy"
 //!
 //! # Get streams and cast
 //! streamtui streams tt1877830
@@ -22,8 +23,16 @@
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use serde::{Deserialize, Serialize};
 use std::io::IsTerminal;
+use std::net::IpAddr;
 use std::path::PathBuf;
 
+use crate::api::torrentio::{StreamKind, TorrentioConfig, TorrentioSort};
+use crate::config::{WatchlistEntry, DEFAULT_SEEK_STEP};
+use crate::models::{
+    CastDevice, CastState, MediaType, Quality, SearchResult, StreamSource, SubtitleResult,
+    HEALTHY_SEEDS,
+};
+
 // =============================================================================
 // Exit Codes
 // =============================================================================
@@ -85,22 +94,55 @@ impl From<ExitCode> for std::process::ExitCode {
                   streamtui status --json             Check playback status"
 )]
 pub struct Cli {
-    /// Output format as JSON (default for non-TTY)
+    /// Output format as JSON (default for non-TTY; same as --format json)
     #[arg(long, short = 'j', global = true)]
     pub json: bool,
 
+    /// Output format: table, json, or line (one terse line per item)
+    #[arg(long, global = true, value_enum, value_name = "FORMAT")]
+    pub format: Option<OutputFormat>,
+
     /// Target Chromecast device name
     #[arg(long, short = 'd', global = true)]
     pub device: Option<String>,
 
+    /// Chromecast address to use without scanning (overrides --device)
+    #[arg(long, global = true, value_name = "ADDR")]
+    pub device_ip: Option<IpAddr>,
+
     /// Suppress non-essential output
     #[arg(long, short = 'q', global = true)]
     pub quiet: bool,
 
+    /// JSON output without the schema envelope (bare data, as before schema 1)
+    #[arg(long, global = true)]
+    pub raw_json: bool,
+
     /// Path to config file
     #[arg(long, short = 'c', global = true)]
     pub config: Option<PathBuf>,
 
+    /// Config profile to load (overrides STREAMTUI_PROFILE and the saved choice)
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Write the playback log here, with timestamped playback steps
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Read subtitles in this charset (e.g. windows-1252, latin1, utf-16) instead of detecting it
+    #[arg(long, global = true, value_name = "LABEL", value_parser = parse_encoding_arg)]
+    pub subtitle_encoding: Option<String>,
+
+    /// Local subtitle file to use for TUI playback (skips the subtitle search)
+    #[arg(long, value_name = "PATH")]
+    pub subtitle_file: Option<PathBuf>,
+
+    /// Draw the TUI with plain ASCII instead of emoji and box drawing
+    /// (also STREAMTUI_ASCII=1 or `ascii_mode` in config)
+    #[arg(long)]
+    pub ascii: bool,
+
     /// Subcommand to run (omit for TUI mode)
     #[command(subcommand)]
     pub command: Option<Command>,
@@ -112,12 +154,45 @@ impl Cli {
         self.command.is_some()
     }
 
+    /// Device for catt's `-d`: the `--device-ip` address, else `--device`
+    pub fn device_target(&self) -> Option<String> {
+        self.device_ip
+            .map(|ip| ip.to_string())
+            .or_else(|| self.device.clone())
+    }
+
+    /// Output format: `--json`/`--raw-json`, then `--format`, then JSON
+    /// unless stdout is a terminal
+    pub fn output_format(&self) -> OutputFormat {
+        if self.json || self.raw_json {
+            return OutputFormat::Json;
+        }
+        self.format.unwrap_or_else(|| {
+            if std::io::stdout().is_terminal() {
+                OutputFormat::Table
+            } else {
+                OutputFormat::Json
+            }
+        })
+    }
+
     /// Check if JSON output should be used
     pub fn should_json(&self) -> bool {
-        self.json || !std::io::stdout().is_terminal()
+        self.output_format() == OutputFormat::Json
     }
 }
 
+/// How CLI results are printed (`--format`)
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Readable output for a terminal
+    Table,
+    /// JSON (same as --json)
+    Json,
+    /// One space-separated line per item, for grep/awk
+    Line,
+}
+
 // =============================================================================
 // Subcommands
 // =============================================================================
@@ -128,22 +203,41 @@ pub enum Command {
     #[command(visible_alias = "s")]
     Search(SearchCmd),
 
+    /// Search for people (actors, directors) and their known-for titles
+    Person(PersonCmd),
+
     /// Get trending content
     #[command(visible_alias = "tr")]
     Trending(TrendingCmd),
 
+    /// Browse movies or TV shows by genre
+    Discover(DiscoverCmd),
+
     /// Get details for a movie or show
     #[command(visible_alias = "i")]
     Info(InfoCmd),
 
+    /// Print the YouTube URL of a title's trailer
+    Trailer(TrailerCmd),
+
+    /// List titles similar to a movie or show
+    Similar(SimilarCmd),
+
     /// Get available streams for content
     #[command(visible_alias = "st")]
     Streams(StreamsCmd),
 
+    /// Print the magnet link of a stream (for other torrent clients)
+    Magnet(MagnetCmd),
+
     /// Search for subtitles
     #[command(visible_alias = "sub")]
     Subtitles(SubtitlesCmd),
 
+    /// Download the best subtitle as a WebVTT file
+    #[command(visible_alias = "gs")]
+    GetSubtitle(GetSubtitleCmd),
+
     /// List available Chromecast devices
     #[command(visible_alias = "dev")]
     Devices(DevicesCmd),
@@ -151,9 +245,16 @@ pub enum Command {
     /// Start casting content to a device
     Cast(CastCmd),
 
+    /// Search a movie, pick its best stream and cast it, in one go
+    #[command(visible_alias = "qc")]
+    Quickcast(QuickcastCmd),
+
     /// Get current playback status
     Status(StatusCmd),
 
+    /// Print playback status on every change until interrupted
+    Watch(WatchCmd),
+
     /// Resume playback
     Play(PlayCmd),
 
@@ -174,9 +275,31 @@ pub enum Command {
     #[command(visible_alias = "cm")]
     CastMagnet(CastMagnetCmd),
 
+    /// Resume the last-watched source of a title at its saved position
+    Resume(ResumeCmd),
+
+    /// Manage starred titles
+    #[command(visible_alias = "wl")]
+    Watchlist(WatchlistCmd),
+
+    /// Show or clear recent search queries
+    History(HistoryCmd),
+
+    /// Manage the on-disk TMDB response cache
+    Cache(CacheCmd),
+
+    /// Manage config profiles
+    Config(ConfigCmd),
+
     /// Play locally in VLC or mpv (no Chromecast)
     #[command(visible_alias = "pl")]
     PlayLocal(PlayLocalCmd),
+
+    /// Check that external tools (webtorrent, catt, VLC/mpv) are installed
+    Doctor(DoctorCmd),
+
+    /// Print a shell completion script to stdout
+    Completions(CompletionsCmd),
 }
 
 // =============================================================================
@@ -190,7 +313,7 @@ pub struct SearchCmd {
     #[arg(required = true)]
     pub query: String,
 
-    /// Maximum number of results
+    /// Maximum number of results (0 for no limit)
     #[arg(long, short = 'l', default_value = "20")]
     pub limit: usize,
 
@@ -205,6 +328,14 @@ pub struct SearchCmd {
     /// Maximum year
     #[arg(long)]
     pub year_to: Option<u16>,
+
+    /// Results page to fetch (1-based)
+    #[arg(long, short = 'p', default_value = "1")]
+    pub page: u32,
+
+    /// Keep adult titles (overrides `include_adult` in config)
+    #[arg(long)]
+    pub include_adult: bool,
 }
 
 /// Media type filter for search
@@ -216,6 +347,26 @@ pub enum MediaTypeFilter {
     Tv,
 }
 
+// =============================================================================
+// Person Command
+// =============================================================================
+
+/// Search for people by name
+#[derive(Args, Debug)]
+pub struct PersonCmd {
+    /// Person name (e.g., "cillian murphy")
+    #[arg(required = true)]
+    pub name: String,
+
+    /// Maximum number of people (0 for no limit)
+    #[arg(long, short = 'l', default_value = "5")]
+    pub limit: usize,
+
+    /// Filter known-for titles by media type
+    #[arg(long, short = 't', value_enum)]
+    pub media_type: Option<MediaTypeFilter>,
+}
+
 // =============================================================================
 // Trending Command
 // =============================================================================
@@ -227,7 +378,7 @@ pub struct TrendingCmd {
     #[arg(long, short = 'w', value_enum, default_value = "day")]
     pub window: TrendingWindow,
 
-    /// Maximum number of results
+    /// Maximum number of results (0 for no limit)
     #[arg(long, short = 'l', default_value = "20")]
     pub limit: usize,
 
@@ -246,6 +397,44 @@ pub enum TrendingWindow {
     Week,
 }
 
+// =============================================================================
+// Discover Command
+// =============================================================================
+
+/// Browse movies or TV shows in a genre
+#[derive(Args, Debug)]
+pub struct DiscoverCmd {
+    /// Genre name (e.g., action, comedy, sci-fi)
+    #[arg(long, short = 'g')]
+    pub genre: String,
+
+    /// Media type to browse
+    #[arg(long = "type", short = 't', value_enum, default_value = "movie")]
+    pub media_type: MediaTypeFilter,
+
+    /// Result ordering
+    #[arg(long, short = 's', value_enum, default_value = "popularity")]
+    pub sort: DiscoverOrder,
+
+    /// Maximum number of results (0 for no limit)
+    #[arg(long, short = 'l', default_value = "20")]
+    pub limit: usize,
+
+    /// Keep adult titles (overrides `include_adult` in config)
+    #[arg(long)]
+    pub include_adult: bool,
+}
+
+/// Ordering for discover results
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiscoverOrder {
+    /// Most popular first
+    #[default]
+    Popularity,
+    /// Highest rated first (ignores titles with few votes)
+    Rating,
+}
+
 // =============================================================================
 // Info Command
 // =============================================================================
@@ -262,6 +451,46 @@ pub struct InfoCmd {
     pub media_type: Option<MediaTypeFilter>,
 }
 
+// =============================================================================
+// Trailer Command
+// =============================================================================
+
+/// Print the YouTube URL of a title's trailer
+#[derive(Args, Debug)]
+pub struct TrailerCmd {
+    /// IMDB ID (e.g., tt1877830) or TMDB ID
+    #[arg(required = true)]
+    pub id: String,
+
+    /// ID type if using TMDB ID
+    #[arg(long, short = 't', value_enum)]
+    pub media_type: Option<MediaTypeFilter>,
+}
+
+// =============================================================================
+// Similar Command
+// =============================================================================
+
+/// List titles similar to a movie or show
+#[derive(Args, Debug)]
+pub struct SimilarCmd {
+    /// IMDB ID (e.g., tt1877830) or TMDB ID
+    #[arg(required = true)]
+    pub id: String,
+
+    /// ID type if using TMDB ID
+    #[arg(long, short = 't', value_enum)]
+    pub media_type: Option<MediaTypeFilter>,
+
+    /// Use TMDB's recommendations instead of similar titles
+    #[arg(long, short = 'r')]
+    pub recommended: bool,
+
+    /// Maximum number of results (0 for no limit)
+    #[arg(long, short = 'l', default_value = "20")]
+    pub limit: usize,
+}
+
 // =============================================================================
 // Streams Command
 // =============================================================================
@@ -281,17 +510,163 @@ pub struct StreamsCmd {
     #[arg(long, short = 'e')]
     pub episode: Option<u16>,
 
+    /// Treat --episode as an absolute number (anime listed as one long season)
+    #[arg(long, requires = "episode", conflicts_with = "season")]
+    pub absolute: bool,
+
+    /// Movie or TV show (`-t tv` without --episode finds season packs)
+    #[arg(long, short = 't', value_enum)]
+    pub media_type: Option<MediaTypeFilter>,
+
     /// Filter by minimum quality
     #[arg(long, short = 'Q', value_enum)]
     pub quality: Option<QualityFilter>,
 
-    /// Maximum number of results
+    /// Maximum number of results (0 for no limit)
     #[arg(long, short = 'l', default_value = "20")]
     pub limit: usize,
 
     /// Sort by criterion
     #[arg(long, value_enum, default_value = "seeds")]
     pub sort: StreamSort,
+
+    /// Only show HDR sources (HDR10, HDR10+, Dolby Vision)
+    #[arg(long)]
+    pub hdr: bool,
+
+    /// Only show sources encoded with this video codec
+    #[arg(long, value_enum)]
+    pub codec: Option<CodecFilter>,
+
+    /// Only sources from a release group containing this (case-insensitive)
+    #[arg(long, value_name = "NAME")]
+    pub group: Option<String>,
+
+    /// Keep duplicate listings of the same torrent from different providers
+    #[arg(long)]
+    pub no_dedup: bool,
+
+    /// Print only the top-scoring stream (weights from config.toml)
+    #[arg(long)]
+    pub best: bool,
+
+    /// Only ask these Torrentio providers, comma-separated (e.g. yts,eztv)
+    #[arg(long, value_delimiter = ',', value_name = "LIST")]
+    pub providers: Vec<String>,
+
+    /// Torrentio's own sort: quality, qualitysize, seeders or size
+    #[arg(long, value_name = "ORDER", value_parser = parse_torrentio_sort)]
+    pub torrentio_sort: Option<TorrentioSort>,
+
+    /// Only sources up to this size (e.g. 4GB, 700MB)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size_arg)]
+    pub max_size: Option<u64>,
+
+    /// Only sources at least this size (e.g. 700MB)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size_arg)]
+    pub min_size: Option<u64>,
+
+    /// Keep sources of unknown size when --max-size is set
+    #[arg(long)]
+    pub include_unknown_size: bool,
+
+    /// Drop sources with fewer seeds than this
+    #[arg(long, value_name = "N")]
+    pub min_seeds: Option<u32>,
+
+    /// Only well-seeded sources (same as --min-seeds 10)
+    #[arg(long, conflicts_with = "min_seeds")]
+    pub healthy: bool,
+}
+
+impl StreamsCmd {
+    /// Which Torrentio path to query (see [`stream_kind`])
+    pub fn stream_kind(&self) -> Result<StreamKind, String> {
+        match (self.absolute, self.episode) {
+            (true, Some(episode)) => match self.media_type {
+                Some(MediaTypeFilter::Movie) => {
+                    Err("--absolute only applies to TV shows".to_string())
+                }
+                _ => Ok(StreamKind::Series { season: 1, episode }),
+            },
+            _ => stream_kind(self.media_type, self.season, self.episode),
+        }
+    }
+
+    /// The config's Torrentio options with this command's flags on top
+    pub fn torrentio_config(&self, mut config: TorrentioConfig) -> TorrentioConfig {
+        if !self.providers.is_empty() {
+            config.providers = self.providers.clone();
+        }
+        if self.torrentio_sort.is_some() {
+            config.sort = self.torrentio_sort;
+        }
+        config
+    }
+
+    /// Seed floor from `--min-seeds` or `--healthy`
+    pub fn min_seeds(&self) -> Option<u32> {
+        self.min_seeds.or(self.healthy.then_some(HEALTHY_SEEDS))
+    }
+
+    /// Whether a source of `size_bytes` passes `--min-size`/`--max-size`
+    ///
+    /// An unknown size can't be shown to fit a cap, so it only passes one
+    /// with `--include-unknown-size`.
+    pub fn size_matches(&self, size_bytes: Option<u64>) -> bool {
+        match size_bytes {
+            Some(size) => {
+                self.min_size.is_none_or(|min| size >= min)
+                    && self.max_size.is_none_or(|max| size <= max)
+            }
+            None => self.max_size.is_none() || self.include_unknown_size,
+        }
+    }
+
+    /// Whether a source's release group passes `--group`
+    ///
+    /// Sources without a known group never match a filter.
+    pub fn group_matches(&self, release_group: Option<&str>) -> bool {
+        let Some(wanted) = &self.group else {
+            return true;
+        };
+        release_group.is_some_and(|group| group.to_lowercase().contains(&wanted.to_lowercase()))
+    }
+}
+
+/// Check a charset label (anything `encoding_rs` knows, e.g. "latin1", "utf-16")
+pub fn parse_encoding_arg(arg: &str) -> Result<String, String> {
+    match encoding_rs::Encoding::for_label(arg.trim().as_bytes()) {
+        Some(_) => Ok(arg.trim().to_string()),
+        None => Err(format!(
+            "unknown encoding '{}' (try windows-1252, latin1 or utf-16)",
+            arg
+        )),
+    }
+}
+
+/// Parse a size like "4GB", "1.5 gb" or "700MB" into bytes
+///
+/// Uses the same units as sizes in Torrentio titles ([`StreamSource::parse_size`]).
+pub fn parse_size_arg(arg: &str) -> Result<u64, String> {
+    let normalized = arg.trim().to_uppercase();
+    let whole = regex::Regex::new(r"^\d+(\.\d+)?\s*(GB|MB)$").expect("valid size regex");
+    match StreamSource::parse_size(&normalized) {
+        Some(bytes) if whole.is_match(&normalized) => Ok(bytes),
+        _ => Err(format!(
+            "invalid size '{}' (expected e.g. 4GB or 700MB)",
+            arg
+        )),
+    }
+}
+
+fn parse_torrentio_sort(value: &str) -> Result<TorrentioSort, String> {
+    TorrentioSort::from_name(value).ok_or_else(|| {
+        format!(
+            "unknown sort '{}' (quality, qualitysize, seeders, size)",
+            value
+        )
+    })
 }
 
 /// Quality filter for streams
@@ -311,6 +686,18 @@ pub enum QualityFilter {
     Q480p,
 }
 
+impl QualityFilter {
+    /// The stream quality this filter names
+    pub fn quality(self) -> Quality {
+        match self {
+            QualityFilter::Q4k => Quality::UHD4K,
+            QualityFilter::Q1080p => Quality::FHD1080p,
+            QualityFilter::Q720p => Quality::HD720p,
+            QualityFilter::Q480p => Quality::SD480p,
+        }
+    }
+}
+
 impl std::fmt::Display for QualityFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -322,6 +709,20 @@ impl std::fmt::Display for QualityFilter {
     }
 }
 
+/// Video codec filter for streams
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodecFilter {
+    /// H.264 / AVC
+    #[value(name = "x264", alias = "h264")]
+    X264,
+    /// H.265 / HEVC
+    #[value(name = "x265", alias = "hevc", alias = "h265")]
+    X265,
+    /// AV1
+    #[value(name = "av1")]
+    Av1,
+}
+
 /// Sort criterion for streams
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum StreamSort {
@@ -337,7 +738,7 @@ pub enum StreamSort {
 /// Local player selection
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PlayerChoice {
-    /// VLC media player (default)
+    /// VLC media player
     #[default]
     Vlc,
     /// mpv media player
@@ -375,7 +776,7 @@ pub struct SubtitlesCmd {
     #[arg(long)]
     pub trusted: bool,
 
-    /// Maximum number of results
+    /// Maximum number of results (0 for no limit)
     #[arg(long, default_value = "20")]
     pub limit: usize,
 }
@@ -387,6 +788,49 @@ impl SubtitlesCmd {
     }
 }
 
+/// Download the most trusted subtitle, converted to WebVTT
+#[derive(Args, Debug)]
+pub struct GetSubtitleCmd {
+    /// IMDB ID (e.g., tt1877830)
+    #[arg(required = true)]
+    pub imdb_id: String,
+
+    /// Language code (e.g., eng, spa)
+    #[arg(long, short = 'l', default_value = "eng")]
+    pub lang: String,
+
+    /// Season number (for TV shows)
+    #[arg(long, short = 's')]
+    pub season: Option<u8>,
+
+    /// Episode number (for TV shows)
+    #[arg(long, short = 'e')]
+    pub episode: Option<u16>,
+
+    /// File or directory to write to (default: current directory)
+    #[arg(long, short = 'o', value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+impl GetSubtitleCmd {
+    /// Default file name, e.g. "tt0903747.S01E02.eng.vtt"
+    pub fn file_name(&self) -> String {
+        match (self.season, self.episode) {
+            (Some(s), Some(e)) => format!("{}.S{:02}E{:02}.{}.vtt", self.imdb_id, s, e, self.lang),
+            _ => format!("{}.{}.vtt", self.imdb_id, self.lang),
+        }
+    }
+
+    /// Where the file goes: `--output` (a directory gets the default name) or the CWD
+    pub fn target_path(&self) -> PathBuf {
+        match &self.output {
+            Some(path) if path.is_dir() => path.join(self.file_name()),
+            Some(path) => path.clone(),
+            None => PathBuf::from(self.file_name()),
+        }
+    }
+}
+
 // =============================================================================
 // Devices Command
 // =============================================================================
@@ -422,7 +866,7 @@ pub struct CastCmd {
     #[arg(long, short = 'Q', value_enum)]
     pub quality: Option<QualityFilter>,
 
-    /// Season number (for TV shows)
+    /// Season number (for TV shows; without --episode the whole season is cast)
     #[arg(long, short = 's')]
     pub season: Option<u8>,
 
@@ -430,10 +874,18 @@ pub struct CastCmd {
     #[arg(long, short = 'e')]
     pub episode: Option<u16>,
 
+    /// Movie or TV show (`-t tv` without --episode finds season packs)
+    #[arg(long, short = 't', value_enum)]
+    pub media_type: Option<MediaTypeFilter>,
+
     /// Stream index from `streams` output
     #[arg(long, short = 'i')]
     pub index: Option<usize>,
 
+    /// Pick the top-scoring stream (same as `streams --best`)
+    #[arg(long, conflicts_with = "index")]
+    pub auto: bool,
+
     /// Subtitle language code (e.g., "en")
     #[arg(long)]
     pub subtitle: Option<String>,
@@ -457,6 +909,22 @@ pub struct CastCmd {
     /// Play locally in VLC instead of casting
     #[arg(long)]
     pub vlc: bool,
+
+    /// Report download progress and exit once the stream is ready
+    #[arg(long)]
+    pub wait: bool,
+
+    /// With --wait, give up if no peers show up within this many seconds
+    #[arg(long, value_name = "SECS", default_value_t = 60, requires = "wait")]
+    pub peer_timeout: u64,
+
+    /// Skip sources with fewer seeds than this (applied before --index)
+    #[arg(long, value_name = "N")]
+    pub min_seeds: Option<u32>,
+
+    /// Only well-seeded sources (same as --min-seeds 10)
+    #[arg(long, conflicts_with = "min_seeds")]
+    pub healthy: bool,
 }
 
 impl CastCmd {
@@ -464,6 +932,137 @@ impl CastCmd {
     pub fn effective_device<'a>(&'a self, global: &'a Option<String>) -> Option<&'a str> {
         self.device.as_deref().or(global.as_deref())
     }
+
+    /// Seed floor from `--min-seeds` or `--healthy`
+    pub fn min_seeds(&self) -> Option<u32> {
+        self.min_seeds.or(self.healthy.then_some(HEALTHY_SEEDS))
+    }
+
+    /// Which Torrentio path to query (see [`stream_kind`])
+    pub fn stream_kind(&self) -> Result<StreamKind, String> {
+        stream_kind(self.media_type, self.season, self.episode)
+    }
+}
+
+/// Torrentio path for `-t`, `--season` and `--episode`
+///
+/// Without `-t`, only a season and an episode together mean a series.
+/// `-t tv` fills in the rest with season 1, episode 1; Torrentio lists
+/// season packs under each of their episodes, so that still finds them.
+/// `-t movie` has no seasons, so `--season`/`--episode` are refused.
+pub fn stream_kind(
+    media_type: Option<MediaTypeFilter>,
+    season: Option<u8>,
+    episode: Option<u16>,
+) -> Result<StreamKind, String> {
+    match media_type {
+        Some(MediaTypeFilter::Movie) if season.is_some() || episode.is_some() => {
+            Err("--season/--episode only apply to TV shows, not -t movie".to_string())
+        }
+        Some(MediaTypeFilter::Movie) => Ok(StreamKind::Movie),
+        Some(MediaTypeFilter::Tv) => Ok(StreamKind::Series {
+            season: season.unwrap_or(1) as u16,
+            episode: episode.unwrap_or(1),
+        }),
+        None => match (season, episode) {
+            (Some(season), Some(episode)) => Ok(StreamKind::Series {
+                season: season as u16,
+                episode,
+            }),
+            _ => Ok(StreamKind::Movie),
+        },
+    }
+}
+
+// =============================================================================
+// Quickcast Command
+// =============================================================================
+
+/// Search, pick the best stream and cast without any prompts
+#[derive(Args, Debug)]
+pub struct QuickcastCmd {
+    /// Movie to search for (the top movie result is cast)
+    #[arg(required = true)]
+    pub query: String,
+
+    /// Target device name (overrides --device global flag)
+    #[arg(long, short = 'd')]
+    pub device: Option<String>,
+
+    /// Preferred quality (default: the top-scoring stream)
+    #[arg(long, short = 'Q', value_enum)]
+    pub quality: Option<QualityFilter>,
+}
+
+impl QuickcastCmd {
+    /// The `cast` run for the movie the search found
+    ///
+    /// Without `--quality` the top-scoring stream is picked (`cast --auto`).
+    pub fn to_cast(&self, imdb_id: String) -> CastCmd {
+        CastCmd {
+            imdb_id,
+            device: self.device.clone(),
+            quality: self.quality,
+            season: None,
+            episode: None,
+            media_type: Some(MediaTypeFilter::Movie),
+            index: None,
+            auto: self.quality.is_none(),
+            subtitle: None,
+            subtitle_id: None,
+            no_subtitle: false,
+            subtitle_file: None,
+            start: None,
+            vlc: false,
+            wait: false,
+            peer_timeout: 60,
+            min_seeds: None,
+            healthy: false,
+        }
+    }
+}
+
+// =============================================================================
+// Magnet Command
+// =============================================================================
+
+/// Print the magnet link of the stream `cast` would pick
+#[derive(Args, Debug)]
+pub struct MagnetCmd {
+    /// IMDB ID (e.g., tt1877830)
+    #[arg(required = true)]
+    pub imdb_id: String,
+
+    /// Preferred quality
+    #[arg(long, short = 'Q', value_enum)]
+    pub quality: Option<QualityFilter>,
+
+    /// Season number (for TV shows)
+    #[arg(long, short = 's', requires = "episode")]
+    pub season: Option<u8>,
+
+    /// Episode number (for TV shows)
+    #[arg(long, short = 'e', requires = "season")]
+    pub episode: Option<u16>,
+
+    /// Movie or TV show (`-t tv` without --episode finds season packs)
+    #[arg(long, short = 't', value_enum)]
+    pub media_type: Option<MediaTypeFilter>,
+
+    /// Stream index from `streams` output
+    #[arg(long, short = 'i')]
+    pub index: Option<usize>,
+
+    /// Pick the top-scoring stream (same as `streams --best`)
+    #[arg(long, conflicts_with = "index")]
+    pub best: bool,
+}
+
+impl MagnetCmd {
+    /// Which Torrentio path to query (see [`stream_kind`])
+    pub fn stream_kind(&self) -> Result<StreamKind, String> {
+        stream_kind(self.media_type, self.season, self.episode)
+    }
 }
 
 // =============================================================================
@@ -473,7 +1072,7 @@ impl CastCmd {
 /// Cast a raw magnet link directly to a Chromecast device
 #[derive(Args, Debug)]
 pub struct CastMagnetCmd {
-    /// Magnet link URL
+    /// Magnet link, bare info hash (hex or base32) or path to a .torrent file
     #[arg(required = true)]
     pub magnet: String,
 
@@ -500,6 +1099,10 @@ pub struct CastMagnetCmd {
     /// Play locally in VLC instead of casting
     #[arg(long)]
     pub vlc: bool,
+
+    /// Play locally in mpv instead of casting
+    #[arg(long, conflicts_with = "vlc")]
+    pub mpv: bool,
 }
 
 impl CastMagnetCmd {
@@ -509,6 +1112,164 @@ impl CastMagnetCmd {
     }
 }
 
+// =============================================================================
+// Resume Command
+// =============================================================================
+
+/// Replay the last-watched source of a title from its saved position
+#[derive(Args, Debug)]
+pub struct ResumeCmd {
+    /// IMDB ID (e.g., tt1877830)
+    #[arg(required = true)]
+    pub imdb_id: String,
+
+    /// Target device name (overrides --device global flag)
+    #[arg(long, short = 'd')]
+    pub device: Option<String>,
+
+    /// Play locally in VLC instead of casting
+    #[arg(long)]
+    pub vlc: bool,
+
+    /// Play locally in mpv instead of casting
+    #[arg(long, conflicts_with = "vlc")]
+    pub mpv: bool,
+}
+
+// =============================================================================
+// Watchlist Command
+// =============================================================================
+
+/// Manage the watchlist stored in config.toml
+#[derive(Args, Debug)]
+pub struct WatchlistCmd {
+    #[command(subcommand)]
+    pub action: WatchlistAction,
+}
+
+/// Watchlist subcommands
+#[derive(Subcommand, Debug)]
+pub enum WatchlistAction {
+    /// List saved titles
+    List,
+
+    /// Add a title by TMDB ID
+    Add {
+        /// TMDB ID
+        id: u64,
+
+        /// Media type of the ID
+        #[arg(long, short = 't', value_enum)]
+        media_type: Option<MediaTypeFilter>,
+    },
+
+    /// Remove a title by TMDB ID
+    Remove {
+        /// TMDB ID
+        id: u64,
+
+        /// Only remove this media type
+        #[arg(long, short = 't', value_enum)]
+        media_type: Option<MediaTypeFilter>,
+    },
+}
+
+// =============================================================================
+// History Command
+// =============================================================================
+
+/// Recent activity stored in config.toml
+#[derive(Args, Debug)]
+pub struct HistoryCmd {
+    #[command(subcommand)]
+    pub action: HistoryAction,
+}
+
+/// History subcommands
+#[derive(Subcommand, Debug)]
+pub enum HistoryAction {
+    /// List recent search queries, most recent first
+    Search {
+        /// Forget all recent searches
+        #[arg(long)]
+        clear: bool,
+    },
+}
+
+// =============================================================================
+// Cache Command
+// =============================================================================
+
+/// Manage cached TMDB responses (~/.cache/streamtui/tmdb)
+#[derive(Args, Debug)]
+pub struct CacheCmd {
+    #[command(subcommand)]
+    pub action: CacheAction,
+}
+
+/// Cache subcommands
+#[derive(Subcommand, Debug)]
+pub enum CacheAction {
+    /// Delete all cached responses
+    Clear,
+}
+
+// =============================================================================
+// Config Command
+// =============================================================================
+
+/// Manage config.toml
+#[derive(Args, Debug)]
+pub struct ConfigCmd {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+/// Config subcommands
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+    /// Manage named profiles (`[profiles.<name>]`)
+    Profile {
+        #[command(subcommand)]
+        action: ProfileAction,
+    },
+
+    /// Print a setting, e.g. `config get theme`
+    Get {
+        /// Setting name as in config.toml
+        key: String,
+    },
+
+    /// Change a setting and save it (lists are comma-separated)
+    Set {
+        /// Setting name as in config.toml
+        key: String,
+        value: String,
+    },
+
+    /// Remove a setting so its default applies again
+    Unset {
+        /// Setting name as in config.toml
+        key: String,
+    },
+
+    /// Print where config.toml is read from
+    Path,
+}
+
+/// Profile subcommands
+#[derive(Subcommand, Debug)]
+pub enum ProfileAction {
+    /// List profiles and show which one is active
+    List,
+
+    /// Load this profile by default (`default` goes back to the top-level settings)
+    Use {
+        /// Profile name
+        name: String,
+    },
+}
+
 // =============================================================================
 // Play Local Command
 // =============================================================================
@@ -516,13 +1277,13 @@ impl CastMagnetCmd {
 /// Play a magnet link locally in VLC or mpv
 #[derive(Args, Debug)]
 pub struct PlayLocalCmd {
-    /// Magnet link URL
+    /// Magnet link, bare info hash (hex or base32) or path to a .torrent file
     #[arg(required = true)]
     pub magnet: String,
 
-    /// Player to use (vlc or mpv)
-    #[arg(long, short = 'p', value_enum, default_value = "vlc")]
-    pub player: PlayerChoice,
+    /// Player to use (default: `default_local_player` from config, else whichever is installed)
+    #[arg(long, short = 'p', value_enum)]
+    pub player: Option<PlayerChoice>,
 
     /// Path to a local subtitle file (.srt, .vtt)
     #[arg(long)]
@@ -531,6 +1292,31 @@ pub struct PlayLocalCmd {
     /// File index within the torrent (default: largest video file)
     #[arg(long, short = 'i')]
     pub file_idx: Option<u32>,
+
+    /// Start position in seconds
+    #[arg(long)]
+    pub start: Option<u64>,
+}
+
+// =============================================================================
+// Doctor Command
+// =============================================================================
+
+/// Report which external tools are installed, with their versions
+#[derive(Args, Debug)]
+pub struct DoctorCmd {}
+
+// =============================================================================
+// Completions Command
+// =============================================================================
+
+/// Generate a completion script, e.g.
+/// `streamtui completions zsh > ~/.zfunc/_streamtui`
+#[derive(Args, Debug)]
+pub struct CompletionsCmd {
+    /// Shell to generate completions for
+    #[arg(value_enum)]
+    pub shell: clap_complete::Shell,
 }
 
 // =============================================================================
@@ -547,6 +1333,22 @@ pub struct StatusCmd {
     /// Update interval in seconds (for watch mode)
     #[arg(long, short = 'i', default_value = "1")]
     pub interval: u64,
+
+    /// List the torrent streams streamtui is running instead
+    #[arg(long, conflicts_with = "watch")]
+    pub sessions: bool,
+}
+
+/// Poll playback status, printing each change
+#[derive(Args, Debug)]
+pub struct WatchCmd {
+    /// Poll interval in seconds
+    #[arg(long, short = 'i', default_value = "2")]
+    pub interval: u64,
+
+    /// Exit once playback ends (the device goes idle after playing)
+    #[arg(long)]
+    pub until_idle: bool,
 }
 
 /// Resume playback
@@ -563,26 +1365,36 @@ pub struct StopCmd {
     /// Also stop the torrent stream
     #[arg(long)]
     pub kill_stream: bool,
+
+    /// Only stop the torrent stream with this info hash (implies --kill-stream)
+    #[arg(long, value_name = "HASH")]
+    pub info_hash: Option<String>,
 }
 
 /// Seek to a position in playback
 #[derive(Args, Debug)]
 pub struct SeekCmd {
-    /// Target position in seconds, or relative (+/-) seconds
-    #[arg(required = true)]
+    /// Target position in seconds, relative (+/-) seconds, or relative
+    /// seek steps (+2x, -x)
+    #[arg(required = true, allow_hyphen_values = true)]
     pub position: String,
 }
 
 impl SeekCmd {
-    /// Parse the position argument
+    /// Parse the position argument (steps of the default 30s)
     pub fn parse_position(&self) -> SeekPosition {
+        self.parse_position_with_step(DEFAULT_SEEK_STEP)
+    }
+
+    /// Parse the position argument, with `x` steps of `step` seconds
+    pub fn parse_position_with_step(&self, step: u32) -> SeekPosition {
         let s = self.position.trim();
         if let Some(stripped) = s.strip_prefix('+') {
-            if let Ok(secs) = stripped.parse::<i64>() {
+            if let Some(secs) = relative_secs(stripped, step) {
                 return SeekPosition::Forward(secs);
             }
         } else if let Some(stripped) = s.strip_prefix('-') {
-            if let Ok(secs) = stripped.parse::<i64>() {
+            if let Some(secs) = relative_secs(stripped, step) {
                 return SeekPosition::Backward(secs);
             }
         } else if let Ok(secs) = s.parse::<u64>() {
@@ -596,6 +1408,15 @@ impl SeekCmd {
     }
 }
 
+/// Seconds in a relative seek: "45", or a step count "3x" ("x" is one step)
+fn relative_secs(s: &str, step: u32) -> Option<i64> {
+    match s.strip_suffix(['x', 'X']) {
+        Some("") => Some(step as i64),
+        Some(steps) => steps.parse::<i64>().ok()?.checked_mul(step as i64),
+        None => s.parse().ok(),
+    }
+}
+
 /// Parsed seek position
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SeekPosition {
@@ -609,10 +1430,13 @@ pub enum SeekPosition {
     Invalid(String),
 }
 
-/// Parse timestamp string (HH:MM:SS or MM:SS) to seconds
-fn parse_timestamp(s: &str) -> Option<u64> {
-    let parts: Vec<&str> = s.split(':').collect();
+/// Parse timestamp string (SS, MM:SS or HH:MM:SS) to seconds
+///
+/// Shared by the `seek` command and the TUI seek-to prompt.
+pub fn parse_timestamp(s: &str) -> Option<u64> {
+    let parts: Vec<&str> = s.trim().split(':').collect();
     match parts.len() {
+        1 => parts[0].parse().ok(),
         2 => {
             let mins: u64 = parts[0].parse().ok()?;
             let secs: u64 = parts[1].parse().ok()?;
@@ -666,6 +1490,24 @@ pub enum VolumeLevel {
     Invalid(String),
 }
 
+impl VolumeLevel {
+    /// catt arguments for this level (`volume N`, `volumeup N` or `volumedown N`)
+    ///
+    /// Returns None for invalid levels.
+    pub fn catt_args(&self) -> Option<Vec<String>> {
+        match self {
+            VolumeLevel::Absolute(vol) => Some(vec!["volume".into(), vol.to_string()]),
+            VolumeLevel::Relative(delta) if *delta >= 0 => {
+                Some(vec!["volumeup".into(), delta.to_string()])
+            }
+            VolumeLevel::Relative(delta) => {
+                Some(vec!["volumedown".into(), delta.unsigned_abs().to_string()])
+            }
+            VolumeLevel::Invalid(_) => None,
+        }
+    }
+}
+
 // =============================================================================
 // JSON Output Types
 // =============================================================================
@@ -705,6 +1547,30 @@ impl<T: Serialize> JsonOutput<T> {
     }
 }
 
+/// Version of the `--json` envelope; bump when a `kind`'s data changes shape
+///
+/// 2: `status` has `position_secs`/`duration_secs` (was `position`/`duration`)
+/// and `streaming`.
+pub const JSON_SCHEMA: u32 = 2;
+
+/// Versioned `--json` output: `{ "schema": 2, "kind": "streams", "data": [...] }`
+#[derive(Debug, Serialize)]
+pub struct JsonEnvelope<'a, T: Serialize> {
+    pub schema: u32,
+    pub kind: &'a str,
+    pub data: &'a T,
+}
+
+impl<'a, T: Serialize> JsonEnvelope<'a, T> {
+    pub fn new(kind: &'a str, data: &'a T) -> Self {
+        Self {
+            schema: JSON_SCHEMA,
+            kind,
+            data,
+        }
+    }
+}
+
 /// Status OK response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StatusOk {
@@ -726,13 +1592,16 @@ pub struct PlaybackStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub device: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub position: Option<u64>,
+    pub position_secs: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration: Option<u64>,
+    pub duration_secs: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub progress: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub volume: Option<u8>,
+    /// A webtorrent process streamtui started is still running
+    #[serde(default)]
+    pub streaming: bool,
 }
 
 /// Playback state enum
@@ -747,16 +1616,119 @@ pub enum PlaybackState {
     Error,
 }
 
+impl PlaybackState {
+    /// Name as serialized ("playing")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Buffering => "buffering",
+            Self::Playing => "playing",
+            Self::Paused => "paused",
+            Self::Stopped => "stopped",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl PlaybackStatus {
+    /// Status from a finished `catt status` run
+    ///
+    /// A Chromecast that's on but has nothing loaded makes catt exit non-zero
+    /// ("Chromecast is inactive"); that's an idle status, not an error. Only
+    /// a missing or unreachable device is `DeviceNotFound`. `device` and
+    /// `streaming` are left for the caller.
+    pub fn from_catt(
+        exit_ok: bool,
+        stdout: &str,
+        stderr: &str,
+    ) -> Result<Self, (String, ExitCode)> {
+        if !exit_ok {
+            let reason = stderr.trim();
+            let lower = reason.to_lowercase();
+            let has = |needles: &[&str]| needles.iter().any(|n| lower.contains(n));
+            if has(&[
+                "inactive",
+                "nothing is currently playing",
+                "nothing is playing",
+            ]) {
+                return Ok(Self::default());
+            }
+            if has(&[
+                "not found",
+                "could not connect",
+                "connection",
+                "timed out",
+                "unreachable",
+                "no route",
+            ]) {
+                return Err((
+                    format!("Device unreachable: {}", reason),
+                    ExitCode::DeviceNotFound,
+                ));
+            }
+            return Err((
+                format!("catt status failed: {}", reason),
+                ExitCode::CastFailed,
+            ));
+        }
+
+        let Some(status) = crate::models::PlaybackStatus::parse_catt_status(stdout) else {
+            // Nothing loaded on the device
+            return Ok(Self::default());
+        };
+        Ok(Self {
+            state: match status.state {
+                CastState::Idle => PlaybackState::Idle,
+                CastState::Buffering | CastState::Connecting => PlaybackState::Buffering,
+                CastState::Playing => PlaybackState::Playing,
+                CastState::Paused => PlaybackState::Paused,
+                CastState::Stopped => PlaybackState::Stopped,
+                CastState::Error(_) => PlaybackState::Error,
+            },
+            progress: Some(status.progress() as f64),
+            position_secs: Some(status.position.as_secs()),
+            duration_secs: Some(status.duration.as_secs()),
+            volume: Some((status.volume * 100.0) as u8),
+            title: status.title,
+            ..Self::default()
+        })
+    }
+
+    /// One line for `watch`: "playing  The Batman  0:20:34 / 2:56:00  vol 80%"
+    pub fn summary(&self) -> String {
+        let mut line = self.state.as_str().to_string();
+        if let Some(title) = &self.title {
+            line.push_str(&format!("  {}", title));
+        }
+        if let (Some(position), Some(duration)) = (self.position_secs, self.duration_secs) {
+            line.push_str(&format!("  {} / {}", clock(position), clock(duration)));
+        }
+        if let Some(volume) = self.volume {
+            line.push_str(&format!("  vol {}%", volume));
+        }
+        if self.streaming {
+            line.push_str("  [streaming]");
+        }
+        line
+    }
+}
+
+/// Seconds as H:MM:SS
+fn clock(secs: u64) -> String {
+    format!("{}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60)
+}
+
 impl Default for PlaybackStatus {
     fn default() -> Self {
         Self {
             state: PlaybackState::Idle,
             title: None,
             device: None,
-            position: None,
-            duration: None,
+            position_secs: None,
+            duration_secs: None,
             progress: None,
             volume: None,
+            streaming: false,
         }
     }
 }
@@ -781,14 +1753,52 @@ pub struct CastResponse {
 pub struct Output {
     pub json: bool,
     pub quiet: bool,
+    /// Skip the schema envelope in JSON mode
+    pub raw: bool,
+    /// One line per item for lists (`--format line`)
+    pub line: bool,
 }
 
 impl Output {
     pub fn new(cli: &Cli) -> Self {
+        let format = cli.output_format();
         Self {
-            json: cli.should_json(),
+            json: format == OutputFormat::Json,
             quiet: cli.quiet,
+            raw: cli.raw_json,
+            line: format == OutputFormat::Line,
+        }
+    }
+
+    /// Print a list: one [`CompactLine`] per item with `--format line`,
+    /// otherwise as [`Self::print_versioned`]
+    pub fn print_list<T: Serialize + CompactLine>(
+        &self,
+        kind: &str,
+        items: &[T],
+    ) -> anyhow::Result<()> {
+        if self.line {
+            for item in items {
+                println!("{}", item.compact_line());
+            }
+            return Ok(());
         }
+        self.print_versioned(kind, &items)
+    }
+
+    /// Print success data, wrapped in a [`JsonEnvelope`] tagged with `kind` in
+    /// JSON mode (bare with `--raw-json`)
+    pub fn print_versioned<T: Serialize>(&self, kind: &str, data: &T) -> anyhow::Result<()> {
+        if self.json && !self.raw {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&JsonEnvelope::new(kind, data))?
+            );
+        } else {
+            // For non-JSON, caller should handle formatting
+            println!("{}", serde_json::to_string_pretty(data)?);
+        }
+        Ok(())
     }
 
     /// Print success data
@@ -803,6 +1813,16 @@ impl Output {
         Ok(())
     }
 
+    /// Print one compact JSON line per event (for progress streams)
+    pub fn print_event<T: Serialize>(&self, kind: &str, data: &T) -> anyhow::Result<()> {
+        if self.raw {
+            println!("{}", serde_json::to_string(data)?);
+        } else {
+            println!("{}", serde_json::to_string(&JsonEnvelope::new(kind, data))?);
+        }
+        Ok(())
+    }
+
     /// Print raw JSON (already formatted)
     pub fn print_json<T: Serialize>(&self, data: &T) -> anyhow::Result<()> {
         println!("{}", serde_json::to_string_pretty(data)?);
@@ -824,6 +1844,9 @@ impl Output {
     }
 
     /// Print info message (suppressed in quiet mode)
+    ///
+    /// Goes to stderr so stdout only ever carries results; dropped entirely
+    /// with `--quiet` or in JSON mode.
     pub fn info(&self, msg: impl std::fmt::Display) {
         if !self.quiet && !self.json {
             eprintln!("{}", msg);
@@ -831,6 +1854,99 @@ impl Output {
     }
 }
 
+// =============================================================================
+// Compact Lines
+// =============================================================================
+
+/// One terse, space-separated line for `--format line`
+///
+/// Unknown values print as `-` so columns stay put, and free text (titles,
+/// names) comes last since it may contain spaces.
+pub trait CompactLine {
+    fn compact_line(&self) -> String;
+}
+
+/// `id media_type year rating title`
+impl CompactLine for SearchResult {
+    fn compact_line(&self) -> String {
+        format!(
+            "{} {} {} {:.1} {}",
+            self.id,
+            media_word(self.media_type),
+            or_dash(self.year),
+            self.vote_average,
+            self.title
+        )
+    }
+}
+
+/// `quality seeds size group provider`, e.g. `1080p 1500s 8.5GB SPARKS Torrentio`
+impl CompactLine for StreamSource {
+    fn compact_line(&self) -> String {
+        format!(
+            "{} {}s {} {} {}",
+            self.quality,
+            self.seeds,
+            compact_size(self.size_bytes),
+            or_dash(self.release_group.as_deref()),
+            or_dash(Some(self.provider.as_str()).filter(|p| !p.is_empty()))
+        )
+    }
+}
+
+/// `id language downloads trusted release`
+impl CompactLine for SubtitleResult {
+    fn compact_line(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.id,
+            self.language,
+            self.downloads,
+            if self.from_trusted { "trusted" } else { "-" },
+            self.release
+        )
+    }
+}
+
+/// `address:port name`
+impl CompactLine for CastDevice {
+    fn compact_line(&self) -> String {
+        format!("{}:{} {}", self.address, self.port, self.name)
+    }
+}
+
+/// `id media_type title`
+impl CompactLine for WatchlistEntry {
+    fn compact_line(&self) -> String {
+        format!("{} {} {}", self.id, media_word(self.media_type), self.title)
+    }
+}
+
+/// "movie" or "tv", as in the JSON output
+fn media_word(media_type: MediaType) -> &'static str {
+    match media_type {
+        MediaType::Movie => "movie",
+        MediaType::Tv => "tv",
+    }
+}
+
+/// A value, or `-` when there's none
+fn or_dash<T: std::fmt::Display>(value: Option<T>) -> String {
+    value
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// "8.5GB" / "700MB" (no space, unlike [`StreamSource::format_size`])
+fn compact_size(bytes: Option<u64>) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    match bytes {
+        Some(b) if b as f64 >= 1024.0 * MB => format!("{:.1}GB", b as f64 / (1024.0 * MB)),
+        Some(b) => format!("{:.0}MB", b as f64 / MB),
+        None => "-".to_string(),
+    }
+}
+
 // =============================================================================
 // IMDB ID Validation
 // =============================================================================
@@ -844,6 +1960,18 @@ pub fn validate_imdb_id(id: &str) -> Result<&str, &'static str> {
     }
 }
 
+// =============================================================================
+// Result Limits
+// =============================================================================
+
+/// Keep the first `limit` items (`--limit`); 0 keeps them all
+pub fn apply_limit<T>(mut items: Vec<T>, limit: usize) -> Vec<T> {
+    if limit > 0 {
+        items.truncate(limit);
+    }
+    items
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -892,6 +2020,26 @@ mod tests {
         assert_eq!(cli.device.as_deref(), Some("Living Room TV"));
     }
 
+    #[test]
+    fn test_device_ip_overrides_device() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "-d",
+            "TV",
+            "status",
+            "--device-ip",
+            "192.168.1.36",
+        ]);
+        assert_eq!(cli.device_target().as_deref(), Some("192.168.1.36"));
+
+        let cli = Cli::parse_from(["streamtui", "-d", "TV", "status"]);
+        assert_eq!(cli.device_target().as_deref(), Some("TV"));
+
+        assert!(
+            Cli::try_parse_from(["streamtui", "--device-ip", "living-room", "status"]).is_err()
+        );
+    }
+
     #[test]
     fn test_cast_with_options() {
         let cli = Cli::parse_from([
@@ -944,6 +2092,37 @@ mod tests {
             position: "5:30".to_string(),
         };
         assert_eq!(cmd.parse_position(), SeekPosition::Absolute(330));
+
+        let cmd = SeekCmd {
+            position: "+2x".to_string(),
+        };
+        assert_eq!(cmd.parse_position(), SeekPosition::Forward(60));
+        assert_eq!(cmd.parse_position_with_step(10), SeekPosition::Forward(20));
+
+        let cmd = SeekCmd {
+            position: "-x".to_string(),
+        };
+        assert_eq!(cmd.parse_position_with_step(15), SeekPosition::Backward(15));
+
+        let cmd = SeekCmd {
+            position: "+2.5x".to_string(),
+        };
+        assert!(matches!(cmd.parse_position(), SeekPosition::Invalid(_)));
+    }
+
+    #[test]
+    fn test_parse_timestamp_forms() {
+        assert_eq!(parse_timestamp("90"), Some(90));
+        assert_eq!(parse_timestamp("1:30"), Some(90));
+        assert_eq!(parse_timestamp("1:23:45"), Some(5025));
+        assert_eq!(parse_timestamp("1:2:3:4"), None);
+        assert_eq!(parse_timestamp("1:xx"), None);
+        assert_eq!(parse_timestamp(""), None);
+
+        let cmd = SeekCmd {
+            position: "1:23:45".to_string(),
+        };
+        assert_eq!(cmd.parse_position(), SeekPosition::Absolute(5025));
     }
 
     #[test]
@@ -970,6 +2149,23 @@ mod tests {
         assert_eq!(cmd.parse_level(), VolumeLevel::Absolute(100));
     }
 
+    #[test]
+    fn test_volume_catt_args() {
+        assert_eq!(
+            VolumeLevel::Absolute(50).catt_args(),
+            Some(vec!["volume".to_string(), "50".to_string()])
+        );
+        assert_eq!(
+            VolumeLevel::Relative(10).catt_args(),
+            Some(vec!["volumeup".to_string(), "10".to_string()])
+        );
+        assert_eq!(
+            VolumeLevel::Relative(-5).catt_args(),
+            Some(vec!["volumedown".to_string(), "5".to_string()])
+        );
+        assert_eq!(VolumeLevel::Invalid("loud".into()).catt_args(), None);
+    }
+
     #[test]
     fn test_validate_imdb_id() {
         assert!(validate_imdb_id("tt1877830").is_ok());
@@ -1017,6 +2213,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_streams_absolute_episode() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "streams",
+            "tt0388629",
+            "-e",
+            "1000",
+            "--absolute",
+        ]);
+        if let Some(Command::Streams(cmd)) = cli.command {
+            assert!(cmd.absolute);
+            assert_eq!(cmd.episode, Some(1000));
+        } else {
+            panic!("Expected Streams command");
+        }
+        // Needs an episode and can't be mixed with a season
+        assert!(Cli::try_parse_from(["streamtui", "streams", "tt0388629", "--absolute"]).is_err());
+        assert!(Cli::try_parse_from([
+            "streamtui",
+            "streams",
+            "tt0388629",
+            "-s",
+            "2",
+            "-e",
+            "3",
+            "--absolute"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_magnet_command() {
+        let cli = Cli::parse_from(["streamtui", "magnet", "tt0240772", "-Q", "1080p", "--best"]);
+        if let Some(Command::Magnet(cmd)) = cli.command {
+            assert_eq!(cmd.imdb_id, "tt0240772");
+            assert!(cmd.best);
+            assert!(cmd.quality.is_some());
+        } else {
+            panic!("Expected Magnet command");
+        }
+        assert!(
+            Cli::try_parse_from(["streamtui", "magnet", "tt0240772", "-i", "2", "--best"]).is_err()
+        );
+        assert!(Cli::try_parse_from(["streamtui", "magnet", "tt0903747", "-s", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_seek_backward_steps_parse() {
+        let cli = Cli::parse_from(["streamtui", "-d", "TV", "seek", "-2x"]);
+        if let Some(Command::Seek(cmd)) = cli.command {
+            assert_eq!(cmd.parse_position_with_step(10), SeekPosition::Backward(20));
+        } else {
+            panic!("Expected Seek command");
+        }
+        let cli = Cli::parse_from(["streamtui", "seek", "-d", "TV", "+30"]);
+        assert_eq!(cli.device.as_deref(), Some("TV"));
+    }
+
     #[test]
     fn test_exit_codes() {
         assert_eq!(i32::from(ExitCode::Success), 0);
@@ -5,16 +5,28 @@
 
 use serde::Serialize;
 
+use crate::api::tmdb::TmdbError;
+use crate::api::torrentio::StreamKind;
 use crate::api::{TmdbClient, TorrentioClient};
 use crate::cli::{
-    CastCmd, CastMagnetCmd, DevicesCmd, ExitCode, InfoCmd, MediaTypeFilter, Output, PauseCmd,
-    PlayCmd, PlayLocalCmd, PlaybackState, PlaybackStatus, PlayerChoice, SearchCmd, SeekCmd,
-    SeekPosition, StatusCmd, StopCmd, StreamsCmd, SubtitlesCmd, TrendingCmd, TrendingWindow,
-    VolumeCmd, VolumeLevel,
+    apply_limit, CacheAction, CacheCmd, CastCmd, CastMagnetCmd, CompactLine, ConfigAction,
+    ConfigCmd, DevicesCmd, DiscoverCmd, DiscoverOrder, DoctorCmd, ExitCode, GetSubtitleCmd,
+    HistoryAction, HistoryCmd, InfoCmd, MagnetCmd, MediaTypeFilter, Output, PauseCmd, PersonCmd,
+    PlayCmd, PlayLocalCmd, PlaybackState, PlaybackStatus, PlayerChoice, ProfileAction,
+    QualityFilter, QuickcastCmd, ResumeCmd, SearchCmd, SeekCmd, SeekPosition, SimilarCmd,
+    StatusCmd, StopCmd, StreamsCmd, SubtitlesCmd, TrailerCmd, TrendingCmd, TrendingWindow,
+    VolumeCmd, VolumeLevel, WatchCmd, WatchlistAction, WatchlistCmd,
 };
-use crate::config::Config;
-use crate::models::{CastDevice, MediaType, Quality, StreamSource};
-use crate::stream::{LocalPlayer, PlayerType, SubtitleClient};
+use crate::config::{Config, WatchlistEntry};
+use crate::models::{
+    magnet_for_hash, normalize_magnet, CastDevice, DiscoverSort, Genre, MediaType, Quality,
+    QueuedItem, SelectionPrefs, StreamSource, TorrentSession, Video, VideoCodec,
+};
+use crate::stream::cast::{SCAN_TIMEOUT, STATUS_TIMEOUT};
+use crate::stream::deps::{self, Dependency};
+use crate::stream::playback_log::PlaybackLog;
+use crate::stream::sessions;
+use crate::stream::{self, LocalPlayer, PlayerType, SubtitleClient};
 
 // =============================================================================
 // Search Command
@@ -22,13 +34,16 @@ use crate::stream::{LocalPlayer, PlayerType, SubtitleClient};
 
 pub async fn search_cmd(cmd: SearchCmd, output: &Output) -> ExitCode {
     let mut config = Config::load();
-    let api_key = config.get_tmdb_api_key();
-    let client = TmdbClient::new(api_key);
+    config.include_adult |= cmd.include_adult;
+    let client = TmdbClient::from_config(&mut config);
 
     output.info(format!("Searching for: {}", cmd.query));
 
-    match client.search(&cmd.query).await {
-        Ok(mut results) => {
+    match client.search_paged(&cmd.query, cmd.page).await {
+        Ok(page) => {
+            output.info(format!("Page {}/{}", page.page, page.total_pages.max(1)));
+            let mut results = page.results;
+
             // Filter by media type if specified
             if let Some(filter) = cmd.media_type {
                 results.retain(|r| match filter {
@@ -46,9 +61,9 @@ pub async fn search_cmd(cmd: SearchCmd, output: &Output) -> ExitCode {
             }
 
             // Limit results
-            results.truncate(cmd.limit);
+            results = apply_limit(results, cmd.limit);
 
-            if let Err(e) = output.print(&results) {
+            if let Err(e) = output.print_list("search", &results) {
                 return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
             }
             ExitCode::Success
@@ -57,14 +72,53 @@ pub async fn search_cmd(cmd: SearchCmd, output: &Output) -> ExitCode {
     }
 }
 
+// =============================================================================
+// Person Command
+// =============================================================================
+
+pub async fn person_cmd(cmd: PersonCmd, output: &Output) -> ExitCode {
+    let mut config = Config::load();
+    let client = TmdbClient::from_config(&mut config);
+
+    output.info(format!("Searching people: {}", cmd.name));
+
+    match client.search_person(&cmd.name).await {
+        Ok(mut people) => {
+            // Filter known-for titles by media type if specified
+            if let Some(filter) = cmd.media_type {
+                for person in &mut people {
+                    person.known_for.retain(|r| match filter {
+                        MediaTypeFilter::Movie => r.media_type == MediaType::Movie,
+                        MediaTypeFilter::Tv => r.media_type == MediaType::Tv,
+                    });
+                }
+            }
+
+            people = apply_limit(people, cmd.limit);
+
+            if people.is_empty() {
+                return output.error("No people found", ExitCode::NoStreams);
+            }
+
+            if let Err(e) = output.print_versioned("people", &people) {
+                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+            }
+            ExitCode::Success
+        }
+        Err(e) => output.error(
+            format!("Person search failed: {}", e),
+            ExitCode::NetworkError,
+        ),
+    }
+}
+
 // =============================================================================
 // Trending Command
 // =============================================================================
 
 pub async fn trending_cmd(cmd: TrendingCmd, output: &Output) -> ExitCode {
     let mut config = Config::load();
-    let api_key = config.get_tmdb_api_key();
-    let client = TmdbClient::new(api_key);
+    let client = TmdbClient::from_config(&mut config);
 
     let window_str = match cmd.window {
         TrendingWindow::Day => "day",
@@ -72,7 +126,7 @@ pub async fn trending_cmd(cmd: TrendingCmd, output: &Output) -> ExitCode {
     };
     output.info(format!("Fetching trending ({})...", window_str));
 
-    match client.trending().await {
+    match client.trending(cmd.window).await {
         Ok(mut results) => {
             // Filter by media type if specified
             if let Some(filter) = cmd.media_type {
@@ -83,9 +137,9 @@ pub async fn trending_cmd(cmd: TrendingCmd, output: &Output) -> ExitCode {
             }
 
             // Limit results
-            results.truncate(cmd.limit);
+            results = apply_limit(results, cmd.limit);
 
-            if let Err(e) = output.print(&results) {
+            if let Err(e) = output.print_list("trending", &results) {
                 return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
             }
             ExitCode::Success
@@ -97,53 +151,219 @@ pub async fn trending_cmd(cmd: TrendingCmd, output: &Output) -> ExitCode {
     }
 }
 
+// =============================================================================
+// Discover Command
+// =============================================================================
+
+pub async fn discover_cmd(cmd: DiscoverCmd, output: &Output) -> ExitCode {
+    let media_type = match cmd.media_type {
+        MediaTypeFilter::Movie => MediaType::Movie,
+        MediaTypeFilter::Tv => MediaType::Tv,
+    };
+    let sort = match cmd.sort {
+        DiscoverOrder::Popularity => DiscoverSort::Popularity,
+        DiscoverOrder::Rating => DiscoverSort::Rating,
+    };
+
+    let Some(genre) = Genre::find(&cmd.genre) else {
+        let names: Vec<_> = Genre::available_for(media_type)
+            .map(|g| g.name.to_lowercase())
+            .collect();
+        return output.error(
+            format!(
+                "Unknown genre '{}'. Available: {}",
+                cmd.genre,
+                names.join(", ")
+            ),
+            ExitCode::InvalidArgs,
+        );
+    };
+    let Some(genre_id) = genre.id_for(media_type) else {
+        return output.error(
+            format!("TMDB has no {} genre for TV shows", genre.name),
+            ExitCode::InvalidArgs,
+        );
+    };
+
+    let mut config = Config::load();
+    config.include_adult |= cmd.include_adult;
+    let client = TmdbClient::from_config(&mut config);
+
+    output.info(format!(
+        "Discovering {} ({}, by {})...",
+        genre.name, media_type, sort
+    ));
+
+    match client.discover(genre_id, media_type, sort).await {
+        Ok(mut results) => {
+            results = apply_limit(results, cmd.limit);
+
+            if let Err(e) = output.print_list("discover", &results) {
+                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+            }
+            ExitCode::Success
+        }
+        Err(e) => output.error(format!("Discover failed: {}", e), ExitCode::NetworkError),
+    }
+}
+
 // =============================================================================
 // Info Command
 // =============================================================================
 
 pub async fn info_cmd(cmd: InfoCmd, output: &Output) -> ExitCode {
     let mut config = Config::load();
-    let api_key = config.get_tmdb_api_key();
-    let client = TmdbClient::new(api_key);
+    let client = TmdbClient::from_config(&mut config);
 
     output.info(format!("Getting info for: {}", cmd.id));
 
-    // Try to parse as TMDB ID (number)
-    if let Ok(tmdb_id) = cmd.id.parse::<u64>() {
-        // Need media type for TMDB ID lookup
-        match cmd.media_type {
-            Some(MediaTypeFilter::Movie) => match client.movie_detail(tmdb_id).await {
-                Ok(detail) => {
-                    if let Err(e) = output.print(&detail) {
-                        return output
-                            .error(format!("Failed to serialize: {}", e), ExitCode::Error);
-                    }
-                    ExitCode::Success
+    let (tmdb_id, media_type) = match resolve_title(&client, &cmd.id, cmd.media_type).await {
+        Ok(resolved) => resolved,
+        Err((msg, code)) => return output.error(msg, code),
+    };
+
+    match media_type {
+        MediaType::Movie => match client.movie_detail(tmdb_id).await {
+            Ok(detail) => {
+                if let Err(e) = output.print_versioned("info", &detail) {
+                    return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
                 }
-                Err(e) => output.error(format!("Movie info failed: {}", e), ExitCode::NetworkError),
-            },
-            Some(MediaTypeFilter::Tv) => match client.tv_detail(tmdb_id).await {
-                Ok(detail) => {
-                    if let Err(e) = output.print(&detail) {
-                        return output
-                            .error(format!("Failed to serialize: {}", e), ExitCode::Error);
-                    }
-                    ExitCode::Success
+                ExitCode::Success
+            }
+            Err(e) => output.error(format!("Movie info failed: {}", e), ExitCode::NetworkError),
+        },
+        MediaType::Tv => match client.tv_detail(tmdb_id).await {
+            Ok(detail) => {
+                if let Err(e) = output.print_versioned("info", &detail) {
+                    return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
                 }
-                Err(e) => output.error(format!("TV info failed: {}", e), ExitCode::NetworkError),
-            },
-            None => output.error(
-                "Media type required for TMDB ID lookup. Use -t movie or -t tv.",
+                ExitCode::Success
+            }
+            Err(e) => output.error(format!("TV info failed: {}", e), ExitCode::NetworkError),
+        },
+    }
+}
+
+/// TMDB ID and media type for an IMDB or TMDB ID argument
+///
+/// IMDB IDs are looked up; a TMDB ID needs `media_type` (`-t`).
+async fn resolve_title(
+    client: &TmdbClient,
+    id: &str,
+    media_type: Option<MediaTypeFilter>,
+) -> Result<(u64, MediaType), (String, ExitCode)> {
+    if let Ok(tmdb_id) = id.parse::<u64>() {
+        return match media_type {
+            Some(MediaTypeFilter::Movie) => Ok((tmdb_id, MediaType::Movie)),
+            Some(MediaTypeFilter::Tv) => Ok((tmdb_id, MediaType::Tv)),
+            None => Err((
+                "Media type required for TMDB ID lookup. Use -t movie or -t tv.".to_string(),
                 ExitCode::InvalidArgs,
-            ),
+            )),
+        };
+    }
+    crate::cli::validate_imdb_id(id).map_err(|e| (e.to_string(), ExitCode::InvalidArgs))?;
+    match client.find_by_imdb(id).await {
+        Ok(found) => Ok((found.id, found.media_type)),
+        Err(e) if matches!(e.downcast_ref(), Some(TmdbError::NotFound)) => Err((
+            format!("No movie or TV show found for {}", id),
+            ExitCode::InvalidArgs,
+        )),
+        Err(e) => Err((
+            format!("IMDB lookup failed for {}: {}", id, e),
+            ExitCode::NetworkError,
+        )),
+    }
+}
+
+// =============================================================================
+// Similar Command
+// =============================================================================
+
+pub async fn similar_cmd(cmd: SimilarCmd, output: &Output) -> ExitCode {
+    let mut config = Config::load();
+    let client = TmdbClient::from_config(&mut config);
+
+    let (tmdb_id, media_type) = match resolve_title(&client, &cmd.id, cmd.media_type).await {
+        Ok(resolved) => resolved,
+        Err((msg, code)) => return output.error(msg, code),
+    };
+
+    let result = if cmd.recommended {
+        output.info(format!("Fetching recommendations for: {}", cmd.id));
+        client.recommendations(media_type, tmdb_id).await
+    } else {
+        output.info(format!("Finding titles similar to: {}", cmd.id));
+        client.similar(media_type, tmdb_id).await
+    };
+
+    match result {
+        Ok(mut results) => {
+            results = apply_limit(results, cmd.limit);
+
+            if let Err(e) = output.print_list("similar", &results) {
+                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+            }
+            ExitCode::Success
+        }
+        Err(e) => output.error(
+            format!("Similar titles failed: {}", e),
+            ExitCode::NetworkError,
+        ),
+    }
+}
+
+// =============================================================================
+// Trailer Command
+// =============================================================================
+
+/// `trailer` result (`url` is null when TMDB has no trailer)
+#[derive(Serialize)]
+struct TrailerLink {
+    tmdb_id: u64,
+    media_type: MediaType,
+    name: Option<String>,
+    url: Option<String>,
+}
+
+pub async fn trailer_cmd(cmd: TrailerCmd, output: &Output) -> ExitCode {
+    let mut config = Config::load();
+    let client = TmdbClient::from_config(&mut config);
+
+    output.info(format!("Finding trailer for: {}", cmd.id));
+
+    let (tmdb_id, media_type) = match resolve_title(&client, &cmd.id, cmd.media_type).await {
+        Ok(resolved) => resolved,
+        Err((msg, code)) => return output.error(msg, code),
+    };
+    let videos = match client.videos(media_type, tmdb_id).await {
+        Ok(videos) => videos,
+        Err(e) => {
+            return output.error(
+                format!("Trailer lookup failed: {}", e),
+                ExitCode::NetworkError,
+            )
+        }
+    };
+
+    let trailer = Video::pick_trailer(&videos);
+    let link = TrailerLink {
+        tmdb_id,
+        media_type,
+        name: trailer.map(|t| t.name.clone()),
+        url: trailer.map(|t| t.youtube_url()),
+    };
+    if output.json {
+        if let Err(e) = output.print_versioned("trailer", &link) {
+            return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
         }
     } else {
-        // Assume it's an IMDB ID - search for it
-        output.error(
-            "IMDB ID lookup not yet supported. Use TMDB ID with -t flag.",
-            ExitCode::InvalidArgs,
-        )
+        match link.url {
+            Some(url) => println!("{}", url),
+            None => output.info(format!("No trailer on TMDB for {}", cmd.id)),
+        }
     }
+    ExitCode::Success
 }
 
 // =============================================================================
@@ -151,17 +371,19 @@ pub async fn info_cmd(cmd: InfoCmd, output: &Output) -> ExitCode {
 // =============================================================================
 
 pub async fn streams_cmd(cmd: StreamsCmd, output: &Output) -> ExitCode {
-    let client = TorrentioClient::new();
+    let config = Config::load();
+    let client = TorrentioClient::from_config(&config)
+        .with_config(cmd.torrentio_config(config.torrentio_config()))
+        .with_dedup(!cmd.no_dedup);
+
+    let kind = match cmd.stream_kind() {
+        Ok(kind) => kind,
+        Err(e) => return output.error(e, ExitCode::InvalidArgs),
+    };
 
     output.info(format!("Finding streams for: {}", cmd.imdb_id));
 
-    let result = if let (Some(season), Some(episode)) = (cmd.season, cmd.episode) {
-        client
-            .episode_streams(&cmd.imdb_id, season as u16, episode)
-            .await
-    } else {
-        client.movie_streams(&cmd.imdb_id).await
-    };
+    let result = client.streams(&cmd.imdb_id, kind).await;
 
     match result {
         Ok(mut streams) => {
@@ -171,31 +393,66 @@ pub async fn streams_cmd(cmd: StreamsCmd, output: &Output) -> ExitCode {
 
             // Filter by quality if specified
             if let Some(quality_filter) = cmd.quality {
-                let min_quality = match quality_filter {
-                    crate::cli::QualityFilter::Q4k => Quality::UHD4K,
-                    crate::cli::QualityFilter::Q1080p => Quality::FHD1080p,
-                    crate::cli::QualityFilter::Q720p => Quality::HD720p,
-                    crate::cli::QualityFilter::Q480p => Quality::SD480p,
-                };
+                let min_quality = quality_filter.quality();
                 streams.retain(|s| s.quality.rank() >= min_quality.rank());
             }
 
+            // Keep only HDR sources if requested
+            if cmd.hdr {
+                streams.retain(|s| s.hdr.is_hdr());
+            }
+
+            // Size range (e.g. a data plan cap)
+            streams.retain(|s| cmd.size_matches(s.size_bytes));
+
+            if let Some(min_seeds) = cmd.min_seeds() {
+                streams.retain(|s| s.has_seeds(min_seeds));
+            }
+
+            // Filter by codec if specified
+            if let Some(codec_filter) = cmd.codec {
+                let codec = match codec_filter {
+                    crate::cli::CodecFilter::X264 => VideoCodec::X264,
+                    crate::cli::CodecFilter::X265 => VideoCodec::X265,
+                    crate::cli::CodecFilter::Av1 => VideoCodec::Av1,
+                };
+                streams.retain(|s| s.codec == codec);
+            }
+
+            streams.retain(|s| cmd.group_matches(s.release_group.as_deref()));
+
             // Sort streams
             match cmd.sort {
                 crate::cli::StreamSort::Seeds => {
-                    streams.sort_by(|a, b| b.seeds.cmp(&a.seeds));
+                    streams.sort_by_key(|s| std::cmp::Reverse(s.seeds));
                 }
                 crate::cli::StreamSort::Quality => {
-                    streams.sort_by(|a, b| b.quality.rank().cmp(&a.quality.rank()));
+                    streams.sort_by_key(|s| std::cmp::Reverse(s.quality.rank()));
                 }
                 crate::cli::StreamSort::Size => {
-                    streams
-                        .sort_by(|a, b| b.size_bytes.unwrap_or(0).cmp(&a.size_bytes.unwrap_or(0)));
+                    streams.sort_by_key(|s| std::cmp::Reverse(s.size_bytes.unwrap_or(0)));
+                }
+            }
+
+            // Keep only the top-scoring stream, with its index in the full list
+            if cmd.best {
+                let Some(idx) = config.selection_prefs().best_index(&streams) else {
+                    return output.error("No streams match the filters", ExitCode::NoStreams);
+                };
+                let stream = streams.swap_remove(idx);
+                let best = IndexedStream {
+                    index: idx,
+                    pack: stream.is_pack(),
+                    stream,
+                };
+                if let Err(e) = output.print_list("streams", &[best]) {
+                    return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
                 }
+                return ExitCode::Success;
             }
 
             // Limit results
-            streams.truncate(cmd.limit);
+            streams = apply_limit(streams, cmd.limit);
 
             // Create output with index for easy reference
             let indexed: Vec<IndexedStream> = streams
@@ -203,11 +460,12 @@ pub async fn streams_cmd(cmd: StreamsCmd, output: &Output) -> ExitCode {
                 .enumerate()
                 .map(|(i, s)| IndexedStream {
                     index: i,
+                    pack: s.is_pack(),
                     stream: s,
                 })
                 .collect();
 
-            if let Err(e) = output.print(&indexed) {
+            if let Err(e) = output.print_list("streams", &indexed) {
                 return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
             }
             ExitCode::Success
@@ -222,16 +480,25 @@ pub async fn streams_cmd(cmd: StreamsCmd, output: &Output) -> ExitCode {
 #[derive(Serialize)]
 struct IndexedStream {
     index: usize,
+    /// Season pack: pass the episode's file index with `-i`
+    pack: bool,
     #[serde(flatten)]
     stream: StreamSource,
 }
 
+/// `index` and then the stream's own line
+impl CompactLine for IndexedStream {
+    fn compact_line(&self) -> String {
+        format!("{} {}", self.index, self.stream.compact_line())
+    }
+}
+
 // =============================================================================
 // Subtitles Command
 // =============================================================================
 
 pub async fn subtitles_cmd(cmd: SubtitlesCmd, output: &Output) -> ExitCode {
-    let client = SubtitleClient::new();
+    let client = SubtitleClient::from_config(&Config::load());
     let languages = cmd.languages();
     let lang = languages.first().copied();
 
@@ -266,9 +533,9 @@ pub async fn subtitles_cmd(cmd: SubtitlesCmd, output: &Output) -> ExitCode {
             subs.sort_by_key(|s| std::cmp::Reverse(s.trust_score()));
 
             // Limit results
-            subs.truncate(cmd.limit);
+            subs = apply_limit(subs, cmd.limit);
 
-            if let Err(e) = output.print(&subs) {
+            if let Err(e) = output.print_list("subtitles", &subs) {
                 return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
             }
             ExitCode::Success
@@ -280,6 +547,82 @@ pub async fn subtitles_cmd(cmd: SubtitlesCmd, output: &Output) -> ExitCode {
     }
 }
 
+/// `get-subtitle` result
+#[derive(Serialize)]
+struct SavedSubtitle {
+    path: String,
+    id: String,
+    language: String,
+    release: String,
+}
+
+pub async fn get_subtitle_cmd(cmd: GetSubtitleCmd, output: &Output) -> ExitCode {
+    let client = SubtitleClient::from_config(&Config::load());
+
+    output.info(format!(
+        "Searching subtitles for: {} ({})",
+        cmd.imdb_id, cmd.lang
+    ));
+
+    let result = if let (Some(season), Some(episode)) = (cmd.season, cmd.episode) {
+        client
+            .search_episode(&cmd.imdb_id, season as u16, episode, Some(&cmd.lang))
+            .await
+    } else {
+        client.search(&cmd.imdb_id, Some(&cmd.lang)).await
+    };
+
+    let subs = match result {
+        Ok(subs) => subs,
+        Err(e) => {
+            return output.error(
+                format!("Subtitle search failed: {}", e),
+                ExitCode::NetworkError,
+            );
+        }
+    };
+    let Some(best) = subs.into_iter().max_by_key(|s| s.trust_score()) else {
+        return output.error("No subtitles found", ExitCode::NoStreams);
+    };
+
+    output.info(format!("Downloading: {}", best.release));
+    let text = match client.fetch_subtitle(&best.url).await {
+        Ok((text, format)) => stream::subtitles::to_webvtt(text, format),
+        Err(e) => {
+            return output.error(
+                format!("Subtitle download failed: {}", e),
+                ExitCode::NetworkError,
+            );
+        }
+    };
+
+    let path = cmd.target_path();
+    if let Err(e) = std::fs::write(&path, text) {
+        return output.error(
+            format!("Failed to write {}: {}", path.display(), e),
+            ExitCode::Error,
+        );
+    }
+
+    let saved = SavedSubtitle {
+        path: std::fs::canonicalize(&path)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string(),
+        id: best.id,
+        language: best.language,
+        release: best.release,
+    };
+    if output.json {
+        if let Err(e) = output.print_versioned("subtitle_file", &saved) {
+            return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+        }
+    } else {
+        println!("{}", saved.path);
+    }
+    ExitCode::Success
+}
+
 // =============================================================================
 // Devices Command
 // =============================================================================
@@ -287,48 +630,81 @@ pub async fn subtitles_cmd(cmd: SubtitlesCmd, output: &Output) -> ExitCode {
 pub async fn devices_cmd(_cmd: DevicesCmd, output: &Output) -> ExitCode {
     output.info("Scanning for Chromecast devices...");
 
-    // Use catt scan to discover devices (no timeout flag in catt 0.13+)
-    match tokio::process::Command::new("catt")
-        .arg("scan")
-        .output()
-        .await
-    {
-        Ok(result) => {
-            let stdout = String::from_utf8_lossy(&result.stdout);
-            let stderr = String::from_utf8_lossy(&result.stderr);
-
-            // Parse catt output
-            let devices = CastDevice::parse_catt_scan(&stdout);
-
-            if devices.is_empty() {
-                // Check if catt reported anything in stderr
-                if !stderr.is_empty() && stderr.contains("No devices") {
-                    return output.error("No Chromecast devices found", ExitCode::DeviceNotFound);
-                }
-                // Try parsing stderr too (catt sometimes outputs there)
-                let devices_stderr = CastDevice::parse_catt_scan(&stderr);
-                if devices_stderr.is_empty() {
-                    return output.error("No Chromecast devices found", ExitCode::DeviceNotFound);
-                }
-                if let Err(e) = output.print(&devices_stderr) {
-                    return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
-                }
-            } else if let Err(e) = output.print(&devices) {
-                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+    // Several scans, merged: one scan often misses a device the next finds
+    let attempts = Config::load().scan_attempts();
+    let mut devices = Vec::new();
+    for attempt in 0..attempts {
+        match catt_scan().await {
+            Ok(found) => CastDevice::merge_scan(&mut devices, found),
+            // A later scan failing keeps what the earlier ones found
+            Err(_) if attempt > 0 => break,
+            Err(e) if e.kind() == std::io::ErrorKind::TimedOut => {
+                return output.error(
+                    format!("Device scan timed out after {}s", SCAN_TIMEOUT.as_secs()),
+                    ExitCode::NetworkError,
+                );
             }
-            ExitCode::Success
-        }
-        Err(e) => {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                output.error(
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return output.error(
                     "catt not found. Install with: pip install catt",
                     ExitCode::Error,
-                )
-            } else {
-                output.error(format!("Device scan failed: {}", e), ExitCode::NetworkError)
+                );
+            }
+            Err(e) => {
+                return output.error(format!("Device scan failed: {}", e), ExitCode::NetworkError);
             }
         }
     }
+
+    if devices.is_empty() {
+        return output.error("No Chromecast devices found", ExitCode::DeviceNotFound);
+    }
+    if let Err(e) = output.print_list("devices", &devices) {
+        return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+    }
+    ExitCode::Success
+}
+
+/// Devices from one `catt scan` (no timeout flag in catt 0.13+, so we add one)
+async fn catt_scan() -> std::io::Result<Vec<CastDevice>> {
+    let scan = tokio::process::Command::new("catt")
+        .arg("scan")
+        .kill_on_drop(true)
+        .output();
+    let result = tokio::time::timeout(SCAN_TIMEOUT, scan)
+        .await
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
+
+    // catt sometimes prints the device list on stderr
+    let devices = CastDevice::parse_catt_scan(&String::from_utf8_lossy(&result.stdout));
+    if devices.is_empty() {
+        return Ok(CastDevice::parse_catt_scan(&String::from_utf8_lossy(
+            &result.stderr,
+        )));
+    }
+    Ok(devices)
+}
+
+/// Whether `device` answers before anything is cast to it
+///
+/// An address is tried directly. A name goes through `catt -d <name> status`,
+/// which reports a device that's off as not found.
+async fn device_reachable(device: &str) -> bool {
+    if let Ok(address) = device.parse::<std::net::IpAddr>() {
+        return CastDevice::manual(address).is_reachable().await;
+    }
+    let status = tokio::process::Command::new("catt")
+        .args(["-d", device, "status"])
+        .kill_on_drop(true)
+        .output();
+    match tokio::time::timeout(STATUS_TIMEOUT, status).await {
+        Ok(Ok(result)) => !String::from_utf8_lossy(&result.stderr)
+            .to_lowercase()
+            .contains("not found"),
+        // A missing catt is reported when casting starts
+        Ok(Err(_)) => true,
+        Err(_) => false,
+    }
 }
 
 // =============================================================================
@@ -374,6 +750,7 @@ async fn wait_for_stream(url: &str, timeout_secs: u64, output: &Output) -> bool
 async fn play_locally(
     stream_url: &str,
     subtitle_path: Option<&std::path::Path>,
+    start: Option<u64>,
     player_type: PlayerType,
     output: &Output,
 ) -> ExitCode {
@@ -392,7 +769,7 @@ async fn play_locally(
 
     output.info(format!("Opening in {}...", player_type.display_name()));
 
-    match player.play(stream_url, subtitle_path).await {
+    match player.play(stream_url, subtitle_path, start).await {
         Ok(_child) => {
             #[derive(Serialize)]
             struct PlayLocalSuccess {
@@ -407,7 +784,7 @@ async fn play_locally(
                 stream_url: stream_url.to_string(),
             };
 
-            if let Err(e) = output.print(&response) {
+            if let Err(e) = output.print_versioned("playback", &response) {
                 return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
             }
             ExitCode::Success
@@ -420,6 +797,52 @@ async fn play_locally(
 // Cast Command
 // =============================================================================
 
+/// Pick the stream `cast` and `magnet` use: by index, by score, or the one
+/// closest to the wanted quality
+///
+/// Streams are sorted by seeds first, same as the `streams` command, so
+/// `--index` refers to what the user saw there.
+fn pick_stream(
+    mut streams: Vec<StreamSource>,
+    config: &Config,
+    quality: Option<QualityFilter>,
+    index: Option<usize>,
+    best: bool,
+) -> Result<StreamSource, (String, ExitCode)> {
+    streams.sort_by_key(|s| std::cmp::Reverse(s.seeds));
+
+    if best {
+        let idx = config.selection_prefs().best_index(&streams).unwrap_or(0);
+        return Ok(streams.remove(idx));
+    }
+    if let Some(idx) = index {
+        if idx >= streams.len() {
+            return Err((
+                format!(
+                    "Stream index {} out of range (0-{})",
+                    idx,
+                    streams.len() - 1
+                ),
+                ExitCode::InvalidArgs,
+            ));
+        }
+        return Ok(streams.remove(idx));
+    }
+    // Re-sort by --quality, or the configured preferred quality
+    let target_quality = quality
+        .map(|q| q.quality())
+        .or_else(|| config.preferred_quality());
+    if let Some(target_quality) = target_quality {
+        // Prefer exact match, then the closest quality (ties by seeds)
+        streams.sort_by(|a, b| {
+            let a_diff = a.quality.distance(target_quality);
+            let b_diff = b.quality.distance(target_quality);
+            a_diff.cmp(&b_diff).then_with(|| b.seeds.cmp(&a.seeds))
+        });
+    }
+    Ok(streams.remove(0))
+}
+
 pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> ExitCode {
     // If --vlc flag is set, we don't need a device
     let device_name = if cmd.vlc {
@@ -434,6 +857,23 @@ pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> Ex
         }
     };
 
+    let kind = match cmd.stream_kind() {
+        Ok(kind) => kind,
+        Err(e) => return output.error(e, ExitCode::InvalidArgs),
+    };
+
+    // Fail fast instead of starting webtorrent for a TV that's off
+    if let Some(device) = device_name {
+        if !device_reachable(device).await {
+            return output.error(crate::app::DEVICE_UNREACHABLE, ExitCode::DeviceNotFound);
+        }
+    }
+
+    // A season without an episode queues the whole season
+    if let (Some(season), None) = (cmd.season, cmd.episode) {
+        return cast_season(&cmd.imdb_id, season, device_name, output).await;
+    }
+
     if cmd.vlc {
         output.info(format!("Playing {} in VLC...", cmd.imdb_id));
     } else {
@@ -445,14 +885,9 @@ pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> Ex
     }
 
     // Step 1: Get streams
-    let torrentio = TorrentioClient::new();
-    let streams_result = if let (Some(season), Some(episode)) = (cmd.season, cmd.episode) {
-        torrentio
-            .episode_streams(&cmd.imdb_id, season as u16, episode)
-            .await
-    } else {
-        torrentio.movie_streams(&cmd.imdb_id).await
-    };
+    let config = Config::load();
+    let torrentio = TorrentioClient::from_config(&config);
+    let streams_result = torrentio.streams(&cmd.imdb_id, kind).await;
 
     let mut streams = match streams_result {
         Ok(s) if s.is_empty() => {
@@ -467,47 +902,45 @@ pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> Ex
         }
     };
 
-    // Step 2: Sort streams (same as `streams` command to ensure index consistency)
-    // Default sort by seeds to match what user sees in `streams` output
-    streams.sort_by(|a, b| b.seeds.cmp(&a.seeds));
-
-    // Step 3: Select stream (by index or quality preference)
-    let stream = if let Some(idx) = cmd.index {
-        if idx >= streams.len() {
+    if let Some(min_seeds) = cmd.min_seeds() {
+        streams.retain(|s| s.has_seeds(min_seeds));
+        if streams.is_empty() {
             return output.error(
-                format!(
-                    "Stream index {} out of range (0-{})",
-                    idx,
-                    streams.len() - 1
-                ),
-                ExitCode::InvalidArgs,
+                format!("No streams with at least {} seeds", min_seeds),
+                ExitCode::NoStreams,
             );
         }
-        streams.remove(idx)
-    } else {
-        // Re-sort by quality preference if specified
-        if let Some(quality_filter) = cmd.quality {
-            let target_quality = match quality_filter {
-                crate::cli::QualityFilter::Q4k => Quality::UHD4K,
-                crate::cli::QualityFilter::Q1080p => Quality::FHD1080p,
-                crate::cli::QualityFilter::Q720p => Quality::HD720p,
-                crate::cli::QualityFilter::Q480p => Quality::SD480p,
-            };
-            // Prefer exact match, then closest higher quality
-            streams.sort_by(|a, b| {
-                let a_diff = (a.quality.rank() as i8 - target_quality.rank() as i8).abs();
-                let b_diff = (b.quality.rank() as i8 - target_quality.rank() as i8).abs();
-                a_diff.cmp(&b_diff).then_with(|| b.seeds.cmp(&a.seeds))
-            });
-        }
-        streams.remove(0)
+    }
+
+    // Step 2-3: Sort and select stream (by index, score, or quality preference)
+    let stream = match pick_stream(streams, &config, cmd.quality, cmd.index, cmd.auto) {
+        Ok(stream) => stream,
+        Err((msg, code)) => return output.error(msg, code),
     };
 
+    cast_stream(&cmd, kind, stream, device_name, &config, output).await
+}
+
+/// Cast a picked stream (the rest of `cast` after the stream is chosen)
+async fn cast_stream(
+    cmd: &CastCmd,
+    kind: StreamKind,
+    stream: StreamSource,
+    device_name: Option<&str>,
+    config: &Config,
+    output: &Output,
+) -> ExitCode {
     // Step 4: Generate magnet link
-    let magnet = stream.to_magnet(&cmd.imdb_id);
+    if stream.direct_url.is_none() && stream.normalized_hash().is_none() {
+        return output.error(
+            format!("Stream has an invalid info hash: {:?}", stream.info_hash),
+            ExitCode::CastFailed,
+        );
+    }
+    let magnet = stream.to_magnet_with_trackers(&cmd.imdb_id, &config.trackers());
     output.info(format!(
         "Selected: {} ({}) - {} seeds",
-        stream.name, stream.quality, stream.seeds
+        stream.provider, stream.quality, stream.seeds
     ));
 
     // Step 5: Handle subtitles if requested
@@ -528,7 +961,7 @@ pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> Ex
     } else if let Some(ref sub_id) = cmd.subtitle_id {
         // Download specific subtitle by ID
         output.info(format!("Downloading subtitle {}...", sub_id));
-        let sub_client = SubtitleClient::new();
+        let sub_client = SubtitleClient::from_config(config);
         match sub_client
             .download_by_id(
                 &cmd.imdb_id,
@@ -550,13 +983,14 @@ pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> Ex
     } else if let Some(ref lang) = cmd.subtitle {
         // Search for subtitle by language and download the best one
         output.info(format!("Searching for {} subtitles...", lang));
-        let sub_client = SubtitleClient::new();
-        let search_result = if let (Some(season), Some(episode)) = (cmd.season, cmd.episode) {
-            sub_client
-                .search_episode(&cmd.imdb_id, season as u16, episode, Some(lang))
-                .await
-        } else {
-            sub_client.search(&cmd.imdb_id, Some(lang)).await
+        let sub_client = SubtitleClient::from_config(config);
+        let search_result = match kind {
+            StreamKind::Series { season, episode } => {
+                sub_client
+                    .search_episode(&cmd.imdb_id, season, episode, Some(lang))
+                    .await
+            }
+            StreamKind::Movie => sub_client.search(&cmd.imdb_id, Some(lang)).await,
         };
 
         match search_result {
@@ -598,41 +1032,41 @@ pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> Ex
         None
     };
 
+    // Debrid-resolved source: cast the HTTP link directly, no webtorrent
+    if let Some(ref url) = stream.direct_url {
+        return cast_direct_url(url, device_name, subtitle_path.as_deref(), output).await;
+    }
+
     // Step 6: Start webtorrent with built-in Chromecast/VLC support
     output.info("Starting torrent stream...");
 
     let file_idx = stream.file_idx.unwrap_or(0);
-
-    // Build webtorrent command with player flag
-    let mut wt_cmd = tokio::process::Command::new("webtorrent");
-    wt_cmd
-        .arg(&magnet)
-        .arg("-s")
-        .arg(file_idx.to_string());
-
-    // Add subtitle file if we have one
+    let mut wt_cmd = webtorrent_command(&magnet, file_idx, device_name, subtitle_path.as_deref());
     if let Some(ref sub_path) = subtitle_path {
-        wt_cmd.arg("-t").arg(sub_path);
         output.info(format!("Using subtitles: {}", sub_path.display()));
     }
 
-    if cmd.vlc {
-        wt_cmd.arg("--vlc");
-    } else {
-        // Use webtorrent's built-in Chromecast support
-        wt_cmd.arg("--chromecast").arg(device_name.unwrap());
+    if cmd.wait {
+        let log = PlaybackLog::from_config(config);
+        let peer_timeout = std::time::Duration::from_secs(cmd.peer_timeout);
+        return cast_and_wait(wt_cmd, &magnet, file_idx, &log, peer_timeout, output).await;
     }
 
-    wt_cmd.arg("--not-on-top");
-
     // Start webtorrent (blocks until playback ends or user quits)
     output.info("Connecting to peers and starting playback...");
 
-    let result = wt_cmd
-        .stdout(std::process::Stdio::inherit())
-        .stderr(std::process::Stdio::inherit())
-        .status()
-        .await;
+    // webtorrent's progress display is chatter too; keep it off stdout with --quiet
+    let stdout = if output.quiet {
+        std::process::Stdio::null()
+    } else {
+        std::process::Stdio::inherit()
+    };
+    let result = run_tracked(
+        wt_cmd.stdout(stdout).stderr(std::process::Stdio::inherit()),
+        &magnet,
+        file_idx,
+    )
+    .await;
 
     match result {
         Ok(status) if status.success() => {
@@ -655,7 +1089,7 @@ pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> Ex
         Err(e) => {
             if e.kind() == std::io::ErrorKind::NotFound {
                 output.error(
-                    "webtorrent not found. Install with: npm install -g webtorrent-cli",
+                    "webtorrent not found. Install with: npm i -g webtorrent-cli",
                     ExitCode::Error,
                 )
             } else {
@@ -668,6 +1102,629 @@ pub async fn cast_cmd(cmd: CastCmd, device: Option<&str>, output: &Output) -> Ex
     }
 }
 
+/// Progress event printed by `cast --wait`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StreamProgress {
+    /// "buffering" until webtorrent's server is up, then "ready"
+    pub state: &'static str,
+    /// Fraction of the file downloaded (0.0-1.0)
+    pub progress: f32,
+    /// Download speed in bytes/sec
+    pub speed: u64,
+    pub peers: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_url: Option<String>,
+}
+
+/// Run webtorrent in the background and report progress until it serves the stream
+///
+/// webtorrent writes to the playback log so it keeps running (and logging)
+/// after we exit; the log is polled the same way the TUI does.
+async fn cast_and_wait(
+    mut wt_cmd: tokio::process::Command,
+    magnet: &str,
+    file_idx: u32,
+    log: &PlaybackLog,
+    peer_timeout: std::time::Duration,
+    output: &Output,
+) -> ExitCode {
+    log.clear();
+    let open_log = || {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log.path)
+    };
+    let (stdout, stderr) = match (open_log(), open_log()) {
+        (Ok(stdout), Ok(stderr)) => (stdout, stderr),
+        (Err(e), _) | (_, Err(e)) => {
+            return output.error(
+                format!("Failed to open {}: {}", log.path.display(), e),
+                ExitCode::Error,
+            )
+        }
+    };
+
+    let mut child = match wt_cmd
+        .stdin(std::process::Stdio::null())
+        .stdout(stdout)
+        .stderr(stderr)
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return output.error(
+                "webtorrent not found. Install with: npm i -g webtorrent-cli",
+                ExitCode::Error,
+            )
+        }
+        Err(e) => {
+            return output.error(
+                format!("Failed to start webtorrent: {}", e),
+                ExitCode::Error,
+            )
+        }
+    };
+    sessions::record(magnet, child.id(), Some(file_idx));
+
+    output.info("Connecting to peers...");
+    let started = std::time::Instant::now();
+    let mut last: Option<StreamProgress> = None;
+    loop {
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+        let text = std::fs::read_to_string(&log.path).unwrap_or_default();
+        let (progress, speed, peers) =
+            TorrentSession::parse_log_progress(&text).unwrap_or((0.0, 0, 0));
+        let stream_url = TorrentSession::parse_server_url(&text);
+        let event = StreamProgress {
+            state: if stream_url.is_some() {
+                "ready"
+            } else {
+                "buffering"
+            },
+            progress,
+            speed,
+            peers,
+            stream_url,
+        };
+
+        if last.as_ref() != Some(&event) {
+            if output.json {
+                let _ = output.print_event("progress", &event);
+            } else if let Some(ref url) = event.stream_url {
+                output.info(format!("Stream ready: {}", url));
+            } else {
+                output.info(format!(
+                    "Buffering {:.0}% · {:.1} MB/s · {} peers",
+                    event.progress * 100.0,
+                    event.speed as f64 / (1024.0 * 1024.0),
+                    event.peers
+                ));
+            }
+        }
+        if event.stream_url.is_some() {
+            output.info(format!(
+                "webtorrent keeps streaming; log: {}",
+                log.path.display()
+            ));
+            return ExitCode::Success;
+        }
+        last = Some(event);
+
+        if let Ok(Some(status)) = child.try_wait() {
+            return output.error(
+                format!(
+                    "webtorrent exited with code {} before the stream was ready",
+                    status.code().unwrap_or(1)
+                ),
+                ExitCode::CastFailed,
+            );
+        }
+        if peers == 0 && started.elapsed() >= peer_timeout {
+            let pid = child.id();
+            let _ = child.kill().await;
+            sessions::forget(magnet, pid);
+            return output.error(
+                format!("No peers found within {}s", peer_timeout.as_secs()),
+                ExitCode::NoStreams,
+            );
+        }
+    }
+}
+
+/// Run webtorrent to completion, registered as the session for `magnet`
+async fn run_tracked(
+    wt_cmd: &mut tokio::process::Command,
+    magnet: &str,
+    file_idx: u32,
+) -> std::io::Result<std::process::ExitStatus> {
+    let mut child = wt_cmd.spawn()?;
+    let pid = child.id();
+    sessions::record(magnet, pid, Some(file_idx));
+    let status = child.wait().await;
+    sessions::forget(magnet, pid);
+    status
+}
+
+/// webtorrent streaming one file of a magnet to a Chromecast (or VLC without a device)
+fn webtorrent_command(
+    magnet: &str,
+    file_idx: u32,
+    device: Option<&str>,
+    subtitle_path: Option<&std::path::Path>,
+) -> tokio::process::Command {
+    let mut wt_cmd = tokio::process::Command::new("webtorrent");
+    wt_cmd.arg(magnet).arg("-s").arg(file_idx.to_string());
+
+    if let Some(sub_path) = subtitle_path {
+        wt_cmd.arg("-t").arg(sub_path);
+    }
+
+    match device {
+        // Use webtorrent's built-in Chromecast support
+        Some(device) => wt_cmd.arg("--chromecast").arg(device),
+        None => wt_cmd.arg("--vlc"),
+    };
+
+    wt_cmd.arg("--not-on-top");
+    wt_cmd
+}
+
+/// Pick the best-scoring stream for each episode of a season
+///
+/// Episodes without streams (or whose lookup fails) are left out of the queue.
+pub async fn resolve_season_queue(
+    torrentio: &TorrentioClient,
+    prefs: &SelectionPrefs,
+    imdb_id: &str,
+    title: &str,
+    season: u8,
+    episodes: &[u8],
+) -> Vec<QueuedItem> {
+    let mut queue = Vec::new();
+    for &episode in episodes {
+        let Ok(mut streams) = torrentio
+            .episode_streams(imdb_id, season as u16, episode as u16)
+            .await
+        else {
+            continue;
+        };
+        let Some(idx) = prefs.best_index(&streams) else {
+            continue;
+        };
+        queue.push(QueuedItem {
+            imdb_id: imdb_id.to_string(),
+            title: format!("{} S{}E{}", title, season, episode),
+            season,
+            episode,
+            source: streams.swap_remove(idx),
+        });
+    }
+    queue
+}
+
+/// Cast every episode of a season in order, moving on when the device goes idle
+async fn cast_season(imdb_id: &str, season: u8, device: Option<&str>, output: &Output) -> ExitCode {
+    let mut config = Config::load();
+    let tmdb = TmdbClient::from_config(&mut config);
+
+    let show = match tmdb.find_by_imdb(imdb_id).await {
+        Ok(show) if show.media_type == MediaType::Tv => show,
+        Ok(_) => {
+            return output.error(
+                "--season without --episode needs a TV show",
+                ExitCode::InvalidArgs,
+            )
+        }
+        Err(e) => {
+            return output.error(
+                format!("Failed to look up {}: {}", imdb_id, e),
+                ExitCode::NetworkError,
+            )
+        }
+    };
+    let episodes: Vec<u8> = match tmdb.tv_season(show.id, season).await {
+        Ok(episodes) => episodes.iter().map(|e| e.episode).collect(),
+        Err(e) => {
+            return output.error(
+                format!("Failed to get season {}: {}", season, e),
+                ExitCode::NetworkError,
+            )
+        }
+    };
+
+    output.info(format!(
+        "Finding streams for {} episodes of {} season {}...",
+        episodes.len(),
+        show.title,
+        season
+    ));
+    let torrentio = TorrentioClient::from_config(&config);
+    let queue = resolve_season_queue(
+        &torrentio,
+        &config.selection_prefs(),
+        imdb_id,
+        &show.title,
+        season,
+        &episodes,
+    )
+    .await;
+
+    if queue.is_empty() {
+        return output.error("No streams found for this season", ExitCode::NoStreams);
+    }
+    if queue.len() < episodes.len() {
+        output.info(format!(
+            "Queued {} of {} episodes (the rest have no streams)",
+            queue.len(),
+            episodes.len()
+        ));
+    }
+
+    let trackers = config.trackers();
+    for (i, item) in queue.iter().enumerate() {
+        let next = queue
+            .get(i + 1)
+            .map(|n| format!(" (Next: {})", n.label()))
+            .unwrap_or_default();
+        output.info(format!("Now playing {}{}", item.label(), next));
+
+        if let Err(e) = play_queued(item, device, &trackers).await {
+            return output.error(
+                format!("{} failed: {}", item.label(), e),
+                ExitCode::CastFailed,
+            );
+        }
+    }
+
+    output.info("Season finished");
+    ExitCode::Success
+}
+
+/// Play one queued episode and return once it has finished
+async fn play_queued(
+    item: &QueuedItem,
+    device: Option<&str>,
+    trackers: &[String],
+) -> Result<(), String> {
+    if item.source.direct_url.is_none() && item.source.normalized_hash().is_none() {
+        return Err(format!("invalid info hash {:?}", item.source.info_hash));
+    }
+    let magnet = item.source.to_magnet_with_trackers(&item.title, trackers);
+    let file_idx = item.source.file_idx.unwrap_or(0);
+
+    // VLC blocks until the player is closed
+    let Some(device) = device else {
+        return match item.source.direct_url {
+            Some(ref url) => LocalPlayer::vlc()
+                .play_and_wait(url, None)
+                .await
+                .map_err(|e| e.to_string()),
+            None => run_tracked(
+                &mut webtorrent_command(&magnet, file_idx, None, None),
+                &magnet,
+                file_idx,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+        };
+    };
+
+    let mut stream = None;
+    if let Some(ref url) = item.source.direct_url {
+        let status = tokio::process::Command::new("catt")
+            .args(["-d", device, "cast", url])
+            .status()
+            .await
+            .map_err(|e| e.to_string())?;
+        if !status.success() {
+            return Err(format!(
+                "catt exited with code {}",
+                status.code().unwrap_or(1)
+            ));
+        }
+    } else {
+        let child = webtorrent_command(&magnet, file_idx, Some(device), None)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| format!("Failed to start webtorrent: {}", e))?;
+        sessions::record(&magnet, child.id(), Some(file_idx));
+        stream = Some(child);
+    }
+
+    wait_until_finished(device, stream.as_mut()).await;
+    if let Some(mut child) = stream {
+        let pid = child.id();
+        let _ = child.kill().await;
+        sessions::forget(&magnet, pid);
+    }
+    Ok(())
+}
+
+/// Poll the device until its media has played and gone idle again
+///
+/// Also returns if the torrent stream exits or catt can't be run.
+async fn wait_until_finished(device: &str, mut stream: Option<&mut tokio::process::Child>) {
+    let mut started = false;
+    loop {
+        tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+
+        if let Some(child) = stream.as_mut() {
+            if child.try_wait().ok().flatten().is_some() {
+                return;
+            }
+        }
+
+        let Ok(result) = tokio::process::Command::new("catt")
+            .args(["-d", device, "status"])
+            .output()
+            .await
+        else {
+            return;
+        };
+        let stdout = String::from_utf8_lossy(&result.stdout);
+        let state = crate::models::PlaybackStatus::parse_catt_status(&stdout).map(|s| s.state);
+
+        // "Nothing playing" comes back as a failed status
+        let finished = !result.status.success() || state.as_ref().is_none_or(|s| s.is_finished());
+        if state.is_some_and(|s| s.is_active()) && !finished {
+            started = true;
+        } else if started && finished {
+            return;
+        }
+    }
+}
+
+/// Cast a direct HTTP link (debrid-resolved) with catt, or play it in VLC
+async fn cast_direct_url(
+    url: &str,
+    device: Option<&str>,
+    subtitle_path: Option<&std::path::Path>,
+    output: &Output,
+) -> ExitCode {
+    if device.is_some() {
+        if let Err(e) = deps::CATT.require() {
+            return output.error(e.to_string(), ExitCode::Error);
+        }
+    }
+
+    output.info("Casting direct link (debrid)...");
+
+    let result = match device {
+        None => LocalPlayer::vlc()
+            .play_and_wait(url, subtitle_path)
+            .await
+            .map_err(|e| e.to_string()),
+        Some(dev) => {
+            let mut catt = tokio::process::Command::new("catt");
+            catt.arg("-d").arg(dev).arg("cast").arg(url);
+            if let Some(sub_path) = subtitle_path {
+                catt.arg("-s").arg(sub_path);
+            }
+            match catt.status().await {
+                Ok(status) if status.success() => Ok(()),
+                Ok(status) => Err(format!(
+                    "catt exited with code {}",
+                    status.code().unwrap_or(1)
+                )),
+                Err(e) => Err(e.to_string()),
+            }
+        }
+    };
+
+    match result {
+        Ok(()) => {
+            output.info("Playback started");
+            ExitCode::Success
+        }
+        Err(e) => output.error(format!("Direct cast failed: {}", e), ExitCode::CastFailed),
+    }
+}
+
+// =============================================================================
+// Quickcast Command
+// =============================================================================
+
+/// A step `quickcast` resolved (one JSON line each with `--json`)
+#[derive(Serialize)]
+#[serde(tag = "step", rename_all = "lowercase")]
+enum QuickcastStep<'a> {
+    Search {
+        query: &'a str,
+        tmdb_id: u64,
+        title: &'a str,
+        year: Option<u16>,
+    },
+    Detail {
+        imdb_id: &'a str,
+    },
+    Stream {
+        provider: &'a str,
+        quality: Quality,
+        seeds: u32,
+    },
+    Device {
+        device: &'a str,
+    },
+}
+
+pub async fn quickcast_cmd(cmd: QuickcastCmd, device: Option<&str>, output: &Output) -> ExitCode {
+    let Some(device_name) = cmd.device.as_deref().or(device) else {
+        return output.error(
+            "No device specified. Use --device or -d flag.",
+            ExitCode::DeviceNotFound,
+        );
+    };
+    let report = |step: QuickcastStep| {
+        if output.json {
+            let _ = output.print_event("quickcast", &step);
+        }
+    };
+
+    // Search: the top movie result
+    let mut config = Config::load();
+    let client = TmdbClient::from_config(&mut config);
+    output.info(format!("Searching for: {}", cmd.query));
+    let results = match client.search(&cmd.query).await {
+        Ok(results) => results,
+        Err(e) => return output.error(format!("Search failed: {}", e), ExitCode::NetworkError),
+    };
+    let Some(movie) = results
+        .into_iter()
+        .find(|r| r.media_type == MediaType::Movie)
+    else {
+        return output.error(
+            format!("No movies found for \"{}\"", cmd.query),
+            ExitCode::NoStreams,
+        );
+    };
+    output.info(format!(
+        "Found: {}{}",
+        movie.title,
+        movie.year.map(|y| format!(" ({})", y)).unwrap_or_default()
+    ));
+    report(QuickcastStep::Search {
+        query: &cmd.query,
+        tmdb_id: movie.id,
+        title: &movie.title,
+        year: movie.year,
+    });
+
+    // Detail: Torrentio wants the IMDB ID
+    let imdb_id = match client.movie_detail(movie.id).await {
+        Ok(detail) if detail.imdb_id.is_empty() => {
+            return output.error(
+                format!("{} has no IMDB ID to find streams with", movie.title),
+                ExitCode::NoStreams,
+            )
+        }
+        Ok(detail) => detail.imdb_id,
+        Err(e) => return output.error(format!("Movie info failed: {}", e), ExitCode::NetworkError),
+    };
+    output.info(format!("IMDB ID: {}", imdb_id));
+    report(QuickcastStep::Detail { imdb_id: &imdb_id });
+
+    // Streams: the one `cast` would pick
+    let torrentio = TorrentioClient::from_config(&config);
+    let streams = match torrentio.streams(&imdb_id, StreamKind::Movie).await {
+        Ok(s) if s.is_empty() => {
+            return output.error("No streams found for this content", ExitCode::NoStreams)
+        }
+        Ok(s) => s,
+        Err(e) => {
+            return output.error(
+                format!("Failed to get streams: {}", e),
+                ExitCode::NetworkError,
+            )
+        }
+    };
+    let stream = match pick_stream(streams, &config, cmd.quality, None, cmd.quality.is_none()) {
+        Ok(stream) => stream,
+        Err((msg, code)) => return output.error(msg, code),
+    };
+    report(QuickcastStep::Stream {
+        provider: &stream.provider,
+        quality: stream.quality,
+        seeds: stream.seeds,
+    });
+
+    // Device: the same check as `cast`
+    if !device_reachable(device_name).await {
+        return output.error(crate::app::DEVICE_UNREACHABLE, ExitCode::DeviceNotFound);
+    }
+    output.info(format!("Casting {} to {}...", movie.title, device_name));
+    report(QuickcastStep::Device {
+        device: device_name,
+    });
+
+    let cast = cmd.to_cast(imdb_id);
+    cast_stream(
+        &cast,
+        StreamKind::Movie,
+        stream,
+        Some(device_name),
+        &config,
+        output,
+    )
+    .await
+}
+
+// =============================================================================
+// Magnet Command
+// =============================================================================
+
+/// `magnet` result
+#[derive(Serialize)]
+struct MagnetLink {
+    imdb_id: String,
+    name: String,
+    quality: Quality,
+    seeds: u32,
+    info_hash: String,
+    magnet: String,
+}
+
+pub async fn magnet_cmd(cmd: MagnetCmd, output: &Output) -> ExitCode {
+    let kind = match cmd.stream_kind() {
+        Ok(kind) => kind,
+        Err(e) => return output.error(e, ExitCode::InvalidArgs),
+    };
+    let config = Config::load();
+    let torrentio = TorrentioClient::from_config(&config);
+
+    output.info(format!("Finding streams for: {}", cmd.imdb_id));
+
+    let streams = match torrentio.streams(&cmd.imdb_id, kind).await {
+        Ok(s) if s.is_empty() => {
+            return output.error("No streams found for this content", ExitCode::NoStreams)
+        }
+        Ok(s) => s,
+        Err(e) => {
+            return output.error(
+                format!("Failed to get streams: {}", e),
+                ExitCode::NetworkError,
+            )
+        }
+    };
+
+    let stream = match pick_stream(streams, &config, cmd.quality, cmd.index, cmd.best) {
+        Ok(stream) => stream,
+        Err((msg, code)) => return output.error(msg, code),
+    };
+    // Debrid links have no torrent behind them
+    let Some(info_hash) = stream.normalized_hash() else {
+        return output.error(
+            format!("Stream has no usable info hash: {:?}", stream.info_hash),
+            ExitCode::InvalidArgs,
+        );
+    };
+    output.info(format!(
+        "Selected: {} ({}) - {} seeds",
+        stream.provider, stream.quality, stream.seeds
+    ));
+
+    let link = MagnetLink {
+        magnet: stream.to_magnet_with_trackers(&cmd.imdb_id, &config.trackers()),
+        imdb_id: cmd.imdb_id,
+        name: stream.name,
+        quality: stream.quality,
+        seeds: stream.seeds,
+        info_hash,
+    };
+    if output.json {
+        if let Err(e) = output.print_versioned("magnet", &link) {
+            return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+        }
+    } else {
+        println!("{}", link.magnet);
+    }
+    ExitCode::Success
+}
+
 // =============================================================================
 // Cast Magnet Command
 // =============================================================================
@@ -677,42 +1734,65 @@ pub async fn cast_magnet_cmd(
     device: Option<&str>,
     output: &Output,
 ) -> ExitCode {
-    // If --vlc flag is set, we don't need a device
-    let device_name = if cmd.vlc {
+    // --vlc/--mpv play locally, so we don't need a device
+    let local_player = if cmd.mpv {
+        Some(PlayerType::Mpv)
+    } else if cmd.vlc {
+        Some(PlayerType::Vlc)
+    } else {
+        None
+    };
+    let device_name = if local_player.is_some() {
         None
     } else {
         match cmd.device.as_deref().or(device) {
             Some(d) => Some(d),
             None => return output.error(
-                "No device specified. Use --device or -d flag, or use --vlc for local playback.",
+                "No device specified. Use --device or -d flag, or use --vlc/--mpv for local playback.",
                 ExitCode::DeviceNotFound,
             ),
         }
     };
 
-    // Validate magnet link
-    if !cmd.magnet.starts_with("magnet:?") {
-        return output.error(
-            "Invalid magnet link. Must start with 'magnet:?'",
-            ExitCode::InvalidArgs,
-        );
+    // Bare info hashes become magnets; .torrent files go to webtorrent as-is
+    let magnet = match normalize_magnet(&cmd.magnet) {
+        Ok(magnet) => magnet,
+        Err(e) => return output.error(e.to_string(), ExitCode::InvalidArgs),
+    };
+
+    if let Err(e) = deps::WEBTORRENT.require() {
+        return output.error(e.to_string(), ExitCode::Error);
     }
 
-    // If --vlc flag, use webtorrent's built-in VLC support
-    if cmd.vlc {
-        output.info("Playing magnet in VLC...");
+    // Local playback uses webtorrent's built-in VLC/mpv support
+    if let Some(player_type) = local_player {
+        if !player_type.is_installed() {
+            return output.error(
+                format!(
+                    "{} not found. Install it first.",
+                    player_type.display_name()
+                ),
+                ExitCode::Error,
+            );
+        }
+
+        output.info(format!(
+            "Playing magnet in {}...",
+            player_type.display_name()
+        ));
 
         let file_idx = cmd.file_idx.unwrap_or(0);
 
-        // Build webtorrent command with --vlc flag
+        // Build webtorrent command with --vlc/--mpv flag
         let mut wt_args = vec![
-            cmd.magnet.clone(),
-            "--vlc".to_string(),
+            magnet.clone(),
+            player_type.webtorrent_flag().to_string(),
             "--not-on-top".to_string(),
-            "-s".to_string(), file_idx.to_string(), // Select file index
+            "-s".to_string(),
+            file_idx.to_string(), // Select file index
         ];
 
-        // Add subtitle file if provided
+        // Validate subtitle file if provided
         if let Some(sub_file) = &cmd.subtitle_file {
             if !sub_file.exists() {
                 return output.error(
@@ -720,28 +1800,33 @@ pub async fn cast_magnet_cmd(
                     ExitCode::InvalidArgs,
                 );
             }
-            // VLC subtitle args passed through webtorrent (must be single arg with =)
-            wt_args.push(format!("--player-args=--sub-file={}", sub_file.display()));
         }
 
-        // Start webtorrent with --vlc (it handles opening VLC when ready)
+        // Player args passed through webtorrent (must be single arg with =)
+        let player_args = player_type.player_args(cmd.subtitle_file.as_deref(), cmd.start);
+        if !player_args.is_empty() {
+            wt_args.push(format!("--player-args={}", player_args.join(" ")));
+        }
+
+        // webtorrent handles opening the player when ready
         match tokio::process::Command::new("webtorrent")
             .args(&wt_args)
             .spawn()
         {
-            Ok(_child) => {
+            Ok(child) => {
+                sessions::record(&magnet, child.id(), Some(file_idx));
                 #[derive(Serialize)]
-                struct VlcSuccess {
+                struct LocalSuccess {
                     status: &'static str,
                     player: &'static str,
                     magnet: String,
                 }
-                let response = VlcSuccess {
+                let response = LocalSuccess {
                     status: "playing",
-                    player: "VLC",
-                    magnet: cmd.magnet,
+                    player: player_type.display_name(),
+                    magnet,
                 };
-                if let Err(e) = output.print(&response) {
+                if let Err(e) = output.print_versioned("playback", &response) {
                     return output.error(format!("Failed to print: {}", e), ExitCode::Error);
                 }
                 return ExitCode::Success;
@@ -749,7 +1834,7 @@ pub async fn cast_magnet_cmd(
             Err(e) => {
                 if e.kind() == std::io::ErrorKind::NotFound {
                     return output.error(
-                        "webtorrent not found. Install with: npm install -g webtorrent-cli",
+                        "webtorrent not found. Install with: npm i -g webtorrent-cli",
                         ExitCode::Error,
                     );
                 }
@@ -769,7 +1854,7 @@ pub async fn cast_magnet_cmd(
     // Build webtorrent command with --chromecast flag
     // webtorrent handles HTTP server and casting internally
     let mut wt_args = vec![
-        cmd.magnet.clone(),
+        magnet.clone(),
         "--chromecast".to_string(),
         device_name.to_string(),
         "--not-on-top".to_string(),
@@ -785,47 +1870,471 @@ pub async fn cast_magnet_cmd(
                 ExitCode::InvalidArgs,
             );
         }
-        wt_args.push("-t".to_string());
-        wt_args.push(sub_file.to_string_lossy().to_string());
-        output.info(format!("Using subtitle file: {}", sub_file.display()));
-    }
-
-    output.info("Starting torrent stream and casting to Chromecast...");
-    output.info("Note: If no audio, the source may use DTS/AC3 codec (not supported by Chromecast). Try --vlc for local playback.");
+        wt_args.push("-t".to_string());
+        wt_args.push(sub_file.to_string_lossy().to_string());
+        output.info(format!("Using subtitle file: {}", sub_file.display()));
+    }
+
+    output.info("Starting torrent stream and casting to Chromecast...");
+    output.info("Note: If no audio, the source may use DTS/AC3 codec (not supported by Chromecast). Try --vlc for local playback.");
+
+    // Start webtorrent with --chromecast (it handles everything internally)
+    match tokio::process::Command::new("webtorrent")
+        .args(&wt_args)
+        .spawn()
+    {
+        Ok(child) => {
+            sessions::record(&magnet, child.id(), Some(file_idx));
+
+            // webtorrent can't start mid-file on Chromecast - seek once it plays
+            if let Some(secs) = cmd.start.filter(|&s| s > 0) {
+                seek_when_playing(device_name, secs, output).await;
+            }
+
+            #[derive(Serialize)]
+            struct CastMagnetSuccess {
+                status: &'static str,
+                device: String,
+            }
+
+            let response = CastMagnetSuccess {
+                status: "casting",
+                device: device_name.to_string(),
+            };
+
+            if let Err(e) = output.print_versioned("playback", &response) {
+                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+            }
+
+            // webtorrent continues in background
+            ExitCode::Success
+        }
+        Err(e) => {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                output.error(
+                    "webtorrent not found. Install with: npm i -g webtorrent-cli",
+                    ExitCode::Error,
+                )
+            } else {
+                output.error(format!("Cast failed: {}", e), ExitCode::CastFailed)
+            }
+        }
+    }
+}
+
+/// Wait for the Chromecast to start playing, then seek to `secs`
+async fn seek_when_playing(device: &str, secs: u64, output: &Output) -> bool {
+    output.info(format!("Waiting for playback to resume at {}s...", secs));
+
+    // Metadata + buffering can take a while on slow torrents
+    for _ in 0..60 {
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+        let Ok(result) = tokio::process::Command::new("catt")
+            .args(["-d", device, "status"])
+            .output()
+            .await
+        else {
+            return false;
+        };
+
+        let stdout = String::from_utf8_lossy(&result.stdout);
+        let playing = crate::models::PlaybackStatus::parse_catt_status(&stdout).is_some_and(|s| {
+            s.duration.as_secs() > 0
+                && matches!(
+                    s.state,
+                    crate::models::CastState::Playing | crate::models::CastState::Paused
+                )
+        });
+
+        if playing {
+            return tokio::process::Command::new("catt")
+                .args(["-d", device, "seek", &secs.to_string()])
+                .status()
+                .await
+                .is_ok_and(|s| s.success());
+        }
+    }
+
+    output.info("Playback did not start in time, not resuming");
+    false
+}
+
+// =============================================================================
+// Resume Command
+// =============================================================================
+
+pub async fn resume_cmd(cmd: ResumeCmd, device: Option<&str>, output: &Output) -> ExitCode {
+    let config = Config::load();
+    let Some((info_hash, entry)) = config.last_resume_for(&cmd.imdb_id) else {
+        return output.error(
+            format!("No saved position for {}", cmd.imdb_id),
+            ExitCode::NoStreams,
+        );
+    };
+
+    output.info(format!(
+        "Resuming {} at {}",
+        entry.title,
+        crate::models::format_duration(std::time::Duration::from_secs(entry.position))
+    ));
+
+    let magnet = magnet_for_hash(info_hash, &entry.title, &config.trackers());
+
+    let magnet_cmd = CastMagnetCmd {
+        magnet,
+        device: cmd.device,
+        subtitle: None,
+        subtitle_file: None,
+        file_idx: entry.file_idx,
+        start: Some(entry.position),
+        vlc: cmd.vlc,
+        mpv: cmd.mpv,
+    };
+
+    cast_magnet_cmd(magnet_cmd, device, output).await
+}
+
+// =============================================================================
+// Watchlist Command
+// =============================================================================
+
+pub async fn watchlist_cmd(cmd: WatchlistCmd, output: &Output) -> ExitCode {
+    let mut config = Config::load();
+
+    match cmd.action {
+        WatchlistAction::List => {
+            if let Err(e) = output.print_list("watchlist", &config.watchlist) {
+                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+            }
+            ExitCode::Success
+        }
+        WatchlistAction::Add { id, media_type } => {
+            let client = TmdbClient::from_config(&mut config);
+
+            // Look the title up so the list shows a name
+            let entry = match media_type {
+                Some(MediaTypeFilter::Movie) => match client.movie_detail(id).await {
+                    Ok(detail) => WatchlistEntry::new(id, MediaType::Movie, detail.title),
+                    Err(e) => {
+                        return output
+                            .error(format!("Movie info failed: {}", e), ExitCode::NetworkError)
+                    }
+                },
+                Some(MediaTypeFilter::Tv) => match client.tv_detail(id).await {
+                    Ok(detail) => WatchlistEntry::new(id, MediaType::Tv, detail.name),
+                    Err(e) => {
+                        return output
+                            .error(format!("TV info failed: {}", e), ExitCode::NetworkError)
+                    }
+                },
+                None => {
+                    return output.error(
+                        "Media type required for TMDB ID lookup. Use -t movie or -t tv.",
+                        ExitCode::InvalidArgs,
+                    )
+                }
+            };
+
+            if !config.add_to_watchlist(entry.clone()) {
+                output.info(format!("{} is already in the watchlist", entry.title));
+            } else if let Err(e) = config.save() {
+                return output.error(format!("Failed to save config: {}", e), ExitCode::Error);
+            }
+
+            if let Err(e) = output.print_versioned("watchlist_entry", &entry) {
+                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+            }
+            ExitCode::Success
+        }
+        WatchlistAction::Remove { id, media_type } => {
+            let media_type = media_type.map(|t| match t {
+                MediaTypeFilter::Movie => MediaType::Movie,
+                MediaTypeFilter::Tv => MediaType::Tv,
+            });
+            if !config.remove_from_watchlist(id, media_type) {
+                return output.error(
+                    format!("{} is not in the watchlist", id),
+                    ExitCode::InvalidArgs,
+                );
+            }
+            if let Err(e) = config.save() {
+                return output.error(format!("Failed to save config: {}", e), ExitCode::Error);
+            }
+
+            #[derive(Serialize)]
+            struct RemoveOk {
+                status: &'static str,
+                id: u64,
+            }
+            if output
+                .print_versioned(
+                    "ack",
+                    &RemoveOk {
+                        status: "removed",
+                        id,
+                    },
+                )
+                .is_err()
+            {
+                return ExitCode::Error;
+            }
+            ExitCode::Success
+        }
+    }
+}
+
+// =============================================================================
+// History Command
+// =============================================================================
+
+pub async fn history_cmd(cmd: HistoryCmd, output: &Output) -> ExitCode {
+    let mut config = Config::load();
+
+    match cmd.action {
+        HistoryAction::Search { clear: false } => {
+            if let Err(e) = output.print_versioned("search_history", &config.search_history) {
+                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+            }
+            ExitCode::Success
+        }
+        HistoryAction::Search { clear: true } => {
+            let removed = std::mem::take(&mut config.search_history).len();
+            if let Err(e) = config.save() {
+                return output.error(format!("Failed to save config: {}", e), ExitCode::Error);
+            }
+
+            #[derive(Serialize)]
+            struct ClearOk {
+                status: &'static str,
+                removed: usize,
+            }
+            if output
+                .print_versioned(
+                    "ack",
+                    &ClearOk {
+                        status: "cleared",
+                        removed,
+                    },
+                )
+                .is_err()
+            {
+                return ExitCode::Error;
+            }
+            ExitCode::Success
+        }
+    }
+}
+
+// =============================================================================
+// Cache Command
+// =============================================================================
+
+pub async fn cache_cmd(cmd: CacheCmd, output: &Output) -> ExitCode {
+    match cmd.action {
+        CacheAction::Clear => {
+            let dir = TmdbClient::cache_dir();
+            let files = std::fs::read_dir(&dir).map(|d| d.count()).unwrap_or(0);
+            if dir.exists() {
+                if let Err(e) = std::fs::remove_dir_all(&dir) {
+                    return output.error(
+                        format!("Failed to clear {}: {}", dir.display(), e),
+                        ExitCode::Error,
+                    );
+                }
+            }
+
+            #[derive(Serialize)]
+            struct ClearOk {
+                status: &'static str,
+                path: String,
+                files: usize,
+            }
+            if output
+                .print(&ClearOk {
+                    status: "cleared",
+                    path: dir.display().to_string(),
+                    files,
+                })
+                .is_err()
+            {
+                return ExitCode::Error;
+            }
+            ExitCode::Success
+        }
+    }
+}
+
+// =============================================================================
+// Config Command
+// =============================================================================
+
+pub async fn config_cmd(cmd: ConfigCmd, output: &Output) -> ExitCode {
+    let mut config = Config::load();
+
+    #[derive(Serialize)]
+    struct KeyValue {
+        key: String,
+        value: serde_json::Value,
+    }
+
+    #[derive(Serialize)]
+    struct KeyOk {
+        status: &'static str,
+        key: String,
+        value: serde_json::Value,
+    }
+
+    let (status, key) = match cmd.action {
+        ConfigAction::Profile { action } => return profile_action(action, config, output),
+        ConfigAction::Path => {
+            let Some(path) = Config::path() else {
+                return output.error("Could not determine config path", ExitCode::Error);
+            };
+            if output.json {
+                #[derive(Serialize)]
+                struct ConfigPath {
+                    path: std::path::PathBuf,
+                    exists: bool,
+                }
+                let info = ConfigPath {
+                    exists: path.exists(),
+                    path,
+                };
+                if output.print_versioned("config_path", &info).is_err() {
+                    return ExitCode::Error;
+                }
+            } else {
+                println!("{}", path.display());
+            }
+            return ExitCode::Success;
+        }
+        ConfigAction::Get { key } => {
+            let value = match config.get_key(&key) {
+                Ok(value) => value,
+                Err(e) => return output.error(e.to_string(), ExitCode::InvalidArgs),
+            };
+            if output.json {
+                if output
+                    .print_versioned("config_value", &KeyValue { key, value })
+                    .is_err()
+                {
+                    return ExitCode::Error;
+                }
+            } else {
+                match value {
+                    serde_json::Value::Null => output.info(format!("{} is not set", key)),
+                    serde_json::Value::String(s) => println!("{}", s),
+                    serde_json::Value::Array(items) => {
+                        let items: Vec<String> = items
+                            .iter()
+                            .map(|v| v.as_str().map_or_else(|| v.to_string(), str::to_string))
+                            .collect();
+                        println!("{}", items.join(","));
+                    }
+                    other => println!("{}", other),
+                }
+            }
+            return ExitCode::Success;
+        }
+        ConfigAction::Set { key, value } => {
+            if let Err(e) = config.set_key(&key, &value) {
+                return output.error(e.to_string(), ExitCode::InvalidArgs);
+            }
+            ("saved", key)
+        }
+        ConfigAction::Unset { key } => {
+            if let Err(e) = config.unset_key(&key) {
+                return output.error(e.to_string(), ExitCode::InvalidArgs);
+            }
+            ("unset", key)
+        }
+    };
 
-    // Start webtorrent with --chromecast (it handles everything internally)
-    match tokio::process::Command::new("webtorrent")
-        .args(&wt_args)
-        .spawn()
+    if let Err(e) = config.save() {
+        return output.error(format!("Failed to save config: {}", e), ExitCode::Error);
+    }
+    let value = config.get_key(&key).unwrap_or_default();
+    if output
+        .print_versioned("ack", &KeyOk { status, key, value })
+        .is_err()
     {
-        Ok(_child) => {
+        return ExitCode::Error;
+    }
+    ExitCode::Success
+}
+
+/// `config profile list/use`
+fn profile_action(action: ProfileAction, mut config: Config, output: &Output) -> ExitCode {
+    match action {
+        ProfileAction::List => {
             #[derive(Serialize)]
-            struct CastMagnetSuccess {
-                status: &'static str,
-                device: String,
+            struct ProfileInfo {
+                name: String,
+                active: bool,
+                default_device: Option<String>,
             }
 
-            let response = CastMagnetSuccess {
-                status: "casting",
-                device: device_name.to_string(),
-            };
+            let profiles: Vec<ProfileInfo> = config
+                .profiles
+                .iter()
+                .map(|(name, profile)| ProfileInfo {
+                    name: name.clone(),
+                    active: config.active_profile.as_deref() == Some(name.as_str()),
+                    default_device: profile.default_device.clone(),
+                })
+                .collect();
 
-            if let Err(e) = output.print(&response) {
+            if profiles.is_empty() {
+                output.info("No profiles yet. Add a [profiles.<name>] table to config.toml.");
+            }
+            if let Err(e) = output.print_versioned("profiles", &profiles) {
                 return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
             }
-
-            // webtorrent continues in background
             ExitCode::Success
         }
-        Err(e) => {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                output.error(
-                    "webtorrent not found. Install with: npm install -g webtorrent-cli",
-                    ExitCode::Error,
-                )
+        ProfileAction::Use { name } => {
+            if name == "default" && !config.profiles.contains_key(&name) {
+                config.profile = None;
+            } else if config.profiles.contains_key(&name) {
+                config.profile = Some(name.clone());
             } else {
-                output.error(format!("Cast failed: {}", e), ExitCode::CastFailed)
+                let known: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+                return output.error(
+                    format!(
+                        "Unknown profile '{}'. Available: {}",
+                        name,
+                        known.join(", ")
+                    ),
+                    ExitCode::InvalidArgs,
+                );
+            }
+
+            if let Err(e) = config.save() {
+                return output.error(format!("Failed to save config: {}", e), ExitCode::Error);
+            }
+
+            #[derive(Serialize)]
+            struct UseOk {
+                status: &'static str,
+                profile: Option<String>,
+            }
+            if std::env::var(crate::config::PROFILE_ENV).is_ok() {
+                output.info("Note: STREAMTUI_PROFILE is set and takes precedence");
+            }
+            if output
+                .print_versioned(
+                    "profile",
+                    &UseOk {
+                        status: "saved",
+                        profile: config.profile,
+                    },
+                )
+                .is_err()
+            {
+                return ExitCode::Error;
             }
+            ExitCode::Success
         }
     }
 }
@@ -835,15 +2344,19 @@ pub async fn cast_magnet_cmd(
 // =============================================================================
 
 pub async fn play_local_cmd(cmd: PlayLocalCmd, output: &Output) -> ExitCode {
-    // Validate magnet link
-    if !cmd.magnet.starts_with("magnet:?") {
-        return output.error(
-            "Invalid magnet link. Must start with 'magnet:?'",
-            ExitCode::InvalidArgs,
-        );
-    }
+    let magnet = match normalize_magnet(&cmd.magnet) {
+        Ok(magnet) => magnet,
+        Err(e) => return output.error(e.to_string(), ExitCode::InvalidArgs),
+    };
 
-    let player_type = to_player_type(cmd.player);
+    let (player_type, warning) = PlayerType::select(
+        cmd.player.map(to_player_type),
+        Config::load().default_local_player(),
+        &PlayerType::installed(),
+    );
+    if let Some(warning) = warning {
+        output.info(warning);
+    }
     output.info(format!(
         "Playing magnet in {}...",
         player_type.display_name()
@@ -872,7 +2385,7 @@ pub async fn play_local_cmd(cmd: PlayLocalCmd, output: &Output) -> ExitCode {
 
     // Start webtorrent in background
     let webtorrent = match tokio::process::Command::new("webtorrent")
-        .arg(&cmd.magnet)
+        .arg(&magnet)
         .arg("--port")
         .arg(port.to_string())
         .arg("-s")
@@ -887,7 +2400,7 @@ pub async fn play_local_cmd(cmd: PlayLocalCmd, output: &Output) -> ExitCode {
         Err(e) => {
             if e.kind() == std::io::ErrorKind::NotFound {
                 return output.error(
-                    "webtorrent not found. Install with: npm install -g webtorrent-cli",
+                    "webtorrent not found. Install with: npm i -g webtorrent-cli",
                     ExitCode::Error,
                 );
             }
@@ -924,84 +2437,351 @@ pub async fn play_local_cmd(cmd: PlayLocalCmd, output: &Output) -> ExitCode {
     let _ = webtorrent;
 
     // Play locally
-    play_locally(&stream_url, subtitle_path, player_type, output).await
+    play_locally(&stream_url, subtitle_path, cmd.start, player_type, output).await
+}
+
+// =============================================================================
+// Doctor Command
+// =============================================================================
+
+pub async fn doctor_cmd(_cmd: DoctorCmd, output: &Output) -> ExitCode {
+    #[derive(Serialize)]
+    struct ToolStatus {
+        name: &'static str,
+        purpose: &'static str,
+        installed: bool,
+        version: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        install: Option<&'static str>,
+    }
+
+    #[derive(Serialize)]
+    struct EndpointStatus {
+        name: &'static str,
+        url: &'static str,
+        reachable: bool,
+        http_status: Option<u16>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    }
+
+    #[derive(Serialize)]
+    struct TmdbKeyStatus {
+        /// "env", "config" or "pool"
+        source: &'static str,
+        /// None when TMDB couldn't be reached
+        accepted: Option<bool>,
+    }
+
+    #[derive(Serialize)]
+    struct DoctorReport {
+        status: &'static str,
+        tools: Vec<ToolStatus>,
+        tmdb_key: TmdbKeyStatus,
+        network: Vec<EndpointStatus>,
+    }
+
+    let tools: Vec<Dependency> = [deps::WEBTORRENT, deps::CATT]
+        .into_iter()
+        .chain(PlayerType::ALL.iter().map(PlayerType::dependency))
+        .chain([deps::CHAFA])
+        .collect();
+
+    let mut report = Vec::new();
+    for dep in &tools {
+        let installed = dep.is_installed();
+        let version = if installed { dep.version().await } else { None };
+        report.push(ToolStatus {
+            name: dep.name,
+            purpose: dep.purpose,
+            installed,
+            version,
+            install: (!installed).then_some(dep.install),
+        });
+    }
+
+    // Don't pick (and save) a pool key just to diagnose
+    let config = Config::load();
+    let key_source = config.tmdb_key_source();
+    let tmdb_key = std::env::var("TMDB_API_KEY")
+        .ok()
+        .or(config.tmdb_api_key.clone())
+        .unwrap_or_else(Config::random_pool_key);
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new());
+    let endpoints = [
+        ("TMDB", "https://api.themoviedb.org/3/configuration"),
+        ("Torrentio", "https://torrentio.strem.fun/manifest.json"),
+    ];
+    let mut network = Vec::new();
+    for (name, url) in endpoints {
+        let mut request = client.get(url);
+        if name == "TMDB" {
+            request = request.query(&[("api_key", tmdb_key.as_str())]);
+        }
+        // Any HTTP answer means the host is reachable
+        let (http_status, error) = match request.send().await {
+            Ok(response) => (Some(response.status().as_u16()), None),
+            // Drop the URL from the message, it carries the API key
+            Err(e) => (None, Some(e.without_url().to_string())),
+        };
+        network.push(EndpointStatus {
+            name,
+            url,
+            reachable: http_status.is_some(),
+            http_status,
+            error,
+        });
+    }
+    let accepted = network[0].http_status.map(|s| s != 401);
+
+    // Torrents need webtorrent, and something has to play them
+    let found = |name: &str| report.iter().any(|t| t.name == name && t.installed);
+    let can_play =
+        found(deps::CATT.name) || PlayerType::ALL.iter().any(|p| found(p.display_name()));
+    let ready = found(deps::WEBTORRENT.name) && can_play;
+
+    let doctor = DoctorReport {
+        status: if ready { "ok" } else { "missing" },
+        tools: report,
+        tmdb_key: TmdbKeyStatus {
+            source: key_source,
+            accepted,
+        },
+        network,
+    };
+
+    if output.json {
+        if output.print_versioned("doctor", &doctor).is_err() {
+            return ExitCode::Error;
+        }
+    } else {
+        println!("Tools:");
+        for tool in &doctor.tools {
+            match (tool.installed, &tool.version) {
+                (true, Some(version)) => {
+                    println!("  ✓ {:<11} {:<9} {}", tool.name, version, tool.purpose)
+                }
+                (true, None) => println!("  ✓ {:<11} {:<9} {}", tool.name, "?", tool.purpose),
+                (false, _) => println!(
+                    "  ✗ {:<11} {:<9} {} (install: {})",
+                    tool.name,
+                    "missing",
+                    tool.purpose,
+                    tool.install.unwrap_or_default()
+                ),
+            }
+        }
+
+        let key = match doctor.tmdb_key.source {
+            "env" => "TMDB_API_KEY",
+            "config" => "config.toml",
+            _ => "bundled key",
+        };
+        let verdict = match doctor.tmdb_key.accepted {
+            Some(true) => "accepted",
+            Some(false) => "rejected",
+            None => "not checked",
+        };
+        println!("\nTMDB key: {} ({})", key, verdict);
+
+        println!("\nNetwork:");
+        for endpoint in &doctor.network {
+            match (endpoint.http_status, &endpoint.error) {
+                (Some(status), _) => println!("  ✓ {:<11} HTTP {}", endpoint.name, status),
+                (None, error) => println!(
+                    "  ✗ {:<11} {}",
+                    endpoint.name,
+                    error.as_deref().unwrap_or("unreachable")
+                ),
+            }
+        }
+    }
+
+    if ready {
+        ExitCode::Success
+    } else {
+        ExitCode::Error
+    }
 }
 
 // =============================================================================
 // Status Command
 // =============================================================================
 
-pub async fn status_cmd(_cmd: StatusCmd, device: Option<&str>, output: &Output) -> ExitCode {
-    let mut catt_args = vec!["status".to_string()];
+pub async fn status_cmd(cmd: StatusCmd, device: Option<&str>, output: &Output) -> ExitCode {
+    if cmd.sessions {
+        return sessions_status(output);
+    }
+    if cmd.watch {
+        let watch = WatchCmd {
+            interval: cmd.interval,
+            until_idle: false,
+        };
+        return watch_cmd(watch, device, output).await;
+    }
+
+    match query_status(device).await {
+        Ok(status) => {
+            if let Err(e) = output.print_versioned("status", &status) {
+                return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+            }
+            ExitCode::Success
+        }
+        Err((msg, code)) => output.error(msg, code),
+    }
+}
 
+/// `catt status` for `device`, plus whether one of our torrent streams is running
+///
+/// Fails with `DeviceNotFound` when catt can't reach the device; an idle or
+/// inactive Chromecast is an idle status.
+async fn query_status(device: Option<&str>) -> Result<PlaybackStatus, (String, ExitCode)> {
+    let mut catt = tokio::process::Command::new("catt");
     if let Some(d) = device {
-        catt_args.insert(0, "-d".to_string());
-        catt_args.insert(1, d.to_string());
+        catt.arg("-d").arg(d);
     }
+    catt.arg("status").kill_on_drop(true);
+
+    // An unreachable Chromecast can leave catt waiting for a long time
+    let result = match tokio::time::timeout(STATUS_TIMEOUT, catt.output()).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err((
+                "catt not found. Install with: pip install catt".to_string(),
+                ExitCode::Error,
+            ));
+        }
+        Ok(Err(e)) => return Err((format!("catt status failed: {}", e), ExitCode::Error)),
+        Err(_) => {
+            return Err((
+                format!(
+                    "No answer from {} after {}s",
+                    device.unwrap_or("the device"),
+                    STATUS_TIMEOUT.as_secs()
+                ),
+                ExitCode::DeviceNotFound,
+            ));
+        }
+    };
 
-    match tokio::process::Command::new("catt")
-        .args(&catt_args)
-        .output()
-        .await
-    {
-        Ok(result) => {
-            let stdout = String::from_utf8_lossy(&result.stdout);
-
-            if let Some(status) = crate::models::PlaybackStatus::parse_catt_status(&stdout) {
-                // Compute values before moving
-                let progress = status.progress() as f64;
-                let position = status.position.as_secs();
-                let duration = status.duration.as_secs();
-                let volume = (status.volume * 100.0) as u8;
-
-                // Convert to CLI PlaybackStatus
-                let cli_status = PlaybackStatus {
-                    state: match status.state {
-                        crate::models::CastState::Idle => PlaybackState::Idle,
-                        crate::models::CastState::Buffering => PlaybackState::Buffering,
-                        crate::models::CastState::Playing => PlaybackState::Playing,
-                        crate::models::CastState::Paused => PlaybackState::Paused,
-                        crate::models::CastState::Stopped => PlaybackState::Stopped,
-                        crate::models::CastState::Connecting => PlaybackState::Buffering,
-                        crate::models::CastState::Error(_) => PlaybackState::Error,
-                    },
-                    title: status.title,
-                    device: device.map(String::from),
-                    position: Some(position),
-                    duration: Some(duration),
-                    progress: Some(progress),
-                    volume: Some(volume),
-                };
+    let mut status = PlaybackStatus::from_catt(
+        result.status.success(),
+        &String::from_utf8_lossy(&result.stdout),
+        &String::from_utf8_lossy(&result.stderr),
+    )?;
+    status.device = device.map(String::from);
+    status.streaming = !sessions::prune_stale().is_empty();
+    Ok(status)
+}
 
-                if let Err(e) = output.print_json(&cli_status) {
-                    return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+// =============================================================================
+// Watch Command
+// =============================================================================
+
+pub async fn watch_cmd(cmd: WatchCmd, device: Option<&str>, output: &Output) -> ExitCode {
+    let interval = std::time::Duration::from_secs(cmd.interval.max(1));
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    // Only changes are printed; an unreachable device is reported once
+    let mut last: Option<Result<PlaybackStatus, String>> = None;
+    let mut was_active = false;
+
+    loop {
+        let result = tokio::select! {
+            _ = &mut ctrl_c => return ExitCode::Success,
+            result = query_status(device) => result,
+        };
+
+        match result {
+            Ok(status) => {
+                let changed = !matches!(&last, Some(Ok(prev)) if same_status(prev, &status));
+                if changed {
+                    let printed = if output.json {
+                        output.print_event("status", &status)
+                    } else {
+                        println!("{}", status.summary());
+                        Ok(())
+                    };
+                    if let Err(e) = printed {
+                        return output
+                            .error(format!("Failed to serialize: {}", e), ExitCode::Error);
+                    }
                 }
-                ExitCode::Success
-            } else {
-                // Return idle status if can't parse
-                let status = PlaybackStatus::default();
-                if let Err(e) = output.print_json(&status) {
-                    return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+
+                let idle = matches!(status.state, PlaybackState::Idle | PlaybackState::Stopped);
+                if cmd.until_idle && idle && was_active {
+                    return ExitCode::Success;
                 }
-                ExitCode::Success
+                was_active |= !idle;
+                last = Some(Ok(status));
             }
-        }
-        Err(e) => {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                output.error(
-                    "catt not found. Install with: pip install catt",
-                    ExitCode::Error,
-                )
-            } else {
-                // Return idle on error
-                let status = PlaybackStatus::default();
-                if output.print_json(&status).is_err() {
-                    return ExitCode::Error;
+            // catt itself is missing: polling again won't help
+            Err((msg, ExitCode::Error)) => return output.error(msg, ExitCode::Error),
+            Err((msg, code)) => {
+                if !matches!(&last, Some(Err(prev)) if *prev == msg) {
+                    output.error(msg.clone(), code);
                 }
-                ExitCode::Success
+                last = Some(Err(msg));
+            }
+        }
+
+        tokio::select! {
+            _ = &mut ctrl_c => return ExitCode::Success,
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+}
+
+/// Whether two polls would print the same thing
+fn same_status(a: &PlaybackStatus, b: &PlaybackStatus) -> bool {
+    match (serde_json::to_value(a), serde_json::to_value(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// A running torrent stream, as listed by `status --sessions`
+#[derive(Serialize)]
+struct SessionStatus {
+    info_hash: String,
+    pid: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_idx: Option<u32>,
+}
+
+fn sessions_status(output: &Output) -> ExitCode {
+    let list: Vec<SessionStatus> = sessions::prune_stale()
+        .entries
+        .into_iter()
+        .flat_map(|(info_hash, list)| {
+            list.into_iter().map(move |s| SessionStatus {
+                info_hash: info_hash.clone(),
+                pid: s.pid,
+                file_idx: s.file_idx,
+            })
+        })
+        .collect();
+
+    if output.json {
+        if let Err(e) = output.print_versioned("sessions", &list) {
+            return output.error(format!("Failed to serialize: {}", e), ExitCode::Error);
+        }
+    } else if list.is_empty() {
+        output.info("No torrent streams running");
+    } else {
+        for s in &list {
+            match s.file_idx {
+                Some(idx) => println!("{}  file {}  (pid {})", s.info_hash, idx, s.pid),
+                None => println!("{}  (pid {})", s.info_hash, s.pid),
             }
         }
     }
+    ExitCode::Success
 }
 
 // =============================================================================
@@ -1017,22 +2797,84 @@ pub async fn pause_cmd(_cmd: PauseCmd, device: Option<&str>, output: &Output) ->
 }
 
 pub async fn stop_cmd(cmd: StopCmd, device: Option<&str>, output: &Output) -> ExitCode {
-    let result = playback_control("stop", device, output).await;
+    if let Some(ref hash) = cmd.info_hash {
+        if !sessions::prune_stale()
+            .entries
+            .contains_key(&hash.to_lowercase())
+        {
+            return output.error(
+                format!("No torrent stream running for {}", hash),
+                ExitCode::InvalidArgs,
+            );
+        }
+    }
 
-    if cmd.kill_stream && result == ExitCode::Success {
-        // Try to kill any running webtorrent processes
-        let _ = tokio::process::Command::new("pkill")
-            .arg("-f")
-            .arg("webtorrent")
-            .output()
-            .await;
-        output.info("Stopped torrent stream");
+    if !cmd.kill_stream && cmd.info_hash.is_none() {
+        return playback_control("stop", device, output).await;
+    }
+
+    // The torrent goes whether or not a Chromecast answers. Only streams we
+    // started; a targeted stop leaves the others running
+    let cast = run_catt("stop", device).await;
+    let streams = sessions::stop(cmd.info_hash.as_deref());
+
+    #[derive(Serialize)]
+    struct StopReport {
+        status: &'static str,
+        cast_stopped: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cast_error: Option<String>,
+        streams_stopped: Vec<String>,
     }
 
-    result
+    if let Err(ref e) = cast {
+        output.info(format!("Cast not stopped: {}", e));
+    }
+    for hash in &streams {
+        output.info(format!("Stopped torrent stream {}", hash));
+    }
+    let code = if cast.is_ok() || !streams.is_empty() {
+        ExitCode::Success
+    } else {
+        ExitCode::Error
+    };
+    let report = StopReport {
+        status: match (&cast, streams.is_empty()) {
+            (Ok(()), _) => "ok",
+            (Err(_), false) => "partial",
+            (Err(_), true) => "failed",
+        },
+        cast_stopped: cast.is_ok(),
+        cast_error: cast.err(),
+        streams_stopped: streams,
+    };
+    if output.print_versioned("ack", &report).is_err() {
+        return ExitCode::Error;
+    }
+    code
 }
 
 async fn playback_control(action: &str, device: Option<&str>, output: &Output) -> ExitCode {
+    match run_catt(action, device).await {
+        Ok(()) => {
+            #[derive(Serialize)]
+            struct ActionOk {
+                status: &'static str,
+            }
+            if output
+                .print_versioned("ack", &ActionOk { status: "ok" })
+                .is_err()
+            {
+                return ExitCode::Error;
+            }
+            ExitCode::Success
+        }
+        Err(msg) => output.error(msg, ExitCode::Error),
+    }
+}
+
+/// Run `catt <action>`, with the message to show when it fails
+async fn run_catt(action: &str, device: Option<&str>) -> Result<(), String> {
     let mut catt_args = vec![action.to_string()];
 
     if let Some(d) = device {
@@ -1045,31 +2887,15 @@ async fn playback_control(action: &str, device: Option<&str>, output: &Output) -
         .output()
         .await
     {
+        Ok(result) if result.status.success() => Ok(()),
         Ok(result) => {
-            if result.status.success() {
-                #[derive(Serialize)]
-                struct ActionOk {
-                    status: &'static str,
-                }
-                if output.print(&ActionOk { status: "ok" }).is_err() {
-                    return ExitCode::Error;
-                }
-                ExitCode::Success
-            } else {
-                let stderr = String::from_utf8_lossy(&result.stderr);
-                output.error(format!("{} failed: {}", action, stderr), ExitCode::Error)
-            }
+            let stderr = String::from_utf8_lossy(&result.stderr);
+            Err(format!("{} failed: {}", action, stderr))
         }
-        Err(e) => {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                output.error(
-                    "catt not found. Install with: pip install catt",
-                    ExitCode::Error,
-                )
-            } else {
-                output.error(format!("{} failed: {}", action, e), ExitCode::Error)
-            }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            Err("catt not found. Install with: pip install catt".to_string())
         }
+        Err(e) => Err(format!("{} failed: {}", action, e)),
     }
 }
 
@@ -1078,7 +2904,7 @@ async fn playback_control(action: &str, device: Option<&str>, output: &Output) -
 // =============================================================================
 
 pub async fn seek_cmd(cmd: SeekCmd, device: Option<&str>, output: &Output) -> ExitCode {
-    let position = match cmd.parse_position() {
+    let position = match cmd.parse_position_with_step(Config::load().seek_step()) {
         SeekPosition::Absolute(secs) => secs as f64,
         SeekPosition::Forward(secs) => {
             // Need to get current position first for relative seek
@@ -1185,27 +3011,19 @@ async fn relative_seek(delta: i64, device: Option<&str>, output: &Output) -> Exi
 // =============================================================================
 
 pub async fn volume_cmd(cmd: VolumeCmd, device: Option<&str>, output: &Output) -> ExitCode {
-    let level = match cmd.parse_level() {
-        VolumeLevel::Absolute(vol) => vol,
-        VolumeLevel::Relative(delta) => {
-            // For relative volume, we need current volume first
-            // catt volume accepts 0-100, and "volume up/down" for relative
-            if delta > 0 {
-                return volume_relative("volumeup", delta as u8, device, output).await;
-            } else {
-                return volume_relative("volumedown", (-delta) as u8, device, output).await;
-            }
-        }
-        VolumeLevel::Invalid(s) => {
+    // Absolute and relative levels share one path so the TUI (which shells
+    // out to `volume +10` / `volume 50`) behaves exactly like the CLI
+    let level = cmd.parse_level();
+    let mut catt_args = match level.catt_args() {
+        Some(args) => args,
+        None => {
             return output.error(
-                format!("Invalid volume level: {}", s),
+                format!("Invalid volume level: {}", cmd.level),
                 ExitCode::InvalidArgs,
             );
         }
     };
 
-    let mut catt_args = vec!["volume".to_string(), level.to_string()];
-
     if let Some(d) = device {
         catt_args.insert(0, "-d".to_string());
         catt_args.insert(1, d.to_string());
@@ -1221,12 +3039,17 @@ pub async fn volume_cmd(cmd: VolumeCmd, device: Option<&str>, output: &Output) -
                 #[derive(Serialize)]
                 struct VolumeOk {
                     status: &'static str,
-                    volume: u8,
+                    #[serde(skip_serializing_if = "Option::is_none")]
+                    volume: Option<u8>,
                 }
+                let volume = match level {
+                    VolumeLevel::Absolute(vol) => Some(vol),
+                    _ => None,
+                };
                 if output
                     .print(&VolumeOk {
                         status: "ok",
-                        volume: level,
+                        volume,
                     })
                     .is_err()
                 {
@@ -1241,41 +3064,3 @@ pub async fn volume_cmd(cmd: VolumeCmd, device: Option<&str>, output: &Output) -
         Err(e) => output.error(format!("Volume failed: {}", e), ExitCode::Error),
     }
 }
-
-async fn volume_relative(
-    action: &str,
-    _steps: u8,
-    device: Option<&str>,
-    output: &Output,
-) -> ExitCode {
-    // catt doesn't have stepped volume, just volumeup/volumedown
-    let mut catt_args = vec![action.to_string()];
-
-    if let Some(d) = device {
-        catt_args.insert(0, "-d".to_string());
-        catt_args.insert(1, d.to_string());
-    }
-
-    match tokio::process::Command::new("catt")
-        .args(&catt_args)
-        .output()
-        .await
-    {
-        Ok(result) => {
-            if result.status.success() {
-                #[derive(Serialize)]
-                struct VolumeOk {
-                    status: &'static str,
-                }
-                if output.print(&VolumeOk { status: "ok" }).is_err() {
-                    return ExitCode::Error;
-                }
-                ExitCode::Success
-            } else {
-                let stderr = String::from_utf8_lossy(&result.stderr);
-                output.error(format!("Volume failed: {}", stderr), ExitCode::Error)
-            }
-        }
-        Err(e) => output.error(format!("Volume failed: {}", e), ExitCode::Error),
-    }
-}
@@ -2,10 +2,54 @@
 //!
 //! Handles config file loading/saving and API key management.
 //! Config is stored at ~/.config/streamtui/config.toml
+//!
+//! Named `[profiles.<name>]` tables override a subset of the settings (one per
+//! household); the selected profile is merged over the top-level values on
+//! load and changes to those settings are saved back into it.
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+
+use crate::api::torrentio::{TorrentioConfig, TorrentioSort};
+use crate::api::RetryPolicy;
+use crate::keymap::KeyMap;
+use crate::models::{
+    CastDevice, MediaType, Quality, SelectionPrefs, DEFAULT_TRACKERS, HEALTHY_SEEDS,
+};
+use crate::stream::player::PlayerType;
+use crate::stream::subtitles::ProviderChoice;
+use crate::ui::Palette;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+/// Environment variable selecting a profile (overridden by `--profile`)
+pub const PROFILE_ENV: &str = "STREAMTUI_PROFILE";
+
+/// Environment variable turning on ASCII mode (any value but empty or "0")
+pub const ASCII_ENV: &str = "STREAMTUI_ASCII";
+
+/// Profile chosen with the global `--profile` flag
+static PROFILE_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+/// Playback log chosen with `--log-file`
+static LOG_FILE_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Subtitle charset chosen with `--subtitle-encoding`
+static SUBTITLE_ENCODING_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+/// Searches kept in `search_history`
+pub const SEARCH_HISTORY_CAP: usize = 50;
+
+/// `catt scan` runs per discovery when `scan_attempts` isn't set
+pub const DEFAULT_SCAN_ATTEMPTS: u32 = 2;
+
+/// Trending titles on the home screen when `home_limit` isn't set
+pub const DEFAULT_HOME_LIMIT: usize = 20;
+
+/// Seconds `←`/`→` (and `seek +x`) jump when `seek_step_secs` isn't set
+pub const DEFAULT_SEEK_STEP: u32 = 30;
 
 /// Bundled TMDB API keys (from freekeys pool)
 const TMDB_KEY_POOL: &[&str] = &[
@@ -41,6 +85,362 @@ pub struct Config {
     pub default_subtitle_lang: Option<String>,
     /// Preferred subtitle languages (3-letter codes: eng, spa, fre, etc.)
     pub subtitle_languages: Option<Vec<String>>,
+    /// Last language list typed for the TUI's custom subtitle filter (e.g. "fre,jpn")
+    pub custom_subtitle_lang: Option<String>,
+    /// RealDebrid API key (https://real-debrid.com/apitoken) for direct HTTP streams
+    pub debrid_api_key: Option<String>,
+    /// Torrentio providers to search (e.g. ["yts", "eztv"]; default: all)
+    pub torrentio_providers: Option<Vec<String>>,
+    /// Torrentio sort order: quality (default), qualitysize, seeders or size
+    pub torrentio_sort: Option<String>,
+    /// OpenSubtitles API key (https://www.opensubtitles.com/consumers)
+    pub opensubtitles_api_key: Option<String>,
+    /// Subtitle search backend: stremio (default), opensubtitles or both
+    pub subtitle_provider: Option<String>,
+    /// Charset to read subtitles as (e.g. "windows-1252", "utf-16"; default: detected)
+    pub subtitle_encoding: Option<String>,
+    /// Remember playback positions and offer to resume them
+    #[serde(default)]
+    pub enable_resume: bool,
+    /// Show TMDB posters in the detail view (kitty/sixel terminals with chafa)
+    #[serde(default)]
+    pub show_posters: bool,
+    /// Color palette: neon (default), solarized, mono or matrix
+    pub theme: Option<String>,
+    /// Plain ASCII symbols instead of emoji and box drawing (TUI)
+    #[serde(default)]
+    pub ascii_mode: bool,
+    /// Start the next episode automatically when one ends (TUI)
+    #[serde(default)]
+    pub autoplay_next: bool,
+    /// Switch to a lower-quality source when buffering stalls (TUI)
+    #[serde(default)]
+    pub auto_downgrade: bool,
+    /// Keep TMDB results flagged as adult in searches and listings
+    #[serde(default)]
+    pub include_adult: bool,
+    /// Reopen the last search and title when the TUI starts
+    #[serde(default)]
+    pub restore_session: bool,
+    /// Player for local playback: "vlc" or "mpv" (default: whichever is installed)
+    pub default_local_player: Option<String>,
+    /// Seconds buffering may stand still before that (default 30)
+    pub stall_secs: Option<u64>,
+    /// Weight of quality when auto-picking a stream (default 3)
+    pub prefer_quality: Option<u32>,
+    /// Weight of seed count when auto-picking a stream (default 2)
+    pub prefer_seeds: Option<u32>,
+    /// Seed floor for hiding sources in the TUI (`h`, default 10)
+    pub min_seeds: Option<u32>,
+    /// Trending titles on the TUI home screen before `m` shows the rest
+    /// (default 20, 0 shows all)
+    pub home_limit: Option<usize>,
+    /// Preferred file size range in GB when auto-picking a stream
+    pub prefer_size_min_gb: Option<f64>,
+    pub prefer_size_max_gb: Option<f64>,
+    /// How long cached TMDB responses stay fresh (default 3600, 0 disables)
+    pub tmdb_cache_ttl_secs: Option<u64>,
+    /// Retries for failed TMDB/Torrentio requests on flaky networks (default 3)
+    pub max_retries: Option<u32>,
+    /// Seconds before a TMDB/Torrentio/subtitle request is abandoned (default 15)
+    pub request_timeout_secs: Option<u64>,
+    /// `catt scan` runs per device discovery, merged together (default 2)
+    pub scan_attempts: Option<u32>,
+    /// Seconds a rewind/fast-forward jumps (default 30; shift jumps 5x)
+    pub seek_step_secs: Option<u32>,
+    /// Playback log file (default ~/.cache/streamtui/playback.log); setting
+    /// it also logs streamtui's own playback steps
+    pub log_path: Option<PathBuf>,
+    /// Trackers added to generated magnets (default: a built-in public set;
+    /// an empty list leaves peer discovery to the DHT)
+    pub trackers: Option<Vec<String>>,
+    /// Profile loaded when neither `--profile` nor STREAMTUI_PROFILE is given
+    pub profile: Option<String>,
+    /// Device names in the order the device picker lists them
+    #[serde(default)]
+    pub device_order: Vec<String>,
+    /// Recent search queries, most recent first
+    #[serde(default)]
+    pub search_history: Vec<String>,
+    /// Key overrides: action name -> key string (e.g. `search = "ctrl+f"`)
+    #[serde(default)]
+    pub keybindings: BTreeMap<String, String>,
+    /// Named setting overrides, e.g. `[profiles.home]`
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    /// Starred titles
+    #[serde(default)]
+    pub watchlist: Vec<WatchlistEntry>,
+    /// Devices found by the last scan
+    #[serde(default)]
+    pub known_devices: Vec<SavedDevice>,
+    /// Saved playback positions keyed by torrent info_hash
+    /// (tables kept last - TOML tables must follow plain values)
+    #[serde(default)]
+    pub resume: BTreeMap<String, ResumeEntry>,
+    /// Profile merged into this config by `load()`, if any
+    #[serde(skip)]
+    pub active_profile: Option<String>,
+    /// Top-level values of the profile settings, before the merge
+    #[serde(skip)]
+    defaults: Profile,
+}
+
+/// Per-household settings under `[profiles.<name>]`
+///
+/// Unset fields fall back to the top-level value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub tmdb_api_key: Option<String>,
+    pub debrid_api_key: Option<String>,
+    pub default_device: Option<String>,
+    pub default_subtitle_lang: Option<String>,
+    pub preferred_quality: Option<String>,
+    pub device_order: Option<Vec<String>>,
+    /// (arrays of tables kept last)
+    pub known_devices: Option<Vec<SavedDevice>>,
+}
+
+impl Profile {
+    /// The profile settings as they currently are in `config`
+    fn capture(config: &Config) -> Self {
+        Self {
+            tmdb_api_key: config.tmdb_api_key.clone(),
+            debrid_api_key: config.debrid_api_key.clone(),
+            default_device: config.default_device.clone(),
+            default_subtitle_lang: config.default_subtitle_lang.clone(),
+            preferred_quality: config.preferred_quality.clone(),
+            device_order: Some(config.device_order.clone()),
+            known_devices: Some(config.known_devices.clone()),
+        }
+    }
+
+    /// Override `config` with every field set in this profile
+    fn apply(&self, config: &mut Config) {
+        config.tmdb_api_key = self.tmdb_api_key.clone().or(config.tmdb_api_key.take());
+        config.debrid_api_key = self.debrid_api_key.clone().or(config.debrid_api_key.take());
+        config.default_device = self.default_device.clone().or(config.default_device.take());
+        config.default_subtitle_lang = self
+            .default_subtitle_lang
+            .clone()
+            .or(config.default_subtitle_lang.take());
+        config.preferred_quality = self
+            .preferred_quality
+            .clone()
+            .or(config.preferred_quality.take());
+        if let Some(order) = &self.device_order {
+            config.device_order = order.clone();
+        }
+        if let Some(devices) = &self.known_devices {
+            config.known_devices = devices.clone();
+        }
+    }
+
+    /// Fold `current` values into this profile
+    ///
+    /// A field is stored once it differs from the top-level `defaults` (or was
+    /// already set), so inherited values keep following the defaults.
+    fn absorb(&mut self, current: Profile, defaults: &Profile) {
+        fn pick<T: PartialEq>(stored: &mut Option<T>, current: Option<T>, default: &Option<T>) {
+            if stored.is_some() || current != *default {
+                *stored = current;
+            }
+        }
+        pick(
+            &mut self.tmdb_api_key,
+            current.tmdb_api_key,
+            &defaults.tmdb_api_key,
+        );
+        pick(
+            &mut self.debrid_api_key,
+            current.debrid_api_key,
+            &defaults.debrid_api_key,
+        );
+        pick(
+            &mut self.default_device,
+            current.default_device,
+            &defaults.default_device,
+        );
+        pick(
+            &mut self.default_subtitle_lang,
+            current.default_subtitle_lang,
+            &defaults.default_subtitle_lang,
+        );
+        pick(
+            &mut self.preferred_quality,
+            current.preferred_quality,
+            &defaults.preferred_quality,
+        );
+        pick(
+            &mut self.device_order,
+            current.device_order,
+            &defaults.device_order,
+        );
+        pick(
+            &mut self.known_devices,
+            current.known_devices,
+            &defaults.known_devices,
+        );
+    }
+}
+
+/// A starred title in the watchlist
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchlistEntry {
+    /// TMDB ID
+    pub id: u64,
+    pub media_type: MediaType,
+    pub title: String,
+    /// Unix timestamp when the title was added
+    pub added_at: u64,
+}
+
+impl WatchlistEntry {
+    /// Create an entry stamped with the current time
+    pub fn new(id: u64, media_type: MediaType, title: impl Into<String>) -> Self {
+        Self {
+            id,
+            media_type,
+            title: title.into(),
+            added_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        }
+    }
+
+    /// Whether this entry refers to the given title
+    pub fn matches(&self, id: u64, media_type: MediaType) -> bool {
+        self.id == id && self.media_type == media_type
+    }
+}
+
+/// A Chromecast remembered from an earlier scan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedDevice {
+    pub name: String,
+    pub id: String,
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
+impl From<&CastDevice> for SavedDevice {
+    fn from(device: &CastDevice) -> Self {
+        Self {
+            name: device.name.clone(),
+            id: device.id.clone(),
+            model: device.model.clone(),
+        }
+    }
+}
+
+impl SavedDevice {
+    /// Device entry for a saved device that didn't answer this scan
+    ///
+    /// catt finds devices by name, so the address is only a placeholder.
+    pub fn to_cast_device(&self) -> CastDevice {
+        CastDevice {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED),
+            port: 8009,
+            model: self.model.clone(),
+        }
+    }
+}
+
+/// Merge a device scan with saved devices, in the user's order
+///
+/// Devices named in `order` come first, in that order, followed by the rest
+/// of the scan as discovered. Ordered devices that missed this scan (mDNS
+/// drops one now and then) are kept so the list doesn't reshuffle.
+pub fn arrange_devices(
+    discovered: Vec<CastDevice>,
+    known: &[SavedDevice],
+    order: &[String],
+) -> Vec<CastDevice> {
+    let mut devices = discovered;
+    for saved in known {
+        if order.contains(&saved.name) && !devices.iter().any(|d| d.name == saved.name) {
+            devices.push(saved.to_cast_device());
+        }
+    }
+    devices.sort_by_key(|d| {
+        order
+            .iter()
+            .position(|name| *name == d.name)
+            .unwrap_or(usize::MAX)
+    });
+    devices
+}
+
+/// Move `query` to the front of a search history, capped at `SEARCH_HISTORY_CAP`
+///
+/// Repeats (ignoring case) are merged into the new entry; blank queries are
+/// not recorded.
+pub fn push_search(history: &mut Vec<String>, query: &str) {
+    let query = query.trim();
+    if query.is_empty() {
+        return;
+    }
+    history.retain(|q| !q.eq_ignore_ascii_case(query));
+    history.insert(0, query.to_string());
+    history.truncate(SEARCH_HISTORY_CAP);
+}
+
+/// Saved playback position for a single torrent
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResumeEntry {
+    /// Last known position in seconds
+    pub position: u64,
+    /// IMDB ID of the title (used by `streamtui resume`)
+    pub imdb_id: Option<String>,
+    /// Display title
+    pub title: String,
+    /// File index within the torrent
+    pub file_idx: Option<u32>,
+    /// Unix timestamp of the last update
+    pub updated_at: u64,
+    /// Total duration in seconds (0 when unknown)
+    #[serde(default)]
+    pub duration: u64,
+    /// Season number for TV episodes
+    #[serde(default)]
+    pub season: Option<u8>,
+    /// Episode number for TV episodes
+    #[serde(default)]
+    pub episode: Option<u8>,
+}
+
+/// A partially watched title shown in the Home "Continue Watching" row
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryEntry {
+    /// Torrent the position was saved for
+    pub info_hash: String,
+    /// IMDB ID used to refetch sources
+    pub imdb_id: String,
+    /// Display title
+    pub title: String,
+    /// Season number for TV episodes
+    pub season: Option<u8>,
+    /// Episode number for TV episodes
+    pub episode: Option<u8>,
+    /// Saved position in seconds
+    pub position: u64,
+    /// Total duration in seconds (0 when unknown)
+    pub duration: u64,
+    /// Unix timestamp of the last update
+    pub updated_at: u64,
+}
+
+impl HistoryEntry {
+    /// Watched fraction in 0.0..=1.0 (0.0 when the duration is unknown)
+    pub fn progress(&self) -> f64 {
+        if self.duration == 0 {
+            return 0.0;
+        }
+        (self.position as f64 / self.duration as f64).min(1.0)
+    }
 }
 
 impl Config {
@@ -57,23 +457,108 @@ impl Config {
     }
 
     /// Load config from file, or return default if not found
+    ///
+    /// The selected profile (see [`Config::selected_profile`]) is merged in.
     pub fn load() -> Self {
-        Self::path()
+        let file: Config = Self::path()
             .and_then(|p| std::fs::read_to_string(p).ok())
             .and_then(|s| toml::from_str(&s).ok())
-            .unwrap_or_default()
+            .unwrap_or_default();
+        let selected = Self::selected_profile(file.profile.as_deref());
+        file.with_profile(selected)
+    }
+
+    /// Select a profile for this process (the global `--profile` flag)
+    pub fn set_profile_override(name: impl Into<String>) {
+        let _ = PROFILE_OVERRIDE.set(name.into());
+    }
+
+    /// Use `path` as the playback log for this run (from `--log-file`)
+    pub fn set_log_file_override(path: impl Into<PathBuf>) {
+        let _ = LOG_FILE_OVERRIDE.set(path.into());
+    }
+
+    /// Playback log picked by the user: `--log-file`, then `log_path`
+    ///
+    /// A leading `~/` is expanded to the home directory.
+    pub fn log_file(&self) -> Option<PathBuf> {
+        let path = LOG_FILE_OVERRIDE.get().or(self.log_path.as_ref())?;
+        match path.strip_prefix("~") {
+            Ok(rest) => dirs::home_dir().map(|home| home.join(rest)),
+            Err(_) => Some(path.clone()),
+        }
+    }
+
+    /// Read subtitles as `label` for this run (from `--subtitle-encoding`)
+    pub fn set_subtitle_encoding_override(label: impl Into<String>) {
+        let _ = SUBTITLE_ENCODING_OVERRIDE.set(label.into());
+    }
+
+    /// Charset subtitles are forced to: `--subtitle-encoding`, then
+    /// `subtitle_encoding` (None detects it; unknown labels are ignored)
+    pub fn subtitle_encoding(&self) -> Option<&'static encoding_rs::Encoding> {
+        let label = SUBTITLE_ENCODING_OVERRIDE
+            .get()
+            .or(self.subtitle_encoding.as_ref())?;
+        encoding_rs::Encoding::for_label(label.trim().as_bytes())
+    }
+
+    /// Profile to load: `--profile`, then STREAMTUI_PROFILE, then the
+    /// file's `profile` pointer
+    pub fn selected_profile(pointer: Option<&str>) -> Option<String> {
+        PROFILE_OVERRIDE
+            .get()
+            .cloned()
+            .or_else(|| std::env::var(PROFILE_ENV).ok().filter(|p| !p.is_empty()))
+            .or_else(|| pointer.map(str::to_string))
+    }
+
+    /// Merge profile `name` over the top-level settings
+    ///
+    /// An unknown name starts an empty profile, created on the next save.
+    pub fn with_profile(mut self, name: Option<String>) -> Self {
+        self.defaults = Profile::capture(&self);
+        if let Some(profile) = name.as_ref().and_then(|n| self.profiles.get(n)).cloned() {
+            profile.apply(&mut self);
+        }
+        self.active_profile = name;
+        self
+    }
+
+    /// What `save()` writes: profile settings go back into the active profile
+    /// and the top-level values stay as they were loaded
+    pub fn to_file(&self) -> Config {
+        let mut file = self.clone();
+        let Some(name) = self.active_profile.clone() else {
+            return file;
+        };
+
+        let mut profile = file.profiles.remove(&name).unwrap_or_default();
+        profile.absorb(Profile::capture(self), &self.defaults);
+        file.profiles.insert(name, profile);
+
+        let defaults = &self.defaults;
+        file.tmdb_api_key = defaults.tmdb_api_key.clone();
+        file.debrid_api_key = defaults.debrid_api_key.clone();
+        file.default_device = defaults.default_device.clone();
+        file.default_subtitle_lang = defaults.default_subtitle_lang.clone();
+        file.preferred_quality = defaults.preferred_quality.clone();
+        file.device_order = defaults.device_order.clone().unwrap_or_default();
+        file.known_devices = defaults.known_devices.clone().unwrap_or_default();
+        file
     }
 
     /// Save config to file
     pub fn save(&self) -> Result<()> {
-        let path = Self::path().ok_or_else(|| anyhow::anyhow!("Could not determine config path"))?;
+        let path =
+            Self::path().ok_or_else(|| anyhow::anyhow!("Could not determine config path"))?;
 
         // Create parent directory if needed
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        let toml = toml::to_string_pretty(self)?;
+        let toml = toml::to_string_pretty(&self.to_file())?;
         std::fs::write(path, toml)?;
         Ok(())
     }
@@ -100,6 +585,18 @@ impl Config {
         key
     }
 
+    /// Where [`Self::get_tmdb_api_key`] would take the key from:
+    /// `"env"`, `"config"` or `"pool"` (the bundled keys)
+    pub fn tmdb_key_source(&self) -> &'static str {
+        if std::env::var("TMDB_API_KEY").is_ok() {
+            "env"
+        } else if self.tmdb_api_key.is_some() {
+            "config"
+        } else {
+            "pool"
+        }
+    }
+
     /// Get a random key from the bundled pool
     pub fn random_pool_key() -> String {
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -118,7 +615,9 @@ impl Config {
         let current_idx = TMDB_KEY_POOL.iter().position(|&k| k == failed_key);
 
         // Try next key in pool
-        let next_idx = current_idx.map(|i| (i + 1) % TMDB_KEY_POOL.len()).unwrap_or(0);
+        let next_idx = current_idx
+            .map(|i| (i + 1) % TMDB_KEY_POOL.len())
+            .unwrap_or(0);
 
         // Don't loop forever - if we've tried all keys, give up
         let key = TMDB_KEY_POOL[next_idx].to_string();
@@ -130,6 +629,211 @@ impl Config {
         let _ = self.save();
         Some(key)
     }
+
+    /// TMDB cache lifetime, or None when caching is disabled
+    pub fn tmdb_cache_ttl(&self) -> Option<std::time::Duration> {
+        match self.tmdb_cache_ttl_secs.unwrap_or(3600) {
+            0 => None,
+            secs => Some(std::time::Duration::from_secs(secs)),
+        }
+    }
+
+    /// Timeout for TMDB, Torrentio and subtitle requests
+    pub fn request_timeout(&self) -> std::time::Duration {
+        match self.request_timeout_secs {
+            Some(secs) if secs > 0 => std::time::Duration::from_secs(secs),
+            _ => crate::api::DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Subtitle search backend(s); unknown names mean the default (Stremio)
+    pub fn subtitle_provider(&self) -> ProviderChoice {
+        self.subtitle_provider
+            .as_deref()
+            .and_then(ProviderChoice::from_name)
+            .unwrap_or_default()
+    }
+
+    /// Torrentio addon options from `torrentio_providers` and `torrentio_sort`
+    pub fn torrentio_config(&self) -> TorrentioConfig {
+        TorrentioConfig {
+            providers: self.torrentio_providers.clone().unwrap_or_default(),
+            sort: self
+                .torrentio_sort
+                .as_deref()
+                .and_then(TorrentioSort::from_name),
+            ..TorrentioConfig::default()
+        }
+    }
+
+    /// Configured local player (None if unset or not "vlc"/"mpv")
+    pub fn default_local_player(&self) -> Option<PlayerType> {
+        self.default_local_player
+            .as_deref()
+            .and_then(PlayerType::from_name)
+    }
+
+    /// Sources under this many seeds are hidden by the TUI's `h` toggle
+    pub fn min_seeds(&self) -> u32 {
+        self.min_seeds.unwrap_or(HEALTHY_SEEDS)
+    }
+
+    /// Trending titles listed on the home screen (None shows all)
+    pub fn home_limit(&self) -> Option<usize> {
+        Some(self.home_limit.unwrap_or(DEFAULT_HOME_LIMIT)).filter(|&n| n > 0)
+    }
+
+    /// Quality picked first when sources load (None if unset or unknown)
+    pub fn preferred_quality(&self) -> Option<Quality> {
+        self.preferred_quality
+            .as_deref()
+            .and_then(Quality::from_name)
+    }
+
+    /// Seconds a rewind/fast-forward jumps (at least 1)
+    pub fn seek_step(&self) -> u32 {
+        self.seek_step_secs.unwrap_or(DEFAULT_SEEK_STEP).max(1)
+    }
+
+    /// How many times device discovery runs `catt scan` (at least once)
+    pub fn scan_attempts(&self) -> u32 {
+        self.scan_attempts.unwrap_or(DEFAULT_SCAN_ATTEMPTS).max(1)
+    }
+
+    /// Trackers to put in magnet links
+    pub fn trackers(&self) -> Vec<String> {
+        match self.trackers {
+            Some(ref trackers) => trackers.clone(),
+            None => DEFAULT_TRACKERS.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    /// Retry policy for the TMDB and Torrentio clients
+    pub fn retry_policy(&self) -> RetryPolicy {
+        match self.max_retries {
+            Some(retries) => RetryPolicy::with_max_retries(retries),
+            None => RetryPolicy::default(),
+        }
+    }
+
+    /// Stream selection weights for `cast --auto` and `streams --best`
+    pub fn selection_prefs(&self) -> SelectionPrefs {
+        const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+        let defaults = SelectionPrefs::default();
+        let size_range = match (self.prefer_size_min_gb, self.prefer_size_max_gb) {
+            (None, None) => None,
+            (min, max) => Some((
+                (min.unwrap_or(0.0) * GB) as u64,
+                max.map(|m| (m * GB) as u64).unwrap_or(u64::MAX),
+            )),
+        };
+        SelectionPrefs {
+            quality_weight: self.prefer_quality.unwrap_or(defaults.quality_weight),
+            seeds_weight: self.prefer_seeds.unwrap_or(defaults.seeds_weight),
+            size_weight: defaults.size_weight,
+            size_range,
+        }
+    }
+
+    /// Add a title to the watchlist (returns false if already present)
+    pub fn add_to_watchlist(&mut self, entry: WatchlistEntry) -> bool {
+        if self.in_watchlist(entry.id, entry.media_type) {
+            return false;
+        }
+        self.watchlist.push(entry);
+        true
+    }
+
+    /// Remove a title from the watchlist (any media type when None)
+    ///
+    /// Returns false if nothing was removed.
+    pub fn remove_from_watchlist(&mut self, id: u64, media_type: Option<MediaType>) -> bool {
+        let before = self.watchlist.len();
+        self.watchlist
+            .retain(|e| !(e.id == id && media_type.is_none_or(|t| t == e.media_type)));
+        self.watchlist.len() != before
+    }
+
+    /// Whether a title is in the watchlist
+    pub fn in_watchlist(&self, id: u64, media_type: MediaType) -> bool {
+        self.watchlist.iter().any(|e| e.matches(id, media_type))
+    }
+
+    /// Saved position (seconds) for a torrent, if any
+    pub fn resume_position(&self, info_hash: &str) -> Option<u64> {
+        self.resume
+            .get(&info_hash.to_lowercase())
+            .map(|e| e.position)
+            .filter(|&p| p > 0)
+    }
+
+    /// Record a playback position (a zero position clears the entry)
+    pub fn record_resume(&mut self, info_hash: &str, entry: ResumeEntry) {
+        let key = info_hash.to_lowercase();
+        if entry.position == 0 {
+            self.resume.remove(&key);
+        } else {
+            self.resume.insert(key, entry);
+        }
+    }
+
+    /// Most recently updated resume entry for an IMDB ID
+    pub fn last_resume_for(&self, imdb_id: &str) -> Option<(&str, &ResumeEntry)> {
+        self.resume
+            .iter()
+            .filter(|(_, e)| e.imdb_id.as_deref() == Some(imdb_id))
+            .max_by_key(|(_, e)| e.updated_at)
+            .map(|(hash, e)| (hash.as_str(), e))
+    }
+
+    /// Whether the TUI draws plain ASCII (`ascii_mode` or STREAMTUI_ASCII)
+    pub fn ascii(&self) -> bool {
+        self.ascii_mode || std::env::var(ASCII_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+    }
+
+    /// Palette named by `theme` (Neon when unset or unknown)
+    pub fn palette(&self) -> Palette {
+        self.theme
+            .as_deref()
+            .and_then(Palette::from_name)
+            .unwrap_or_default()
+    }
+
+    /// Keymap with the `[keybindings]` overrides applied
+    ///
+    /// Also returns a description of each entry that was ignored.
+    pub fn keymap(&self) -> (KeyMap, Vec<String>) {
+        KeyMap::from_config(&self.keybindings)
+    }
+
+    /// Titles with a saved position, most recent first
+    ///
+    /// Entries without an IMDB ID can't be refetched and are skipped.
+    /// Only the latest torrent per title/episode is kept.
+    pub fn continue_watching(&self) -> Vec<HistoryEntry> {
+        let mut entries: Vec<HistoryEntry> = self
+            .resume
+            .iter()
+            .filter(|(_, e)| e.position > 0)
+            .filter_map(|(hash, e)| {
+                Some(HistoryEntry {
+                    info_hash: hash.clone(),
+                    imdb_id: e.imdb_id.clone()?,
+                    title: e.title.clone(),
+                    season: e.season,
+                    episode: e.episode,
+                    position: e.position,
+                    duration: e.duration,
+                    updated_at: e.updated_at,
+                })
+            })
+            .collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.updated_at));
+
+        let mut seen = std::collections::HashSet::new();
+        entries.retain(|e| seen.insert((e.imdb_id.clone(), e.season, e.episode)));
+        entries
+    }
 }
 
 /// Save settings synchronously (for immediate persistence)
@@ -140,6 +844,253 @@ pub fn save_settings_sync(subtitle_lang: &str, device_name: Option<&str>) {
     let _ = config.save();
 }
 
+/// Settings `config get/set/unset` work on, by their config.toml name
+pub const CONFIG_KEYS: &[&str] = &[
+    "tmdb_api_key",
+    "default_device",
+    "preferred_quality",
+    "default_subtitle_lang",
+    "subtitle_languages",
+    "custom_subtitle_lang",
+    "debrid_api_key",
+    "torrentio_providers",
+    "torrentio_sort",
+    "opensubtitles_api_key",
+    "subtitle_provider",
+    "subtitle_encoding",
+    "enable_resume",
+    "show_posters",
+    "theme",
+    "ascii_mode",
+    "autoplay_next",
+    "auto_downgrade",
+    "include_adult",
+    "restore_session",
+    "default_local_player",
+    "stall_secs",
+    "prefer_quality",
+    "prefer_seeds",
+    "min_seeds",
+    "home_limit",
+    "prefer_size_min_gb",
+    "prefer_size_max_gb",
+    "tmdb_cache_ttl_secs",
+    "max_retries",
+    "request_timeout_secs",
+    "scan_attempts",
+    "seek_step_secs",
+    "log_path",
+    "trackers",
+];
+
+/// Why a `config get/set/unset` key or value was refused
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConfigKeyError {
+    #[error("Unknown config key '{0}'. Known keys: {keys}", keys = CONFIG_KEYS.join(", "))]
+    UnknownKey(String),
+
+    #[error("Invalid value for {key}: {reason}")]
+    InvalidValue { key: String, reason: String },
+}
+
+impl Config {
+    /// Current value of a setting, as it would appear in config.toml
+    /// (null when unset)
+    pub fn get_key(&self, key: &str) -> Result<serde_json::Value, ConfigKeyError> {
+        if !CONFIG_KEYS.contains(&key) {
+            return Err(ConfigKeyError::UnknownKey(key.to_string()));
+        }
+        let all = serde_json::to_value(self).unwrap_or_default();
+        Ok(all.get(key).cloned().unwrap_or_default())
+    }
+
+    /// Parse and store a setting (nothing changes when `value` is invalid)
+    ///
+    /// Lists are comma-separated; names (theme, sort, player...) are stored
+    /// in their canonical spelling.
+    pub fn set_key(&mut self, key: &str, value: &str) -> Result<(), ConfigKeyError> {
+        let value = value.trim();
+        let invalid = |reason: &str| ConfigKeyError::InvalidValue {
+            key: key.to_string(),
+            reason: reason.to_string(),
+        };
+        let text = || {
+            (!value.is_empty())
+                .then(|| value.to_string())
+                .ok_or_else(|| invalid("must not be empty"))
+        };
+        let flag = || parse_flag(value).ok_or_else(|| invalid("expected true or false"));
+        let gb = || {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|gb| gb.is_finite() && *gb >= 0.0)
+                .ok_or_else(|| invalid("expected a size in GB"))
+        };
+        let languages = || {
+            let codes = split_list(value);
+            if codes.is_empty() || !codes.iter().all(|c| is_language_code(c)) {
+                return Err(invalid(
+                    "expected 2-3 letter language codes (en, spa, fre...)",
+                ));
+            }
+            Ok(codes.iter().map(|c| c.to_lowercase()).collect::<Vec<_>>())
+        };
+
+        match key {
+            "tmdb_api_key" => self.tmdb_api_key = Some(text()?),
+            "default_device" => self.default_device = Some(text()?),
+            "preferred_quality" => {
+                let quality = Quality::from_name(value)
+                    .ok_or_else(|| invalid("expected 4k, 1080p, 720p or 480p"))?;
+                self.preferred_quality = Some(quality.to_string().to_lowercase());
+            }
+            "default_subtitle_lang" => match languages()?.as_slice() {
+                [code] => self.default_subtitle_lang = Some(code.clone()),
+                _ => return Err(invalid("expected a single 2-3 letter language code")),
+            },
+            "subtitle_languages" => self.subtitle_languages = Some(languages()?),
+            "custom_subtitle_lang" => self.custom_subtitle_lang = Some(languages()?.join(",")),
+            "debrid_api_key" => self.debrid_api_key = Some(text()?),
+            "torrentio_providers" => {
+                let providers: Vec<String> =
+                    split_list(value).iter().map(|p| p.to_lowercase()).collect();
+                if providers.is_empty() {
+                    return Err(invalid("expected a comma-separated list of providers"));
+                }
+                self.torrentio_providers = Some(providers);
+            }
+            "torrentio_sort" => {
+                let sort = TorrentioSort::from_name(value)
+                    .ok_or_else(|| invalid("expected quality, qualitysize, seeders or size"))?;
+                self.torrentio_sort = Some(sort.as_str().to_string());
+            }
+            "opensubtitles_api_key" => self.opensubtitles_api_key = Some(text()?),
+            "subtitle_provider" => {
+                ProviderChoice::from_name(value)
+                    .ok_or_else(|| invalid("expected stremio, opensubtitles or both"))?;
+                self.subtitle_provider = Some(value.to_lowercase());
+            }
+            "subtitle_encoding" => {
+                encoding_rs::Encoding::for_label(value.as_bytes())
+                    .ok_or_else(|| invalid("unknown charset"))?;
+                self.subtitle_encoding = Some(value.to_string());
+            }
+            "enable_resume" => self.enable_resume = flag()?,
+            "show_posters" => self.show_posters = flag()?,
+            "theme" => {
+                let names: Vec<&str> = Palette::ALL.iter().map(|p| p.name()).collect();
+                let palette = Palette::from_name(value)
+                    .ok_or_else(|| invalid(&format!("expected one of {}", names.join(", "))))?;
+                self.theme = Some(palette.name().to_string());
+            }
+            "ascii_mode" => self.ascii_mode = flag()?,
+            "autoplay_next" => self.autoplay_next = flag()?,
+            "auto_downgrade" => self.auto_downgrade = flag()?,
+            "include_adult" => self.include_adult = flag()?,
+            "restore_session" => self.restore_session = flag()?,
+            "default_local_player" => {
+                let player =
+                    PlayerType::from_name(value).ok_or_else(|| invalid("expected vlc or mpv"))?;
+                self.default_local_player = Some(player.display_name().to_lowercase());
+            }
+            "stall_secs" => self.stall_secs = Some(parse_number(key, value)?),
+            "prefer_quality" => self.prefer_quality = Some(parse_number(key, value)?),
+            "prefer_seeds" => self.prefer_seeds = Some(parse_number(key, value)?),
+            "min_seeds" => self.min_seeds = Some(parse_number(key, value)?),
+            "home_limit" => self.home_limit = Some(parse_number(key, value)?),
+            "prefer_size_min_gb" => self.prefer_size_min_gb = Some(gb()?),
+            "prefer_size_max_gb" => self.prefer_size_max_gb = Some(gb()?),
+            "tmdb_cache_ttl_secs" => self.tmdb_cache_ttl_secs = Some(parse_number(key, value)?),
+            "max_retries" => self.max_retries = Some(parse_number(key, value)?),
+            "request_timeout_secs" => self.request_timeout_secs = Some(parse_number(key, value)?),
+            "scan_attempts" => self.scan_attempts = Some(parse_number(key, value)?),
+            "seek_step_secs" => self.seek_step_secs = Some(parse_number(key, value)?),
+            "log_path" => self.log_path = Some(PathBuf::from(text()?)),
+            // An empty list is meaningful here: DHT only
+            "trackers" => {
+                self.trackers = Some(split_list(value).iter().map(|t| t.to_string()).collect())
+            }
+            _ => return Err(ConfigKeyError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Put a setting back to its default
+    pub fn unset_key(&mut self, key: &str) -> Result<(), ConfigKeyError> {
+        match key {
+            "tmdb_api_key" => self.tmdb_api_key = None,
+            "default_device" => self.default_device = None,
+            "preferred_quality" => self.preferred_quality = None,
+            "default_subtitle_lang" => self.default_subtitle_lang = None,
+            "subtitle_languages" => self.subtitle_languages = None,
+            "custom_subtitle_lang" => self.custom_subtitle_lang = None,
+            "debrid_api_key" => self.debrid_api_key = None,
+            "torrentio_providers" => self.torrentio_providers = None,
+            "torrentio_sort" => self.torrentio_sort = None,
+            "opensubtitles_api_key" => self.opensubtitles_api_key = None,
+            "subtitle_provider" => self.subtitle_provider = None,
+            "subtitle_encoding" => self.subtitle_encoding = None,
+            "enable_resume" => self.enable_resume = false,
+            "show_posters" => self.show_posters = false,
+            "theme" => self.theme = None,
+            "ascii_mode" => self.ascii_mode = false,
+            "autoplay_next" => self.autoplay_next = false,
+            "auto_downgrade" => self.auto_downgrade = false,
+            "include_adult" => self.include_adult = false,
+            "restore_session" => self.restore_session = false,
+            "default_local_player" => self.default_local_player = None,
+            "stall_secs" => self.stall_secs = None,
+            "prefer_quality" => self.prefer_quality = None,
+            "prefer_seeds" => self.prefer_seeds = None,
+            "min_seeds" => self.min_seeds = None,
+            "home_limit" => self.home_limit = None,
+            "prefer_size_min_gb" => self.prefer_size_min_gb = None,
+            "prefer_size_max_gb" => self.prefer_size_max_gb = None,
+            "tmdb_cache_ttl_secs" => self.tmdb_cache_ttl_secs = None,
+            "max_retries" => self.max_retries = None,
+            "request_timeout_secs" => self.request_timeout_secs = None,
+            "scan_attempts" => self.scan_attempts = None,
+            "seek_step_secs" => self.seek_step_secs = None,
+            "log_path" => self.log_path = None,
+            "trackers" => self.trackers = None,
+            _ => return Err(ConfigKeyError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+/// "true"/"false" and the usual synonyms
+fn parse_flag(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Some(true),
+        "false" | "no" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// A whole number setting
+fn parse_number<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, ConfigKeyError> {
+    value.parse().map_err(|_| ConfigKeyError::InvalidValue {
+        key: key.to_string(),
+        reason: "expected a whole number".to_string(),
+    })
+}
+
+/// Comma-separated items, trimmed, blanks dropped
+fn split_list(value: &str) -> Vec<&str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// ISO 639 code shape: 2 or 3 ASCII letters
+fn is_language_code(code: &str) -> bool {
+    (2..=3).contains(&code.len()) && code.chars().all(|c| c.is_ascii_alphabetic())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,4 +1115,441 @@ mod tests {
         assert!(!key.is_empty());
         assert_eq!(key.len(), 32); // TMDB keys are 32 chars
     }
+
+    #[test]
+    fn test_selection_prefs_from_config() {
+        let config: Config =
+            toml::from_str("prefer_quality = 10\nprefer_seeds = 1\nprefer_size_max_gb = 8.0\n")
+                .unwrap();
+        let prefs = config.selection_prefs();
+        assert_eq!(prefs.quality_weight, 10);
+        assert_eq!(prefs.seeds_weight, 1);
+        assert_eq!(prefs.size_range, Some((0, 8 * 1024 * 1024 * 1024)));
+
+        assert_eq!(
+            Config::default().selection_prefs(),
+            SelectionPrefs::default()
+        );
+    }
+
+    #[test]
+    fn test_trackers_override() {
+        assert_eq!(Config::default().trackers().len(), DEFAULT_TRACKERS.len());
+
+        let config: Config =
+            toml::from_str("trackers = [\"udp://tracker.example.org:1337\"]\n").unwrap();
+        assert_eq!(
+            config.trackers(),
+            vec!["udp://tracker.example.org:1337".to_string()]
+        );
+
+        let config: Config = toml::from_str("trackers = []\n").unwrap();
+        assert!(config.trackers().is_empty());
+    }
+
+    #[test]
+    fn test_watchlist_add_remove() {
+        let mut config = Config::default();
+        assert!(config.add_to_watchlist(WatchlistEntry::new(27205, MediaType::Movie, "Inception")));
+        assert!(!config.add_to_watchlist(WatchlistEntry::new(
+            27205,
+            MediaType::Movie,
+            "Inception"
+        )));
+        assert!(config.add_to_watchlist(WatchlistEntry::new(1396, MediaType::Tv, "Breaking Bad")));
+        assert!(config.in_watchlist(1396, MediaType::Tv));
+        assert!(!config.in_watchlist(1396, MediaType::Movie));
+
+        // Round-trips through TOML with the resume table
+        config.record_resume(
+            "abc",
+            ResumeEntry {
+                position: 10,
+                ..Default::default()
+            },
+        );
+        let parsed: Config = toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        assert_eq!(parsed.watchlist, config.watchlist);
+
+        assert!(!config.remove_from_watchlist(27205, Some(MediaType::Tv)));
+        assert!(config.remove_from_watchlist(27205, None));
+        assert_eq!(config.watchlist.len(), 1);
+    }
+
+    #[test]
+    fn test_resume_roundtrip_and_lookup() {
+        let mut config = Config {
+            enable_resume: true,
+            default_device: Some("Living Room".into()),
+            ..Default::default()
+        };
+        config.record_resume(
+            "ABC123",
+            ResumeEntry {
+                position: 2530,
+                imdb_id: Some("tt1375666".into()),
+                title: "Inception".into(),
+                file_idx: Some(0),
+                updated_at: 100,
+                ..Default::default()
+            },
+        );
+        config.record_resume(
+            "def456",
+            ResumeEntry {
+                position: 60,
+                imdb_id: Some("tt1375666".into()),
+                title: "Inception".into(),
+                file_idx: None,
+                updated_at: 200,
+                ..Default::default()
+            },
+        );
+
+        // Keys are case-insensitive
+        assert_eq!(config.resume_position("abc123"), Some(2530));
+        let (hash, entry) = config.last_resume_for("tt1375666").unwrap();
+        assert_eq!(hash, "def456");
+        assert_eq!(entry.position, 60);
+
+        // Serializes to valid TOML alongside plain values
+        let toml = toml::to_string_pretty(&config).unwrap();
+        let parsed: Config = toml::from_str(&toml).unwrap();
+        assert!(parsed.enable_resume);
+        assert_eq!(parsed.resume, config.resume);
+
+        // Zero position clears the entry
+        config.record_resume("abc123", ResumeEntry::default());
+        assert_eq!(config.resume_position("abc123"), None);
+    }
+
+    #[test]
+    fn test_continue_watching_latest_per_episode() {
+        let mut config = Config::default();
+        let episode = |position, episode, updated_at| ResumeEntry {
+            position,
+            imdb_id: Some("tt0903747".into()),
+            title: "Breaking Bad".into(),
+            updated_at,
+            duration: 3000,
+            season: Some(1),
+            episode: Some(episode),
+            ..Default::default()
+        };
+        config.record_resume("aaa", episode(100, 1, 10));
+        config.record_resume("bbb", episode(1500, 1, 30));
+        config.record_resume("ccc", episode(200, 2, 20));
+        // No IMDB ID: can't be resumed from Home
+        config.record_resume(
+            "ddd",
+            ResumeEntry {
+                position: 50,
+                title: "Unknown".into(),
+                updated_at: 40,
+                ..Default::default()
+            },
+        );
+
+        let entries = config.continue_watching();
+        let hashes: Vec<&str> = entries.iter().map(|e| e.info_hash.as_str()).collect();
+        assert_eq!(hashes, vec!["bbb", "ccc"]);
+        assert_eq!(entries[0].progress(), 0.5);
+        assert_eq!(entries[1].episode, Some(2));
+    }
+
+    #[test]
+    fn test_arrange_devices_keeps_user_order() {
+        let device = |name: &str| CastDevice {
+            id: format!("id-{}", name),
+            name: name.to_string(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 10)),
+            port: 8009,
+            model: None,
+        };
+        let known = vec![
+            SavedDevice::from(&device("Living Room TV")),
+            SavedDevice::from(&device("Garage")),
+        ];
+        let order = vec!["Living Room TV".to_string(), "Bedroom".to_string()];
+
+        // Living Room TV missed the scan but stays on top; unordered devices keep scan order
+        let arranged = arrange_devices(
+            vec![device("Kitchen"), device("Bedroom"), device("Office")],
+            &known,
+            &order,
+        );
+        let names: Vec<&str> = arranged.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["Living Room TV", "Bedroom", "Kitchen", "Office"]
+        );
+        assert_eq!(arranged[0].id, "id-Living Room TV");
+    }
+
+    #[test]
+    fn test_device_list_round_trips_through_toml() {
+        let config = Config {
+            device_order: vec!["Living Room TV".into()],
+            known_devices: vec![SavedDevice {
+                name: "Living Room TV".into(),
+                id: "abc".into(),
+                model: Some("Chromecast Ultra".into()),
+            }],
+            ..Default::default()
+        };
+        let parsed: Config = toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        assert_eq!(parsed.device_order, config.device_order);
+        assert_eq!(parsed.known_devices, config.known_devices);
+    }
+
+    const PROFILES_TOML: &str = r#"
+default_device = "Living Room TV"
+preferred_quality = "1080p"
+default_subtitle_lang = "en"
+profile = "home"
+
+[profiles.home]
+
+[profiles.friend]
+default_device = "Den TV"
+tmdb_api_key = "friendkey"
+
+[[profiles.friend.known_devices]]
+name = "Den TV"
+id = "den"
+"#;
+
+    #[test]
+    fn test_profile_merges_over_defaults() {
+        let file: Config = toml::from_str(PROFILES_TOML).unwrap();
+        assert_eq!(file.profile.as_deref(), Some("home"));
+
+        let config = file.with_profile(Some("friend".into()));
+        assert_eq!(config.active_profile.as_deref(), Some("friend"));
+        assert_eq!(config.default_device.as_deref(), Some("Den TV"));
+        assert_eq!(config.tmdb_api_key.as_deref(), Some("friendkey"));
+        assert_eq!(config.known_devices[0].name, "Den TV");
+        // Not overridden: inherited from the top level
+        assert_eq!(config.preferred_quality.as_deref(), Some("1080p"));
+    }
+
+    #[test]
+    fn test_profile_changes_save_into_profile() {
+        let file: Config = toml::from_str(PROFILES_TOML).unwrap();
+        let mut config = file.with_profile(Some("friend".into()));
+        config.default_subtitle_lang = Some("es".into());
+        config.default_device = Some("Den TV 2".into());
+
+        let saved = config.to_file();
+        // Top-level values are untouched
+        assert_eq!(saved.default_subtitle_lang.as_deref(), Some("en"));
+        assert_eq!(saved.default_device.as_deref(), Some("Living Room TV"));
+        assert!(saved.tmdb_api_key.is_none());
+        assert!(saved.known_devices.is_empty());
+
+        let friend = &saved.profiles["friend"];
+        assert_eq!(friend.default_subtitle_lang.as_deref(), Some("es"));
+        assert_eq!(friend.default_device.as_deref(), Some("Den TV 2"));
+        // Inherited values aren't copied into the profile
+        assert!(friend.preferred_quality.is_none());
+        assert!(friend.device_order.is_none());
+        assert_eq!(saved.profiles["home"], Profile::default());
+
+        // And it all survives a TOML round trip
+        let reparsed: Config = toml::from_str(&toml::to_string_pretty(&saved).unwrap()).unwrap();
+        assert_eq!(reparsed.profiles, saved.profiles);
+    }
+
+    #[test]
+    fn test_ascii_mode() {
+        let config: Config = toml::from_str("ascii_mode = true").unwrap();
+        assert!(config.ascii_mode);
+        assert!(config.ascii());
+        assert!(!Config::default().ascii_mode);
+    }
+
+    #[test]
+    fn test_subtitle_provider() {
+        assert_eq!(
+            Config::default().subtitle_provider(),
+            ProviderChoice::Stremio
+        );
+        let config: Config = toml::from_str(r#"subtitle_provider = "Both""#).unwrap();
+        assert_eq!(config.subtitle_provider(), ProviderChoice::Both);
+        let config: Config = toml::from_str(r#"subtitle_provider = "podnapisi""#).unwrap();
+        assert_eq!(config.subtitle_provider(), ProviderChoice::Stremio);
+    }
+
+    #[test]
+    fn test_torrentio_config() {
+        assert_eq!(
+            Config::default().torrentio_config(),
+            TorrentioConfig::default()
+        );
+        let config: Config = toml::from_str(
+            r#"torrentio_providers = ["yts", "eztv"]
+torrentio_sort = "QualitySize""#,
+        )
+        .unwrap();
+        let torrentio = config.torrentio_config();
+        assert_eq!(torrentio.providers, vec!["yts", "eztv"]);
+        assert_eq!(torrentio.sort, Some(TorrentioSort::QualitySize));
+    }
+
+    #[test]
+    fn test_min_seeds() {
+        assert_eq!(Config::default().min_seeds(), 10);
+        let config: Config = toml::from_str("min_seeds = 25").unwrap();
+        assert_eq!(config.min_seeds(), 25);
+    }
+
+    #[test]
+    fn test_preferred_quality() {
+        assert_eq!(Config::default().preferred_quality(), None);
+        let config: Config = toml::from_str(r#"preferred_quality = "1080p""#).unwrap();
+        assert_eq!(config.preferred_quality(), Some(Quality::FHD1080p));
+        let config: Config = toml::from_str(r#"preferred_quality = "4K""#).unwrap();
+        assert_eq!(config.preferred_quality(), Some(Quality::UHD4K));
+        let config: Config = toml::from_str(r#"preferred_quality = "best""#).unwrap();
+        assert_eq!(config.preferred_quality(), None);
+    }
+
+    #[test]
+    fn test_home_limit() {
+        assert_eq!(Config::default().home_limit(), Some(20));
+        let config: Config = toml::from_str("home_limit = 8").unwrap();
+        assert_eq!(config.home_limit(), Some(8));
+        let config: Config = toml::from_str("home_limit = 0").unwrap();
+        assert_eq!(config.home_limit(), None);
+    }
+
+    #[test]
+    fn test_subtitle_encoding_labels() {
+        assert_eq!(Config::default().subtitle_encoding(), None);
+        let config: Config = toml::from_str("subtitle_encoding = \"latin1\"").unwrap();
+        assert_eq!(config.subtitle_encoding(), Some(encoding_rs::WINDOWS_1252));
+        let config: Config = toml::from_str("subtitle_encoding = \"utf-16\"").unwrap();
+        assert_eq!(config.subtitle_encoding(), Some(encoding_rs::UTF_16LE));
+        let config: Config = toml::from_str("subtitle_encoding = \"klingon\"").unwrap();
+        assert_eq!(config.subtitle_encoding(), None);
+    }
+
+    #[test]
+    fn test_default_local_player() {
+        assert_eq!(Config::default().default_local_player(), None);
+        let config: Config = toml::from_str("default_local_player = \"mpv\"").unwrap();
+        assert_eq!(config.default_local_player(), Some(PlayerType::Mpv));
+        let config: Config = toml::from_str("default_local_player = \"totem\"").unwrap();
+        assert_eq!(config.default_local_player(), None);
+    }
+
+    #[test]
+    fn test_seek_step() {
+        assert_eq!(Config::default().seek_step(), 30);
+        let config: Config = toml::from_str("seek_step_secs = 10").unwrap();
+        assert_eq!(config.seek_step(), 10);
+        let config: Config = toml::from_str("seek_step_secs = 0").unwrap();
+        assert_eq!(config.seek_step(), 1);
+    }
+
+    #[test]
+    fn test_scan_attempts() {
+        assert_eq!(Config::default().scan_attempts(), 2);
+        let config: Config = toml::from_str("scan_attempts = 4").unwrap();
+        assert_eq!(config.scan_attempts(), 4);
+        let config: Config = toml::from_str("scan_attempts = 0").unwrap();
+        assert_eq!(config.scan_attempts(), 1);
+    }
+
+    #[test]
+    fn test_theme_palette() {
+        let config: Config = toml::from_str(r#"theme = "Matrix""#).unwrap();
+        assert_eq!(config.palette(), Palette::Matrix);
+        let config: Config = toml::from_str(r#"theme = "vaporwave""#).unwrap();
+        assert_eq!(config.palette(), Palette::Neon);
+        assert_eq!(Config::default().palette(), Palette::Neon);
+    }
+
+    #[test]
+    fn test_search_history() {
+        let mut history = Vec::new();
+        push_search(&mut history, "heat");
+        push_search(&mut history, "  ");
+        push_search(&mut history, "alien");
+        push_search(&mut history, "Heat ");
+        assert_eq!(history, vec!["Heat", "alien"]);
+
+        for i in 0..SEARCH_HISTORY_CAP {
+            push_search(&mut history, &format!("query {}", i));
+        }
+        assert_eq!(history.len(), SEARCH_HISTORY_CAP);
+        assert_eq!(history[0], format!("query {}", SEARCH_HISTORY_CAP - 1));
+
+        let config = Config {
+            search_history: history.clone(),
+            ..Config::default()
+        };
+        let parsed: Config = toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        assert_eq!(parsed.search_history, history);
+    }
+
+    #[test]
+    fn test_no_profile_saves_top_level() {
+        let file: Config = toml::from_str(PROFILES_TOML).unwrap();
+        let mut config = file.with_profile(None);
+        config.default_device = Some("Bedroom".into());
+        let saved = config.to_file();
+        assert_eq!(saved.default_device.as_deref(), Some("Bedroom"));
+        assert_eq!(
+            saved.profiles["friend"].default_device.as_deref(),
+            Some("Den TV")
+        );
+    }
+
+    #[test]
+    fn test_set_get_unset_keys() {
+        let mut config = Config::default();
+        config.set_key("theme", "Solarized").unwrap();
+        assert_eq!(config.theme.as_deref(), Some("solarized"));
+        assert_eq!(
+            config.get_key("theme").unwrap(),
+            serde_json::json!("solarized")
+        );
+
+        config.set_key("subtitle_languages", "eng, SPA").unwrap();
+        assert_eq!(
+            config.get_key("subtitle_languages").unwrap(),
+            serde_json::json!(["eng", "spa"])
+        );
+        config.set_key("autoplay_next", "yes").unwrap();
+        assert!(config.autoplay_next);
+        config.set_key("min_seeds", "25").unwrap();
+        assert_eq!(config.min_seeds, Some(25));
+        config.set_key("torrentio_sort", "Seeds").unwrap();
+        assert_eq!(config.torrentio_sort.as_deref(), Some("seeders"));
+        config.set_key("trackers", "").unwrap();
+        assert_eq!(config.trackers, Some(vec![]));
+
+        // Bad values leave the setting alone
+        assert!(matches!(
+            config.set_key("theme", "vaporwave"),
+            Err(ConfigKeyError::InvalidValue { .. })
+        ));
+        assert!(config.set_key("default_subtitle_lang", "english").is_err());
+        assert!(config.set_key("default_subtitle_lang", "en,fr").is_err());
+        assert!(config.set_key("min_seeds", "-1").is_err());
+        assert!(config.set_key("prefer_size_max_gb", "big").is_err());
+        assert!(config.set_key("default_device", "  ").is_err());
+        assert_eq!(config.theme.as_deref(), Some("solarized"));
+
+        config.unset_key("theme").unwrap();
+        config.unset_key("autoplay_next").unwrap();
+        assert_eq!(config.get_key("theme").unwrap(), serde_json::Value::Null);
+        assert!(!config.autoplay_next);
+
+        let unknown = ConfigKeyError::UnknownKey("watchlist".into());
+        assert_eq!(config.get_key("watchlist"), Err(unknown.clone()));
+        assert_eq!(config.set_key("watchlist", "x"), Err(unknown.clone()));
+        assert_eq!(config.unset_key("watchlist"), Err(unknown));
+    }
 }
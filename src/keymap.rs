@@ -0,0 +1,514 @@
+//! Remappable keybindings
+//!
+//! Global shortcuts and list navigation go through a `KeyMap` so they can be
+//! changed from the `[keybindings]` table in config.toml:
+//!
+//! ```toml
+//! [keybindings]
+//! search = "ctrl+f"
+//! down = "n"
+//! ```
+//!
+//! A configured key replaces that action's defaults. Arrow keys always
+//! navigate lists regardless of the mapping.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Something a remappable key can do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Action {
+    /// Quit the app
+    Quit,
+    /// Filter the sources list (Sources only)
+    Filter,
+    /// Stop playback (Playing only)
+    Stop,
+    /// Open the trailer in a browser (Detail only)
+    Trailer,
+    /// Focus the search box
+    Search,
+    /// Open the device picker
+    Device,
+    /// Open the watchlist
+    Watchlist,
+    /// Open the settings modal
+    Settings,
+    /// Cycle the color palette
+    Theme,
+    /// Toggle the keybinding help overlay
+    Help,
+    /// Go back a screen
+    Back,
+    /// Move the selection up
+    Up,
+    /// Move the selection down
+    Down,
+}
+
+impl Action {
+    /// All actions, in the order conflicts are resolved (view-specific first)
+    pub const ALL: [Action; 13] = [
+        Action::Quit,
+        Action::Filter,
+        Action::Stop,
+        Action::Trailer,
+        Action::Search,
+        Action::Device,
+        Action::Watchlist,
+        Action::Settings,
+        Action::Theme,
+        Action::Help,
+        Action::Back,
+        Action::Up,
+        Action::Down,
+    ];
+
+    /// Name used in the `[keybindings]` table
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Filter => "filter",
+            Action::Stop => "stop",
+            Action::Trailer => "trailer",
+            Action::Search => "search",
+            Action::Device => "device",
+            Action::Watchlist => "watchlist",
+            Action::Settings => "settings",
+            Action::Theme => "theme",
+            Action::Help => "help",
+            Action::Back => "back",
+            Action::Up => "up",
+            Action::Down => "down",
+        }
+    }
+
+    /// Parse an action name (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|a| a.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Keys bound when the config doesn't override the action
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q"],
+            Action::Filter => &["/"],
+            Action::Stop => &["s"],
+            Action::Trailer => &["t"],
+            Action::Search => &["/", "s"],
+            Action::Device => &["d"],
+            Action::Watchlist => &["w"],
+            Action::Settings => &["o"],
+            Action::Theme => &["t"],
+            Action::Help => &["?"],
+            Action::Back => &["esc"],
+            Action::Up => &["up", "k"],
+            Action::Down => &["down", "j"],
+        }
+    }
+}
+
+/// A single key with modifiers, e.g. `ctrl+f`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Parse a key string like `q`, `ctrl+f`, `alt+enter`, `pagedown` or `f5`
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        // A bare "+" is the plus key, not a separator
+        let (mods, key) = match s.rsplit_once('+') {
+            Some((mods, "")) => (mods.strip_suffix('+').unwrap_or(mods), "+"),
+            Some((mods, key)) => (mods, key),
+            None => ("", s),
+        };
+
+        let mut modifiers = KeyModifiers::empty();
+        for m in mods.split('+').filter(|m| !m.is_empty()) {
+            modifiers |= match m.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+
+        let code = match key.to_ascii_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            lower => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                        Some(n @ 1..=12) => KeyCode::F(n),
+                        _ => return None,
+                    },
+                }
+            }
+        };
+
+        Some(Self { code, modifiers })
+    }
+
+    /// Whether a key press matches this binding
+    ///
+    /// Shift is ignored for characters since it's already part of the
+    /// character itself (`S` arrives as `shift+S`).
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let strip = |m: KeyModifiers| match key.code {
+            KeyCode::Char(_) => m - KeyModifiers::SHIFT,
+            _ => m,
+        };
+        self.code == key.code && strip(self.modifiers) == strip(key.modifiers)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) && !matches!(self.code, KeyCode::Char(_)) {
+            write!(f, "shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// A group of keybindings shown in the help overlay
+#[derive(Debug, Clone, PartialEq)]
+pub struct HelpSection {
+    pub title: &'static str,
+    /// (keys, description) pairs
+    pub entries: Vec<(String, &'static str)>,
+}
+
+/// Action lookup for key presses
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyMap {
+    bindings: BTreeMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        let bindings = Action::ALL
+            .into_iter()
+            .map(|action| {
+                let keys = action
+                    .default_keys()
+                    .iter()
+                    .filter_map(|k| KeyBinding::parse(k))
+                    .collect();
+                (action, keys)
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl KeyMap {
+    /// Build a keymap from the config `[keybindings]` table
+    ///
+    /// Unknown actions and unparseable keys are skipped (the defaults stay)
+    /// and reported in the returned list.
+    pub fn from_config(table: &BTreeMap<String, String>) -> (Self, Vec<String>) {
+        let mut keymap = Self::default();
+        let mut problems = Vec::new();
+        for (name, key) in table {
+            let Some(action) = Action::from_name(name) else {
+                problems.push(format!("unknown action '{}'", name));
+                continue;
+            };
+            let Some(binding) = KeyBinding::parse(key) else {
+                problems.push(format!("invalid key '{}' for '{}'", key, name));
+                continue;
+            };
+            keymap.bindings.insert(action, vec![binding]);
+        }
+        (keymap, problems)
+    }
+
+    /// Keys bound to an action for display, e.g. `↓/j`
+    pub fn keys_label(&self, action: Action) -> String {
+        self.bindings
+            .get(&action)
+            .map(|keys| {
+                keys.iter()
+                    .map(|k| k.to_string())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .unwrap_or_default()
+    }
+
+    /// Keybindings grouped by screen for the help overlay
+    ///
+    /// Remappable actions are labelled from the current bindings; the
+    /// screen-specific keys below are fixed.
+    pub fn help_sections(&self) -> Vec<HelpSection> {
+        let key = |action| self.keys_label(action);
+        let fixed = |entries: &[(&str, &'static str)]| -> Vec<(String, &'static str)> {
+            entries.iter().map(|(k, d)| (k.to_string(), *d)).collect()
+        };
+
+        let mut global = vec![
+            (
+                format!("{} {}", key(Action::Up), key(Action::Down)),
+                "Move selection",
+            ),
+            (key(Action::Search), "Search"),
+            (key(Action::Watchlist), "Open watchlist"),
+            (key(Action::Device), "Select cast device"),
+            (key(Action::Settings), "Settings"),
+            (key(Action::Theme), "Cycle color theme"),
+            (key(Action::Help), "Toggle this help"),
+            (key(Action::Back), "Go back"),
+            (key(Action::Quit), "Quit"),
+        ];
+        global.push(("ctrl+c".to_string(), "Quit from anywhere"));
+
+        let mut sources = vec![(key(Action::Filter), "Filter sources by title")];
+        sources.extend(fixed(&[
+            ("Enter/c", "Play selected source"),
+            ("0-9 Enter", "Pick the file in a season pack"),
+            ("S", "Cycle sort: seeds / quality / size"),
+            ("h", "Hide/show sources under min_seeds"),
+            ("Q", "Hide/show sources under the quality floor"),
+            ("y", "Copy the magnet link"),
+            ("r", "Fetch the sources again"),
+            ("1-9", "Quick-select a source"),
+            ("Tab/shift+Tab", "Cycle cast device"),
+            ("u", "Select subtitles"),
+        ]));
+
+        let mut detail = fixed(&[
+            ("Tab/h/l", "Switch seasons/episodes (TV)"),
+            ("Enter", "Select season or episode"),
+            ("c", "View sources"),
+            ("C", "Cast the season (TV) / list the collection (movie)"),
+            ("u", "Select subtitles"),
+            ("f", "Add/remove from watchlist"),
+            ("r", "More like this"),
+            ("+/-", "Raise/lower the quality floor for sources"),
+        ]);
+        detail.push((key(Action::Trailer), "Open the trailer in a browser"));
+
+        let mut playing = fixed(&[("Space", "Play/pause")]);
+        playing.push((key(Action::Stop), "Stop playback"));
+        playing.extend(fixed(&[
+            ("← →", "Seek back/forward"),
+            ("Shift ← →", "Seek five steps"),
+            ("↑ ↓", "Volume up/down"),
+            ("0-9 Enter", "Set exact volume"),
+            ("g HH:MM:SS", "Seek to a timestamp"),
+            ("[ ]", "Shift subtitles ∓0.5s"),
+            ("u", "Change subtitles"),
+            ("Esc", "Cancel \"Playing next\" countdown"),
+        ]));
+
+        vec![
+            HelpSection {
+                title: "Global",
+                entries: global,
+            },
+            HelpSection {
+                title: "Home",
+                entries: fixed(&[
+                    ("Enter/i", "Open title or resume"),
+                    ("f", "Add/remove from watchlist"),
+                    ("g", "Browse by genre"),
+                    ("m", "Show more trending titles"),
+                ]),
+            },
+            HelpSection {
+                title: "Search",
+                entries: fixed(&[
+                    ("Enter/i", "Open title"),
+                    ("n/p", "Next/previous results page"),
+                    ("PgUp/PgDn", "Move by page"),
+                    ("Home/End", "Jump to first/last"),
+                    ("f", "Add/remove from watchlist"),
+                ]),
+            },
+            HelpSection {
+                title: "Detail",
+                entries: detail,
+            },
+            HelpSection {
+                title: "Sources",
+                entries: sources,
+            },
+            HelpSection {
+                title: "Subtitles",
+                entries: fixed(&[
+                    ("Enter", "Use selected subtitle"),
+                    ("Space", "Preview the first lines"),
+                    ("Tab", "Change language filter"),
+                    ("c", "Filter by any language code(s)"),
+                    ("l", "Load a local subtitle file"),
+                    ("r", "Fetch the subtitles again"),
+                    ("n", "No subtitles"),
+                ]),
+            },
+            HelpSection {
+                title: "Playing",
+                entries: playing,
+            },
+            HelpSection {
+                title: "Watchlist",
+                entries: fixed(&[
+                    ("Enter/i", "Open title"),
+                    ("f/x/Del", "Remove from watchlist"),
+                ]),
+            },
+        ]
+    }
+
+    /// Actions bound to a key press, view-specific ones first
+    pub fn actions(&self, key: &KeyEvent) -> Vec<Action> {
+        self.bindings
+            .iter()
+            .filter(|(_, keys)| keys.iter().any(|k| k.matches(key)))
+            .map(|(action, _)| *action)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    // -------------------------------------------------------------------------
+    // Parsing
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_key_strings() {
+        assert_eq!(
+            KeyBinding::parse("ctrl+f"),
+            Some(KeyBinding {
+                code: KeyCode::Char('f'),
+                modifiers: KeyModifiers::CONTROL
+            })
+        );
+        assert_eq!(KeyBinding::parse("Esc").map(|k| k.code), Some(KeyCode::Esc));
+        assert_eq!(KeyBinding::parse("f5").map(|k| k.code), Some(KeyCode::F(5)));
+        assert_eq!(
+            KeyBinding::parse("ctrl++").map(|k| k.code),
+            Some(KeyCode::Char('+'))
+        );
+        assert_eq!(KeyBinding::parse("hyper+x"), None);
+        assert_eq!(KeyBinding::parse("nonsense"), None);
+    }
+
+    #[test]
+    fn test_shift_ignored_for_chars() {
+        let binding = KeyBinding::parse("S").unwrap();
+        assert!(binding.matches(&press(KeyCode::Char('S'), KeyModifiers::SHIFT)));
+        assert!(!binding.matches(&press(KeyCode::Char('S'), KeyModifiers::CONTROL)));
+    }
+
+    // -------------------------------------------------------------------------
+    // KeyMap
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_defaults_match_builtin_keys() {
+        let keymap = KeyMap::default();
+        let plain = |c| press(KeyCode::Char(c), KeyModifiers::empty());
+        assert_eq!(keymap.actions(&plain('q')), vec![Action::Quit]);
+        assert_eq!(
+            keymap.actions(&plain('/')),
+            vec![Action::Filter, Action::Search]
+        );
+        assert_eq!(keymap.actions(&plain('j')), vec![Action::Down]);
+        assert_eq!(
+            keymap.actions(&plain('t')),
+            vec![Action::Trailer, Action::Theme]
+        );
+        assert!(keymap.actions(&plain('x')).is_empty());
+    }
+
+    #[test]
+    fn test_config_overrides_and_reports_problems() {
+        let table = BTreeMap::from([
+            ("search".to_string(), "ctrl+f".to_string()),
+            ("jump".to_string(), "x".to_string()),
+            ("quit".to_string(), "meta+q".to_string()),
+        ]);
+        let (keymap, problems) = KeyMap::from_config(&table);
+
+        // Remapped action no longer answers to its defaults
+        assert_eq!(
+            keymap.actions(&press(KeyCode::Char('f'), KeyModifiers::CONTROL)),
+            vec![Action::Search]
+        );
+        assert_eq!(
+            keymap.actions(&press(KeyCode::Char('s'), KeyModifiers::empty())),
+            vec![Action::Stop]
+        );
+
+        // Bad entries keep the defaults
+        assert_eq!(
+            keymap.actions(&press(KeyCode::Char('q'), KeyModifiers::empty())),
+            vec![Action::Quit]
+        );
+        assert_eq!(problems.len(), 2);
+        assert!(problems.iter().any(|p| p.contains("jump")));
+    }
+
+    #[test]
+    fn test_help_reflects_remapped_keys() {
+        let table = BTreeMap::from([("search".to_string(), "ctrl+f".to_string())]);
+        let (keymap, _) = KeyMap::from_config(&table);
+        assert_eq!(KeyMap::default().keys_label(Action::Down), "↓/j");
+
+        let sections = keymap.help_sections();
+        assert_eq!(sections[0].title, "Global");
+        assert!(sections[0]
+            .entries
+            .contains(&("ctrl+f".to_string(), "Search")));
+    }
+}
@@ -10,6 +10,8 @@
 //! - `stream` - Torrent and cast managers
 //! - `ui` - TUI components
 //! - `app` - Application state and navigation
+//! - `keymap` - Remappable keybindings
+//! - `session` - TUI browsing session saved between runs
 //! - `cli` - Command-line interface for automation
 
 // Allow dead code for TUI components and models prepared for future interactive mode
@@ -19,14 +21,17 @@ pub mod api;
 pub mod app;
 pub mod cli;
 pub mod config;
+pub mod keymap;
 pub mod models;
+pub mod session;
 pub mod stream;
 pub mod ui;
 
 // Re-export commonly used types
 pub use models::{
-    CastDevice, CastState, Episode, MediaType, MovieDetail, PlaybackStatus, Quality, SearchResult,
-    StreamSource, SubFormat, SubtitleFile, SubtitleResult, TorrentSession, TorrentState, TvDetail,
+    CastDevice, CastState, Episode, MediaType, MovieDetail, PersonResult, PlaybackStatus, Quality,
+    SearchPage, SearchResult, StreamSource, SubFormat, SubtitleFile, SubtitleResult,
+    TorrentSession, TorrentState, TvDetail,
 };
 
 pub use api::{TmdbClient, TorrentioClient};
@@ -22,19 +22,22 @@ mod app;
 mod cli;
 mod commands;
 mod config;
+mod keymap;
 mod models;
+mod session;
 
 mod api;
 mod stream;
 mod ui;
 
 use std::io::{stdout, Stdout};
+use std::process::Stdio;
 use std::time::Duration;
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use crossterm::{
-    event::{self, Event, KeyEventKind},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -50,12 +53,24 @@ use ratatui::{
 use tokio::sync::mpsc;
 
 use crate::api::{TmdbClient, TorrentioClient};
-use crate::stream::SubtitleClient;
-use crate::app::{App, AppCommand, AppMessage, AppState, DetailState, InputMode, ListState, LoadingState, TvFocus};
-use crate::cli::{Cli, Command, ExitCode, Output};
+use crate::app::{
+    App, AppCommand, AppMessage, AppState, DetailState, InputMode, ListRegion, ListState,
+    LoadingState, TvFocus,
+};
+use crate::cli::{Cli, Command, ExitCode, Output, TrendingWindow};
 use crate::config::Config;
-use crate::models::{CastDevice, CastState, Episode, TorrentState};
-use crate::ui::Theme;
+use crate::models::{
+    CastDevice, CastState, Episode, HdrType, SubtitleResult, TorrentState, Video, VideoCodec,
+};
+use crate::session::SavedSession;
+use crate::stream::cast::SCAN_TIMEOUT;
+use crate::stream::deps::MissingTool;
+use crate::stream::playback;
+use crate::stream::playback_log::PlaybackLog;
+use crate::stream::sessions;
+use crate::stream::{PlayerType, SubtitleClient};
+use crate::ui::poster::{self, GraphicsProtocol};
+use crate::ui::{Glyphs, Theme};
 
 /// Terminal type alias for convenience
 type Tui = Terminal<CrosstermBackend<Stdout>>;
@@ -63,6 +78,17 @@ type Tui = Terminal<CrosstermBackend<Stdout>>;
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    if let Some(ref profile) = cli.profile {
+        Config::set_profile_override(profile.clone());
+    }
+    if let Some(ref path) = cli.log_file {
+        Config::set_log_file_override(path.clone());
+    }
+    if let Some(ref label) = cli.subtitle_encoding {
+        Config::set_subtitle_encoding_override(label.clone());
+    }
+    // Forget torrent streams that exited without us noticing (crash, Ctrl+C)
+    sessions::prune_stale();
 
     if cli.is_cli_mode() {
         // CLI mode: execute command and exit
@@ -70,22 +96,38 @@ async fn main() -> Result<()> {
         std::process::exit(exit_code.into());
     } else {
         // TUI mode: launch interactive interface
-        run_tui().await
+        run_tui(cli.subtitle_file, cli.ascii, cli.device_ip).await
     }
 }
 
 /// Run CLI command and return exit code
 async fn run_cli(cli: Cli) -> ExitCode {
+    // Completions only need the clap definition, not config or output mode
+    if let Some(Command::Completions(cmd)) = &cli.command {
+        let mut command = Cli::command();
+        let name = command.get_name().to_string();
+        clap_complete::generate(cmd.shell, &mut command, name, &mut stdout());
+        return ExitCode::Success;
+    }
+
     let output = Output::new(&cli);
-    let device = cli.device.as_deref();
+    let device = cli.device_target();
+    let device = device.as_deref();
 
     match cli.command {
         Some(Command::Search(cmd)) => commands::search_cmd(cmd, &output).await,
 
+        Some(Command::Person(cmd)) => commands::person_cmd(cmd, &output).await,
+
         Some(Command::Trending(cmd)) => commands::trending_cmd(cmd, &output).await,
+        Some(Command::Discover(cmd)) => commands::discover_cmd(cmd, &output).await,
 
         Some(Command::Info(cmd)) => commands::info_cmd(cmd, &output).await,
 
+        Some(Command::Trailer(cmd)) => commands::trailer_cmd(cmd, &output).await,
+
+        Some(Command::Similar(cmd)) => commands::similar_cmd(cmd, &output).await,
+
         Some(Command::Streams(cmd)) => {
             if let Err(e) = cli::validate_imdb_id(&cmd.imdb_id) {
                 return output.error(e, ExitCode::InvalidArgs);
@@ -93,6 +135,13 @@ async fn run_cli(cli: Cli) -> ExitCode {
             commands::streams_cmd(cmd, &output).await
         }
 
+        Some(Command::Magnet(cmd)) => {
+            if let Err(e) = cli::validate_imdb_id(&cmd.imdb_id) {
+                return output.error(e, ExitCode::InvalidArgs);
+            }
+            commands::magnet_cmd(cmd, &output).await
+        }
+
         Some(Command::Subtitles(cmd)) => {
             if let Err(e) = cli::validate_imdb_id(&cmd.imdb_id) {
                 return output.error(e, ExitCode::InvalidArgs);
@@ -100,6 +149,13 @@ async fn run_cli(cli: Cli) -> ExitCode {
             commands::subtitles_cmd(cmd, &output).await
         }
 
+        Some(Command::GetSubtitle(cmd)) => {
+            if let Err(e) = cli::validate_imdb_id(&cmd.imdb_id) {
+                return output.error(e, ExitCode::InvalidArgs);
+            }
+            commands::get_subtitle_cmd(cmd, &output).await
+        }
+
         Some(Command::Devices(cmd)) => commands::devices_cmd(cmd, &output).await,
 
         Some(Command::Cast(cmd)) => {
@@ -109,12 +165,34 @@ async fn run_cli(cli: Cli) -> ExitCode {
             commands::cast_cmd(cmd, device, &output).await
         }
 
+        Some(Command::Quickcast(cmd)) => commands::quickcast_cmd(cmd, device, &output).await,
+
         Some(Command::CastMagnet(cmd)) => commands::cast_magnet_cmd(cmd, device, &output).await,
 
+        Some(Command::Resume(cmd)) => {
+            if let Err(e) = cli::validate_imdb_id(&cmd.imdb_id) {
+                return output.error(e, ExitCode::InvalidArgs);
+            }
+            commands::resume_cmd(cmd, device, &output).await
+        }
+
+        Some(Command::Watchlist(cmd)) => commands::watchlist_cmd(cmd, &output).await,
+
+        Some(Command::History(cmd)) => commands::history_cmd(cmd, &output).await,
+        Some(Command::Cache(cmd)) => commands::cache_cmd(cmd, &output).await,
+
+        Some(Command::Config(cmd)) => commands::config_cmd(cmd, &output).await,
+
         Some(Command::PlayLocal(cmd)) => commands::play_local_cmd(cmd, &output).await,
 
+        Some(Command::Doctor(cmd)) => commands::doctor_cmd(cmd, &output).await,
+
+        Some(Command::Completions(_)) => unreachable!("completions are generated above"),
+
         Some(Command::Status(cmd)) => commands::status_cmd(cmd, device, &output).await,
 
+        Some(Command::Watch(cmd)) => commands::watch_cmd(cmd, device, &output).await,
+
         Some(Command::Play(cmd)) => commands::play_cmd(cmd, device, &output).await,
 
         Some(Command::Pause(cmd)) => commands::pause_cmd(cmd, device, &output).await,
@@ -140,7 +218,7 @@ async fn run_cli(cli: Cli) -> ExitCode {
 fn init_terminal() -> Result<Tui> {
     enable_raw_mode()?;
     let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
     Ok(terminal)
@@ -149,13 +227,28 @@ fn init_terminal() -> Result<Tui> {
 /// Restore terminal to normal state
 fn restore_terminal(terminal: &mut Tui) -> Result<()> {
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()?;
     Ok(())
 }
 
 /// Run interactive TUI
-async fn run_tui() -> Result<()> {
+async fn run_tui(
+    subtitle_file: Option<std::path::PathBuf>,
+    ascii: bool,
+    device_ip: Option<std::net::IpAddr>,
+) -> Result<()> {
+    // Validate before taking over the terminal so the error stays readable
+    if let Some(ref path) = subtitle_file {
+        if !path.is_file() {
+            anyhow::bail!("Subtitle file not found: {}", path.display());
+        }
+    }
+
     // Initialize terminal
     let mut terminal = init_terminal()?;
 
@@ -169,6 +262,54 @@ async fn run_tui() -> Result<()> {
     }
     // Store default device name for later matching when devices are discovered
     app.default_device_name = config.default_device.clone();
+    app.min_seeds = config.min_seeds();
+    app.preferred_quality = config.preferred_quality();
+    app.seek_step = config.seek_step();
+    app.home_limit = config.home_limit();
+    app.enable_resume = config.enable_resume;
+    app.autoplay_next = config.autoplay_next;
+    app.auto_downgrade = config.auto_downgrade;
+    if let Some(secs) = config.stall_secs.filter(|&s| s > 0) {
+        app.stall_after = std::time::Duration::from_secs(secs);
+    }
+    app.trackers = config.trackers();
+    app.known_devices = config.known_devices.clone();
+    app.device_order = config.device_order.clone();
+    // Only offer the local players that are actually installed, the preferred one first
+    app.local_players = PlayerType::installed();
+    let (preferred, warning) =
+        PlayerType::select(None, config.default_local_player(), &app.local_players);
+    app.local_players.sort_by_key(|player| *player != preferred);
+    app.notice = warning;
+    app.cast_devices = app.local_players.iter().map(PlayerType::device).collect();
+    app.selected_device = if app.cast_devices.is_empty() {
+        None
+    } else {
+        Some(0)
+    };
+    app.watchlist.set_entries(config.watchlist.clone());
+    app.search.set_history(config.search_history.clone());
+    app.subtitles.custom_lang = config
+        .custom_subtitle_lang
+        .as_deref()
+        .and_then(app::normalize_lang_list);
+    app.theme = Theme::new(config.palette());
+    app.glyphs = Glyphs::new(ascii || config.ascii());
+    app.resume = config.resume.clone();
+    if config.enable_resume {
+        app.home.set_continue_watching(config.continue_watching());
+    }
+    if let Some(ref path) = subtitle_file {
+        app.subtitles.selected = Some(SubtitleResult::local_file(path));
+    }
+    if config.show_posters {
+        app.graphics = GraphicsProtocol::detect();
+    }
+    let (keymap, problems) = config.keymap();
+    app.keymap = keymap;
+    if !problems.is_empty() {
+        app.set_error(format!("Ignored keybindings: {}", problems.join(", ")));
+    }
 
     // Create message channel for async results
     let (msg_tx, msg_rx) = mpsc::unbounded_channel();
@@ -180,12 +321,27 @@ async fn run_tui() -> Result<()> {
     app.home.loading = LoadingState::Loading(Some("Loading trending...".into()));
     app.send_command(AppCommand::FetchTrending);
 
-    // Discover devices at startup (for auto-selecting saved default)
-    app.send_command(AppCommand::DiscoverDevices);
+    // Discover devices at startup (for auto-selecting saved default), unless
+    // --device-ip already named the one to use
+    match device_ip {
+        Some(address) => app.add_manual_device(address),
+        None => app.send_command(AppCommand::DiscoverDevices),
+    }
+
+    if config.restore_session {
+        if let Some(saved) = SavedSession::load() {
+            app.restore_session(saved);
+        }
+    }
 
     // Run the main event loop
     let result = run_event_loop(&mut terminal, &mut app, msg_rx).await;
 
+    // Saved even when the loop failed, so the next start picks up from here
+    if config.restore_session {
+        let _ = app.session().save();
+    }
+
     // Clean up
     drop(app); // Drop app to close cmd_tx, which will end the task handler
     let _ = task_handle.await;
@@ -208,121 +364,304 @@ async fn handle_async_commands(
 
     while let Some(cmd) = cmd_rx.recv().await {
         let msg_tx = msg_tx.clone();
-        let api_key = config.get_tmdb_api_key();
+        let client = TmdbClient::from_config(&mut config);
+        let torrentio = TorrentioClient::from_config(&config);
+        let subtitle_client = SubtitleClient::from_config(&config);
+        let log = PlaybackLog::from_config(&config);
+        let prefs = config.selection_prefs();
+        let scan_attempts = config.scan_attempts();
 
         // Spawn each command as a separate task for concurrency
         tokio::spawn(async move {
-            let client = TmdbClient::new(api_key);
             let result = match cmd {
                 AppCommand::FetchTrending => {
-                    match client.trending().await {
+                    // The home screen shows the weekly list (daily churns too much)
+                    match client.trending(TrendingWindow::Week).await {
                         Ok(results) => AppMessage::TrendingLoaded(results),
-                        Err(e) => AppMessage::Error(format!("Failed to fetch trending: {}", e)),
-                    }
-                }
-                AppCommand::Search(query) => {
-                    match client.search(&query).await {
-                        Ok(results) => AppMessage::SearchResults(results),
-                        Err(e) => AppMessage::Error(format!("Search failed: {}", e)),
+                        Err(e) => fetch_failed("Failed to fetch trending", e),
                     }
                 }
-                AppCommand::FetchMovieDetail(id) => {
-                    match client.movie_detail(id).await {
-                        Ok(detail) => AppMessage::MovieDetailLoaded(detail),
-                        Err(e) => AppMessage::Error(format!("Failed to fetch movie: {}", e)),
+                AppCommand::Search { query, generation } => {
+                    match client.search_paged(&query, 1).await {
+                        Ok(page) => AppMessage::SearchResults {
+                            results: page.results,
+                            page: page.page,
+                            total_pages: page.total_pages,
+                            generation,
+                        },
+                        Err(e) => fetch_failed("Search failed", e),
                     }
                 }
-                AppCommand::FetchTvDetail(id) => {
-                    match client.tv_detail(id).await {
-                        Ok(detail) => AppMessage::TvDetailLoaded(detail),
-                        Err(e) => AppMessage::Error(format!("Failed to fetch TV show: {}", e)),
+                AppCommand::Discover {
+                    genre_id,
+                    media_type,
+                    sort,
+                    generation,
+                } => match client.discover(genre_id, media_type, sort).await {
+                    Ok(results) => AppMessage::SearchResults {
+                        results,
+                        page: 1,
+                        total_pages: 1,
+                        generation,
+                    },
+                    Err(e) => fetch_failed("Discover failed", e),
+                },
+                AppCommand::SearchPage {
+                    query,
+                    page,
+                    generation,
+                } => match client.search_paged(&query, page).await {
+                    Ok(page) => {
+                        if page.skipped > 0 {
+                            log.event(format!(
+                                "search \"{}\": skipped {} TMDB rows that aren't movies or shows",
+                                query, page.skipped
+                            ));
+                        }
+                        AppMessage::SearchResults {
+                            results: page.results,
+                            page: page.page,
+                            total_pages: page.total_pages,
+                            generation,
+                        }
                     }
-                }
+                    Err(e) => fetch_failed("Search failed", e),
+                },
+                AppCommand::FetchMovieDetail(id) => match client.movie_detail(id).await {
+                    Ok(detail) => AppMessage::MovieDetailLoaded(detail),
+                    Err(e) => fetch_failed("Failed to fetch movie", e),
+                },
+                AppCommand::FetchTvDetail(id) => match client.tv_detail(id).await {
+                    Ok(detail) => AppMessage::TvDetailLoaded(detail),
+                    Err(e) => fetch_failed("Failed to fetch TV show", e),
+                },
                 AppCommand::FetchEpisodes { tv_id, season } => {
                     match client.tv_season(tv_id, season).await {
                         Ok(episodes) => AppMessage::EpisodesLoaded { season, episodes },
-                        Err(e) => AppMessage::Error(format!("Failed to fetch episodes: {}", e)),
+                        Err(e) => fetch_failed("Failed to fetch episodes", e),
                     }
                 }
-                AppCommand::FetchStreams { imdb_id, season, episode } => {
-                    let torrentio = TorrentioClient::new();
+                AppCommand::FetchStreams {
+                    imdb_id,
+                    season,
+                    episode,
+                    absolute,
+                } => {
                     let result = if let (Some(s), Some(e)) = (season, episode) {
-                        // TV episode
-                        torrentio.episode_streams(&imdb_id, s as u16, e as u16).await
+                        // TV episode, falling back to absolute numbering (anime)
+                        match (
+                            torrentio
+                                .episode_streams(&imdb_id, s as u16, e as u16)
+                                .await,
+                            absolute,
+                        ) {
+                            (Ok(streams), Some(n)) if streams.is_empty() => {
+                                torrentio.absolute_episode_streams(&imdb_id, n).await
+                            }
+                            (result, _) => result,
+                        }
                     } else {
                         // Movie
                         torrentio.movie_streams(&imdb_id).await
                     };
                     match result {
                         Ok(streams) => AppMessage::StreamsLoaded(streams),
-                        Err(e) => AppMessage::Error(format!("Failed to fetch streams: {}", e)),
+                        Err(e) => fetch_failed("Failed to fetch streams", e),
                     }
                 }
-                AppCommand::FetchSubtitles { imdb_id, season, episode, lang } => {
-                    // Stremio client is free - no API key needed
-                    let subtitle_client = SubtitleClient::new();
-                    let lang_opt = if lang.is_empty() { None } else { Some(lang.as_str()) };
+                AppCommand::CastSeason {
+                    imdb_id,
+                    title,
+                    season,
+                    episodes,
+                } => {
+                    let queue = commands::resolve_season_queue(
+                        &torrentio, &prefs, &imdb_id, &title, season, &episodes,
+                    )
+                    .await;
+                    AppMessage::SeasonQueued(queue)
+                }
+                AppCommand::FetchSubtitles {
+                    imdb_id,
+                    season,
+                    episode,
+                    lang,
+                } => {
+                    // Searches the configured provider(s), Stremio by default
+                    let lang_opt = if lang.is_empty() {
+                        None
+                    } else {
+                        Some(lang.as_str())
+                    };
                     // Use episode-specific search for TV, movie search otherwise
                     let result = match (season, episode) {
-                        (Some(s), Some(e)) => subtitle_client.search_episode(&imdb_id, s, e, lang_opt).await,
+                        (Some(s), Some(e)) => {
+                            subtitle_client
+                                .search_episode(&imdb_id, s, e, lang_opt)
+                                .await
+                        }
                         _ => subtitle_client.search(&imdb_id, lang_opt).await,
                     };
                     match result {
                         Ok(subs) => AppMessage::SubtitlesLoaded(subs),
-                        Err(e) => AppMessage::Error(format!("Failed to fetch subtitles: {}", e)),
+                        Err(e) => fetch_failed("Failed to fetch subtitles", e),
+                    }
+                }
+                AppCommand::PreviewSubtitle { url } => {
+                    match subtitle_client.fetch_preview(&url).await {
+                        Ok(cues) => AppMessage::SubtitlePreviewLoaded { url, cues },
+                        Err(e) => fetch_failed("Failed to preview subtitle", e),
                     }
                 }
                 AppCommand::DiscoverDevices => {
                     // Discover Chromecast devices using catt scan
-                    match discover_cast_devices().await {
+                    match discover_cast_devices(scan_attempts).await {
                         Ok(devices) => AppMessage::DevicesLoaded(devices),
                         Err(e) => AppMessage::Error(format!("Device discovery failed: {}", e)),
                     }
                 }
-                AppCommand::StartPlayback { magnet, title, device, subtitle_url, file_idx } => {
+                AppCommand::StartPlayback {
+                    magnet,
+                    title,
+                    device,
+                    subtitle_url,
+                    file_idx,
+                    direct_url,
+                    start,
+                    also,
+                    preflight,
+                } => {
+                    // Don't start webtorrent for a TV that's off
+                    if let Some(target) = preflight {
+                        if !target.is_reachable().await {
+                            log.event(format!(
+                                "device unreachable: {}:{}",
+                                target.address, target.port
+                            ));
+                            let _ =
+                                msg_tx.send(AppMessage::Error(app::DEVICE_UNREACHABLE.to_string()));
+                            return;
+                        }
+                    }
+
                     // Clear old log file so monitor starts fresh
-                    let log_path = get_playback_log_path();
-                    let _ = std::fs::remove_file(&log_path);
+                    log.clear();
+                    log.event(format!("start playback: {}", title));
+
+                    // A finished queued episode may still be serving its torrent
+                    // (only ours: CLI streams and other TUIs keep running)
+                    sessions::stop_started();
+                    if direct_url.is_none() {
+                        sessions::mark_started(&magnet);
+                    }
 
-                    // Start webtorrent + cast flow
-                    match start_playback(&magnet, &title, &device, subtitle_url.as_deref(), file_idx).await {
+                    // Start webtorrent + cast flow (or cast the debrid link directly)
+                    match playback::start_playback(
+                        &magnet,
+                        &device,
+                        subtitle_url.as_deref(),
+                        file_idx,
+                        direct_url.as_deref(),
+                        start,
+                        &log,
+                    )
+                    .await
+                    {
                         Ok(stream_url) => {
                             // Spawn log monitor to update TUI with torrent progress
                             let monitor_tx = msg_tx.clone();
-                            tokio::spawn(monitor_playback_log(monitor_tx));
-                            AppMessage::PlaybackStarted { stream_url }
+                            tokio::spawn(monitor_playback_log(monitor_tx, log.path.clone()));
+                            if also.is_empty() {
+                                AppMessage::PlaybackStarted { stream_url }
+                            } else {
+                                let stream_url = format!("{} and {} more", stream_url, also.len());
+                                let log = log.clone();
+                                tokio::spawn(async move {
+                                    if let Err(e) =
+                                        playback::cast_to_more(&also, direct_url.as_deref(), &log)
+                                            .await
+                                    {
+                                        log.event(format!("casting to more devices failed: {}", e));
+                                    }
+                                });
+                                AppMessage::PlaybackStarted { stream_url }
+                            }
+                        }
+                        Err(e) => {
+                            log.event(format!("playback failed: {}", e));
+                            if e.is::<MissingTool>() {
+                                AppMessage::Error(e.to_string())
+                            } else {
+                                AppMessage::Error(format!("Playback failed: {}", e))
+                            }
                         }
-                        Err(e) => AppMessage::Error(format!("Playback failed: {}", e)),
                     }
                 }
-                AppCommand::StopPlayback => {
+                AppCommand::StopPlayback { info_hash } => {
                     // Stop webtorrent and cast
-                    let _ = stop_playback().await;
+                    let _ = playback::stop_playback(info_hash.as_deref()).await;
                     AppMessage::PlaybackStopped
                 }
-                AppCommand::RestartWithSubtitles { magnet, title, device, subtitle_url, seek_seconds, file_idx } => {
+                AppCommand::RestartWithSubtitles {
+                    magnet,
+                    title,
+                    device,
+                    subtitle_url,
+                    seek_seconds,
+                    file_idx,
+                    offset_ms,
+                } => {
                     // Clear old log file so monitor starts fresh
-                    let log_path = get_playback_log_path();
-                    let _ = std::fs::remove_file(&log_path);
+                    log.clear();
+                    log.event(format!(
+                        "restart with subtitles at {}s: {}",
+                        seek_seconds, title
+                    ));
 
                     // Restart playback with subtitles at saved position
-                    match restart_with_subtitles(&magnet, &title, &device, &subtitle_url, seek_seconds, file_idx).await {
+                    match playback::restart_with_subtitles(
+                        &magnet,
+                        &device,
+                        &subtitle_url,
+                        seek_seconds,
+                        file_idx,
+                        offset_ms,
+                        &log,
+                    )
+                    .await
+                    {
                         Ok(msg_str) => {
                             // Spawn log monitor to update TUI with torrent progress
                             let monitor_tx = msg_tx.clone();
-                            tokio::spawn(monitor_playback_log(monitor_tx));
-                            AppMessage::PlaybackStarted { stream_url: msg_str }
+                            tokio::spawn(monitor_playback_log(monitor_tx, log.path.clone()));
+                            AppMessage::PlaybackStarted {
+                                stream_url: msg_str,
+                            }
+                        }
+                        Err(e) => {
+                            log.event(format!("restart failed: {}", e));
+                            if e.is::<MissingTool>() {
+                                AppMessage::Error(e.to_string())
+                            } else {
+                                AppMessage::Error(format!("Restart failed: {}", e))
+                            }
                         }
-                        Err(e) => AppMessage::Error(format!("Restart failed: {}", e)),
                     }
                 }
-                AppCommand::PlaybackControl { action, device } => {
+                AppCommand::PlaybackControl {
+                    action,
+                    device,
+                    value,
+                } => {
                     // Send control command to catt
-                    let _ = playback_control(&action, &device).await;
+                    let _ = playback::playback_control(&action, &device, value).await;
                     // No message needed - fire and forget
                     return;
                 }
-                AppCommand::SaveSettings { subtitle_lang, device_name } => {
+                AppCommand::SaveSettings {
+                    subtitle_lang,
+                    device_name,
+                } => {
                     // Save settings to config file
                     let mut cfg = Config::load();
                     cfg.default_subtitle_lang = Some(subtitle_lang);
@@ -330,6 +669,137 @@ async fn handle_async_commands(
                     let _ = cfg.save();
                     return;
                 }
+                AppCommand::PollTorrentStatus => {
+                    // The log appears once webtorrent starts - nothing to report before that
+                    let Ok(log) = std::fs::read_to_string(&log.path) else {
+                        return;
+                    };
+                    let state = stream::torrent::parse_log_state(&log);
+                    match (models::TorrentSession::parse_log_progress(&log), state) {
+                        (Some((progress, speed, peers)), state) => AppMessage::TorrentProgress {
+                            progress,
+                            speed,
+                            peers,
+                            state,
+                        },
+                        // Only "Connected to N peers" / "downloaded X of Y" so far
+                        (None, Some(state)) => {
+                            let progress = match state {
+                                TorrentState::Buffering { progress, .. } => {
+                                    f32::from(progress) / 100.0
+                                }
+                                _ => 0.0,
+                            };
+                            let peers = state.peers().unwrap_or(0);
+                            AppMessage::TorrentProgress {
+                                progress,
+                                speed: 0,
+                                peers,
+                                state: Some(state),
+                            }
+                        }
+                        (None, None) => return,
+                    }
+                }
+                AppCommand::FetchCastStatus { device } => match fetch_cast_status(&device).await {
+                    Some(status) => AppMessage::CastStatusLoaded(status),
+                    None => AppMessage::CastStatusUnavailable,
+                },
+                AppCommand::SaveWatchlist(entries) => {
+                    let mut cfg = Config::load();
+                    cfg.watchlist = entries;
+                    let _ = cfg.save();
+                    return;
+                }
+                AppCommand::SaveSearchHistory(history) => {
+                    let mut cfg = Config::load();
+                    cfg.search_history = history;
+                    let _ = cfg.save();
+                    return;
+                }
+                AppCommand::SaveSubtitleLang(codes) => {
+                    let mut cfg = Config::load();
+                    cfg.custom_subtitle_lang = Some(codes);
+                    let _ = cfg.save();
+                    return;
+                }
+                AppCommand::SaveTheme(palette) => {
+                    let mut cfg = Config::load();
+                    cfg.theme = Some(palette.name().to_string());
+                    let _ = cfg.save();
+                    return;
+                }
+                AppCommand::SaveDevices { known, order } => {
+                    let mut cfg = Config::load();
+                    cfg.known_devices = known;
+                    cfg.device_order = order;
+                    let _ = cfg.save();
+                    return;
+                }
+                AppCommand::SaveResume { info_hash, entry } => {
+                    let mut cfg = Config::load();
+                    cfg.record_resume(&info_hash, entry);
+                    let _ = cfg.save();
+                    return;
+                }
+                AppCommand::FetchPoster(poster_path) => {
+                    // Posters are decoration: failures just keep the text layout
+                    match ui::poster::fetch(&reqwest::Client::new(), &poster_path).await {
+                        Ok(file) => AppMessage::PosterLoaded { poster_path, file },
+                        Err(_) => return,
+                    }
+                }
+                AppCommand::FetchRecommendations {
+                    id,
+                    media_type,
+                    generation,
+                } => match client.recommendations(media_type, id).await {
+                    Ok(results) => AppMessage::SearchResults {
+                        results,
+                        page: 1,
+                        total_pages: 1,
+                        generation,
+                    },
+                    Err(e) => fetch_failed("Failed to fetch recommendations", e),
+                },
+                AppCommand::FetchCollection { id, generation } => {
+                    match client.collection(id).await {
+                        Ok(results) => AppMessage::SearchResults {
+                            results,
+                            page: 1,
+                            total_pages: 1,
+                            generation,
+                        },
+                        Err(e) => fetch_failed("Failed to fetch collection", e),
+                    }
+                }
+                AppCommand::OpenTrailer {
+                    id,
+                    media_type,
+                    title,
+                } => match client.videos(media_type, id).await {
+                    Ok(videos) => match Video::pick_trailer(&videos) {
+                        Some(trailer) => match open_url(&trailer.youtube_url()).await {
+                            Ok(()) => {
+                                AppMessage::Notice(format!("Opened trailer: {}", trailer.name))
+                            }
+                            Err(e) => AppMessage::Error(format!(
+                                "Couldn't open {}: {}",
+                                trailer.youtube_url(),
+                                e
+                            )),
+                        },
+                        None => AppMessage::Notice(format!("No trailer on TMDB for {}", title)),
+                    },
+                    Err(e) => fetch_failed("Failed to fetch trailer", e),
+                },
+                AppCommand::CopyMagnet(magnet) => {
+                    let text = magnet.clone();
+                    match tokio::task::spawn_blocking(move || copy_to_clipboard(&text)).await {
+                        Ok(Ok(())) => AppMessage::Notice("Magnet copied to clipboard".into()),
+                        _ => AppMessage::ClipboardUnavailable(magnet),
+                    }
+                }
             };
             let _ = msg_tx.send(result);
         });
@@ -343,42 +813,130 @@ async fn run_event_loop(
     mut msg_rx: mpsc::UnboundedReceiver<AppMessage>,
 ) -> Result<()> {
     const TICK_RATE: Duration = Duration::from_millis(50);
+    const POLL_RATE: Duration = Duration::from_secs(1);
+    let mut last_poll = std::time::Instant::now();
+    let mut poster = PosterPainter::default();
 
     while app.running {
         // Render current state
         terminal.draw(|frame| render_ui(frame, app))?;
+        poster.paint(terminal, app)?;
 
         // Check for async messages (non-blocking)
         while let Ok(msg) = msg_rx.try_recv() {
             app.handle_message(msg);
         }
 
-        // Poll for keyboard events with timeout
+        // Background polling (torrent progress, etc.)
+        if last_poll.elapsed() >= POLL_RATE {
+            last_poll = std::time::Instant::now();
+            app.tick();
+        }
+
+        // Poll for keyboard and mouse events with timeout
         if event::poll(TICK_RATE)? {
-            if let Event::Key(key) = event::read()? {
+            match event::read()? {
                 // Only handle key press events (ignore releases on Windows)
-                if key.kind == KeyEventKind::Press {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     app.handle_key(key);
                 }
+                Event::Mouse(mouse) => {
+                    app.handle_mouse(mouse);
+                }
+                // Redraw at the new size right away rather than on the next tick
+                Event::Resize(..) => {
+                    terminal.autoresize()?;
+                    terminal.draw(|frame| render_ui(frame, app))?;
+                }
+                _ => {}
             }
         }
     }
 
+    poster.clear(terminal, app)?;
     Ok(())
 }
 
+/// Draws the detail poster with terminal graphics after each frame
+///
+/// Images live outside ratatui's buffer, so they're only re-sent when the
+/// poster or its area changes.
+#[derive(Default)]
+struct PosterPainter {
+    /// Poster currently on screen and where
+    shown: Option<(std::path::PathBuf, Rect)>,
+}
+
+impl PosterPainter {
+    fn paint(&mut self, terminal: &mut Tui, app: &App) -> Result<()> {
+        use std::io::Write;
+
+        let Some(protocol) = app.graphics else {
+            return Ok(());
+        };
+        let wanted = app
+            .current_poster()
+            .zip(app.poster_area.get())
+            .map(|(file, area)| (file.to_path_buf(), area));
+        if wanted == self.shown {
+            return Ok(());
+        }
+
+        if self.shown.is_some() {
+            self.clear(terminal, app)?;
+            if protocol == GraphicsProtocol::Sixel {
+                // Sixel pixels are only removed by the full redraw next frame
+                return Ok(());
+            }
+        }
+
+        if let Some((ref file, area)) = wanted {
+            // An image chafa can't handle is skipped rather than retried every frame
+            if let Ok(image) = poster::encode(protocol, file, area.width, area.height) {
+                let backend = terminal.backend_mut();
+                execute!(backend, crossterm::cursor::MoveTo(area.x, area.y))?;
+                backend.write_all(&image)?;
+                backend.flush()?;
+            }
+        }
+        self.shown = wanted;
+        Ok(())
+    }
+
+    /// Remove the drawn poster (kitty deletes it, sixel needs a repaint)
+    fn clear(&mut self, terminal: &mut Tui, app: &App) -> Result<()> {
+        use std::io::Write;
+
+        let (Some(protocol), Some(_)) = (app.graphics, self.shown.take()) else {
+            return Ok(());
+        };
+        match protocol {
+            GraphicsProtocol::Kitty => {
+                let backend = terminal.backend_mut();
+                backend.write_all(poster::clear_sequence(protocol))?;
+                backend.flush()?;
+            }
+            GraphicsProtocol::Sixel => terminal.clear()?,
+        }
+        Ok(())
+    }
+}
+
 // =============================================================================
 // UI Rendering
 // =============================================================================
 
 /// Main render function - dispatches to view-specific renderers
-fn render_ui(frame: &mut Frame, app: &App) {
+fn render_ui(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
+    // Renderers re-record the clickable lists (and poster area) every frame
+    app.rendered_lists.borrow_mut().clear();
+    app.poster_area.set(None);
 
     // Clear with background color
     frame.render_widget(Clear, area);
     frame.render_widget(
-        Block::default().style(ratatui::style::Style::default().bg(Theme::BACKGROUND)),
+        Block::default().style(ratatui::style::Style::default().bg(app.theme.background)),
         area,
     );
 
@@ -396,10 +954,16 @@ fn render_ui(frame: &mut Frame, app: &App) {
     render_header(frame, chunks[0], app);
     render_content(frame, chunks[1], app);
     render_status_bar(frame, chunks[2], app);
+    let theme = &app.theme;
 
     // Render error overlay if present
     if let Some(ref error) = app.error {
-        render_error_popup(frame, area, error);
+        render_error_popup(frame, area, error, theme, &app.glyphs);
+    }
+
+    // Magnet that couldn't be copied
+    if let Some(ref magnet) = app.magnet_popup {
+        render_magnet_popup(frame, area, magnet, theme, &app.glyphs);
     }
 
     // Render device selection modal if open
@@ -407,14 +971,31 @@ fn render_ui(frame: &mut Frame, app: &App) {
         render_device_modal(frame, area, app);
     }
 
+    // Render genre picker if open
+    if app.show_genre_modal {
+        render_genre_modal(frame, area, app);
+    }
+
+    // Render subtitle preview if open
+    if app.state == AppState::Subtitles && app.subtitles.preview.is_some() {
+        render_subtitle_preview_modal(frame, area, app);
+    }
+
     // Render settings modal if open
     if app.show_settings_modal {
         render_settings_modal(frame, area, app);
     }
+
+    // Render keybinding help overlay if open
+    if app.show_help_modal {
+        render_help_modal(frame, area, app);
+    }
 }
 
 /// Render the header with title and search box
 fn render_header(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
     let header_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -428,13 +1009,13 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
         Span::styled(
             "STREAM",
             ratatui::style::Style::default()
-                .fg(Theme::PRIMARY)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(
             "TUI",
             ratatui::style::Style::default()
-                .fg(Theme::SECONDARY)
+                .fg(theme.secondary)
                 .add_modifier(Modifier::BOLD),
         ),
     ]))
@@ -442,47 +1023,47 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(ratatui::style::Style::default().fg(Theme::BORDER)),
+            .border_set(glyphs.border(BorderType::Rounded))
+            .border_style(ratatui::style::Style::default().fg(theme.border)),
     );
     frame.render_widget(logo, header_chunks[0]);
 
     // Search box
     let search_style = if app.input_mode == InputMode::Editing {
-        Theme::border_focused()
+        theme.border_focused()
     } else {
-        Theme::border()
+        theme.border()
     };
 
     let search_text = if app.input_mode == InputMode::Editing {
         let query = &app.search.query;
         let cursor = app.search.cursor.min(query.len());
         let (before, after) = query.split_at(cursor);
-        format!("⌕ {}│{}", before, after)
+        format!("{}{}{}{}", glyphs.search, before, glyphs.cursor, after)
     } else if app.search.query.is_empty() {
-        "⌕ Type / to search...".to_string()
+        format!("{}Type / to search...", glyphs.search)
     } else {
-        format!("⌕ {}", app.search.query)
+        format!("{}{}", glyphs.search, app.search.query)
     };
 
     let search_box = Paragraph::new(search_text)
         .style(if app.input_mode == InputMode::Editing {
-            Theme::input().fg(Theme::PRIMARY)
+            theme.input().fg(theme.primary)
         } else {
-            Theme::input()
+            theme.input()
         })
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
+                .border_set(glyphs.border(BorderType::Rounded))
                 .border_style(search_style)
-                .title(Span::styled(" SEARCH ", Theme::title())),
+                .title(Span::styled(" SEARCH ", theme.title())),
         );
     frame.render_widget(search_box, header_chunks[1]);
 }
 
 /// Render the main content area based on current state
-fn render_content(frame: &mut Frame, area: Rect, app: &App) {
+fn render_content(frame: &mut Frame, area: Rect, app: &mut App) {
     match app.state {
         AppState::Home => render_home(frame, area, app),
         AppState::Search => render_search_results(frame, area, app),
@@ -490,18 +1071,44 @@ fn render_content(frame: &mut Frame, area: Rect, app: &App) {
         AppState::Sources => render_sources(frame, area, app),
         AppState::Subtitles => render_subtitles(frame, area, app),
         AppState::Playing => render_playing(frame, area, app),
+        AppState::Watchlist => render_watchlist(frame, area, app),
     }
 }
 
 /// Render home screen with trending content
-fn render_home(frame: &mut Frame, area: Rect, app: &App) {
+fn render_home(frame: &mut Frame, area: Rect, app: &mut App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    // Continue Watching section above trending (only when something is saved)
+    let area = if app.home.continue_watching.is_empty() {
+        area
+    } else {
+        let rows = app.home.continue_watching.len().min(4) as u16;
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(rows + 2), Constraint::Min(1)])
+            .split(area);
+        render_continue_watching(frame, chunks[0], app);
+        chunks[1]
+    };
+
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(Theme::border())
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
         .title(Span::styled(
-            format!(" ⚡ TRENDING ({}) ", app.home.results.len()),
-            Theme::title(),
+            if app.home.more.is_empty() {
+                format!(" {}TRENDING ({}) ", glyphs.trending, app.home.results.len())
+            } else {
+                let total = app.home.results.len() + app.home.more.len();
+                format!(
+                    " {}TRENDING ({} of {}, m: more) ",
+                    glyphs.trending,
+                    app.home.results.len(),
+                    total
+                )
+            },
+            theme.title(),
         ));
 
     let inner = block.inner(area);
@@ -509,8 +1116,8 @@ fn render_home(frame: &mut Frame, area: Rect, app: &App) {
 
     // Show loading state
     if app.home.loading.is_loading() {
-        let loading = Paragraph::new("⟳ Loading trending content...")
-            .style(Theme::loading())
+        let loading = Paragraph::new(format!("{}Loading trending content...", glyphs.loading))
+            .style(theme.loading())
             .alignment(Alignment::Center);
         frame.render_widget(loading, inner);
         return;
@@ -521,24 +1128,24 @@ fn render_home(frame: &mut Frame, area: Rect, app: &App) {
         let help = Paragraph::new(vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("Welcome to ", Theme::text()),
+                Span::styled("Welcome to ", theme.text()),
                 Span::styled(
                     "StreamTUI",
                     ratatui::style::Style::default()
-                        .fg(Theme::PRIMARY)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
             Line::from(""),
-            Line::from(Span::styled("Quick Start:", Theme::accent())),
+            Line::from(Span::styled("Quick Start:", theme.accent())),
             Line::from(""),
             Line::from(vec![
-                Span::styled("  /  ", Theme::keybind()),
-                Span::styled("Search for movies & shows", Theme::dimmed()),
+                Span::styled("  /  ", theme.keybind()),
+                Span::styled("Search for movies & shows", theme.dimmed()),
             ]),
             Line::from(vec![
-                Span::styled("  q  ", Theme::keybind()),
-                Span::styled("Quit", Theme::dimmed()),
+                Span::styled("  q  ", theme.keybind()),
+                Span::styled("Quit", theme.dimmed()),
             ]),
         ])
         .alignment(Alignment::Center);
@@ -546,15 +1153,24 @@ fn render_home(frame: &mut Frame, area: Rect, app: &App) {
         return;
     }
 
-    // Show trending results list
+    // Show the rows of the trending list around the selection
+    let rows = inner.height.max(1) as usize;
+    app.home.list.scroll_into_view(rows);
+    let offset = app.home.list.offset;
     let items: Vec<ListItem> = app
         .home
         .results
         .iter()
         .enumerate()
+        .skip(offset)
+        .take(rows)
         .map(|(i, result)| {
-            let is_selected = i == app.home.list.selected;
-            let marker = if is_selected { "▸ " } else { "  " };
+            let is_selected = i == app.home.list.selected && !app.home.continue_focused;
+            let marker = if is_selected {
+                glyphs.selected_marker
+            } else {
+                "  "
+            };
             let year_str = result.year.map(|y| format!(" ({})", y)).unwrap_or_default();
             let type_str = match result.media_type {
                 crate::models::MediaType::Movie => "MOVIE",
@@ -564,24 +1180,40 @@ fn render_home(frame: &mut Frame, area: Rect, app: &App) {
             let line = Line::from(vec![
                 Span::styled(
                     marker,
-                    if is_selected { Theme::accent() } else { Theme::dimmed() },
+                    if is_selected {
+                        theme.accent()
+                    } else {
+                        theme.dimmed()
+                    },
                 ),
                 Span::styled(
                     &result.title,
-                    if is_selected { Theme::highlighted() } else { Theme::text() },
+                    if is_selected {
+                        theme.highlighted()
+                    } else {
+                        theme.text()
+                    },
+                ),
+                Span::styled(year_str, theme.year()),
+                Span::styled(
+                    if app.watchlist.contains(result.id, result.media_type) {
+                        format!(" {}", glyphs.favorite)
+                    } else {
+                        String::new()
+                    },
+                    theme.accent(),
                 ),
-                Span::styled(year_str, Theme::year()),
                 Span::raw(" "),
-                Span::styled(format!("[{}]", type_str), Theme::secondary()),
+                Span::styled(format!("[{}]", type_str), theme.secondary()),
                 Span::raw(" "),
                 Span::styled(
-                    format!("★ {:.1}", result.vote_average),
+                    format!("{} {:.1}", glyphs.rating, result.vote_average),
                     if result.vote_average >= 7.0 {
-                        Theme::success()
+                        theme.success()
                     } else if result.vote_average >= 5.0 {
-                        Theme::warning()
+                        theme.warning()
                     } else {
-                        Theme::dimmed()
+                        theme.dimmed()
                     },
                 ),
             ]);
@@ -590,167 +1222,456 @@ fn render_home(frame: &mut Frame, area: Rect, app: &App) {
         })
         .collect();
 
-    let list = List::new(items).style(Theme::text());
+    let list = List::new(items).style(theme.text());
     frame.render_widget(list, inner);
+    app.record_list(ListRegion::Trending, inner, offset);
 }
 
-/// Render search results
-fn render_search_results(frame: &mut Frame, area: Rect, app: &App) {
+/// Render the Continue Watching rows with a mini progress bar each
+fn render_continue_watching(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(Theme::border())
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(if app.home.continue_focused {
+            theme.border_focused()
+        } else {
+            theme.border()
+        })
         .title(Span::styled(
-            format!(" RESULTS ({}) ", app.search.results.len()),
-            Theme::title(),
+            format!(
+                " {} CONTINUE WATCHING ({}) ",
+                glyphs.play,
+                app.home.continue_watching.len()
+            ),
+            theme.title(),
         ));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    if app.search.loading.is_loading() {
-        let loading = Paragraph::new("⟳ Searching...")
-            .style(Theme::loading())
-            .alignment(Alignment::Center);
-        frame.render_widget(loading, inner);
-        return;
-    }
+    // Keep the selected row visible when there are more entries than rows
+    let rows = inner.height as usize;
+    let skip = app
+        .home
+        .continue_list
+        .selected
+        .saturating_sub(rows.saturating_sub(1));
+    let bar_width = 10;
 
-    if app.search.results.is_empty() {
-        let empty = Paragraph::new(if app.search.query.is_empty() {
-            "Type to search for movies and TV shows..."
-        } else {
-            "No results found"
+    let items: Vec<ListItem> = app
+        .home
+        .continue_watching
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(i, entry)| {
+            let is_selected = app.home.continue_focused && i == app.home.continue_list.selected;
+            let marker = if is_selected {
+                glyphs.selected_marker
+            } else {
+                "  "
+            };
+            let filled = (entry.progress() * bar_width as f64).round() as usize;
+            let position = models::format_duration(Duration::from_secs(entry.position));
+            let time_str = if entry.duration > 0 {
+                format!(
+                    " {} / {}",
+                    position,
+                    models::format_duration(Duration::from_secs(entry.duration))
+                )
+            } else {
+                format!(" {}", position)
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    marker,
+                    if is_selected {
+                        theme.accent()
+                    } else {
+                        theme.dimmed()
+                    },
+                ),
+                Span::styled(
+                    &entry.title,
+                    if is_selected {
+                        theme.highlighted()
+                    } else {
+                        theme.text()
+                    },
+                ),
+                Span::raw("  "),
+                Span::styled(glyphs.bar_filled.repeat(filled), theme.accent()),
+                Span::styled(glyphs.bar_empty.repeat(bar_width - filled), theme.dimmed()),
+                Span::styled(time_str, theme.dimmed()),
+            ]))
         })
-        .style(Theme::dimmed())
+        .collect();
+
+    frame.render_widget(List::new(items).style(theme.text()), inner);
+    app.record_list(ListRegion::ContinueWatching, inner, skip);
+}
+
+/// Render saved watchlist titles
+fn render_watchlist(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
+        .title(Span::styled(
+            format!(
+                " {} WATCHLIST ({}) ",
+                glyphs.favorite,
+                app.watchlist.entries.len()
+            ),
+            theme.title(),
+        ));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if app.watchlist.entries.is_empty() {
+        let empty = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled("Your watchlist is empty", theme.dimmed())),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("  f  ", theme.keybind()),
+                Span::styled(
+                    "Star a title from trending, search or detail",
+                    theme.dimmed(),
+                ),
+            ]),
+        ])
         .alignment(Alignment::Center);
         frame.render_widget(empty, inner);
         return;
     }
 
-    // Build result list
     let items: Vec<ListItem> = app
-        .search
-        .results
+        .watchlist
+        .entries
         .iter()
         .enumerate()
-        .map(|(i, result)| {
-            let is_selected = i == app.search.list.selected;
-            let marker = if is_selected { "▸ " } else { "  " };
-            let year_str = result.year.map(|y| format!(" ({})", y)).unwrap_or_default();
-            let type_str = match result.media_type {
+        .map(|(i, entry)| {
+            let is_selected = i == app.watchlist.list.selected;
+            let marker = if is_selected {
+                glyphs.selected_marker
+            } else {
+                "  "
+            };
+            let type_str = match entry.media_type {
                 crate::models::MediaType::Movie => "MOVIE",
                 crate::models::MediaType::Tv => "TV",
             };
 
-            let line = Line::from(vec![
+            ListItem::new(Line::from(vec![
                 Span::styled(
                     marker,
                     if is_selected {
-                        Theme::accent()
+                        theme.accent()
                     } else {
-                        Theme::dimmed()
+                        theme.dimmed()
                     },
                 ),
                 Span::styled(
-                    &result.title,
+                    &entry.title,
                     if is_selected {
-                        Theme::highlighted()
+                        theme.highlighted()
                     } else {
-                        Theme::text()
+                        theme.text()
                     },
                 ),
-                Span::styled(year_str, Theme::year()),
                 Span::raw(" "),
-                Span::styled(format!("[{}]", type_str), Theme::secondary()),
-                Span::raw(" "),
-                Span::styled(
-                    format!("★ {:.1}", result.vote_average),
-                    if result.vote_average >= 7.0 {
-                        Theme::success()
-                    } else if result.vote_average >= 5.0 {
-                        Theme::warning()
-                    } else {
-                        Theme::dimmed()
-                    },
-                ),
-            ]);
-
-            ListItem::new(line)
+                Span::styled(format!("[{}]", type_str), theme.secondary()),
+            ]))
         })
         .collect();
 
-    let list = List::new(items).style(Theme::text());
+    let list = List::new(items).style(theme.text());
     frame.render_widget(list, inner);
+    app.record_list(ListRegion::Watchlist, inner, 0);
 }
 
-/// Render detail view (movie or TV show)
-fn render_detail(frame: &mut Frame, area: Rect, app: &App) {
-    let Some(detail) = &app.detail else {
-        // No detail loaded yet
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Theme::border())
-            .title(Span::styled(" DETAIL ", Theme::title()));
-        frame.render_widget(block, area);
-        return;
-    };
-
-    match detail {
-        DetailState::Movie { detail, .. } => render_movie_detail(frame, area, detail),
-        DetailState::Tv { detail, season_list, episode_list, episodes, selected_season, focus, .. } => {
-            render_tv_detail(frame, area, detail, season_list, episode_list, episodes, *selected_season, *focus);
-        }
-    }
-}
-
-/// Render movie detail view
-fn render_movie_detail(frame: &mut Frame, area: Rect, movie: &crate::models::MovieDetail) {
+/// Render recent searches (search view with an empty query)
+fn render_search_history(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(Theme::border())
-        .title(Span::styled(format!(" {} ", movie.title), Theme::title()));
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
+        .title(Span::styled(" RECENT SEARCHES ", theme.title()));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    // Format runtime
-    let hours = movie.runtime / 60;
-    let mins = movie.runtime % 60;
-    let runtime_str = if hours > 0 {
-        format!("{}h {}m", hours, mins)
+    let items: Vec<ListItem> = app
+        .search
+        .history
+        .iter()
+        .enumerate()
+        .map(|(i, query)| {
+            let is_selected = i == app.search.history_list.selected;
+            let marker = if is_selected {
+                glyphs.selected_marker
+            } else {
+                "  "
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    marker,
+                    if is_selected {
+                        theme.accent()
+                    } else {
+                        theme.dimmed()
+                    },
+                ),
+                Span::styled(
+                    query.as_str(),
+                    if is_selected {
+                        theme.highlighted()
+                    } else {
+                        theme.text()
+                    },
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).style(theme.text());
+    frame.render_widget(list, inner);
+}
+
+/// Render search results
+fn render_search_results(frame: &mut Frame, area: Rect, app: &mut App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    if app.search.showing_history() && !app.search.loading.is_loading() {
+        render_search_history(frame, area, app);
+        return;
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
+        .title(Span::styled(
+            format!(" RESULTS ({}) ", app.search.results.len()),
+            theme.title(),
+        ));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if app.search.loading.is_loading() {
+        let loading = Paragraph::new(format!("{}Searching...", glyphs.loading))
+            .style(theme.loading())
+            .alignment(Alignment::Center);
+        frame.render_widget(loading, inner);
+        return;
+    }
+
+    if app.search.results.is_empty() {
+        let empty = Paragraph::new(if app.search.query.is_empty() {
+            "Type to search for movies and TV shows..."
+        } else {
+            "No results found"
+        })
+        .style(theme.dimmed())
+        .alignment(Alignment::Center);
+        frame.render_widget(empty, inner);
+        return;
+    }
+
+    // Build the visible rows of the result list
+    let rows = inner.height.max(1) as usize;
+    app.search.list.scroll_into_view(rows);
+    let offset = app.search.list.offset;
+    let items: Vec<ListItem> = app
+        .search
+        .results
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(rows)
+        .map(|(i, result)| {
+            let is_selected = i == app.search.list.selected;
+            let marker = if is_selected {
+                glyphs.selected_marker
+            } else {
+                "  "
+            };
+            let year_str = result.year.map(|y| format!(" ({})", y)).unwrap_or_default();
+            let type_str = match result.media_type {
+                crate::models::MediaType::Movie => "MOVIE",
+                crate::models::MediaType::Tv => "TV",
+            };
+
+            let line = Line::from(vec![
+                Span::styled(
+                    marker,
+                    if is_selected {
+                        theme.accent()
+                    } else {
+                        theme.dimmed()
+                    },
+                ),
+                Span::styled(
+                    &result.title,
+                    if is_selected {
+                        theme.highlighted()
+                    } else {
+                        theme.text()
+                    },
+                ),
+                Span::styled(year_str, theme.year()),
+                Span::styled(
+                    if app.watchlist.contains(result.id, result.media_type) {
+                        format!(" {}", glyphs.favorite)
+                    } else {
+                        String::new()
+                    },
+                    theme.accent(),
+                ),
+                Span::raw(" "),
+                Span::styled(format!("[{}]", type_str), theme.secondary()),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{} {:.1}", glyphs.rating, result.vote_average),
+                    if result.vote_average >= 7.0 {
+                        theme.success()
+                    } else if result.vote_average >= 5.0 {
+                        theme.warning()
+                    } else {
+                        theme.dimmed()
+                    },
+                ),
+            ]);
+
+            ListItem::new(line)
+        })
+        .collect();
+
+    let list = List::new(items).style(theme.text());
+    frame.render_widget(list, inner);
+    app.record_list(ListRegion::Search, inner, offset);
+}
+
+/// Render detail view (movie or TV show)
+fn render_detail(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    let Some(detail) = &app.detail else {
+        // No detail loaded yet
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs.border(BorderType::Rounded))
+            .border_style(theme.border())
+            .title(Span::styled(" DETAIL ", theme.title()));
+        frame.render_widget(block, area);
+        return;
+    };
+
+    // Poster side panel (drawn after the frame by PosterPainter)
+    let area = if app.current_poster().is_some() && area.width >= 70 {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(40), Constraint::Length(26)])
+            .split(area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs.border(BorderType::Rounded))
+            .border_style(theme.border());
+        app.poster_area.set(Some(block.inner(chunks[1])));
+        frame.render_widget(block, chunks[1]);
+        chunks[0]
     } else {
-        format!("{}m", mins)
+        area
     };
 
+    match detail {
+        DetailState::Movie { detail, .. } => {
+            render_movie_detail(frame, area, detail, app.quality_floor, theme, &app.glyphs)
+        }
+        DetailState::Tv {
+            detail,
+            season_list,
+            episode_list,
+            episodes,
+            selected_season,
+            focus,
+            ..
+        } => {
+            render_tv_detail(
+                frame,
+                area,
+                app,
+                detail,
+                season_list,
+                episode_list,
+                episodes,
+                *selected_season,
+                *focus,
+            );
+        }
+    }
+}
+
+/// Render movie detail view
+fn render_movie_detail(
+    frame: &mut Frame,
+    area: Rect,
+    movie: &crate::models::MovieDetail,
+    quality_floor: Option<crate::models::Quality>,
+    theme: &Theme,
+    glyphs: &Glyphs,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
+        .title(Span::styled(format!(" {} ", movie.title), theme.title()));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Format runtime
+    let runtime_str = format_runtime(movie.runtime);
+
     // Format genres
-    let genres_str = movie.genres.join(" · ");
+    let genres_str = movie.genres.join(&format!(" {} ", glyphs.bullet));
 
     // Rating color based on score
     let rating_style = if movie.vote_average >= 7.0 {
-        Theme::success()
+        theme.success()
     } else if movie.vote_average >= 5.0 {
-        Theme::warning()
+        theme.warning()
     } else {
-        Theme::dimmed()
+        theme.dimmed()
     };
 
     // Build content lines
     let mut lines = vec![
         // Title line with year and rating
         Line::from(vec![
-            Span::styled(format!("{} ", movie.title), Theme::highlighted()),
-            Span::styled(format!("({}) ", movie.year), Theme::year()),
-            Span::styled(format!("★ {:.1}", movie.vote_average), rating_style),
+            Span::styled(format!("{} ", movie.title), theme.highlighted()),
+            Span::styled(format!("({}) ", movie.year), theme.year()),
+            Span::styled(
+                format!("{} {:.1}", glyphs.rating, movie.vote_average),
+                rating_style,
+            ),
         ]),
         Line::from(""),
         // Runtime and genres
         Line::from(vec![
-            Span::styled(runtime_str, Theme::accent()),
-            Span::styled(" │ ", Theme::dimmed()),
-            Span::styled(genres_str, Theme::secondary()),
+            Span::styled(runtime_str, theme.accent()),
+            Span::styled(format!(" {} ", glyphs.separator), theme.dimmed()),
+            Span::styled(genres_str, theme.secondary()),
         ]),
         Line::from(""),
     ];
@@ -759,10 +1680,13 @@ fn render_movie_detail(frame: &mut Frame, area: Rect, movie: &crate::models::Mov
     let overview_width = inner.width.saturating_sub(4) as usize;
     if !movie.overview.is_empty() {
         for line in wrap_text(&movie.overview, overview_width) {
-            lines.push(Line::from(Span::styled(line, Theme::text())));
+            lines.push(Line::from(Span::styled(line, theme.text())));
         }
     } else {
-        lines.push(Line::from(Span::styled("No overview available.", Theme::dimmed())));
+        lines.push(Line::from(Span::styled(
+            "No overview available.",
+            theme.dimmed(),
+        )));
     }
 
     // Add spacing before keybinds
@@ -771,22 +1695,36 @@ fn render_movie_detail(frame: &mut Frame, area: Rect, movie: &crate::models::Mov
 
     // Keybind hints
     lines.push(Line::from(vec![
-        Span::styled("  c  ", Theme::keybind()),
-        Span::styled("View sources    ", Theme::dimmed()),
-        Span::styled("  u  ", Theme::keybind()),
-        Span::styled("Subtitles    ", Theme::dimmed()),
-        Span::styled(" ESC ", Theme::keybind()),
-        Span::styled("Back", Theme::dimmed()),
+        Span::styled("  c  ", theme.keybind()),
+        Span::styled("View sources    ", theme.dimmed()),
+        Span::styled("  u  ", theme.keybind()),
+        Span::styled("Subtitles    ", theme.dimmed()),
+        Span::styled(" ESC ", theme.keybind()),
+        Span::styled("Back", theme.dimmed()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" +/- ", theme.keybind()),
+        Span::styled("Quality ", theme.dimmed()),
+        quality_floor_span(quality_floor, theme),
     ]));
+    // Only movies in a franchise have something to list
+    if let Some(ref collection) = movie.collection_name {
+        lines.push(Line::from(vec![
+            Span::styled("  C  ", theme.keybind()),
+            Span::styled(collection.clone(), theme.dimmed()),
+        ]));
+    }
 
     let content = Paragraph::new(lines);
     frame.render_widget(content, inner);
 }
 
 /// Render TV show detail view with season/episode selection
+#[allow(clippy::too_many_arguments)]
 fn render_tv_detail(
     frame: &mut Frame,
     area: Rect,
+    app: &App,
     tv: &crate::models::TvDetail,
     season_list: &ListState,
     episode_list: &ListState,
@@ -795,12 +1733,14 @@ fn render_tv_detail(
     focus: TvFocus,
 ) {
     use ratatui::layout::{Constraint, Direction, Layout};
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(Theme::border())
-        .title(Span::styled(format!(" {} ", tv.name), Theme::title()));
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
+        .title(Span::styled(format!(" {} ", tv.name), theme.title()));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -808,33 +1748,47 @@ fn render_tv_detail(
     // Split into header (info) and body (seasons/episodes)
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(4), Constraint::Min(5), Constraint::Length(2)])
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Min(5),
+            Constraint::Length(2),
+        ])
         .split(inner);
 
     // Header: Title, year, rating, genres
     let rating_style = if tv.vote_average >= 7.0 {
-        Theme::success()
+        theme.success()
     } else if tv.vote_average >= 5.0 {
-        Theme::warning()
+        theme.warning()
     } else {
-        Theme::dimmed()
+        theme.dimmed()
     };
 
     let header_lines = vec![
         Line::from(vec![
-            Span::styled(format!("{} ", tv.name), Theme::highlighted()),
-            Span::styled(format!("({}) ", tv.year), Theme::year()),
-            Span::styled(format!("★ {:.1}", tv.vote_average), rating_style),
+            Span::styled(format!("{} ", tv.name), theme.highlighted()),
+            Span::styled(format!("({}) ", tv.year), theme.year()),
+            Span::styled(
+                format!("{} {:.1}", glyphs.rating, tv.vote_average),
+                rating_style,
+            ),
         ]),
-        Line::from(Span::styled(tv.genres.join(" · "), Theme::secondary())),
+        Line::from(Span::styled(
+            tv.genres.join(&format!(" {} ", glyphs.bullet)),
+            theme.secondary(),
+        )),
     ];
     let header = Paragraph::new(header_lines);
     frame.render_widget(header, chunks[0]);
 
-    // Body: Split into seasons (left) and episodes (right)
+    // Body: seasons | episodes | episode detail
     let body_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Length(20), Constraint::Min(30)])
+        .constraints([
+            Constraint::Length(20),
+            Constraint::Min(30),
+            Constraint::Percentage(40),
+        ])
         .split(chunks[1]);
 
     // Seasons list
@@ -843,39 +1797,60 @@ fn render_tv_detail(
         .iter()
         .map(|s| {
             let style = if s.season_number == selected_season {
-                Theme::selected()
+                theme.selected()
             } else {
-                Theme::text()
+                theme.text()
             };
-            ListItem::new(format!("Season {} ({} ep)", s.season_number, s.episode_count))
-                .style(style)
+            ListItem::new(format!(
+                "Season {} ({} ep)",
+                s.season_number, s.episode_count
+            ))
+            .style(style)
         })
         .collect();
 
     let seasons_focused = focus == TvFocus::Seasons;
     let seasons_block = Block::default()
         .borders(Borders::ALL)
-        .border_type(if seasons_focused { BorderType::Double } else { BorderType::Rounded })
-        .border_style(if seasons_focused { Theme::accent() } else { Theme::border() })
-        .title(Span::styled(" Seasons ", if seasons_focused { Theme::highlighted() } else { Theme::accent() }));
+        .border_set(glyphs.border(if seasons_focused {
+            BorderType::Double
+        } else {
+            BorderType::Rounded
+        }))
+        .border_style(if seasons_focused {
+            theme.accent()
+        } else {
+            theme.border()
+        })
+        .title(Span::styled(
+            " Seasons ",
+            if seasons_focused {
+                theme.highlighted()
+            } else {
+                theme.accent()
+            },
+        ));
 
+    let seasons_inner = seasons_block.inner(body_chunks[0]);
     let seasons_widget = List::new(season_items)
         .block(seasons_block)
-        .highlight_style(Theme::highlighted())
-        .highlight_symbol("▸ ");
+        .highlight_style(theme.highlighted())
+        .highlight_symbol(glyphs.selected_marker);
 
     // Convert our ListState to ratatui's ListState
     let mut ratatui_season_state = ratatui::widgets::ListState::default();
     ratatui_season_state.select(Some(season_list.selected));
     frame.render_stateful_widget(seasons_widget, body_chunks[0], &mut ratatui_season_state);
+    app.record_list(
+        ListRegion::Seasons,
+        seasons_inner,
+        ratatui_season_state.offset(),
+    );
 
     // Episodes list
     let episode_items: Vec<ListItem> = episodes
         .iter()
-        .map(|ep| {
-            ListItem::new(format!("E{:02} {}", ep.episode, ep.name))
-                .style(Theme::text())
-        })
+        .map(|ep| ListItem::new(format!("E{:02} {}", ep.episode, ep.name)).style(theme.text()))
         .collect();
 
     let episodes_title = if episodes.is_empty() {
@@ -887,36 +1862,130 @@ fn render_tv_detail(
     let episodes_focused = focus == TvFocus::Episodes;
     let episodes_block = Block::default()
         .borders(Borders::ALL)
-        .border_type(if episodes_focused { BorderType::Double } else { BorderType::Rounded })
-        .border_style(if episodes_focused { Theme::accent() } else { Theme::border() })
-        .title(Span::styled(episodes_title, if episodes_focused { Theme::highlighted() } else { Theme::accent() }));
+        .border_set(glyphs.border(if episodes_focused {
+            BorderType::Double
+        } else {
+            BorderType::Rounded
+        }))
+        .border_style(if episodes_focused {
+            theme.accent()
+        } else {
+            theme.border()
+        })
+        .title(Span::styled(
+            episodes_title,
+            if episodes_focused {
+                theme.highlighted()
+            } else {
+                theme.accent()
+            },
+        ));
 
+    let episodes_inner = episodes_block.inner(body_chunks[1]);
     let episodes_widget = List::new(episode_items)
         .block(episodes_block)
-        .highlight_style(Theme::highlighted())
-        .highlight_symbol("▸ ");
+        .highlight_style(theme.highlighted())
+        .highlight_symbol(glyphs.selected_marker);
 
     // Convert our ListState to ratatui's ListState
     let mut ratatui_episode_state = ratatui::widgets::ListState::default();
     ratatui_episode_state.select(Some(episode_list.selected));
     frame.render_stateful_widget(episodes_widget, body_chunks[1], &mut ratatui_episode_state);
+    app.record_list(
+        ListRegion::Episodes,
+        episodes_inner,
+        ratatui_episode_state.offset(),
+    );
+
+    // Episode detail: synopsis and runtime of the focused episode
+    let detail_block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
+        .title(Span::styled(" Episode ", theme.accent()));
+    let detail_inner = detail_block.inner(body_chunks[2]);
+    frame.render_widget(detail_block, body_chunks[2]);
+
+    let focused_episode = episodes
+        .get(episode_list.selected)
+        .filter(|_| episodes_focused);
+    let detail_lines = match focused_episode {
+        Some(ep) => {
+            let mut meta = vec![Span::styled(
+                format!("S{:02}E{:02}", ep.season, ep.episode),
+                theme.secondary(),
+            )];
+            if let Some(runtime) = ep.runtime.filter(|&r| r > 0) {
+                meta.push(Span::styled(
+                    format!(" {} ", glyphs.separator),
+                    theme.dimmed(),
+                ));
+                meta.push(Span::styled(format_runtime(runtime), theme.accent()));
+            }
+            let mut lines = vec![
+                Line::from(Span::styled(ep.name.as_str(), theme.highlighted())),
+                Line::from(meta),
+                Line::from(""),
+            ];
+            if ep.overview.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    "No overview available.",
+                    theme.dimmed(),
+                )));
+            } else {
+                for line in wrap_text(&ep.overview, detail_inner.width as usize) {
+                    lines.push(Line::from(Span::styled(line, theme.text())));
+                }
+            }
+            lines
+        }
+        None => vec![Line::from(Span::styled(
+            "Tab to the episode list for details",
+            theme.dimmed(),
+        ))],
+    };
+    frame.render_widget(Paragraph::new(detail_lines), detail_inner);
 
     // Footer: Keybind hints
     let footer = Line::from(vec![
-        Span::styled("Tab", Theme::keybind()),
-        Span::styled(":switch  ", Theme::dimmed()),
-        Span::styled("↑↓", Theme::keybind()),
-        Span::styled(":select  ", Theme::dimmed()),
-        Span::styled("Enter", Theme::keybind()),
-        Span::styled(":sources  ", Theme::dimmed()),
-        Span::styled("u", Theme::keybind()),
-        Span::styled(":subtitles  ", Theme::dimmed()),
-        Span::styled("ESC", Theme::keybind()),
-        Span::styled(":back", Theme::dimmed()),
+        Span::styled("Tab", theme.keybind()),
+        Span::styled(":switch  ", theme.dimmed()),
+        Span::styled(glyphs.up_down, theme.keybind()),
+        Span::styled(":select  ", theme.dimmed()),
+        Span::styled("Enter", theme.keybind()),
+        Span::styled(":sources  ", theme.dimmed()),
+        Span::styled("u", theme.keybind()),
+        Span::styled(":subtitles  ", theme.dimmed()),
+        Span::styled("+/-", theme.keybind()),
+        Span::styled(":quality ", theme.dimmed()),
+        quality_floor_span(app.quality_floor, &app.theme),
+        Span::styled("ESC", theme.keybind()),
+        Span::styled(":back", theme.dimmed()),
     ]);
     frame.render_widget(Paragraph::new(footer), chunks[2]);
 }
 
+/// Quality floor for the detail hints, e.g. "≥1080p"
+fn quality_floor_span(
+    quality_floor: Option<crate::models::Quality>,
+    theme: &Theme,
+) -> Span<'static> {
+    match quality_floor {
+        Some(quality) => Span::styled(format!("\u{2265}{}  ", quality), theme.accent()),
+        None => Span::styled("any  ", theme.dimmed()),
+    }
+}
+
+/// Runtime in minutes as "2h 28m" or "48m"
+fn format_runtime(minutes: u32) -> String {
+    let (hours, mins) = (minutes / 60, minutes % 60);
+    if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
 /// Wrap text to fit within a given width
 fn wrap_text(text: &str, width: usize) -> Vec<String> {
     if width == 0 {
@@ -947,30 +2016,97 @@ fn wrap_text(text: &str, width: usize) -> Vec<String> {
 
 /// Render sources view
 fn render_sources(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    let filtered = app.sources.filtered_sources();
+    let mut count = if app.sources.is_narrowed() {
+        format!("{}/{}", filtered.len(), app.sources.sources.len())
+    } else {
+        format!("{}", app.sources.sources.len())
+    };
+    if let Some(min) = app.sources.seed_floor {
+        count.push_str(&format!(", {}+ seeds", min));
+    }
+    if let Some(quality) = app.sources.quality_floor {
+        count.push_str(&format!(", {}+", quality));
+    }
+
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(Theme::border())
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
         .title(Span::styled(
-            format!(" SOURCES ({}) ", app.sources.sources.len()),
-            Theme::title(),
+            format!(" SOURCES ({}) ", count),
+            theme.title(),
         ));
+    let block = match app.sources.sort {
+        Some(sort) => block.title(
+            Line::from(Span::styled(
+                format!(" S sort: {} {} ", sort.display(), glyphs.descending),
+                theme.dimmed(),
+            ))
+            .right_aligned(),
+        ),
+        None => block,
+    };
 
-    let inner = block.inner(area);
+    let mut inner = block.inner(area);
     frame.render_widget(block, area);
 
+    // Filter input line
+    if app.sources.filtering || !app.sources.filter.is_empty() {
+        let cursor = if app.sources.filtering { "_" } else { "" };
+        let filter_line = Paragraph::new(Line::from(vec![
+            Span::styled(" / ", theme.keybind()),
+            Span::styled(format!("{}{}", app.sources.filter, cursor), theme.accent()),
+            Span::styled("  ESC clear", theme.dimmed()),
+        ]));
+        frame.render_widget(filter_line, Rect { height: 1, ..inner });
+        inner.y += 1;
+        inner.height = inner.height.saturating_sub(1);
+    }
+
+    // Season pack file picker
+    if let Some(ref typed) = app.sources.file_idx_input {
+        let mut spans = vec![
+            Span::styled(" Pack file #: ", theme.keybind()),
+            Span::styled(format!("{}_", typed), theme.accent()),
+            Span::styled("  ENTER play  ESC cancel", theme.dimmed()),
+        ];
+        if let Some(name) = app
+            .sources
+            .selected_source()
+            .and_then(|s| s.pack_file_name())
+        {
+            spans.push(Span::styled(
+                format!("  (addon picked {})", name),
+                theme.dimmed(),
+            ));
+        }
+        frame.render_widget(
+            Paragraph::new(Line::from(spans)),
+            Rect { height: 1, ..inner },
+        );
+        inner.y += 1;
+        inner.height = inner.height.saturating_sub(1);
+    }
+
     if app.sources.loading.is_loading() {
-        let loading = Paragraph::new("⟳ Fetching sources...")
-            .style(Theme::loading())
+        let loading = Paragraph::new(format!("{}Fetching sources...", glyphs.loading))
+            .style(theme.loading())
             .alignment(Alignment::Center);
         frame.render_widget(loading, inner);
         return;
     }
 
-    if app.sources.sources.is_empty() {
-        let empty = Paragraph::new("No sources available")
-            .style(Theme::dimmed())
-            .alignment(Alignment::Center);
+    if filtered.is_empty() {
+        let empty = Paragraph::new(if app.sources.sources.is_empty() {
+            "No sources available"
+        } else {
+            "No sources match the filter"
+        })
+        .style(theme.dimmed())
+        .alignment(Alignment::Center);
         frame.render_widget(empty, inner);
         return;
     }
@@ -982,127 +2118,169 @@ fn render_sources(frame: &mut Frame, area: Rect, app: &App) {
         .split(inner);
 
     // Build compact source list for left panel
-    let items: Vec<ListItem> = app
-        .sources
-        .sources
+    let items: Vec<ListItem> = filtered
         .iter()
         .enumerate()
         .map(|(i, source)| {
             let is_selected = i == app.sources.list.selected;
-            let marker = if is_selected { "▸ " } else { "  " };
+            let marker = if is_selected {
+                glyphs.selected_marker
+            } else {
+                "  "
+            };
 
             let quality_style = match source.quality {
-                crate::models::Quality::UHD4K => Theme::quality_4k(),
-                crate::models::Quality::FHD1080p => Theme::quality_1080p(),
-                crate::models::Quality::HD720p => Theme::quality_720p(),
-                _ => Theme::quality_sd(),
+                crate::models::Quality::UHD4K => theme.quality_4k(),
+                crate::models::Quality::FHD1080p => theme.quality_1080p(),
+                crate::models::Quality::HD720p => theme.quality_720p(),
+                _ => theme.quality_sd(),
             };
 
             let seeds_style = if source.seeds >= 100 {
-                Theme::seeds_high()
+                theme.seeds_high()
             } else if source.seeds >= 10 {
-                Theme::seeds_medium()
+                theme.seeds_medium()
             } else {
-                Theme::seeds_low()
+                theme.seeds_low()
             };
 
-            // Truncate title for compact display (use chars to handle UTF-8 properly)
-            let max_title_len = chunks[0].width.saturating_sub(22) as usize;
-            let title_chars: Vec<char> = source.title.chars().collect();
-            let truncated_title = if title_chars.len() > max_title_len {
-                format!("{}…", title_chars[..max_title_len.saturating_sub(1)].iter().collect::<String>())
+            // HDR badge (only shown for HDR sources)
+            let hdr_badge = if source.hdr.is_hdr() {
+                format!(" {} ", source.hdr)
             } else {
-                source.title.clone()
+                String::new()
             };
 
+            // Truncate title for compact display (use chars to handle UTF-8 properly)
+            let max_title_len = chunks[0]
+                .width
+                .saturating_sub(22 + hdr_badge.chars().count() as u16)
+                as usize;
+            let truncated_title = glyphs.truncate_to_width(&source.title, max_title_len);
+
             let line = Line::from(vec![
                 Span::styled(
                     marker,
                     if is_selected {
-                        Theme::accent()
+                        theme.accent()
                     } else {
-                        Theme::dimmed()
+                        theme.dimmed()
                     },
                 ),
                 Span::styled(format!("{:6}", source.quality), quality_style),
                 Span::raw(" "),
+                Span::styled(hdr_badge, hdr_style(source.hdr, theme)),
                 Span::styled(
                     truncated_title,
                     if is_selected {
-                        Theme::highlighted()
+                        theme.highlighted()
                     } else {
-                        Theme::text()
+                        theme.text()
                     },
                 ),
                 Span::raw(" "),
-                Span::styled(format!("👤{:>4}", source.seeds), seeds_style),
+                Span::styled(format!("{}{:>4}", glyphs.seeds, source.seeds), seeds_style),
             ]);
 
             ListItem::new(line)
         })
         .collect();
 
-    let list = List::new(items).style(Theme::text());
+    let list = List::new(items).style(theme.text());
     frame.render_widget(list, chunks[0]);
+    app.record_list(ListRegion::Sources, chunks[0], 0);
 
     // Render detail panel for selected source
     render_source_detail(frame, chunks[1], app);
 }
 
+/// Badge style for a source's HDR format
+fn hdr_style(hdr: HdrType, theme: &Theme) -> ratatui::style::Style {
+    match hdr {
+        HdrType::DolbyVision => theme.hdr_dolby_vision(),
+        HdrType::Hdr10Plus => theme.hdr10_plus(),
+        HdrType::Hdr10 => theme.hdr10(),
+        HdrType::None => theme.dimmed(),
+    }
+}
+
 /// Render the detail panel for the selected source
 fn render_source_detail(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
     let detail_block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(Theme::border())
-        .title(Span::styled(" DETAILS ", Theme::title()));
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
+        .title(Span::styled(" DETAILS ", theme.title()));
 
     let detail_inner = detail_block.inner(area);
     frame.render_widget(detail_block, area);
 
-    let selected_source = app.sources.sources.get(app.sources.list.selected);
+    let selected_source = app.sources.selected_source();
     let Some(source) = selected_source else {
         let empty = Paragraph::new("No source selected")
-            .style(Theme::dimmed())
+            .style(theme.dimmed())
             .alignment(Alignment::Center);
         frame.render_widget(empty, detail_inner);
         return;
     };
 
     let quality_style = match source.quality {
-        crate::models::Quality::UHD4K => Theme::quality_4k(),
-        crate::models::Quality::FHD1080p => Theme::quality_1080p(),
-        crate::models::Quality::HD720p => Theme::quality_720p(),
-        _ => Theme::quality_sd(),
+        crate::models::Quality::UHD4K => theme.quality_4k(),
+        crate::models::Quality::FHD1080p => theme.quality_1080p(),
+        crate::models::Quality::HD720p => theme.quality_720p(),
+        _ => theme.quality_sd(),
     };
 
     let seeds_style = if source.seeds >= 100 {
-        Theme::seeds_high()
+        theme.seeds_high()
     } else if source.seeds >= 10 {
-        Theme::seeds_medium()
+        theme.seeds_medium()
     } else {
-        Theme::seeds_low()
+        theme.seeds_low()
     };
 
     // Build detail lines
     let mut lines = vec![
         Line::from(vec![
-            Span::styled("Quality: ", Theme::dimmed()),
+            Span::styled("Quality: ", theme.dimmed()),
             Span::styled(format!("{}", source.quality), quality_style),
+            Span::raw(" "),
+            Span::styled(format!(" {} ", source.hdr), hdr_style(source.hdr, theme)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Codec: ", theme.dimmed()),
+            Span::styled(
+                source.codec.to_string(),
+                if source.codec == VideoCodec::Unknown {
+                    theme.dimmed()
+                } else {
+                    theme.text()
+                },
+            ),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Size: ", Theme::dimmed()),
-            Span::styled(source.format_size(), Theme::file_size()),
+            Span::styled("Size: ", theme.dimmed()),
+            Span::styled(source.format_size(), theme.file_size()),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Seeds: ", Theme::dimmed()),
+            Span::styled("Seeds: ", theme.dimmed()),
             Span::styled(format!("{}", source.seeds), seeds_style),
         ]),
         Line::from(""),
-        Line::from(vec![Span::styled("Title:", Theme::dimmed())]),
     ];
+    if let Some(group) = &source.release_group {
+        lines.push(Line::from(vec![
+            Span::styled("Group: ", theme.dimmed()),
+            Span::styled(group.clone(), theme.accent()),
+        ]));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(vec![Span::styled("Title:", theme.dimmed())]));
 
     // Wrap the title text to fit the panel width
     let title_width = detail_inner.width.saturating_sub(2) as usize;
@@ -1110,16 +2288,16 @@ fn render_source_detail(frame: &mut Frame, area: Rect, app: &App) {
     for chunk in title.chars().collect::<Vec<_>>().chunks(title_width) {
         lines.push(Line::from(Span::styled(
             chunk.iter().collect::<String>(),
-            Theme::text(),
+            theme.text(),
         )));
     }
 
     // Add provider info if available
-    if !source.name.is_empty() {
+    if !source.provider.is_empty() {
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
-            Span::styled("Provider: ", Theme::dimmed()),
-            Span::styled(&source.name, Theme::text()),
+            Span::styled("Provider: ", theme.dimmed()),
+            Span::styled(&source.provider, theme.text()),
         ]));
     }
 
@@ -1129,27 +2307,56 @@ fn render_source_detail(frame: &mut Frame, area: Rect, app: &App) {
 
 /// Render subtitles view
 fn render_subtitles(frame: &mut Frame, area: Rect, app: &App) {
-    let filter_display = app.subtitles.lang_filter.display();
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    let filter_display = app.subtitles.filter_display();
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(Theme::border())
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border())
         .title(Span::styled(
             format!(" SUBTITLES ({}) ", app.subtitles.subtitles.len()),
-            Theme::title(),
+            theme.title(),
         ))
         .title_bottom(Line::from(vec![
-            Span::styled(" Tab:", Theme::dimmed()),
-            Span::styled(filter_display, Theme::accent()),
-            Span::styled("  ↑↓:select  Enter:use  n:none  ESC:back ", Theme::dimmed()),
+            Span::styled(" Tab:", theme.dimmed()),
+            Span::styled(filter_display, theme.accent()),
+            Span::styled(format!("  c:language  {}:select  Enter:use  Space:preview  l:local file  n:none  ESC:back ", glyphs.up_down), theme.dimmed()),
         ]));
 
-    let inner = block.inner(area);
+    let mut inner = block.inner(area);
     frame.render_widget(block, area);
 
+    // Custom language input
+    if let Some(ref typed) = app.subtitles.lang_input {
+        let lang_line = Paragraph::new(Line::from(vec![
+            Span::styled(" Languages: ", theme.keybind()),
+            Span::styled(format!("{}_", typed), theme.accent()),
+            Span::styled(
+                "  codes like fre or fre,jpn  ENTER search  ESC cancel",
+                theme.dimmed(),
+            ),
+        ]));
+        frame.render_widget(lang_line, Rect { height: 1, ..inner });
+        inner.y += 1;
+        inner.height = inner.height.saturating_sub(1);
+    }
+
+    // Local file path input
+    if let Some(ref typed) = app.subtitles.path_input {
+        let path_line = Paragraph::new(Line::from(vec![
+            Span::styled(" File: ", theme.keybind()),
+            Span::styled(format!("{}_", typed), theme.accent()),
+            Span::styled("  ENTER use  ESC cancel", theme.dimmed()),
+        ]));
+        frame.render_widget(path_line, Rect { height: 1, ..inner });
+        inner.y += 1;
+        inner.height = inner.height.saturating_sub(1);
+    }
+
     if app.subtitles.loading.is_loading() {
-        let loading = Paragraph::new("⟳ Fetching subtitles...")
-            .style(Theme::loading())
+        let loading = Paragraph::new(format!("{}Fetching subtitles...", glyphs.loading))
+            .style(theme.loading())
             .alignment(Alignment::Center);
         frame.render_widget(loading, inner);
         return;
@@ -1157,41 +2364,57 @@ fn render_subtitles(frame: &mut Frame, area: Rect, app: &App) {
 
     if app.subtitles.subtitles.is_empty() {
         let empty = Paragraph::new("No subtitles available\n\nPress Tab to change language filter")
-            .style(Theme::dimmed())
+            .style(theme.dimmed())
             .alignment(Alignment::Center);
         frame.render_widget(empty, inner);
         return;
     }
 
-    // Build subtitle list
-    let items: Vec<ListItem> = app
-        .subtitles
-        .subtitles
+    // Build subtitle list, grouped under language headings
+    let rows = app.subtitles.sorted_grouped();
+    let mut selected_row = 0;
+    let items: Vec<ListItem> = rows
         .iter()
         .enumerate()
-        .map(|(i, sub)| {
+        .map(|(row, entry)| {
+            let i = match entry {
+                app::SubtitleListRow::Language(name) => {
+                    return ListItem::new(Line::from(Span::styled(
+                        format!(" {}", name),
+                        theme.dimmed().add_modifier(Modifier::BOLD),
+                    )));
+                }
+                app::SubtitleListRow::Subtitle(i) => *i,
+            };
+            let sub = &app.subtitles.subtitles[i];
             let is_selected = i == app.subtitles.list.selected;
-            let marker = if is_selected { "▸ " } else { "  " };
+            if is_selected {
+                selected_row = row;
+            }
+            let marker = if is_selected {
+                glyphs.selected_marker
+            } else {
+                "  "
+            };
 
             let line = Line::from(vec![
+                Span::raw("  "),
                 Span::styled(
                     marker,
                     if is_selected {
-                        Theme::accent()
+                        theme.accent()
                     } else {
-                        Theme::dimmed()
+                        theme.dimmed()
                     },
                 ),
                 Span::styled(
-                    &sub.language_name,
+                    &sub.release,
                     if is_selected {
-                        Theme::highlighted()
+                        theme.highlighted()
                     } else {
-                        Theme::text()
+                        theme.text()
                     },
                 ),
-                Span::raw(" "),
-                Span::styled(&sub.release, Theme::dimmed()),
             ]);
 
             ListItem::new(line)
@@ -1199,24 +2422,27 @@ fn render_subtitles(frame: &mut Frame, area: Rect, app: &App) {
         .collect();
 
     let list = List::new(items)
-        .style(Theme::text())
-        .highlight_style(Theme::highlighted());
+        .style(theme.text())
+        .highlight_style(theme.highlighted());
 
     // Convert app ListState to ratatui ListState for scrolling
     let mut list_state = ratatui::widgets::ListState::default();
-    list_state.select(Some(app.subtitles.list.selected));
+    list_state.select(Some(selected_row));
 
     frame.render_stateful_widget(list, inner, &mut list_state);
+    app.record_list(ListRegion::Subtitles, inner, list_state.offset());
 }
 
 /// Render now playing view - a nice centered video player interface
 fn render_playing(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
     // Main container
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Double)
-        .border_style(Theme::accent())
-        .style(ratatui::style::Style::default().bg(Theme::BACKGROUND));
+        .border_set(glyphs.border(BorderType::Double))
+        .border_style(theme.accent())
+        .style(ratatui::style::Style::default().bg(theme.background));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -1225,10 +2451,10 @@ fn render_playing(frame: &mut Frame, area: Rect, app: &App) {
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(1),      // Top spacer
-            Constraint::Length(16),  // Player card
-            Constraint::Length(3),   // Controls
-            Constraint::Min(1),      // Bottom spacer
+            Constraint::Min(1),     // Top spacer
+            Constraint::Length(16), // Player card
+            Constraint::Length(3),  // Controls
+            Constraint::Min(1),     // Bottom spacer
         ])
         .split(inner);
 
@@ -1244,9 +2470,12 @@ fn render_playing(frame: &mut Frame, area: Rect, app: &App) {
     // Player card with double border
     let card_block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(Theme::border_focused())
-        .title(Span::styled(" ▶ NOW PLAYING ", Theme::success()))
+        .border_set(glyphs.border(BorderType::Rounded))
+        .border_style(theme.border_focused())
+        .title(Span::styled(
+            format!(" {} NOW PLAYING ", glyphs.play),
+            theme.success(),
+        ))
         .title_alignment(Alignment::Center);
 
     let card_inner = card_block.inner(card_area);
@@ -1266,44 +2495,75 @@ fn render_playing(frame: &mut Frame, area: Rect, app: &App) {
         .unwrap_or("Unknown");
 
     // Animated spinner for loading states
-    let spinner_frame = (std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis()
-        / 150) % 8;
-    let spinner = ["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"][spinner_frame as usize];
+    let spinner = glyphs.spinner_frame(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis(),
+    );
 
     // Build player content based on state
     let mut lines: Vec<Line> = Vec::new();
 
     // Title (truncate if needed)
-    let title = if app.playing.title.len() > card_inner.width as usize - 4 {
-        format!("{}...", &app.playing.title[..card_inner.width as usize - 7])
-    } else {
-        app.playing.title.clone()
-    };
+    let title = glyphs.truncate_to_width(
+        &app.playing.title,
+        (card_inner.width as usize).saturating_sub(4),
+    );
 
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         title,
         ratatui::style::Style::default()
-            .fg(Theme::PRIMARY)
+            .fg(theme.primary)
             .add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
 
-    // Device indicator
-    lines.push(Line::from(vec![
-        Span::styled("📺 ", Theme::text()),
-        Span::styled(device_name, Theme::accent()),
-    ]));
+    // Device indicator (plus the next episode when casting a season)
+    let mut device_line = vec![
+        Span::styled(glyphs.device, theme.text()),
+        Span::styled(device_name, theme.accent()),
+    ];
+    if let Some(next) = app.playing.playback_queue.first() {
+        device_line.push(Span::styled(
+            format!("  {} Next: {}", glyphs.next, next.label()),
+            theme.dimmed(),
+        ));
+    }
+    lines.push(Line::from(device_line));
+    if let Some(ref next) = app.playing.autoplay {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Playing next in {}s: S{:02}E{:02} (Esc to cancel)",
+                next.remaining_secs(),
+                next.season,
+                next.episode
+            ),
+            theme.warning(),
+        )));
+    }
+    if let Some(ref downgrade) = app.playing.downgrade {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Buffering stalled - switching to {} in {}s (Esc to keep this source)",
+                downgrade.source.quality,
+                downgrade.remaining_secs()
+            ),
+            theme.warning(),
+        )));
+    }
     lines.push(Line::from(""));
 
     if let Some(ref status) = app.playing.playback {
         // Active playback - show progress
         let pos = status.position.as_secs();
         let dur = status.duration.as_secs();
-        let progress = if dur > 0 { pos as f64 / dur as f64 } else { 0.0 };
+        let progress = if dur > 0 {
+            pos as f64 / dur as f64
+        } else {
+            0.0
+        };
 
         // Progress bar
         let bar_width = (card_inner.width as usize).saturating_sub(8).min(40);
@@ -1311,69 +2571,109 @@ fn render_playing(frame: &mut Frame, area: Rect, app: &App) {
         let empty = bar_width.saturating_sub(filled);
 
         let state_icon = match status.state {
-            CastState::Playing => "▶",
-            CastState::Paused => "⏸",
+            CastState::Playing => glyphs.play,
+            CastState::Paused => glyphs.pause,
             CastState::Buffering => spinner,
-            _ => "●",
+            _ => glyphs.idle,
         };
 
         lines.push(Line::from(vec![
-            Span::styled(format!(" {} ", state_icon), Theme::success()),
-            Span::styled("▓".repeat(filled), Theme::accent()),
-            Span::styled("░".repeat(empty), Theme::dimmed()),
+            Span::styled(format!(" {} ", state_icon), theme.success()),
+            Span::styled(glyphs.bar_filled.repeat(filled), theme.accent()),
+            Span::styled(glyphs.bar_empty.repeat(empty), theme.dimmed()),
         ]));
 
         // Time display
         lines.push(Line::from(Span::styled(
             format!(
                 "{:02}:{:02} / {:02}:{:02}",
-                pos / 60, pos % 60, dur / 60, dur % 60
+                pos / 60,
+                pos % 60,
+                dur / 60,
+                dur % 60
             ),
-            Theme::dimmed(),
+            theme.dimmed(),
         )));
 
         // Volume
         lines.push(Line::from(""));
         let vol_bars = (status.volume * 10.0) as usize;
         lines.push(Line::from(vec![
-            Span::styled("🔊 ", Theme::text()),
-            Span::styled("█".repeat(vol_bars), Theme::accent()),
-            Span::styled("░".repeat(10 - vol_bars), Theme::dimmed()),
-            Span::styled(format!(" {:.0}%", status.volume * 100.0), Theme::dimmed()),
+            Span::styled(glyphs.volume, theme.text()),
+            Span::styled(glyphs.volume_bar.repeat(vol_bars), theme.accent()),
+            Span::styled(glyphs.bar_empty.repeat(10 - vol_bars), theme.dimmed()),
+            Span::styled(format!(" {:.0}%", status.volume * 100.0), theme.dimmed()),
         ]));
     } else if let Some(ref torrent) = app.playing.torrent {
         // Torrent streaming state - show comprehensive stats
         let is_streaming = matches!(torrent.state, TorrentState::Streaming);
 
         // State indicator
-        let state_icon = if is_streaming { "▶" } else { spinner };
+        let state_icon = if is_streaming { glyphs.play } else { spinner };
         let state_text = torrent.state.to_string();
 
         lines.push(Line::from(vec![
-            Span::styled(format!("{} ", state_icon), if is_streaming { Theme::success() } else { Theme::accent() }),
-            Span::styled(state_text.clone(), if is_streaming { Theme::success() } else { Theme::loading() }),
+            Span::styled(
+                format!("{} ", state_icon),
+                if is_streaming {
+                    theme.success()
+                } else {
+                    theme.accent()
+                },
+            ),
+            Span::styled(
+                state_text.clone(),
+                if is_streaming {
+                    theme.success()
+                } else {
+                    theme.loading()
+                },
+            ),
         ]));
 
-        // Download progress bar (if we have total size info)
-        if torrent.total_size > 0 {
+        // Download progress bar (byte counts from the log monitor, else polled progress)
+        if torrent.total_size > 0 || torrent.progress > 0.0 {
             lines.push(Line::from(""));
             let bar_width = (card_inner.width as usize).saturating_sub(12).min(35);
-            let progress_pct = (torrent.downloaded as f64 / torrent.total_size as f64 * 100.0).min(100.0);
+            let progress_pct = if torrent.total_size > 0 {
+                (torrent.downloaded as f64 / torrent.total_size as f64 * 100.0).min(100.0)
+            } else {
+                (torrent.progress as f64 * 100.0).min(100.0)
+            };
             let filled = (progress_pct / 100.0 * bar_width as f64) as usize;
             let empty = bar_width.saturating_sub(filled);
 
             lines.push(Line::from(vec![
-                Span::styled("▓".repeat(filled), Theme::success()),
-                Span::styled("░".repeat(empty), Theme::dimmed()),
-                Span::styled(format!(" {:.1}%", progress_pct), Theme::text()),
+                Span::styled(glyphs.bar_filled.repeat(filled), theme.success()),
+                Span::styled(glyphs.bar_empty.repeat(empty), theme.dimmed()),
+                Span::styled(format!(" {:.1}%", progress_pct), theme.text()),
             ]));
 
             // Downloaded / Total
-            let downloaded_str = format_bytes(torrent.downloaded);
-            let total_str = format_bytes(torrent.total_size);
+            if torrent.total_size > 0 {
+                let downloaded_str = format_bytes(torrent.downloaded);
+                let total_str = format_bytes(torrent.total_size);
+                lines.push(Line::from(Span::styled(
+                    format!("{} / {}", downloaded_str, total_str),
+                    theme.dimmed(),
+                )));
+            }
+        }
+
+        // Time until the player has enough to start
+        if let Some(eta) = torrent
+            .eta_to_buffer(torrent.buffer_target())
+            .filter(|eta| !eta.is_zero())
+        {
+            let secs = eta.as_secs();
+            let eta = if secs < 60 {
+                format!("{}s", secs)
+            } else {
+                format!("{}m {:02}s", secs / 60, secs % 60)
+            };
             lines.push(Line::from(Span::styled(
-                format!("{} / {}", downloaded_str, total_str),
-                Theme::dimmed(),
+                format!("~{} to playback", eta),
+                theme.dimmed(),
             )));
         }
 
@@ -1381,39 +2681,41 @@ fn render_playing(frame: &mut Frame, area: Rect, app: &App) {
 
         // Stats row: Speed | Peers
         let speed_str = if torrent.download_speed > 0 {
-            format!("↓ {}/s", format_bytes(torrent.download_speed))
+            format!(
+                "{}{}/s",
+                glyphs.download,
+                format_bytes(torrent.download_speed)
+            )
         } else {
-            "↓ --".to_string()
+            format!("{}--", glyphs.download)
         };
         let peers_str = if torrent.peers > 0 {
-            format!("👥 {} peers", torrent.peers)
+            format!("{}{} peers", glyphs.peers, torrent.peers)
         } else if let Some(p) = torrent.state.peers() {
-            format!("👥 {} peers", p)
+            format!("{}{} peers", glyphs.peers, p)
         } else {
-            "👥 --".to_string()
+            format!("{}-- peers", glyphs.peers)
         };
 
         lines.push(Line::from(vec![
-            Span::styled(speed_str, Theme::accent()),
-            Span::styled("  │  ", Theme::dimmed()),
-            Span::styled(peers_str, Theme::text()),
+            Span::styled(speed_str, theme.accent()),
+            Span::styled(format!("  {}  ", glyphs.separator), theme.dimmed()),
+            Span::styled(peers_str, theme.text()),
         ]));
 
         // Hint when connecting
-        if !is_streaming {
-            if torrent.state.peers() == Some(0) && torrent.peers == 0 {
-                lines.push(Line::from(""));
-                lines.push(Line::from(Span::styled(
-                    "Searching DHT for peers...",
-                    Theme::dimmed(),
-                )));
-            }
+        if !is_streaming && torrent.state.peers() == Some(0) && torrent.peers == 0 {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Searching DHT for peers...",
+                theme.dimmed(),
+            )));
         }
     } else {
         // Fallback - initializing
         lines.push(Line::from(vec![
-            Span::styled(format!("{} ", spinner), Theme::accent()),
-            Span::styled("Initializing...", Theme::loading()),
+            Span::styled(format!("{} ", spinner), theme.accent()),
+            Span::styled("Initializing...", theme.loading()),
         ]));
     }
 
@@ -1428,70 +2730,154 @@ fn render_playing(frame: &mut Frame, area: Rect, app: &App) {
         height: 3,
     };
 
-    let controls = Paragraph::new(vec![
-        Line::from(""),
+    let controls_line = if let Some(secs) = app.playing.resume_prompt {
         Line::from(vec![
-            Span::styled(" SPACE ", Theme::keybind()),
-            Span::styled("Play/Pause ", Theme::dimmed()),
-            Span::styled(" ←→ ", Theme::keybind()),
-            Span::styled("Seek ", Theme::dimmed()),
-            Span::styled(" ↑↓ ", Theme::keybind()),
-            Span::styled("Vol ", Theme::dimmed()),
-            Span::styled(" u ", Theme::keybind()),
-            Span::styled("Subs ", Theme::dimmed()),
-            Span::styled(" s ", Theme::keybind()),
-            Span::styled("Stop", Theme::dimmed()),
-        ]),
-    ])
-    .alignment(Alignment::Center);
+            Span::styled(
+                format!(
+                    "Resume at {}? ",
+                    models::format_duration(Duration::from_secs(secs))
+                ),
+                theme.text(),
+            ),
+            Span::styled(" y ", theme.keybind()),
+            Span::styled("Resume ", theme.dimmed()),
+            Span::styled(" n ", theme.keybind()),
+            Span::styled("Start over", theme.dimmed()),
+        ])
+    } else if let Some(ref typed) = app.playing.seek_input {
+        Line::from(vec![
+            Span::styled("Seek to: ", theme.text()),
+            Span::styled(format!("{}_", typed), theme.accent()),
+            Span::styled("  HH:MM:SS ", theme.dimmed()),
+            Span::styled(" ENTER ", theme.keybind()),
+            Span::styled("Seek ", theme.dimmed()),
+            Span::styled(" ESC ", theme.keybind()),
+            Span::styled("Cancel", theme.dimmed()),
+        ])
+    } else if let Some(ref typed) = app.playing.volume_input {
+        Line::from(vec![
+            Span::styled("Volume: ", theme.text()),
+            Span::styled(format!("{}_%", typed), theme.accent()),
+            Span::styled("  ENTER ", theme.keybind()),
+            Span::styled("Set ", theme.dimmed()),
+            Span::styled(" ESC ", theme.keybind()),
+            Span::styled("Cancel", theme.dimmed()),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled(" SPACE ", theme.keybind()),
+            Span::styled("Play/Pause ", theme.dimmed()),
+            Span::styled(format!(" {} ", glyphs.left_right), theme.keybind()),
+            Span::styled("Seek ", theme.dimmed()),
+            Span::styled(" g ", theme.keybind()),
+            Span::styled("Go to ", theme.dimmed()),
+            Span::styled(format!(" {} ", glyphs.up_down), theme.keybind()),
+            Span::styled("Vol ", theme.dimmed()),
+            Span::styled(" 0-9 ", theme.keybind()),
+            Span::styled("Set Vol ", theme.dimmed()),
+            Span::styled(" u ", theme.keybind()),
+            Span::styled("Subs ", theme.dimmed()),
+            Span::styled(" s ", theme.keybind()),
+            Span::styled("Stop", theme.dimmed()),
+        ])
+    };
+    // Subtitle sync line (only meaningful with a subtitle track loaded)
+    let sync_line = if app.subtitles.selected.is_some() {
+        Line::from(vec![
+            Span::styled(" [ ] ", theme.keybind()),
+            Span::styled("Sub sync ", theme.dimmed()),
+            Span::styled(
+                format!("{:+.1}s", app.playing.subtitle_offset_ms as f64 / 1000.0),
+                theme.accent(),
+            ),
+        ])
+    } else {
+        Line::from("")
+    };
+    let controls = Paragraph::new(vec![sync_line, controls_line]).alignment(Alignment::Center);
     frame.render_widget(controls, controls_area);
 }
 
 /// Render status bar at bottom
 fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
     let mode_indicator = match app.input_mode {
         InputMode::Normal => Span::styled(
             " NORMAL ",
             ratatui::style::Style::default()
-                .fg(Theme::BACKGROUND)
-                .bg(Theme::PRIMARY),
+                .fg(theme.background)
+                .bg(theme.primary),
         ),
         InputMode::Editing => Span::styled(
             " INSERT ",
             ratatui::style::Style::default()
-                .fg(Theme::BACKGROUND)
-                .bg(Theme::ACCENT),
+                .fg(theme.background)
+                .bg(theme.accent),
         ),
     };
 
     let state_indicator = Span::styled(
         format!(" {} ", format!("{:?}", app.state).to_uppercase()),
-        ratatui::style::Style::default().fg(Theme::DIM),
+        ratatui::style::Style::default().fg(theme.dim),
     );
 
     let device_indicator = if let Some(device) = app.selected_cast_device() {
-        Span::styled(format!(" 📺 {} ", device.name), Theme::cast_target())
+        Span::styled(
+            format!(" {}{} ", glyphs.device, device.name),
+            theme.cast_target(),
+        )
     } else {
-        Span::styled(" No device ", Theme::dimmed())
+        Span::styled(" No device ", theme.dimmed())
     };
 
-    let help = Span::styled(" q:quit  /:search  d:device  o:settings  ESC:back ", Theme::dimmed());
+    let help = Span::styled(
+        " q:quit  /:search  w:watchlist  d:device  o:settings  ?:help  ESC:back ",
+        theme.dimmed(),
+    );
 
-    let status_line = Line::from(vec![
-        mode_indicator,
-        state_indicator,
+    let mut spans = vec![mode_indicator, state_indicator];
+    // Something is loading, whichever screen asked for it
+    if app.pending_commands > 0 {
+        let spinner = glyphs.spinner_frame(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis(),
+        );
+        spans.push(Span::styled(format!("{} ", spinner), theme.loading()));
+    }
+    spans.extend([
         Span::raw(" "),
         device_indicator,
-        Span::raw(" │ "),
-        help,
+        Span::raw(format!(" {} ", glyphs.separator)),
     ]);
 
-    let status = Paragraph::new(status_line).style(Theme::status_bar());
+    // Page indicator for multi-page search results
+    if app.state == AppState::Search && app.search.total_pages > 1 {
+        spans.push(Span::styled(
+            format!(
+                "Page {}/{}  n/p:page ",
+                app.search.page, app.search.total_pages
+            ),
+            theme.accent(),
+        ));
+        spans.push(Span::raw(format!("{} ", glyphs.separator)));
+    }
+    if let Some(ref notice) = app.notice {
+        spans.push(Span::styled(format!(" {} ", notice), theme.accent()));
+        spans.push(Span::raw(format!("{} ", glyphs.separator)));
+    }
+    spans.push(help);
+
+    let status_line = Line::from(spans);
+
+    let status = Paragraph::new(status_line).style(theme.status_bar());
     frame.render_widget(status, area);
 }
 
 /// Render error popup overlay
-fn render_error_popup(frame: &mut Frame, area: Rect, error: &str) {
+fn render_error_popup(frame: &mut Frame, area: Rect, error: &str, theme: &Theme, glyphs: &Glyphs) {
     // Calculate centered popup
     let popup_width = 60.min(area.width.saturating_sub(4));
     let popup_height = 5;
@@ -1507,26 +2893,75 @@ fn render_error_popup(frame: &mut Frame, area: Rect, error: &str) {
 
     let error_block = Paragraph::new(vec![
         Line::from(""),
-        Line::from(Span::styled(error, Theme::error())),
+        Line::from(Span::styled(error, theme.error())),
     ])
     .alignment(Alignment::Center)
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .border_type(BorderType::Double)
-            .border_style(Theme::error())
-            .title(Span::styled(" ✗ ERROR ", Theme::error()))
-            .style(ratatui::style::Style::default().bg(Theme::BACKGROUND)),
+            .border_set(glyphs.border(BorderType::Double))
+            .border_style(theme.error())
+            .title(Span::styled(
+                format!(" {} ERROR ", glyphs.error),
+                theme.error(),
+            ))
+            .style(ratatui::style::Style::default().bg(theme.background)),
     );
 
     frame.render_widget(error_block, popup_area);
 }
 
+/// Render a magnet link for copying by hand (clipboard unavailable)
+fn render_magnet_popup(
+    frame: &mut Frame,
+    area: Rect,
+    magnet: &str,
+    theme: &Theme,
+    glyphs: &Glyphs,
+) {
+    let popup_width = 76.min(area.width.saturating_sub(4));
+    let text_width = popup_width.saturating_sub(4).max(1) as usize;
+    let magnet_lines = magnet.chars().count().div_ceil(text_width) as u16;
+    let popup_height = (magnet_lines + 5).min(area.height);
+
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let magnet_block = Paragraph::new(vec![
+        Line::from(Span::styled(
+            "No clipboard available, copy the link below:",
+            theme.dimmed(),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(magnet, theme.text())),
+    ])
+    .wrap(Wrap { trim: false })
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs.border(BorderType::Double))
+            .border_style(theme.accent())
+            .title(Span::styled(" MAGNET ", theme.accent()))
+            .padding(ratatui::widgets::Padding::horizontal(1))
+            .style(ratatui::style::Style::default().bg(theme.background)),
+    );
+
+    frame.render_widget(magnet_block, popup_area);
+}
+
 /// Render device selection modal
 fn render_device_modal(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
     // Calculate centered popup
-    let popup_width = 50.min(area.width.saturating_sub(4));
-    let popup_height = (app.cast_devices.len() as u16 + 4).min(15).max(6);
+    let popup_width = 68.min(area.width.saturating_sub(4));
+    let popup_height = (app.cast_devices.len() as u16 + 4).clamp(6, 15);
 
     let popup_area = Rect {
         x: area.x + (area.width.saturating_sub(popup_width)) / 2,
@@ -1539,17 +2974,20 @@ fn render_device_modal(frame: &mut Frame, area: Rect, app: &App) {
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Double)
-        .border_style(Theme::accent())
-        .title(Span::styled(" 📺 SELECT DEVICE ", Theme::title()))
-        .style(ratatui::style::Style::default().bg(Theme::BACKGROUND));
+        .border_set(glyphs.border(BorderType::Double))
+        .border_style(theme.accent())
+        .title(Span::styled(
+            format!(" {}SELECT DEVICE ", glyphs.device),
+            theme.title(),
+        ))
+        .style(ratatui::style::Style::default().bg(theme.background));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
 
     if app.cast_devices.is_empty() {
         let msg = Paragraph::new("Scanning for devices...")
-            .style(Theme::loading())
+            .style(theme.loading())
             .alignment(Alignment::Center);
         frame.render_widget(msg, inner);
     } else {
@@ -1559,17 +2997,34 @@ fn render_device_modal(frame: &mut Frame, area: Rect, app: &App) {
             .enumerate()
             .map(|(i, device)| {
                 let is_selected = i == app.device_modal_index;
-                let marker = if is_selected { "▸ " } else { "  " };
+                let marker = if is_selected {
+                    glyphs.selected_marker
+                } else {
+                    "  "
+                };
                 let style = if is_selected {
-                    Theme::highlighted()
+                    theme.highlighted()
                 } else {
-                    Theme::text()
+                    theme.text()
                 };
                 let model = device.model.as_deref().unwrap_or("Chromecast");
+                let mark = if app.selected_devices.contains(&i) {
+                    format!("{} ", glyphs.bullet)
+                } else {
+                    String::new()
+                };
                 ListItem::new(Line::from(vec![
-                    Span::styled(marker, if is_selected { Theme::accent() } else { Theme::dimmed() }),
+                    Span::styled(
+                        marker,
+                        if is_selected {
+                            theme.accent()
+                        } else {
+                            theme.dimmed()
+                        },
+                    ),
+                    Span::styled(mark, theme.accent()),
                     Span::styled(&device.name, style),
-                    Span::styled(format!(" ({})", model), Theme::dimmed()),
+                    Span::styled(format!(" ({})", model), theme.dimmed()),
                 ]))
             })
             .collect();
@@ -1585,13 +3040,237 @@ fn render_device_modal(frame: &mut Frame, area: Rect, app: &App) {
         width: popup_area.width - 2,
         height: 1,
     };
-    let help = Paragraph::new("↑↓:select  Enter:confirm  r:refresh  Esc:close")
-        .style(Theme::dimmed())
-        .alignment(Alignment::Center);
+    let help = match app.device_ip_input {
+        Some(ref typed) => Paragraph::new(Line::from(vec![
+            Span::styled("IP: ", theme.keybind()),
+            Span::styled(format!("{}_", typed), theme.accent()),
+            Span::styled("  Enter:add  Esc:cancel", theme.dimmed()),
+        ])),
+        None => Paragraph::new(format!(
+            "{}:select  Space:mark  K/J:move  Enter:ok  a:IP  r:scan  Esc:close",
+            glyphs.up_down
+        ))
+        .style(theme.dimmed()),
+    };
+    frame.render_widget(help.alignment(Alignment::Center), help_area);
+}
+
+/// Render the genre picker opened from Home
+fn render_genre_modal(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    let genres = app.genre_choices();
+    let popup_width = 44.min(area.width.saturating_sub(4));
+    let popup_height = (genres.len() as u16 + 5).min(area.height.saturating_sub(2));
+
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let kind = match app.genre_media_type {
+        crate::models::MediaType::Movie => "MOVIES",
+        crate::models::MediaType::Tv => "TV",
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(glyphs.border(BorderType::Double))
+        .border_style(theme.accent())
+        .title(Span::styled(
+            format!(" {}BROWSE {} ", glyphs.genre, kind),
+            theme.title(),
+        ))
+        .title_bottom(Span::styled(
+            format!(" by {} ", app.genre_sort),
+            theme.dimmed(),
+        ))
+        .style(ratatui::style::Style::default().bg(theme.background));
+
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let items: Vec<ListItem> = genres
+        .iter()
+        .enumerate()
+        .map(|(i, genre)| {
+            let is_selected = i == app.genre_modal_index;
+            let marker = if is_selected {
+                glyphs.selected_marker
+            } else {
+                "  "
+            };
+            let style = if is_selected {
+                theme.highlighted()
+            } else {
+                theme.text()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    marker,
+                    if is_selected {
+                        theme.accent()
+                    } else {
+                        theme.dimmed()
+                    },
+                ),
+                Span::styled(genre.name, style),
+            ]))
+        })
+        .collect();
+
+    // Leave the last two rows for the help line
+    let list_area = Rect {
+        height: inner.height.saturating_sub(2),
+        ..inner
+    };
+    let mut state =
+        ratatui::widgets::ListState::default().with_selected(Some(app.genre_modal_index));
+    frame.render_stateful_widget(List::new(items), list_area, &mut state);
+
+    let help_area = Rect {
+        x: popup_area.x + 1,
+        y: popup_area.y + popup_area.height.saturating_sub(2),
+        width: popup_area.width.saturating_sub(2),
+        height: 1,
+    };
+    let help = Paragraph::new(format!(
+        "{}:select  Tab:movies/TV  s:sort  Enter:browse",
+        glyphs.up_down
+    ))
+    .style(theme.dimmed())
+    .alignment(Alignment::Center);
     frame.render_widget(help, help_area);
 }
 
+/// Render the first cues of the highlighted subtitle (`space` in Subtitles)
+fn render_subtitle_preview_modal(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    let Some(preview) = app.subtitles.preview.as_ref() else {
+        return;
+    };
+    let popup_width = 70.min(area.width.saturating_sub(4));
+    let popup_height = 22.min(area.height.saturating_sub(2));
+
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(glyphs.border(BorderType::Double))
+        .border_style(theme.accent())
+        .title(Span::styled(
+            format!(
+                " {}{} ",
+                glyphs.subtitle,
+                preview.release.chars().take(50).collect::<String>()
+            ),
+            theme.title(),
+        ))
+        .title_bottom(
+            Line::from(Span::styled(
+                format!(" {}:scroll  Enter:use  Space/Esc:close ", glyphs.up_down),
+                theme.dimmed(),
+            ))
+            .centered(),
+        )
+        .style(ratatui::style::Style::default().bg(theme.background));
+
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let status = match &preview.loading {
+        LoadingState::Loading(msg) => Some((
+            format!(
+                "{}{}",
+                glyphs.loading,
+                msg.as_deref().unwrap_or("Loading...")
+            ),
+            theme.loading(),
+        )),
+        LoadingState::Error(e) => Some((e.clone(), theme.error())),
+        LoadingState::Idle => None,
+    };
+    if let Some((text, style)) = status {
+        let paragraph = Paragraph::new(text)
+            .style(style)
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, inner);
+        return;
+    }
+
+    let mut lines = Vec::new();
+    for cue in preview.cues.iter().skip(preview.scroll) {
+        lines.push(Line::from(Span::styled(cue.timestamp(), theme.dimmed())));
+        for text in cue.text.lines() {
+            lines.push(Line::from(Span::styled(text.to_string(), theme.text())));
+        }
+        lines.push(Line::from(""));
+    }
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
+/// Render the full-screen keybinding help overlay (scrolls when too tall)
+fn render_help_modal(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
+    let popup_area = Rect {
+        x: area.x + 2.min(area.width / 4),
+        y: area.y + 1.min(area.height / 4),
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(glyphs.border(BorderType::Double))
+        .border_style(theme.accent())
+        .title(Span::styled(" ? KEYBINDINGS ", theme.title()))
+        .title_bottom(
+            Line::from(Span::styled(
+                format!(" {}:scroll  ?/Esc:close ", glyphs.up_down),
+                theme.dimmed(),
+            ))
+            .centered(),
+        )
+        .style(ratatui::style::Style::default().bg(theme.background));
+
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let mut lines = Vec::new();
+    for section in app.keymap.help_sections() {
+        lines.push(Line::from(Span::styled(section.title, theme.accent())));
+        for (keys, description) in section.entries {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<16}", keys), theme.keybind()),
+                Span::styled(description, theme.text()),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // Don't scroll past the point where the last line reaches the bottom
+    let max_scroll = lines.len().saturating_sub(inner.height as usize);
+    let scroll = app.help_scroll.min(max_scroll) as u16;
+    frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), inner);
+}
+
 fn render_settings_modal(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let glyphs = &app.glyphs;
     // Calculate centered popup
     let popup_width = 45.min(area.width.saturating_sub(4));
     let popup_height = 10;
@@ -1607,21 +3286,25 @@ fn render_settings_modal(frame: &mut Frame, area: Rect, app: &App) {
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(BorderType::Double)
-        .border_style(Theme::accent())
-        .title(Span::styled(" SETTINGS ", Theme::title()))
-        .style(ratatui::style::Style::default().bg(Theme::BACKGROUND));
+        .border_set(glyphs.border(BorderType::Double))
+        .border_style(theme.accent())
+        .title(Span::styled(" SETTINGS ", theme.title()))
+        .style(ratatui::style::Style::default().bg(theme.background));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
 
     // Language field
     let lang_label_style = if app.settings_field_index == 0 {
-        Theme::highlighted()
+        theme.highlighted()
     } else {
-        Theme::text()
+        theme.text()
+    };
+    let lang_marker = if app.settings_field_index == 0 {
+        glyphs.selected_marker
+    } else {
+        "  "
     };
-    let lang_marker = if app.settings_field_index == 0 { "▸ " } else { "  " };
     let lang_value = if app.settings_field_index == 0 {
         format!("[{}]", app.settings_lang_input)
     } else {
@@ -1629,18 +3312,36 @@ fn render_settings_modal(frame: &mut Frame, area: Rect, app: &App) {
     };
 
     let lang_line = Line::from(vec![
-        Span::styled(lang_marker, if app.settings_field_index == 0 { Theme::accent() } else { Theme::dimmed() }),
+        Span::styled(
+            lang_marker,
+            if app.settings_field_index == 0 {
+                theme.accent()
+            } else {
+                theme.dimmed()
+            },
+        ),
         Span::styled("Subtitle language: ", lang_label_style),
-        Span::styled(lang_value, if app.settings_field_index == 0 { Theme::accent() } else { Theme::dimmed() }),
+        Span::styled(
+            lang_value,
+            if app.settings_field_index == 0 {
+                theme.accent()
+            } else {
+                theme.dimmed()
+            },
+        ),
     ]);
 
     // Device field (read-only info, use 'd' to change)
     let device_label_style = if app.settings_field_index == 1 {
-        Theme::highlighted()
+        theme.highlighted()
     } else {
-        Theme::text()
+        theme.text()
+    };
+    let device_marker = if app.settings_field_index == 1 {
+        glyphs.selected_marker
+    } else {
+        "  "
     };
-    let device_marker = if app.settings_field_index == 1 { "▸ " } else { "  " };
     let device_name = app
         .selected_device
         .and_then(|i| app.cast_devices.get(i))
@@ -1648,9 +3349,16 @@ fn render_settings_modal(frame: &mut Frame, area: Rect, app: &App) {
         .unwrap_or("None (press 'd' to select)");
 
     let device_line = Line::from(vec![
-        Span::styled(device_marker, if app.settings_field_index == 1 { Theme::accent() } else { Theme::dimmed() }),
+        Span::styled(
+            device_marker,
+            if app.settings_field_index == 1 {
+                theme.accent()
+            } else {
+                theme.dimmed()
+            },
+        ),
         Span::styled("Default device: ", device_label_style),
-        Span::styled(device_name, Theme::dimmed()),
+        Span::styled(device_name, theme.dimmed()),
     ]);
 
     let content = Paragraph::new(vec![
@@ -1669,9 +3377,12 @@ fn render_settings_modal(frame: &mut Frame, area: Rect, app: &App) {
         width: popup_area.width - 2,
         height: 1,
     };
-    let help = Paragraph::new("↑↓:navigate  Enter:save  Esc:close")
-        .style(Theme::dimmed())
-        .alignment(Alignment::Center);
+    let help = Paragraph::new(format!(
+        "{}:navigate  Enter:save  Esc:close",
+        glyphs.up_down
+    ))
+    .style(theme.dimmed())
+    .alignment(Alignment::Center);
     frame.render_widget(help, help_area);
 }
 
@@ -1679,12 +3390,83 @@ fn render_settings_modal(frame: &mut Frame, area: Rect, app: &App) {
 // Playback Functions
 // =============================================================================
 
+/// Error message for a failed network fetch
+///
+/// Timeouts get their own message so a dead mirror doesn't read like a bug.
+fn fetch_failed(what: &str, e: anyhow::Error) -> AppMessage {
+    if api::is_timeout(&e) {
+        AppMessage::Error("Request timed out".to_string())
+    } else {
+        AppMessage::Error(format!("{}: {}", what, e))
+    }
+}
+
+/// Open a URL in the default browser (`open` on macOS, `xdg-open` elsewhere)
+async fn open_url(url: &str) -> std::io::Result<()> {
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    let status = tokio::process::Command::new(opener)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(std::io::Error::other(format!(
+            "{} exited with {}",
+            opener, status
+        )))
+    }
+}
+
+/// Put text on the system clipboard
+///
+/// On X11 and Wayland the copying program serves the clipboard, so the
+/// handle is kept for the rest of the session instead of being dropped.
+fn copy_to_clipboard(text: &str) -> Result<(), arboard::Error> {
+    static CLIPBOARD: std::sync::Mutex<Option<arboard::Clipboard>> = std::sync::Mutex::new(None);
+    let mut clipboard = CLIPBOARD.lock().unwrap_or_else(|e| e.into_inner());
+    if clipboard.is_none() {
+        *clipboard = Some(arboard::Clipboard::new()?);
+    }
+    clipboard
+        .as_mut()
+        .expect("clipboard was just set")
+        .set_text(text)
+}
+
 /// Discover Chromecast devices using catt scan
-async fn discover_cast_devices() -> anyhow::Result<Vec<CastDevice>> {
-    let output = tokio::process::Command::new("catt")
+/// Run `catt scan` up to `attempts` times and merge what each one found
+///
+/// mDNS answers are easily missed, so a device absent from one scan often
+/// turns up in the next. A failing later scan keeps the earlier results.
+async fn discover_cast_devices(attempts: u32) -> anyhow::Result<Vec<CastDevice>> {
+    let mut devices = scan_cast_devices().await?;
+    for _ in 1..attempts {
+        match scan_cast_devices().await {
+            Ok(found) => CastDevice::merge_scan(&mut devices, found),
+            Err(_) => break,
+        }
+    }
+    Ok(devices)
+}
+
+/// One `catt scan`
+async fn scan_cast_devices() -> anyhow::Result<Vec<CastDevice>> {
+    let scan = tokio::process::Command::new("catt")
         .arg("scan")
-        .output()
-        .await?;
+        .kill_on_drop(true)
+        .output();
+    let Ok(output) = tokio::time::timeout(SCAN_TIMEOUT, scan).await else {
+        anyhow::bail!("catt scan timed out after {}s", SCAN_TIMEOUT.as_secs());
+    };
+    let output = output?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -1706,245 +3488,28 @@ async fn discover_cast_devices() -> anyhow::Result<Vec<CastDevice>> {
     Ok(devices)
 }
 
-/// Kill any running webtorrent processes on TUI exit
+/// Kill the webtorrent processes this TUI started, on exit
 fn cleanup_torrent_processes() {
-    // Kill webtorrent processes spawned by this app
-    let _ = std::process::Command::new("pkill")
-        .args(["-f", "webtorrent"])
-        .output();
-}
-
-/// Start playback: webtorrent with native chromecast support
-async fn start_playback(
-    magnet: &str,
-    _title: &str,
-    device: &str,
-    subtitle_url: Option<&str>,
-    file_idx: Option<u32>,
-) -> anyhow::Result<String> {
-    // Download subtitle file if URL provided
-    let subtitle_path = if let Some(url) = subtitle_url {
-        download_subtitle(url).await.ok()
-    } else {
-        None
-    };
-
-    // Use our own CLI tool for casting
-    let exe = std::env::current_exe()
-        .map_err(|e| anyhow::anyhow!("Failed to get executable path: {}", e))?;
-    let exe_path = exe.to_string_lossy();
-
-    // Build command: streamtui cast-magnet <magnet> [-d <device> | --vlc] [--subtitle-file <path>] -q
-    let is_vlc = device == "VLC (Local)";
-    let mut args = if is_vlc {
-        format!(
-            "nohup '{}' cast-magnet '{}' --vlc -q",
-            exe_path.replace('\'', "'\\''"),
-            magnet.replace('\'', "'\\''")
-        )
-    } else {
-        format!(
-            "nohup '{}' cast-magnet '{}' -d '{}' -q",
-            exe_path.replace('\'', "'\\''"),
-            magnet.replace('\'', "'\\''"),
-            device.replace('\'', "'\\''")
-        )
-    };
-
-    if let Some(ref sub_path) = subtitle_path {
-        args.push_str(&format!(" --subtitle-file '{}'", sub_path.replace('\'', "'\\''")));
-    }
-
-    // Add file index if specified (to select correct file in multi-file torrents)
-    if let Some(idx) = file_idx {
-        args.push_str(&format!(" -i {}", idx));
-    }
-
-    // Use a log file instead of /dev/null - webtorrent/VLC need somewhere to output
-    let log_path = dirs::cache_dir()
-        .unwrap_or_else(|| std::path::PathBuf::from("/tmp"))
-        .join("streamtui")
-        .join("playback.log");
-    if let Some(parent) = log_path.parent() {
-        std::fs::create_dir_all(parent).ok();
-    }
-    let log_path_str = log_path.to_string_lossy();
-
-    args.push_str(&format!(" </dev/null >>'{}' 2>&1 &", log_path_str));
-
-    let child = std::process::Command::new("sh")
-        .arg("-c")
-        .arg(&args)
-        .stdin(std::process::Stdio::null())
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .spawn()
-        .map_err(|e| anyhow::anyhow!("Failed to start cast: {}", e))?;
-
-    std::mem::forget(child);
-
-    let msg = if is_vlc {
-        if subtitle_path.is_some() {
-            "Playing in VLC (with subtitles)".to_string()
-        } else {
-            "Playing in VLC".to_string()
-        }
-    } else if subtitle_path.is_some() {
-        format!("Casting to {} (with subtitles)", device)
-    } else {
-        format!("Casting to {}", device)
-    };
-    Ok(msg)
-}
-
-/// Download subtitle file to temp directory
-async fn download_subtitle(url: &str) -> anyhow::Result<String> {
-    use std::io::Write;
-
-    let client = reqwest::Client::new();
-    let response = client.get(url).send().await?;
-
-    if !response.status().is_success() {
-        anyhow::bail!("Failed to download subtitle: HTTP {}", response.status());
-    }
-
-    let bytes = response.bytes().await?;
-
-    // Create temp file with .srt extension
-    let temp_dir = std::env::temp_dir();
-    let filename = format!("streamtui_sub_{}.srt", std::process::id());
-    let path = temp_dir.join(filename);
-
-    let mut file = std::fs::File::create(&path)?;
-    file.write_all(&bytes)?;
-
-    Ok(path.to_string_lossy().to_string())
+    sessions::stop_started();
 }
 
-/// Stop playback - kill webtorrent processes
-async fn stop_playback() -> anyhow::Result<()> {
-    // Kill any running webtorrent processes
-    let _ = tokio::process::Command::new("pkill")
-        .arg("-f")
-        .arg("webtorrent")
-        .output()
-        .await;
-
-    // Stop catt playback
-    let _ = tokio::process::Command::new("catt")
-        .arg("stop")
-        .output()
-        .await;
+/// Query a Chromecast with `catt status` (None if catt fails or times out)
+async fn fetch_cast_status(device: &str) -> Option<models::PlaybackStatus> {
+    let output = tokio::time::timeout(
+        Duration::from_secs(3),
+        tokio::process::Command::new("catt")
+            .args(["-d", device, "status"])
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .ok()?
+    .ok()?;
 
-    Ok(())
-}
-
-/// Restart playback with subtitles at a specific position
-async fn restart_with_subtitles(
-    magnet: &str,
-    _title: &str,
-    device: &str,
-    subtitle_url: &str,
-    seek_seconds: u32,
-    file_idx: Option<u32>,
-) -> anyhow::Result<String> {
-    // 1. Stop current playback
-    stop_playback().await?;
-
-    // Small delay to ensure clean stop
-    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-
-    // 2. Download subtitle file
-    let subtitle_path = download_subtitle(subtitle_url).await?;
-
-    // 3. Use our own CLI tool with --start for seeking
-    let exe = std::env::current_exe()?;
-    let exe_path = exe.to_string_lossy();
-
-    // Build command: streamtui cast-magnet <magnet> [-d <device> | --vlc] --subtitle-file <path> --start <pos> -q
-    let is_vlc = device == "VLC (Local)";
-    let mut args = if is_vlc {
-        format!(
-            "nohup '{}' cast-magnet '{}' --vlc --subtitle-file '{}' -q",
-            exe_path.replace('\'', "'\\''"),
-            magnet.replace('\'', "'\\''"),
-            subtitle_path.replace('\'', "'\\''")
-        )
-    } else {
-        format!(
-            "nohup '{}' cast-magnet '{}' -d '{}' --subtitle-file '{}' -q",
-            exe_path.replace('\'', "'\\''"),
-            magnet.replace('\'', "'\\''"),
-            device.replace('\'', "'\\''"),
-            subtitle_path.replace('\'', "'\\''")
-        )
-    };
-
-    if seek_seconds > 0 {
-        args.push_str(&format!(" --start {}", seek_seconds));
-    }
-
-    // Add file index if specified
-    if let Some(idx) = file_idx {
-        args.push_str(&format!(" -i {}", idx));
-    }
-
-    // Use a log file instead of /dev/null - webtorrent/VLC need somewhere to output
-    let log_path = dirs::cache_dir()
-        .unwrap_or_else(|| std::path::PathBuf::from("/tmp"))
-        .join("streamtui")
-        .join("playback.log");
-    if let Some(parent) = log_path.parent() {
-        std::fs::create_dir_all(parent).ok();
-    }
-    let log_path_str = log_path.to_string_lossy();
-
-    args.push_str(&format!(" </dev/null >>'{}' 2>&1 &", log_path_str));
-
-    let child = std::process::Command::new("sh")
-        .arg("-c")
-        .arg(&args)
-        .stdin(std::process::Stdio::null())
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .spawn()?;
-
-    std::mem::forget(child);
-
-    Ok(format!("Restarted with subtitles at {}s", seek_seconds))
-}
-
-/// Send playback control command using our own CLI
-async fn playback_control(action: &str, device: &str) -> anyhow::Result<()> {
-    // VLC controls from TUI not supported - users control VLC directly
-    if device == "VLC (Local)" {
-        return Ok(());
-    }
-
-    let exe = std::env::current_exe()?;
-
-    // Map action to our CLI command
-    let (cmd, extra_arg): (&str, Option<&str>) = match action {
-        "play_toggle" | "play" => ("play", None),
-        "pause" => ("pause", None),
-        "stop" => ("stop", None),
-        "volumeup" => ("volume", Some("+10")),
-        "volumedown" => ("volume", Some("-10")),
-        "ffwd" => ("seek", Some("+30")),
-        "rewind" => ("seek", Some("-30")),
-        _ => anyhow::bail!("Unknown action: {}", action),
-    };
-
-    let mut command = tokio::process::Command::new(&exe);
-    command.arg(cmd).arg("-d").arg(device).arg("-q");
-
-    if let Some(arg) = extra_arg {
-        command.arg(arg);
+    if !output.status.success() {
+        return None;
     }
-
-    command.output().await?;
-
-    Ok(())
+    models::PlaybackStatus::parse_catt_status(&String::from_utf8_lossy(&output.stdout))
 }
 
 /// Parse webtorrent output line to extract torrent state
@@ -2030,7 +3595,10 @@ fn extract_download_progress(line: &str) -> Option<u8> {
     let downloaded_bytes = parse_size_to_bytes(downloaded_part)?;
 
     // Parse total amount - need to find where it ends (before next field)
-    let total_end = total_part.find(|c: char| c.is_alphabetic() && c != 'B' && c != 'K' && c != 'M' && c != 'G' && c != 'T')
+    let total_end = total_part
+        .find(|c: char| {
+            c.is_alphabetic() && c != 'B' && c != 'K' && c != 'M' && c != 'G' && c != 'T'
+        })
         .unwrap_or(total_part.len());
     let total_str = &total_part[..total_end].trim();
     let total_bytes = parse_size_to_bytes(total_str)?;
@@ -2046,7 +3614,9 @@ fn extract_download_progress(line: &str) -> Option<u8> {
 /// Parse size string like "161 MB" or "8.7 GB" to bytes
 fn parse_size_to_bytes(s: &str) -> Option<u64> {
     let s = s.trim();
-    let num_end = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+    let num_end = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
     let (num_str, unit) = s.split_at(num_end);
     let num: f64 = num_str.trim().parse().ok()?;
     let unit = unit.trim().to_uppercase();
@@ -2102,7 +3672,8 @@ fn parse_webtorrent_stats(lines: &[&str]) -> Option<TorrentStats> {
         if let Some(pos) = line.find("Speed:") {
             let after = &line[pos + 6..].trim_start();
             // Find end of speed value (before next field)
-            let end = after.find(|c: char| c.is_alphabetic() && c != 'K' && c != 'M' && c != 'G' && c != 'B')
+            let end = after
+                .find(|c: char| c.is_alphabetic() && c != 'K' && c != 'M' && c != 'G' && c != 'B')
                 .map(|p| p + 2) // include unit like "KB"
                 .unwrap_or(after.len());
             let speed_str = &after[..end.min(after.len())].trim();
@@ -2144,21 +3715,18 @@ fn parse_webtorrent_stats(lines: &[&str]) -> Option<TorrentStats> {
         }
     }
 
-    if found_any { Some(stats) } else { None }
-}
-
-/// Get the playback log file path
-fn get_playback_log_path() -> std::path::PathBuf {
-    dirs::cache_dir()
-        .unwrap_or_else(|| std::path::PathBuf::from("/tmp"))
-        .join("streamtui")
-        .join("playback.log")
+    if found_any {
+        Some(stats)
+    } else {
+        None
+    }
 }
 
 /// Monitor playback log file and send state/stats updates
-async fn monitor_playback_log(msg_tx: mpsc::UnboundedSender<AppMessage>) {
-    let log_path = get_playback_log_path();
-
+async fn monitor_playback_log(
+    msg_tx: mpsc::UnboundedSender<AppMessage>,
+    log_path: std::path::PathBuf,
+) {
     // Wait for the log file to appear (playback starts asynchronously)
     for _ in 0..50 {
         if log_path.exists() {
@@ -2200,9 +3768,16 @@ async fn monitor_playback_log(msg_tx: mpsc::UnboundedSender<AppMessage>) {
                             // Don't downgrade from Streaming to earlier states
                             let dominated = matches!(
                                 (&last_state, &state),
-                                (Some(TorrentState::Streaming), TorrentState::Buffering { .. })
-                                    | (Some(TorrentState::Streaming), TorrentState::Connecting { .. })
-                                    | (Some(TorrentState::Streaming), TorrentState::FetchingMetadata { .. })
+                                (
+                                    Some(TorrentState::Streaming),
+                                    TorrentState::Buffering { .. }
+                                ) | (
+                                    Some(TorrentState::Streaming),
+                                    TorrentState::Connecting { .. }
+                                ) | (
+                                    Some(TorrentState::Streaming),
+                                    TorrentState::FetchingMetadata { .. }
+                                )
                             );
 
                             // Only send updates when state changes and not downgrading
@@ -2221,12 +3796,15 @@ async fn monitor_playback_log(msg_tx: mpsc::UnboundedSender<AppMessage>) {
                         // Only send if stats changed
                         if last_stats.as_ref() != Some(&stats) {
                             last_stats = Some(stats.clone());
-                            if msg_tx.send(AppMessage::TorrentStatsUpdate {
-                                download_speed: stats.download_speed,
-                                peers: stats.peers,
-                                downloaded: stats.downloaded,
-                                total: stats.total,
-                            }).is_err() {
+                            if msg_tx
+                                .send(AppMessage::TorrentStatsUpdate {
+                                    download_speed: stats.download_speed,
+                                    peers: stats.peers,
+                                    downloaded: stats.downloaded,
+                                    total: stats.total,
+                                })
+                                .is_err()
+                            {
                                 return;
                             }
                         }
@@ -54,6 +54,32 @@ impl fmt::Display for SearchResult {
     }
 }
 
+/// One page of TMDB search results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchPage {
+    pub results: Vec<SearchResult>,
+    pub page: u32,
+    pub total_pages: u32,
+    /// Rows on this page that weren't movies or shows (people, collections)
+    #[serde(default)]
+    pub skipped: usize,
+}
+
+/// Person result from TMDB person search (actors, directors, etc.)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonResult {
+    pub id: u64,
+    pub name: String,
+    pub known_for: Vec<SearchResult>,
+    pub profile_path: Option<String>,
+}
+
+impl fmt::Display for PersonResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} known for)", self.name, self.known_for.len())
+    }
+}
+
 /// Summary of a TV season (used in TvDetail)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SeasonSummary {
@@ -87,6 +113,11 @@ pub struct MovieDetail {
     pub vote_average: f32,
     pub poster_path: Option<String>,
     pub backdrop_path: Option<String>,
+    /// Franchise the movie belongs to (TMDB collection)
+    #[serde(default)]
+    pub collection_id: Option<u64>,
+    #[serde(default)]
+    pub collection_name: Option<String>,
 }
 
 impl fmt::Display for MovieDetail {
@@ -116,6 +147,42 @@ pub struct TvDetail {
     pub backdrop_path: Option<String>,
 }
 
+impl TvDetail {
+    /// Episode after `season`/`episode`, rolling over to the next season
+    ///
+    /// Uses the season episode counts, so it returns None after the last
+    /// episode of the last known season.
+    pub fn next_episode(&self, season: u8, episode: u8) -> Option<(u8, u8)> {
+        let current = self.seasons.iter().find(|s| s.season_number == season)?;
+        if u16::from(episode) < current.episode_count {
+            return Some((season, episode + 1));
+        }
+        self.seasons
+            .iter()
+            .filter(|s| s.season_number > season && s.episode_count > 0)
+            .min_by_key(|s| s.season_number)
+            .map(|s| (s.season_number, 1))
+    }
+
+    /// `episode` of `season` counted from the first episode of the show
+    ///
+    /// Adds up the episode counts of the regular seasons before `season`
+    /// (specials are left out). None for season 1, where the number is the
+    /// same, or when `season` isn't in the list.
+    pub fn absolute_episode(&self, season: u8, episode: u8) -> Option<u16> {
+        if season <= 1 || !self.seasons.iter().any(|s| s.season_number == season) {
+            return None;
+        }
+        let prior: u16 = self
+            .seasons
+            .iter()
+            .filter(|s| s.season_number > 0 && s.season_number < season)
+            .map(|s| s.episode_count)
+            .sum();
+        Some(prior + u16::from(episode))
+    }
+}
+
 impl fmt::Display for TvDetail {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -129,6 +196,40 @@ impl fmt::Display for TvDetail {
     }
 }
 
+/// A YouTube video attached to a TMDB title (trailer, teaser, clip...)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Video {
+    /// YouTube video key
+    pub key: String,
+    pub name: String,
+    /// "Trailer", "Teaser", "Clip", "Featurette"...
+    pub kind: String,
+    /// Published by the studio or network
+    pub official: bool,
+}
+
+impl Video {
+    /// Watch page for the video
+    pub fn youtube_url(&self) -> String {
+        format!("https://www.youtube.com/watch?v={}", self.key)
+    }
+
+    /// The trailer to show for a title
+    ///
+    /// The first official trailer, else the first trailer of any kind.
+    /// Teasers and clips don't count.
+    pub fn pick_trailer(videos: &[Video]) -> Option<&Video> {
+        let trailers = || {
+            videos
+                .iter()
+                .filter(|v| v.kind.eq_ignore_ascii_case("trailer"))
+        };
+        trailers()
+            .find(|v| v.official)
+            .or_else(|| trailers().next())
+    }
+}
+
 /// TV episode information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Episode {
@@ -146,6 +247,188 @@ impl fmt::Display for Episode {
     }
 }
 
+// =============================================================================
+// Discovery
+// =============================================================================
+
+/// A TMDB genre, with its ID in the movie and TV genre lists
+///
+/// TMDB keeps separate genre lists; TV merges some movie genres (e.g. Action
+/// and Adventure become "Action & Adventure") and lacks others entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Genre {
+    pub name: &'static str,
+    pub movie_id: Option<u32>,
+    pub tv_id: Option<u32>,
+}
+
+/// Genres that can be discovered by name
+pub const GENRES: &[Genre] = &[
+    Genre {
+        name: "Action",
+        movie_id: Some(28),
+        tv_id: Some(10759),
+    },
+    Genre {
+        name: "Adventure",
+        movie_id: Some(12),
+        tv_id: Some(10759),
+    },
+    Genre {
+        name: "Animation",
+        movie_id: Some(16),
+        tv_id: Some(16),
+    },
+    Genre {
+        name: "Comedy",
+        movie_id: Some(35),
+        tv_id: Some(35),
+    },
+    Genre {
+        name: "Crime",
+        movie_id: Some(80),
+        tv_id: Some(80),
+    },
+    Genre {
+        name: "Documentary",
+        movie_id: Some(99),
+        tv_id: Some(99),
+    },
+    Genre {
+        name: "Drama",
+        movie_id: Some(18),
+        tv_id: Some(18),
+    },
+    Genre {
+        name: "Family",
+        movie_id: Some(10751),
+        tv_id: Some(10751),
+    },
+    Genre {
+        name: "Fantasy",
+        movie_id: Some(14),
+        tv_id: Some(10765),
+    },
+    Genre {
+        name: "History",
+        movie_id: Some(36),
+        tv_id: None,
+    },
+    Genre {
+        name: "Horror",
+        movie_id: Some(27),
+        tv_id: None,
+    },
+    Genre {
+        name: "Music",
+        movie_id: Some(10402),
+        tv_id: None,
+    },
+    Genre {
+        name: "Mystery",
+        movie_id: Some(9648),
+        tv_id: Some(9648),
+    },
+    Genre {
+        name: "Romance",
+        movie_id: Some(10749),
+        tv_id: None,
+    },
+    Genre {
+        name: "Sci-Fi",
+        movie_id: Some(878),
+        tv_id: Some(10765),
+    },
+    Genre {
+        name: "Thriller",
+        movie_id: Some(53),
+        tv_id: None,
+    },
+    Genre {
+        name: "War",
+        movie_id: Some(10752),
+        tv_id: Some(10768),
+    },
+    Genre {
+        name: "Western",
+        movie_id: Some(37),
+        tv_id: Some(37),
+    },
+];
+
+impl Genre {
+    /// Look up a genre by name, ignoring case and punctuation ("sci-fi", "SciFi")
+    pub fn find(name: &str) -> Option<&'static Genre> {
+        let key = normalize_genre(name);
+        GENRES.iter().find(|g| normalize_genre(g.name) == key)
+    }
+
+    /// TMDB genre ID for the given media type, if TMDB has one
+    pub fn id_for(&self, media_type: MediaType) -> Option<u32> {
+        match media_type {
+            MediaType::Movie => self.movie_id,
+            MediaType::Tv => self.tv_id,
+        }
+    }
+
+    /// Genres available for a media type
+    pub fn available_for(media_type: MediaType) -> impl Iterator<Item = &'static Genre> {
+        GENRES
+            .iter()
+            .filter(move |g| g.id_for(media_type).is_some())
+    }
+}
+
+fn normalize_genre(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Ordering for discover results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiscoverSort {
+    #[default]
+    Popularity,
+    Rating,
+}
+
+impl DiscoverSort {
+    /// TMDB `sort_by` value
+    pub fn sort_by(&self) -> &'static str {
+        match self {
+            DiscoverSort::Popularity => "popularity.desc",
+            DiscoverSort::Rating => "vote_average.desc",
+        }
+    }
+
+    /// Minimum vote count, so rating sorts aren't topped by titles with one 10/10 vote
+    pub fn min_votes(&self) -> Option<u32> {
+        match self {
+            DiscoverSort::Popularity => None,
+            DiscoverSort::Rating => Some(200),
+        }
+    }
+
+    /// The other ordering
+    pub fn toggle(self) -> Self {
+        match self {
+            DiscoverSort::Popularity => DiscoverSort::Rating,
+            DiscoverSort::Rating => DiscoverSort::Popularity,
+        }
+    }
+}
+
+impl fmt::Display for DiscoverSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiscoverSort::Popularity => write!(f, "Popularity"),
+            DiscoverSort::Rating => write!(f, "Rating"),
+        }
+    }
+}
+
 // =============================================================================
 // Addon Models (Torrentio/Stremio)
 // =============================================================================
@@ -188,6 +471,16 @@ impl Quality {
             Quality::Unknown => 0,
         }
     }
+
+    /// How many rungs apart two qualities are (0 for the same)
+    pub fn distance(&self, other: Quality) -> u8 {
+        self.rank().abs_diff(other.rank())
+    }
+
+    /// Parse a configured quality name, None when it isn't one
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(Self::from_str_loose(name)).filter(|q| *q != Quality::Unknown)
+    }
 }
 
 impl fmt::Display for Quality {
@@ -214,26 +507,194 @@ impl PartialOrd for Quality {
     }
 }
 
+/// High dynamic range format advertised in a release name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum HdrType {
+    #[default]
+    None,
+    Hdr10,
+    Hdr10Plus,
+    DolbyVision,
+}
+
+impl HdrType {
+    /// Parse HDR format from a release title (e.g., "2160p.DV.HDR10", "HDR10+")
+    ///
+    /// Dolby Vision wins when a title advertises several formats, since
+    /// DV releases usually carry an HDR10 fallback layer.
+    pub fn from_title(title: &str) -> Self {
+        let lower = title.to_lowercase();
+        let dv = regex::Regex::new(r"\b(dv|dovi|dolby[ ._-]?vision)\b").ok();
+        if dv.is_some_and(|re| re.is_match(&lower)) {
+            HdrType::DolbyVision
+        } else if lower.contains("hdr10+") || lower.contains("hdr10plus") {
+            HdrType::Hdr10Plus
+        } else if regex::Regex::new(r"\bhdr(10)?\b")
+            .ok()
+            .is_some_and(|re| re.is_match(&lower))
+        {
+            HdrType::Hdr10
+        } else {
+            HdrType::None
+        }
+    }
+
+    /// Whether this is any HDR format
+    pub fn is_hdr(&self) -> bool {
+        *self != HdrType::None
+    }
+}
+
+impl fmt::Display for HdrType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HdrType::None => write!(f, "SDR"),
+            HdrType::Hdr10 => write!(f, "HDR10"),
+            HdrType::Hdr10Plus => write!(f, "HDR10+"),
+            HdrType::DolbyVision => write!(f, "DV"),
+        }
+    }
+}
+
+/// Video codec advertised in a release name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum VideoCodec {
+    X264,
+    X265,
+    Av1,
+    #[default]
+    Unknown,
+}
+
+impl VideoCodec {
+    /// Parse codec from a release title (e.g., "x265", "HEVC", "h.264", "AV1")
+    pub fn from_title(title: &str) -> Self {
+        let lower = title.to_lowercase();
+        let has = |pattern: &str| {
+            regex::Regex::new(pattern)
+                .ok()
+                .is_some_and(|re| re.is_match(&lower))
+        };
+
+        if has(r"\b(x|h)\.?265\b|\bhevc\b") {
+            VideoCodec::X265
+        } else if has(r"\bav1\b") {
+            VideoCodec::Av1
+        } else if has(r"\b(x|h)\.?264\b|\bavc\b") {
+            VideoCodec::X264
+        } else {
+            VideoCodec::Unknown
+        }
+    }
+}
+
+impl fmt::Display for VideoCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VideoCodec::X264 => write!(f, "x264"),
+            VideoCodec::X265 => write!(f, "x265/HEVC"),
+            VideoCodec::Av1 => write!(f, "AV1"),
+            VideoCodec::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Weights used to score stream sources for automatic selection
+///
+/// Each criterion is normalised to 0-1000 before weighting, so the weights
+/// express relative importance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectionPrefs {
+    pub quality_weight: u32,
+    pub seeds_weight: u32,
+    pub size_weight: u32,
+    /// Preferred file size range in bytes (inclusive)
+    pub size_range: Option<(u64, u64)>,
+}
+
+impl Default for SelectionPrefs {
+    fn default() -> Self {
+        Self {
+            quality_weight: 3,
+            seeds_weight: 2,
+            size_weight: 1,
+            size_range: None,
+        }
+    }
+}
+
+impl SelectionPrefs {
+    /// Index of the highest-scoring stream (earliest wins ties)
+    pub fn best_index(&self, streams: &[StreamSource]) -> Option<usize> {
+        streams
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, s)| s.score(self))
+            .map(|(i, _)| i)
+    }
+}
+
+/// Seed floor for `--healthy` and the TUI's hide-low-seeds toggle
+pub const HEALTHY_SEEDS: u32 = 10;
+
 /// Stream source from Torrentio or other Stremio addons
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamSource {
+    /// Addon's name field as sent, e.g. "Torrentio\n4k"
     pub name: String,
+    /// Who found the stream: the first line of `name` ("Torrentio")
+    #[serde(default)]
+    pub provider: String,
     pub title: String,
     pub info_hash: String,
     pub file_idx: Option<u32>,
     pub seeds: u32,
     pub quality: Quality,
     pub size_bytes: Option<u64>,
+    #[serde(default)]
+    pub hdr: HdrType,
+    #[serde(default)]
+    pub codec: VideoCodec,
+    /// Release group from the end of the release name ("SPARKS")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_group: Option<String>,
+    /// Direct HTTP link when resolved through a debrid service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direct_url: Option<String>,
 }
 
 impl StreamSource {
-    /// Generate magnet URL for this stream
+    /// Generate magnet URL for this stream, with the built-in trackers
+    ///
+    /// Uses the hex form of the hash; an invalid hash is embedded as-is, so
+    /// check [`Self::normalized_hash`] first where that matters.
     pub fn to_magnet(&self, display_name: &str) -> String {
-        format!(
-            "magnet:?xt=urn:btih:{}&dn={}",
-            self.info_hash,
-            urlencoding::encode(display_name)
-        )
+        self.to_magnet_with_trackers(display_name, DEFAULT_TRACKERS)
+    }
+
+    /// Generate magnet URL for this stream with these trackers (none = DHT only)
+    pub fn to_magnet_with_trackers<S: AsRef<str>>(
+        &self,
+        display_name: &str,
+        trackers: &[S],
+    ) -> String {
+        let hash = self
+            .normalized_hash()
+            .unwrap_or_else(|| self.info_hash.clone());
+        magnet_for_hash(&hash, display_name, trackers)
+    }
+
+    /// Info hash as 40 lowercase hex characters (base32 hashes are converted)
+    pub fn normalized_hash(&self) -> Option<String> {
+        normalize_info_hash(&self.info_hash)
+    }
+
+    /// Whether at least `min_seeds` peers seed it
+    ///
+    /// Debrid links are served over HTTP, so seeds don't matter for them.
+    pub fn has_seeds(&self, min_seeds: u32) -> bool {
+        self.direct_url.is_some() || self.seeds >= min_seeds
     }
 
     /// Parse seeds from title string (e.g., "👤 142" or "👤 1.2k")
@@ -281,6 +742,86 @@ impl StreamSource {
         Some(bytes)
     }
 
+    /// Release group from the trailing "-GROUP" of the release name
+    ///
+    /// Only the first title line counts, minus a file extension.
+    /// "...BluRay.x264-SPARKS" gives "SPARKS"; "WEB-DL" and years don't count.
+    pub fn parse_release_group(title: &str) -> Option<String> {
+        let name = title.lines().next()?.trim();
+        let name = regex::Regex::new(r"(?i)\.(mkv|mp4|avi|m4v)$")
+            .ok()?
+            .replace(name, "");
+        let re = regex::Regex::new(r"-([A-Za-z0-9]{2,20})$").ok()?;
+        let group = re.captures(&name)?.get(1)?.as_str();
+        const NOT_GROUPS: &[&str] = &["DL", "RIP", "HD", "DTS"];
+        if group.chars().all(|c| c.is_ascii_digit())
+            || NOT_GROUPS.contains(&group.to_uppercase().as_str())
+        {
+            return None;
+        }
+        Some(group.to_string())
+    }
+
+    /// Whether this torrent looks like a season pack (several episodes)
+    ///
+    /// Only the release name (first title line) counts; the line after it is
+    /// usually the episode file the addon picked. "Complete", "Season 1",
+    /// a bare "S01" and episode ranges like "E01-E10" mark a pack, a single
+    /// "S01E03" doesn't.
+    pub fn is_pack(&self) -> bool {
+        let name = self.title.lines().next().unwrap_or_default().to_lowercase();
+        let range = regex::Regex::new(r"e\d{1,3}[ ._]?-[ ._]?e?\d{1,3}\b").ok();
+        if range.is_some_and(|re| re.is_match(&name)) {
+            return true;
+        }
+        let episode = regex::Regex::new(r"s\d{1,2}[ ._]?e\d{1,3}").ok();
+        if episode.is_some_and(|re| re.is_match(&name)) {
+            return false;
+        }
+        let pack = regex::Regex::new(r"\b(complete|seasons?[ ._]?\d{1,2}|s\d{1,2})\b").ok();
+        pack.is_some_and(|re| re.is_match(&name))
+    }
+
+    /// Episode file the addon picked inside a pack (second title line)
+    pub fn pack_file_name(&self) -> Option<&str> {
+        self.title
+            .lines()
+            .nth(1)
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.contains('👤'))
+    }
+
+    /// Score this source for automatic selection (higher = better)
+    ///
+    /// Seeds count logarithmically, saturating at 10k; a source inside the
+    /// preferred size range gets the full size score, anything else none.
+    pub fn score(&self, prefs: &SelectionPrefs) -> u32 {
+        let quality = self.quality.rank() as u32 * 250;
+        let seeds = ((self.seeds as f64 + 1.0).log10() / 4.0 * 1000.0).min(1000.0) as u32;
+        let size = match (prefs.size_range, self.size_bytes) {
+            (Some((min, max)), Some(bytes)) if (min..=max).contains(&bytes) => 1000,
+            _ => 0,
+        };
+
+        quality * prefs.quality_weight + seeds * prefs.seeds_weight + size * prefs.size_weight
+    }
+
+    /// Fold a duplicate listing of the same torrent into this one
+    ///
+    /// Keeps whichever entry has more seeds and joins both provider names.
+    pub fn merge(&mut self, other: &StreamSource) {
+        let mut name = self.name.clone();
+        if !name.split(" / ").any(|n| n == other.name) {
+            name = format!("{} / {}", name, other.name);
+        }
+        let size_bytes = self.size_bytes.or(other.size_bytes);
+        if other.seeds > self.seeds {
+            *self = other.clone();
+        }
+        self.size_bytes = self.size_bytes.or(size_bytes);
+        self.name = name;
+    }
+
     /// Format size for display
     pub fn format_size(&self) -> String {
         match self.size_bytes {
@@ -309,6 +850,24 @@ impl fmt::Display for StreamSource {
     }
 }
 
+/// An episode waiting in a season cast queue, with the stream picked for it
+#[derive(Debug, Clone)]
+pub struct QueuedItem {
+    pub imdb_id: String,
+    /// Display title (e.g., "Breaking Bad S1E4")
+    pub title: String,
+    pub season: u8,
+    pub episode: u8,
+    pub source: StreamSource,
+}
+
+impl QueuedItem {
+    /// Short episode label (e.g., "S01E04")
+    pub fn label(&self) -> String {
+        format!("S{:02}E{:02}", self.season, self.episode)
+    }
+}
+
 // =============================================================================
 // Torrent Models
 // =============================================================================
@@ -381,6 +940,98 @@ impl fmt::Display for TorrentState {
     }
 }
 
+/// Public trackers added to generated magnets (config `trackers` overrides)
+///
+/// Without them webtorrent has to find peers through the DHT alone, which
+/// can take minutes.
+pub const DEFAULT_TRACKERS: &[&str] = &[
+    "udp://tracker.opentrackr.org:1337/announce",
+    "udp://open.demonii.com:1337/announce",
+    "udp://open.stealth.si:80/announce",
+    "udp://tracker.torrent.eu.org:451/announce",
+    "udp://exodus.desync.com:6969/announce",
+    "udp://explodie.org:6969/announce",
+    "wss://tracker.openwebtorrent.com",
+    "wss://tracker.btorrent.xyz",
+];
+
+/// Magnet link for an info hash with a display name and `tr=` trackers
+pub fn magnet_for_hash<S: AsRef<str>>(
+    info_hash: &str,
+    display_name: &str,
+    trackers: &[S],
+) -> String {
+    let mut magnet = format!(
+        "magnet:?xt=urn:btih:{}&dn={}",
+        info_hash,
+        urlencoding::encode(display_name)
+    );
+    for tracker in trackers {
+        magnet.push_str("&tr=");
+        magnet.push_str(&urlencoding::encode(tracker.as_ref()));
+    }
+    magnet
+}
+
+/// Lowercase hex form of a 40-char hex or 32-char base32 info hash
+pub fn normalize_info_hash(hash: &str) -> Option<String> {
+    let hash = hash.trim();
+    match hash.len() {
+        40 if hash.chars().all(|c| c.is_ascii_hexdigit()) => Some(hash.to_ascii_lowercase()),
+        32 => {
+            // RFC 4648 base32: 32 chars * 5 bits = the 20-byte SHA-1
+            let mut bits: u32 = 0;
+            let mut nbits = 0;
+            let mut hex = String::with_capacity(40);
+            for c in hash.chars() {
+                let value = match c.to_ascii_uppercase() {
+                    c @ 'A'..='Z' => c as u32 - 'A' as u32,
+                    c @ '2'..='7' => c as u32 - '2' as u32 + 26,
+                    _ => return None,
+                };
+                bits = (bits << 5) | value;
+                nbits += 5;
+                if nbits >= 8 {
+                    nbits -= 8;
+                    hex.push_str(&format!("{:02x}", (bits >> nbits) & 0xff));
+                    bits &= (1 << nbits) - 1;
+                }
+            }
+            Some(hex)
+        }
+        _ => None,
+    }
+}
+
+/// Turn user input into something webtorrent can open
+///
+/// Magnet links pass through, a bare info hash (40 hex or 32 base32
+/// characters) becomes a magnet link with the hex hash, and a `.torrent` path is returned as-is
+/// once it's known to exist.
+pub fn normalize_magnet(input: &str) -> anyhow::Result<String> {
+    let input = input.trim();
+    if input.starts_with("magnet:?") {
+        return Ok(input.to_string());
+    }
+
+    if let Some(hash) = normalize_info_hash(input) {
+        return Ok(format!("magnet:?xt=urn:btih:{}", hash));
+    }
+
+    if input.to_ascii_lowercase().ends_with(".torrent") {
+        let path = PathBuf::from(input);
+        if !path.is_file() {
+            anyhow::bail!("Torrent file not found: {}", input);
+        }
+        return Ok(input.to_string());
+    }
+
+    anyhow::bail!("Expected a magnet link, an info hash or a .torrent file")
+}
+
+/// Roughly what a player reads ahead before the first frame shows
+pub const INITIAL_BUFFER_BYTES: u64 = 20 * 1024 * 1024;
+
 /// Active torrent streaming session
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TorrentSession {
@@ -413,6 +1064,26 @@ impl TorrentSession {
         }
     }
 
+    /// Bytes to download before playback starts: [`INITIAL_BUFFER_BYTES`],
+    /// or the whole file if it's smaller
+    pub fn buffer_target(&self) -> u64 {
+        match self.total_size {
+            0 => INITIAL_BUFFER_BYTES,
+            size => size.min(INITIAL_BUFFER_BYTES),
+        }
+    }
+
+    /// Time until `buffer_bytes` are downloaded at the current speed
+    ///
+    /// None while nothing is coming in (speed zero).
+    pub fn eta_to_buffer(&self, buffer_bytes: u64) -> Option<Duration> {
+        if self.download_speed == 0 {
+            return None;
+        }
+        let remaining = buffer_bytes.saturating_sub(self.downloaded);
+        Some(Duration::from_secs(remaining.div_ceil(self.download_speed)))
+    }
+
     /// Generate stream URL for casting
     pub fn generate_stream_url(lan_ip: IpAddr, port: u16, file_idx: u32) -> String {
         format!("http://{}:{}/{}", lan_ip, port, file_idx)
@@ -479,6 +1150,62 @@ impl TorrentSession {
         }
         0
     }
+
+    /// Extract (progress, speed, peers) from the webtorrent log
+    ///
+    /// Uses the most recent "Speed:", "Downloaded: X/Y" and "Peers: N/M" fields.
+    /// Returns None until webtorrent has printed any stats.
+    pub fn parse_log_progress(log: &str) -> Option<(f32, u64, u32)> {
+        let mut progress = None;
+        let mut speed = None;
+        let mut peers = None;
+
+        for line in log.lines().rev() {
+            if speed.is_none() {
+                if let Some(pos) = line.find("Speed:") {
+                    speed = Some(Self::parse_speed(&line[pos..]));
+                }
+            }
+            if progress.is_none() {
+                if let Some(pos) = line.find("Downloaded:") {
+                    // "Downloaded: 161 MB/8.7 GB" - total follows the slash
+                    if let Some((done, total)) = line[pos..].split_once('/') {
+                        let total_bytes = StreamSource::parse_size(total).unwrap_or(0);
+                        progress = Some(Self::parse_progress(done, total_bytes).min(1.0));
+                    }
+                }
+            }
+            if peers.is_none() {
+                if let Some(pos) = line.find("Peers:") {
+                    peers = line[pos + 6..]
+                        .trim_start()
+                        .split(|c: char| !c.is_ascii_digit())
+                        .next()
+                        .and_then(|n| n.parse().ok());
+                }
+            }
+            if progress.is_some() && speed.is_some() && peers.is_some() {
+                break;
+            }
+        }
+
+        if progress.is_none() && speed.is_none() && peers.is_none() {
+            return None;
+        }
+        Some((
+            progress.unwrap_or(0.0),
+            speed.unwrap_or(0),
+            peers.unwrap_or(0),
+        ))
+    }
+
+    /// URL of webtorrent's HTTP server, once it prints "Server running at: <url>"
+    pub fn parse_server_url(log: &str) -> Option<String> {
+        log.lines().find_map(|line| {
+            let (_, rest) = line.split_once("Server running at:")?;
+            rest.split_whitespace().next().map(str::to_string)
+        })
+    }
 }
 
 impl fmt::Display for TorrentSession {
@@ -497,6 +1224,12 @@ impl fmt::Display for TorrentSession {
 // Cast Models (Chromecast)
 // =============================================================================
 
+/// Model shown for devices added by IP (`--device-ip` or `a` in the device modal)
+pub const MANUAL_MODEL: &str = "Manual";
+
+/// How long a device gets to accept a connection before casting to it
+pub const REACH_TIMEOUT: Duration = Duration::from_secs(3);
+
 /// Chromecast device discovered on the network
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CastDevice {
@@ -508,44 +1241,144 @@ pub struct CastDevice {
 }
 
 impl CastDevice {
+    /// Device added by address instead of found by `catt scan`
+    ///
+    /// Named after its IP so `catt -d <name>` reaches it directly.
+    pub fn manual(address: IpAddr) -> Self {
+        CastDevice {
+            id: address.to_string(),
+            name: address.to_string(),
+            address,
+            port: 8009,
+            model: Some(MANUAL_MODEL.to_string()),
+        }
+    }
+
+    /// Whether this device was added by address
+    pub fn is_manual(&self) -> bool {
+        self.model.as_deref() == Some(MANUAL_MODEL)
+    }
+
+    /// Whether this is a speaker group ("Google Inc. Google Cast Group")
+    ///
+    /// catt reaches a group by its name like any other device.
+    pub fn is_group(&self) -> bool {
+        self.model
+            .as_deref()
+            .is_some_and(|m| m.contains("Cast Group"))
+    }
+
+    /// Whether the device accepts a connection on its cast port
+    ///
+    /// Saved devices that missed the last scan have no address to try and
+    /// count as reachable; catt gets to look for them by name.
+    pub async fn is_reachable(&self) -> bool {
+        if self.address.is_unspecified() {
+            return true;
+        }
+        let connect = tokio::net::TcpStream::connect((self.address, self.port));
+        matches!(
+            tokio::time::timeout(REACH_TIMEOUT, connect).await,
+            Ok(Ok(_))
+        )
+    }
+
     /// Parse devices from catt scan output
     /// Format: "192.168.1.36 - Device Name - Google Inc. Chromecast"
+    ///
+    /// Other catt versions print "Name - IP:port" or put the address in
+    /// brackets ("Name [IP]"); those are recognized too, and lines that don't
+    /// hold an address are skipped.
     pub fn parse_catt_scan(output: &str) -> Vec<CastDevice> {
+        let address_re =
+            regex::Regex::new(r"[\[(]?(\d{1,3}(?:\.\d{1,3}){3})(?::(\d{1,5}))?[\])]?").ok();
         let mut devices = Vec::new();
 
         for line in output.lines() {
             let line = line.trim();
-            if line.is_empty()
-                || line.starts_with("Scanning")
-                || line.contains("No devices")
-            {
+            if line.is_empty() || line.starts_with("Scanning") || line.contains("No devices") {
                 continue;
             }
 
-            // Parse "IP - Name - Model" format (catt 0.13+ output)
-            let parts: Vec<&str> = line.splitn(3, " - ").collect();
+            let parts: Vec<&str> = line.splitn(3, " - ").map(str::trim).collect();
+            let model = parts.get(2).map(|m| m.to_string());
             if parts.len() >= 2 {
-                let ip_str = parts[0].trim();
-                if let Ok(addr) = ip_str.parse::<IpAddr>() {
-                    let name = parts[1].trim().to_string();
-                    let model = if parts.len() >= 3 {
-                        Some(parts[2].trim().to_string())
-                    } else {
-                        None
-                    };
-                    devices.push(CastDevice {
-                        id: ip_str.to_string(),
-                        name,
-                        address: addr,
-                        port: 8009, // Default Chromecast port
-                        model,
-                    });
+                // "IP - Name - Model" (catt 0.13+ output)
+                if let Some((address, port)) = parse_host(parts[0]) {
+                    devices.push(CastDevice::scanned(address, port, parts[1], model));
+                    continue;
+                }
+                // "Name - IP:port"
+                if let Some((address, port)) = parse_host(parts[1]) {
+                    devices.push(CastDevice::scanned(address, port, parts[0], model));
+                    continue;
                 }
             }
+
+            // Anything else holding an IPv4 address, named by the rest of the line
+            let Some(caps) = address_re.as_ref().and_then(|re| re.captures(line)) else {
+                continue;
+            };
+            let Ok(address) = caps[1].parse::<IpAddr>() else {
+                continue;
+            };
+            let port = caps.get(2).and_then(|p| p.as_str().parse().ok());
+            let whole = caps.get(0).unwrap();
+            let name = format!("{} {}", &line[..whole.start()], &line[whole.end()..]);
+            let name = name.trim_matches(|c: char| c.is_whitespace() || "-:|,".contains(c));
+            devices.push(CastDevice::scanned(address, port, name, None));
         }
 
         devices
     }
+
+    /// Device from a scan line; unnamed devices go by their address
+    ///
+    /// A group answers from one of its members' addresses, so its id also
+    /// carries the name to keep it apart from that device.
+    fn scanned(address: IpAddr, port: Option<u16>, name: &str, model: Option<String>) -> Self {
+        let name = if name.is_empty() {
+            address.to_string()
+        } else {
+            name.to_string()
+        };
+        let mut device = CastDevice {
+            id: address.to_string(),
+            name,
+            address,
+            port: port.unwrap_or(8009), // Default Chromecast port
+            model,
+        };
+        if device.is_group() {
+            device.id = format!("{}/{}", address, device.name);
+        }
+        device
+    }
+
+    /// Add the devices from another scan, skipping ones already found
+    ///
+    /// catt names devices by IP in `id`, so a device answering several
+    /// scans is listed once, with the details from its first answer.
+    pub fn merge_scan(devices: &mut Vec<CastDevice>, scan: Vec<CastDevice>) {
+        for device in scan {
+            if !devices.iter().any(|d| d.id == device.id) {
+                devices.push(device);
+            }
+        }
+    }
+}
+
+/// Address and optional port from "IP", "IP:port" or a bracketed form of either
+fn parse_host(text: &str) -> Option<(IpAddr, Option<u16>)> {
+    let text = text.trim_matches(|c| matches!(c, '(' | ')'));
+    if let Ok(address) = text
+        .trim_matches(|c| matches!(c, '[' | ']'))
+        .parse::<IpAddr>()
+    {
+        return Some((address, None));
+    }
+    let socket = text.parse::<std::net::SocketAddr>().ok()?;
+    Some((socket.ip(), Some(socket.port())))
 }
 
 impl fmt::Display for CastDevice {
@@ -581,6 +1414,19 @@ impl CastState {
             _ => CastState::Idle,
         }
     }
+
+    /// Whether media is loaded on the device (playing, paused or buffering)
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            CastState::Playing | CastState::Paused | CastState::Buffering
+        )
+    }
+
+    /// Whether the device has nothing playing (media ended or was stopped)
+    pub fn is_finished(&self) -> bool {
+        matches!(self, CastState::Idle | CastState::Stopped)
+    }
 }
 
 impl fmt::Display for CastState {
@@ -746,6 +1592,111 @@ impl fmt::Display for SubFormat {
     }
 }
 
+/// English names for ISO 639-1 and 639-2 (B and T) language codes
+///
+/// Also covers the OpenSubtitles-specific codes Stremio passes through
+/// (`pob` for Brazilian Portuguese, `zht`/`zhe` for Chinese variants).
+const LANGUAGE_NAMES: &[(&str, &[&str])] = &[
+    ("Afrikaans", &["af", "afr"]),
+    ("Albanian", &["sq", "alb", "sqi"]),
+    ("Amharic", &["am", "amh"]),
+    ("Arabic", &["ar", "ara"]),
+    ("Armenian", &["hy", "arm", "hye"]),
+    ("Azerbaijani", &["az", "aze"]),
+    ("Basque", &["eu", "baq", "eus"]),
+    ("Belarusian", &["be", "bel"]),
+    ("Bengali", &["bn", "ben"]),
+    ("Bosnian", &["bs", "bos"]),
+    ("Breton", &["br", "bre"]),
+    ("Bulgarian", &["bg", "bul"]),
+    ("Burmese", &["my", "bur", "mya"]),
+    ("Catalan", &["ca", "cat"]),
+    ("Chinese", &["zh", "chi", "zho", "zht", "zhe"]),
+    ("Croatian", &["hr", "hrv"]),
+    ("Czech", &["cs", "cze", "ces"]),
+    ("Danish", &["da", "dan"]),
+    ("Dutch", &["nl", "dut", "nld"]),
+    ("English", &["en", "eng"]),
+    ("Esperanto", &["eo", "epo"]),
+    ("Estonian", &["et", "est"]),
+    ("Finnish", &["fi", "fin"]),
+    ("French", &["fr", "fre", "fra"]),
+    ("Galician", &["gl", "glg"]),
+    ("Georgian", &["ka", "geo", "kat"]),
+    ("German", &["de", "ger", "deu"]),
+    ("Greek", &["el", "gre", "ell"]),
+    ("Hebrew", &["he", "heb"]),
+    ("Hindi", &["hi", "hin"]),
+    ("Hungarian", &["hu", "hun"]),
+    ("Icelandic", &["is", "ice", "isl"]),
+    ("Indonesian", &["id", "ind"]),
+    ("Irish", &["ga", "gle"]),
+    ("Italian", &["it", "ita"]),
+    ("Japanese", &["ja", "jpn"]),
+    ("Kannada", &["kn", "kan"]),
+    ("Kazakh", &["kk", "kaz"]),
+    ("Khmer", &["km", "khm"]),
+    ("Korean", &["ko", "kor"]),
+    ("Kurdish", &["ku", "kur"]),
+    ("Latvian", &["lv", "lav"]),
+    ("Lithuanian", &["lt", "lit"]),
+    ("Luxembourgish", &["lb", "ltz"]),
+    ("Macedonian", &["mk", "mac", "mkd"]),
+    ("Malay", &["ms", "may", "msa"]),
+    ("Malayalam", &["ml", "mal"]),
+    ("Marathi", &["mr", "mar"]),
+    ("Mongolian", &["mn", "mon"]),
+    ("Nepali", &["ne", "nep"]),
+    ("Norwegian", &["no", "nor", "nb", "nob", "nn", "nno"]),
+    ("Occitan", &["oc", "oci"]),
+    ("Persian", &["fa", "per", "fas"]),
+    ("Polish", &["pl", "pol"]),
+    ("Portuguese", &["pt", "por", "pob", "pb", "pm"]),
+    ("Punjabi", &["pa", "pan"]),
+    ("Romanian", &["ro", "rum", "ron"]),
+    ("Russian", &["ru", "rus"]),
+    ("Serbian", &["sr", "srp", "scc"]),
+    ("Sinhala", &["si", "sin"]),
+    ("Slovak", &["sk", "slo", "slk"]),
+    ("Slovenian", &["sl", "slv"]),
+    ("Somali", &["so", "som"]),
+    ("Spanish", &["es", "spa", "spn", "ea"]),
+    ("Swahili", &["sw", "swa"]),
+    ("Swedish", &["sv", "swe"]),
+    ("Tagalog", &["tl", "tgl", "fil"]),
+    ("Tamil", &["ta", "tam"]),
+    ("Telugu", &["te", "tel"]),
+    ("Thai", &["th", "tha"]),
+    ("Turkish", &["tr", "tur"]),
+    ("Ukrainian", &["uk", "ukr"]),
+    ("Urdu", &["ur", "urd"]),
+    ("Uzbek", &["uz", "uzb"]),
+    ("Vietnamese", &["vi", "vie"]),
+    ("Welsh", &["cy", "wel", "cym"]),
+];
+
+/// English name for a language code, e.g. "eng", "fr", "pt-BR"
+///
+/// Region suffixes are ignored. Returns None for codes not in the table.
+pub fn language_name(code: &str) -> Option<&'static str> {
+    let code = code.trim().to_lowercase();
+    let base = code.split(['-', '_']).next().unwrap_or_default();
+    LANGUAGE_NAMES
+        .iter()
+        .find(|(_, codes)| codes.contains(&base))
+        .map(|(name, _)| *name)
+}
+
+/// Two-letter ISO 639-1 code for a language code, e.g. "fre" -> "fr"
+pub fn language_alpha2(code: &str) -> Option<&'static str> {
+    let name = language_name(code)?;
+    LANGUAGE_NAMES
+        .iter()
+        .find(|(n, _)| *n == name)
+        .and_then(|(_, codes)| codes.first().copied())
+        .filter(|code| code.len() == 2)
+}
+
 /// Subtitle search result from Stremio
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubtitleResult {
@@ -764,6 +1715,44 @@ pub struct SubtitleResult {
 }
 
 impl SubtitleResult {
+    /// Build a subtitle entry for a file already on disk (`file://` URL)
+    pub fn local_file(path: &std::path::Path) -> Self {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let format = path
+            .extension()
+            .map(|e| SubFormat::from_extension(&e.to_string_lossy()))
+            .unwrap_or(SubFormat::Srt);
+
+        Self {
+            id: format!("local:{}", path.display()),
+            url: format!("file://{}", path.display()),
+            language: "local".to_string(),
+            language_name: "Local file".to_string(),
+            release: name,
+            fps: None,
+            format,
+            downloads: 0,
+            from_trusted: true,
+            hearing_impaired: false,
+            ai_translated: false,
+        }
+    }
+
+    /// Fill in `language_name` from the code if the source left it out
+    ///
+    /// Unknown codes are shown uppercased rather than as a bare "eng".
+    pub fn fill_language_name(&mut self) {
+        if self.language_name.is_empty() || self.language_name.eq_ignore_ascii_case(&self.language)
+        {
+            self.language_name = language_name(&self.language)
+                .map(str::to_string)
+                .unwrap_or_else(|| self.language.to_uppercase());
+        }
+    }
+
     /// Trust score for sorting (higher = better)
     pub fn trust_score(&self) -> u32 {
         let mut score = self.downloads;
@@ -828,12 +1817,180 @@ impl SubtitleFile {
         webvtt
     }
 
+    /// Convert ASS/SSA content to WebVTT format (required for Chromecast)
+    ///
+    /// Only `Dialogue:` lines from the `[Events]` section are kept; script
+    /// info, styles and `{\...}` override tags are dropped.
+    pub fn ass_to_webvtt(ass: &str) -> String {
+        let mut webvtt = String::from("WEBVTT\n\n");
+
+        // Field layout from the `Format:` line, defaulting to the ASS v4+ order
+        let mut start_idx = 1;
+        let mut end_idx = 2;
+        let mut field_count = 10;
+        let mut in_events = false;
+
+        for line in ass.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_events = line.eq_ignore_ascii_case("[events]");
+                continue;
+            }
+            if !in_events {
+                continue;
+            }
+
+            if let Some(format) = line.strip_prefix("Format:") {
+                let fields: Vec<String> =
+                    format.split(',').map(|f| f.trim().to_lowercase()).collect();
+                start_idx = fields.iter().position(|f| f == "start").unwrap_or(1);
+                end_idx = fields.iter().position(|f| f == "end").unwrap_or(2);
+                field_count = fields.len();
+                continue;
+            }
+
+            let Some(dialogue) = line.strip_prefix("Dialogue:") else {
+                continue;
+            };
+            // Text is always the last field and may itself contain commas
+            let fields: Vec<&str> = dialogue.trim_start().splitn(field_count, ',').collect();
+            if fields.len() < field_count {
+                continue;
+            }
+            let (Some(start), Some(end)) = (
+                ass_timestamp_to_webvtt(fields[start_idx]),
+                ass_timestamp_to_webvtt(fields[end_idx]),
+            ) else {
+                continue;
+            };
+
+            let text = strip_ass_tags(fields[field_count - 1]);
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            webvtt.push_str(&format!("{} --> {}\n{}\n\n", start, end, text));
+        }
+
+        webvtt
+    }
+
+    /// Shift every WebVTT cue by `offset_ms` (negative = earlier)
+    ///
+    /// Cues that would start before zero are clamped to `00:00:00.000`.
+    pub fn shift_webvtt(vtt: &str, offset_ms: i64) -> String {
+        let mut shifted = String::with_capacity(vtt.len());
+
+        for line in vtt.lines() {
+            match line.split_once(" --> ") {
+                Some((start, rest)) => {
+                    // Cue settings may follow the end timestamp ("... align:start")
+                    let (end, settings) = match rest.split_once(' ') {
+                        Some((end, settings)) => (end, Some(settings)),
+                        None => (rest, None),
+                    };
+                    match (parse_webvtt_timestamp(start), parse_webvtt_timestamp(end)) {
+                        (Some(start), Some(end)) => {
+                            shifted.push_str(&format_webvtt_timestamp(start + offset_ms));
+                            shifted.push_str(" --> ");
+                            shifted.push_str(&format_webvtt_timestamp(end + offset_ms));
+                            if let Some(settings) = settings {
+                                shifted.push(' ');
+                                shifted.push_str(settings);
+                            }
+                        }
+                        _ => shifted.push_str(line),
+                    }
+                }
+                None => shifted.push_str(line),
+            }
+            shifted.push('\n');
+        }
+
+        shifted
+    }
+
     /// Generate URL for serving this subtitle file
     pub fn generate_url(lan_ip: IpAddr, port: u16, language: &str) -> String {
         format!("http://{}:{}/subtitles/{}.vtt", lan_ip, port, language)
     }
 }
 
+/// One timed line of dialogue, as shown in the subtitle preview
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cue {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+}
+
+impl Cue {
+    /// Parse up to `limit` cues from SRT or WebVTT text
+    ///
+    /// Both formats share the `start --> end` timing line, so cue numbers,
+    /// the `WEBVTT` header and NOTE blocks are simply skipped.
+    pub fn parse(content: &str, limit: usize) -> Vec<Cue> {
+        let mut cues = Vec::new();
+        let mut lines = content.lines();
+
+        while cues.len() < limit {
+            let Some(line) = lines.next() else { break };
+            let Some((start, rest)) = line.split_once(" --> ") else {
+                continue;
+            };
+            let end = rest.split_whitespace().next().unwrap_or(rest);
+            let (Some(start_ms), Some(end_ms)) = (
+                parse_webvtt_timestamp(&start.replace(',', ".")),
+                parse_webvtt_timestamp(&end.replace(',', ".")),
+            ) else {
+                continue;
+            };
+
+            let text: Vec<String> = lines
+                .by_ref()
+                .take_while(|l| !l.trim().is_empty())
+                .map(strip_markup)
+                .collect();
+            let text = text.join("\n");
+            if !text.trim().is_empty() {
+                cues.push(Cue {
+                    start_ms,
+                    end_ms,
+                    text,
+                });
+            }
+        }
+
+        cues
+    }
+
+    /// Start time as `HH:MM:SS`
+    pub fn timestamp(&self) -> String {
+        let secs = self.start_ms.max(0) / 1000;
+        format!(
+            "{:02}:{:02}:{:02}",
+            secs / 3600,
+            (secs / 60) % 60,
+            secs % 60
+        )
+    }
+}
+
+/// Drop `<i>`/`<font ...>`-style tags from a cue line
+fn strip_markup(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for c in line.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out.trim().to_string()
+}
+
 impl fmt::Display for SubtitleFile {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -845,12 +2002,74 @@ impl fmt::Display for SubtitleFile {
     }
 }
 
+/// Convert an ASS timestamp (`H:MM:SS.cc`) to WebVTT (`HH:MM:SS.mmm`)
+fn ass_timestamp_to_webvtt(ts: &str) -> Option<String> {
+    let mut parts = ts.trim().split(':');
+    let hours: u32 = parts.next()?.parse().ok()?;
+    let minutes: u32 = parts.next()?.parse().ok()?;
+    let (secs, centis) = parts.next()?.split_once('.')?;
+    let secs: u32 = secs.parse().ok()?;
+    let centis: u32 = centis.parse().ok()?;
+    Some(format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        hours,
+        minutes,
+        secs,
+        centis * 10
+    ))
+}
+
+/// Parse a WebVTT timestamp (`HH:MM:SS.mmm` or `MM:SS.mmm`) into milliseconds
+fn parse_webvtt_timestamp(ts: &str) -> Option<i64> {
+    let (clock, millis) = ts.trim().split_once('.')?;
+    let millis: i64 = millis.parse().ok()?;
+    let parts: Vec<i64> = clock
+        .split(':')
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    let secs = match parts.as_slice() {
+        [h, m, s] => h * 3600 + m * 60 + s,
+        [m, s] => m * 60 + s,
+        _ => return None,
+    };
+    Some(secs * 1000 + millis)
+}
+
+/// Format milliseconds as a WebVTT timestamp, clamping negatives to zero
+fn format_webvtt_timestamp(ms: i64) -> String {
+    let ms = ms.max(0);
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        (ms / 60_000) % 60,
+        (ms / 1000) % 60,
+        ms % 1000
+    )
+}
+
+/// Remove `{...}` override blocks and translate ASS line breaks
+fn strip_ass_tags(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut depth = 0;
+    for c in text.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            _ if depth == 0 => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("\\N", "\n")
+        .replace("\\n", "\n")
+        .replace("\\h", " ")
+}
+
 // =============================================================================
 // Utility Functions
 // =============================================================================
 
 /// Format a Duration as HH:MM:SS or MM:SS
-fn format_duration(d: Duration) -> String {
+pub fn format_duration(d: Duration) -> String {
     let total_secs = d.as_secs();
     let hours = total_secs / 3600;
     let mins = (total_secs % 3600) / 60;
@@ -1031,38 +2250,239 @@ mod tests {
         assert!(StreamSource::parse_size("No size here").is_none());
     }
 
+    #[test]
+    fn test_is_pack() {
+        let source = |title: &str| StreamSource {
+            name: "Torrentio".to_string(),
+            provider: "Torrentio".to_string(),
+            title: title.to_string(),
+            info_hash: "abc".to_string(),
+            file_idx: Some(2),
+            seeds: 10,
+            quality: Quality::FHD1080p,
+            size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
+        };
+
+        let pack = source("Breaking.Bad.Complete.Season.1.1080p\nBreaking.Bad.S01E03.mkv\n👤 50");
+        assert!(pack.is_pack());
+        assert_eq!(pack.pack_file_name(), Some("Breaking.Bad.S01E03.mkv"));
+        assert!(source("Show.S02.1080p.WEB-DL").is_pack());
+        assert!(source("Show Season 3 720p").is_pack());
+        assert!(source("Show.S01E01-E10.1080p").is_pack());
+
+        let single = source("Breaking.Bad.S01E03.1080p.BluRay\n👤 142 💾 1.2 GB");
+        assert!(!single.is_pack());
+        assert_eq!(single.pack_file_name(), None);
+        assert!(!source("The.Matrix.1999.1080p").is_pack());
+    }
+
     #[test]
     fn test_magnet_generation() {
         let source = StreamSource {
             name: "Test".to_string(),
+            provider: "Test".to_string(),
             title: "Test Title".to_string(),
             info_hash: "abc123def456".to_string(),
             file_idx: Some(0),
             seeds: 100,
             quality: Quality::FHD1080p,
             size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         };
 
         let magnet = source.to_magnet("Movie Name");
-        assert_eq!(magnet, "magnet:?xt=urn:btih:abc123def456&dn=Movie%20Name");
+        assert!(magnet.starts_with("magnet:?xt=urn:btih:abc123def456&dn=Movie%20Name&tr="));
+    }
+
+    #[test]
+    fn test_magnet_trackers() {
+        let source = scored_source("abc123", Quality::FHD1080p, 1, 1.0);
+
+        // Built-in trackers, URL-encoded
+        let magnet = source.to_magnet("Movie");
+        assert_eq!(magnet.matches("&tr=").count(), DEFAULT_TRACKERS.len());
+        assert!(magnet.contains("&tr=udp%3A%2F%2Ftracker.opentrackr.org%3A1337%2Fannounce"));
+
+        let custom = vec!["udp://tracker.example.org:6969/announce?key=a&b".to_string()];
+        assert_eq!(
+            source.to_magnet_with_trackers("Movie", &custom),
+            "magnet:?xt=urn:btih:abc123&dn=Movie&tr=udp%3A%2F%2Ftracker.example.org%3A6969%2Fannounce%3Fkey%3Da%26b"
+        );
+
+        // An empty list leaves peer discovery to the DHT
+        let none: &[String] = &[];
+        assert_eq!(
+            source.to_magnet_with_trackers("Movie", none),
+            "magnet:?xt=urn:btih:abc123&dn=Movie"
+        );
     }
 
     #[test]
     fn test_magnet_url_encoding() {
         let source = StreamSource {
             name: "Test".to_string(),
+            provider: "Test".to_string(),
             title: "Test".to_string(),
             info_hash: "abc123".to_string(),
             file_idx: None,
             seeds: 0,
             quality: Quality::Unknown,
             size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         };
 
         let magnet = source.to_magnet("Test & Movie (2022)");
         assert!(magnet.contains("Test%20%26%20Movie%20%282022%29"));
     }
 
+    #[test]
+    fn test_normalized_hash() {
+        let mut source = scored_source(
+            "BCW2LJ5GDA5K4HQJ3AY56Z2I2VTASWQQ",
+            Quality::FHD1080p,
+            1,
+            1.0,
+        );
+        let hex = "08ada5a7a6183aae1e09d831df6748d566095a10";
+        assert_eq!(source.normalized_hash().as_deref(), Some(hex));
+        assert!(source
+            .to_magnet("Movie")
+            .starts_with(&format!("magnet:?xt=urn:btih:{}&", hex)));
+
+        source.info_hash = hex.to_uppercase();
+        assert_eq!(source.normalized_hash().as_deref(), Some(hex));
+
+        // Wrong length, or characters outside hex/base32
+        for bad in [
+            "abc123",
+            "08ada5a7a6183aae1e09d831df6748d566095a1z",
+            "BCW2LJ5GDA5K4HQJ3AY56Z2I2VTASWQ1",
+        ] {
+            source.info_hash = bad.to_string();
+            assert_eq!(source.normalized_hash(), None, "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_normalize_magnet_links_and_hashes() {
+        let magnet = "magnet:?xt=urn:btih:abc&dn=Movie";
+        assert_eq!(normalize_magnet(magnet).unwrap(), magnet);
+        assert_eq!(
+            normalize_magnet(&format!("  {}\n", magnet)).unwrap(),
+            magnet
+        );
+
+        let hex = "08ADA5A7A6183AAE1E09D831DF6748D566095A10";
+        assert_eq!(
+            normalize_magnet(hex).unwrap(),
+            "magnet:?xt=urn:btih:08ada5a7a6183aae1e09d831df6748d566095a10"
+        );
+
+        let base32 = "bcw2lj5gda5k4hqj3ay56z2i2vtaswqq";
+        assert_eq!(
+            normalize_magnet(base32).unwrap(),
+            "magnet:?xt=urn:btih:08ada5a7a6183aae1e09d831df6748d566095a10"
+        );
+
+        // Wrong length, or not hex/base32
+        assert!(normalize_magnet("08ada5a7a6183aae1e09d831df6748d566095a1").is_err());
+        assert!(normalize_magnet("bc2wlj5gdanvmhqj3ay56z2irvtaswq1").is_err());
+        assert!(normalize_magnet("the matrix").is_err());
+    }
+
+    #[test]
+    fn test_normalize_magnet_torrent_files() {
+        let path = std::env::temp_dir().join(format!("streamtui-{}.torrent", std::process::id()));
+        std::fs::write(&path, b"d4:infod4:name4:testee").unwrap();
+        let input = path.to_string_lossy().to_string();
+        assert_eq!(normalize_magnet(&input).unwrap(), input);
+        std::fs::remove_file(&path).unwrap();
+
+        let err = normalize_magnet(&input).unwrap_err();
+        assert!(err.to_string().starts_with("Torrent file not found"));
+    }
+
+    // -------------------------------------------------------------------------
+    // Stream Scoring Tests
+    // -------------------------------------------------------------------------
+
+    fn scored_source(hash: &str, quality: Quality, seeds: u32, gb: f64) -> StreamSource {
+        StreamSource {
+            name: hash.to_string(),
+            provider: String::new(),
+            title: hash.to_string(),
+            info_hash: hash.to_string(),
+            file_idx: None,
+            seeds,
+            quality,
+            size_bytes: Some((gb * 1024.0 * 1024.0 * 1024.0) as u64),
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
+        }
+    }
+
+    #[test]
+    fn test_score_balances_quality_and_seeds() {
+        let streams = vec![
+            scored_source("uhd-dead", Quality::UHD4K, 3, 40.0),
+            scored_source("fhd-healthy", Quality::FHD1080p, 2000, 8.0),
+            scored_source("sd-popular", Quality::SD480p, 9000, 0.7),
+        ];
+
+        // Defaults: a well-seeded 1080p beats a nearly dead 4K
+        let prefs = SelectionPrefs::default();
+        assert_eq!(prefs.best_index(&streams), Some(1));
+
+        // Quality-first picks the 4K anyway
+        let quality_first = SelectionPrefs {
+            quality_weight: 10,
+            seeds_weight: 1,
+            ..Default::default()
+        };
+        assert_eq!(quality_first.best_index(&streams), Some(0));
+
+        // Seeds-only picks the most seeded
+        let seeds_only = SelectionPrefs {
+            quality_weight: 0,
+            seeds_weight: 1,
+            size_weight: 0,
+            size_range: None,
+        };
+        assert_eq!(seeds_only.best_index(&streams), Some(2));
+    }
+
+    #[test]
+    fn test_score_size_range_and_ties() {
+        const GB: u64 = 1024 * 1024 * 1024;
+        let streams = vec![
+            scored_source("huge", Quality::FHD1080p, 500, 30.0),
+            scored_source("right-size", Quality::FHD1080p, 500, 4.0),
+        ];
+
+        // Identical scores resolve to the earlier stream
+        assert_eq!(SelectionPrefs::default().best_index(&streams), Some(0));
+
+        let prefs = SelectionPrefs {
+            size_range: Some((2 * GB, 10 * GB)),
+            ..Default::default()
+        };
+        assert!(streams[1].score(&prefs) > streams[0].score(&prefs));
+        assert_eq!(prefs.best_index(&streams), Some(1));
+        assert_eq!(prefs.best_index(&[]), None);
+    }
+
     // -------------------------------------------------------------------------
     // TorrentSession Tests
     // -------------------------------------------------------------------------
@@ -1075,6 +2495,38 @@ mod tests {
         assert!(session.stream_url.is_none());
     }
 
+    #[test]
+    fn test_eta_to_buffer() {
+        const MB: u64 = 1024 * 1024;
+        let mut session = TorrentSession::new("magnet:?xt=urn:btih:abc".to_string(), None);
+        assert_eq!(session.eta_to_buffer(20 * MB), None);
+
+        session.download_speed = 2 * MB;
+        assert_eq!(
+            session.eta_to_buffer(20 * MB),
+            Some(Duration::from_secs(10))
+        );
+        session.downloaded = 15 * MB;
+        assert_eq!(session.eta_to_buffer(20 * MB), Some(Duration::from_secs(3)));
+        session.downloaded = 25 * MB;
+        assert_eq!(session.eta_to_buffer(20 * MB), Some(Duration::ZERO));
+
+        // Slow starts on big files
+        session.downloaded = 0;
+        session.download_speed = 150 * 1024;
+        assert_eq!(
+            session.eta_to_buffer(20 * MB),
+            Some(Duration::from_secs(137))
+        );
+
+        // Files smaller than the target only need themselves
+        assert_eq!(session.buffer_target(), INITIAL_BUFFER_BYTES);
+        session.total_size = 8 * MB;
+        assert_eq!(session.buffer_target(), 8 * MB);
+        session.total_size = 4_000 * MB;
+        assert_eq!(session.buffer_target(), INITIAL_BUFFER_BYTES);
+    }
+
     #[test]
     fn test_torrent_state_display() {
         assert_eq!(TorrentState::Starting.to_string(), "Starting...");
@@ -1087,7 +2539,11 @@ mod tests {
             "Connecting (3 peers)"
         );
         assert_eq!(
-            TorrentState::Buffering { peers: 5, progress: 42 }.to_string(),
+            TorrentState::Buffering {
+                peers: 5,
+                progress: 42
+            }
+            .to_string(),
             "Buffering 42% (5 peers)"
         );
         assert_eq!(TorrentState::Streaming.to_string(), "Streaming");
@@ -1102,7 +2558,14 @@ mod tests {
         assert_eq!(TorrentState::Starting.peers(), None);
         assert_eq!(TorrentState::FetchingMetadata { peers: 0 }.peers(), Some(0));
         assert_eq!(TorrentState::Connecting { peers: 5 }.peers(), Some(5));
-        assert_eq!(TorrentState::Buffering { peers: 10, progress: 50 }.peers(), Some(10));
+        assert_eq!(
+            TorrentState::Buffering {
+                peers: 10,
+                progress: 50
+            }
+            .peers(),
+            Some(10)
+        );
         assert_eq!(TorrentState::Streaming.peers(), None);
     }
 
@@ -1111,7 +2574,11 @@ mod tests {
         assert!(TorrentState::Starting.is_connecting());
         assert!(TorrentState::FetchingMetadata { peers: 0 }.is_connecting());
         assert!(TorrentState::Connecting { peers: 3 }.is_connecting());
-        assert!(TorrentState::Buffering { peers: 5, progress: 20 }.is_connecting());
+        assert!(TorrentState::Buffering {
+            peers: 5,
+            progress: 20
+        }
+        .is_connecting());
         assert!(!TorrentState::Streaming.is_connecting());
         assert!(!TorrentState::Stopped.is_connecting());
     }
@@ -1143,6 +2610,35 @@ mod tests {
         assert!(speed_kb > 520_000 && speed_kb < 530_000);
     }
 
+    #[test]
+    fn test_parse_log_progress() {
+        let log = "fetching torrent metadata from 3 peers\n\
+                   Speed: 100 KB/s Downloaded: 10 MB/4 GB Peers: 2/9\n\
+                   Speed: 2.0 MB/s Downloaded: 1.0 GB/4.0 GB Uploaded: 0 B Peers: 7/12\n";
+        let (progress, speed, peers) = TorrentSession::parse_log_progress(log).unwrap();
+        assert!(progress > 0.24 && progress < 0.26);
+        assert_eq!(speed, 2 * 1024 * 1024);
+        assert_eq!(peers, 7);
+
+        // No stats printed yet
+        assert!(TorrentSession::parse_log_progress("fetching torrent metadata").is_none());
+        assert!(TorrentSession::parse_log_progress("").is_none());
+    }
+
+    #[test]
+    fn test_parse_server_url() {
+        let log = "fetching torrent metadata from 3 peers\n\
+                   Server running at: http://localhost:8000/webtorrent/abc/0\n\
+                   Speed: 2.0 MB/s Downloaded: 1.0 GB/4.0 GB Peers: 7/12\n";
+        assert_eq!(
+            TorrentSession::parse_server_url(log).as_deref(),
+            Some("http://localhost:8000/webtorrent/abc/0")
+        );
+        assert!(
+            TorrentSession::parse_server_url("fetching torrent metadata from 0 peers").is_none()
+        );
+    }
+
     // -------------------------------------------------------------------------
     // CastDevice Tests
     // -------------------------------------------------------------------------
@@ -1156,12 +2652,75 @@ mod tests {
         assert_eq!(devices.len(), 2);
         assert_eq!(devices[0].name, "Living Room TV");
         assert_eq!(devices[0].address.to_string(), "192.168.1.50");
-        assert_eq!(devices[0].model, Some("Google Inc. Chromecast Ultra".to_string()));
+        assert_eq!(
+            devices[0].model,
+            Some("Google Inc. Chromecast Ultra".to_string())
+        );
         assert_eq!(devices[1].name, "Bedroom");
         assert_eq!(devices[1].address.to_string(), "192.168.1.51");
         assert_eq!(devices[1].model, Some("Google Inc. Chromecast".to_string()));
     }
 
+    #[test]
+    fn test_merge_scans_dedupes_by_id() {
+        let first = "192.168.1.50 - Living Room TV - Google Inc. Chromecast Ultra\n192.168.1.51 - Bedroom - Google Inc. Chromecast\n";
+        let second = "192.168.1.51 - Bedroom - Google Inc. Chromecast\n192.168.1.52 - Kitchen - Google Inc. Nest Hub\n";
+
+        let mut devices = CastDevice::parse_catt_scan(first);
+        CastDevice::merge_scan(&mut devices, CastDevice::parse_catt_scan(second));
+        CastDevice::merge_scan(&mut devices, CastDevice::parse_catt_scan(first));
+
+        let ids: Vec<&str> = devices.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, ["192.168.1.50", "192.168.1.51", "192.168.1.52"]);
+        assert_eq!(devices[2].name, "Kitchen");
+    }
+
+    #[test]
+    fn test_parse_catt_scan_keeps_groups() {
+        let output = "192.168.1.50 - Living Room TV - Google Inc. Chromecast Ultra\n192.168.1.50 - Whole House - Google Inc. Google Cast Group\n";
+        let mut devices = CastDevice::parse_catt_scan(output);
+        CastDevice::merge_scan(&mut devices, CastDevice::parse_catt_scan(output));
+
+        assert_eq!(devices.len(), 2);
+        assert!(!devices[0].is_group());
+        assert!(devices[1].is_group());
+        assert_eq!(devices[1].name, "Whole House");
+        assert_eq!(devices[1].id, "192.168.1.50/Whole House");
+    }
+
+    #[test]
+    fn test_parse_catt_scan_name_first_with_port() {
+        let output = "Scanning Chromecasts...\nLiving Room TV - 192.168.1.50:8009\nBedroom - 192.168.1.51:8009 - Google Inc. Chromecast\n";
+        let devices = CastDevice::parse_catt_scan(output);
+
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].name, "Living Room TV");
+        assert_eq!(devices[0].id, "192.168.1.50");
+        assert_eq!(devices[0].port, 8009);
+        assert_eq!(devices[0].model, None);
+        assert_eq!(devices[1].name, "Bedroom");
+        assert_eq!(devices[1].model.as_deref(), Some("Google Inc. Chromecast"));
+    }
+
+    #[test]
+    fn test_parse_catt_scan_bracketed_addresses() {
+        let output = "Found 3 devices:\nLiving Room TV [192.168.1.50]\nKitchen - Nest Hub (192.168.1.52:8009)\n[192.168.1.53:8010]\n";
+        let devices = CastDevice::parse_catt_scan(output);
+
+        let found: Vec<(&str, String, u16)> = devices
+            .iter()
+            .map(|d| (d.name.as_str(), d.address.to_string(), d.port))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("Living Room TV", "192.168.1.50".to_string(), 8009),
+                ("Kitchen - Nest Hub", "192.168.1.52".to_string(), 8009),
+                ("192.168.1.53", "192.168.1.53".to_string(), 8010),
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_catt_scan_no_devices() {
         let output = "Scanning...\nNo devices found\n";
@@ -1278,6 +2837,51 @@ mod tests {
     // SubtitleResult Tests
     // -------------------------------------------------------------------------
 
+    #[test]
+    fn test_language_name_common_codes() {
+        assert_eq!(language_name("eng"), Some("English"));
+        assert_eq!(language_name("en"), Some("English"));
+        assert_eq!(language_name("spa"), Some("Spanish"));
+        assert_eq!(language_name("fre"), Some("French"));
+        assert_eq!(language_name("fra"), Some("French"));
+        assert_eq!(language_name("por"), Some("Portuguese"));
+        assert_eq!(language_name("pt-BR"), Some("Portuguese"));
+        assert_eq!(language_name("JPN"), Some("Japanese"));
+        assert_eq!(language_name("xx"), None);
+    }
+
+    #[test]
+    fn test_fill_language_name_only_when_missing() {
+        let mut sub = SubtitleResult {
+            id: "1".to_string(),
+            url: "https://subs.strem.io/1".to_string(),
+            language: "ger".to_string(),
+            language_name: String::new(),
+            release: "Test".to_string(),
+            fps: None,
+            format: SubFormat::Srt,
+            downloads: 0,
+            from_trusted: true,
+            hearing_impaired: false,
+            ai_translated: false,
+        };
+        sub.fill_language_name();
+        assert_eq!(sub.language_name, "German");
+
+        // A bare code is replaced too, a real name is kept
+        sub.language_name = "GER".to_string();
+        sub.fill_language_name();
+        assert_eq!(sub.language_name, "German");
+        let mut local = SubtitleResult::local_file(std::path::Path::new("/tmp/movie.srt"));
+        local.fill_language_name();
+        assert_eq!(local.language_name, "Local file");
+
+        sub.language = "xyz".to_string();
+        sub.language_name = String::new();
+        sub.fill_language_name();
+        assert_eq!(sub.language_name, "XYZ");
+    }
+
     #[test]
     fn test_subtitle_trust_score() {
         let trusted = SubtitleResult {
@@ -1335,6 +2939,73 @@ mod tests {
         assert!(vtt.contains("Hello. world!")); // comma becomes period
     }
 
+    // -------------------------------------------------------------------------
+    // ASS to WebVTT Conversion Tests
+    // -------------------------------------------------------------------------
+
+    const ASS_SAMPLE: &str = "[Script Info]
+Title: Sample
+ScriptType: v4.00+
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour
+Style: Default,Arial,20,&H00FFFFFF
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:01:23.45,0:01:25.00,Default,,0,0,0,,{\\i1}Hello{\\i0}, world
+Dialogue: 0,1:02:03.04,1:02:05.50,Default,,0,0,0,,{\\an8\\c&H00FFFF&}Top line\\Nsecond line
+Comment: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,not shown
+Dialogue: 0,0:00:10.00,0:00:11.00,Default,,0,0,0,,{\\p1}
+";
+
+    #[test]
+    fn test_ass_to_webvtt_converts_dialogue() {
+        let vtt = SubtitleFile::ass_to_webvtt(ASS_SAMPLE);
+        assert!(vtt.starts_with("WEBVTT\n\n"));
+        assert!(vtt.contains("00:01:23.450 --> 00:01:25.000\nHello, world\n"));
+        assert!(vtt.contains("01:02:03.040 --> 01:02:05.500\nTop line\nsecond line\n"));
+    }
+
+    #[test]
+    fn test_ass_to_webvtt_strips_headers_and_tags() {
+        let vtt = SubtitleFile::ass_to_webvtt(ASS_SAMPLE);
+        assert!(!vtt.contains("Script Info"));
+        assert!(!vtt.contains("Arial"));
+        assert!(!vtt.contains("not shown"));
+        assert!(!vtt.contains('{'));
+        assert!(!vtt.contains("\\N"));
+        // Drawing-only line leaves no empty cue behind
+        assert!(!vtt.contains("00:00:10.000"));
+    }
+
+    // -------------------------------------------------------------------------
+    // WebVTT Offset Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_shift_webvtt_positive_offset() {
+        let vtt = "WEBVTT\n\n00:00:01.000 --> 00:00:02.500\nHello\n\n00:59:59.800 --> 01:00:00.000 align:start\nBye\n";
+        let shifted = SubtitleFile::shift_webvtt(vtt, 1500);
+        assert!(shifted.starts_with("WEBVTT\n"));
+        assert!(shifted.contains("00:00:02.500 --> 00:00:04.000\nHello"));
+        assert!(shifted.contains("01:00:01.300 --> 01:00:01.500 align:start\nBye"));
+    }
+
+    #[test]
+    fn test_shift_webvtt_negative_offset_clamps_at_zero() {
+        let vtt =
+            "WEBVTT\n\n00:00:00.300 --> 00:00:01.200\nFirst\n\n01:05.000 --> 01:06.000\nSecond\n";
+        let shifted = SubtitleFile::shift_webvtt(vtt, -500);
+        assert!(shifted.contains("00:00:00.000 --> 00:00:00.700\nFirst"));
+        assert!(shifted.contains("00:01:04.500 --> 00:01:05.500\nSecond"));
+
+        // A cue that ends before zero collapses onto zero rather than going negative
+        let shifted = SubtitleFile::shift_webvtt(vtt, -2000);
+        assert!(shifted.contains("00:00:00.000 --> 00:00:00.000\nFirst"));
+        assert!(!shifted.contains("-0"));
+    }
+
     #[test]
     fn test_subtitle_url_generation() {
         let url =
@@ -1342,6 +3013,22 @@ mod tests {
         assert_eq!(url, "http://192.168.1.100:8889/subtitles/en.vtt");
     }
 
+    #[test]
+    fn test_cue_parse_srt_and_webvtt() {
+        let srt = "1\n00:00:01,000 --> 00:00:02,500\n<i>Hello</i>\nthere\n\n2\n00:01:05,000 --> 00:01:06,000\nBye\n";
+        let cues = Cue::parse(srt, 15);
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].start_ms, 1000);
+        assert_eq!(cues[0].end_ms, 2500);
+        assert_eq!(cues[0].text, "Hello\nthere");
+        assert_eq!(cues[1].timestamp(), "00:01:05");
+
+        let vtt = "WEBVTT\n\nNOTE made by hand\n\n00:00:03.000 --> 00:00:04.000 align:start\nOne\n\n00:05.000 --> 00:06.000\nTwo\n";
+        let cues = Cue::parse(vtt, 1);
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].text, "One");
+    }
+
     // -------------------------------------------------------------------------
     // Episode Tests
     // -------------------------------------------------------------------------
@@ -1376,6 +3063,8 @@ mod tests {
             vote_average: 7.8,
             poster_path: None,
             backdrop_path: None,
+            collection_id: None,
+            collection_name: None,
         };
         assert_eq!(movie.to_string(), "The Batman (2022) - 2h 56m - ⭐ 7.8");
     }
@@ -1384,6 +3073,89 @@ mod tests {
     // TvDetail Tests
     // -------------------------------------------------------------------------
 
+    #[test]
+    fn test_tv_next_episode_rolls_over_seasons() {
+        let season = |season_number, episode_count| SeasonSummary {
+            season_number,
+            episode_count,
+            name: None,
+            air_date: None,
+        };
+        let tv = TvDetail {
+            id: 1,
+            imdb_id: "tt0000001".to_string(),
+            name: "Show".to_string(),
+            year: 2020,
+            seasons: vec![season(0, 3), season(1, 7), season(2, 0), season(3, 10)],
+            genres: vec![],
+            overview: "".to_string(),
+            vote_average: 0.0,
+            poster_path: None,
+            backdrop_path: None,
+        };
+        assert_eq!(tv.next_episode(1, 3), Some((1, 4)));
+        // Empty (unaired) seasons are skipped
+        assert_eq!(tv.next_episode(1, 7), Some((3, 1)));
+        assert_eq!(tv.next_episode(3, 10), None);
+        assert_eq!(tv.next_episode(5, 1), None);
+    }
+
+    #[test]
+    fn test_pick_trailer() {
+        let video = |key: &str, kind: &str, official| Video {
+            key: key.to_string(),
+            name: format!("{} video", kind),
+            kind: kind.to_string(),
+            official,
+        };
+        let videos = vec![
+            video("clip", "Clip", true),
+            video("fan", "Trailer", false),
+            video("main", "Trailer", true),
+        ];
+        assert_eq!(
+            Video::pick_trailer(&videos).map(|v| v.key.as_str()),
+            Some("main")
+        );
+        assert_eq!(
+            Video::pick_trailer(&videos[..2]).map(|v| v.key.as_str()),
+            Some("fan")
+        );
+        assert_eq!(Video::pick_trailer(&videos[..1]), None);
+        assert_eq!(
+            videos[2].youtube_url(),
+            "https://www.youtube.com/watch?v=main"
+        );
+    }
+
+    #[test]
+    fn test_tv_absolute_episode() {
+        let season = |season_number, episode_count| SeasonSummary {
+            season_number,
+            episode_count,
+            name: None,
+            air_date: None,
+        };
+        let tv = TvDetail {
+            id: 1,
+            imdb_id: "tt0388629".to_string(),
+            name: "One Piece".to_string(),
+            year: 1999,
+            seasons: vec![season(0, 4), season(1, 61), season(2, 16), season(3, 14)],
+            genres: vec![],
+            overview: "".to_string(),
+            vote_average: 0.0,
+            poster_path: None,
+            backdrop_path: None,
+        };
+        // Specials don't count towards the number
+        assert_eq!(tv.absolute_episode(2, 1), Some(62));
+        assert_eq!(tv.absolute_episode(3, 5), Some(61 + 16 + 5));
+        assert_eq!(tv.absolute_episode(1, 5), None);
+        assert_eq!(tv.absolute_episode(0, 2), None);
+        assert_eq!(tv.absolute_episode(9, 1), None);
+    }
+
     #[test]
     fn test_tv_detail_display() {
         let tv = TvDetail {
@@ -1413,4 +3185,15 @@ mod tests {
         };
         assert_eq!(tv.to_string(), "Breaking Bad (2008) - 2 seasons - ⭐ 9.5");
     }
+
+    #[test]
+    fn test_genre_lookup() {
+        let scifi = Genre::find("sci-fi").unwrap();
+        assert_eq!(Genre::find("SciFi"), Some(scifi));
+        assert_eq!(scifi.id_for(MediaType::Movie), Some(878));
+        assert_eq!(scifi.id_for(MediaType::Tv), Some(10765));
+        assert_eq!(Genre::find("Horror").unwrap().id_for(MediaType::Tv), None);
+        assert!(Genre::find("polka").is_none());
+        assert!(Genre::available_for(MediaType::Tv).all(|g| g.tv_id.is_some()));
+    }
 }
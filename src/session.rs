@@ -0,0 +1,101 @@
+//! TUI browsing session, saved on exit and restored on the next start
+//!
+//! Opt-in with `restore_session = true`. Only ids and list positions are
+//! kept in `~/.cache/streamtui/tui-session.json`; the detail and search
+//! results are fetched again on restore. (Not to be confused with
+//! [`crate::stream::sessions`], the registry of running webtorrents.)
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::app::AppState;
+use crate::models::MediaType;
+
+/// Where the user was when the TUI closed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SavedSession {
+    /// Screen that was showing
+    pub state: AppState,
+    /// Title open in the detail view (also for its sources and subtitles)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<SavedDetail>,
+    /// Last search query
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub query: String,
+    /// Selected row in the search results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_selected: Option<usize>,
+}
+
+/// The detail view's title and selection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedDetail {
+    pub id: u64,
+    pub media_type: MediaType,
+    /// Selected season number (TV)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub season: Option<u8>,
+    /// Selected row in the episode list (TV)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub episode_index: Option<usize>,
+}
+
+impl SavedSession {
+    /// Default location (~/.cache/streamtui/tui-session.json)
+    pub fn default_path() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(|| PathBuf::from("/tmp"))
+            .join("streamtui")
+            .join("tui-session.json")
+    }
+
+    /// Load the saved session (None if missing or unreadable)
+    pub fn load() -> Option<Self> {
+        Self::load_from(&Self::default_path())
+    }
+
+    pub fn load_from(path: &Path) -> Option<Self> {
+        let body = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&body).ok()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        self.save_to(&Self::default_path())
+    }
+
+    pub fn save_to(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("streamtui-session-{}", std::process::id()));
+        let path = dir.join("tui-session.json");
+        let session = SavedSession {
+            state: AppState::Detail,
+            detail: Some(SavedDetail {
+                id: 1396,
+                media_type: MediaType::Tv,
+                season: Some(2),
+                episode_index: Some(4),
+            }),
+            query: "breaking bad".into(),
+            search_selected: Some(1),
+        };
+        session.save_to(&path).unwrap();
+        assert_eq!(SavedSession::load_from(&path), Some(session));
+
+        // Unreadable files are ignored
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(SavedSession::load_from(&path), None);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
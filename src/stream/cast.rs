@@ -5,6 +5,16 @@
 
 use crate::models::{CastDevice, PlaybackStatus};
 use anyhow::Result;
+use std::time::Duration;
+
+/// Longest a `catt scan` may run before discovery gives up
+///
+/// catt has no timeout flag, and a wedged mDNS lookup would otherwise block
+/// device discovery forever.
+pub const SCAN_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Longest `streamtui status` waits on `catt status` before calling the device unreachable
+pub const STATUS_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Chromecast manager using catt CLI
 pub struct CastManager {
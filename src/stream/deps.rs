@@ -0,0 +1,164 @@
+//! External tool checks
+//!
+//! Streaming shells out to webtorrent, catt and a local player. Checking for
+//! them up front turns a missing tool into a clear error instead of a
+//! playback that silently never starts.
+
+use std::path::Path;
+use std::process::Stdio;
+use thiserror::Error;
+use tokio::process::Command;
+
+/// An external program streamtui runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dependency {
+    /// Name shown to the user
+    pub name: &'static str,
+    /// Binary name on `PATH` (or an absolute path)
+    pub command: &'static str,
+    /// What it's needed for
+    pub purpose: &'static str,
+    /// How to install it
+    pub install: &'static str,
+}
+
+/// A required tool isn't installed
+#[derive(Debug, Error)]
+#[error("{name} not found. Install with: {install}")]
+pub struct MissingTool {
+    pub name: &'static str,
+    pub install: &'static str,
+}
+
+/// Torrent streaming (every non-debrid source)
+pub const WEBTORRENT: Dependency = Dependency {
+    name: "webtorrent",
+    command: "webtorrent",
+    purpose: "Torrent streaming",
+    install: "npm i -g webtorrent-cli",
+};
+
+/// Chromecast discovery, casting and playback control
+pub const CATT: Dependency = Dependency {
+    name: "catt",
+    command: "catt",
+    purpose: "Chromecast casting",
+    install: "pip install catt",
+};
+
+/// Poster previews in terminals without a graphics protocol
+pub const CHAFA: Dependency = Dependency {
+    name: "chafa",
+    command: "chafa",
+    purpose: "Poster previews (optional)",
+    install: "brew install chafa / apt install chafa",
+};
+
+impl Dependency {
+    /// Check that the binary can be found
+    pub fn is_installed(&self) -> bool {
+        on_path(self.command)
+    }
+
+    /// Fail with an install hint if the binary can't be found
+    pub fn require(&self) -> Result<(), MissingTool> {
+        if self.is_installed() {
+            Ok(())
+        } else {
+            Err(MissingTool {
+                name: self.name,
+                install: self.install,
+            })
+        }
+    }
+
+    /// Version number reported by `<command> --version`, if it runs and has one
+    pub async fn version(&self) -> Option<String> {
+        parse_version(&self.version_output().await?)
+    }
+
+    /// First line of `<command> --version`, if it runs
+    pub async fn version_output(&self) -> Option<String> {
+        let output = Command::new(self.command)
+            .arg("--version")
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .ok()?;
+
+        // Some tools print their version on stderr
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        stdout
+            .lines()
+            .chain(stderr.lines())
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string)
+    }
+}
+
+/// Pull a dotted version number out of `--version` output
+///
+/// `"webtorrent-cli 4.1.0"`, `"VLC media player 3.0.20 Vetinari"` and
+/// `"mpv v0.37.0 Copyright"` all work; a bare `"catt 0.12"` too.
+pub fn parse_version(text: &str) -> Option<String> {
+    let re = regex::Regex::new(r"\bv?(\d+(?:\.\d+)+)").ok()?;
+    re.captures(text).map(|c| c[1].to_string())
+}
+
+/// Whether `command` is an existing absolute path or a file on `PATH`
+pub fn on_path(command: &str) -> bool {
+    if command.starts_with('/') {
+        return Path::new(command).exists();
+    }
+    std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(command).is_file()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_tool_message() {
+        let missing = Dependency {
+            command: "streamtui-no-such-tool",
+            ..WEBTORRENT
+        };
+        assert!(!missing.is_installed());
+        assert_eq!(
+            missing.require().unwrap_err().to_string(),
+            "webtorrent not found. Install with: npm i -g webtorrent-cli"
+        );
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            parse_version("webtorrent-cli 4.1.0").as_deref(),
+            Some("4.1.0")
+        );
+        assert_eq!(
+            parse_version("VLC media player 3.0.20 Vetinari").as_deref(),
+            Some("3.0.20")
+        );
+        assert_eq!(
+            parse_version("mpv v0.37.0 Copyright © 2000-2023").as_deref(),
+            Some("0.37.0")
+        );
+        assert_eq!(
+            parse_version("catt v0.12.11, Eucalyptus").as_deref(),
+            Some("0.12.11")
+        );
+        assert_eq!(parse_version("usage: tool [options]"), None);
+    }
+
+    #[test]
+    fn test_on_path_finds_shell() {
+        assert!(on_path("sh"));
+        assert!(on_path("/bin/sh"));
+        assert!(!on_path("/no/such/binary"));
+    }
+}
@@ -3,10 +3,20 @@
 //! - Torrent: webtorrent-cli integration for streaming torrents
 //! - Cast: Chromecast discovery and control via catt
 //! - Subtitles: OpenSubtitles API integration
+//! - OpenSubtitles: REST API client, an alternate subtitle provider
 //! - Player: Local playback via VLC/mpv
+//! - Deps: Checks for the external tools above
+//! - Playback: Starts TUI playback in the background and controls it
+//! - Playback log: Where the tools above write their output
+//! - Sessions: Which webtorrent process streams which torrent
 
 pub mod cast;
+pub mod deps;
+pub mod opensubtitles;
+pub mod playback;
+pub mod playback_log;
 pub mod player;
+pub mod sessions;
 pub mod subtitles;
 pub mod torrent;
 
@@ -0,0 +1,236 @@
+//! OpenSubtitles REST client
+//!
+//! An alternate subtitle provider to the Stremio addon, with download counts,
+//! trust flags and release names. Needs an API key from
+//! https://www.opensubtitles.com/consumers (`opensubtitles_api_key`).
+//!
+//! Search results don't carry a download URL; the API hands out a link per
+//! file on request (and counts it against a daily quota). Results get an
+//! `opensubtitles:<file_id>` URL instead, resolved with [`download_link`]
+//! only when a subtitle is actually used.
+//!
+//! [`download_link`]: OpenSubtitlesClient::download_link
+
+use crate::models::{language_alpha2, SubFormat, SubtitleResult};
+use crate::stream::subtitles::SubtitleProvider;
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+/// URL prefix for files that still need a download link
+pub const URL_PREFIX: &str = "opensubtitles:";
+
+/// OpenSubtitles API client
+pub struct OpenSubtitlesClient {
+    base_url: String,
+    api_key: String,
+    client: reqwest::Client,
+}
+
+/// Search response
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    data: Vec<SearchHit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchHit {
+    id: String,
+    attributes: Attributes,
+}
+
+#[derive(Debug, Deserialize)]
+struct Attributes {
+    language: Option<String>,
+    #[serde(default)]
+    download_count: u32,
+    #[serde(default)]
+    hearing_impaired: bool,
+    #[serde(default)]
+    ai_translated: bool,
+    #[serde(default)]
+    from_trusted: bool,
+    fps: Option<f32>,
+    release: Option<String>,
+    #[serde(default)]
+    files: Vec<File>,
+}
+
+#[derive(Debug, Deserialize)]
+struct File {
+    file_id: u64,
+    file_name: Option<String>,
+}
+
+/// `/download` response
+#[derive(Debug, Deserialize)]
+struct DownloadResponse {
+    link: String,
+}
+
+impl OpenSubtitlesClient {
+    /// Client for the public API
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self::with_base_url("https://api.opensubtitles.com/api/v1", api_key)
+    }
+
+    /// Create with custom base URL (for testing)
+    pub fn with_base_url(base_url: impl Into<String>, api_key: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            api_key: api_key.into(),
+            client: crate::api::http_client(crate::api::DEFAULT_TIMEOUT),
+        }
+    }
+
+    /// Give up on a request after `timeout`
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.client = crate::api::http_client(timeout);
+        self
+    }
+
+    /// Request with the headers every endpoint wants
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        self.client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("Api-Key", &self.api_key)
+            .header(
+                reqwest::header::USER_AGENT,
+                concat!("streamtui v", env!("CARGO_PKG_VERSION")),
+            )
+            .header(reqwest::header::ACCEPT, "application/json")
+    }
+
+    /// Run a `/subtitles` query and convert the hits
+    async fn fetch(
+        &self,
+        mut query: Vec<(&str, String)>,
+        language: Option<&str>,
+    ) -> Result<Vec<SubtitleResult>> {
+        if let Some(languages) = language.and_then(api_languages) {
+            query.push(("languages", languages));
+        }
+        let response = self
+            .request(reqwest::Method::GET, "/subtitles")
+            .query(&query)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("OpenSubtitles API error: {}", response.status()));
+        }
+
+        let body: SearchResponse = response.json().await?;
+        Ok(body.data.into_iter().filter_map(to_result).collect())
+    }
+
+    /// Direct download URL for a file from a search result
+    pub async fn download_link(&self, file_id: u64) -> Result<String> {
+        let response = self
+            .request(reqwest::Method::POST, "/download")
+            .json(&serde_json::json!({ "file_id": file_id }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "OpenSubtitles download failed: {}",
+                response.status()
+            ));
+        }
+
+        let body: DownloadResponse = response.json().await?;
+        Ok(body.link)
+    }
+}
+
+impl SubtitleProvider for OpenSubtitlesClient {
+    async fn search(&self, imdb_id: &str, language: Option<&str>) -> Result<Vec<SubtitleResult>> {
+        self.fetch(vec![("imdb_id", imdb_number(imdb_id))], language)
+            .await
+    }
+
+    async fn search_episode(
+        &self,
+        imdb_id: &str,
+        season: u16,
+        episode: u16,
+        language: Option<&str>,
+    ) -> Result<Vec<SubtitleResult>> {
+        let query = vec![
+            ("parent_imdb_id", imdb_number(imdb_id)),
+            ("season_number", season.to_string()),
+            ("episode_number", episode.to_string()),
+        ];
+        self.fetch(query, language).await
+    }
+}
+
+/// File id from an `opensubtitles:<file_id>` URL
+pub fn file_id(url: &str) -> Option<u64> {
+    url.strip_prefix(URL_PREFIX)?.parse().ok()
+}
+
+/// "tt0133093" as "133093" (the API wants the number)
+fn imdb_number(imdb_id: &str) -> String {
+    let digits = imdb_id.trim_start_matches("tt").trim_start_matches('0');
+    if digits.is_empty() {
+        "0".to_string()
+    } else {
+        digits.to_string()
+    }
+}
+
+/// "eng,spa" as the API's "en,es" (two-letter codes, sorted)
+fn api_languages(language: &str) -> Option<String> {
+    let mut codes: Vec<&str> = language
+        .split(',')
+        .filter_map(|code| language_alpha2(code.trim()))
+        .collect();
+    codes.sort_unstable();
+    codes.dedup();
+    (!codes.is_empty()).then(|| codes.join(","))
+}
+
+/// A search hit as a `SubtitleResult` (hits without a file are skipped)
+fn to_result(hit: SearchHit) -> Option<SubtitleResult> {
+    let attrs = hit.attributes;
+    let file = attrs.files.first()?;
+    let language = attrs.language.unwrap_or_default();
+    let format = file
+        .file_name
+        .as_deref()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| SubFormat::from_extension(ext))
+        .unwrap_or(SubFormat::Srt);
+    let mut result = SubtitleResult {
+        id: format!("os-{}", hit.id),
+        url: format!("{}{}", URL_PREFIX, file.file_id),
+        language,
+        language_name: String::new(),
+        release: attrs.release.unwrap_or_else(|| "OpenSubtitles".to_string()),
+        fps: attrs.fps,
+        format,
+        downloads: attrs.download_count,
+        from_trusted: attrs.from_trusted,
+        hearing_impaired: attrs.hearing_impaired,
+        ai_translated: attrs.ai_translated,
+    };
+    result.fill_language_name();
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_helpers() {
+        assert_eq!(imdb_number("tt0133093"), "133093");
+        assert_eq!(imdb_number("1877830"), "1877830");
+        assert_eq!(api_languages("spa,eng").as_deref(), Some("en,es"));
+        assert_eq!(api_languages("fre, fra").as_deref(), Some("fr"));
+        assert_eq!(api_languages("xyz"), None);
+        assert_eq!(file_id("opensubtitles:8765432"), Some(8765432));
+        assert_eq!(file_id("https://subs.io/1"), None);
+    }
+}
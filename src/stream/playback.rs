@@ -0,0 +1,615 @@
+//! TUI playback
+//!
+//! The TUI doesn't run webtorrent itself: it starts `streamtui cast-magnet`
+//! in the background, so a stream outlives the TUI and its output lands in
+//! the playback log. Debrid links skip webtorrent and go straight to catt or
+//! the local player.
+//!
+//! [`Playback`] describes one `cast-magnet` run and builds its argument
+//! vector. It is spawned directly, never through a shell, so magnets,
+//! device names and paths need no quoting however many quotes or backticks
+//! they contain.
+
+use std::path::Path;
+use std::process::Stdio;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::config::{Config, DEFAULT_SEEK_STEP};
+use crate::models::{normalize_info_hash, SubFormat, SubtitleFile, TorrentSession};
+use crate::stream::deps;
+use crate::stream::playback_log::PlaybackLog;
+use crate::stream::sessions;
+use crate::stream::subtitles::{self, SubtitleClient};
+use crate::stream::PlayerType;
+
+/// A `streamtui cast-magnet` run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Playback {
+    /// The streamtui executable
+    pub exe: String,
+    pub magnet: String,
+    /// Chromecast name, or a local player's pseudo-device
+    pub device: String,
+    pub subtitle_path: Option<String>,
+    /// File to play in a multi-file torrent
+    pub file_idx: Option<u32>,
+    /// Position to start at, in seconds
+    pub start: Option<u64>,
+}
+
+impl Playback {
+    pub fn new(
+        exe: impl Into<String>,
+        magnet: impl Into<String>,
+        device: impl Into<String>,
+    ) -> Self {
+        Self {
+            exe: exe.into(),
+            magnet: magnet.into(),
+            device: device.into(),
+            subtitle_path: None,
+            file_idx: None,
+            start: None,
+        }
+    }
+
+    /// The same run, using the currently running streamtui
+    pub fn current_exe(magnet: impl Into<String>, device: impl Into<String>) -> Result<Self> {
+        let exe =
+            std::env::current_exe().map_err(|e| anyhow!("Failed to get executable path: {}", e))?;
+        Ok(Self::new(exe.to_string_lossy(), magnet, device))
+    }
+
+    pub fn with_subtitle(mut self, path: Option<String>) -> Self {
+        self.subtitle_path = path;
+        self
+    }
+
+    pub fn with_file_idx(mut self, idx: Option<u32>) -> Self {
+        self.file_idx = idx;
+        self
+    }
+
+    /// Start at `secs` (0 starts from the beginning)
+    pub fn with_start(mut self, secs: Option<u64>) -> Self {
+        self.start = secs.filter(|&s| s > 0);
+        self
+    }
+
+    /// `streamtui cast-magnet <magnet> [-d <device> | --vlc | --mpv] [--subtitle-file <path>] [-i <idx>] [--start <secs>] -q`
+    pub fn build_cast_command(&self) -> Vec<String> {
+        let mut args = vec![
+            self.exe.clone(),
+            "cast-magnet".to_string(),
+            self.magnet.clone(),
+        ];
+        match PlayerType::from_device_name(&self.device) {
+            Some(player) => args.push(player.webtorrent_flag().to_string()),
+            None => args.extend(["-d".to_string(), self.device.clone()]),
+        }
+        if let Some(path) = &self.subtitle_path {
+            args.extend(["--subtitle-file".to_string(), path.clone()]);
+        }
+        if let Some(idx) = self.file_idx {
+            args.extend(["-i".to_string(), idx.to_string()]);
+        }
+        if let Some(secs) = self.start {
+            args.extend(["--start".to_string(), secs.to_string()]);
+        }
+        args.push("-q".to_string());
+        args
+    }
+
+    /// Run it in the background, output appended to the playback log
+    ///
+    /// webtorrent/VLC need somewhere to write, and the TUI tails the log for
+    /// torrent progress.
+    fn spawn(&self, log: &PlaybackLog) -> Result<()> {
+        let args = self.build_cast_command();
+        log.event(format!("exec: {}", args.join(" ")));
+
+        let output = log.open_append()?;
+        let mut command = std::process::Command::new(&args[0]);
+        command
+            .args(&args[1..])
+            .stdin(Stdio::null())
+            .stdout(Stdio::from(output.try_clone()?))
+            .stderr(Stdio::from(output));
+        detach(&mut command);
+
+        let mut child = command
+            .spawn()
+            .map_err(|e| anyhow!("Failed to start cast: {}", e))?;
+
+        // Reap it whenever it exits so it doesn't linger as a zombie
+        std::thread::spawn(move || child.wait());
+        Ok(())
+    }
+}
+
+/// Start `command` in its own session
+///
+/// Without a controlling terminal, Ctrl+C in the TUI or closing the terminal
+/// doesn't take the stream down with it (what `nohup` used to do).
+#[cfg(unix)]
+fn detach(command: &mut std::process::Command) {
+    use std::os::unix::process::CommandExt;
+
+    // SAFETY: setsid is async-signal-safe and touches no Rust state
+    unsafe {
+        command.pre_exec(|| {
+            if libc::setsid() == -1 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn detach(_command: &mut std::process::Command) {}
+
+/// Start playback: webtorrent with native chromecast support
+///
+/// Returns the status line for the TUI.
+pub async fn start_playback(
+    magnet: &str,
+    device: &str,
+    subtitle_url: Option<&str>,
+    file_idx: Option<u32>,
+    direct_url: Option<&str>,
+    start: Option<u64>,
+    log: &PlaybackLog,
+) -> Result<String> {
+    log.event(format!("magnet: {}", magnet));
+    log.event(format!("device: {}", device));
+    let local_player = PlayerType::from_device_name(device);
+    if let Some(player) = local_player.filter(|p| !p.is_installed()) {
+        bail!("{} not found. Install it first.", player.display_name());
+    }
+    // A missing tool would otherwise only show up in the playback log
+    if direct_url.is_none() {
+        deps::WEBTORRENT.require()?;
+        if sessions::info_hash(magnet)
+            .and_then(|h| normalize_info_hash(&h))
+            .is_none()
+        {
+            bail!("This stream has an invalid info hash");
+        }
+    }
+    if local_player.is_none() {
+        deps::CATT.require()?;
+    }
+
+    // Download subtitle file if URL provided
+    let subtitle_path = if let Some(url) = subtitle_url {
+        download_subtitle(url, 0).await.ok()
+    } else {
+        None
+    };
+    log.event(format!(
+        "subtitle: {}",
+        subtitle_path.as_deref().unwrap_or("none")
+    ));
+    let with_subtitles = subtitle_path.is_some();
+
+    // Debrid-resolved sources are plain HTTP - no webtorrent needed
+    if let Some(url) = direct_url {
+        log.event(format!("direct url: {}", redact_url(url)));
+        return start_direct_playback(url, device, subtitle_path.as_deref(), start);
+    }
+
+    Playback::current_exe(magnet, device)?
+        .with_subtitle(subtitle_path)
+        .with_file_idx(file_idx)
+        .with_start(start)
+        .spawn(log)?;
+
+    let msg = if let Some(player) = local_player {
+        if with_subtitles {
+            format!("Playing in {} (with subtitles)", player)
+        } else {
+            format!("Playing in {}", player)
+        }
+    } else if with_subtitles {
+        format!("Casting to {} (with subtitles)", device)
+    } else {
+        format!("Casting to {}", device)
+    };
+    Ok(msg)
+}
+
+/// Play a direct HTTP stream (debrid link) via catt or a local player, bypassing webtorrent
+fn start_direct_playback(
+    url: &str,
+    device: &str,
+    subtitle_path: Option<&str>,
+    start: Option<u64>,
+) -> Result<String> {
+    let local_player = PlayerType::from_device_name(device);
+
+    let mut cmd = if let Some(player) = local_player {
+        let mut cmd = std::process::Command::new(player.command());
+        cmd.arg(url);
+        cmd.args(player.player_args(subtitle_path.map(Path::new), start));
+        cmd
+    } else {
+        let mut cmd = std::process::Command::new("catt");
+        cmd.arg("-d").arg(device).arg("cast").arg(url);
+        if let Some(sub_path) = subtitle_path {
+            cmd.arg("-s").arg(sub_path);
+        }
+        if let Some(secs) = start {
+            cmd.arg("-t").arg(secs.to_string());
+        }
+        cmd
+    };
+
+    let child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| anyhow!("Failed to start cast: {}", e))?;
+
+    std::mem::forget(child);
+
+    Ok(if let Some(player) = local_player {
+        format!("Playing in {} (debrid)", player)
+    } else {
+        format!("Casting to {} (debrid)", device)
+    })
+}
+
+/// Seconds extra devices wait for webtorrent's server before giving up
+const STREAM_WAIT_SECS: u32 = 120;
+
+/// Cast a stream that's already playing to more devices (multi-device playback)
+///
+/// Debrid links are cast as they are. Torrents wait for webtorrent's server
+/// to show up in the log, then go out at this machine's LAN address, since
+/// webtorrent reports `localhost`. Subtitles and controls stay with the
+/// main device.
+pub async fn cast_to_more(
+    devices: &[String],
+    direct_url: Option<&str>,
+    log: &PlaybackLog,
+) -> Result<()> {
+    let url = match direct_url {
+        Some(url) => url.to_string(),
+        None => {
+            let mut server = None;
+            for _ in 0..STREAM_WAIT_SECS {
+                server = std::fs::read_to_string(&log.path)
+                    .ok()
+                    .and_then(|content| TorrentSession::parse_server_url(&content));
+                if server.is_some() {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+            }
+            let server = server.ok_or_else(|| anyhow!("webtorrent's server never came up"))?;
+            lan_url(&server, local_ip_address::local_ip()?)
+        }
+    };
+
+    // Debrid links carry the account token; the log only gets the host
+    let shown = match direct_url {
+        Some(_) => redact_url(&url),
+        None => url.clone(),
+    };
+    for device in devices {
+        log.event(format!("also casting to {}: {}", device, shown));
+        let status = tokio::process::Command::new("catt")
+            .arg("-d")
+            .arg(device)
+            .arg("cast")
+            .arg(&url)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await;
+        if !status.is_ok_and(|s| s.success()) {
+            log.event(format!("casting to {} failed", device));
+        }
+    }
+    Ok(())
+}
+
+/// `url` with a loopback host swapped for `ip`, so other devices can reach it
+fn lan_url(url: &str, ip: std::net::IpAddr) -> String {
+    ["localhost", "127.0.0.1"]
+        .iter()
+        .find_map(|host| {
+            let prefix = format!("http://{}", host);
+            url.strip_prefix(&prefix)
+                .map(|rest| format!("http://{}{}", ip, rest))
+        })
+        .unwrap_or_else(|| url.to_string())
+}
+
+/// `url` cut down to scheme and host, for logs users attach to bug reports
+fn redact_url(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => format!(
+            "{}://{}/…",
+            parsed.scheme(),
+            parsed.host_str().unwrap_or("?")
+        ),
+        Err(_) => "(unparsable url)".to_string(),
+    }
+}
+
+/// Restart playback with subtitles at a specific position
+pub async fn restart_with_subtitles(
+    magnet: &str,
+    device: &str,
+    subtitle_url: &str,
+    seek_seconds: u32,
+    file_idx: Option<u32>,
+    offset_ms: i64,
+    log: &PlaybackLog,
+) -> Result<String> {
+    log.event(format!("magnet: {}", magnet));
+    log.event(format!("device: {}", device));
+    deps::WEBTORRENT.require()?;
+    if PlayerType::from_device_name(device).is_none() {
+        deps::CATT.require()?;
+    }
+
+    // 1. Stop current playback
+    stop_playback(sessions::info_hash(magnet).as_deref()).await?;
+
+    // Small delay to ensure clean stop
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    // 2. Download subtitle file
+    let subtitle_path = download_subtitle(subtitle_url, offset_ms).await?;
+    log.event(format!(
+        "subtitle: {} (offset {}ms)",
+        subtitle_path, offset_ms
+    ));
+
+    // 3. Use our own CLI tool with --start for seeking
+    Playback::current_exe(magnet, device)?
+        .with_subtitle(Some(subtitle_path))
+        .with_file_idx(file_idx)
+        .with_start(Some(seek_seconds.into()))
+        .spawn(log)?;
+
+    Ok(format!("Restarted with subtitles at {}s", seek_seconds))
+}
+
+/// Download subtitle file to temp directory
+///
+/// A non-zero `offset_ms` re-times the cues, which means writing WebVTT.
+pub async fn download_subtitle(url: &str, offset_ms: i64) -> Result<String> {
+    // Resolves OpenSubtitles links, reads local `file://` picks, and
+    // normalizes gzip and Latin-1 subs to plain UTF-8
+    let (text, format) = SubtitleClient::from_config(&Config::load())
+        .fetch_subtitle(url)
+        .await?;
+
+    // ASS/SSA isn't playable on Chromecast; SRT is, unless it needs shifting
+    let (text, extension) = match format {
+        SubFormat::Srt | SubFormat::Sub if offset_ms == 0 => (text, "srt"),
+        _ => (subtitles::to_webvtt(text, format), "vtt"),
+    };
+    let text = if offset_ms != 0 {
+        SubtitleFile::shift_webvtt(&text, offset_ms)
+    } else {
+        text
+    };
+
+    // Create temp file with the matching extension
+    let filename = format!("streamtui_sub_{}.{}", std::process::id(), extension);
+    let path = std::env::temp_dir().join(filename);
+    std::fs::write(&path, text)?;
+
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Stop playback - kill the torrent's webtorrent (the ones this process
+/// started without a hash)
+pub async fn stop_playback(info_hash: Option<&str>) -> Result<()> {
+    match info_hash {
+        Some(hash) => sessions::stop(Some(hash)),
+        None => sessions::stop_started(),
+    };
+
+    // Stop catt playback
+    let _ = tokio::process::Command::new("catt")
+        .arg("stop")
+        .output()
+        .await;
+
+    Ok(())
+}
+
+/// Send playback control command using our own CLI
+pub async fn playback_control(action: &str, device: &str, value: Option<u32>) -> Result<()> {
+    // Local player controls from TUI not supported - users control the player directly
+    if PlayerType::from_device_name(device).is_some() {
+        return Ok(());
+    }
+
+    let exe = std::env::current_exe()?;
+    let (cmd, extra_arg) = control_args(action, value)?;
+
+    let mut command = tokio::process::Command::new(&exe);
+    command.arg(cmd).arg("-d").arg(device).arg("-q");
+
+    if let Some(arg) = extra_arg {
+        command.arg(arg);
+    }
+
+    command.output().await?;
+
+    Ok(())
+}
+
+/// Map a TUI control action to our CLI command and its argument
+fn control_args(action: &str, value: Option<u32>) -> Result<(&'static str, Option<String>)> {
+    Ok(match action {
+        "play_toggle" | "play" => ("play", None),
+        "pause" => ("pause", None),
+        "stop" => ("stop", None),
+        "volumeup" => ("volume", Some("+10".into())),
+        "volumedown" => ("volume", Some("-10".into())),
+        "volume_set" => {
+            let level = value.ok_or_else(|| anyhow!("volume_set needs a level"))?;
+            ("volume", Some(level.min(100).to_string()))
+        }
+        "seek_to" => {
+            let secs = value.ok_or_else(|| anyhow!("seek_to needs a position"))?;
+            ("seek", Some(secs.to_string()))
+        }
+        "ffwd" => (
+            "seek",
+            Some(format!("+{}", value.unwrap_or(DEFAULT_SEEK_STEP))),
+        ),
+        "rewind" => (
+            "seek",
+            Some(format!("-{}", value.unwrap_or(DEFAULT_SEEK_STEP))),
+        ),
+        _ => bail!("Unknown action: {}", action),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAGNET: &str =
+        "magnet:?xt=urn:btih:c9e15763f722f23e98a29decdfae341b98d53056&dn=Ocean's+Eleven";
+
+    #[test]
+    fn test_redact_url() {
+        assert_eq!(
+            redact_url("https://abc.download.real-debrid.com/d/SECRETTOKEN/Movie.mkv"),
+            "https://abc.download.real-debrid.com/…"
+        );
+        assert_eq!(redact_url("not a url"), "(unparsable url)");
+    }
+
+    #[test]
+    fn test_lan_url_for_other_devices() {
+        let ip: std::net::IpAddr = "192.168.1.10".parse().unwrap();
+        assert_eq!(
+            lan_url("http://localhost:8000/0", ip),
+            "http://192.168.1.10:8000/0"
+        );
+        assert_eq!(
+            lan_url("http://127.0.0.1:8000/2", ip),
+            "http://192.168.1.10:8000/2"
+        );
+        assert_eq!(
+            lan_url("http://192.168.1.7:8000/0", ip),
+            "http://192.168.1.7:8000/0"
+        );
+    }
+
+    #[test]
+    fn test_build_cast_command_chromecast() {
+        let playback = Playback::new("/usr/bin/streamtui", MAGNET, "Living Room TV")
+            .with_subtitle(Some("/tmp/sub.srt".into()))
+            .with_file_idx(Some(3))
+            .with_start(Some(90));
+        assert_eq!(
+            playback.build_cast_command(),
+            vec![
+                "/usr/bin/streamtui",
+                "cast-magnet",
+                MAGNET,
+                "-d",
+                "Living Room TV",
+                "--subtitle-file",
+                "/tmp/sub.srt",
+                "-i",
+                "3",
+                "--start",
+                "90",
+                "-q",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_cast_command_local_player() {
+        let device = PlayerType::Vlc.device_name();
+        let playback = Playback::new("streamtui", MAGNET, device).with_start(Some(0));
+        assert_eq!(
+            playback.build_cast_command(),
+            vec!["streamtui", "cast-magnet", MAGNET, "--vlc", "-q"]
+        );
+    }
+
+    #[test]
+    fn test_build_cast_command_passes_quotes_verbatim() {
+        let playback = Playback::new("/home/o'neil/bin/streamtui", MAGNET, "Kid's `Room` $(TV)")
+            .with_subtitle(Some("/tmp/It's \"here\".srt".into()));
+        let args = playback.build_cast_command();
+        assert_eq!(args[0], "/home/o'neil/bin/streamtui");
+        assert_eq!(args[2], MAGNET);
+        assert_eq!(args[4], "Kid's `Room` $(TV)");
+        assert_eq!(args[6], "/tmp/It's \"here\".srt");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_spawn_appends_to_log_without_a_shell() {
+        let dir = std::env::temp_dir().join(format!("streamtui-spawn-{}", std::process::id()));
+        let log = PlaybackLog {
+            path: dir.join("play.log"),
+            events: false,
+        };
+        log.clear();
+
+        // `echo` stands in for streamtui; a shell would have run the backticks
+        let playback = Playback::new("echo", "it's `touch pwned`", "TV");
+        playback.spawn(&log).unwrap();
+
+        let mut written = String::new();
+        for _ in 0..50 {
+            written = std::fs::read_to_string(&log.path).unwrap_or_default();
+            if !written.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        assert_eq!(
+            written.trim_end(),
+            "cast-magnet it's `touch pwned` -d TV -q"
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_control_args() {
+        assert_eq!(control_args("play_toggle", None).unwrap(), ("play", None));
+        assert_eq!(
+            control_args("volume_set", Some(150)).unwrap(),
+            ("volume", Some("100".into()))
+        );
+        assert_eq!(
+            control_args("seek_to", Some(42)).unwrap(),
+            ("seek", Some("42".into()))
+        );
+        assert!(control_args("seek_to", None).is_err());
+        assert_eq!(
+            control_args("ffwd", None).unwrap(),
+            ("seek", Some("+30".into()))
+        );
+        assert_eq!(
+            control_args("ffwd", Some(10)).unwrap(),
+            ("seek", Some("+10".into()))
+        );
+        assert_eq!(
+            control_args("rewind", Some(150)).unwrap(),
+            ("seek", Some("-150".into()))
+        );
+        assert!(control_args("bogus", None).is_err());
+    }
+}
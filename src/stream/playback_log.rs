@@ -0,0 +1,141 @@
+//! Playback log
+//!
+//! webtorrent, catt and the local players write their output here; the TUI
+//! tails it for torrent progress. With `--log-file` or `log_path` set, the
+//! steps streamtui takes (magnet, device, subtitle file) are written to it
+//! too, with timestamps, so a bug report can attach a single file.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+
+/// Where playback output goes, and whether streamtui logs its own steps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaybackLog {
+    pub path: PathBuf,
+    /// Only when the user picked the file; the default log stays tool output
+    pub events: bool,
+}
+
+impl PlaybackLog {
+    /// Default location (~/.cache/streamtui/playback.log)
+    pub fn default_path() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(|| PathBuf::from("/tmp"))
+            .join("streamtui")
+            .join("playback.log")
+    }
+
+    /// `--log-file`, then `log_path` from the config, then the default
+    pub fn from_config(config: &Config) -> Self {
+        match config.log_file() {
+            Some(path) => Self { path, events: true },
+            None => Self {
+                path: Self::default_path(),
+                events: false,
+            },
+        }
+    }
+
+    /// Start a fresh log so the monitor only sees this playback
+    pub fn clear(&self) {
+        let _ = std::fs::remove_file(&self.path);
+        if let Some(parent) = self.path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+    }
+
+    /// The log opened for appending, for a tool's stdout/stderr
+    pub fn open_append(&self) -> std::io::Result<std::fs::File> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+    }
+
+    /// Append a timestamped line (best effort, only for a user-chosen log)
+    pub fn event(&self, msg: impl std::fmt::Display) {
+        if !self.events {
+            return;
+        }
+        let Ok(mut file) = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+        else {
+            return;
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let _ = writeln!(file, "[{}] streamtui: {}", utc_timestamp(now), msg);
+    }
+}
+
+/// `YYYY-MM-DD HH:MM:SSZ` for seconds since the Unix epoch
+pub fn utc_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utc_timestamp() {
+        assert_eq!(utc_timestamp(0), "1970-01-01 00:00:00Z");
+        assert_eq!(utc_timestamp(951_782_400), "2000-02-29 00:00:00Z");
+        assert_eq!(utc_timestamp(1_792_150_245), "2026-10-16 11:30:45Z");
+    }
+
+    #[test]
+    fn test_events_only_in_chosen_log() {
+        let dir = std::env::temp_dir().join(format!("streamtui-log-{}", std::process::id()));
+        let log = PlaybackLog {
+            path: dir.join("play.log"),
+            events: true,
+        };
+        log.clear();
+        log.event("device: TV");
+        let written = std::fs::read_to_string(&log.path).unwrap();
+        assert!(written.starts_with('['));
+        assert!(written.trim_end().ends_with("] streamtui: device: TV"));
+
+        let quiet = PlaybackLog {
+            events: false,
+            ..log.clone()
+        };
+        quiet.clear();
+        quiet.event("device: TV");
+        assert!(!quiet.path.exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
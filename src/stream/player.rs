@@ -2,6 +2,8 @@
 //!
 //! Opens streams directly in VLC or mpv instead of casting to Chromecast.
 
+use super::deps::{self, Dependency};
+use crate::models::CastDevice;
 use std::path::Path;
 use std::process::Stdio;
 use thiserror::Error;
@@ -18,6 +20,9 @@ pub enum PlayerType {
 }
 
 impl PlayerType {
+    /// Every supported player, in the order they're offered
+    pub const ALL: [PlayerType; 2] = [PlayerType::Vlc, PlayerType::Mpv];
+
     /// Get the command name for this player
     pub fn command(&self) -> &'static str {
         match self {
@@ -40,6 +45,112 @@ impl PlayerType {
             PlayerType::Mpv => "mpv",
         }
     }
+
+    /// Check that the player binary is on `PATH` (or the macOS app bundle exists)
+    pub fn is_installed(&self) -> bool {
+        deps::on_path(self.command())
+    }
+
+    /// The player as an external dependency (for `streamtui doctor`)
+    pub fn dependency(&self) -> Dependency {
+        Dependency {
+            name: self.display_name(),
+            command: self.command(),
+            purpose: "Local playback",
+            install: match self {
+                PlayerType::Vlc => "https://www.videolan.org/vlc/",
+                PlayerType::Mpv => "https://mpv.io/installation/",
+            },
+        }
+    }
+
+    /// Players found on this system
+    pub fn installed() -> Vec<PlayerType> {
+        Self::ALL.into_iter().filter(|p| p.is_installed()).collect()
+    }
+
+    /// Parse a config value ("vlc" or "mpv", any case)
+    pub fn from_name(name: &str) -> Option<PlayerType> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.display_name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Player for local playback: `flag` (from the CLI) wins, then the
+    /// configured `preferred` one if it's installed, then the first of
+    /// `installed`.
+    ///
+    /// Also returns a warning when the configured player is missing and
+    /// another one stands in for it.
+    pub fn select(
+        flag: Option<PlayerType>,
+        preferred: Option<PlayerType>,
+        installed: &[PlayerType],
+    ) -> (PlayerType, Option<String>) {
+        if let Some(player) = flag {
+            return (player, None);
+        }
+        match (preferred, installed.first()) {
+            (Some(player), _) if installed.contains(&player) => (player, None),
+            (Some(player), Some(&other)) => (
+                other,
+                Some(format!(
+                    "{} (default_local_player) isn't installed; using {}",
+                    player, other
+                )),
+            ),
+            // Nothing installed: keep the preference and let playback report it
+            (Some(player), None) => (player, None),
+            (None, first) => (first.copied().unwrap_or_default(), None),
+        }
+    }
+
+    /// Player options for a subtitle file and start position (no stream URL)
+    ///
+    /// Each option is a single `--flag=value` word so the list can also be
+    /// handed to webtorrent's `--player-args`.
+    pub fn player_args(&self, subtitle_path: Option<&Path>, start: Option<u64>) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(sub_path) = subtitle_path {
+            args.push(format!("--sub-file={}", sub_path.display()));
+        }
+        if let Some(secs) = start.filter(|&s| s > 0) {
+            args.push(match self {
+                PlayerType::Vlc => format!("--start-time={}", secs),
+                PlayerType::Mpv => format!("--start={}", secs),
+            });
+        }
+        args
+    }
+
+    /// webtorrent flag that launches this player
+    pub fn webtorrent_flag(&self) -> &'static str {
+        match self {
+            PlayerType::Vlc => "--vlc",
+            PlayerType::Mpv => "--mpv",
+        }
+    }
+
+    /// Name of the pseudo-device offered in the TUI device list
+    pub fn device_name(&self) -> String {
+        format!("{} (Local)", self.display_name())
+    }
+
+    /// Pseudo-device for playing in this player, listed before Chromecasts
+    pub fn device(&self) -> CastDevice {
+        CastDevice {
+            id: format!("{}-local", self.display_name().to_lowercase()),
+            name: self.device_name(),
+            address: std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+            port: 0,
+            model: Some("Local Playback".to_string()),
+        }
+    }
+
+    /// The local player behind a device name like "mpv (Local)"
+    pub fn from_device_name(name: &str) -> Option<PlayerType> {
+        Self::ALL.into_iter().find(|p| p.device_name() == name)
+    }
 }
 
 impl std::fmt::Display for PlayerType {
@@ -87,22 +198,7 @@ impl LocalPlayer {
 
     /// Check if the player is available on the system
     pub async fn is_available(&self) -> bool {
-        let cmd = self.player_type.command();
-
-        // If it's a full path (macOS app bundle), check if it exists
-        if cmd.starts_with('/') {
-            return std::path::Path::new(cmd).exists();
-        }
-
-        // Otherwise use 'which' to find in PATH
-        Command::new("which")
-            .arg(cmd)
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
-            .await
-            .map(|s| s.success())
-            .unwrap_or(false)
+        self.player_type.is_installed()
     }
 
     /// Play a stream URL with optional subtitles
@@ -110,6 +206,7 @@ impl LocalPlayer {
     /// # Arguments
     /// * `stream_url` - The URL to stream (http, file, etc.)
     /// * `subtitle_path` - Optional path to a subtitle file
+    /// * `start` - Optional start position in seconds
     ///
     /// # Returns
     /// The spawned child process
@@ -117,6 +214,7 @@ impl LocalPlayer {
         &self,
         stream_url: &str,
         subtitle_path: Option<&Path>,
+        start: Option<u64>,
     ) -> Result<Child, PlayerError> {
         // Validate subtitle path if provided
         if let Some(sub_path) = subtitle_path {
@@ -128,25 +226,15 @@ impl LocalPlayer {
         }
 
         let mut cmd = Command::new(self.player_type.command());
+        cmd.arg(stream_url);
+        cmd.args(self.player_type.player_args(subtitle_path, start));
 
         match self.player_type {
-            PlayerType::Vlc => {
-                cmd.arg(stream_url);
-                if let Some(sub_path) = subtitle_path {
-                    cmd.arg("--sub-file").arg(sub_path);
-                }
-                // VLC-specific options for better streaming
-                cmd.arg("--no-video-title-show"); // Don't show filename overlay
-            }
-            PlayerType::Mpv => {
-                cmd.arg(stream_url);
-                if let Some(sub_path) = subtitle_path {
-                    cmd.arg(format!("--sub-file={}", sub_path.display()));
-                }
-                // mpv-specific options
-                cmd.arg("--force-window=immediate"); // Show window immediately
-            }
-        }
+            // VLC-specific options for better streaming
+            PlayerType::Vlc => cmd.arg("--no-video-title-show"), // Don't show filename overlay
+            // mpv-specific options
+            PlayerType::Mpv => cmd.arg("--force-window=immediate"), // Show window immediately
+        };
 
         // Don't capture output - let it display normally
         cmd.stdout(Stdio::null());
@@ -167,7 +255,7 @@ impl LocalPlayer {
         stream_url: &str,
         subtitle_path: Option<&Path>,
     ) -> Result<(), PlayerError> {
-        let mut child = self.play(stream_url, subtitle_path).await?;
+        let mut child = self.play(stream_url, subtitle_path, None).await?;
         let _ = child.wait().await;
         Ok(())
     }
@@ -195,4 +283,57 @@ mod tests {
     fn test_default_player() {
         assert_eq!(PlayerType::default(), PlayerType::Vlc);
     }
+
+    #[test]
+    fn test_select_player_precedence() {
+        use PlayerType::{Mpv, Vlc};
+        // The CLI flag wins, installed or not
+        assert_eq!(
+            PlayerType::select(Some(Vlc), Some(Mpv), &[Mpv]),
+            (Vlc, None)
+        );
+        // Then the configured default
+        assert_eq!(
+            PlayerType::select(None, Some(Mpv), &[Vlc, Mpv]),
+            (Mpv, None)
+        );
+        // A missing default falls back to what's installed, with a warning
+        let (player, warning) = PlayerType::select(None, Some(Mpv), &[Vlc]);
+        assert_eq!(player, Vlc);
+        assert!(warning.unwrap().contains("mpv"));
+        // Without a default, the first installed player
+        assert_eq!(PlayerType::select(None, None, &[Mpv]), (Mpv, None));
+        assert_eq!(PlayerType::select(None, None, &[]), (Vlc, None));
+        assert_eq!(PlayerType::select(None, Some(Mpv), &[]), (Mpv, None));
+
+        assert_eq!(PlayerType::from_name("MPV"), Some(Mpv));
+        assert_eq!(PlayerType::from_name("vlc"), Some(Vlc));
+        assert_eq!(PlayerType::from_name("totem"), None);
+    }
+
+    #[test]
+    fn test_player_args() {
+        let sub = Path::new("/tmp/subs.srt");
+        assert_eq!(
+            PlayerType::Mpv.player_args(Some(sub), Some(90)),
+            vec!["--sub-file=/tmp/subs.srt", "--start=90"]
+        );
+        assert_eq!(
+            PlayerType::Vlc.player_args(Some(sub), Some(90)),
+            vec!["--sub-file=/tmp/subs.srt", "--start-time=90"]
+        );
+        // Starting at zero is the same as not seeking
+        assert!(PlayerType::Mpv.player_args(None, Some(0)).is_empty());
+    }
+
+    #[test]
+    fn test_local_device_round_trip() {
+        for player in PlayerType::ALL {
+            let device = player.device();
+            assert_eq!(PlayerType::from_device_name(&device.name), Some(player));
+        }
+        assert_eq!(PlayerType::Mpv.device().name, "mpv (Local)");
+        assert_eq!(PlayerType::Vlc.device().id, "vlc-local");
+        assert_eq!(PlayerType::from_device_name("Living Room"), None);
+    }
 }
@@ -0,0 +1,381 @@
+//! webtorrent session registry
+//!
+//! Every webtorrent streamtui spawns is recorded by info_hash in
+//! `~/.cache/streamtui/sessions.json`, so `stop` can end one stream without
+//! taking down another one started in parallel. A hash can have several
+//! processes (two episodes of one season pack, say).
+//!
+//! A recorded pid only counts while its command line still names webtorrent
+//! and the hash, so a pid reused by an unrelated process is never killed.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// info_hashes this process started streams for (the TUI's own playback)
+static STARTED: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// A running webtorrent process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    pub pid: u32,
+    /// File streamed from a multi-file torrent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_idx: Option<u32>,
+}
+
+/// Sessions keyed by lowercase info_hash
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sessions {
+    path: PathBuf,
+    pub entries: BTreeMap<String, Vec<Session>>,
+}
+
+impl Sessions {
+    /// Default location (~/.cache/streamtui/sessions.json)
+    pub fn default_path() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(|| PathBuf::from("/tmp"))
+            .join("streamtui")
+            .join("sessions.json")
+    }
+
+    /// Load the registry (empty if missing or unreadable)
+    pub fn load() -> Self {
+        Self::load_from(Self::default_path())
+    }
+
+    pub fn load_from(path: PathBuf) -> Self {
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        Self { path, entries }
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(&self.entries)?)
+    }
+
+    /// Drop entries whose process has exited (or whose pid now belongs to
+    /// something else), returning how many were removed
+    pub fn prune(&mut self) -> usize {
+        let before = self.len();
+        for (hash, list) in self.entries.iter_mut() {
+            list.retain(|s| is_ours(hash, s.pid));
+        }
+        self.entries.retain(|_, list| !list.is_empty());
+        before - self.len()
+    }
+
+    /// Number of tracked processes
+    pub fn len(&self) -> usize {
+        self.entries.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add a process for `hash` (replacing an entry with the same pid)
+    pub fn insert(&mut self, hash: &str, session: Session) {
+        let list = self.entries.entry(hash.to_lowercase()).or_default();
+        list.retain(|s| s.pid != session.pid);
+        list.push(session);
+    }
+
+    /// Remove the entry for `pid` under `hash`, returning whether it was there
+    pub fn remove(&mut self, hash: &str, pid: u32) -> bool {
+        let Some(list) = self.entries.get_mut(hash) else {
+            return false;
+        };
+        let before = list.len();
+        list.retain(|s| s.pid != pid);
+        let removed = list.len() != before;
+        if list.is_empty() {
+            self.entries.remove(hash);
+        }
+        removed
+    }
+
+    /// Kill the sessions for `info_hash`, or every tracked session for `None`
+    ///
+    /// Returns the info_hashes that were stopped.
+    pub fn stop(&mut self, info_hash: Option<&str>) -> Vec<String> {
+        let hashes: Vec<String> = match info_hash {
+            Some(hash) => {
+                let hash = hash.to_lowercase();
+                if self.entries.contains_key(&hash) {
+                    vec![hash]
+                } else {
+                    Vec::new()
+                }
+            }
+            None => self.entries.keys().cloned().collect(),
+        };
+        for hash in &hashes {
+            for session in self.entries.remove(hash).unwrap_or_default() {
+                if is_ours(hash, session.pid) {
+                    kill(session.pid);
+                }
+            }
+        }
+        hashes
+    }
+}
+
+/// Load the registry without entries for processes that have exited
+pub fn prune_stale() -> Sessions {
+    let mut sessions = Sessions::load();
+    if sessions.prune() > 0 {
+        let _ = sessions.save();
+    }
+    sessions
+}
+
+/// Record a webtorrent process streaming `magnet` (best effort)
+pub fn record(magnet: &str, pid: Option<u32>, file_idx: Option<u32>) {
+    let (Some(hash), Some(pid)) = (info_hash(magnet), pid) else {
+        return;
+    };
+    let mut sessions = Sessions::load();
+    sessions.prune();
+    sessions.insert(&hash, Session { pid, file_idx });
+    let _ = sessions.save();
+}
+
+/// Forget the `pid` streaming `magnet` once it has exited
+pub fn forget(magnet: &str, pid: Option<u32>) {
+    let (Some(hash), Some(pid)) = (info_hash(magnet), pid) else {
+        return;
+    };
+    let mut sessions = Sessions::load();
+    if sessions.remove(&hash, pid) {
+        let _ = sessions.save();
+    }
+}
+
+/// Stop one session (or all tracked ones) and update the registry
+pub fn stop(info_hash: Option<&str>) -> Vec<String> {
+    let mut sessions = Sessions::load();
+    let stopped = sessions.stop(info_hash);
+    if !stopped.is_empty() {
+        let _ = sessions.save();
+    }
+    stopped
+}
+
+/// Remember that this process started streaming `magnet`
+pub fn mark_started(magnet: &str) {
+    if let Some(hash) = info_hash(magnet) {
+        started().insert(hash);
+    }
+}
+
+/// Stop the streams this process started (see [`mark_started`])
+///
+/// Streams from the CLI or another TUI share the registry and keep running.
+pub fn stop_started() -> Vec<String> {
+    let hashes = std::mem::take(&mut *started());
+    let mut sessions = Sessions::load();
+    let stopped: Vec<String> = hashes
+        .iter()
+        .flat_map(|hash| sessions.stop(Some(hash)))
+        .collect();
+    if !stopped.is_empty() {
+        let _ = sessions.save();
+    }
+    stopped
+}
+
+fn started() -> std::sync::MutexGuard<'static, BTreeSet<String>> {
+    STARTED.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Lowercase info_hash from a `magnet:?xt=urn:btih:<hash>` link
+pub fn info_hash(magnet: &str) -> Option<String> {
+    let start = magnet.find("urn:btih:")? + "urn:btih:".len();
+    let hash = magnet[start..].split('&').next()?;
+    (!hash.is_empty()).then(|| hash.to_lowercase())
+}
+
+/// Whether `pid` is still the webtorrent streaming `hash`
+fn is_ours(hash: &str, pid: u32) -> bool {
+    is_alive(pid)
+        && command_line(pid).is_some_and(|cmd| {
+            let cmd = cmd.to_lowercase();
+            cmd.contains("webtorrent") && cmd.contains(hash)
+        })
+}
+
+/// Whether a process with this pid still exists
+#[cfg(unix)]
+fn is_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // SAFETY: signal 0 only checks that the process exists
+    pid > 0 && unsafe { libc::kill(pid, 0) } == 0
+}
+
+#[cfg(not(unix))]
+fn is_alive(_pid: u32) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn kill(pid: u32) {
+    if let Ok(pid) = libc::pid_t::try_from(pid) {
+        // SAFETY: plain SIGTERM to a pid checked with `is_ours`
+        unsafe {
+            libc::kill(pid, libc::SIGTERM);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn kill(_pid: u32) {}
+
+/// Arguments `pid` was started with, space-separated
+#[cfg(target_os = "linux")]
+fn command_line(pid: u32) -> Option<String> {
+    let raw = std::fs::read(format!("/proc/{pid}/cmdline")).ok()?;
+    Some(String::from_utf8_lossy(&raw).replace('\0', " "))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn command_line(pid: u32) -> Option<String> {
+    let out = std::process::Command::new("ps")
+        .args(["-p", &pid.to_string(), "-o", "command="])
+        .output()
+        .ok()?;
+    out.status
+        .success()
+        .then(|| String::from_utf8_lossy(&out.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[test]
+    fn test_info_hash_from_magnet() {
+        assert_eq!(
+            info_hash("magnet:?xt=urn:btih:ABC123&dn=Movie").as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(info_hash("magnet:?xt=urn:btih:abc").as_deref(), Some("abc"));
+        assert_eq!(info_hash("magnet:?dn=Movie"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_prune_and_targeted_stop() {
+        let path =
+            std::env::temp_dir().join(format!("streamtui-sessions-{}.json", std::process::id()));
+        let mut sessions = Sessions::load_from(path.clone());
+        // Stands in for webtorrent: the hash is on its command line
+        let hash = "5ea5b0a7";
+        let mut stream = Command::new("sh")
+            .args(["-c", "sleep 30; true", "webtorrent", hash])
+            .spawn()
+            .unwrap();
+        let live = Session {
+            pid: stream.id(),
+            file_idx: Some(2),
+        };
+        // Give the fork a moment to exec
+        for _ in 0..100 {
+            if is_ours(hash, live.pid) {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        sessions.insert(hash, live);
+        // Alive, but not a webtorrent (a reused pid, say)
+        sessions.insert(
+            "reused",
+            Session {
+                pid: std::process::id(),
+                file_idx: None,
+            },
+        );
+        // Not a real process
+        sessions.insert(
+            "gone",
+            Session {
+                pid: 0x7fff_fff0,
+                file_idx: None,
+            },
+        );
+        assert_eq!(sessions.prune(), 2);
+        sessions.save().unwrap();
+
+        let mut loaded = Sessions::load_from(path.clone());
+        assert_eq!(loaded.entries.get(hash), Some(&vec![live]));
+
+        // Unknown hashes are left alone (and nothing gets killed)
+        assert!(loaded.stop(Some("other")).is_empty());
+        assert_eq!(loaded.entries.len(), 1);
+
+        assert_eq!(loaded.stop(Some(hash)), vec![hash.to_string()]);
+        assert!(stream.wait().unwrap().code().is_none()); // killed by a signal
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_stop_spares_reused_pid() {
+        let mut sessions = Sessions::default();
+        sessions.insert(
+            "abc123",
+            Session {
+                pid: std::process::id(),
+                file_idx: None,
+            },
+        );
+        // Our pid isn't a webtorrent for abc123, so we survive the stop
+        assert_eq!(sessions.stop(None), vec!["abc123".to_string()]);
+        assert!(sessions.is_empty());
+    }
+
+    #[test]
+    fn test_started_hashes_are_per_process() {
+        mark_started("magnet:?xt=urn:btih:5EA5DEADBEEF&dn=Ours");
+        mark_started("magnet:?dn=NoHash");
+        assert!(started().contains("5ea5deadbeef"));
+        assert_eq!(started().len(), 1);
+
+        // Not in the registry, so nothing is killed, but it's forgotten
+        assert!(stop_started().is_empty());
+        assert!(started().is_empty());
+    }
+
+    #[test]
+    fn test_two_files_of_one_torrent() {
+        let mut sessions = Sessions::default();
+        let first = Session {
+            pid: 0x7fff_fff1,
+            file_idx: Some(1),
+        };
+        let second = Session {
+            pid: 0x7fff_fff2,
+            file_idx: Some(2),
+        };
+        sessions.insert("PACK", first);
+        sessions.insert("pack", second);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions.entries["pack"], vec![first, second]);
+
+        // Forgetting one process keeps the other stoppable
+        assert!(sessions.remove("pack", first.pid));
+        assert!(!sessions.remove("pack", first.pid));
+        assert_eq!(sessions.entries["pack"], vec![second]);
+        assert!(sessions.remove("pack", second.pid));
+        assert!(sessions.is_empty());
+    }
+}
@@ -1,23 +1,86 @@
 //! Stremio Subtitle Client
 //!
 //! Free subtitle search using Stremio's public addon endpoint.
-//! No API key required - uses Stremio's OpenSubtitles v3 addon.
+//! No API key required - uses Stremio's OpenSubtitles v3 addon. The
+//! OpenSubtitles REST API can be searched instead of it or as well
+//! (`subtitle_provider`, see [`SubtitleProvider`]).
 //!
 //! Handles SRT to WebVTT conversion for Chromecast.
 //! Caches downloaded subtitles in ~/.cache/streamtui/subtitles/
 
-use crate::models::{SubFormat, SubtitleResult};
+use crate::config::Config;
+use crate::models::{Cue, SubFormat, SubtitleFile, SubtitleResult};
+use crate::stream::opensubtitles::{self, OpenSubtitlesClient};
 use anyhow::{anyhow, Result};
+use encoding_rs::{Encoding, WINDOWS_1252};
 use serde::Deserialize;
+use std::future::Future;
 use std::path::PathBuf;
 
+/// Number of cues shown in the subtitle preview
+pub const PREVIEW_CUES: usize = 15;
+
+/// A subtitle search backend
+pub trait SubtitleProvider {
+    /// Subtitles for a movie, optionally only in `language` (e.g. "eng,spa")
+    fn search(
+        &self,
+        imdb_id: &str,
+        language: Option<&str>,
+    ) -> impl Future<Output = Result<Vec<SubtitleResult>>> + Send;
+
+    /// Subtitles for a TV episode
+    fn search_episode(
+        &self,
+        imdb_id: &str,
+        season: u16,
+        episode: u16,
+        language: Option<&str>,
+    ) -> impl Future<Output = Result<Vec<SubtitleResult>>> + Send;
+}
+
+/// Which backend(s) `SubtitleClient` searches (config `subtitle_provider`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProviderChoice {
+    /// Stremio's OpenSubtitles addon (free, no key)
+    #[default]
+    Stremio,
+    /// The OpenSubtitles REST API (needs `opensubtitles_api_key`)
+    OpenSubtitles,
+    /// Both, merged
+    Both,
+}
+
+impl ProviderChoice {
+    /// Parse a config value (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "stremio" => Some(Self::Stremio),
+            "opensubtitles" => Some(Self::OpenSubtitles),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+}
+
 /// Subtitle client using Stremio's free public endpoint
 ///
-/// Uses Stremio's OpenSubtitles v3 addon - no API key required!
+/// Uses Stremio's OpenSubtitles v3 addon - no API key required! With an
+/// OpenSubtitles API key it can search the REST API instead, or both.
 pub struct SubtitleClient {
-    base_url: String,
+    stremio: StremioSubtitles,
+    opensubtitles: Option<OpenSubtitlesClient>,
+    provider: ProviderChoice,
     client: reqwest::Client,
     cache_dir: PathBuf,
+    /// Charset forced with `--subtitle-encoding` / `subtitle_encoding`
+    charset: Option<&'static Encoding>,
+}
+
+/// Stremio's OpenSubtitles v3 addon
+pub struct StremioSubtitles {
+    base_url: String,
+    client: reqwest::Client,
 }
 
 /// Stremio subtitle response
@@ -40,16 +103,7 @@ struct StremioSubtitle {
 impl SubtitleClient {
     /// Create a new subtitle client (free, no API key)
     pub fn new() -> Self {
-        let cache_dir = dirs::cache_dir()
-            .unwrap_or_else(|| PathBuf::from("/tmp"))
-            .join("streamtui")
-            .join("subtitles");
-
-        Self {
-            base_url: "https://opensubtitles-v3.strem.io".to_string(),
-            client: reqwest::Client::new(),
-            cache_dir,
-        }
+        Self::with_base_url("https://opensubtitles-v3.strem.io")
     }
 
     /// Create with custom base URL (for testing)
@@ -58,12 +112,60 @@ impl SubtitleClient {
             .unwrap_or_else(|| PathBuf::from("/tmp"))
             .join("streamtui")
             .join("subtitles");
+        let client = crate::api::http_client(crate::api::DEFAULT_TIMEOUT);
 
         Self {
-            base_url: base_url.into(),
-            client: reqwest::Client::new(),
+            stremio: StremioSubtitles {
+                base_url: base_url.into(),
+                client: client.clone(),
+            },
+            opensubtitles: None,
+            provider: ProviderChoice::Stremio,
+            client,
             cache_dir,
+            charset: None,
+        }
+    }
+
+    /// Read downloaded subtitles as `charset` instead of detecting it
+    pub fn with_charset(mut self, charset: Option<&'static Encoding>) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// Create a client using the config's provider, API key and request timeout
+    pub fn from_config(config: &Config) -> Self {
+        let mut client = Self::new().with_provider(config.subtitle_provider());
+        if let Some(key) = config
+            .opensubtitles_api_key
+            .as_deref()
+            .filter(|k| !k.is_empty())
+        {
+            client = client.with_opensubtitles(OpenSubtitlesClient::new(key));
         }
+        client
+            .with_timeout(config.request_timeout())
+            .with_charset(config.subtitle_encoding())
+    }
+
+    /// Give up on a request after `timeout`
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.client = crate::api::http_client(timeout);
+        self.stremio.client = self.client.clone();
+        self.opensubtitles = self.opensubtitles.map(|os| os.with_timeout(timeout));
+        self
+    }
+
+    /// Use this OpenSubtitles client for searches and downloads
+    pub fn with_opensubtitles(mut self, client: OpenSubtitlesClient) -> Self {
+        self.opensubtitles = Some(client);
+        self
+    }
+
+    /// Search these backend(s)
+    pub fn with_provider(mut self, provider: ProviderChoice) -> Self {
+        self.provider = provider;
+        self
     }
 
     /// Search for movie subtitles by IMDB ID
@@ -76,9 +178,12 @@ impl SubtitleClient {
         imdb_id: &str,
         language: Option<&str>,
     ) -> Result<Vec<SubtitleResult>> {
-        let imdb = normalize_imdb_id(imdb_id);
-        let url = format!("{}/subtitles/movie/{}.json", self.base_url, imdb);
-        self.fetch_subtitles(&url, language).await
+        let opensubtitles = self.opensubtitles_for_search()?;
+        let stremio = self
+            .searches_stremio()
+            .then(|| self.stremio.search(imdb_id, language));
+        let opensubtitles = opensubtitles.map(|os| os.search(imdb_id, language));
+        combine(stremio, opensubtitles).await
     }
 
     /// Search for TV episode subtitles
@@ -95,64 +200,46 @@ impl SubtitleClient {
         episode: u16,
         language: Option<&str>,
     ) -> Result<Vec<SubtitleResult>> {
-        let imdb = normalize_imdb_id(imdb_id);
-        let url = format!(
-            "{}/subtitles/series/{}:{}:{}.json",
-            self.base_url, imdb, season, episode
-        );
-        self.fetch_subtitles(&url, language).await
+        let opensubtitles = self.opensubtitles_for_search()?;
+        let stremio = self.searches_stremio().then(|| {
+            self.stremio
+                .search_episode(imdb_id, season, episode, language)
+        });
+        let opensubtitles =
+            opensubtitles.map(|os| os.search_episode(imdb_id, season, episode, language));
+        combine(stremio, opensubtitles).await
     }
 
-    /// Fetch and parse subtitles from Stremio endpoint
-    async fn fetch_subtitles(
-        &self,
-        url: &str,
-        language: Option<&str>,
-    ) -> Result<Vec<SubtitleResult>> {
-        let response = self.client.get(url).send().await?;
+    fn searches_stremio(&self) -> bool {
+        self.provider != ProviderChoice::OpenSubtitles
+    }
 
-        if !response.status().is_success() {
-            return Err(anyhow!("Stremio API error: {}", response.status()));
+    /// The OpenSubtitles client, if the provider choice searches it
+    ///
+    /// "both" without an API key quietly falls back to Stremio alone.
+    fn opensubtitles_for_search(&self) -> Result<Option<&OpenSubtitlesClient>> {
+        match (self.provider, &self.opensubtitles) {
+            (ProviderChoice::Stremio, _) | (ProviderChoice::Both, None) => Ok(None),
+            (_, Some(client)) => Ok(Some(client)),
+            (ProviderChoice::OpenSubtitles, None) => Err(anyhow!(
+                "OpenSubtitles needs opensubtitles_api_key in the config"
+            )),
         }
+    }
 
-        let api_response: StremioResponse = response.json().await?;
-
-        // Convert and optionally filter by language(s)
-        // Supports comma-separated languages like "eng,spa"
-        let langs: Vec<&str> = language
-            .map(|l| l.split(',').map(|s| s.trim()).collect())
-            .unwrap_or_default();
-
-        let results: Vec<SubtitleResult> = api_response
-            .subtitles
-            .into_iter()
-            .filter(|s| {
-                langs.is_empty() || langs.iter().any(|lang| {
-                    s.lang.eq_ignore_ascii_case(lang)
-                        || s.lang.starts_with(lang)
-                        || lang.starts_with(&s.lang)
-                })
-            })
-            .map(|s| {
-                // Extract release name from subtitle ID (format: "id|release_name" or just use ID)
-                let release = extract_release_from_id(&s.id);
-                SubtitleResult {
-                    id: s.id.clone(),
-                    url: s.url,
-                    language: s.lang.clone(),
-                    language_name: lang_code_to_name(&s.lang),
-                    release,
-                    fps: None,
-                    format: SubFormat::Srt,
-                    downloads: 0, // Stremio API doesn't provide download counts
-                    from_trusted: true,
-                    hearing_impaired: false,
-                    ai_translated: false,
-                }
-            })
-            .collect();
-
-        Ok(results)
+    /// URL a subtitle can be downloaded from
+    ///
+    /// OpenSubtitles results only name a file until a download link is
+    /// requested; every other URL is returned as is.
+    pub async fn resolve_url(&self, url: &str) -> Result<String> {
+        let Some(file_id) = opensubtitles::file_id(url) else {
+            return Ok(url.to_string());
+        };
+        let client = self
+            .opensubtitles
+            .as_ref()
+            .ok_or_else(|| anyhow!("OpenSubtitles needs opensubtitles_api_key in the config"))?;
+        client.download_link(file_id).await
     }
 
     /// Download subtitle by ID - searches for the subtitle and downloads it
@@ -193,20 +280,9 @@ impl SubtitleClient {
             return Ok(content);
         }
 
-        // Download from Stremio
-        let response = self.client.get(&subtitle.url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(anyhow!(
-                "Failed to download subtitle: {}",
-                response.status()
-            ));
-        }
-
-        let srt_content = response.text().await?;
-
-        // Convert to WebVTT
-        let webvtt_content = Self::srt_to_webvtt(&srt_content);
+        // Download from Stremio (or the link OpenSubtitles hands out)
+        let (content, format) = self.fetch_subtitle(&subtitle.url).await?;
+        let webvtt_content = to_webvtt(content, format);
 
         // Cache the result
         if let Some(parent) = cache_path.parent() {
@@ -217,6 +293,50 @@ impl SubtitleClient {
         Ok(webvtt_content)
     }
 
+    /// Download a subtitle and parse its first [`PREVIEW_CUES`] cues
+    ///
+    /// Nothing is written to disk or the cache.
+    pub async fn fetch_preview(&self, url: &str) -> Result<Vec<Cue>> {
+        let (content, format) = self.fetch_subtitle(url).await?;
+        let content = match format {
+            SubFormat::Ass => SubtitleFile::ass_to_webvtt(&content),
+            _ => content,
+        };
+        Ok(Cue::parse(&content, PREVIEW_CUES))
+    }
+
+    /// Fetch a subtitle as UTF-8 text, along with its format
+    ///
+    /// Accepts anything a `SubtitleResult` URL can be: http(s), an
+    /// `opensubtitles:` file, or a `file://` path picked locally. Nothing is
+    /// cached; the text is left in its original format.
+    pub async fn fetch_subtitle(&self, url: &str) -> Result<(String, SubFormat)> {
+        let url = self.resolve_url(url).await?;
+        let content = match url.strip_prefix("file://") {
+            Some(path) => {
+                decode_subtitle_bytes_as(std::fs::read(path)?, None, self.charset).await?
+            }
+            None => self.fetch_text(&url).await?,
+        };
+        Ok((content, format_from_url(url.trim_end_matches(".gz"))))
+    }
+
+    /// GET a subtitle file and decode it with [`decode_subtitle_bytes`]
+    async fn fetch_text(&self, url: &str) -> Result<String> {
+        let response = self.client.get(url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Failed to download subtitle: {}",
+                response.status()
+            ));
+        }
+
+        let content_encoding = content_encoding(&response);
+        let bytes = response.bytes().await?.to_vec();
+        decode_subtitle_bytes_as(bytes, content_encoding.as_deref(), self.charset).await
+    }
+
     /// Get the cache path for a subtitle
     fn get_cache_path(&self, subtitle: &SubtitleResult) -> PathBuf {
         self.cache_dir
@@ -254,6 +374,129 @@ impl Default for SubtitleClient {
     }
 }
 
+impl SubtitleProvider for StremioSubtitles {
+    async fn search(&self, imdb_id: &str, language: Option<&str>) -> Result<Vec<SubtitleResult>> {
+        let imdb = normalize_imdb_id(imdb_id);
+        let url = format!("{}/subtitles/movie/{}.json", self.base_url, imdb);
+        self.fetch_subtitles(&url, language).await
+    }
+
+    async fn search_episode(
+        &self,
+        imdb_id: &str,
+        season: u16,
+        episode: u16,
+        language: Option<&str>,
+    ) -> Result<Vec<SubtitleResult>> {
+        let imdb = normalize_imdb_id(imdb_id);
+        let url = format!(
+            "{}/subtitles/series/{}:{}:{}.json",
+            self.base_url, imdb, season, episode
+        );
+        self.fetch_subtitles(&url, language).await
+    }
+}
+
+impl StremioSubtitles {
+    /// Fetch and parse subtitles from Stremio endpoint
+    async fn fetch_subtitles(
+        &self,
+        url: &str,
+        language: Option<&str>,
+    ) -> Result<Vec<SubtitleResult>> {
+        let response = self.client.get(url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Stremio API error: {}", response.status()));
+        }
+
+        let api_response: StremioResponse = response.json().await?;
+
+        // Convert and optionally filter by language(s)
+        // Supports comma-separated languages like "eng,spa"
+        let langs: Vec<&str> = language
+            .map(|l| l.split(',').map(|s| s.trim()).collect())
+            .unwrap_or_default();
+
+        let results: Vec<SubtitleResult> = api_response
+            .subtitles
+            .into_iter()
+            .filter(|s| {
+                langs.is_empty()
+                    || langs.iter().any(|lang| {
+                        s.lang.eq_ignore_ascii_case(lang)
+                            || s.lang.starts_with(lang)
+                            || lang.starts_with(&s.lang)
+                    })
+            })
+            .map(|s| {
+                // Extract release name from subtitle ID (format: "id|release_name" or just use ID)
+                let release = extract_release_from_id(&s.id);
+                // Stremio only sends the code; the name comes from our table
+                let mut result = SubtitleResult {
+                    id: s.id.clone(),
+                    url: s.url,
+                    language: s.lang.clone(),
+                    language_name: String::new(),
+                    release,
+                    fps: None,
+                    format: SubFormat::Srt,
+                    downloads: 0, // Stremio API doesn't provide download counts
+                    from_trusted: true,
+                    hearing_impaired: false,
+                    ai_translated: false,
+                };
+                result.fill_language_name();
+                result
+            })
+            .collect();
+
+        Ok(results)
+    }
+}
+
+/// Results from whichever providers ran, merged
+///
+/// One provider failing doesn't hide the other's results. Merged lists drop
+/// repeats (same id or URL) and are ordered by `trust_score`.
+async fn combine(
+    stremio: Option<impl Future<Output = Result<Vec<SubtitleResult>>>>,
+    opensubtitles: Option<impl Future<Output = Result<Vec<SubtitleResult>>>>,
+) -> Result<Vec<SubtitleResult>> {
+    async fn run<F: Future<Output = Result<Vec<SubtitleResult>>>>(
+        f: Option<F>,
+    ) -> Option<Result<Vec<SubtitleResult>>> {
+        match f {
+            Some(f) => Some(f.await),
+            None => None,
+        }
+    }
+    match tokio::join!(run(stremio), run(opensubtitles)) {
+        (Some(only), None) | (None, Some(only)) => only,
+        (Some(Err(e)), Some(Err(_))) => Err(e),
+        (Some(Ok(subs)), Some(Err(_))) | (Some(Err(_)), Some(Ok(subs))) => Ok(subs),
+        (Some(Ok(mut subs)), Some(Ok(more))) => {
+            for sub in more {
+                if !subs.iter().any(|s| s.id == sub.id || s.url == sub.url) {
+                    subs.push(sub);
+                }
+            }
+            subs.sort_by_key(|s| std::cmp::Reverse(s.trust_score()));
+            Ok(subs)
+        }
+        (None, None) => Ok(Vec::new()),
+    }
+}
+
+/// Convert subtitle text of `format` to WebVTT (Chromecast only plays WebVTT)
+pub fn to_webvtt(text: String, format: SubFormat) -> String {
+    match format {
+        SubFormat::Ass => SubtitleFile::ass_to_webvtt(&text),
+        SubFormat::WebVtt => text,
+        _ => SubtitleClient::srt_to_webvtt(&text),
+    }
+}
+
 /// Normalize IMDB ID to have "tt" prefix
 fn normalize_imdb_id(imdb_id: &str) -> String {
     if imdb_id.starts_with("tt") {
@@ -263,6 +506,106 @@ fn normalize_imdb_id(imdb_id: &str) -> String {
     }
 }
 
+/// The `Content-Encoding` header of a response, if any
+pub fn content_encoding(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Turn a downloaded subtitle into UTF-8 text
+///
+/// OpenSubtitles often serves gzip (detected by the `0x1f 0x8b` magic bytes or
+/// `Content-Encoding: gzip`), and older subs, Spanish ones especially, are
+/// Latin-1/Windows-1252 rather than UTF-8. A UTF-8 byte order mark is dropped.
+pub async fn decode_subtitle_bytes(
+    bytes: Vec<u8>,
+    content_encoding: Option<&str>,
+) -> Result<String> {
+    decode_subtitle_bytes_as(bytes, content_encoding, None).await
+}
+
+/// [`decode_subtitle_bytes`], but read as `charset` when one is given
+/// (`--subtitle-encoding`) instead of guessing between UTF-8 and Windows-1252
+pub async fn decode_subtitle_bytes_as(
+    bytes: Vec<u8>,
+    content_encoding: Option<&str>,
+    charset: Option<&'static Encoding>,
+) -> Result<String> {
+    let has_magic = bytes.starts_with(&[0x1f, 0x8b]);
+    let header_gzip = content_encoding.is_some_and(|e| e.trim().eq_ignore_ascii_case("gzip"));
+
+    let bytes = if has_magic {
+        gunzip(bytes).await?
+    } else if header_gzip {
+        // The header can be stale (e.g. a proxy already decompressed the body)
+        gunzip(bytes.clone()).await.unwrap_or(bytes)
+    } else {
+        bytes
+    };
+
+    let text = match charset {
+        Some(charset) => charset.decode_without_bom_handling(&bytes).0.into_owned(),
+        None => match String::from_utf8(bytes) {
+            Ok(text) => text,
+            // Windows-1252 is a superset of Latin-1 for printable text
+            Err(e) => WINDOWS_1252
+                .decode_without_bom_handling(e.as_bytes())
+                .0
+                .into_owned(),
+        },
+    };
+    Ok(text
+        .strip_prefix('\u{feff}')
+        .map(str::to_string)
+        .unwrap_or(text))
+}
+
+/// Decompress gzip data with the system `gzip`
+async fn gunzip(data: Vec<u8>) -> Result<Vec<u8>> {
+    use std::process::Stdio;
+    use tokio::io::AsyncWriteExt;
+
+    let mut child = tokio::process::Command::new("gzip")
+        .arg("-dc")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| anyhow!("Failed to run gzip: {}", e))?;
+
+    // Feed stdin from a separate task so a full stdout pipe can't deadlock us
+    let mut stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("gzip stdin unavailable"))?;
+    let writer = tokio::spawn(async move { stdin.write_all(&data).await });
+
+    let output = child.wait_with_output().await?;
+    writer.await??;
+
+    // gzip exits 2 on warnings like trailing garbage but still decompresses
+    if output.stdout.is_empty() && !output.status.success() {
+        return Err(anyhow!("Failed to decompress subtitle"));
+    }
+    Ok(output.stdout)
+}
+
+/// Guess the subtitle format from the download URL's file extension
+///
+/// URLs without an extension are treated as SRT, which is what Stremio serves.
+pub fn format_from_url(url: &str) -> SubFormat {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let file = path.rsplit('/').next().unwrap_or(path);
+    match file.rsplit_once('.') {
+        Some((_, ext)) => SubFormat::from_extension(ext),
+        None => SubFormat::Srt,
+    }
+}
+
 /// Extract release name from Stremio subtitle ID
 /// Stremio IDs can be: "12345678" (numeric) or contain embedded release info
 fn extract_release_from_id(id: &str) -> String {
@@ -271,12 +614,7 @@ fn extract_release_from_id(id: &str) -> String {
         let release = &id[idx + 1..];
         if !release.is_empty() {
             // Clean up the release name (replace dots with spaces, truncate)
-            return release
-                .replace('.', " ")
-                .trim()
-                .chars()
-                .take(50)
-                .collect();
+            return release.replace('.', " ").trim().chars().take(50).collect();
         }
     }
 
@@ -289,44 +627,3 @@ fn extract_release_from_id(id: &str) -> String {
     // Fallback to "OpenSubtitles" for pure numeric IDs
     "OpenSubtitles".to_string()
 }
-
-/// Convert 3-letter language code to full name
-fn lang_code_to_name(code: &str) -> String {
-    match code.to_lowercase().as_str() {
-        "eng" => "English".to_string(),
-        "spa" => "Spanish".to_string(),
-        "fre" => "French".to_string(),
-        "ger" => "German".to_string(),
-        "ita" => "Italian".to_string(),
-        "por" | "pob" => "Portuguese".to_string(),
-        "rus" => "Russian".to_string(),
-        "jpn" => "Japanese".to_string(),
-        "kor" => "Korean".to_string(),
-        "chi" | "zho" => "Chinese".to_string(),
-        "ara" => "Arabic".to_string(),
-        "hin" => "Hindi".to_string(),
-        "dut" | "nld" => "Dutch".to_string(),
-        "pol" => "Polish".to_string(),
-        "tur" => "Turkish".to_string(),
-        "swe" => "Swedish".to_string(),
-        "nor" => "Norwegian".to_string(),
-        "dan" => "Danish".to_string(),
-        "fin" => "Finnish".to_string(),
-        "gre" | "ell" => "Greek".to_string(),
-        "heb" => "Hebrew".to_string(),
-        "hun" => "Hungarian".to_string(),
-        "cze" | "ces" => "Czech".to_string(),
-        "rum" | "ron" => "Romanian".to_string(),
-        "bul" => "Bulgarian".to_string(),
-        "hrv" => "Croatian".to_string(),
-        "slv" => "Slovenian".to_string(),
-        "srp" => "Serbian".to_string(),
-        "ukr" => "Ukrainian".to_string(),
-        "vie" => "Vietnamese".to_string(),
-        "tha" => "Thai".to_string(),
-        "ind" => "Indonesian".to_string(),
-        "may" | "msa" => "Malay".to_string(),
-        "ice" | "isl" => "Icelandic".to_string(),
-        _ => code.to_uppercase(),
-    }
-}
@@ -4,6 +4,9 @@
 //! Provides progress updates and stream URL for casting.
 
 use anyhow::Result;
+use regex::Regex;
+
+use crate::models::{StreamSource, TorrentState};
 
 /// Torrent streaming manager
 pub struct TorrentManager {
@@ -61,3 +64,91 @@ impl Default for TorrentManager {
         Self::new()
     }
 }
+
+/// Connection phase reported by the latest lines of a webtorrent log
+///
+/// Understands "fetching torrent metadata from N peers", "Connected to N
+/// peers", "downloaded X of Y" (and the "Peers:"/"Downloaded: X/Y" stats
+/// lines), and "Server running at:". Once the server is up the state stays
+/// `Streaming`. Returns None until webtorrent has reported anything.
+pub fn parse_log_state(log: &str) -> Option<TorrentState> {
+    let connected = Regex::new(r"(?i)connected to (\d+) peers?").ok()?;
+    let stats_peers = Regex::new(r"Peers:\s*(\d+)").ok()?;
+    let downloaded =
+        Regex::new(r"(?i)downloaded:?\s*([\d.]+\s*[KMG]?B)\s*(?:of|/)\s*([\d.]+\s*[KMG]?B)")
+            .ok()?;
+
+    let mut state = None;
+    let mut peers = 0;
+    for line in log.lines().map(str::trim) {
+        if line.contains("Server running at:") {
+            return Some(TorrentState::Streaming);
+        }
+        if let Some(rest) = line.strip_prefix("fetching torrent metadata from ") {
+            if let Some(n) = rest.strip_suffix(" peers").and_then(|n| n.parse().ok()) {
+                peers = n;
+                state = Some(TorrentState::FetchingMetadata { peers });
+            }
+        }
+        if let Some(caps) = connected.captures(line) {
+            peers = caps[1].parse().unwrap_or(peers);
+            state = Some(TorrentState::Connecting { peers });
+        }
+        if let Some(caps) = stats_peers.captures(line) {
+            peers = caps[1].parse().unwrap_or(peers);
+        }
+        if let Some(caps) = downloaded.captures(line) {
+            let done = StreamSource::parse_size(&caps[1]).unwrap_or(0);
+            let total = StreamSource::parse_size(&caps[2]).unwrap_or(0);
+            if total > 0 {
+                let progress = (done as f64 / total as f64 * 100.0).min(100.0) as u8;
+                state = Some(TorrentState::Buffering { peers, progress });
+            }
+        } else if let Some(TorrentState::Buffering { progress, .. }) = state {
+            // Keep the percentage while the peer count moves on
+            state = Some(TorrentState::Buffering { peers, progress });
+        }
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_state_phases() {
+        assert_eq!(parse_log_state(""), None);
+        assert_eq!(
+            parse_log_state("fetching torrent metadata from 3 peers\n"),
+            Some(TorrentState::FetchingMetadata { peers: 3 })
+        );
+        assert_eq!(
+            parse_log_state("fetching torrent metadata from 3 peers\nConnected to 12 peers\n"),
+            Some(TorrentState::Connecting { peers: 12 })
+        );
+        assert_eq!(
+            parse_log_state("Connected to 12 peers\nDownloaded 100 MB of 1.0 GB\n"),
+            Some(TorrentState::Buffering {
+                peers: 12,
+                progress: 9
+            })
+        );
+        assert_eq!(
+            parse_log_state("Connected to 2 peers\nServer running at: http://localhost:8000/0\nConnected to 1 peers\n"),
+            Some(TorrentState::Streaming)
+        );
+    }
+
+    #[test]
+    fn test_parse_log_state_stats_lines() {
+        let log = "Speed: 2.0 MB/s Downloaded: 1.0 GB/4.0 GB Uploaded: 0 B Peers: 7/12\n";
+        assert_eq!(
+            parse_log_state(log),
+            Some(TorrentState::Buffering {
+                peers: 7,
+                progress: 25
+            })
+        );
+    }
+}
@@ -181,7 +181,7 @@ impl BrowserView {
     }
 
     /// Render the browser view
-    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Calculate visible height (minus borders)
         let visible_height = area.height.saturating_sub(2) as usize;
 
@@ -190,7 +190,7 @@ impl BrowserView {
 
         // Handle empty state
         if self.items.is_empty() {
-            self.render_empty(frame, area);
+            self.render_empty(frame, area, theme);
             return;
         }
 
@@ -201,14 +201,14 @@ impl BrowserView {
             .enumerate()
             .skip(self.offset)
             .take(visible_height)
-            .map(|(i, item)| self.render_item(i, item))
+            .map(|(i, item)| self.render_item(i, item, theme))
             .collect();
 
         // Select border style based on focus
         let border_style = if self.focused {
-            Theme::border_focused()
+            theme.border_focused()
         } else {
-            Theme::border()
+            theme.border()
         };
 
         let title = format!(
@@ -224,16 +224,16 @@ impl BrowserView {
                     .borders(Borders::ALL)
                     .border_style(border_style)
                     .border_type(ratatui::widgets::BorderType::Rounded)
-                    .title(Span::styled(title, Theme::title()))
+                    .title(Span::styled(title, theme.title()))
                     .title_alignment(Alignment::Left),
             )
-            .style(Theme::text());
+            .style(theme.text());
 
         frame.render_widget(list, area);
     }
 
     /// Render a single item
-    fn render_item(&self, index: usize, item: &SearchResult) -> ListItem<'static> {
+    fn render_item(&self, index: usize, item: &SearchResult, theme: &Theme) -> ListItem<'static> {
         let is_selected = index == self.selected;
 
         // Format: ▸ Title (Year)                    [TYPE] ★ 8.5
@@ -251,40 +251,40 @@ impl BrowserView {
             Span::styled(
                 marker.to_string(),
                 if is_selected {
-                    Theme::accent()
+                    theme.accent()
                 } else {
-                    Theme::dimmed()
+                    theme.dimmed()
                 },
             ),
             Span::styled(
                 item.title.clone(),
                 if is_selected {
-                    Theme::list_item_selected()
+                    theme.list_item_selected()
                 } else {
-                    Theme::text()
+                    theme.text()
                 },
             ),
             Span::styled(
                 year_str,
                 if is_selected {
-                    Theme::accent()
+                    theme.accent()
                 } else {
-                    Theme::year()
+                    theme.year()
                 },
             ),
             Span::raw(" "),
             Span::styled(
                 format!("[{}]", type_str),
                 if is_selected {
-                    Theme::accent()
+                    theme.accent()
                 } else {
-                    Theme::secondary()
+                    theme.secondary()
                 },
             ),
             Span::raw(" "),
             Span::styled(
                 format!("★ {:.1}", item.vote_average),
-                Self::rating_style(item.vote_average, is_selected),
+                Self::rating_style(item.vote_average, is_selected, theme),
             ),
         ]);
 
@@ -292,37 +292,37 @@ impl BrowserView {
     }
 
     /// Get style for rating based on value
-    fn rating_style(rating: f32, is_selected: bool) -> Style {
+    fn rating_style(rating: f32, is_selected: bool, theme: &Theme) -> Style {
         if is_selected {
-            Theme::accent()
+            theme.accent()
         } else if rating >= 7.5 {
-            Theme::success()
+            theme.success()
         } else if rating >= 6.0 {
-            Theme::warning()
+            theme.warning()
         } else if rating >= 4.0 {
-            Theme::dimmed()
+            theme.dimmed()
         } else {
-            Theme::error()
+            theme.error()
         }
     }
 
     /// Render empty state
-    fn render_empty(&self, frame: &mut Frame, area: Rect) {
+    fn render_empty(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let border_style = if self.focused {
-            Theme::border_focused()
+            theme.border_focused()
         } else {
-            Theme::border()
+            theme.border()
         };
 
         let empty_text = Paragraph::new("No content to display")
-            .style(Theme::dimmed())
+            .style(theme.dimmed())
             .alignment(Alignment::Center)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_style(border_style)
                     .border_type(ratatui::widgets::BorderType::Rounded)
-                    .title(Span::styled(format!(" {} ", self.title), Theme::title())),
+                    .title(Span::styled(format!(" {} ", self.title), theme.title())),
             );
 
         frame.render_widget(empty_text, area);
@@ -382,7 +382,7 @@ impl SourceBrowserView {
     }
 
     /// Render the source browser
-    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let visible_height = area.height.saturating_sub(2) as usize;
 
         // Adjust offset
@@ -396,14 +396,14 @@ impl SourceBrowserView {
 
         if self.items.is_empty() {
             let empty = Paragraph::new("No sources available")
-                .style(Theme::dimmed())
+                .style(theme.dimmed())
                 .alignment(Alignment::Center)
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Theme::border())
+                        .border_style(theme.border())
                         .border_type(ratatui::widgets::BorderType::Rounded)
-                        .title(Span::styled(" SOURCES ", Theme::title())),
+                        .title(Span::styled(" SOURCES ", theme.title())),
                 );
             frame.render_widget(empty, area);
             return;
@@ -415,13 +415,13 @@ impl SourceBrowserView {
             .enumerate()
             .skip(self.offset)
             .take(visible_height)
-            .map(|(i, source)| self.render_source_item(i, source))
+            .map(|(i, source)| self.render_source_item(i, source, theme))
             .collect();
 
         let border_style = if self.focused {
-            Theme::border_focused()
+            theme.border_focused()
         } else {
-            Theme::border()
+            theme.border()
         };
 
         let title = format!(" SOURCES ({}/{}) ", self.selected + 1, self.items.len());
@@ -431,7 +431,7 @@ impl SourceBrowserView {
                 .borders(Borders::ALL)
                 .border_style(border_style)
                 .border_type(ratatui::widgets::BorderType::Rounded)
-                .title(Span::styled(title, Theme::title())),
+                .title(Span::styled(title, theme.title())),
         );
 
         frame.render_widget(list, area);
@@ -439,7 +439,12 @@ impl SourceBrowserView {
 
     /// Render a single source item
     /// Format: [1] 1080p BluRay x264      4.2 GB  👤142
-    fn render_source_item(&self, index: usize, source: &StreamSource) -> ListItem<'static> {
+    fn render_source_item(
+        &self,
+        index: usize,
+        source: &StreamSource,
+        theme: &Theme,
+    ) -> ListItem<'static> {
         let is_selected = index == self.selected;
         let hotkey = if index < 9 {
             format!("[{}] ", index + 1)
@@ -447,8 +452,8 @@ impl SourceBrowserView {
             "    ".to_string()
         };
 
-        let quality_style = Self::quality_style(&source.quality, is_selected);
-        let seeds_style = Self::seeds_style(source.seeds, is_selected);
+        let quality_style = Self::quality_style(&source.quality, is_selected, theme);
+        let seeds_style = Self::seeds_style(source.seeds, is_selected, theme);
 
         // Truncate title to first line/reasonable length
         let title_display: String = source
@@ -464,27 +469,27 @@ impl SourceBrowserView {
             Span::styled(
                 hotkey,
                 if is_selected {
-                    Theme::accent()
+                    theme.accent()
                 } else {
-                    Theme::keybind()
+                    theme.keybind()
                 },
             ),
             Span::styled(format!("{:<6}", source.quality.to_string()), quality_style),
             Span::styled(
                 title_display,
                 if is_selected {
-                    Theme::list_item_selected()
+                    theme.list_item_selected()
                 } else {
-                    Theme::text()
+                    theme.text()
                 },
             ),
             Span::raw(" "),
             Span::styled(
                 format!("{:>8}", source.format_size()),
                 if is_selected {
-                    Theme::accent()
+                    theme.accent()
                 } else {
-                    Theme::file_size()
+                    theme.file_size()
                 },
             ),
             Span::raw(" "),
@@ -495,29 +500,29 @@ impl SourceBrowserView {
     }
 
     /// Style for quality badge
-    fn quality_style(quality: &Quality, is_selected: bool) -> Style {
+    fn quality_style(quality: &Quality, is_selected: bool, theme: &Theme) -> Style {
         if is_selected {
-            return Theme::accent();
+            return theme.accent();
         }
         match quality {
-            Quality::UHD4K => Theme::quality_4k(),
-            Quality::FHD1080p => Theme::quality_1080p(),
-            Quality::HD720p => Theme::quality_720p(),
-            Quality::SD480p | Quality::Unknown => Theme::quality_sd(),
+            Quality::UHD4K => theme.quality_4k(),
+            Quality::FHD1080p => theme.quality_1080p(),
+            Quality::HD720p => theme.quality_720p(),
+            Quality::SD480p | Quality::Unknown => theme.quality_sd(),
         }
     }
 
     /// Style for seed count
-    fn seeds_style(seeds: u32, is_selected: bool) -> Style {
+    fn seeds_style(seeds: u32, is_selected: bool, theme: &Theme) -> Style {
         if is_selected {
-            return Theme::accent();
+            return theme.accent();
         }
         if seeds >= 100 {
-            Theme::seeds_high()
+            theme.seeds_high()
         } else if seeds >= 20 {
-            Theme::seeds_medium()
+            theme.seeds_medium()
         } else {
-            Theme::seeds_low()
+            theme.seeds_low()
         }
     }
 }
@@ -525,6 +530,7 @@ impl SourceBrowserView {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{HdrType, VideoCodec};
 
     fn sample_results() -> Vec<SearchResult> {
         vec![
@@ -562,30 +568,45 @@ mod tests {
         vec![
             StreamSource {
                 name: "Torrentio".to_string(),
+                provider: "Torrentio".to_string(),
                 title: "The.Batman.2022.1080p.BluRay.x264".to_string(),
                 info_hash: "abc123".to_string(),
                 file_idx: Some(0),
                 seeds: 142,
                 quality: Quality::FHD1080p,
                 size_bytes: Some(4_500_000_000),
+                hdr: HdrType::None,
+                codec: VideoCodec::X264,
+                release_group: None,
+                direct_url: None,
             },
             StreamSource {
                 name: "Torrentio".to_string(),
+                provider: "Torrentio".to_string(),
                 title: "The.Batman.2022.2160p.WEB-DL.HDR".to_string(),
                 info_hash: "def456".to_string(),
                 file_idx: Some(0),
                 seeds: 89,
                 quality: Quality::UHD4K,
                 size_bytes: Some(12_000_000_000),
+                hdr: HdrType::Hdr10,
+                codec: VideoCodec::Unknown,
+                release_group: None,
+                direct_url: None,
             },
             StreamSource {
                 name: "Torrentio".to_string(),
+                provider: "Torrentio".to_string(),
                 title: "The.Batman.2022.720p.WEB".to_string(),
                 info_hash: "ghi789".to_string(),
                 file_idx: Some(0),
                 seeds: 203,
                 quality: Quality::HD720p,
                 size_bytes: Some(1_800_000_000),
+                hdr: HdrType::None,
+                codec: VideoCodec::Unknown,
+                release_group: None,
+                direct_url: None,
             },
         ]
     }
@@ -831,12 +852,17 @@ mod tests {
     fn test_stream_source_browser_item() {
         let source = StreamSource {
             name: "Test".to_string(),
+            provider: "Test".to_string(),
             title: "Test.File.1080p".to_string(),
             info_hash: "abc".to_string(),
             file_idx: None,
             seeds: 100,
             quality: Quality::FHD1080p,
             size_bytes: Some(2_000_000_000),
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         };
 
         assert_eq!(source.title(), "Test.File.1080p");
@@ -853,24 +879,24 @@ mod tests {
     #[test]
     fn test_rating_style_thresholds() {
         // High rating (>= 7.5)
-        let style = BrowserView::rating_style(8.0, false);
-        assert_eq!(style.fg, Some(Theme::SUCCESS));
+        let style = BrowserView::rating_style(8.0, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.success));
 
         // Medium rating (6.0-7.5)
-        let style = BrowserView::rating_style(6.5, false);
-        assert_eq!(style.fg, Some(Theme::WARNING));
+        let style = BrowserView::rating_style(6.5, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.warning));
 
         // Low rating (4.0-6.0)
-        let style = BrowserView::rating_style(5.0, false);
-        assert_eq!(style.fg, Some(Theme::DIM));
+        let style = BrowserView::rating_style(5.0, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.dim));
 
         // Very low rating (< 4.0)
-        let style = BrowserView::rating_style(3.0, false);
-        assert_eq!(style.fg, Some(Theme::ERROR));
+        let style = BrowserView::rating_style(3.0, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.error));
 
         // Selected always uses accent
-        let style = BrowserView::rating_style(5.0, true);
-        assert_eq!(style.fg, Some(Theme::ACCENT));
+        let style = BrowserView::rating_style(5.0, true, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.accent));
     }
 
     // =========================================================================
@@ -879,30 +905,30 @@ mod tests {
 
     #[test]
     fn test_quality_style() {
-        let style = SourceBrowserView::quality_style(&Quality::UHD4K, false);
-        assert_eq!(style.fg, Some(Theme::SECONDARY)); // Magenta for 4K
+        let style = SourceBrowserView::quality_style(&Quality::UHD4K, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.secondary)); // Magenta for 4K
 
-        let style = SourceBrowserView::quality_style(&Quality::FHD1080p, false);
-        assert_eq!(style.fg, Some(Theme::PRIMARY)); // Cyan for 1080p
+        let style = SourceBrowserView::quality_style(&Quality::FHD1080p, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.primary)); // Cyan for 1080p
 
         // Selected overrides
-        let style = SourceBrowserView::quality_style(&Quality::UHD4K, true);
-        assert_eq!(style.fg, Some(Theme::ACCENT));
+        let style = SourceBrowserView::quality_style(&Quality::UHD4K, true, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.accent));
     }
 
     #[test]
     fn test_seeds_style() {
-        let style = SourceBrowserView::seeds_style(150, false);
-        assert_eq!(style.fg, Some(Theme::SUCCESS)); // High seeds
+        let style = SourceBrowserView::seeds_style(150, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.success)); // High seeds
 
-        let style = SourceBrowserView::seeds_style(50, false);
-        assert_eq!(style.fg, Some(Theme::WARNING)); // Medium seeds
+        let style = SourceBrowserView::seeds_style(50, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.warning)); // Medium seeds
 
-        let style = SourceBrowserView::seeds_style(10, false);
-        assert_eq!(style.fg, Some(Theme::ERROR)); // Low seeds
+        let style = SourceBrowserView::seeds_style(10, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.error)); // Low seeds
 
         // Selected overrides
-        let style = SourceBrowserView::seeds_style(10, true);
-        assert_eq!(style.fg, Some(Theme::ACCENT));
+        let style = SourceBrowserView::seeds_style(10, true, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.accent));
     }
 }
@@ -308,29 +308,29 @@ impl DetailView {
     }
 
     /// Render the detail view
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         if self.media.is_none() {
-            self.render_empty(frame, area);
+            self.render_empty(frame, area, theme);
             return;
         }
 
         if self.is_tv() {
-            self.render_tv_layout(frame, area);
+            self.render_tv_layout(frame, area, theme);
         } else {
-            self.render_movie_layout(frame, area);
+            self.render_movie_layout(frame, area, theme);
         }
     }
 
     /// Render empty state
-    fn render_empty(&self, frame: &mut Frame, area: Rect) {
+    fn render_empty(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Theme::border())
-            .title(Span::styled(" DETAIL ", Theme::title()));
+            .border_style(theme.border())
+            .title(Span::styled(" DETAIL ", theme.title()));
 
         let empty = Paragraph::new("No media selected")
-            .style(Theme::dimmed())
+            .style(theme.dimmed())
             .alignment(Alignment::Center)
             .block(block);
 
@@ -338,19 +338,19 @@ impl DetailView {
     }
 
     /// Render movie layout (info panel + sources)
-    fn render_movie_layout(&self, frame: &mut Frame, area: Rect) {
+    fn render_movie_layout(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Split into info (left 60%) and sources (right 40%)
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
             .split(area);
 
-        self.render_info_panel(frame, chunks[0]);
-        self.render_sources_panel(frame, chunks[1]);
+        self.render_info_panel(frame, chunks[0], theme);
+        self.render_sources_panel(frame, chunks[1], theme);
     }
 
     /// Render TV layout (info + seasons/episodes + sources)
-    fn render_tv_layout(&self, frame: &mut Frame, area: Rect) {
+    fn render_tv_layout(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Split into left (info) and right (seasons/episodes/sources)
         let h_chunks = Layout::default()
             .direction(Direction::Horizontal)
@@ -358,7 +358,7 @@ impl DetailView {
             .split(area);
 
         // Left: info panel
-        self.render_info_panel(frame, h_chunks[0]);
+        self.render_info_panel(frame, h_chunks[0], theme);
 
         // Right: vertical stack of seasons, episodes, sources
         let v_chunks = Layout::default()
@@ -370,25 +370,25 @@ impl DetailView {
             ])
             .split(h_chunks[1]);
 
-        self.render_seasons_panel(frame, v_chunks[0]);
-        self.render_episodes_panel(frame, v_chunks[1]);
-        self.render_sources_panel(frame, v_chunks[2]);
+        self.render_seasons_panel(frame, v_chunks[0], theme);
+        self.render_episodes_panel(frame, v_chunks[1], theme);
+        self.render_sources_panel(frame, v_chunks[2], theme);
     }
 
     /// Render the info panel (title, rating, overview, etc.)
-    fn render_info_panel(&self, frame: &mut Frame, area: Rect) {
+    fn render_info_panel(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let is_focused = self.focus == DetailFocus::Info;
         let border_style = if is_focused {
-            Theme::border_focused()
+            theme.border_focused()
         } else {
-            Theme::border()
+            theme.border()
         };
 
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(border_style)
-            .title(Span::styled(" INFO ", Theme::title()));
+            .title(Span::styled(" INFO ", theme.title()));
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -399,34 +399,34 @@ impl DetailView {
         // Title line with neon glow effect
         let year_str = self.year().map(|y| format!(" ({})", y)).unwrap_or_default();
         lines.push(Line::from(vec![
-            Span::styled("▶ ", Theme::accent()),
-            Span::styled(self.title().to_string(), Theme::title()),
-            Span::styled(year_str, Theme::secondary()),
+            Span::styled("▶ ", theme.accent()),
+            Span::styled(self.title().to_string(), theme.title()),
+            Span::styled(year_str, theme.secondary()),
         ]));
 
         // Rating and runtime
         let rating = self.rating();
         let rating_style = if rating >= 7.5 {
-            Theme::success()
+            theme.success()
         } else if rating >= 6.0 {
-            Theme::warning()
+            theme.warning()
         } else {
-            Theme::error()
+            theme.error()
         };
 
         let mut meta_spans = vec![Span::styled(format!("★ {:.1}", rating), rating_style)];
 
         if let Some(runtime) = self.runtime_str() {
-            meta_spans.push(Span::styled(" │ ", Theme::dimmed()));
-            meta_spans.push(Span::styled(runtime, Theme::secondary()));
+            meta_spans.push(Span::styled(" │ ", theme.dimmed()));
+            meta_spans.push(Span::styled(runtime, theme.secondary()));
         }
 
         if self.is_tv() {
             if let Some(tv) = &self.tv_detail {
-                meta_spans.push(Span::styled(" │ ", Theme::dimmed()));
+                meta_spans.push(Span::styled(" │ ", theme.dimmed()));
                 meta_spans.push(Span::styled(
                     format!("{} seasons", tv.seasons.len()),
-                    Theme::secondary(),
+                    theme.secondary(),
                 ));
             }
         }
@@ -437,26 +437,26 @@ impl DetailView {
         let genres = self.genres_str();
         if !genres.is_empty() {
             lines.push(Line::from(vec![
-                Span::styled("Genre: ", Theme::dimmed()),
-                Span::styled(genres, Theme::text()),
+                Span::styled("Genre: ", theme.dimmed()),
+                Span::styled(genres, theme.text()),
             ]));
         }
 
         // Separator
         lines.push(Line::from(Span::styled(
             "─".repeat(inner.width as usize),
-            Theme::dimmed(),
+            theme.dimmed(),
         )));
 
         // Overview
         let overview = self.overview();
         if !overview.is_empty() {
-            lines.push(Line::from(Span::styled("OVERVIEW", Theme::accent())));
+            lines.push(Line::from(Span::styled("OVERVIEW", theme.accent())));
             lines.push(Line::from(""));
 
             // Word wrap overview text
             for line in overview.lines() {
-                lines.push(Line::from(Span::styled(line.to_string(), Theme::text())));
+                lines.push(Line::from(Span::styled(line.to_string(), theme.text())));
             }
         }
 
@@ -469,12 +469,12 @@ impl DetailView {
     }
 
     /// Render seasons panel (TV only)
-    fn render_seasons_panel(&self, frame: &mut Frame, area: Rect) {
+    fn render_seasons_panel(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let is_focused = self.focus == DetailFocus::Seasons;
         let border_style = if is_focused {
-            Theme::border_focused()
+            theme.border_focused()
         } else {
-            Theme::border()
+            theme.border()
         };
 
         let title = format!(" SEASONS ({}) ", self.seasons.len());
@@ -482,11 +482,11 @@ impl DetailView {
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(border_style)
-            .title(Span::styled(title, Theme::title()));
+            .title(Span::styled(title, theme.title()));
 
         if self.seasons.is_empty() {
             let empty = Paragraph::new("No seasons")
-                .style(Theme::dimmed())
+                .style(theme.dimmed())
                 .alignment(Alignment::Center)
                 .block(block);
             frame.render_widget(empty, area);
@@ -506,25 +506,25 @@ impl DetailView {
                     Span::styled(
                         marker.to_string(),
                         if is_selected {
-                            Theme::accent()
+                            theme.accent()
                         } else {
-                            Theme::dimmed()
+                            theme.dimmed()
                         },
                     ),
                     Span::styled(
                         format!("{} {}", name, season.season_number),
                         if is_selected {
-                            Theme::list_item_selected()
+                            theme.list_item_selected()
                         } else {
-                            Theme::text()
+                            theme.text()
                         },
                     ),
                     Span::styled(
                         format!(" ({} eps)", season.episode_count),
                         if is_selected {
-                            Theme::accent()
+                            theme.accent()
                         } else {
-                            Theme::dimmed()
+                            theme.dimmed()
                         },
                     ),
                 ]);
@@ -538,12 +538,12 @@ impl DetailView {
     }
 
     /// Render episodes panel (TV only)
-    fn render_episodes_panel(&self, frame: &mut Frame, area: Rect) {
+    fn render_episodes_panel(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let is_focused = self.focus == DetailFocus::Episodes;
         let border_style = if is_focused {
-            Theme::border_focused()
+            theme.border_focused()
         } else {
-            Theme::border()
+            theme.border()
         };
 
         let title = format!(" EPISODES ({}) ", self.episodes.len());
@@ -551,11 +551,11 @@ impl DetailView {
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(border_style)
-            .title(Span::styled(title, Theme::title()));
+            .title(Span::styled(title, theme.title()));
 
         if self.episodes.is_empty() {
             let empty = Paragraph::new("Select a season")
-                .style(Theme::dimmed())
+                .style(theme.dimmed())
                 .alignment(Alignment::Center)
                 .block(block);
             frame.render_widget(empty, area);
@@ -587,26 +587,26 @@ impl DetailView {
                     Span::styled(
                         marker.to_string(),
                         if is_selected {
-                            Theme::accent()
+                            theme.accent()
                         } else {
-                            Theme::dimmed()
+                            theme.dimmed()
                         },
                     ),
                     Span::styled(
                         format!("S{:02}E{:02}", ep.season, ep.episode),
                         if is_selected {
-                            Theme::accent()
+                            theme.accent()
                         } else {
-                            Theme::secondary()
+                            theme.secondary()
                         },
                     ),
-                    Span::styled(" - ", Theme::dimmed()),
+                    Span::styled(" - ", theme.dimmed()),
                     Span::styled(
                         ep.name.chars().take(30).collect::<String>(),
                         if is_selected {
-                            Theme::list_item_selected()
+                            theme.list_item_selected()
                         } else {
-                            Theme::text()
+                            theme.text()
                         },
                     ),
                 ]);
@@ -620,12 +620,12 @@ impl DetailView {
     }
 
     /// Render sources panel
-    fn render_sources_panel(&self, frame: &mut Frame, area: Rect) {
+    fn render_sources_panel(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let is_focused = self.focus == DetailFocus::Sources;
         let border_style = if is_focused {
-            Theme::border_focused()
+            theme.border_focused()
         } else {
-            Theme::border()
+            theme.border()
         };
 
         let title = format!(" SOURCES ({}) ", self.sources.len());
@@ -633,11 +633,11 @@ impl DetailView {
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(border_style)
-            .title(Span::styled(title, Theme::title()));
+            .title(Span::styled(title, theme.title()));
 
         if self.sources.is_empty() {
             let empty = Paragraph::new("Loading sources...")
-                .style(Theme::loading())
+                .style(theme.loading())
                 .alignment(Alignment::Center)
                 .block(block);
             frame.render_widget(empty, area);
@@ -660,7 +660,7 @@ impl DetailView {
             .enumerate()
             .skip(offset)
             .take(visible_height)
-            .map(|(i, source)| self.render_source_item(i, source))
+            .map(|(i, source)| self.render_source_item(i, source, theme))
             .collect();
 
         let list = List::new(items).block(block);
@@ -669,7 +669,12 @@ impl DetailView {
 
     /// Render a single source item
     /// Format: [1] 1080p  4.2 GB  👤142  Title...
-    fn render_source_item(&self, index: usize, source: &StreamSource) -> ListItem<'static> {
+    fn render_source_item(
+        &self,
+        index: usize,
+        source: &StreamSource,
+        theme: &Theme,
+    ) -> ListItem<'static> {
         let is_selected = index == self.selected_source;
 
         // Hotkey [1]-[9]
@@ -680,8 +685,8 @@ impl DetailView {
         };
 
         // Quality style
-        let quality_style = Self::quality_style(&source.quality, is_selected);
-        let seeds_style = Self::seeds_style(source.seeds, is_selected);
+        let quality_style = Self::quality_style(&source.quality, is_selected, theme);
+        let seeds_style = Self::seeds_style(source.seeds, is_selected, theme);
 
         // Truncate title
         let title_display: String = source
@@ -697,18 +702,18 @@ impl DetailView {
             Span::styled(
                 hotkey,
                 if is_selected {
-                    Theme::accent()
+                    theme.accent()
                 } else {
-                    Theme::keybind()
+                    theme.keybind()
                 },
             ),
             Span::styled(format!("{:<6}", source.quality.to_string()), quality_style),
             Span::styled(
                 format!("{:>7}", source.format_size()),
                 if is_selected {
-                    Theme::accent()
+                    theme.accent()
                 } else {
-                    Theme::file_size()
+                    theme.file_size()
                 },
             ),
             Span::raw(" "),
@@ -717,9 +722,9 @@ impl DetailView {
             Span::styled(
                 title_display,
                 if is_selected {
-                    Theme::list_item_selected()
+                    theme.list_item_selected()
                 } else {
-                    Theme::dimmed()
+                    theme.dimmed()
                 },
             ),
         ]);
@@ -728,29 +733,29 @@ impl DetailView {
     }
 
     /// Style for quality badge
-    fn quality_style(quality: &Quality, is_selected: bool) -> Style {
+    fn quality_style(quality: &Quality, is_selected: bool, theme: &Theme) -> Style {
         if is_selected {
-            return Theme::accent();
+            return theme.accent();
         }
         match quality {
-            Quality::UHD4K => Theme::quality_4k(),
-            Quality::FHD1080p => Theme::quality_1080p(),
-            Quality::HD720p => Theme::quality_720p(),
-            Quality::SD480p | Quality::Unknown => Theme::quality_sd(),
+            Quality::UHD4K => theme.quality_4k(),
+            Quality::FHD1080p => theme.quality_1080p(),
+            Quality::HD720p => theme.quality_720p(),
+            Quality::SD480p | Quality::Unknown => theme.quality_sd(),
         }
     }
 
     /// Style for seed count
-    fn seeds_style(seeds: u32, is_selected: bool) -> Style {
+    fn seeds_style(seeds: u32, is_selected: bool, theme: &Theme) -> Style {
         if is_selected {
-            return Theme::accent();
+            return theme.accent();
         }
         if seeds >= 100 {
-            Theme::seeds_high()
+            theme.seeds_high()
         } else if seeds >= 20 {
-            Theme::seeds_medium()
+            theme.seeds_medium()
         } else {
-            Theme::seeds_low()
+            theme.seeds_low()
         }
     }
 }
@@ -758,6 +763,7 @@ impl DetailView {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{HdrType, VideoCodec};
 
     fn sample_search_result() -> SearchResult {
         SearchResult {
@@ -795,6 +801,8 @@ mod tests {
             vote_average: 7.8,
             poster_path: None,
             backdrop_path: None,
+            collection_id: None,
+            collection_name: None,
         }
     }
 
@@ -851,21 +859,31 @@ mod tests {
         vec![
             StreamSource {
                 name: "Torrentio".to_string(),
+                provider: "Torrentio".to_string(),
                 title: "The.Batman.2022.1080p.BluRay.x264".to_string(),
                 info_hash: "abc123".to_string(),
                 file_idx: Some(0),
                 seeds: 142,
                 quality: Quality::FHD1080p,
                 size_bytes: Some(4_500_000_000),
+                hdr: HdrType::None,
+                codec: VideoCodec::X264,
+                release_group: None,
+                direct_url: None,
             },
             StreamSource {
                 name: "Torrentio".to_string(),
+                provider: "Torrentio".to_string(),
                 title: "The.Batman.2022.2160p.WEB-DL.HDR".to_string(),
                 info_hash: "def456".to_string(),
                 file_idx: Some(0),
                 seeds: 89,
                 quality: Quality::UHD4K,
                 size_bytes: Some(12_000_000_000),
+                hdr: HdrType::Hdr10,
+                codec: VideoCodec::Unknown,
+                release_group: None,
+                direct_url: None,
             },
         ]
     }
@@ -1176,32 +1194,32 @@ mod tests {
 
     #[test]
     fn test_quality_style_4k() {
-        let style = DetailView::quality_style(&Quality::UHD4K, false);
-        assert_eq!(style.fg, Some(Theme::SECONDARY)); // Magenta for 4K
+        let style = DetailView::quality_style(&Quality::UHD4K, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.secondary)); // Magenta for 4K
     }
 
     #[test]
     fn test_quality_style_selected() {
-        let style = DetailView::quality_style(&Quality::UHD4K, true);
-        assert_eq!(style.fg, Some(Theme::ACCENT)); // Selected = accent
+        let style = DetailView::quality_style(&Quality::UHD4K, true, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.accent)); // Selected = accent
     }
 
     #[test]
     fn test_seeds_style_high() {
-        let style = DetailView::seeds_style(150, false);
-        assert_eq!(style.fg, Some(Theme::SUCCESS));
+        let style = DetailView::seeds_style(150, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.success));
     }
 
     #[test]
     fn test_seeds_style_medium() {
-        let style = DetailView::seeds_style(50, false);
-        assert_eq!(style.fg, Some(Theme::WARNING));
+        let style = DetailView::seeds_style(50, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.warning));
     }
 
     #[test]
     fn test_seeds_style_low() {
-        let style = DetailView::seeds_style(10, false);
-        assert_eq!(style.fg, Some(Theme::ERROR));
+        let style = DetailView::seeds_style(10, false, &Theme::NEON);
+        assert_eq!(style.fg, Some(Theme::NEON.error));
     }
 
     // =========================================================================
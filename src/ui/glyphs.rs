@@ -0,0 +1,278 @@
+//! Symbols drawn by the TUI
+//!
+//! Emoji, arrows and box drawing look good in a modern terminal but trip up
+//! screen readers and limited fonts. Render code takes its symbols from a
+//! `Glyphs` set so `ascii_mode` (or `--ascii`) can swap in plain ASCII.
+
+use ratatui::symbols::border;
+use ratatui::widgets::BorderType;
+
+/// Borders drawn with `+`, `-` and `|`
+const ASCII_BORDER: border::Set = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+/// A set of symbols for the render functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    /// Plain ASCII only (also disables the spinner animation)
+    pub ascii: bool,
+    /// In front of the selected list row (two columns wide)
+    pub selected_marker: &'static str,
+    /// Before TMDB ratings
+    pub rating: &'static str,
+    /// Watchlist marker
+    pub favorite: &'static str,
+    /// Between fields on one line
+    pub separator: &'static str,
+    /// Between genres
+    pub bullet: &'static str,
+    /// Cursor in the search box
+    pub cursor: &'static str,
+    /// Truncated text
+    pub ellipsis: &'static str,
+    /// Key hints for list navigation and seeking
+    pub up_down: &'static str,
+    pub left_right: &'static str,
+    /// Sort direction in the sources title
+    pub descending: &'static str,
+    /// Playback states
+    pub play: &'static str,
+    pub pause: &'static str,
+    pub idle: &'static str,
+    /// Next queued episode
+    pub next: &'static str,
+    /// Error popup title
+    pub error: &'static str,
+    /// Progress and volume bars
+    pub bar_filled: &'static str,
+    pub bar_empty: &'static str,
+    pub volume_bar: &'static str,
+    /// Loading spinner frames (a single frame doesn't animate)
+    pub spinner: &'static [&'static str],
+    // Labels put in front of a value or title; empty or ending in a space
+    pub search: &'static str,
+    pub loading: &'static str,
+    pub trending: &'static str,
+    pub genre: &'static str,
+    pub subtitle: &'static str,
+    pub device: &'static str,
+    pub volume: &'static str,
+    pub seeds: &'static str,
+    pub peers: &'static str,
+    pub download: &'static str,
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Self::UNICODE
+    }
+}
+
+impl Glyphs {
+    /// Emoji, arrows and rounded box drawing
+    pub const UNICODE: Glyphs = Glyphs {
+        ascii: false,
+        selected_marker: "▸ ",
+        rating: "★",
+        favorite: "♥",
+        separator: "│",
+        bullet: "·",
+        cursor: "│",
+        ellipsis: "…",
+        up_down: "↑↓",
+        left_right: "←→",
+        descending: "↓",
+        play: "▶",
+        pause: "⏸",
+        idle: "●",
+        next: "⏭",
+        error: "✗",
+        bar_filled: "▓",
+        bar_empty: "░",
+        volume_bar: "█",
+        spinner: &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
+        search: "⌕ ",
+        loading: "⟳ ",
+        trending: "⚡ ",
+        genre: "🎭 ",
+        subtitle: "💬 ",
+        device: "📺 ",
+        volume: "🔊 ",
+        seeds: "👤",
+        peers: "👥 ",
+        download: "↓ ",
+    };
+
+    /// Plain ASCII for screen readers and limited fonts
+    pub const ASCII: Glyphs = Glyphs {
+        ascii: true,
+        selected_marker: "> ",
+        rating: "*",
+        favorite: "<3",
+        separator: "|",
+        bullet: "-",
+        cursor: "|",
+        ellipsis: "...",
+        up_down: "Up/Dn",
+        left_right: "Lt/Rt",
+        descending: "desc",
+        play: ">",
+        pause: "||",
+        idle: "o",
+        next: ">>",
+        error: "!",
+        bar_filled: "#",
+        bar_empty: "-",
+        volume_bar: "#",
+        spinner: &["..."],
+        search: "Search: ",
+        loading: "",
+        trending: "",
+        genre: "",
+        subtitle: "",
+        device: "[TV] ",
+        volume: "Vol ",
+        seeds: "seeds:",
+        peers: "",
+        download: "down ",
+    };
+
+    /// ASCII or Unicode symbols
+    pub fn new(ascii: bool) -> Self {
+        if ascii {
+            Self::ASCII
+        } else {
+            Self::UNICODE
+        }
+    }
+
+    /// Border symbols for a block (`+-|` in ASCII mode)
+    pub fn border(&self, border_type: BorderType) -> border::Set {
+        if self.ascii {
+            ASCII_BORDER
+        } else {
+            border_type.to_border_set()
+        }
+    }
+
+    /// `text` cut to at most `width` characters, ending in the ellipsis if cut
+    ///
+    /// Counts chars rather than bytes, so multibyte titles never get sliced
+    /// mid-character. Widths too narrow for the ellipsis get a plain cut.
+    pub fn truncate_to_width(&self, text: &str, width: usize) -> String {
+        if text.chars().count() <= width {
+            return text.to_string();
+        }
+        let ellipsis = self.ellipsis.chars().count();
+        if width < ellipsis {
+            return text.chars().take(width).collect();
+        }
+        let mut truncated: String = text.chars().take(width - ellipsis).collect();
+        truncated.push_str(self.ellipsis);
+        truncated
+    }
+
+    /// Spinner frame for a point in time (milliseconds)
+    pub fn spinner_frame(&self, millis: u128) -> &'static str {
+        self.spinner[(millis / 150) as usize % self.spinner.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_glyphs_are_ascii() {
+        let g = Glyphs::ASCII;
+        let all = [
+            g.selected_marker,
+            g.rating,
+            g.favorite,
+            g.separator,
+            g.bullet,
+            g.cursor,
+            g.ellipsis,
+            g.up_down,
+            g.left_right,
+            g.descending,
+            g.play,
+            g.pause,
+            g.idle,
+            g.next,
+            g.error,
+            g.bar_filled,
+            g.bar_empty,
+            g.volume_bar,
+            g.search,
+            g.loading,
+            g.trending,
+            g.genre,
+            g.subtitle,
+            g.device,
+            g.volume,
+            g.seeds,
+            g.peers,
+            g.download,
+        ];
+        for glyph in all.iter().chain(g.spinner) {
+            assert!(glyph.is_ascii(), "{:?} is not ASCII", glyph);
+        }
+        let border = g.border(BorderType::Double);
+        assert_eq!((border.top_left, border.vertical_left), ("+", "|"));
+    }
+
+    #[test]
+    fn test_truncate_multibyte_title_at_narrow_width() {
+        let title = "Amélie — 東京物語 (2001)";
+        let g = Glyphs::UNICODE;
+        assert_eq!(g.truncate_to_width(title, 8), "Amélie …");
+        assert_eq!(g.truncate_to_width(title, 1), "…");
+        assert_eq!(g.truncate_to_width(title, 0), "");
+        assert_eq!(g.truncate_to_width(title, 100), title);
+        assert_eq!(
+            Glyphs::ASCII.truncate_to_width("東京物語東京物語", 6),
+            "東京物..."
+        );
+        assert_eq!(Glyphs::ASCII.truncate_to_width("東京物語", 2), "東京");
+        // Every width between zero and the full title is safe
+        for width in 0..=title.chars().count() {
+            assert!(g.truncate_to_width(title, width).chars().count() <= width);
+        }
+    }
+
+    #[test]
+    fn test_truncate_to_width_keeps_titles_that_fit() {
+        let g = Glyphs::UNICODE;
+        assert_eq!(g.truncate_to_width("Amélie", 8), "Amélie");
+        assert_eq!(g.truncate_to_width("Amélie", 6), "Amélie");
+        assert_eq!(g.truncate_to_width("Amélie", 5), "Amél…");
+        assert_eq!(
+            g.truncate_to_width("千と千尋の神隠し", 8),
+            "千と千尋の神隠し"
+        );
+        assert_eq!(g.truncate_to_width("千と千尋の神隠し", 4), "千と千…");
+    }
+
+    #[test]
+    fn test_ascii_spinner_is_static() {
+        let g = Glyphs::new(true);
+        assert_eq!(g.spinner_frame(0), g.spinner_frame(1_000));
+
+        let g = Glyphs::new(false);
+        assert_ne!(g.spinner_frame(0), g.spinner_frame(150));
+        assert_eq!(g.spinner_frame(0), g.spinner_frame(150 * 8));
+        assert_eq!(
+            g.border(BorderType::Rounded),
+            BorderType::Rounded.to_border_set()
+        );
+    }
+}
@@ -5,9 +5,12 @@
 
 pub mod browser;
 pub mod detail;
+pub mod glyphs;
 pub mod player;
+pub mod poster;
 pub mod search;
 pub mod subtitles;
 pub mod theme;
 
-pub use theme::Theme;
+pub use glyphs::Glyphs;
+pub use theme::{Palette, Theme};
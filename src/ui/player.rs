@@ -107,14 +107,14 @@ impl PlayerView {
     }
 
     /// Get state indicator symbol
-    fn state_indicator(&self) -> (&str, Style) {
+    fn state_indicator(&self, theme: &Theme) -> (&str, Style) {
         match self.state() {
-            CastState::Playing => ("▶", Theme::success()),
-            CastState::Paused => ("⏸", Theme::warning()),
-            CastState::Buffering => ("◌", Theme::loading()),
-            CastState::Connecting => ("⟳", Theme::loading()),
-            CastState::Idle | CastState::Stopped => ("⏹", Theme::dimmed()),
-            CastState::Error(_) => ("✖", Theme::error()),
+            CastState::Playing => ("▶", theme.success()),
+            CastState::Paused => ("⏸", theme.warning()),
+            CastState::Buffering => ("◌", theme.loading()),
+            CastState::Connecting => ("⟳", theme.loading()),
+            CastState::Idle | CastState::Stopped => ("⏹", theme.dimmed()),
+            CastState::Error(_) => ("✖", theme.error()),
         }
     }
 
@@ -138,7 +138,7 @@ impl PlayerView {
     }
 
     /// Render the player overlay
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Overlay dimensions: 50 chars wide, 9 lines tall
         let overlay_width = 50;
         let overlay_height = 9;
@@ -148,16 +148,16 @@ impl PlayerView {
         frame.render_widget(Clear, overlay_area);
 
         // Build the overlay block with double border for cyberpunk feel
-        let (state_symbol, state_style) = self.state_indicator();
+        let (state_symbol, state_style) = self.state_indicator(theme);
         let header_title = format!(" {} NOW CASTING ", state_symbol);
 
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Double)
-            .border_style(Theme::border_focused())
+            .border_style(theme.border_focused())
             .title(Span::styled(header_title, state_style))
             .title_alignment(Alignment::Left)
-            .style(Style::default().bg(Theme::BACKGROUND));
+            .style(Style::default().bg(theme.background));
 
         let inner = block.inner(overlay_area);
         frame.render_widget(block, overlay_area);
@@ -176,25 +176,25 @@ impl PlayerView {
             .split(inner);
 
         // Title line with year
-        self.render_title(frame, chunks[0]);
+        self.render_title(frame, chunks[0], theme);
 
         // Progress bar with times
-        self.render_progress(frame, chunks[2]);
+        self.render_progress(frame, chunks[2], theme);
 
         // Device name + subtitle indicator
-        self.render_device_line(frame, chunks[4]);
+        self.render_device_line(frame, chunks[4], theme);
 
         // Playback controls hint
-        self.render_controls(frame, chunks[5]);
+        self.render_controls(frame, chunks[5], theme);
     }
 
     /// Render title line
-    fn render_title(&self, frame: &mut Frame, area: Rect) {
+    fn render_title(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let year_str = self.year.map(|y| format!(" ({})", y)).unwrap_or_default();
 
         let title_line = Line::from(vec![
-            Span::styled(&self.title, Theme::title()),
-            Span::styled(year_str, Theme::secondary()),
+            Span::styled(&self.title, theme.title()),
+            Span::styled(year_str, theme.secondary()),
         ]);
 
         let paragraph = Paragraph::new(title_line).alignment(Alignment::Center);
@@ -202,7 +202,7 @@ impl PlayerView {
     }
 
     /// Render progress bar with time positions
-    fn render_progress(&self, frame: &mut Frame, area: Rect) {
+    fn render_progress(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let (position_secs, duration_secs) = self
             .status
             .as_ref()
@@ -227,13 +227,13 @@ impl PlayerView {
             .split(area);
 
         // Current position (left)
-        let pos_text = Paragraph::new(Span::styled(&position_str, Theme::accent()))
-            .alignment(Alignment::Right);
+        let pos_text =
+            Paragraph::new(Span::styled(&position_str, theme.accent())).alignment(Alignment::Right);
         frame.render_widget(pos_text, chunks[0]);
 
         // Progress bar (center)
         let gauge = Gauge::default()
-            .gauge_style(Theme::progress_bar())
+            .gauge_style(theme.progress_bar())
             .ratio(progress_ratio.clamp(0.0, 1.0))
             .label(""); // No label on the gauge itself
 
@@ -241,23 +241,23 @@ impl PlayerView {
 
         // Duration (right)
         let dur_text =
-            Paragraph::new(Span::styled(&duration_str, Theme::dimmed())).alignment(Alignment::Left);
+            Paragraph::new(Span::styled(&duration_str, theme.dimmed())).alignment(Alignment::Left);
         frame.render_widget(dur_text, chunks[2]);
     }
 
     /// Render device name and subtitle indicator
-    fn render_device_line(&self, frame: &mut Frame, area: Rect) {
+    fn render_device_line(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let mut spans = vec![
-            Span::styled("📺 ", Theme::secondary()),
-            Span::styled(self.device_name(), Theme::cast_target()),
+            Span::styled("📺 ", theme.secondary()),
+            Span::styled(self.device_name(), theme.cast_target()),
         ];
 
         // Add subtitle indicator if active
         if self.subtitles_active {
             let lang = self.subtitle_language.as_deref().unwrap_or("??");
-            spans.push(Span::styled("  │  ", Theme::dimmed()));
-            spans.push(Span::styled("CC ", Theme::accent()));
-            spans.push(Span::styled(lang.to_uppercase(), Theme::secondary()));
+            spans.push(Span::styled("  │  ", theme.dimmed()));
+            spans.push(Span::styled("CC ", theme.accent()));
+            spans.push(Span::styled(lang.to_uppercase(), theme.secondary()));
         }
 
         let line = Line::from(spans);
@@ -266,23 +266,23 @@ impl PlayerView {
     }
 
     /// Render playback controls hint
-    fn render_controls(&self, frame: &mut Frame, area: Rect) {
+    fn render_controls(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Show pause/play depending on current state
         let play_pause = if self.is_playing() {
-            ("[Space] Pause", Theme::keybind())
+            ("[Space] Pause", theme.keybind())
         } else {
-            ("[Space] Play", Theme::keybind())
+            ("[Space] Play", theme.keybind())
         };
 
         let line = Line::from(vec![
             Span::styled(play_pause.0, play_pause.1),
-            Span::styled("  ", Theme::dimmed()),
-            Span::styled("[s]", Theme::keybind()),
-            Span::styled(" Stop  ", Theme::keybind_desc()),
-            Span::styled("[←→]", Theme::keybind()),
-            Span::styled(" Seek  ", Theme::keybind_desc()),
-            Span::styled("[Esc]", Theme::keybind()),
-            Span::styled(" Close", Theme::keybind_desc()),
+            Span::styled("  ", theme.dimmed()),
+            Span::styled("[s]", theme.keybind()),
+            Span::styled(" Stop  ", theme.keybind_desc()),
+            Span::styled("[←→]", theme.keybind()),
+            Span::styled(" Seek  ", theme.keybind_desc()),
+            Span::styled("[Esc]", theme.keybind()),
+            Span::styled(" Close", theme.keybind_desc()),
         ]);
 
         let paragraph = Paragraph::new(line).alignment(Alignment::Center);
@@ -425,7 +425,7 @@ mod tests {
     fn test_state_indicator_playing() {
         let mut view = PlayerView::new();
         view.update_status(sample_status_playing());
-        let (symbol, _) = view.state_indicator();
+        let (symbol, _) = view.state_indicator(&Theme::NEON);
         assert_eq!(symbol, "▶");
     }
 
@@ -433,7 +433,7 @@ mod tests {
     fn test_state_indicator_paused() {
         let mut view = PlayerView::new();
         view.update_status(sample_status_paused());
-        let (symbol, _) = view.state_indicator();
+        let (symbol, _) = view.state_indicator(&Theme::NEON);
         assert_eq!(symbol, "⏸");
     }
 
@@ -447,7 +447,7 @@ mod tests {
             volume: 1.0,
             title: None,
         });
-        let (symbol, _) = view.state_indicator();
+        let (symbol, _) = view.state_indicator(&Theme::NEON);
         assert_eq!(symbol, "◌");
     }
 
@@ -0,0 +1,192 @@
+//! Poster thumbnails for the detail view
+//!
+//! Posters are drawn with the kitty graphics protocol or sixel, whichever the
+//! terminal supports. Encoding is delegated to `chafa`, so terminals without
+//! graphics support (or systems without chafa) keep the text-only layout.
+//!
+//! Downloaded posters are cached in ~/.cache/streamtui/posters/
+
+use anyhow::{anyhow, Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// TMDB image CDN (w342 is plenty for a terminal side panel)
+pub const IMAGE_BASE_URL: &str = "https://image.tmdb.org/t/p/w342";
+
+/// Terminal graphics protocol used to draw posters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Sixel,
+}
+
+impl GraphicsProtocol {
+    /// Detect a supported protocol for the current terminal
+    ///
+    /// Returns None if the terminal isn't known to support graphics or
+    /// `chafa` isn't installed.
+    pub fn detect() -> Option<Self> {
+        let protocol = Self::from_env(|name| std::env::var(name).ok())?;
+        chafa_available().then_some(protocol)
+    }
+
+    /// Guess the protocol from terminal environment variables
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let term = var("TERM").unwrap_or_default().to_lowercase();
+        let program = var("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+
+        if var("KITTY_WINDOW_ID").is_some()
+            || term.contains("kitty")
+            || term.contains("ghostty")
+            || matches!(program.as_str(), "wezterm" | "ghostty")
+        {
+            return Some(Self::Kitty);
+        }
+        if term.contains("sixel")
+            || term.starts_with("foot")
+            || term.contains("mlterm")
+            || program == "iterm.app"
+        {
+            return Some(Self::Sixel);
+        }
+        None
+    }
+
+    /// chafa `--format` value
+    fn chafa_format(&self) -> &'static str {
+        match self {
+            Self::Kitty => "kitty",
+            Self::Sixel => "sixels",
+        }
+    }
+}
+
+/// Whether `chafa` can be run
+fn chafa_available() -> bool {
+    Command::new("chafa")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+/// Full CDN URL for a TMDB `poster_path` (e.g. "/abc.jpg")
+pub fn poster_url(poster_path: &str) -> String {
+    format!("{}/{}", IMAGE_BASE_URL, poster_path.trim_start_matches('/'))
+}
+
+/// Poster cache directory (~/.cache/streamtui/posters)
+pub fn cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(|| PathBuf::from("/tmp"))
+        .join("streamtui")
+        .join("posters")
+}
+
+/// Cache file for a poster (only the file name of `poster_path` is used)
+pub fn cache_file(dir: &Path, poster_path: &str) -> PathBuf {
+    let name = poster_path.rsplit('/').next().unwrap_or(poster_path);
+    dir.join(name)
+}
+
+/// Download a poster unless it's already cached, returning the local file
+pub async fn fetch(client: &reqwest::Client, poster_path: &str) -> Result<PathBuf> {
+    let dir = cache_dir();
+    let file = cache_file(&dir, poster_path);
+    if file.exists() {
+        return Ok(file);
+    }
+
+    let response = client
+        .get(poster_url(poster_path))
+        .send()
+        .await
+        .context("Failed to fetch poster")?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Poster download failed: {}", response.status()));
+    }
+    let bytes = response.bytes().await?;
+
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(&file, &bytes)?;
+    Ok(file)
+}
+
+/// Encode an image as terminal graphics sized to `cols` x `rows` cells
+pub fn encode(protocol: GraphicsProtocol, file: &Path, cols: u16, rows: u16) -> Result<Vec<u8>> {
+    let output = Command::new("chafa")
+        .arg(format!("--format={}", protocol.chafa_format()))
+        .arg(format!("--size={}x{}", cols, rows))
+        .arg("--animate=off")
+        .arg("--polite=on")
+        .arg(file)
+        .stderr(Stdio::null())
+        .output()
+        .context("Failed to run chafa")?;
+    if !output.status.success() {
+        return Err(anyhow!("chafa exited with {}", output.status));
+    }
+    Ok(output.stdout)
+}
+
+/// Escape sequence that removes drawn images (kitty only; sixel images are
+/// overwritten by redrawing the screen)
+pub fn clear_sequence(protocol: GraphicsProtocol) -> &'static [u8] {
+    match protocol {
+        GraphicsProtocol::Kitty => b"\x1b_Ga=d\x1b\\",
+        GraphicsProtocol::Sixel => b"",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn test_detect_protocol_from_env() {
+        assert_eq!(
+            GraphicsProtocol::from_env(env(&[("TERM", "xterm-kitty")])),
+            Some(GraphicsProtocol::Kitty)
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(env(&[
+                ("TERM", "xterm-256color"),
+                ("TERM_PROGRAM", "WezTerm")
+            ])),
+            Some(GraphicsProtocol::Kitty)
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(env(&[("TERM", "foot")])),
+            Some(GraphicsProtocol::Sixel)
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(env(&[("TERM", "xterm-256color")])),
+            None
+        );
+        assert_eq!(GraphicsProtocol::from_env(env(&[])), None);
+    }
+
+    #[test]
+    fn test_poster_url_and_cache_file() {
+        assert_eq!(
+            poster_url("/74xTEgt7R36Fpooo50r9T25onhq.jpg"),
+            "https://image.tmdb.org/t/p/w342/74xTEgt7R36Fpooo50r9T25onhq.jpg"
+        );
+        let dir = Path::new("/cache/posters");
+        assert_eq!(
+            cache_file(dir, "/74xTEgt7R36Fpooo50r9T25onhq.jpg"),
+            dir.join("74xTEgt7R36Fpooo50r9T25onhq.jpg")
+        );
+        // Path components can't escape the cache dir
+        assert_eq!(cache_file(dir, "/../../etc/passwd"), dir.join("passwd"));
+    }
+}
@@ -176,7 +176,7 @@ impl SearchView {
     // =========================================================================
 
     /// Render the search view
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Split into search box (top) and results (bottom)
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -186,12 +186,12 @@ impl SearchView {
             ])
             .split(area);
 
-        self.render_search_box(frame, chunks[0]);
-        self.render_results(frame, chunks[1]);
+        self.render_search_box(frame, chunks[0], theme);
+        self.render_results(frame, chunks[1], theme);
     }
 
     /// Render the search input box with neon styling
-    fn render_search_box(&self, frame: &mut Frame, area: Rect) {
+    fn render_search_box(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Build the query text with cursor indicator
         let display_query = if self.focused {
             // Insert cursor character at position
@@ -208,15 +208,15 @@ impl SearchView {
 
         // Select border style based on focus
         let border_style = if self.focused {
-            Theme::border_focused()
+            theme.border_focused()
         } else {
-            Theme::border()
+            theme.border()
         };
 
         let input_style = if self.focused {
-            Theme::input().fg(Theme::PRIMARY)
+            theme.input().fg(theme.primary)
         } else {
-            Theme::input()
+            theme.input()
         };
 
         let search_box = Paragraph::new(text).style(input_style).block(
@@ -224,7 +224,7 @@ impl SearchView {
                 .borders(Borders::ALL)
                 .border_style(border_style)
                 .border_type(ratatui::widgets::BorderType::Rounded)
-                .title(Span::styled(" SEARCH ", Theme::title()))
+                .title(Span::styled(" SEARCH ", theme.title()))
                 .title_alignment(Alignment::Left),
         );
 
@@ -232,17 +232,17 @@ impl SearchView {
     }
 
     /// Render the results list with neon styling
-    fn render_results(&self, frame: &mut Frame, area: Rect) {
+    fn render_results(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Handle error state
         if let Some(ref error) = self.error {
             let error_block = Paragraph::new(format!("✗ {}", error))
-                .style(Theme::error())
+                .style(theme.error())
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Theme::border())
+                        .border_style(theme.border())
                         .border_type(ratatui::widgets::BorderType::Rounded)
-                        .title(Span::styled(" ERROR ", Theme::error())),
+                        .title(Span::styled(" ERROR ", theme.error())),
                 );
             frame.render_widget(error_block, area);
             return;
@@ -251,12 +251,12 @@ impl SearchView {
         // Handle loading state
         if self.loading {
             let loading_block = Paragraph::new("⟳ Searching...")
-                .style(Theme::loading())
+                .style(theme.loading())
                 .alignment(Alignment::Center)
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Theme::border())
+                        .border_style(theme.border())
                         .border_type(ratatui::widgets::BorderType::Rounded),
                 );
             frame.render_widget(loading_block, area);
@@ -267,19 +267,26 @@ impl SearchView {
         if self.results.is_empty() {
             if self.query.is_empty() && !self.trending.is_empty() {
                 // Show trending content
-                self.render_list(frame, area, &self.trending, " 🔥 TRENDING ", self.selected);
+                self.render_list(
+                    frame,
+                    area,
+                    &self.trending,
+                    " 🔥 TRENDING ",
+                    self.selected,
+                    theme,
+                );
                 return;
             }
 
             // Show trending loading state
             if self.trending_loading {
                 let loading_block = Paragraph::new("⟳ Loading trending...")
-                    .style(Theme::loading())
+                    .style(theme.loading())
                     .alignment(Alignment::Center)
                     .block(
                         Block::default()
                             .borders(Borders::ALL)
-                            .border_style(Theme::border())
+                            .border_style(theme.border())
                             .border_type(ratatui::widgets::BorderType::Rounded),
                     );
                 frame.render_widget(loading_block, area);
@@ -293,14 +300,14 @@ impl SearchView {
             };
 
             let empty_block = Paragraph::new(hint)
-                .style(Theme::dimmed())
+                .style(theme.dimmed())
                 .alignment(Alignment::Center)
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Theme::border())
+                        .border_style(theme.border())
                         .border_type(ratatui::widgets::BorderType::Rounded)
-                        .title(Span::styled(" RESULTS ", Theme::title())),
+                        .title(Span::styled(" RESULTS ", theme.title())),
                 );
             frame.render_widget(empty_block, area);
             return;
@@ -313,6 +320,7 @@ impl SearchView {
             &self.results,
             &format!(" RESULTS ({}) ", self.results.len()),
             self.selected,
+            theme,
         );
     }
 
@@ -324,6 +332,7 @@ impl SearchView {
         items: &[SearchResult],
         title: &str,
         selected_idx: usize,
+        theme: &Theme,
     ) {
         // Build result items with cyberpunk styling
         let list_items: Vec<ListItem> = items
@@ -344,31 +353,31 @@ impl SearchView {
                     Span::styled(
                         marker,
                         if is_selected {
-                            Theme::accent()
+                            theme.accent()
                         } else {
-                            Theme::dimmed()
+                            theme.dimmed()
                         },
                     ),
                     Span::styled(
                         &result.title,
                         if is_selected {
-                            Theme::highlighted()
+                            theme.highlighted()
                         } else {
-                            Theme::text()
+                            theme.text()
                         },
                     ),
-                    Span::styled(year_str, Theme::year()),
+                    Span::styled(year_str, theme.year()),
                     Span::raw(" "),
-                    Span::styled(format!("[{}]", type_str), Theme::secondary()),
+                    Span::styled(format!("[{}]", type_str), theme.secondary()),
                     Span::raw(" "),
                     Span::styled(
                         format!("★ {:.1}", result.vote_average),
                         if result.vote_average >= 7.0 {
-                            Theme::success()
+                            theme.success()
                         } else if result.vote_average >= 5.0 {
-                            Theme::warning()
+                            theme.warning()
                         } else {
-                            Theme::dimmed()
+                            theme.dimmed()
                         },
                     ),
                 ]);
@@ -381,18 +390,18 @@ impl SearchView {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Theme::border())
+                    .border_style(theme.border())
                     .border_type(ratatui::widgets::BorderType::Rounded)
-                    .title(Span::styled(title, Theme::title()))
+                    .title(Span::styled(title, theme.title()))
                     .title_alignment(Alignment::Left),
             )
-            .style(Theme::text());
+            .style(theme.text());
 
         frame.render_widget(results_list, area);
     }
 
     /// Render as a popup/overlay (centered on screen)
-    pub fn render_popup(&self, frame: &mut Frame, area: Rect) {
+    pub fn render_popup(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Calculate centered popup area (60% width, 80% height)
         let popup_width = (area.width as f32 * 0.6).min(80.0) as u16;
         let popup_height = (area.height as f32 * 0.8).min(30.0) as u16;
@@ -408,7 +417,7 @@ impl SearchView {
         frame.render_widget(Clear, popup_area);
 
         // Render the search view inside
-        self.render(frame, popup_area);
+        self.render(frame, popup_area, theme);
     }
 }
 
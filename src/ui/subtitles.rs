@@ -190,7 +190,7 @@ impl SubtitlesView {
     }
 
     /// Render the subtitles view
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         // Main layout: header, content, keybinds
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -201,23 +201,23 @@ impl SubtitlesView {
             ])
             .split(area);
 
-        self.render_header(frame, chunks[0]);
-        self.render_content(frame, chunks[1]);
-        self.render_keybinds(frame, chunks[2]);
+        self.render_header(frame, chunks[0], theme);
+        self.render_content(frame, chunks[1], theme);
+        self.render_keybinds(frame, chunks[2], theme);
     }
 
     /// Render the header with title
-    fn render_header(&self, frame: &mut Frame, area: Rect) {
+    fn render_header(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let year_str = self.year.map(|y| format!(" ({})", y)).unwrap_or_default();
         let header_text = format!("📝 SUBTITLES - {}{}", self.title, year_str);
 
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Double)
-            .border_style(Theme::border_focused())
-            .title(Span::styled(" StreamTUI ", Theme::title()));
+            .border_style(theme.border_focused())
+            .title(Span::styled(" StreamTUI ", theme.title()));
 
-        let header = Paragraph::new(Line::from(vec![Span::styled(header_text, Theme::title())]))
+        let header = Paragraph::new(Line::from(vec![Span::styled(header_text, theme.title())]))
             .block(block)
             .alignment(Alignment::Center);
 
@@ -225,16 +225,16 @@ impl SubtitlesView {
     }
 
     /// Render the main content area with grouped subtitles
-    fn render_content(&self, frame: &mut Frame, area: Rect) {
+    fn render_content(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Theme::border_focused());
+            .border_style(theme.border_focused());
 
         if self.subtitles.is_empty() {
             let empty = Paragraph::new(Line::from(vec![Span::styled(
                 "No subtitles found",
-                Theme::dimmed(),
+                theme.dimmed(),
             )]))
             .block(block)
             .alignment(Alignment::Center);
@@ -267,10 +267,10 @@ impl SubtitlesView {
             .skip(scroll_offset)
             .take(visible_height)
             .map(|(_, row)| match row {
-                SubtitleRow::LanguageHeader(lang) => self.render_language_header(lang),
+                SubtitleRow::LanguageHeader(lang) => self.render_language_header(lang, theme),
                 SubtitleRow::Subtitle(sub) => {
                     let is_selected = selectable_idx == self.selected;
-                    let item = self.render_subtitle_item(sub, is_selected);
+                    let item = self.render_subtitle_item(sub, is_selected, theme);
                     selectable_idx += 1;
                     item
                 }
@@ -296,13 +296,13 @@ impl SubtitlesView {
     }
 
     /// Render a language header row
-    fn render_language_header(&self, language: &str) -> ListItem<'static> {
+    fn render_language_header(&self, language: &str, theme: &Theme) -> ListItem<'static> {
         let line = Line::from(vec![
-            Span::styled("  🌐 ", Theme::accent()),
+            Span::styled("  🌐 ", theme.accent()),
             Span::styled(
                 language.to_string(),
                 Style::default()
-                    .fg(Theme::PRIMARY)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
         ]);
@@ -310,50 +310,55 @@ impl SubtitlesView {
     }
 
     /// Render a subtitle item row
-    fn render_subtitle_item(&self, sub: &SubtitleResult, is_selected: bool) -> ListItem<'static> {
+    fn render_subtitle_item(
+        &self,
+        sub: &SubtitleResult,
+        is_selected: bool,
+        theme: &Theme,
+    ) -> ListItem<'static> {
         // Selection marker
         let marker = if is_selected { "  ▸ " } else { "    " };
         let marker_style = if is_selected {
-            Theme::accent()
+            theme.accent()
         } else {
-            Theme::dimmed()
+            theme.dimmed()
         };
 
         // Trust indicator: ✓ for trusted, empty otherwise
         let trust_indicator = if sub.from_trusted { "[✓] " } else { "[ ] " };
         let trust_style = if sub.from_trusted {
-            Theme::success()
+            theme.success()
         } else {
-            Theme::dimmed()
+            theme.dimmed()
         };
 
         // Release name (truncated)
         let release: String = sub.release.chars().take(32).collect();
         let release_style = if is_selected {
-            Theme::list_item_selected()
+            theme.list_item_selected()
         } else {
-            Theme::text()
+            theme.text()
         };
 
         // Download count (formatted)
         let downloads = Self::format_downloads(sub.downloads);
         let downloads_style = if is_selected {
-            Theme::accent()
+            theme.accent()
         } else {
-            Theme::dimmed()
+            theme.dimmed()
         };
 
         // Status indicators
         let mut status_spans = Vec::new();
 
         if sub.from_trusted {
-            status_spans.push(Span::styled(" Trusted", Theme::success()));
+            status_spans.push(Span::styled(" Trusted", theme.success()));
         }
         if sub.ai_translated {
-            status_spans.push(Span::styled(" ⚠️AI", Theme::warning()));
+            status_spans.push(Span::styled(" ⚠️AI", theme.warning()));
         }
         if sub.hearing_impaired {
-            status_spans.push(Span::styled(" 👂SDH", Theme::secondary()));
+            status_spans.push(Span::styled(" 👂SDH", theme.secondary()));
         }
 
         let mut spans = vec![
@@ -379,21 +384,21 @@ impl SubtitlesView {
     }
 
     /// Render keybinding hints
-    fn render_keybinds(&self, frame: &mut Frame, area: Rect) {
+    fn render_keybinds(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Theme::border());
+            .border_style(theme.border());
 
         let keybinds = Line::from(vec![
-            Span::styled("[Enter] ", Theme::keybind()),
-            Span::styled("Select  ", Theme::keybind_desc()),
-            Span::styled("[c] ", Theme::keybind()),
-            Span::styled("Cast with selected  ", Theme::keybind_desc()),
-            Span::styled("[n] ", Theme::keybind()),
-            Span::styled("No subtitles  ", Theme::keybind_desc()),
-            Span::styled("[Esc] ", Theme::keybind()),
-            Span::styled("Back", Theme::keybind_desc()),
+            Span::styled("[Enter] ", theme.keybind()),
+            Span::styled("Select  ", theme.keybind_desc()),
+            Span::styled("[c] ", theme.keybind()),
+            Span::styled("Cast with selected  ", theme.keybind_desc()),
+            Span::styled("[n] ", theme.keybind()),
+            Span::styled("No subtitles  ", theme.keybind_desc()),
+            Span::styled("[Esc] ", theme.keybind()),
+            Span::styled("Back", theme.keybind_desc()),
         ]);
 
         let para = Paragraph::new(keybinds)
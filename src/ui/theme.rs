@@ -1,157 +1,277 @@
-//! Cyberpunk neon theme for StreamTUI
+//! Color themes for StreamTUI
 //!
-//! Color palette and style helpers for the TUI.
-//! All colors are from specs/tui.md - the cyberpunk neon aesthetic.
+//! Color palettes and style helpers for the TUI. The default Neon palette is
+//! the cyberpunk aesthetic from specs/tui.md; the others trade it for
+//! readability on terminals where neon on black washes out.
 
 use ratatui::style::{Color, Modifier, Style};
 
-/// Cyberpunk neon color palette
-///
-/// All colors defined exactly as specified in specs/tui.md
-pub struct Theme;
-
-impl Theme {
-    // ═══════════════════════════════════════════════════════════════════════
-    // CORE PALETTE (from specs/tui.md)
-    // ═══════════════════════════════════════════════════════════════════════
-
-    /// Background: #0a0a0f (deep black-blue)
-    pub const BACKGROUND: Color = Color::Rgb(0x0a, 0x0a, 0x0f);
-
-    /// Primary: #00fff2 (cyan neon)
-    pub const PRIMARY: Color = Color::Rgb(0x00, 0xff, 0xf2);
-
-    /// Secondary: #ff00ff (magenta)
-    pub const SECONDARY: Color = Color::Rgb(0xff, 0x00, 0xff);
-
-    /// Accent: #ffff00 (yellow)
-    pub const ACCENT: Color = Color::Rgb(0xff, 0xff, 0x00);
-
-    /// Highlight: #ff0080 (hot pink)
-    pub const HIGHLIGHT: Color = Color::Rgb(0xff, 0x00, 0x80);
+/// Selectable color palette (`theme` in config.toml, `t` in the TUI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Palette {
+    /// Cyberpunk neon (specs/tui.md)
+    #[default]
+    Neon,
+    /// Solarized dark
+    Solarized,
+    /// Grayscale, for low-color or low-contrast terminals
+    Mono,
+    /// Green phosphor
+    Matrix,
+}
 
-    /// Text: #e0e0e0 (soft white)
-    pub const TEXT: Color = Color::Rgb(0xe0, 0xe0, 0xe0);
+impl Palette {
+    /// All palettes, in the order `t` cycles through them
+    pub const ALL: [Palette; 4] = [
+        Palette::Neon,
+        Palette::Solarized,
+        Palette::Mono,
+        Palette::Matrix,
+    ];
+
+    /// Name used in config.toml
+    pub fn name(&self) -> &'static str {
+        match self {
+            Palette::Neon => "neon",
+            Palette::Solarized => "solarized",
+            Palette::Mono => "mono",
+            Palette::Matrix => "matrix",
+        }
+    }
 
-    /// Dim: #404050 (muted)
-    pub const DIM: Color = Color::Rgb(0x40, 0x40, 0x50);
+    /// Parse a palette name (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.name().eq_ignore_ascii_case(name.trim()))
+    }
 
-    /// Success: #00ff00 (green)
-    pub const SUCCESS: Color = Color::Rgb(0x00, 0xff, 0x00);
+    /// The palette after this one, wrapping around
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|&p| p == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+}
 
-    /// Warning: #ffaa00 (orange)
-    pub const WARNING: Color = Color::Rgb(0xff, 0xaa, 0x00);
+/// Colors of a palette plus the styles built from them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub palette: Palette,
 
-    /// Error: #ff0040 (red)
-    pub const ERROR: Color = Color::Rgb(0xff, 0x00, 0x40);
+    // ═══════════════════════════════════════════════════════════════════════
+    // CORE PALETTE
+    // ═══════════════════════════════════════════════════════════════════════
+    /// Screen background
+    pub background: Color,
+    /// Titles, borders in focus, loading indicators
+    pub primary: Color,
+    /// Years, type tags, 4K badges
+    pub secondary: Color,
+    /// Keybinds and emphasized values
+    pub accent: Color,
+    /// Selected items
+    pub highlight: Color,
+    /// Body text
+    pub text: Color,
+    /// Muted text
+    pub dim: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
 
     // ═══════════════════════════════════════════════════════════════════════
     // DERIVED COLORS (for UI elements)
     // ═══════════════════════════════════════════════════════════════════════
-
     /// Slightly lighter background for panels/cards
-    pub const BACKGROUND_LIGHT: Color = Color::Rgb(0x14, 0x14, 0x1e);
-
+    pub background_light: Color,
     /// Even lighter for hover states
-    pub const BACKGROUND_HOVER: Color = Color::Rgb(0x1e, 0x1e, 0x2d);
+    pub background_hover: Color,
+    /// Normal border
+    pub border: Color,
+    /// Border when focused
+    pub border_focused: Color,
+}
 
-    /// Border color (dim cyan)
-    pub const BORDER: Color = Color::Rgb(0x00, 0x80, 0x78);
+impl Default for Theme {
+    fn default() -> Self {
+        Self::NEON
+    }
+}
 
-    /// Border color when focused (full cyan)
-    pub const BORDER_FOCUSED: Color = Self::PRIMARY;
+impl Theme {
+    /// Cyberpunk neon, all colors exactly as specified in specs/tui.md
+    pub const NEON: Theme = Theme {
+        palette: Palette::Neon,
+        background: Color::Rgb(0x0a, 0x0a, 0x0f),
+        primary: Color::Rgb(0x00, 0xff, 0xf2),
+        secondary: Color::Rgb(0xff, 0x00, 0xff),
+        accent: Color::Rgb(0xff, 0xff, 0x00),
+        highlight: Color::Rgb(0xff, 0x00, 0x80),
+        text: Color::Rgb(0xe0, 0xe0, 0xe0),
+        dim: Color::Rgb(0x40, 0x40, 0x50),
+        success: Color::Rgb(0x00, 0xff, 0x00),
+        warning: Color::Rgb(0xff, 0xaa, 0x00),
+        error: Color::Rgb(0xff, 0x00, 0x40),
+        background_light: Color::Rgb(0x14, 0x14, 0x1e),
+        background_hover: Color::Rgb(0x1e, 0x1e, 0x2d),
+        border: Color::Rgb(0x00, 0x80, 0x78),
+        border_focused: Color::Rgb(0x00, 0xff, 0xf2),
+    };
+
+    /// Solarized dark (ethanschoonover.com/solarized)
+    pub const SOLARIZED: Theme = Theme {
+        palette: Palette::Solarized,
+        background: Color::Rgb(0x00, 0x2b, 0x36),
+        primary: Color::Rgb(0x2a, 0xa1, 0x98),
+        secondary: Color::Rgb(0x6c, 0x71, 0xc4),
+        accent: Color::Rgb(0xb5, 0x89, 0x00),
+        highlight: Color::Rgb(0xd3, 0x36, 0x82),
+        text: Color::Rgb(0x93, 0xa1, 0xa1),
+        dim: Color::Rgb(0x58, 0x6e, 0x75),
+        success: Color::Rgb(0x85, 0x99, 0x00),
+        warning: Color::Rgb(0xcb, 0x4b, 0x16),
+        error: Color::Rgb(0xdc, 0x32, 0x2f),
+        background_light: Color::Rgb(0x07, 0x36, 0x42),
+        background_hover: Color::Rgb(0x0e, 0x44, 0x52),
+        border: Color::Rgb(0x26, 0x8b, 0xd2),
+        border_focused: Color::Rgb(0x2a, 0xa1, 0x98),
+    };
+
+    /// Grayscale with white for everything that needs to stand out
+    pub const MONO: Theme = Theme {
+        palette: Palette::Mono,
+        background: Color::Rgb(0x00, 0x00, 0x00),
+        primary: Color::Rgb(0xff, 0xff, 0xff),
+        secondary: Color::Rgb(0xb0, 0xb0, 0xb0),
+        accent: Color::Rgb(0xff, 0xff, 0xff),
+        highlight: Color::Rgb(0xff, 0xff, 0xff),
+        text: Color::Rgb(0xd0, 0xd0, 0xd0),
+        dim: Color::Rgb(0x80, 0x80, 0x80),
+        success: Color::Rgb(0xe0, 0xe0, 0xe0),
+        warning: Color::Rgb(0xe0, 0xe0, 0xe0),
+        error: Color::Rgb(0xff, 0xff, 0xff),
+        background_light: Color::Rgb(0x1c, 0x1c, 0x1c),
+        background_hover: Color::Rgb(0x30, 0x30, 0x30),
+        border: Color::Rgb(0x80, 0x80, 0x80),
+        border_focused: Color::Rgb(0xff, 0xff, 0xff),
+    };
+
+    /// Green phosphor; errors stay red so they still read as errors
+    pub const MATRIX: Theme = Theme {
+        palette: Palette::Matrix,
+        background: Color::Rgb(0x00, 0x00, 0x00),
+        primary: Color::Rgb(0x00, 0xff, 0x41),
+        secondary: Color::Rgb(0x00, 0xb3, 0x2d),
+        accent: Color::Rgb(0xb8, 0xff, 0x00),
+        highlight: Color::Rgb(0x39, 0xff, 0x14),
+        text: Color::Rgb(0xb6, 0xff, 0xc8),
+        dim: Color::Rgb(0x1f, 0x66, 0x30),
+        success: Color::Rgb(0x00, 0xff, 0x41),
+        warning: Color::Rgb(0xd4, 0xff, 0x00),
+        error: Color::Rgb(0xff, 0x33, 0x33),
+        background_light: Color::Rgb(0x06, 0x1a, 0x0a),
+        background_hover: Color::Rgb(0x0c, 0x2a, 0x12),
+        border: Color::Rgb(0x00, 0x6b, 0x1f),
+        border_focused: Color::Rgb(0x00, 0xff, 0x41),
+    };
+
+    /// Colors for a palette
+    pub fn new(palette: Palette) -> Self {
+        match palette {
+            Palette::Neon => Self::NEON,
+            Palette::Solarized => Self::SOLARIZED,
+            Palette::Mono => Self::MONO,
+            Palette::Matrix => Self::MATRIX,
+        }
+    }
 
     // ═══════════════════════════════════════════════════════════════════════
     // STYLE HELPERS
     // ═══════════════════════════════════════════════════════════════════════
 
     /// Default text style
-    pub fn text() -> Style {
-        Style::default().fg(Self::TEXT).bg(Self::BACKGROUND)
+    pub fn text(&self) -> Style {
+        Style::default().fg(self.text).bg(self.background)
     }
 
     /// Highlighted text (inverted with primary color)
-    pub fn highlighted() -> Style {
+    pub fn highlighted(&self) -> Style {
         Style::default()
-            .fg(Self::BACKGROUND)
-            .bg(Self::PRIMARY)
+            .fg(self.background)
+            .bg(self.primary)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Selected item style (hot pink, bold)
-    pub fn selected() -> Style {
+    pub fn selected(&self) -> Style {
         Style::default()
-            .fg(Self::HIGHLIGHT)
+            .fg(self.highlight)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Dimmed/muted text
-    pub fn dimmed() -> Style {
-        Style::default().fg(Self::DIM)
+    pub fn dimmed(&self) -> Style {
+        Style::default().fg(self.dim)
     }
 
     /// Error style
-    pub fn error() -> Style {
-        Style::default()
-            .fg(Self::ERROR)
-            .add_modifier(Modifier::BOLD)
+    pub fn error(&self) -> Style {
+        Style::default().fg(self.error).add_modifier(Modifier::BOLD)
     }
 
     /// Success style
-    pub fn success() -> Style {
+    pub fn success(&self) -> Style {
         Style::default()
-            .fg(Self::SUCCESS)
+            .fg(self.success)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Warning style
-    pub fn warning() -> Style {
+    pub fn warning(&self) -> Style {
         Style::default()
-            .fg(Self::WARNING)
+            .fg(self.warning)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Title/header style
-    pub fn title() -> Style {
+    pub fn title(&self) -> Style {
         Style::default()
-            .fg(Self::PRIMARY)
+            .fg(self.primary)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Secondary text style (magenta)
-    pub fn secondary() -> Style {
-        Style::default().fg(Self::SECONDARY)
+    pub fn secondary(&self) -> Style {
+        Style::default().fg(self.secondary)
     }
 
     /// Accent text style (yellow)
-    pub fn accent() -> Style {
+    pub fn accent(&self) -> Style {
         Style::default()
-            .fg(Self::ACCENT)
+            .fg(self.accent)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Normal/unfocused border
-    pub fn border() -> Style {
-        Style::default().fg(Self::BORDER)
+    pub fn border(&self) -> Style {
+        Style::default().fg(self.border)
     }
 
     /// Focused border (glowing effect)
-    pub fn border_focused() -> Style {
+    pub fn border_focused(&self) -> Style {
         Style::default()
-            .fg(Self::BORDER_FOCUSED)
+            .fg(self.border_focused)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Progress bar style
-    pub fn progress_bar() -> Style {
-        Style::default()
-            .fg(Self::SUCCESS)
-            .bg(Self::BACKGROUND_LIGHT)
+    pub fn progress_bar(&self) -> Style {
+        Style::default().fg(self.success).bg(self.background_light)
     }
 
     /// Progress bar unfilled portion
-    pub fn progress_bar_empty() -> Style {
-        Style::default().fg(Self::DIM).bg(Self::BACKGROUND_LIGHT)
+    pub fn progress_bar_empty(&self) -> Style {
+        Style::default().fg(self.dim).bg(self.background_light)
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -159,25 +279,49 @@ impl Theme {
     // ═══════════════════════════════════════════════════════════════════════
 
     /// 4K/2160p quality indicator
-    pub fn quality_4k() -> Style {
+    pub fn quality_4k(&self) -> Style {
         Style::default()
-            .fg(Self::SECONDARY) // Magenta for premium
+            .fg(self.secondary) // Magenta for premium
             .add_modifier(Modifier::BOLD)
     }
 
     /// 1080p quality indicator
-    pub fn quality_1080p() -> Style {
-        Style::default().fg(Self::PRIMARY) // Cyan
+    pub fn quality_1080p(&self) -> Style {
+        Style::default().fg(self.primary) // Cyan
     }
 
     /// 720p quality indicator
-    pub fn quality_720p() -> Style {
-        Style::default().fg(Self::SUCCESS) // Green
+    pub fn quality_720p(&self) -> Style {
+        Style::default().fg(self.success) // Green
     }
 
     /// SD quality indicator
-    pub fn quality_sd() -> Style {
-        Style::default().fg(Self::DIM)
+    pub fn quality_sd(&self) -> Style {
+        Style::default().fg(self.dim)
+    }
+
+    /// Dolby Vision badge
+    pub fn hdr_dolby_vision(&self) -> Style {
+        Style::default()
+            .fg(self.background)
+            .bg(self.secondary)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// HDR10+ badge
+    pub fn hdr10_plus(&self) -> Style {
+        Style::default()
+            .fg(self.background)
+            .bg(self.accent)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// HDR10 badge
+    pub fn hdr10(&self) -> Style {
+        Style::default()
+            .fg(self.background)
+            .bg(self.warning)
+            .add_modifier(Modifier::BOLD)
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -185,88 +329,88 @@ impl Theme {
     // ═══════════════════════════════════════════════════════════════════════
 
     /// Style for list items (normal state)
-    pub fn list_item() -> Style {
-        Style::default().fg(Self::TEXT)
+    pub fn list_item(&self) -> Style {
+        Style::default().fg(self.text)
     }
 
     /// Style for list items (selected/highlighted)
-    pub fn list_item_selected() -> Style {
+    pub fn list_item_selected(&self) -> Style {
         Style::default()
-            .fg(Self::BACKGROUND)
-            .bg(Self::PRIMARY)
+            .fg(self.background)
+            .bg(self.primary)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Style for input fields
-    pub fn input() -> Style {
-        Style::default().fg(Self::TEXT).bg(Self::BACKGROUND_LIGHT)
+    pub fn input(&self) -> Style {
+        Style::default().fg(self.text).bg(self.background_light)
     }
 
     /// Style for input cursor
-    pub fn input_cursor() -> Style {
-        Style::default().fg(Self::BACKGROUND).bg(Self::PRIMARY)
+    pub fn input_cursor(&self) -> Style {
+        Style::default().fg(self.background).bg(self.primary)
     }
 
     /// Keybinding hint style
-    pub fn keybind() -> Style {
-        Style::default().fg(Self::ACCENT)
+    pub fn keybind(&self) -> Style {
+        Style::default().fg(self.accent)
     }
 
     /// Keybinding description style
-    pub fn keybind_desc() -> Style {
-        Style::default().fg(Self::DIM)
+    pub fn keybind_desc(&self) -> Style {
+        Style::default().fg(self.dim)
     }
 
     /// Status bar style
-    pub fn status_bar() -> Style {
-        Style::default().fg(Self::TEXT).bg(Self::BACKGROUND_LIGHT)
+    pub fn status_bar(&self) -> Style {
+        Style::default().fg(self.text).bg(self.background_light)
     }
 
     /// Cast target indicator
-    pub fn cast_target() -> Style {
+    pub fn cast_target(&self) -> Style {
         Style::default()
-            .fg(Self::SUCCESS)
+            .fg(self.success)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Loading/spinner indicator
-    pub fn loading() -> Style {
+    pub fn loading(&self) -> Style {
         Style::default()
-            .fg(Self::PRIMARY)
+            .fg(self.primary)
             .add_modifier(Modifier::BOLD)
     }
 
     /// Seed count (high seeds = green, low = yellow/red)
-    pub fn seeds_high() -> Style {
-        Style::default().fg(Self::SUCCESS)
+    pub fn seeds_high(&self) -> Style {
+        Style::default().fg(self.success)
     }
 
-    pub fn seeds_medium() -> Style {
-        Style::default().fg(Self::WARNING)
+    pub fn seeds_medium(&self) -> Style {
+        Style::default().fg(self.warning)
     }
 
-    pub fn seeds_low() -> Style {
-        Style::default().fg(Self::ERROR)
+    pub fn seeds_low(&self) -> Style {
+        Style::default().fg(self.error)
     }
 
     /// File size indicator
-    pub fn file_size() -> Style {
-        Style::default().fg(Self::DIM)
+    pub fn file_size(&self) -> Style {
+        Style::default().fg(self.dim)
     }
 
     /// Year/date metadata
-    pub fn year() -> Style {
-        Style::default().fg(Self::SECONDARY)
+    pub fn year(&self) -> Style {
+        Style::default().fg(self.secondary)
     }
 
     /// Genre tags
-    pub fn genre() -> Style {
-        Style::default().fg(Self::DIM)
+    pub fn genre(&self) -> Style {
+        Style::default().fg(self.dim)
     }
 
     /// Duration text
-    pub fn duration() -> Style {
-        Style::default().fg(Self::DIM)
+    pub fn duration(&self) -> Style {
+        Style::default().fg(self.dim)
     }
 }
 
@@ -331,46 +475,46 @@ mod tests {
     #[test]
     fn test_all_theme_colors_are_rgb() {
         // Verify all theme colors are valid RGB values
-        assert!(color_to_rgb(Theme::BACKGROUND).is_some());
-        assert!(color_to_rgb(Theme::PRIMARY).is_some());
-        assert!(color_to_rgb(Theme::SECONDARY).is_some());
-        assert!(color_to_rgb(Theme::ACCENT).is_some());
-        assert!(color_to_rgb(Theme::HIGHLIGHT).is_some());
-        assert!(color_to_rgb(Theme::TEXT).is_some());
-        assert!(color_to_rgb(Theme::DIM).is_some());
-        assert!(color_to_rgb(Theme::SUCCESS).is_some());
-        assert!(color_to_rgb(Theme::WARNING).is_some());
-        assert!(color_to_rgb(Theme::ERROR).is_some());
+        assert!(color_to_rgb(Theme::NEON.background).is_some());
+        assert!(color_to_rgb(Theme::NEON.primary).is_some());
+        assert!(color_to_rgb(Theme::NEON.secondary).is_some());
+        assert!(color_to_rgb(Theme::NEON.accent).is_some());
+        assert!(color_to_rgb(Theme::NEON.highlight).is_some());
+        assert!(color_to_rgb(Theme::NEON.text).is_some());
+        assert!(color_to_rgb(Theme::NEON.dim).is_some());
+        assert!(color_to_rgb(Theme::NEON.success).is_some());
+        assert!(color_to_rgb(Theme::NEON.warning).is_some());
+        assert!(color_to_rgb(Theme::NEON.error).is_some());
     }
 
     #[test]
     fn test_colors_match_spec() {
         // Background: #0a0a0f
-        assert_eq!(rgb(Theme::BACKGROUND), (0x0a, 0x0a, 0x0f));
+        assert_eq!(rgb(Theme::NEON.background), (0x0a, 0x0a, 0x0f));
         // Primary: #00fff2
-        assert_eq!(rgb(Theme::PRIMARY), (0x00, 0xff, 0xf2));
+        assert_eq!(rgb(Theme::NEON.primary), (0x00, 0xff, 0xf2));
         // Secondary: #ff00ff
-        assert_eq!(rgb(Theme::SECONDARY), (0xff, 0x00, 0xff));
+        assert_eq!(rgb(Theme::NEON.secondary), (0xff, 0x00, 0xff));
         // Accent: #ffff00
-        assert_eq!(rgb(Theme::ACCENT), (0xff, 0xff, 0x00));
+        assert_eq!(rgb(Theme::NEON.accent), (0xff, 0xff, 0x00));
         // Highlight: #ff0080
-        assert_eq!(rgb(Theme::HIGHLIGHT), (0xff, 0x00, 0x80));
+        assert_eq!(rgb(Theme::NEON.highlight), (0xff, 0x00, 0x80));
         // Text: #e0e0e0
-        assert_eq!(rgb(Theme::TEXT), (0xe0, 0xe0, 0xe0));
+        assert_eq!(rgb(Theme::NEON.text), (0xe0, 0xe0, 0xe0));
         // Dim: #404050
-        assert_eq!(rgb(Theme::DIM), (0x40, 0x40, 0x50));
+        assert_eq!(rgb(Theme::NEON.dim), (0x40, 0x40, 0x50));
         // Success: #00ff00
-        assert_eq!(rgb(Theme::SUCCESS), (0x00, 0xff, 0x00));
+        assert_eq!(rgb(Theme::NEON.success), (0x00, 0xff, 0x00));
         // Warning: #ffaa00
-        assert_eq!(rgb(Theme::WARNING), (0xff, 0xaa, 0x00));
+        assert_eq!(rgb(Theme::NEON.warning), (0xff, 0xaa, 0x00));
         // Error: #ff0040
-        assert_eq!(rgb(Theme::ERROR), (0xff, 0x00, 0x40));
+        assert_eq!(rgb(Theme::NEON.error), (0xff, 0x00, 0x40));
     }
 
     #[test]
     fn test_text_contrast_against_background() {
-        let bg = rgb(Theme::BACKGROUND);
-        let text = rgb(Theme::TEXT);
+        let bg = rgb(Theme::NEON.background);
+        let text = rgb(Theme::NEON.text);
 
         let ratio = contrast_ratio(text, bg);
         println!("Text/Background contrast ratio: {:.2}:1", ratio);
@@ -385,8 +529,8 @@ mod tests {
 
     #[test]
     fn test_primary_contrast_against_background() {
-        let bg = rgb(Theme::BACKGROUND);
-        let primary = rgb(Theme::PRIMARY);
+        let bg = rgb(Theme::NEON.background);
+        let primary = rgb(Theme::NEON.primary);
 
         let ratio = contrast_ratio(primary, bg);
         println!("Primary/Background contrast ratio: {:.2}:1", ratio);
@@ -401,8 +545,8 @@ mod tests {
 
     #[test]
     fn test_highlight_contrast() {
-        let bg = rgb(Theme::BACKGROUND);
-        let highlight = rgb(Theme::HIGHLIGHT);
+        let bg = rgb(Theme::NEON.background);
+        let highlight = rgb(Theme::NEON.highlight);
 
         let ratio = contrast_ratio(highlight, bg);
         println!("Highlight/Background contrast ratio: {:.2}:1", ratio);
@@ -416,8 +560,8 @@ mod tests {
 
     #[test]
     fn test_error_contrast() {
-        let bg = rgb(Theme::BACKGROUND);
-        let error = rgb(Theme::ERROR);
+        let bg = rgb(Theme::NEON.background);
+        let error = rgb(Theme::NEON.error);
 
         let ratio = contrast_ratio(error, bg);
         println!("Error/Background contrast ratio: {:.2}:1", ratio);
@@ -432,8 +576,8 @@ mod tests {
     #[test]
     fn test_inverted_highlighted_contrast() {
         // When we invert (text on primary background), it should still be readable
-        let fg = rgb(Theme::BACKGROUND);
-        let bg = rgb(Theme::PRIMARY);
+        let fg = rgb(Theme::NEON.background);
+        let bg = rgb(Theme::NEON.primary);
 
         let ratio = contrast_ratio(fg, bg);
         println!("Background on Primary contrast ratio: {:.2}:1", ratio);
@@ -449,33 +593,72 @@ mod tests {
     fn test_style_helpers_return_valid_styles() {
         // Just verify all style helpers return without panicking
         // and have the expected foreground colors
-        let _ = Theme::text();
-        let _ = Theme::highlighted();
-        let _ = Theme::selected();
-        let _ = Theme::dimmed();
-        let _ = Theme::error();
-        let _ = Theme::success();
-        let _ = Theme::warning();
-        let _ = Theme::title();
-        let _ = Theme::secondary();
-        let _ = Theme::accent();
-        let _ = Theme::border();
-        let _ = Theme::border_focused();
-        let _ = Theme::progress_bar();
-        let _ = Theme::quality_4k();
-        let _ = Theme::quality_1080p();
-        let _ = Theme::quality_720p();
-        let _ = Theme::quality_sd();
-        let _ = Theme::list_item();
-        let _ = Theme::list_item_selected();
-        let _ = Theme::input();
-        let _ = Theme::keybind();
-        let _ = Theme::status_bar();
-        let _ = Theme::cast_target();
-        let _ = Theme::loading();
-        let _ = Theme::seeds_high();
-        let _ = Theme::seeds_medium();
-        let _ = Theme::seeds_low();
+        let theme = Theme::default();
+        let _ = theme.text();
+        let _ = theme.highlighted();
+        let _ = theme.selected();
+        let _ = theme.dimmed();
+        let _ = theme.error();
+        let _ = theme.success();
+        let _ = theme.warning();
+        let _ = theme.title();
+        let _ = theme.secondary();
+        let _ = theme.accent();
+        let _ = theme.border();
+        let _ = theme.border_focused();
+        let _ = theme.progress_bar();
+        let _ = theme.quality_4k();
+        let _ = theme.quality_1080p();
+        let _ = theme.quality_720p();
+        let _ = theme.quality_sd();
+        let _ = theme.list_item();
+        let _ = theme.list_item_selected();
+        let _ = theme.input();
+        let _ = theme.keybind();
+        let _ = theme.status_bar();
+        let _ = theme.cast_target();
+        let _ = theme.loading();
+        let _ = theme.seeds_high();
+        let _ = theme.seeds_medium();
+        let _ = theme.seeds_low();
+    }
+
+    #[test]
+    fn test_each_palette() {
+        for palette in Palette::ALL {
+            let theme = Theme::new(palette);
+            assert_eq!(theme.palette, palette);
+            assert_eq!(Palette::from_name(palette.name()), Some(palette));
+            assert_eq!(theme.text().fg, Some(theme.text));
+            assert_eq!(theme.highlighted().bg, Some(theme.primary));
+
+            // Readable body text and titles in every palette
+            let bg = rgb(theme.background);
+            assert!(
+                meets_wcag_aa(rgb(theme.text), bg),
+                "{} text",
+                palette.name()
+            );
+            assert!(
+                meets_wcag_aa_large(rgb(theme.primary), bg),
+                "{} primary",
+                palette.name()
+            );
+            assert!(
+                meets_wcag_aa_large(bg, rgb(theme.primary)),
+                "{} highlighted",
+                palette.name()
+            );
+        }
+        assert_eq!(Theme::default(), Theme::NEON);
+        assert_eq!(Palette::from_name(" Solarized "), Some(Palette::Solarized));
+        assert_eq!(Palette::from_name("vaporwave"), None);
+    }
+
+    #[test]
+    fn test_palette_cycle_wraps() {
+        assert_eq!(Palette::Neon.next(), Palette::Solarized);
+        assert_eq!(Palette::Matrix.next(), Palette::Neon);
     }
 
     #[test]
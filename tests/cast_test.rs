@@ -33,7 +33,10 @@ fn test_discover_parses_catt_output() {
         "192.168.1.50".parse::<IpAddr>().unwrap()
     );
     assert_eq!(devices[0].port, 8009); // Default Chromecast port
-    assert_eq!(devices[0].model, Some("Google Inc. Chromecast Ultra".to_string()));
+    assert_eq!(
+        devices[0].model,
+        Some("Google Inc. Chromecast Ultra".to_string())
+    );
 
     // Second device
     assert_eq!(devices[1].name, "Bedroom");
@@ -87,6 +90,23 @@ fn test_discover_handles_special_names() {
     assert_eq!(devices[2].address, "10.0.0.100".parse::<IpAddr>().unwrap());
 }
 
+/// A device only counts as reachable while something listens on its port
+#[tokio::test]
+async fn test_device_reachability() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut device = CastDevice::manual("127.0.0.1".parse().unwrap());
+    device.port = listener.local_addr().unwrap().port();
+    assert!(device.is_reachable().await);
+
+    drop(listener);
+    assert!(!device.is_reachable().await);
+
+    // Saved devices without an address are left to catt
+    let mut saved = device.clone();
+    saved.address = "0.0.0.0".parse().unwrap();
+    assert!(saved.is_reachable().await);
+}
+
 // =============================================================================
 // Cast Command Tests
 // =============================================================================
@@ -107,11 +127,12 @@ fn test_cast_forms_correct_command() {
     ];
 
     // Build actual args as CastManager would
-    let mut args = Vec::new();
-    args.push("-d".to_string());
-    args.push(device_name.to_string());
-    args.push("cast".to_string());
-    args.push(url.to_string());
+    let args = vec![
+        "-d".to_string(),
+        device_name.to_string(),
+        "cast".to_string(),
+        url.to_string(),
+    ];
 
     assert_eq!(args, expected_args);
 }
@@ -124,13 +145,14 @@ fn test_cast_with_subtitles_forms_correct_command() {
     let subtitle_url = "http://192.168.1.100:8888/subtitles.vtt";
 
     // Expected: catt -d "Living Room TV" cast "url" --subtitle "subtitle_url"
-    let mut args = Vec::new();
-    args.push("-d".to_string());
-    args.push(device_name.to_string());
-    args.push("cast".to_string());
-    args.push(url.to_string());
-    args.push("--subtitle".to_string());
-    args.push(subtitle_url.to_string());
+    let args = [
+        "-d".to_string(),
+        device_name.to_string(),
+        "cast".to_string(),
+        url.to_string(),
+        "--subtitle".to_string(),
+        subtitle_url.to_string(),
+    ];
 
     assert_eq!(args.len(), 6);
     assert_eq!(args[4], "--subtitle");
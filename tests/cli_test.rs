@@ -9,9 +9,12 @@
 
 mod cli_parsing {
     use clap::Parser;
+    use streamtui::api::torrentio::{StreamKind, TorrentioConfig, TorrentioSort};
     use streamtui::cli::{
-        Cli, Command, ExitCode as CliExitCode, MediaTypeFilter, QualityFilter, SeekCmd,
-        SeekPosition, StreamSort, TrendingWindow, VolumeCmd, VolumeLevel,
+        parse_size_arg, CacheAction, CacheCmd, Cli, Command, ConfigAction, ConfigCmd,
+        DiscoverOrder, ExitCode as CliExitCode, HistoryAction, MediaTypeFilter, ProfileAction,
+        QualityFilter, SeekCmd, SeekPosition, StreamSort, TrendingWindow, VolumeCmd, VolumeLevel,
+        WatchlistAction,
     };
 
     #[test]
@@ -28,6 +31,7 @@ mod cli_parsing {
             Some(Command::Search(cmd)) => {
                 assert_eq!(cmd.query, "batman");
                 assert_eq!(cmd.limit, 20); // default
+                assert_eq!(cmd.page, 1); // default
                 assert!(cmd.media_type.is_none());
             }
             _ => panic!("Expected Search command"),
@@ -61,6 +65,191 @@ mod cli_parsing {
         }
     }
 
+    #[test]
+    fn test_person_command() {
+        let cli = Cli::parse_from(["streamtui", "person", "cillian murphy", "-t", "movie"]);
+        match cli.command {
+            Some(Command::Person(cmd)) => {
+                assert_eq!(cmd.name, "cillian murphy");
+                assert_eq!(cmd.limit, 5); // default
+                assert_eq!(cmd.media_type, Some(MediaTypeFilter::Movie));
+            }
+            _ => panic!("Expected Person command"),
+        }
+    }
+
+    #[test]
+    fn test_resume_command() {
+        let cli = Cli::parse_from(["streamtui", "resume", "tt1375666", "--vlc"]);
+        match cli.command {
+            Some(Command::Resume(cmd)) => {
+                assert_eq!(cmd.imdb_id, "tt1375666");
+                assert!(cmd.vlc);
+                assert!(cmd.device.is_none());
+            }
+            _ => panic!("Expected Resume command"),
+        }
+    }
+
+    #[test]
+    fn test_config_profile_commands() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "--profile",
+            "friend",
+            "config",
+            "profile",
+            "use",
+            "home",
+        ]);
+        assert_eq!(cli.profile.as_deref(), Some("friend"));
+        match cli.command {
+            Some(Command::Config(ConfigCmd {
+                action: ConfigAction::Profile { action },
+            })) => {
+                assert!(matches!(action, ProfileAction::Use { ref name } if name == "home"));
+            }
+            _ => panic!("Expected Config command"),
+        }
+
+        let cli = Cli::parse_from(["streamtui", "config", "profile", "list"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config(ConfigCmd {
+                action: ConfigAction::Profile {
+                    action: ProfileAction::List
+                }
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_config_key_commands() {
+        let cli = Cli::parse_from(["streamtui", "config", "set", "theme", "matrix"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config(ConfigCmd { action: ConfigAction::Set { ref key, ref value } }))
+                if key == "theme" && value == "matrix"
+        ));
+
+        let cli = Cli::parse_from(["streamtui", "config", "get", "default_device"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config(ConfigCmd { action: ConfigAction::Get { ref key } })) if key == "default_device"
+        ));
+
+        let cli = Cli::parse_from(["streamtui", "config", "unset", "theme"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config(ConfigCmd {
+                action: ConfigAction::Unset { .. }
+            }))
+        ));
+
+        let cli = Cli::parse_from(["streamtui", "config", "path"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config(ConfigCmd {
+                action: ConfigAction::Path
+            }))
+        ));
+
+        assert!(Cli::try_parse_from(["streamtui", "config", "set", "theme"]).is_err());
+    }
+
+    #[test]
+    fn test_doctor_command() {
+        let cli = Cli::parse_from(["streamtui", "doctor"]);
+        assert!(matches!(cli.command, Some(Command::Doctor(_))));
+    }
+
+    #[test]
+    fn test_completions_command() {
+        let cli = Cli::parse_from(["streamtui", "completions", "zsh"]);
+        match cli.command {
+            Some(Command::Completions(cmd)) => assert_eq!(cmd.shell, clap_complete::Shell::Zsh),
+            _ => panic!("Expected Completions command"),
+        }
+        assert!(Cli::try_parse_from(["streamtui", "completions", "tcsh"]).is_err());
+    }
+
+    #[test]
+    fn test_cast_magnet_mpv() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "cast-magnet",
+            "magnet:?xt=urn:btih:abc",
+            "--mpv",
+            "--start",
+            "90",
+        ]);
+        match cli.command {
+            Some(Command::CastMagnet(cmd)) => {
+                assert!(cmd.mpv);
+                assert!(!cmd.vlc);
+                assert_eq!(cmd.start, Some(90));
+            }
+            _ => panic!("Expected CastMagnet command"),
+        }
+
+        // One local player at a time
+        let result = Cli::try_parse_from([
+            "streamtui",
+            "cast-magnet",
+            "magnet:?xt=urn:btih:abc",
+            "--mpv",
+            "--vlc",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cache_clear_command() {
+        let cli = Cli::parse_from(["streamtui", "cache", "clear"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Cache(CacheCmd {
+                action: CacheAction::Clear
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_watchlist_commands() {
+        let cli = Cli::parse_from(["streamtui", "watchlist", "add", "27205", "-t", "movie"]);
+        match cli.command {
+            Some(Command::Watchlist(cmd)) => match cmd.action {
+                WatchlistAction::Add { id, media_type } => {
+                    assert_eq!(id, 27205);
+                    assert_eq!(media_type, Some(MediaTypeFilter::Movie));
+                }
+                other => panic!("Expected Add, got {:?}", other),
+            },
+            _ => panic!("Expected Watchlist command"),
+        }
+
+        let cli = Cli::parse_from(["streamtui", "wl", "list"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Watchlist(cmd)) if matches!(cmd.action, WatchlistAction::List)
+        ));
+    }
+
+    #[test]
+    fn test_history_command() {
+        let cli = Cli::parse_from(["streamtui", "history", "search"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::History(cmd)) if matches!(cmd.action, HistoryAction::Search { clear: false })
+        ));
+
+        let cli = Cli::parse_from(["streamtui", "history", "search", "--clear"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::History(cmd)) if matches!(cmd.action, HistoryAction::Search { clear: true })
+        ));
+    }
+
     #[test]
     fn test_trending_command() {
         let cli = Cli::parse_from(["streamtui", "trending", "-w", "week", "-l", "5"]);
@@ -73,6 +262,37 @@ mod cli_parsing {
         }
     }
 
+    #[test]
+    fn test_discover_command() {
+        let cli = Cli::parse_from(["streamtui", "discover", "--genre", "action"]);
+        match cli.command {
+            Some(Command::Discover(cmd)) => {
+                assert_eq!(cmd.genre, "action");
+                assert_eq!(cmd.media_type, MediaTypeFilter::Movie);
+                assert_eq!(cmd.sort, DiscoverOrder::Popularity);
+            }
+            _ => panic!("Expected Discover command"),
+        }
+
+        let cli = Cli::parse_from([
+            "streamtui",
+            "discover",
+            "-g",
+            "sci-fi",
+            "--type",
+            "tv",
+            "--sort",
+            "rating",
+        ]);
+        match cli.command {
+            Some(Command::Discover(cmd)) => {
+                assert_eq!(cmd.media_type, MediaTypeFilter::Tv);
+                assert_eq!(cmd.sort, DiscoverOrder::Rating);
+            }
+            _ => panic!("Expected Discover command"),
+        }
+    }
+
     #[test]
     fn test_info_command() {
         let cli = Cli::parse_from(["streamtui", "info", "12345", "-t", "movie"]);
@@ -85,6 +305,35 @@ mod cli_parsing {
         }
     }
 
+    #[test]
+    fn test_trailer_command() {
+        let cli = Cli::parse_from(["streamtui", "trailer", "414906", "-t", "movie"]);
+        match cli.command {
+            Some(Command::Trailer(cmd)) => {
+                assert_eq!(cmd.id, "414906");
+                assert_eq!(cmd.media_type, Some(MediaTypeFilter::Movie));
+            }
+            _ => panic!("Expected Trailer command"),
+        }
+    }
+
+    #[test]
+    fn test_similar_command() {
+        let cli = Cli::parse_from(["streamtui", "similar", "414906", "-t", "movie", "-l", "5"]);
+        match cli.command {
+            Some(Command::Similar(cmd)) => {
+                assert_eq!(cmd.id, "414906");
+                assert_eq!(cmd.media_type, Some(MediaTypeFilter::Movie));
+                assert_eq!(cmd.limit, 5);
+                assert!(!cmd.recommended);
+            }
+            _ => panic!("Expected Similar command"),
+        }
+
+        let cli = Cli::parse_from(["streamtui", "similar", "tt0903747", "--recommended"]);
+        assert!(matches!(cli.command, Some(Command::Similar(cmd)) if cmd.recommended));
+    }
+
     #[test]
     fn test_streams_command_movie() {
         let cli = Cli::parse_from(["streamtui", "streams", "tt1877830"]);
@@ -93,9 +342,169 @@ mod cli_parsing {
                 assert_eq!(cmd.imdb_id, "tt1877830");
                 assert!(cmd.season.is_none());
                 assert!(cmd.episode.is_none());
+                assert!(!cmd.hdr);
+                assert!(cmd.codec.is_none());
+                assert!(!cmd.no_dedup);
             }
             _ => panic!("Expected Streams command"),
         }
+
+        let cli = Cli::parse_from(["streamtui", "streams", "tt1877830", "--no-dedup", "--best"]);
+        match cli.command {
+            Some(Command::Streams(cmd)) => {
+                assert!(cmd.no_dedup);
+                assert!(cmd.best);
+            }
+            _ => panic!("Expected Streams command"),
+        }
+
+        // Torrentio flags override the config's options
+        let cli = Cli::parse_from([
+            "streamtui",
+            "streams",
+            "tt1877830",
+            "--providers",
+            "yts,eztv",
+            "--torrentio-sort",
+            "size",
+        ]);
+        match cli.command {
+            Some(Command::Streams(cmd)) => {
+                let from_config = TorrentioConfig {
+                    sort: Some(TorrentioSort::Seeders),
+                    ..Default::default()
+                };
+                let config = cmd.torrentio_config(from_config);
+                assert_eq!(config.providers, vec!["yts", "eztv"]);
+                assert_eq!(config.sort, Some(TorrentioSort::Size));
+            }
+            _ => panic!("Expected Streams command"),
+        }
+        assert!(Cli::try_parse_from([
+            "streamtui",
+            "streams",
+            "tt1877830",
+            "--torrentio-sort",
+            "newest"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_size_arg() {
+        const GB: u64 = 1024 * 1024 * 1024;
+        const MB: u64 = 1024 * 1024;
+        assert_eq!(parse_size_arg("4GB"), Ok(4 * GB));
+        assert_eq!(parse_size_arg("1.5 gb"), Ok(GB + GB / 2));
+        assert_eq!(parse_size_arg("700MB"), Ok(700 * MB));
+        assert_eq!(parse_size_arg(" 700mb "), Ok(700 * MB));
+
+        for bad in [
+            "",
+            "4",
+            "GB",
+            "4TB",
+            "4 GB extra",
+            "about 4GB",
+            "-4GB",
+            "4.GB",
+            "4,5GB",
+        ] {
+            assert!(parse_size_arg(bad).is_err(), "{:?} should be rejected", bad);
+        }
+    }
+
+    #[test]
+    fn test_min_seeds_flags() {
+        let cli = Cli::parse_from(["streamtui", "streams", "tt1877830", "--healthy"]);
+        let Some(Command::Streams(cmd)) = cli.command else {
+            panic!("Expected Streams command");
+        };
+        assert_eq!(cmd.min_seeds(), Some(10));
+
+        let cli = Cli::parse_from(["streamtui", "cast", "tt1877830", "--min-seeds", "50"]);
+        let Some(Command::Cast(cmd)) = cli.command else {
+            panic!("Expected Cast command");
+        };
+        assert_eq!(cmd.min_seeds(), Some(50));
+
+        let cli = Cli::parse_from(["streamtui", "cast", "tt1877830"]);
+        assert!(matches!(cli.command, Some(Command::Cast(cmd)) if cmd.min_seeds().is_none()));
+        assert!(Cli::try_parse_from([
+            "streamtui",
+            "streams",
+            "tt1",
+            "--healthy",
+            "--min-seeds",
+            "3"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_streams_size_range() {
+        const GB: u64 = 1024 * 1024 * 1024;
+        let cli = Cli::parse_from([
+            "streamtui",
+            "streams",
+            "tt1877830",
+            "--min-size",
+            "700MB",
+            "--max-size",
+            "4GB",
+        ]);
+        let Some(Command::Streams(cmd)) = cli.command else {
+            panic!("Expected Streams command");
+        };
+        assert!(cmd.size_matches(Some(2 * GB)));
+        assert!(cmd.size_matches(Some(4 * GB)));
+        assert!(!cmd.size_matches(Some(5 * GB)));
+        assert!(!cmd.size_matches(Some(GB / 2)));
+        // Unknown sizes only pass a cap when asked to
+        assert!(!cmd.size_matches(None));
+
+        let cli = Cli::parse_from([
+            "streamtui",
+            "streams",
+            "tt1877830",
+            "--max-size",
+            "4GB",
+            "--include-unknown-size",
+        ]);
+        let Some(Command::Streams(cmd)) = cli.command else {
+            panic!("Expected Streams command");
+        };
+        assert!(cmd.size_matches(None));
+
+        let cli = Cli::parse_from(["streamtui", "streams", "tt1877830", "--min-size", "1GB"]);
+        let Some(Command::Streams(cmd)) = cli.command else {
+            panic!("Expected Streams command");
+        };
+        assert!(cmd.size_matches(None));
+        assert!(!cmd.size_matches(Some(GB / 2)));
+
+        assert!(
+            Cli::try_parse_from(["streamtui", "streams", "tt1877830", "--max-size", "huge"])
+                .is_err()
+        );
+
+        // Release group filter
+        let cli = Cli::parse_from(["streamtui", "streams", "tt1877830", "--group", "spark"]);
+        let Some(Command::Streams(cmd)) = cli.command else {
+            panic!("Expected Streams command");
+        };
+        assert!(cmd.group_matches(Some("SPARKS")));
+        assert!(!cmd.group_matches(Some("GECKOS")));
+        assert!(!cmd.group_matches(None));
+        let cli = Cli::parse_from(["streamtui", "streams", "tt1877830"]);
+        assert!(matches!(cli.command, Some(Command::Streams(cmd)) if cmd.group_matches(None)));
+
+        // cast --auto can't be combined with an explicit index
+        let cli = Cli::parse_from(["streamtui", "cast", "tt1877830", "--auto"]);
+        assert!(matches!(cli.command, Some(Command::Cast(cmd)) if cmd.auto));
+        assert!(
+            Cli::try_parse_from(["streamtui", "cast", "tt1877830", "--auto", "-i", "2"]).is_err()
+        );
     }
 
     #[test]
@@ -125,6 +534,146 @@ mod cli_parsing {
         }
     }
 
+    fn streams_kind(args: &[&str]) -> Result<StreamKind, String> {
+        let cli = Cli::parse_from([&["streamtui", "streams", "tt0903747"], args].concat());
+        let Some(Command::Streams(cmd)) = cli.command else {
+            panic!("Expected Streams command");
+        };
+        cmd.stream_kind()
+    }
+
+    #[test]
+    fn test_streams_kind_from_flags() {
+        // No -t: a series only with both season and episode
+        assert_eq!(streams_kind(&[]), Ok(StreamKind::Movie));
+        assert_eq!(streams_kind(&["-s", "2"]), Ok(StreamKind::Movie));
+        assert_eq!(
+            streams_kind(&["-s", "2", "-e", "3"]),
+            Ok(StreamKind::Series {
+                season: 2,
+                episode: 3
+            })
+        );
+        assert_eq!(
+            streams_kind(&["-e", "40", "--absolute"]),
+            Ok(StreamKind::Series {
+                season: 1,
+                episode: 40
+            })
+        );
+
+        // -t tv works without an episode (season packs)
+        assert_eq!(
+            streams_kind(&["-t", "tv"]),
+            Ok(StreamKind::Series {
+                season: 1,
+                episode: 1
+            })
+        );
+        assert_eq!(
+            streams_kind(&["-t", "tv", "-s", "4"]),
+            Ok(StreamKind::Series {
+                season: 4,
+                episode: 1
+            })
+        );
+
+        // -t movie has no seasons
+        assert_eq!(streams_kind(&["-t", "movie"]), Ok(StreamKind::Movie));
+        assert!(streams_kind(&["-t", "movie", "-s", "1"]).is_err());
+        assert!(streams_kind(&["-t", "movie", "-s", "1", "-e", "2"]).is_err());
+        assert!(streams_kind(&["-t", "movie", "-e", "2", "--absolute"]).is_err());
+    }
+
+    #[test]
+    fn test_cast_kind_from_flags() {
+        let kind = |args: &[&str]| {
+            let cli =
+                Cli::parse_from([&["streamtui", "cast", "tt0903747", "--vlc"], args].concat());
+            let Some(Command::Cast(cmd)) = cli.command else {
+                panic!("Expected Cast command");
+            };
+            cmd.stream_kind()
+        };
+        assert_eq!(kind(&[]), Ok(StreamKind::Movie));
+        assert_eq!(
+            kind(&["-t", "tv"]),
+            Ok(StreamKind::Series {
+                season: 1,
+                episode: 1
+            })
+        );
+        assert_eq!(
+            kind(&["-s", "1", "-e", "7"]),
+            Ok(StreamKind::Series {
+                season: 1,
+                episode: 7
+            })
+        );
+        assert!(kind(&["-t", "movie", "--season", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_magnet_kind_from_flags() {
+        let kind = |args: &[&str]| {
+            let cli = Cli::parse_from([&["streamtui", "magnet", "tt0903747"], args].concat());
+            let Some(Command::Magnet(cmd)) = cli.command else {
+                panic!("Expected Magnet command");
+            };
+            cmd.stream_kind()
+        };
+        assert_eq!(kind(&[]), Ok(StreamKind::Movie));
+        // Season packs are listed under their first episode
+        assert_eq!(
+            kind(&["-t", "tv"]),
+            Ok(StreamKind::Series {
+                season: 1,
+                episode: 1
+            })
+        );
+        assert_eq!(
+            kind(&["-s", "2", "-e", "5"]),
+            Ok(StreamKind::Series {
+                season: 2,
+                episode: 5
+            })
+        );
+        assert!(kind(&["-t", "movie", "-s", "1", "-e", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_quickcast_command() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "quickcast",
+            "the batman",
+            "-Q",
+            "1080p",
+            "-d",
+            "Living Room TV",
+        ]);
+        let Some(Command::Quickcast(cmd)) = cli.command else {
+            panic!("Expected Quickcast command");
+        };
+        assert_eq!(cmd.query, "the batman");
+        assert_eq!(cmd.device.as_deref(), Some("Living Room TV"));
+
+        // The cast it hands over to is a movie at the asked quality
+        let cast = cmd.to_cast("tt1877830".into());
+        assert_eq!(cast.imdb_id, "tt1877830");
+        assert_eq!(cast.quality, Some(QualityFilter::Q1080p));
+        assert!(!cast.auto);
+        assert_eq!(cast.stream_kind(), Ok(StreamKind::Movie));
+
+        // Without a quality the top-scoring stream wins
+        let cli = Cli::parse_from(["streamtui", "qc", "dune"]);
+        let Some(Command::Quickcast(cmd)) = cli.command else {
+            panic!("Expected Quickcast command");
+        };
+        assert!(cmd.device.is_none());
+        assert!(cmd.to_cast("tt1160419".into()).auto);
+    }
+
     #[test]
     fn test_subtitles_command() {
         let cli = Cli::parse_from([
@@ -146,6 +695,47 @@ mod cli_parsing {
         }
     }
 
+    #[test]
+    fn test_get_subtitle_command() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "get-subtitle",
+            "tt0903747",
+            "-s",
+            "1",
+            "-e",
+            "2",
+        ]);
+        match cli.command {
+            Some(Command::GetSubtitle(cmd)) => {
+                assert_eq!(cmd.lang, "eng");
+                assert_eq!(cmd.file_name(), "tt0903747.S01E02.eng.vtt");
+                assert_eq!(
+                    cmd.target_path(),
+                    std::path::PathBuf::from("tt0903747.S01E02.eng.vtt")
+                );
+            }
+            _ => panic!("Expected GetSubtitle command"),
+        }
+
+        let dir = std::env::temp_dir();
+        let cli = Cli::parse_from([
+            "streamtui",
+            "gs",
+            "tt1877830",
+            "--lang",
+            "spa",
+            "-o",
+            dir.to_str().unwrap(),
+        ]);
+        match cli.command {
+            Some(Command::GetSubtitle(cmd)) => {
+                assert_eq!(cmd.target_path(), dir.join("tt1877830.spa.vtt"))
+            }
+            _ => panic!("Expected GetSubtitle command"),
+        }
+    }
+
     #[test]
     fn test_devices_command() {
         let cli = Cli::parse_from(["streamtui", "devices", "-t", "10", "--refresh"]);
@@ -183,6 +773,53 @@ mod cli_parsing {
         }
     }
 
+    #[test]
+    fn test_cast_wait() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "cast",
+            "tt1877830",
+            "-d",
+            "TV",
+            "--wait",
+            "--peer-timeout",
+            "30",
+        ]);
+        match cli.command {
+            Some(Command::Cast(cmd)) => {
+                assert!(cmd.wait);
+                assert_eq!(cmd.peer_timeout, 30);
+            }
+            _ => panic!("Expected Cast command"),
+        }
+
+        // The timeout only means something while waiting
+        assert!(
+            Cli::try_parse_from(["streamtui", "cast", "tt1877830", "--peer-timeout", "30"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cast_whole_season() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "cast",
+            "tt0903747",
+            "-d",
+            "TV",
+            "--season",
+            "1",
+        ]);
+        match cli.command {
+            Some(Command::Cast(cmd)) => {
+                assert_eq!(cmd.season, Some(1));
+                assert_eq!(cmd.episode, None);
+            }
+            _ => panic!("Expected Cast command"),
+        }
+    }
+
     #[test]
     fn test_cast_tv_episode() {
         let cli = Cli::parse_from([
@@ -221,6 +858,20 @@ mod cli_parsing {
         }
     }
 
+    #[test]
+    fn test_watch_command() {
+        let cli = Cli::parse_from(["streamtui", "watch", "-d", "TV", "--until-idle", "--json"]);
+        assert_eq!(cli.device.as_deref(), Some("TV"));
+        assert!(cli.json);
+        match cli.command {
+            Some(Command::Watch(cmd)) => {
+                assert_eq!(cmd.interval, 2);
+                assert!(cmd.until_idle);
+            }
+            _ => panic!("Expected Watch command"),
+        }
+    }
+
     #[test]
     fn test_playback_commands() {
         // Play
@@ -239,6 +890,25 @@ mod cli_parsing {
         }
     }
 
+    #[test]
+    fn test_targeted_stop_and_sessions() {
+        let cli = Cli::parse_from(["streamtui", "stop", "--info-hash", "ABC123"]);
+        match cli.command {
+            Some(Command::Stop(cmd)) => {
+                assert_eq!(cmd.info_hash.as_deref(), Some("ABC123"));
+                assert!(!cmd.kill_stream);
+            }
+            _ => panic!("Expected Stop command"),
+        }
+
+        let cli = Cli::parse_from(["streamtui", "status", "--sessions"]);
+        match cli.command {
+            Some(Command::Status(cmd)) => assert!(cmd.sessions),
+            _ => panic!("Expected Status command"),
+        }
+        assert!(Cli::try_parse_from(["streamtui", "status", "--sessions", "--watch"]).is_err());
+    }
+
     #[test]
     fn test_seek_position_parsing_absolute() {
         let cmd = SeekCmd {
@@ -346,6 +1016,24 @@ mod cli_parsing {
         );
     }
 
+    #[test]
+    fn test_tui_subtitle_file_flag() {
+        let cli = Cli::parse_from(["streamtui", "--subtitle-file", "/subs/movie.srt"]);
+        assert!(!cli.is_cli_mode());
+        assert_eq!(
+            cli.subtitle_file,
+            Some(std::path::PathBuf::from("/subs/movie.srt"))
+        );
+    }
+
+    #[test]
+    fn test_tui_ascii_flag() {
+        let cli = Cli::parse_from(["streamtui", "--ascii"]);
+        assert!(!cli.is_cli_mode());
+        assert!(cli.ascii);
+        assert!(!Cli::parse_from(["streamtui"]).ascii);
+    }
+
     #[test]
     fn test_command_aliases() {
         // Search alias: s
@@ -426,13 +1114,50 @@ mod imdb_validation {
     }
 }
 
+// =============================================================================
+// Result Limit Tests
+// =============================================================================
+
+mod result_limit {
+    use clap::Parser;
+    use streamtui::cli::{apply_limit, Cli, Command};
+
+    #[test]
+    fn test_limit_zero_keeps_everything() {
+        let items: Vec<u32> = (1..=8).collect();
+        assert_eq!(apply_limit(items.clone(), 0), items);
+        assert_eq!(apply_limit(items.clone(), 3), vec![1, 2, 3]);
+        assert_eq!(apply_limit(items.clone(), 20), items);
+        assert_eq!(apply_limit(Vec::<u32>::new(), 0), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_limit_zero_parses_for_each_command() {
+        let limit = |args: &[&str]| match Cli::parse_from(args).command {
+            Some(Command::Search(cmd)) => cmd.limit,
+            Some(Command::Trending(cmd)) => cmd.limit,
+            Some(Command::Streams(cmd)) => cmd.limit,
+            Some(Command::Subtitles(cmd)) => cmd.limit,
+            other => panic!("unexpected command: {:?}", other),
+        };
+        assert_eq!(limit(&["streamtui", "search", "dune", "-l", "0"]), 0);
+        assert_eq!(limit(&["streamtui", "trending", "--limit", "0"]), 0);
+        assert_eq!(limit(&["streamtui", "streams", "tt1877830", "-l", "3"]), 3);
+        assert_eq!(
+            limit(&["streamtui", "subtitles", "tt1877830", "--limit", "0"]),
+            0
+        );
+    }
+}
+
 // =============================================================================
 // JSON Output Format Tests
 // =============================================================================
 
 mod json_output {
-    use serde_json;
-    use streamtui::cli::{ExitCode, JsonOutput, PlaybackState, PlaybackStatus, StatusOk};
+    use streamtui::cli::{
+        ExitCode, JsonEnvelope, JsonOutput, PlaybackState, PlaybackStatus, StatusOk, JSON_SCHEMA,
+    };
 
     #[test]
     fn test_json_output_success() {
@@ -454,6 +1179,21 @@ mod json_output {
         assert!(!json.contains("\"data\""));
     }
 
+    #[test]
+    fn test_json_envelope() {
+        let data = vec!["a", "b"];
+        let json = serde_json::to_string(&JsonEnvelope::new("streams", &data)).unwrap();
+
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"schema":{},"kind":"streams","data":["a","b"]}}"#,
+                JSON_SCHEMA
+            )
+        );
+        assert_eq!(JSON_SCHEMA, 2);
+    }
+
     #[test]
     fn test_status_ok_format() {
         let status = StatusOk::default();
@@ -470,6 +1210,7 @@ mod json_output {
         assert!(json.contains("\"state\":\"idle\""));
         // Optional fields should be omitted when None
         assert!(!json.contains("\"title\""));
+        assert!(json.contains("\"streaming\":false"));
     }
 
     #[test]
@@ -478,18 +1219,68 @@ mod json_output {
             state: PlaybackState::Playing,
             title: Some("The Batman".to_string()),
             device: Some("Living Room TV".to_string()),
-            position: Some(1234),
-            duration: Some(10560),
+            position_secs: Some(1234),
+            duration_secs: Some(10560),
             progress: Some(0.117),
             volume: Some(80),
+            streaming: true,
         };
         let json = serde_json::to_string(&status).unwrap();
 
         assert!(json.contains("\"state\":\"playing\""));
         assert!(json.contains("\"title\":\"The Batman\""));
         assert!(json.contains("\"device\":\"Living Room TV\""));
-        assert!(json.contains("\"position\":1234"));
-        assert!(json.contains("\"duration\":10560"));
+        assert!(json.contains("\"position_secs\":1234"));
+        assert!(json.contains("\"duration_secs\":10560"));
+        assert!(json.contains("\"streaming\":true"));
+        assert_eq!(
+            status.summary(),
+            "playing  The Batman  0:20:34 / 2:56:00  vol 80%  [streaming]"
+        );
+    }
+
+    #[test]
+    fn test_playback_status_from_catt() {
+        // Playing
+        let stdout = "Title: The Batman\nState: PLAYING\nDuration: 10560.0\nCurrent time: 1234.5\nVolume: 80\n";
+        let status = PlaybackStatus::from_catt(true, stdout, "").unwrap();
+        assert_eq!(status.state, PlaybackState::Playing);
+        assert_eq!(status.title.as_deref(), Some("The Batman"));
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["position_secs"], 1234);
+        assert_eq!(json["duration_secs"], 10560);
+        assert_eq!(json["volume"], 80);
+        assert_eq!(json["streaming"], false);
+        assert!(json.get("position").is_none());
+
+        // On but idle: catt exits non-zero, still just an idle status
+        for stderr in [
+            "Error: Chromecast is inactive.\n",
+            "Error: Nothing is currently playing.\n",
+        ] {
+            let status = PlaybackStatus::from_catt(false, "", stderr).unwrap();
+            assert_eq!(status.state, PlaybackState::Idle);
+            assert_eq!(status.position_secs, None);
+        }
+
+        // Missing or unreachable device
+        for stderr in [
+            "Error: Device \"Den TV\" not found.\n",
+            "Error: Could not connect to 192.168.1.50\n",
+        ] {
+            let (msg, code) = PlaybackStatus::from_catt(false, "", stderr).unwrap_err();
+            assert_eq!(code, ExitCode::DeviceNotFound);
+            assert!(msg.starts_with("Device unreachable: "));
+        }
+
+        // Anything else is a failed query, not a missing device
+        let (_, code) = PlaybackStatus::from_catt(false, "", "Traceback: KeyError\n").unwrap_err();
+        assert_eq!(code, ExitCode::CastFailed);
+
+        // Output catt didn't format as expected reads as idle
+        let status = PlaybackStatus::from_catt(true, "garbage without fields", "").unwrap();
+        assert_eq!(status.state, PlaybackState::Idle);
+        assert_eq!(status.position_secs, Some(0));
     }
 
     #[test]
@@ -672,7 +1463,7 @@ Current time: 3692.0
 // =============================================================================
 
 mod stream_source {
-    use streamtui::models::{Quality, StreamSource};
+    use streamtui::models::{HdrType, Quality, StreamSource, VideoCodec};
 
     #[test]
     fn test_quality_ranking() {
@@ -720,12 +1511,17 @@ mod stream_source {
     fn test_format_size() {
         let source = StreamSource {
             name: "Test".to_string(),
+            provider: "Test".to_string(),
             title: "Test".to_string(),
             info_hash: "abc123".to_string(),
             file_idx: None,
             seeds: 100,
             quality: Quality::FHD1080p,
             size_bytes: Some(4_500_000_000), // 4.5 GB
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         };
         let formatted = source.format_size();
         assert!(formatted.contains("4.") || formatted.contains("GB"));
@@ -735,12 +1531,17 @@ mod stream_source {
     fn test_to_magnet() {
         let source = StreamSource {
             name: "The.Batman.2022.1080p.BluRay".to_string(),
+            provider: "The.Batman.2022.1080p.BluRay".to_string(),
             title: "Test".to_string(),
             info_hash: "abc123def456".to_string(),
             file_idx: Some(0),
             seeds: 100,
             quality: Quality::FHD1080p,
             size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         };
         let magnet = source.to_magnet("tt1877830");
 
@@ -929,7 +1730,48 @@ mod quality_filter {
 
 mod output_helpers {
     use clap::Parser;
-    use streamtui::cli::{Cli, Output};
+    use streamtui::cli::{Cli, CompactLine, Output, OutputFormat};
+    use streamtui::models::{
+        CastDevice, HdrType, MediaType, Quality, SearchResult, StreamSource, VideoCodec,
+    };
+
+    #[test]
+    fn test_compact_lines() {
+        let result = SearchResult {
+            id: 414906,
+            media_type: MediaType::Movie,
+            title: "The Batman".into(),
+            year: Some(2022),
+            overview: String::new(),
+            poster_path: None,
+            vote_average: 7.7,
+        };
+        assert_eq!(result.compact_line(), "414906 movie 2022 7.7 The Batman");
+
+        let mut stream = StreamSource {
+            name: "Torrentio\n1080p".into(),
+            provider: "Torrentio".into(),
+            title: "The.Batman.2022.1080p.BluRay.x264-SPARKS".into(),
+            info_hash: "abc".into(),
+            file_idx: None,
+            seeds: 1500,
+            quality: Quality::FHD1080p,
+            size_bytes: Some(9_126_805_504),
+            hdr: HdrType::None,
+            codec: VideoCodec::X264,
+            release_group: Some("SPARKS".into()),
+            direct_url: None,
+        };
+        assert_eq!(stream.compact_line(), "1080p 1500s 8.5GB SPARKS Torrentio");
+        stream.size_bytes = Some(700 * 1024 * 1024);
+        stream.release_group = None;
+        assert_eq!(stream.compact_line(), "1080p 1500s 700MB - Torrentio");
+        stream.size_bytes = None;
+        assert_eq!(stream.compact_line(), "1080p 1500s - - Torrentio");
+
+        let device = CastDevice::manual("192.168.1.36".parse().unwrap());
+        assert_eq!(device.compact_line(), "192.168.1.36:8009 192.168.1.36");
+    }
 
     #[test]
     fn test_output_json_mode() {
@@ -960,6 +1802,64 @@ mod output_helpers {
         // Can't easily test TTY detection, but we can verify the flag isn't set
         assert!(!cli.json);
     }
+
+    #[test]
+    fn test_output_format_flag() {
+        let cli = Cli::parse_from(["streamtui", "--format", "line", "search", "test"]);
+        assert_eq!(cli.output_format(), OutputFormat::Line);
+        let output = Output::new(&cli);
+        assert!(output.line);
+        assert!(!output.json);
+
+        let cli = Cli::parse_from(["streamtui", "search", "test", "--format", "table"]);
+        assert_eq!(cli.output_format(), OutputFormat::Table);
+        assert!(!cli.should_json());
+
+        // --json is shorthand for --format json, and wins over it
+        let cli = Cli::parse_from(["streamtui", "--format", "json", "search", "test"]);
+        assert!(cli.should_json());
+        let cli = Cli::parse_from(["streamtui", "--json", "--format", "line", "search", "test"]);
+        assert_eq!(cli.output_format(), OutputFormat::Json);
+
+        assert!(Cli::try_parse_from(["streamtui", "--format", "csv", "search", "test"]).is_err());
+    }
+
+    #[test]
+    fn test_raw_json_implies_json() {
+        let cli = Cli::parse_from(["streamtui", "--raw-json", "streams", "tt1234567"]);
+        assert!(cli.should_json());
+        let output = Output::new(&cli);
+        assert!(output.json);
+        assert!(output.raw);
+    }
+
+    #[test]
+    fn test_log_file_flag() {
+        let cli = Cli::parse_from(["streamtui", "--log-file", "/tmp/stream.log", "status"]);
+        assert_eq!(
+            cli.log_file,
+            Some(std::path::PathBuf::from("/tmp/stream.log"))
+        );
+
+        let cli = Cli::parse_from(["streamtui", "status"]);
+        assert!(cli.log_file.is_none());
+    }
+
+    #[test]
+    fn test_subtitle_encoding_flag() {
+        let cli = Cli::parse_from([
+            "streamtui",
+            "cast",
+            "tt1877830",
+            "--vlc",
+            "--subtitle-encoding",
+            "windows-1252",
+        ]);
+        assert_eq!(cli.subtitle_encoding.as_deref(), Some("windows-1252"));
+
+        let result = Cli::try_parse_from(["streamtui", "--subtitle-encoding", "klingon", "status"]);
+        assert!(result.is_err());
+    }
 }
 
 // =============================================================================
@@ -976,12 +1876,19 @@ mod cast_helpers {
             quality: Some(QualityFilter::Q1080p),
             season: None,
             episode: None,
+            media_type: None,
             index: None,
+            auto: false,
             subtitle: None,
             subtitle_id: None,
             no_subtitle: false,
+            subtitle_file: None,
             start: None,
             vlc: false,
+            wait: false,
+            peer_timeout: 60,
+            min_seeds: None,
+            healthy: false,
         }
     }
 
@@ -1040,3 +1947,198 @@ mod integration_helpers {
         assert!(CastState::Idle.to_string().contains("Idle"));
     }
 }
+
+// =============================================================================
+// Quiet Mode Tests
+// =============================================================================
+
+mod quiet_mode {
+    use std::path::Path;
+    use std::process::{Command, Output};
+
+    /// Run the binary against a throwaway config directory
+    ///
+    /// HOME is moved too, so platforms that ignore the XDG variables don't
+    /// read the developer's real config.
+    fn run(config_home: &Path, args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_streamtui"))
+            .args(args)
+            .env("HOME", config_home)
+            .env("XDG_CONFIG_HOME", config_home)
+            .env("XDG_CACHE_HOME", config_home.join("cache"))
+            .env_remove("STREAMTUI_PROFILE")
+            .output()
+            .expect("failed to run streamtui")
+    }
+
+    #[test]
+    fn test_quiet_stdout_is_only_results() {
+        let home = std::env::temp_dir().join(format!("streamtui-quiet-{}", std::process::id()));
+        std::fs::create_dir_all(home.join("streamtui")).unwrap();
+        std::fs::write(
+            home.join("streamtui").join("config.toml"),
+            "[[watchlist]]\nid = 27205\nmedia_type = \"movie\"\ntitle = \"Inception\"\nadded_at = 0\n",
+        )
+        .unwrap();
+
+        let out = run(&home, &["-q", "--format", "line", "watchlist", "list"]);
+        assert!(out.status.success());
+        assert_eq!(
+            String::from_utf8_lossy(&out.stdout),
+            "27205 movie Inception\n"
+        );
+
+        // The "no profiles" hint is info: stderr normally, gone with -q
+        let out = run(&home, &["--format", "table", "config", "profile", "list"]);
+        assert!(String::from_utf8_lossy(&out.stderr).contains("No profiles yet"));
+        assert!(!String::from_utf8_lossy(&out.stdout).contains("No profiles yet"));
+
+        let out = run(
+            &home,
+            &["-q", "--format", "table", "config", "profile", "list"],
+        );
+        assert!(out.status.success());
+        assert!(out.stderr.is_empty());
+        let parsed: serde_json::Value = serde_json::from_slice(&out.stdout).unwrap();
+        assert_eq!(parsed, serde_json::json!([]));
+
+        let _ = std::fs::remove_dir_all(home);
+    }
+}
+
+// =============================================================================
+// Stop Command Tests
+// =============================================================================
+
+#[cfg(target_os = "linux")]
+mod stop_command {
+    use std::process::Command;
+
+    #[test]
+    fn test_info_hash_stop_kills_stream_without_cast() {
+        let home = std::env::temp_dir().join(format!("streamtui-stop-{}", std::process::id()));
+        let registry = home.join("cache").join("streamtui");
+        std::fs::create_dir_all(&registry).unwrap();
+
+        // Stands in for webtorrent: stop checks the hash is on its command line
+        let hash = "08ada5a7a6183aae1e09d831df6748d566095a10";
+        let mut stream = Command::new("sh")
+            .args(["-c", "sleep 30; true", "webtorrent", hash])
+            .spawn()
+            .unwrap();
+        let cmdline = format!("/proc/{}/cmdline", stream.id());
+        for _ in 0..100 {
+            if std::fs::read_to_string(&cmdline).is_ok_and(|c| c.contains(hash)) {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        std::fs::write(
+            registry.join("sessions.json"),
+            format!(r#"{{"{}": [{{"pid": {}}}]}}"#, hash, stream.id()),
+        )
+        .unwrap();
+
+        // No Chromecast on the bogus device; the torrent is stopped anyway
+        let out = Command::new(env!("CARGO_BIN_EXE_streamtui"))
+            .args(["--json", "stop", "--info-hash", hash, "-d", "No Such TV"])
+            .env("HOME", &home)
+            .env("XDG_CONFIG_HOME", &home)
+            .env("XDG_CACHE_HOME", home.join("cache"))
+            .output()
+            .unwrap();
+        assert!(out.status.success());
+        let report: serde_json::Value = serde_json::from_slice(&out.stdout).unwrap();
+        assert_eq!(report["data"]["streams_stopped"], serde_json::json!([hash]));
+        assert!(stream.wait().unwrap().code().is_none()); // killed by a signal
+
+        let _ = std::fs::remove_dir_all(home);
+    }
+}
+
+// =============================================================================
+// Watch Command Tests
+// =============================================================================
+
+#[cfg(unix)]
+mod watch_command {
+    use std::os::unix::fs::PermissionsExt;
+    use std::process::{Command, Stdio};
+    use std::time::{Duration, Instant};
+
+    /// catt stand-in: playing for two polls, then the cast app closes
+    const FAKE_CATT: &str = r#"#!/bin/sh
+n=$(cat "$FAKE_CATT_POLLS" 2>/dev/null || echo 0)
+echo $((n + 1)) > "$FAKE_CATT_POLLS"
+if [ "$n" -lt 2 ]; then
+    printf 'Title: The Batman\nState: PLAYING\nDuration: 600\nCurrent time: %s\nVolume: 80\n' "$n"
+else
+    echo 'Error: Chromecast is inactive.' >&2
+    exit 1
+fi
+"#;
+
+    #[test]
+    fn test_until_idle_exits_when_playback_ends() {
+        let home = std::env::temp_dir().join(format!("streamtui-watch-{}", std::process::id()));
+        let bin = home.join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        let catt = bin.join("catt");
+        std::fs::write(&catt, FAKE_CATT).unwrap();
+        std::fs::set_permissions(&catt, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let path = format!(
+            "{}:{}",
+            bin.display(),
+            std::env::var("PATH").unwrap_or_default()
+        );
+
+        let mut child = Command::new(env!("CARGO_BIN_EXE_streamtui"))
+            .args([
+                "--json",
+                "-d",
+                "Living Room TV",
+                "watch",
+                "--until-idle",
+                "-i",
+                "1",
+            ])
+            .env("PATH", path)
+            .env("FAKE_CATT_POLLS", home.join("polls"))
+            .env("HOME", &home)
+            .env("XDG_CONFIG_HOME", &home)
+            .env("XDG_CACHE_HOME", home.join("cache"))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        // Before the fix an inactive TV read as unreachable and this never exited
+        let deadline = Instant::now() + Duration::from_secs(20);
+        while child.try_wait().unwrap().is_none() {
+            if Instant::now() > deadline {
+                let _ = child.kill();
+                panic!("watch --until-idle didn't exit after playback ended");
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        let out = child.wait_with_output().unwrap();
+        assert!(out.status.success());
+
+        // One event per change: two playing positions, then idle
+        let states: Vec<String> = String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .map(|line| {
+                let event: serde_json::Value = serde_json::from_str(line).unwrap();
+                event["data"]["state"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(states, ["playing", "playing", "idle"]);
+        assert!(
+            out.stderr.is_empty(),
+            "{}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+
+        let _ = std::fs::remove_dir_all(home);
+    }
+}
@@ -10,8 +10,8 @@ use std::time::Duration;
 use streamtui::api::{TmdbClient, TorrentioClient};
 use streamtui::app::{App, AppState, DetailState, InputMode, LoadingState, SourcesState};
 use streamtui::models::{
-    CastDevice, CastState, MediaType, MovieDetail, PlaybackStatus, Quality, SearchResult,
-    SeasonSummary, StreamSource, SubFormat, TvDetail,
+    CastDevice, CastState, HdrType, MediaType, MovieDetail, PlaybackStatus, Quality, SearchResult,
+    SeasonSummary, StreamSource, SubFormat, TvDetail, VideoCodec,
 };
 use streamtui::stream::SubtitleClient;
 
@@ -209,6 +209,8 @@ async fn test_tui_search_to_detail_flow() {
         vote_average: 7.8,
         poster_path: Some("/poster.jpg".to_string()),
         backdrop_path: None,
+        collection_id: None,
+        collection_name: None,
     };
     app.detail = Some(DetailState::movie(movie_detail));
 
@@ -257,6 +259,8 @@ async fn test_tui_detail_to_sources_flow() {
         vote_average: 7.8,
         poster_path: None,
         backdrop_path: None,
+        collection_id: None,
+        collection_name: None,
     };
     app.detail = Some(DetailState::movie(movie));
     app.sources = SourcesState::new("The Batman".to_string());
@@ -269,30 +273,45 @@ async fn test_tui_detail_to_sources_flow() {
     let sources = vec![
         StreamSource {
             name: "Torrentio\n4K".to_string(),
+            provider: "Torrentio".to_string(),
             title: "The.Batman.2022.2160p 👤 2.1k\n💾 15.2 GB".to_string(),
             info_hash: "abc123def456789012345678901234567890abcd".to_string(),
             file_idx: Some(0),
             seeds: 2100,
             quality: Quality::UHD4K,
             size_bytes: Some(16_323_727_360),
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         },
         StreamSource {
             name: "Torrentio\n1080p".to_string(),
+            provider: "Torrentio".to_string(),
             title: "The.Batman.2022.1080p 👤 1.5k\n💾 8.5 GB".to_string(),
             info_hash: "def456abc789012345678901234567890abcdef".to_string(),
             file_idx: Some(0),
             seeds: 1500,
             quality: Quality::FHD1080p,
             size_bytes: Some(9_126_805_504),
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         },
         StreamSource {
             name: "Torrentio\n720p".to_string(),
+            provider: "Torrentio".to_string(),
             title: "The.Batman.2022.720p 👤 890\n💾 4.2 GB".to_string(),
             info_hash: "789abc123def456789012345678901234567890".to_string(),
             file_idx: Some(0),
             seeds: 890,
             quality: Quality::HD720p,
             size_bytes: Some(4_509_715_660),
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         },
     ];
     app.sources.set_sources(sources);
@@ -720,7 +739,7 @@ async fn test_cli_search_streams_flow() {
 
     // Sort by seeds
     let mut sorted = filtered;
-    sorted.sort_by(|a, b| b.seeds.cmp(&a.seeds));
+    sorted.sort_by_key(|s| std::cmp::Reverse(s.seeds));
 
     // Best stream should be 4K with most seeds
     assert_eq!(sorted[0].quality, Quality::UHD4K);
@@ -867,7 +886,7 @@ async fn test_full_e2e_flow_mocked() {
 
     // Select best subtitle (most downloads, trusted, not AI)
     let mut sorted_subs = subtitles.clone();
-    sorted_subs.sort_by(|a, b| b.trust_score().cmp(&a.trust_score()));
+    sorted_subs.sort_by_key(|s| std::cmp::Reverse(s.trust_score()));
 
     let best_sub = &sorted_subs[0];
     assert!(best_sub.from_trusted);
@@ -877,7 +896,7 @@ async fn test_full_e2e_flow_mocked() {
     assert!(magnet.contains("magnet:?xt=urn:btih:"));
 
     // Simulate device selection
-    let devices = vec![CastDevice {
+    let devices = [CastDevice {
         id: "192.168.1.50".to_string(),
         name: "Living Room TV".to_string(),
         address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
@@ -1132,8 +1151,9 @@ fn test_playback_started_updates_torrent_session() {
         stream_url: "http://localhost:8888/0".to_string(),
     });
 
+    // State transitions are driven by the log monitor, not PlaybackStarted
     let session = app.playing.torrent.as_ref().unwrap();
-    assert_eq!(session.state, TorrentState::Streaming);
+    assert_eq!(session.state, TorrentState::Starting);
     assert_eq!(
         session.stream_url,
         Some("http://localhost:8888/0".to_string())
@@ -1186,12 +1206,17 @@ async fn test_enter_in_sources_sends_start_playback_command() {
     app.sources.title = "The Batman".to_string();
     app.sources.set_sources(vec![StreamSource {
         name: "Torrentio\n1080p".to_string(),
+        provider: "Torrentio".to_string(),
         title: "The.Batman.2022.1080p".to_string(),
         info_hash: "abc123def456789012345678901234567890abcd".to_string(),
         file_idx: Some(0),
         seeds: 1500,
         quality: Quality::FHD1080p,
         size_bytes: Some(8_000_000_000),
+        hdr: HdrType::None,
+        codec: VideoCodec::Unknown,
+        release_group: None,
+        direct_url: None,
     }]);
 
     // Set up a device
@@ -1213,15 +1238,506 @@ async fn test_enter_in_sources_sends_start_playback_command() {
     // Should have sent StartPlayback command
     let cmd = cmd_rx.try_recv().expect("Should have sent a command");
     match cmd {
-        AppCommand::StartPlayback { magnet, title, device, .. } => {
+        AppCommand::StartPlayback {
+            magnet,
+            title,
+            device,
+            preflight,
+            ..
+        } => {
             assert!(magnet.contains("abc123def456789012345678901234567890abcd"));
             assert_eq!(title, "The Batman");
             assert_eq!(device, "Living Room TV");
+            // The Chromecast is checked before webtorrent starts
+            assert_eq!(preflight.map(|d| d.name).as_deref(), Some("Living Room TV"));
         }
         other => panic!("Expected StartPlayback, got {:?}", other),
     }
 }
 
+#[test]
+fn test_slash_filters_sources_by_title() {
+    let mut app = App::new();
+    app.state = AppState::Sources;
+    let titles = [
+        "Movie.2160p.HEVC-GRP",
+        "Movie.1080p.x264-YIFY",
+        "Movie.1080p.HEVC-RARBG",
+    ];
+    app.sources.set_sources(
+        titles
+            .iter()
+            .map(|t| StreamSource {
+                name: "Torrentio".to_string(),
+                provider: "Torrentio".to_string(),
+                title: t.to_string(),
+                info_hash: "abc123def456789012345678901234567890abcd".to_string(),
+                file_idx: None,
+                seeds: 10,
+                quality: Quality::from_str_loose(t),
+                size_bytes: None,
+                hdr: HdrType::None,
+                codec: VideoCodec::from_title(t),
+                release_group: None,
+                direct_url: None,
+            })
+            .collect(),
+    );
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::empty()));
+    assert!(app.sources.filtering);
+    for c in "hevc".chars() {
+        app.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty()));
+    }
+    assert_eq!(app.sources.filtered_sources().len(), 2);
+
+    // Quick-select indexes into the filtered view
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+    app.handle_key(KeyEvent::new(KeyCode::Char('2'), KeyModifiers::empty()));
+    assert_eq!(
+        app.sources.selected_source().map(|s| s.title.as_str()),
+        Some("Movie.1080p.HEVC-RARBG")
+    );
+
+    // Esc clears the filter but stays in Sources
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+    assert_eq!(app.state, AppState::Sources);
+    assert!(app.sources.filter.is_empty());
+    assert_eq!(app.sources.filtered_sources().len(), 3);
+}
+
+#[tokio::test]
+async fn test_tick_polls_torrent_until_streaming() {
+    use streamtui::app::{AppCommand, AppMessage};
+    use streamtui::models::{TorrentSession, TorrentState};
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.state = AppState::Playing;
+    app.playing.torrent = Some(TorrentSession::new("magnet:?xt=urn:btih:abc".into(), None));
+
+    app.tick();
+    assert!(matches!(
+        cmd_rx.try_recv(),
+        Ok(AppCommand::PollTorrentStatus)
+    ));
+
+    app.handle_message(AppMessage::TorrentProgress {
+        progress: 0.25,
+        speed: 2048,
+        peers: 7,
+        state: None,
+    });
+    let session = app.playing.torrent.as_ref().unwrap();
+    assert_eq!(session.progress, 0.25);
+    assert_eq!(session.download_speed, 2048);
+    assert_eq!(session.peers, 7);
+    assert_eq!(session.state, TorrentState::Starting);
+
+    // The poller moves the phase along as peers show up
+    app.handle_message(AppMessage::TorrentProgress {
+        progress: 0.0,
+        speed: 0,
+        peers: 4,
+        state: Some(TorrentState::Connecting { peers: 4 }),
+    });
+    assert_eq!(
+        app.playing.torrent.as_ref().unwrap().state,
+        TorrentState::Connecting { peers: 4 }
+    );
+
+    // No more polling once the stream is up
+    app.handle_message(AppMessage::TorrentStateUpdate(TorrentState::Streaming));
+    app.tick();
+    assert!(cmd_rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_tick_polls_cast_status_except_vlc() {
+    use streamtui::app::{AppCommand, AppMessage};
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.state = AppState::Playing;
+    app.playing.device = Some(CastDevice {
+        id: "1".to_string(),
+        name: "TV".to_string(),
+        address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
+        port: 8009,
+        model: None,
+    });
+
+    app.tick();
+    assert!(matches!(
+        cmd_rx.try_recv(),
+        Ok(AppCommand::FetchCastStatus { device }) if device == "TV"
+    ));
+
+    // Idle TV before our media loads is ignored
+    app.handle_message(AppMessage::CastStatusLoaded(PlaybackStatus {
+        state: CastState::Idle,
+        position: Duration::ZERO,
+        duration: Duration::ZERO,
+        volume: 1.0,
+        title: None,
+    }));
+    assert!(app.playing.playback.is_none());
+
+    // Paused from the remote shows up in the TUI
+    app.handle_message(AppMessage::CastStatusLoaded(PlaybackStatus {
+        state: CastState::Paused,
+        position: Duration::from_secs(120),
+        duration: Duration::from_secs(7200),
+        volume: 0.4,
+        title: None,
+    }));
+    let playback = app.playing.playback.as_ref().unwrap();
+    assert_eq!(playback.state, CastState::Paused);
+    assert_eq!(playback.position, Duration::from_secs(120));
+
+    // catt can't query local VLC
+    app.playing.device.as_mut().unwrap().name = "VLC (Local)".to_string();
+    app.tick();
+    assert!(cmd_rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_season_queue_advances_when_episode_ends() {
+    use streamtui::app::{AppCommand, AppMessage};
+    use streamtui::models::QueuedItem;
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.cast_devices.push(CastDevice {
+        id: "1".to_string(),
+        name: "TV".to_string(),
+        address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
+        port: 8009,
+        model: None,
+    });
+    app.selected_device = Some(1);
+
+    let item = |episode: u8| QueuedItem {
+        imdb_id: "tt0903747".to_string(),
+        title: format!("Breaking Bad S1E{}", episode),
+        season: 1,
+        episode,
+        source: StreamSource {
+            name: "Torrentio".to_string(),
+            provider: "Torrentio".to_string(),
+            title: format!("Breaking.Bad.S01E0{}.1080p", episode),
+            info_hash: format!("hash{}", episode),
+            file_idx: Some(episode as u32),
+            seeds: 50,
+            quality: Quality::FHD1080p,
+            size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
+        },
+    };
+    let status = |state| PlaybackStatus {
+        state,
+        position: Duration::from_secs(60),
+        duration: Duration::from_secs(2800),
+        volume: 1.0,
+        title: None,
+    };
+
+    app.handle_message(AppMessage::SeasonQueued(vec![item(1), item(2)]));
+    assert_eq!(app.state, AppState::Playing);
+    assert!(matches!(
+        cmd_rx.try_recv(),
+        Ok(AppCommand::StartPlayback { title, file_idx: Some(1), .. }) if title == "Breaking Bad S1E1"
+    ));
+    assert_eq!(app.playing.playback_queue.len(), 1);
+    assert_eq!(app.playing.playback_queue[0].label(), "S01E02");
+
+    // Paused or playing doesn't advance; the TV going idle afterwards does
+    app.handle_message(AppMessage::CastStatusLoaded(status(CastState::Playing)));
+    app.handle_message(AppMessage::CastStatusLoaded(status(CastState::Paused)));
+    assert!(cmd_rx.try_recv().is_err());
+    app.handle_message(AppMessage::CastStatusLoaded(status(CastState::Idle)));
+    assert!(matches!(
+        cmd_rx.try_recv(),
+        Ok(AppCommand::StartPlayback { title, .. }) if title == "Breaking Bad S1E2"
+    ));
+    assert!(app.playing.playback_queue.is_empty());
+    assert_eq!(app.playing.episode, Some(2));
+    assert!(app.playing.playback.is_none());
+}
+
+#[tokio::test]
+async fn test_autoplay_counts_down_to_next_season() {
+    use streamtui::app::{AppCommand, AppMessage};
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.autoplay_next = true;
+    app.detail = Some(DetailState::tv(TvDetail {
+        id: 1396,
+        imdb_id: "tt0903747".to_string(),
+        name: "Breaking Bad".to_string(),
+        year: 2008,
+        seasons: vec![
+            SeasonSummary {
+                season_number: 1,
+                episode_count: 7,
+                name: None,
+                air_date: None,
+            },
+            SeasonSummary {
+                season_number: 2,
+                episode_count: 13,
+                name: None,
+                air_date: None,
+            },
+        ],
+        genres: vec![],
+        overview: String::new(),
+        vote_average: 9.5,
+        poster_path: None,
+        backdrop_path: None,
+    }));
+    app.state = AppState::Playing;
+    app.playing.imdb_id = Some("tt0903747".to_string());
+    app.playing.season = Some(1);
+    app.playing.episode = Some(7);
+
+    let status = |state| PlaybackStatus {
+        state,
+        position: Duration::from_secs(2800),
+        duration: Duration::from_secs(2820),
+        volume: 1.0,
+        title: None,
+    };
+    let episode_ends = |app: &mut App| {
+        app.handle_message(AppMessage::CastStatusLoaded(status(CastState::Playing)));
+        app.handle_message(AppMessage::CastStatusLoaded(status(CastState::Idle)));
+    };
+
+    // The last episode of season 1 rolls over to S02E01; Esc cancels
+    episode_ends(&mut app);
+    let next = app.playing.autoplay.as_ref().unwrap();
+    assert_eq!((next.season, next.episode), (2, 1));
+    assert!(next.remaining_secs() <= 10);
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+    assert!(app.playing.autoplay.is_none());
+    assert_eq!(app.state, AppState::Playing);
+
+    // Left alone, the countdown queues the next episode
+    episode_ends(&mut app);
+    app.playing.autoplay.as_mut().unwrap().starts_at = std::time::Instant::now();
+    app.tick();
+    assert!(matches!(
+        cmd_rx.try_recv(),
+        Ok(AppCommand::CastSeason { season: 2, episodes, .. }) if episodes == vec![1]
+    ));
+    assert!(app.playing.autoplay.is_none());
+
+    // Disabled in config: nothing happens
+    app.autoplay_next = false;
+    episode_ends(&mut app);
+    assert!(app.playing.autoplay.is_none());
+}
+
+#[tokio::test]
+async fn test_saved_position_prompts_resume() {
+    use streamtui::app::AppCommand;
+    use streamtui::app::AppMessage;
+    use streamtui::config::ResumeEntry;
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.enable_resume = true;
+    app.resume.insert(
+        "abc123def456789012345678901234567890abcd".to_string(),
+        ResumeEntry {
+            position: 2530,
+            title: "The Batman".to_string(),
+            ..Default::default()
+        },
+    );
+
+    app.state = AppState::Sources;
+    app.sources.title = "The Batman".to_string();
+    app.sources.set_sources(vec![StreamSource {
+        name: "Torrentio\n1080p".to_string(),
+        provider: "Torrentio".to_string(),
+        title: "The.Batman.2022.1080p".to_string(),
+        info_hash: "ABC123DEF456789012345678901234567890ABCD".to_string(),
+        file_idx: Some(0),
+        seeds: 1500,
+        quality: Quality::FHD1080p,
+        size_bytes: Some(8_000_000_000),
+        hdr: HdrType::None,
+        codec: VideoCodec::Unknown,
+        release_group: None,
+        direct_url: None,
+    }]);
+    app.selected_device = Some(0);
+
+    // Enter shows the prompt instead of starting right away
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+    assert_eq!(app.state, AppState::Playing);
+    assert_eq!(app.playing.resume_prompt, Some(2530));
+    assert!(cmd_rx.try_recv().is_err());
+
+    // 'y' starts playback at the saved offset
+    app.handle_key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::empty()));
+    assert!(app.playing.resume_prompt.is_none());
+    match cmd_rx.try_recv().expect("Should have sent a command") {
+        AppCommand::StartPlayback { start, .. } => assert_eq!(start, Some(2530)),
+        other => panic!("Expected StartPlayback, got {:?}", other),
+    }
+
+    // Polled status persists progress
+    app.handle_message(AppMessage::CastStatusLoaded(PlaybackStatus {
+        state: CastState::Playing,
+        position: Duration::from_secs(2600),
+        duration: Duration::from_secs(10000),
+        volume: 0.5,
+        title: None,
+    }));
+    match cmd_rx.try_recv().expect("Should have sent a command") {
+        AppCommand::SaveResume { info_hash, entry } => {
+            assert_eq!(info_hash, "abc123def456789012345678901234567890abcd");
+            assert_eq!(entry.position, 2600);
+            assert_eq!(entry.file_idx, Some(0));
+        }
+        other => panic!("Expected SaveResume, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_continue_watching_jumps_to_sources() {
+    use streamtui::app::AppCommand;
+    use streamtui::config::HistoryEntry;
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.enable_resume = true;
+    app.home.results = vec![SearchResult {
+        id: 414906,
+        media_type: MediaType::Movie,
+        title: "The Batman".to_string(),
+        year: Some(2022),
+        overview: String::new(),
+        poster_path: None,
+        vote_average: 7.8,
+    }];
+    app.home.list.set_len(1);
+    app.home.set_continue_watching(vec![HistoryEntry {
+        info_hash: "abc123".to_string(),
+        imdb_id: "tt0903747".to_string(),
+        title: "Breaking Bad S2E3".to_string(),
+        season: Some(2),
+        episode: Some(3),
+        position: 1200,
+        duration: 2800,
+        updated_at: 100,
+    }]);
+    assert!(app.home.continue_focused);
+
+    // Down moves into trending, Up back into Continue Watching
+    app.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()));
+    assert!(!app.home.continue_focused);
+    app.handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::empty()));
+    assert!(app.home.continue_focused);
+
+    // Enter skips detail and fetches streams for the saved episode
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+    assert_eq!(app.state, AppState::Sources);
+    assert_eq!(app.sources.title, "Breaking Bad S2E3");
+    match cmd_rx.try_recv().expect("Should have sent a command") {
+        AppCommand::FetchStreams {
+            imdb_id,
+            season,
+            episode,
+            ..
+        } => {
+            assert_eq!(imdb_id, "tt0903747");
+            assert_eq!(season, Some(2));
+            assert_eq!(episode, Some(3));
+        }
+        other => panic!("Expected FetchStreams, got {:?}", other),
+    }
+
+    // Playback remembers the episode for the next resume save
+    app.sources.set_sources(vec![StreamSource {
+        name: "Torrentio\n1080p".to_string(),
+        provider: "Torrentio".to_string(),
+        title: "Breaking.Bad.S02E03.1080p".to_string(),
+        info_hash: "abc123".to_string(),
+        file_idx: Some(0),
+        seeds: 100,
+        quality: Quality::FHD1080p,
+        size_bytes: None,
+        hdr: HdrType::None,
+        codec: VideoCodec::Unknown,
+        release_group: None,
+        direct_url: None,
+    }]);
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+    assert_eq!(app.playing.imdb_id.as_deref(), Some("tt0903747"));
+    assert_eq!(
+        (app.playing.season, app.playing.episode),
+        (Some(2), Some(3))
+    );
+}
+
+#[tokio::test]
+async fn test_mouse_click_selects_and_double_click_opens() {
+    use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::layout::Rect;
+    use streamtui::app::{AppCommand, ListRegion};
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.state = AppState::Search;
+    app.search.set_results(
+        ["The Batman", "Batman Begins", "The Dark Knight"]
+            .iter()
+            .enumerate()
+            .map(|(i, title)| SearchResult {
+                id: i as u64 + 1,
+                media_type: MediaType::Movie,
+                title: title.to_string(),
+                year: None,
+                overview: String::new(),
+                poster_path: None,
+                vote_average: 7.0,
+            })
+            .collect(),
+    );
+    app.record_list(ListRegion::Search, Rect::new(2, 5, 40, 10), 0);
+
+    let click = |row| MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 10,
+        row,
+        modifiers: KeyModifiers::empty(),
+    };
+
+    // Single click selects the row under the cursor
+    assert!(app.handle_mouse(click(7)));
+    assert_eq!(app.search.list.selected, 2);
+    assert!(cmd_rx.try_recv().is_err());
+
+    // Clicks below the last row are ignored
+    assert!(!app.handle_mouse(click(12)));
+    assert_eq!(app.search.list.selected, 2);
+
+    // Wheel moves the selection
+    app.handle_mouse(MouseEvent {
+        kind: MouseEventKind::ScrollUp,
+        ..click(0)
+    });
+    assert_eq!(app.search.list.selected, 1);
+
+    // Double-click opens the title
+    app.handle_mouse(click(6));
+    app.handle_mouse(click(6));
+    assert!(matches!(
+        cmd_rx.try_recv(),
+        Ok(AppCommand::FetchMovieDetail(2))
+    ));
+}
+
 #[tokio::test]
 async fn test_enter_in_sources_with_vlc_default_starts_playback() {
     use streamtui::app::AppCommand;
@@ -1236,12 +1752,17 @@ async fn test_enter_in_sources_with_vlc_default_starts_playback() {
     app.state = AppState::Sources;
     app.sources.set_sources(vec![StreamSource {
         name: "Torrentio\n1080p".to_string(),
+        provider: "Torrentio".to_string(),
         title: "Test".to_string(),
         info_hash: "abc123".to_string(),
         file_idx: Some(0),
         seeds: 100,
         quality: Quality::FHD1080p,
         size_bytes: None,
+        hdr: HdrType::None,
+        codec: VideoCodec::Unknown,
+        release_group: None,
+        direct_url: None,
     }]);
 
     // Press Enter
@@ -1253,8 +1774,11 @@ async fn test_enter_in_sources_with_vlc_default_starts_playback() {
     // Verify command was sent
     let cmd = cmd_rx.try_recv().expect("Should receive command");
     match cmd {
-        AppCommand::StartPlayback { device, .. } => {
+        AppCommand::StartPlayback {
+            device, preflight, ..
+        } => {
             assert_eq!(device, "VLC (Local)");
+            assert!(preflight.is_none());
         }
         other => panic!("Expected StartPlayback, got {:?}", other),
     }
@@ -1396,7 +1920,7 @@ async fn test_s_sends_stop_command() {
     app.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::empty()));
 
     let cmd = cmd_rx.try_recv().expect("Should have sent a command");
-    assert!(matches!(cmd, AppCommand::StopPlayback));
+    assert!(matches!(cmd, AppCommand::StopPlayback { .. }));
 }
 
 #[tokio::test]
@@ -1424,6 +1948,42 @@ async fn test_arrow_keys_send_volume_commands() {
     assert!(matches!(cmd, AppCommand::PlaybackControl { action, .. } if action == "volumedown"));
 }
 
+#[tokio::test]
+async fn test_typed_volume_sends_volume_set() {
+    use streamtui::app::AppCommand;
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.state = AppState::Playing;
+    app.playing.device = Some(CastDevice {
+        id: "1".to_string(),
+        name: "TV".to_string(),
+        address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
+        port: 8009,
+        model: None,
+    });
+
+    // Type "45" then Enter
+    app.handle_key(KeyEvent::new(KeyCode::Char('4'), KeyModifiers::empty()));
+    app.handle_key(KeyEvent::new(KeyCode::Char('5'), KeyModifiers::empty()));
+    assert_eq!(app.playing.volume_input.as_deref(), Some("45"));
+    assert!(cmd_rx.try_recv().is_err(), "No command until Enter");
+
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+    let cmd = cmd_rx.try_recv().expect("Should have sent a command");
+    assert!(matches!(
+        cmd,
+        AppCommand::PlaybackControl { action, value: Some(45), .. } if action == "volume_set"
+    ));
+    assert!(app.playing.volume_input.is_none());
+
+    // ESC cancels entry without leaving Playing
+    app.handle_key(KeyEvent::new(KeyCode::Char('9'), KeyModifiers::empty()));
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+    assert!(app.playing.volume_input.is_none());
+    assert_eq!(app.state, AppState::Playing);
+    assert!(cmd_rx.try_recv().is_err());
+}
+
 #[tokio::test]
 async fn test_left_right_send_seek_commands() {
     use streamtui::app::AppCommand;
@@ -1449,6 +2009,96 @@ async fn test_left_right_send_seek_commands() {
     assert!(matches!(cmd, AppCommand::PlaybackControl { action, .. } if action == "ffwd"));
 }
 
+#[tokio::test]
+async fn test_g_seeks_to_typed_timestamp() {
+    use streamtui::app::AppCommand;
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.state = AppState::Playing;
+    app.playing.device = Some(CastDevice {
+        id: "1".to_string(),
+        name: "TV".to_string(),
+        address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
+        port: 8009,
+        model: None,
+    });
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::empty()));
+    for c in "1:23:45".chars() {
+        app.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty()));
+    }
+    assert_eq!(app.playing.seek_input.as_deref(), Some("1:23:45"));
+
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+    let cmd = cmd_rx.try_recv().expect("Should have sent a command");
+    assert!(matches!(
+        cmd,
+        AppCommand::PlaybackControl { action, value: Some(5025), .. } if action == "seek_to"
+    ));
+    assert!(app.playing.seek_input.is_none());
+}
+
+#[tokio::test]
+async fn test_brackets_shift_subtitle_offset() {
+    use streamtui::app::AppCommand;
+    use streamtui::models::SubtitleResult;
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.state = AppState::Playing;
+    app.playing.device = Some(CastDevice {
+        id: "1".to_string(),
+        name: "TV".to_string(),
+        address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
+        port: 8009,
+        model: None,
+    });
+    app.playing.magnet = Some("magnet:?xt=urn:btih:abc".to_string());
+
+    // Without a subtitle track there is nothing to shift
+    app.handle_key(KeyEvent::new(KeyCode::Char(']'), KeyModifiers::empty()));
+    assert!(cmd_rx.try_recv().is_err());
+    assert_eq!(app.playing.subtitle_offset_ms, 0);
+    app.clear_error();
+
+    app.subtitles.selected = Some(SubtitleResult {
+        id: "1".to_string(),
+        url: "https://subs.example/1.srt".to_string(),
+        language: "eng".to_string(),
+        language_name: "English".to_string(),
+        release: "Release".to_string(),
+        fps: None,
+        format: SubFormat::Srt,
+        downloads: 0,
+        from_trusted: true,
+        hearing_impaired: false,
+        ai_translated: false,
+    });
+
+    app.handle_key(KeyEvent::new(KeyCode::Char(']'), KeyModifiers::empty()));
+    let cmd = cmd_rx
+        .try_recv()
+        .expect("Should restart the subtitle track");
+    assert!(matches!(
+        cmd,
+        AppCommand::RestartWithSubtitles { offset_ms: 500, .. }
+    ));
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('['), KeyModifiers::empty()));
+    app.handle_key(KeyEvent::new(KeyCode::Char('['), KeyModifiers::empty()));
+    let _ = cmd_rx.try_recv();
+    let cmd = cmd_rx
+        .try_recv()
+        .expect("Should restart the subtitle track");
+    assert!(matches!(
+        cmd,
+        AppCommand::RestartWithSubtitles {
+            offset_ms: -500,
+            ..
+        }
+    ));
+    assert_eq!(app.playing.subtitle_offset_ms, -500);
+}
+
 // =============================================================================
 // Auto-fetch Subtitles Tests
 // =============================================================================
@@ -1472,6 +2122,8 @@ async fn test_entering_subtitles_triggers_fetch() {
         vote_average: 7.8,
         poster_path: None,
         backdrop_path: None,
+        collection_id: None,
+        collection_name: None,
     }));
 
     // Press 'u' to go to subtitles
@@ -1489,3 +2141,91 @@ async fn test_entering_subtitles_triggers_fetch() {
         other => panic!("Expected FetchSubtitles, got {:?}", other),
     }
 }
+
+#[test]
+fn test_l_loads_local_subtitle_file() {
+    let path = std::env::temp_dir().join(format!("streamtui_local_sub_{}.srt", std::process::id()));
+    std::fs::write(&path, "1\n00:00:01,000 --> 00:00:02,000\nHello\n").unwrap();
+
+    let mut app = App::new();
+    app.navigate(AppState::Sources);
+    app.navigate(AppState::Subtitles);
+
+    // A missing file keeps the user in Subtitles with an error
+    app.handle_key(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::empty()));
+    for c in "/no/such/file.srt".chars() {
+        app.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty()));
+    }
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+    assert_eq!(app.state, AppState::Subtitles);
+    assert!(app.error.is_some());
+    assert!(app.subtitles.selected.is_none());
+    app.clear_error();
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::empty()));
+    for c in path.to_string_lossy().chars() {
+        app.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty()));
+    }
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+
+    let selected = app
+        .subtitles
+        .selected
+        .clone()
+        .expect("local subtitle selected");
+    assert_eq!(selected.url, format!("file://{}", path.display()));
+    assert_eq!(selected.format, SubFormat::Srt);
+    assert_eq!(app.state, AppState::Sources);
+
+    std::fs::remove_file(&path).ok();
+}
+
+// =============================================================================
+// Watchlist Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_watchlist_toggle_and_reopen() {
+    use streamtui::app::AppCommand;
+
+    let (mut app, mut cmd_rx) = App::with_channels();
+    app.home.results = vec![SearchResult {
+        id: 414906,
+        media_type: MediaType::Movie,
+        title: "The Batman".to_string(),
+        year: Some(2022),
+        overview: String::new(),
+        poster_path: None,
+        vote_average: 7.8,
+    }];
+    app.home.list.set_len(1);
+
+    // 'f' stars the selected trending title and persists it
+    app.handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::empty()));
+    assert!(app.watchlist.contains(414906, MediaType::Movie));
+    match cmd_rx.try_recv().expect("Should have sent a command") {
+        AppCommand::SaveWatchlist(entries) => {
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].title, "The Batman");
+        }
+        other => panic!("Expected SaveWatchlist, got {:?}", other),
+    }
+
+    // 'w' opens the watchlist, Enter re-opens the title
+    app.handle_key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::empty()));
+    assert_eq!(app.state, AppState::Watchlist);
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+    assert!(matches!(
+        cmd_rx.try_recv(),
+        Ok(AppCommand::FetchMovieDetail(414906))
+    ));
+
+    // 'f' again removes it
+    app.handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::empty()));
+    assert!(app.watchlist.entries.is_empty());
+    assert!(matches!(cmd_rx.try_recv(), Ok(AppCommand::SaveWatchlist(e)) if e.is_empty()));
+
+    // ESC goes back home
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+    assert_eq!(app.state, AppState::Home);
+}
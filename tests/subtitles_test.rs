@@ -6,6 +6,7 @@
 use mockito::Server;
 use std::net::{IpAddr, Ipv4Addr};
 use streamtui::models::{SubFormat, SubtitleFile, SubtitleResult};
+use streamtui::stream::subtitles::{decode_subtitle_bytes, decode_subtitle_bytes_as};
 
 // =============================================================================
 // Stremio Subtitle Client - Search Tests
@@ -229,6 +230,401 @@ async fn test_language_code_mapping() {
     assert_eq!(results[5].language_name, "UNK"); // Unknown -> uppercase
 }
 
+// =============================================================================
+// OpenSubtitles Provider Tests
+// =============================================================================
+
+const OPENSUBTITLES_BODY: &str = r#"{
+    "total_count": 2,
+    "data": [
+        {
+            "id": "7654321",
+            "type": "subtitle",
+            "attributes": {
+                "language": "en",
+                "download_count": 48210,
+                "hearing_impaired": false,
+                "ai_translated": false,
+                "from_trusted": true,
+                "fps": 23.976,
+                "release": "Dune.2021.1080p.WEBRip.x264",
+                "files": [{"file_id": 8765432, "file_name": "Dune.2021.1080p.WEBRip.x264.srt"}]
+            }
+        },
+        {
+            "id": "7654322",
+            "type": "subtitle",
+            "attributes": {
+                "language": "es",
+                "download_count": 150,
+                "hearing_impaired": true,
+                "ai_translated": true,
+                "from_trusted": false,
+                "release": "Dune.2021.2160p",
+                "files": [{"file_id": 8765433, "file_name": "Dune.2021.2160p.ass"}]
+            }
+        }
+    ]
+}"#;
+
+fn opensubtitles_only(server: &Server) -> streamtui::stream::SubtitleClient {
+    use streamtui::stream::opensubtitles::OpenSubtitlesClient;
+    use streamtui::stream::subtitles::ProviderChoice;
+    streamtui::stream::SubtitleClient::with_base_url(server.url())
+        .with_opensubtitles(OpenSubtitlesClient::with_base_url(server.url(), "test-key"))
+        .with_provider(ProviderChoice::OpenSubtitles)
+}
+
+/// Test: OpenSubtitles hits become SubtitleResults with a file reference
+#[tokio::test]
+async fn test_opensubtitles_search_movie() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/subtitles")
+        .match_header("api-key", "test-key")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("imdb_id".into(), "1160419".into()),
+            mockito::Matcher::UrlEncoded("languages".into(), "en,es".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(OPENSUBTITLES_BODY)
+        .create_async()
+        .await;
+
+    let client = opensubtitles_only(&server);
+    let results = client.search("tt1160419", Some("spa,eng")).await.unwrap();
+
+    mock.assert_async().await;
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].id, "os-7654321");
+    assert_eq!(results[0].url, "opensubtitles:8765432");
+    assert_eq!(results[0].language_name, "English");
+    assert_eq!(results[0].downloads, 48210);
+    assert!(results[0].from_trusted);
+    assert_eq!(results[1].format, SubFormat::Ass);
+    assert!(results[1].hearing_impaired && results[1].ai_translated);
+    assert!(results[0].trust_score() > results[1].trust_score());
+}
+
+/// Test: Episodes are searched by the show's IMDB ID, season and episode
+#[tokio::test]
+async fn test_opensubtitles_search_episode() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/subtitles")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("parent_imdb_id".into(), "903747".into()),
+            mockito::Matcher::UrlEncoded("season_number".into(), "1".into()),
+            mockito::Matcher::UrlEncoded("episode_number".into(), "5".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+
+    let client = opensubtitles_only(&server);
+    let results = client
+        .search_episode("tt0903747", 1, 5, None)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(results.is_empty());
+}
+
+/// Test: "both" merges the providers, drops repeats and orders by trust
+#[tokio::test]
+async fn test_both_providers_merge_by_trust() {
+    use streamtui::stream::opensubtitles::OpenSubtitlesClient;
+    use streamtui::stream::subtitles::ProviderChoice;
+    let mut server = Server::new_async().await;
+
+    let stremio = server
+        .mock("GET", "/subtitles/movie/tt1160419.json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+            "subtitles": [
+                {"id": "55419", "url": "https://subs.io/55419", "lang": "eng"}
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+    let opensubtitles = server
+        .mock("GET", "/subtitles")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(OPENSUBTITLES_BODY)
+        .create_async()
+        .await;
+
+    let client = streamtui::stream::SubtitleClient::with_base_url(server.url())
+        .with_opensubtitles(OpenSubtitlesClient::with_base_url(server.url(), "test-key"))
+        .with_provider(ProviderChoice::Both);
+    let results = client.search("tt1160419", None).await.unwrap();
+
+    stremio.assert_async().await;
+    opensubtitles.assert_async().await;
+
+    let ids: Vec<&str> = results.iter().map(|s| s.id.as_str()).collect();
+    // Trusted OpenSubtitles hit with downloads, then Stremio, then the untrusted AI one
+    assert_eq!(ids, ["os-7654321", "55419", "os-7654322"]);
+}
+
+/// Test: OpenSubtitles without an API key is an error, "both" falls back to Stremio
+#[tokio::test]
+async fn test_opensubtitles_requires_api_key() {
+    use streamtui::stream::subtitles::ProviderChoice;
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/subtitles/movie/tt1160419.json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"subtitles": []}"#)
+        .create_async()
+        .await;
+
+    let client = streamtui::stream::SubtitleClient::with_base_url(server.url())
+        .with_provider(ProviderChoice::OpenSubtitles);
+    let err = client.search("tt1160419", None).await.unwrap_err();
+    assert!(err.to_string().contains("opensubtitles_api_key"));
+
+    let client = client.with_provider(ProviderChoice::Both);
+    assert!(client.search("tt1160419", None).await.unwrap().is_empty());
+    mock.assert_async().await;
+}
+
+/// Test: Downloading an OpenSubtitles result asks for a link first
+#[tokio::test]
+async fn test_opensubtitles_preview_resolves_download_link() {
+    let mut server = Server::new_async().await;
+
+    let link = server
+        .mock("POST", "/download")
+        .match_header("api-key", "test-key")
+        .match_body(mockito::Matcher::Json(
+            serde_json::json!({"file_id": 8765432}),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"link": "{}/files/dune.srt", "remaining": 4}}"#,
+            server.url()
+        ))
+        .create_async()
+        .await;
+    let file = server
+        .mock("GET", "/files/dune.srt")
+        .with_status(200)
+        .with_body("1\n00:00:01,000 --> 00:00:02,000\nThe spice must flow\n")
+        .create_async()
+        .await;
+
+    let client = opensubtitles_only(&server);
+    let cues = client.fetch_preview("opensubtitles:8765432").await.unwrap();
+
+    link.assert_async().await;
+    file.assert_async().await;
+    assert_eq!(cues[0].text, "The spice must flow");
+}
+
+// =============================================================================
+// Subtitle Preview Tests
+// =============================================================================
+
+/// Test: Preview parses the first cues of a plain SRT download
+#[tokio::test]
+async fn test_fetch_preview_parses_srt() {
+    let mut server = Server::new_async().await;
+
+    let srt: String = (1..=20)
+        .map(|i| {
+            format!(
+                "{}\n00:00:{:02},000 --> 00:00:{:02},500\nLine {}\n\n",
+                i, i, i, i
+            )
+        })
+        .collect();
+    let mock = server
+        .mock("GET", "/file/123")
+        .with_status(200)
+        .with_body(srt)
+        .create_async()
+        .await;
+
+    let client = streamtui::stream::SubtitleClient::with_base_url(server.url());
+    let cues = client
+        .fetch_preview(&format!("{}/file/123", server.url()))
+        .await
+        .expect("Should parse preview");
+
+    mock.assert_async().await;
+
+    assert_eq!(cues.len(), streamtui::stream::subtitles::PREVIEW_CUES);
+    assert_eq!(cues[0].text, "Line 1");
+    assert_eq!(cues[0].start_ms, 1000);
+    assert_eq!(cues[0].end_ms, 1500);
+}
+
+/// Test: An ASS download converts to WebVTT (what `get-subtitle` writes)
+#[tokio::test]
+async fn test_fetch_subtitle_ass_to_webvtt() {
+    let mut server = Server::new_async().await;
+
+    let ass = "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+               Dialogue: 0,0:00:01.00,0:00:02.50,Default,,0,0,0,,Hello there\n";
+    let mock = server
+        .mock("GET", "/file/9.ass")
+        .with_status(200)
+        .with_body(ass)
+        .create_async()
+        .await;
+
+    let client = streamtui::stream::SubtitleClient::with_base_url(server.url());
+    let (text, format) = client
+        .fetch_subtitle(&format!("{}/file/9.ass", server.url()))
+        .await
+        .expect("Should download");
+
+    mock.assert_async().await;
+
+    assert_eq!(format, SubFormat::Ass);
+    let vtt = streamtui::stream::subtitles::to_webvtt(text, format);
+    assert!(vtt.starts_with("WEBVTT"));
+    assert!(vtt.contains("00:00:01.000 --> 00:00:02.500"));
+    assert!(vtt.contains("Hello there"));
+}
+
+/// Test: Gzip-compressed subtitles (as OpenSubtitles serves them) are decompressed
+#[tokio::test]
+async fn test_fetch_preview_decompresses_gzip() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/file/456.gz")
+        .with_status(200)
+        .with_body(GZIPPED_SRT)
+        .create_async()
+        .await;
+
+    let client = streamtui::stream::SubtitleClient::with_base_url(server.url());
+    let cues = client
+        .fetch_preview(&format!("{}/file/456.gz", server.url()))
+        .await
+        .expect("Should decompress preview");
+
+    mock.assert_async().await;
+
+    assert_eq!(cues.len(), 2);
+    assert_eq!(cues[0].text, "Hola");
+    assert_eq!(cues[1].text, "Adiós");
+}
+
+// =============================================================================
+// Subtitle Decoding Tests
+// =============================================================================
+
+/// gzip of "1\n00:00:01,000 --> 00:00:02,000\nHola\n\n2\n00:00:03,000 --> 00:00:04,000\nAdiós\n"
+const GZIPPED_SRT: &[u8] = &[
+    0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x33, 0xe4, 0x32, 0x30, 0xb0, 0x02,
+    0x21, 0x43, 0x1d, 0x03, 0x03, 0x03, 0x05, 0x5d, 0x5d, 0x3b, 0x05, 0xa8, 0x80, 0x11, 0x48, 0x80,
+    0xcb, 0x23, 0x3f, 0x27, 0x91, 0x8b, 0xcb, 0x08, 0xa6, 0xca, 0x18, 0x5d, 0x95, 0x09, 0x58, 0x95,
+    0x63, 0x4a, 0xe6, 0xe1, 0xcd, 0xc5, 0x5c, 0x00, 0x17, 0xaf, 0x6c, 0xbf, 0x4d, 0x00, 0x00, 0x00,
+];
+
+/// Test: Gzipped SRT is decompressed by magic bytes alone
+#[tokio::test]
+async fn test_decode_gzipped_srt() {
+    let text = decode_subtitle_bytes(GZIPPED_SRT.to_vec(), None)
+        .await
+        .expect("Should decompress");
+    assert!(text.starts_with("1\n00:00:01,000 --> 00:00:02,000\nHola"));
+    assert!(text.contains("Adiós"));
+
+    // The header is honored too
+    let text = decode_subtitle_bytes(GZIPPED_SRT.to_vec(), Some("gzip"))
+        .await
+        .unwrap();
+    assert!(text.contains("Hola"));
+}
+
+/// Test: A stale gzip Content-Encoding on a plain body keeps the text
+#[tokio::test]
+async fn test_decode_ignores_stale_gzip_header() {
+    let srt = b"1\n00:00:01,000 --> 00:00:02,000\nHello\n".to_vec();
+    let text = decode_subtitle_bytes(srt, Some("gzip")).await.unwrap();
+    assert_eq!(text, "1\n00:00:01,000 --> 00:00:02,000\nHello\n");
+}
+
+/// Test: Latin-1 / Windows-1252 Spanish subs are transcoded to UTF-8
+#[tokio::test]
+async fn test_decode_latin1_srt() {
+    // "¿Qué pasó, señor?" plus Windows-1252 curly quotes around "sí"
+    let mut srt = b"1\n00:00:01,000 --> 00:00:02,000\n".to_vec();
+    srt.extend_from_slice(&[
+        0xbf, b'Q', b'u', 0xe9, b' ', b'p', b'a', b's', 0xf3, b',', b' ',
+    ]);
+    srt.extend_from_slice(&[
+        b's', b'e', 0xf1, b'o', b'r', b'?', b' ', 0x93, b's', 0xed, 0x94, b'\n',
+    ]);
+
+    let text = decode_subtitle_bytes(srt, None).await.unwrap();
+    assert!(text.ends_with("¿Qué pasó, señor? “sí”\n"), "got {:?}", text);
+}
+
+/// Test: A forced charset replaces detection
+#[tokio::test]
+async fn test_decode_with_forced_charset() {
+    // "canción" in Windows-1252
+    let cp1252 = b"canci\xf3n".to_vec();
+    let charset = encoding_rs::Encoding::for_label(b"windows-1252");
+    assert_eq!(
+        decode_subtitle_bytes_as(cp1252, None, charset)
+            .await
+            .unwrap(),
+        "canción"
+    );
+
+    // UTF-16 without a BOM is valid UTF-8 full of NULs, so only the override reads it
+    let utf16: Vec<u8> = "canción"
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    assert_ne!(
+        decode_subtitle_bytes(utf16.clone(), None).await.unwrap(),
+        "canción"
+    );
+    let charset = encoding_rs::Encoding::for_label(b"utf-16");
+    assert_eq!(
+        decode_subtitle_bytes_as(utf16, None, charset)
+            .await
+            .unwrap(),
+        "canción"
+    );
+}
+
+/// Test: UTF-8 passes through untouched, minus a byte order mark
+#[tokio::test]
+async fn test_decode_utf8_strips_bom() {
+    let mut srt = "\u{feff}1\n00:00:01,000 --> 00:00:02,000\nAñejo\n"
+        .as_bytes()
+        .to_vec();
+    let text = decode_subtitle_bytes(srt.clone(), None).await.unwrap();
+    assert_eq!(text, "1\n00:00:01,000 --> 00:00:02,000\nAñejo\n");
+
+    srt.drain(..3);
+    assert_eq!(decode_subtitle_bytes(srt, None).await.unwrap(), text);
+}
+
 // =============================================================================
 // SRT to WebVTT Conversion Tests
 // =============================================================================
@@ -258,7 +654,10 @@ Second subtitle line"#;
     assert!(webvtt.contains("00:02:30.100"));
 
     // Should NOT contain commas in timestamps
-    assert!(!webvtt.contains("00:01:23,"), "Should not have comma timestamps");
+    assert!(
+        !webvtt.contains("00:01:23,"),
+        "Should not have comma timestamps"
+    );
 }
 
 /// Test: Preserve dialogue content during conversion
@@ -275,7 +674,10 @@ I'm vengeance."#;
     let webvtt = streamtui::stream::SubtitleClient::srt_to_webvtt(srt);
 
     assert!(webvtt.contains("Batman"), "Dialogue should be preserved");
-    assert!(webvtt.contains("vengeance"), "Second line should be preserved");
+    assert!(
+        webvtt.contains("vengeance"),
+        "Second line should be preserved"
+    );
 }
 
 /// Test: Handle multi-line subtitles
@@ -298,7 +700,10 @@ Line three"#;
 #[test]
 fn test_srt_to_webvtt_empty() {
     let webvtt = streamtui::stream::SubtitleClient::srt_to_webvtt("");
-    assert!(webvtt.starts_with("WEBVTT"), "Empty SRT should produce valid header");
+    assert!(
+        webvtt.starts_with("WEBVTT"),
+        "Empty SRT should produce valid header"
+    );
 }
 
 // =============================================================================
@@ -410,7 +815,7 @@ fn test_cast_command_with_subtitle() {
     let video_url = "http://192.168.1.100:8888/0";
     let subtitle_url = "http://192.168.1.100:8889/subtitles/en.vtt";
 
-    let args = vec![
+    let args = [
         "-d".to_string(),
         device_name.to_string(),
         "cast".to_string(),
@@ -427,7 +832,7 @@ fn test_cast_command_with_subtitle() {
 /// Test: Cast command without subtitles
 #[test]
 fn test_cast_command_without_subtitle() {
-    let args = vec![
+    let args = [
         "-d".to_string(),
         "Living Room TV".to_string(),
         "cast".to_string(),
@@ -3,8 +3,11 @@
 //! Tests search, metadata retrieval, and error handling.
 
 use mockito::{Matcher, Server};
-use streamtui::api::TmdbClient;
-use streamtui::models::MediaType;
+use std::time::Duration;
+use streamtui::api::tmdb::TmdbError;
+use streamtui::api::{RetryPolicy, TmdbClient};
+use streamtui::cli::TrendingWindow;
+use streamtui::models::{DiscoverSort, MediaType};
 
 // =============================================================================
 // Search Tests
@@ -204,6 +207,288 @@ async fn test_search_filters_person_results() {
     assert_eq!(results[1].media_type, MediaType::Tv);
 }
 
+#[tokio::test]
+async fn test_search_untitled_and_interleaved_people() {
+    let mut server = Server::new_async().await;
+
+    // People between media rows, and media rows with no usable title
+    let mock_response = r#"{
+        "page": 1,
+        "results": [
+            {
+                "id": 10,
+                "media_type": "person",
+                "name": "First Actor",
+                "known_for": [{"id": 1, "media_type": "movie", "title": "Known"}]
+            },
+            {
+                "id": 1,
+                "media_type": "movie",
+                "title": "Dune",
+                "release_date": "2021-09-15"
+            },
+            {
+                "id": 11,
+                "media_type": "person",
+                "profile_path": null
+            },
+            {
+                "id": 2,
+                "media_type": "movie",
+                "title": null,
+                "release_date": "2019-01-01"
+            },
+            {
+                "id": 3,
+                "media_type": "tv",
+                "name": "  "
+            },
+            {
+                "id": 12,
+                "media_type": "collection",
+                "name": "Dune Collection"
+            },
+            {
+                "id": 4,
+                "media_type": "tv",
+                "name": "Dune: Prophecy",
+                "first_air_date": "2024-11-17"
+            }
+        ],
+        "total_results": 7,
+        "total_pages": 1
+    }"#;
+
+    let _mock = server
+        .mock("GET", "/search/multi")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let results = client.search("dune").await.unwrap();
+
+    let rows: Vec<(u64, MediaType, &str)> = results
+        .iter()
+        .map(|r| (r.id, r.media_type, r.title.as_str()))
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            (1, MediaType::Movie, "Dune"),
+            (2, MediaType::Movie, "Untitled"),
+            (3, MediaType::Tv, "Untitled"),
+            (4, MediaType::Tv, "Dune: Prophecy"),
+        ]
+    );
+    assert_eq!(results[1].year, Some(2019));
+
+    // The two people and the collection are counted for the log
+    let page = client.search_paged("dune", 1).await.unwrap();
+    assert_eq!(page.skipped, 3);
+}
+
+#[tokio::test]
+async fn test_search_drops_adult_results() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = r#"{
+        "page": 1,
+        "results": [
+            {
+                "id": 414906,
+                "media_type": "movie",
+                "title": "The Batman",
+                "release_date": "2022-03-01",
+                "adult": false
+            },
+            {
+                "id": 999001,
+                "media_type": "movie",
+                "title": "Batman XXX",
+                "release_date": "2010-01-01",
+                "adult": true
+            }
+        ],
+        "total_results": 2,
+        "total_pages": 1
+    }"#;
+
+    // TMDB is asked to leave adult titles out...
+    let mock = server
+        .mock("GET", "/search/multi")
+        .match_query(Matcher::UrlEncoded("include_adult".into(), "false".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response)
+        .create_async()
+        .await;
+
+    // ...and any it returns anyway are dropped
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let results = client.search("batman").await.unwrap();
+    mock.assert_async().await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, 414906);
+
+    let mock = server
+        .mock("GET", "/search/multi")
+        .match_query(Matcher::UrlEncoded("include_adult".into(), "true".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url()).with_include_adult(true);
+    let results = client.search("batman").await.unwrap();
+    mock.assert_async().await;
+    assert_eq!(results.len(), 2);
+}
+
+#[tokio::test]
+async fn test_search_paged_returns_total_pages() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = r#"{
+        "page": 2,
+        "results": [
+            {
+                "id": 268,
+                "media_type": "movie",
+                "title": "Batman",
+                "release_date": "1989-06-23",
+                "overview": "The Dark Knight of Gotham City",
+                "poster_path": "/cij4dd21v2Rk2YtUQbV5kW69WB2.jpg",
+                "vote_average": 7.2
+            }
+        ],
+        "total_results": 140,
+        "total_pages": 7
+    }"#;
+
+    let mock = server
+        .mock("GET", "/search/multi")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("query".into(), "batman".into()),
+            Matcher::UrlEncoded("page".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let page = client.search_paged("batman", 2).await.unwrap();
+
+    mock.assert_async().await;
+
+    assert_eq!(page.page, 2);
+    assert_eq!(page.total_pages, 7);
+    assert_eq!(page.results.len(), 1);
+    assert_eq!(page.results[0].title, "Batman");
+}
+
+// =============================================================================
+// Person Search Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_search_person_parses_known_for() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = r#"{
+        "page": 1,
+        "results": [
+            {
+                "id": 2037,
+                "name": "Cillian Murphy",
+                "known_for_department": "Acting",
+                "profile_path": "/llkbyWKwpfowZ6C8peBjIV9jj99.jpg",
+                "known_for": [
+                    {
+                        "id": 872585,
+                        "media_type": "movie",
+                        "title": "Oppenheimer",
+                        "release_date": "2023-07-19",
+                        "overview": "The story of J. Robert Oppenheimer",
+                        "poster_path": "/8Gxv8gSFCU0XGDykEGv7zR1n2ua.jpg",
+                        "vote_average": 8.1
+                    },
+                    {
+                        "id": 60574,
+                        "media_type": "tv",
+                        "name": "Peaky Blinders",
+                        "first_air_date": "2013-09-12",
+                        "overview": "A gangster family epic",
+                        "poster_path": "/vUUqzWa2LnHIVqkaKVlVGkVcZIW.jpg",
+                        "vote_average": 8.5
+                    }
+                ]
+            }
+        ],
+        "total_results": 1,
+        "total_pages": 1
+    }"#;
+
+    let mock = server
+        .mock("GET", "/search/person")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("query".into(), "cillian murphy".into()),
+            Matcher::UrlEncoded("page".into(), "1".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let people = client.search_person("cillian murphy").await.unwrap();
+
+    mock.assert_async().await;
+
+    assert_eq!(people.len(), 1);
+    assert_eq!(people[0].id, 2037);
+    assert_eq!(people[0].name, "Cillian Murphy");
+    assert_eq!(
+        people[0].profile_path.as_deref(),
+        Some("/llkbyWKwpfowZ6C8peBjIV9jj99.jpg")
+    );
+
+    let known_for = &people[0].known_for;
+    assert_eq!(known_for.len(), 2);
+    assert_eq!(known_for[0].title, "Oppenheimer");
+    assert_eq!(known_for[0].media_type, MediaType::Movie);
+    assert_eq!(known_for[0].year, Some(2023));
+    assert_eq!(known_for[1].title, "Peaky Blinders");
+    assert_eq!(known_for[1].media_type, MediaType::Tv);
+}
+
+#[tokio::test]
+async fn test_search_person_empty_results() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/search/person")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"page": 1, "results": [], "total_results": 0, "total_pages": 0}"#)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let people = client.search_person("nobody at all").await.unwrap();
+
+    mock.assert_async().await;
+    assert!(people.is_empty());
+}
+
 // =============================================================================
 // Trending Tests
 // =============================================================================
@@ -248,7 +533,7 @@ async fn test_trending_returns_results() {
         .await;
 
     let client = TmdbClient::with_base_url("test_key", server.url());
-    let results = client.trending().await.unwrap();
+    let results = client.trending(TrendingWindow::Week).await.unwrap();
 
     mock.assert_async().await;
 
@@ -258,6 +543,86 @@ async fn test_trending_returns_results() {
     assert_eq!(results[1].title, "Trending Show");
 }
 
+#[tokio::test]
+async fn test_trending_uses_requested_window() {
+    let mut server = Server::new_async().await;
+
+    let day = server
+        .mock("GET", "/trending/all/day")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body(r#"{"page": 1, "results": []}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let week = server
+        .mock("GET", "/trending/all/week")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body(r#"{"page": 1, "results": []}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    client.trending(TrendingWindow::Day).await.unwrap();
+    day.assert_async().await;
+
+    client.trending(TrendingWindow::Week).await.unwrap();
+    week.assert_async().await;
+}
+
+// =============================================================================
+// Discover Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_discover_tags_results_with_media_type() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = r#"{
+        "page": 1,
+        "results": [
+            {
+                "id": 300,
+                "name": "Genre Show",
+                "first_air_date": "2019-05-01",
+                "overview": "A show",
+                "poster_path": null,
+                "vote_average": 8.1
+            }
+        ],
+        "total_results": 1,
+        "total_pages": 1
+    }"#;
+
+    let mock = server
+        .mock("GET", "/discover/tv")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("with_genres".into(), "10765".into()),
+            Matcher::UrlEncoded("sort_by".into(), "vote_average.desc".into()),
+            Matcher::UrlEncoded("vote_count.gte".into(), "200".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let results = client
+        .discover(10765, MediaType::Tv, DiscoverSort::Rating)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "Genre Show");
+    assert_eq!(results[0].media_type, MediaType::Tv);
+    assert_eq!(results[0].year, Some(2019));
+}
+
 // =============================================================================
 // Movie Detail Tests
 // =============================================================================
@@ -312,6 +677,74 @@ async fn test_movie_detail_gets_imdb() {
     assert!((detail.vote_average - 7.8).abs() < 0.01);
 }
 
+#[tokio::test]
+async fn test_movie_detail_collection() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = r#"{
+        "id": 155,
+        "imdb_id": "tt0468569",
+        "title": "The Dark Knight",
+        "release_date": "2008-07-16",
+        "runtime": 152,
+        "genres": [{"id": 28, "name": "Action"}],
+        "overview": "Batman raises the stakes in his war on crime.",
+        "vote_average": 8.5,
+        "poster_path": null,
+        "backdrop_path": null,
+        "belongs_to_collection": {
+            "id": 263,
+            "name": "The Dark Knight Collection",
+            "poster_path": "/bqS2lMgGkuodIXtDILFWTSWDDpa.jpg",
+            "backdrop_path": null
+        }
+    }"#;
+
+    server
+        .mock("GET", "/movie/155")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response)
+        .create_async()
+        .await;
+    let parts = server
+        .mock("GET", "/collection/263")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "id": 263,
+                "name": "The Dark Knight Collection",
+                "parts": [
+                    {"id": 49026, "title": "The Dark Knight Rises", "release_date": "2012-07-17"},
+                    {"id": 272, "title": "Batman Begins", "release_date": "2005-06-10"},
+                    {"id": 155, "title": "The Dark Knight", "release_date": "2008-07-16"}
+                ]
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let detail = client.movie_detail(155).await.unwrap();
+    assert_eq!(detail.collection_id, Some(263));
+    assert_eq!(
+        detail.collection_name.as_deref(),
+        Some("The Dark Knight Collection")
+    );
+
+    let movies = client.collection(263).await.unwrap();
+    parts.assert_async().await;
+    let titles: Vec<&str> = movies.iter().map(|m| m.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        vec!["Batman Begins", "The Dark Knight", "The Dark Knight Rises"]
+    );
+    assert!(movies.iter().all(|m| m.media_type == MediaType::Movie));
+}
+
 #[tokio::test]
 async fn test_movie_detail_handles_missing_imdb() {
     let mut server = Server::new_async().await;
@@ -345,6 +778,7 @@ async fn test_movie_detail_handles_missing_imdb() {
 
     // Should have empty string for missing IMDB
     assert!(detail.imdb_id.is_empty());
+    assert_eq!(detail.collection_id, None);
 }
 
 // =============================================================================
@@ -406,6 +840,174 @@ async fn test_tv_detail_gets_seasons() {
     assert!(detail.genres.contains(&"Drama".to_string()));
 }
 
+// =============================================================================
+// IMDB Lookup Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_similar_and_recommendations() {
+    let mut server = Server::new_async().await;
+    let body = r#"{
+        "page": 1,
+        "results": [
+            {"id": 155, "title": "The Dark Knight", "release_date": "2008-07-16", "vote_average": 8.5},
+            {"id": 272, "title": "Batman Begins", "release_date": "2005-06-10", "vote_average": 7.7}
+        ],
+        "total_pages": 1
+    }"#;
+    let similar = server
+        .mock("GET", "/movie/414906/similar")
+        .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await;
+    let recommended = server
+        .mock("GET", "/tv/1396/recommendations")
+        .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"results": [{"id": 60059, "name": "Better Call Saul", "first_air_date": "2015-02-08"}]}"#)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let results = client.similar(MediaType::Movie, 414906).await.unwrap();
+    similar.assert_async().await;
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].title, "The Dark Knight");
+    // Untagged results take the title's type
+    assert_eq!(results[0].media_type, MediaType::Movie);
+
+    let results = client.recommendations(MediaType::Tv, 1396).await.unwrap();
+    recommended.assert_async().await;
+    assert_eq!(results[0].title, "Better Call Saul");
+    assert_eq!(results[0].media_type, MediaType::Tv);
+}
+
+#[tokio::test]
+async fn test_videos_keeps_youtube_only() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/movie/414906/videos")
+        .match_query(Matcher::UrlEncoded("api_key".into(), "test_key".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "id": 414906,
+                "results": [
+                    {"key": "mqqft2x_Aa4", "name": "Main Trailer", "site": "YouTube", "type": "Trailer", "official": true},
+                    {"key": "123456", "name": "Teaser", "site": "Vimeo", "type": "Teaser", "official": true}
+                ]
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let videos = client.videos(MediaType::Movie, 414906).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(videos.len(), 1);
+    assert_eq!(videos[0].key, "mqqft2x_Aa4");
+    assert_eq!(videos[0].kind, "Trailer");
+    assert!(videos[0].official);
+}
+
+async fn mock_find(server: &mut mockito::ServerGuard, imdb_id: &str, body: &str) -> mockito::Mock {
+    server
+        .mock("GET", format!("/find/{}", imdb_id).as_str())
+        .match_query(Matcher::UrlEncoded(
+            "external_source".into(),
+            "imdb_id".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_find_by_imdb_movie() {
+    let mut server = Server::new_async().await;
+    let mock = mock_find(
+        &mut server,
+        "tt1877830",
+        r#"{
+            "movie_results": [{
+                "id": 414906,
+                "title": "The Batman",
+                "release_date": "2022-03-01",
+                "overview": "Batman ventures into Gotham City's underworld",
+                "vote_average": 7.8
+            }],
+            "person_results": [],
+            "tv_results": [],
+            "tv_episode_results": []
+        }"#,
+    )
+    .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let found = client.find_by_imdb("tt1877830").await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(found.id, 414906);
+    assert_eq!(found.media_type, MediaType::Movie);
+    assert_eq!(found.title, "The Batman");
+    assert_eq!(found.year, Some(2022));
+}
+
+#[tokio::test]
+async fn test_find_by_imdb_prefers_non_empty_list() {
+    let mut server = Server::new_async().await;
+
+    // Only TV results
+    mock_find(
+        &mut server,
+        "tt0903747",
+        r#"{
+            "movie_results": [],
+            "tv_results": [{"id": 1396, "name": "Breaking Bad", "first_air_date": "2008-01-20"}]
+        }"#,
+    )
+    .await;
+    // Both lists populated: the movie wins
+    mock_find(
+        &mut server,
+        "tt0000001",
+        r#"{
+            "movie_results": [{"id": 10, "title": "Film"}],
+            "tv_results": [{"id": 20, "name": "Show"}]
+        }"#,
+    )
+    .await;
+    // Nothing at all
+    mock_find(
+        &mut server,
+        "tt9999999",
+        r#"{"movie_results": [], "tv_results": []}"#,
+    )
+    .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+
+    let tv = client.find_by_imdb("tt0903747").await.unwrap();
+    assert_eq!(tv.id, 1396);
+    assert_eq!(tv.media_type, MediaType::Tv);
+    assert_eq!(tv.title, "Breaking Bad");
+
+    let both = client.find_by_imdb("tt0000001").await.unwrap();
+    assert_eq!(both.id, 10);
+    assert_eq!(both.media_type, MediaType::Movie);
+
+    let err = client.find_by_imdb("tt9999999").await.unwrap_err();
+    assert!(err.to_string().contains("not found"));
+}
+
 // =============================================================================
 // TV Season Tests
 // =============================================================================
@@ -489,10 +1091,33 @@ async fn test_tv_season_gets_episodes() {
     assert_eq!(episodes[0].name, "Pilot");
     assert_eq!(episodes[0].season, 1);
     assert_eq!(episodes[0].runtime, Some(58));
+    assert_eq!(episodes[0].overview, "Walter White joins forces with Jesse");
     assert_eq!(episodes[6].episode, 7);
     assert_eq!(episodes[6].name, "A No-Rough-Stuff-Type Deal");
 }
 
+#[tokio::test]
+async fn test_tv_season_missing_overview_and_runtime() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/tv/1396/season/2")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"episodes": [{"episode_number": 1, "name": "TBA", "overview": null, "runtime": null}]}"#)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url());
+    let episodes = client.tv_season(1396, 2).await.unwrap();
+
+    mock.assert_async().await;
+
+    assert_eq!(episodes[0].overview, "");
+    assert_eq!(episodes[0].runtime, None);
+}
+
 // =============================================================================
 // Error Handling Tests
 // =============================================================================
@@ -522,14 +1147,44 @@ async fn test_handles_rate_limit() {
         .await;
 
     let client = TmdbClient::with_base_url("test_key", server.url());
+    let started = std::time::Instant::now();
     let result = client.search("test").await;
 
-    // Should succeed after retry
+    // Should succeed after waiting out the Retry-After
     assert!(result.is_ok());
+    assert!(started.elapsed() >= Duration::from_secs(1));
     mock_429.assert_async().await;
     mock_200.assert_async().await;
 }
 
+#[tokio::test]
+async fn test_rate_limit_reports_retry_after() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/search/multi")
+        .match_query(Matcher::Any)
+        .with_status(429)
+        .with_header("Retry-After", "30")
+        .expect(1)
+        .create_async()
+        .await;
+
+    // No retries left: the caller gets the wait TMDB asked for
+    let client = TmdbClient::with_base_url("test_key", server.url())
+        .with_retry(RetryPolicy::with_max_retries(0));
+    let err = client.search("test").await.unwrap_err();
+    mock.assert_async().await;
+
+    match err.downcast_ref::<TmdbError>() {
+        Some(TmdbError::RateLimited { retry_after }) => {
+            assert_eq!(*retry_after, Some(Duration::from_secs(30)))
+        }
+        other => panic!("Expected RateLimited, got {:?}", other),
+    }
+    assert!(err.to_string().contains("try again in 30s"));
+}
+
 #[tokio::test]
 async fn test_handles_not_found() {
     let mut server = Server::new_async().await;
@@ -568,17 +1223,81 @@ async fn test_handles_server_error() {
         .match_query(Matcher::Any)
         .with_status(500)
         .with_body("Internal Server Error")
+        .expect(4)
         .create_async()
         .await;
 
-    let client = TmdbClient::with_base_url("test_key", server.url());
-    let result = client.trending().await;
+    // Server errors are retried (3 times by default) before giving up
+    let client = TmdbClient::with_base_url("test_key", server.url()).with_retry(fast_retry(3));
+    let result = client.trending(TrendingWindow::Week).await;
 
     mock.assert_async().await;
 
     assert!(result.is_err());
 }
 
+/// Retry policy without the real backoff delays
+fn fast_retry(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+    }
+}
+
+#[tokio::test]
+async fn test_retries_unavailable_then_succeeds() {
+    let mut server = Server::new_async().await;
+
+    let mock_503 = server
+        .mock("GET", "/search/multi")
+        .match_query(Matcher::Any)
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mock_200 = server
+        .mock("GET", "/search/multi")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"page": 1, "results": [], "total_results": 0, "total_pages": 0}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url()).with_retry(fast_retry(3));
+    let result = client.search("test").await;
+
+    assert!(result.is_ok());
+    mock_503.assert_async().await;
+    mock_200.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_retry_attempt_cap() {
+    let mut server = Server::new_async().await;
+
+    // One attempt plus two retries, then the 503 is reported
+    let mock = server
+        .mock("GET", "/search/multi")
+        .match_query(Matcher::Any)
+        .with_status(503)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = TmdbClient::with_base_url("test_key", server.url()).with_retry(fast_retry(2));
+    let err = client.search("test").await.unwrap_err();
+
+    mock.assert_async().await;
+    assert!(matches!(
+        err.downcast_ref::<streamtui::api::tmdb::TmdbError>(),
+        Some(streamtui::api::tmdb::TmdbError::ServerError(503))
+    ));
+}
+
 #[tokio::test]
 async fn test_handles_invalid_json() {
     let mut server = Server::new_async().await;
@@ -609,7 +1328,8 @@ async fn test_sends_bearer_token() {
     let mut server = Server::new_async().await;
 
     // Use a long token (64+ chars) to trigger Bearer auth instead of query param auth
-    let long_token = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IlRlc3QifQ";
+    let long_token =
+        "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IlRlc3QifQ";
 
     let mock = server
         .mock("GET", "/search/multi")
@@ -651,3 +1371,77 @@ async fn test_sends_legacy_api_key() {
 
     mock.assert_async().await;
 }
+
+// =============================================================================
+// Response Cache Tests
+// =============================================================================
+
+fn temp_cache_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("streamtui_tmdb_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+const TRENDING_BODY: &str = r#"{"page": 1, "results": [
+    {"id": 550, "media_type": "movie", "title": "Fight Club", "release_date": "1999-10-15"}
+]}"#;
+
+#[tokio::test]
+async fn test_cache_serves_repeat_requests() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/trending/all/week")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body(TRENDING_BODY)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let dir = temp_cache_dir("hit");
+    let client = TmdbClient::with_base_url("test_key", server.url())
+        .with_cache(&dir, std::time::Duration::from_secs(3600));
+
+    let first = client.trending(TrendingWindow::Week).await.unwrap();
+    let second = client.trending(TrendingWindow::Week).await.unwrap();
+
+    // Only one live request; the second came from disk
+    mock.assert_async().await;
+    assert_eq!(first.len(), 1);
+    assert_eq!(second[0].title, "Fight Club");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_cache_falls_back_on_corrupt_or_expired_entries() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/trending/all/week")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body(TRENDING_BODY)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let dir = temp_cache_dir("corrupt");
+    let client = TmdbClient::with_base_url("test_key", server.url())
+        .with_cache(&dir, std::time::Duration::from_secs(3600));
+    client.trending(TrendingWindow::Week).await.unwrap();
+
+    // Garbage in the cache file means a live request, not an error
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        std::fs::write(entry.unwrap().path(), "{ not json").unwrap();
+    }
+    let results = client.trending(TrendingWindow::Week).await.unwrap();
+    assert_eq!(results[0].id, 550);
+
+    // A zero TTL treats every entry as stale
+    let stale = TmdbClient::with_base_url("test_key", server.url())
+        .with_cache(&dir, std::time::Duration::ZERO);
+    stale.trending(TrendingWindow::Week).await.unwrap();
+
+    mock.assert_async().await;
+    std::fs::remove_dir_all(&dir).ok();
+}
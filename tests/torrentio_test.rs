@@ -4,8 +4,12 @@
 //! Following specs/addons.md test specifications.
 
 use mockito::Server;
-use streamtui::api::torrentio::TorrentioClient;
-use streamtui::models::{Quality, StreamSource};
+use std::time::Duration;
+use streamtui::api::torrentio::{
+    dedup_streams, StreamKind, TorrentioClient, TorrentioConfig, TorrentioSort,
+};
+use streamtui::api::RetryPolicy;
+use streamtui::models::{HdrType, Quality, StreamSource, VideoCodec};
 
 // =============================================================================
 // Quality Parsing Tests
@@ -70,6 +74,40 @@ fn test_parse_seeds() {
     assert_eq!(StreamSource::parse_seeds("Movie.Without.Seeds.Info"), 0);
 }
 
+// =============================================================================
+// Release Group Parsing Tests
+// =============================================================================
+
+/// Test: Parse the release group from the end of the release name
+#[test]
+fn test_parse_release_group() {
+    assert_eq!(
+        StreamSource::parse_release_group("The.Batman.2022.1080p.BluRay-SPARKS\n👤 142 💾 4.2 GB"),
+        Some("SPARKS".to_string())
+    );
+    assert_eq!(
+        StreamSource::parse_release_group("Dune.2021.2160p.UHD.BluRay.x264-GECKOS"),
+        Some("GECKOS".to_string())
+    );
+    // File extensions are skipped
+    assert_eq!(
+        StreamSource::parse_release_group("Dune.2021.1080p.WEB.h264-ETHEL.mkv"),
+        Some("ETHEL".to_string())
+    );
+
+    // No group
+    assert_eq!(
+        StreamSource::parse_release_group("The Batman (2022) [1080p] [YTS.MX]"),
+        None
+    );
+    assert_eq!(
+        StreamSource::parse_release_group("The.Batman.2022.2160p.WEB-DL"),
+        None
+    );
+    assert_eq!(StreamSource::parse_release_group("Movie-2022"), None);
+    assert_eq!(StreamSource::parse_release_group(""), None);
+}
+
 // =============================================================================
 // Size Parsing Tests
 // =============================================================================
@@ -83,7 +121,7 @@ fn test_parse_size() {
     let bytes = size_gb.unwrap();
     // 4.2 * 1024 * 1024 * 1024 = 4509715660.8
     assert!(
-        bytes >= 4_509_000_000 && bytes <= 4_510_000_000,
+        (4_509_000_000..=4_510_000_000).contains(&bytes),
         "Expected ~4509715660, got {}",
         bytes
     );
@@ -94,7 +132,7 @@ fn test_parse_size() {
     let bytes_mb = size_mb.unwrap();
     // 890 * 1024 * 1024 = 933232640
     assert!(
-        bytes_mb >= 933_000_000 && bytes_mb <= 934_000_000,
+        (933_000_000..=934_000_000).contains(&bytes_mb),
         "Expected ~933232640, got {}",
         bytes_mb
     );
@@ -116,20 +154,22 @@ fn test_parse_size() {
 fn test_magnet_generation() {
     let source = StreamSource {
         name: "Torrentio\n4K".to_string(),
+        provider: "Torrentio".to_string(),
         title: "The.Batman.2022.2160p".to_string(),
         info_hash: "abc123def456789".to_string(),
         file_idx: Some(0),
         seeds: 100,
         quality: Quality::UHD4K,
         size_bytes: Some(4_500_000_000),
+        hdr: HdrType::None,
+        codec: VideoCodec::Unknown,
+        release_group: None,
+        direct_url: None,
     };
 
     // Basic magnet generation
     let magnet = source.to_magnet("Movie Name");
-    assert_eq!(
-        magnet,
-        "magnet:?xt=urn:btih:abc123def456789&dn=Movie%20Name"
-    );
+    assert!(magnet.starts_with("magnet:?xt=urn:btih:abc123def456789&dn=Movie%20Name&tr="));
 
     // URL encoding special characters
     let magnet_special = source.to_magnet("The Batman (2022)");
@@ -141,10 +181,270 @@ fn test_magnet_generation() {
     assert!(magnet_amp.contains("dn=Tom%20%26%20Jerry"));
 }
 
+// =============================================================================
+// HDR Parsing Tests
+// =============================================================================
+
+/// Test: Dolby Vision wins over an HDR10 fallback layer
+#[test]
+fn test_parse_hdr_dolby_vision() {
+    assert_eq!(
+        HdrType::from_title("Dune.2021.2160p.DV.HDR10.WEB-DL.DDP5.1"),
+        HdrType::DolbyVision
+    );
+    assert_eq!(
+        HdrType::from_title("Dune 2021 2160p DoVi x265"),
+        HdrType::DolbyVision
+    );
+    assert_eq!(
+        HdrType::from_title("Dune.2021.Dolby.Vision.2160p"),
+        HdrType::DolbyVision
+    );
+}
+
+/// Test: HDR10+ and plain HDR tags
+#[test]
+fn test_parse_hdr10_variants() {
+    assert_eq!(
+        HdrType::from_title("Movie.2023.2160p.HDR10+.WEB-DL"),
+        HdrType::Hdr10Plus
+    );
+    assert_eq!(
+        HdrType::from_title("Movie.2023.2160p.HDR10.BluRay"),
+        HdrType::Hdr10
+    );
+    assert_eq!(HdrType::from_title("Torrentio\n4k HDR"), HdrType::Hdr10);
+}
+
+/// Test: SDR titles and lookalike tokens don't count as HDR
+#[test]
+fn test_parse_hdr_none() {
+    assert_eq!(
+        HdrType::from_title("Movie.2023.2160p.WEB-DL"),
+        HdrType::None
+    );
+    assert_eq!(HdrType::from_title("Movie.2003.DVDRip.XviD"), HdrType::None);
+    assert_eq!(HdrType::from_title("Movie.2023.HDRip.x264"), HdrType::None);
+    assert!(!HdrType::None.is_hdr());
+}
+
+// =============================================================================
+// Codec Parsing Tests
+// =============================================================================
+
+/// Test: HEVC releases in their various spellings
+#[test]
+fn test_parse_codec_x265() {
+    assert_eq!(
+        VideoCodec::from_title("The.Batman.2022.2160p.WEB-DL.DDP5.1.x265-GROUP"),
+        VideoCodec::X265
+    );
+    assert_eq!(
+        VideoCodec::from_title("Dune.2021.1080p.BluRay.HEVC.10bit"),
+        VideoCodec::X265
+    );
+    assert_eq!(
+        VideoCodec::from_title("Show.S01E01.1080p.WEB.H.265-GROUP"),
+        VideoCodec::X265
+    );
+}
+
+/// Test: AVC releases, matched case-insensitively
+#[test]
+fn test_parse_codec_x264() {
+    assert_eq!(
+        VideoCodec::from_title("The.Batman.2022.1080p.BluRay.X264-GROUP"),
+        VideoCodec::X264
+    );
+    assert_eq!(
+        VideoCodec::from_title("Movie 2020 720p WEB h.264 AAC"),
+        VideoCodec::X264
+    );
+    assert_eq!(
+        VideoCodec::from_title("Movie.2020.1080p.WEB-DL.H264"),
+        VideoCodec::X264
+    );
+}
+
+/// Test: AV1 and releases with no codec token
+#[test]
+fn test_parse_codec_av1_and_unknown() {
+    assert_eq!(
+        VideoCodec::from_title("Movie.2023.2160p.WEB-DL.AV1.Opus"),
+        VideoCodec::Av1
+    );
+    assert_eq!(
+        VideoCodec::from_title("Movie.2023.1080p.WEB-DL.DDP5.1"),
+        VideoCodec::Unknown
+    );
+}
+
 // =============================================================================
 // HTTP Request Tests (with mockito)
 // =============================================================================
 
+/// Test: Debrid-resolved streams carry a direct URL instead of an info hash
+#[tokio::test]
+async fn test_debrid_streams_direct_url() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/realdebrid=KEY/stream/movie/tt1877830.json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+            "streams": [
+                {
+                    "name": "[RD+] Torrentio\n1080p",
+                    "title": "The.Batman.2022.1080p.BluRay.x264 4.2 GB 👤 234",
+                    "url": "https://real-debrid.example/d/ABC123/The.Batman.mkv"
+                },
+                {
+                    "name": "Torrentio\n720p",
+                    "title": "Broken entry with no hash or url"
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = TorrentioClient::with_base_url(format!("{}/realdebrid=KEY", server.url()));
+    let streams = client.movie_streams("tt1877830").await.unwrap();
+
+    mock.assert_async().await;
+
+    // Entries with neither a hash nor a URL are dropped
+    assert_eq!(streams.len(), 1);
+    assert_eq!(
+        streams[0].direct_url.as_deref(),
+        Some("https://real-debrid.example/d/ABC123/The.Batman.mkv")
+    );
+    assert!(streams[0].info_hash.is_empty());
+    assert_eq!(streams[0].quality, Quality::FHD1080p);
+}
+
+/// Test: Addon options follow Torrentio's `providers=...|sort=...` path format
+#[test]
+fn test_options_path_segment() {
+    let config = TorrentioConfig {
+        providers: vec!["yts".into(), "eztv".into()],
+        sort: Some(TorrentioSort::QualitySize),
+        quality_filter: vec!["cam".into(), "scr".into()],
+        size_filter: Some("5GB".into()),
+    };
+    assert_eq!(
+        config.options().join("|"),
+        "providers=yts,eztv|sort=qualitysize|qualityfilter=cam,scr|sizefilter=5GB"
+    );
+
+    // Defaults add nothing; Torrentio's own default sort is left implicit
+    assert_eq!(TorrentioClient::new().options_segment(), "");
+    let quality = TorrentioConfig {
+        sort: Some(TorrentioSort::Quality),
+        ..Default::default()
+    };
+    assert_eq!(
+        TorrentioClient::new()
+            .with_config(quality)
+            .options_segment(),
+        ""
+    );
+
+    // The debrid key shares the segment, last
+    let client = TorrentioClient::with_debrid("KEY").with_config(TorrentioConfig {
+        providers: vec!["yts".into()],
+        sort: Some(TorrentioSort::Seeders),
+        ..Default::default()
+    });
+    assert_eq!(
+        client.options_segment(),
+        "/providers=yts|sort=seeders|realdebrid=KEY"
+    );
+}
+
+/// Test: Movies and series episodes use their own addon paths
+#[test]
+fn test_stream_kind_paths() {
+    assert_eq!(
+        StreamKind::Movie.path("tt0133093"),
+        "stream/movie/tt0133093.json"
+    );
+    let episode = StreamKind::Series {
+        season: 2,
+        episode: 5,
+    };
+    assert_eq!(
+        episode.path("tt0903747"),
+        "stream/series/tt0903747:2:5.json"
+    );
+
+    let client = TorrentioClient::with_base_url("http://addon");
+    assert_eq!(
+        client.stream_url("tt0133093", StreamKind::Movie),
+        "http://addon/stream/movie/tt0133093.json"
+    );
+    let client = client.with_config(TorrentioConfig {
+        providers: vec!["eztv".into()],
+        ..Default::default()
+    });
+    assert_eq!(
+        client.stream_url("tt0903747", episode),
+        "http://addon/providers=eztv/stream/series/tt0903747:2:5.json"
+    );
+}
+
+/// Test: Requests carry the options segment before `/stream`
+#[tokio::test]
+async fn test_streams_request_with_options() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock(
+            "GET",
+            "/providers=yts,eztv|sort=size/stream/series/tt0903747:1:2.json",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"streams": []}"#)
+        .create_async()
+        .await;
+
+    let client = TorrentioClient::with_base_url(server.url()).with_config(TorrentioConfig {
+        providers: vec!["yts".into(), "eztv".into()],
+        sort: Some(TorrentioSort::Size),
+        ..Default::default()
+    });
+    let streams = client.episode_streams("tt0903747", 1, 2).await.unwrap();
+
+    mock.assert_async().await;
+    assert!(streams.is_empty());
+}
+
+/// Test: Absolute episode numbers are asked for as season 1
+#[tokio::test]
+async fn test_absolute_episode_streams_request() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/stream/series/tt0388629:1:1000.json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"streams": []}"#)
+        .create_async()
+        .await;
+
+    let client = TorrentioClient::with_base_url(server.url());
+    let streams = client
+        .absolute_episode_streams("tt0388629", 1000)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(streams.is_empty());
+}
+
 /// Test: Movie streams request forms correct URL
 #[tokio::test]
 async fn test_movie_streams_request() {
@@ -160,7 +460,7 @@ async fn test_movie_streams_request() {
             "streams": [
                 {
                     "name": "Torrentio\n4K",
-                    "title": "The.Batman.2022.2160p.WEB-DL.DDP5.1.Atmos 👤 89",
+                    "title": "The.Batman.2022.2160p.WEB-DL.DV.HDR10.DDP5.1.Atmos 👤 89",
                     "infoHash": "abc123def456",
                     "fileIdx": 0
                 },
@@ -184,8 +484,11 @@ async fn test_movie_streams_request() {
     // Verify parsed results
     assert_eq!(streams.len(), 2);
 
-    // First stream should be 4K
+    // First stream should be 4K Dolby Vision
     assert_eq!(streams[0].quality, Quality::UHD4K);
+    assert_eq!(streams[0].hdr, HdrType::DolbyVision);
+    assert_eq!(streams[1].hdr, HdrType::None);
+    assert_eq!(streams[0].codec, VideoCodec::Unknown);
     assert_eq!(streams[0].info_hash, "abc123def456");
     assert_eq!(streams[0].seeds, 89);
 
@@ -195,6 +498,39 @@ async fn test_movie_streams_request() {
     assert!(streams[1].size_bytes.is_some());
 }
 
+/// Test: The addon name splits into a provider and a quality
+#[tokio::test]
+async fn test_provider_from_name() {
+    let mut server = Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/stream/movie/tt1877830.json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+            "streams": [
+                {"name": "Torrentio\n4K", "title": "The.Batman.2022.2160p 👤 89", "infoHash": "abc123"},
+                {"name": "[RD+] Torrentio\n1080p", "title": "The.Batman.2022.1080p", "url": "https://rd.example/f.mkv"},
+                {"name": "720p", "title": "The.Batman.2022.720p 👤 5", "infoHash": "def456"}
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = TorrentioClient::with_base_url(server.url());
+    let streams = client.movie_streams("tt1877830").await.unwrap();
+
+    assert_eq!(streams[0].provider, "Torrentio");
+    assert_eq!(streams[0].quality, Quality::UHD4K);
+    assert_eq!(streams[0].name, "Torrentio\n4K");
+    assert_eq!(streams[1].provider, "[RD+] Torrentio");
+    assert_eq!(streams[1].quality, Quality::FHD1080p);
+    // A name without a quality line is both
+    assert_eq!(streams[2].provider, "720p");
+    assert_eq!(streams[2].quality, Quality::HD720p);
+}
+
 /// Test: Series streams request forms correct URL format
 #[tokio::test]
 async fn test_series_streams_format() {
@@ -230,6 +566,169 @@ async fn test_series_streams_format() {
     assert_eq!(streams[0].file_idx, Some(2));
 }
 
+/// Test: 503s from a flaky addon are retried until the request succeeds
+#[tokio::test]
+async fn test_retries_unavailable_then_succeeds() {
+    let mut server = Server::new_async().await;
+
+    let mock_503 = server
+        .mock("GET", "/stream/movie/tt1877830.json")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+    let mock_200 = server
+        .mock("GET", "/stream/movie/tt1877830.json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"streams": [{"name": "Torrentio\n1080p", "title": "The.Batman 👤 10", "infoHash": "abc"}]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = TorrentioClient::with_base_url(server.url()).with_retry(RetryPolicy {
+        max_retries: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+    });
+    let streams = client.movie_streams("tt1877830").await.unwrap();
+
+    mock_503.assert_async().await;
+    mock_200.assert_async().await;
+    assert_eq!(streams.len(), 1);
+}
+
+/// Test: a mirror that stops responding fails with a timeout instead of hanging
+#[tokio::test]
+async fn test_request_timeout() {
+    let mut server = Server::new_async().await;
+
+    let _mock = server
+        .mock("GET", "/stream/movie/tt1877830.json")
+        .with_status(200)
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_secs(2));
+            w.write_all(br#"{"streams": []}"#)
+        })
+        .create_async()
+        .await;
+
+    let client = TorrentioClient::with_base_url(server.url())
+        .with_retry(RetryPolicy::with_max_retries(0))
+        .with_timeout(Duration::from_millis(200));
+    let err = client.movie_streams("tt1877830").await.unwrap_err();
+
+    assert!(
+        streamtui::api::is_timeout(&err),
+        "Expected a timeout, got: {:#}",
+        err
+    );
+}
+
+// =============================================================================
+// Deduplication Tests
+// =============================================================================
+
+const DUPLICATE_STREAMS: &str = r#"{
+    "streams": [
+        {
+            "name": "Torrentio\n1080p",
+            "title": "The.Batman.2022.1080p.BluRay 4.2 GB 👤 120",
+            "infoHash": "aaaa1111",
+            "fileIdx": 0
+        },
+        {
+            "name": "Torrentio\n720p",
+            "title": "The.Batman.2022.720p.WEB 1.1 GB 👤 40",
+            "infoHash": "bbbb2222",
+            "fileIdx": 0
+        },
+        {
+            "name": "Torrentio\n1080p",
+            "title": "The.Batman.2022.1080p.BluRay 👤 300",
+            "infoHash": "aaaa1111",
+            "fileIdx": 0
+        },
+        {
+            "name": "Torrentio\n4K",
+            "title": "The.Batman.2022.2160p.WEB-DL 👤 10",
+            "infoHash": "cccc3333",
+            "fileIdx": 0
+        },
+        {
+            "name": "TorrentioMirror\n4K",
+            "title": "The.Batman.2022.2160p.WEB-DL 👤 15",
+            "infoHash": "CCCC3333",
+            "fileIdx": 0
+        }
+    ]
+}"#;
+
+/// Test: Duplicate hashes collapse to the best-seeded entry; unique ones survive
+#[tokio::test]
+async fn test_dedups_duplicate_hashes() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/stream/movie/tt1877830.json")
+        .with_status(200)
+        .with_body(DUPLICATE_STREAMS)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = TorrentioClient::with_base_url(server.url());
+    let streams = client.movie_streams("tt1877830").await.unwrap();
+
+    assert_eq!(streams.len(), 3);
+
+    // 4K duplicate keeps the higher seed count and both provider names
+    assert_eq!(streams[0].quality, Quality::UHD4K);
+    assert_eq!(streams[0].seeds, 15);
+    assert_eq!(streams[0].name, "Torrentio\n4K / TorrentioMirror\n4K");
+
+    // 1080p duplicate keeps 300 seeds and backfills the size from the other listing
+    assert_eq!(streams[1].info_hash, "aaaa1111");
+    assert_eq!(streams[1].seeds, 300);
+    assert!(streams[1].size_bytes.is_some());
+
+    assert_eq!(streams[2].info_hash, "bbbb2222");
+
+    // Raw output keeps every listing
+    let raw = TorrentioClient::with_base_url(server.url()).with_dedup(false);
+    let streams = raw.movie_streams("tt1877830").await.unwrap();
+    assert_eq!(streams.len(), 5);
+
+    mock.assert_async().await;
+}
+
+/// Test: Same torrent but different files (season packs) are not merged
+#[test]
+fn test_dedup_keeps_distinct_files_and_direct_urls() {
+    let source = |hash: &str, file_idx: Option<u32>, url: Option<&str>| StreamSource {
+        name: "Torrentio".to_string(),
+        provider: "Torrentio".to_string(),
+        title: "Show.S01.1080p".to_string(),
+        info_hash: hash.to_string(),
+        file_idx,
+        seeds: 10,
+        quality: Quality::FHD1080p,
+        size_bytes: None,
+        hdr: HdrType::None,
+        codec: VideoCodec::Unknown,
+        release_group: None,
+        direct_url: url.map(String::from),
+    };
+
+    let streams = dedup_streams(vec![
+        source("pack", Some(1), None),
+        source("pack", Some(2), None),
+        source("", None, Some("https://debrid.example/a.mkv")),
+        source("", None, Some("https://debrid.example/b.mkv")),
+    ]);
+    assert_eq!(streams.len(), 4);
+}
+
 // =============================================================================
 // Sorting Tests
 // =============================================================================
@@ -237,42 +736,62 @@ async fn test_series_streams_format() {
 /// Test: Results sorted by quality (4K first) then by seeds descending
 #[test]
 fn test_sorts_by_quality_and_seeds() {
-    let mut streams = vec![
+    let mut streams = [
         StreamSource {
             name: "720p".to_string(),
+            provider: "720p".to_string(),
             title: "720p release".to_string(),
             info_hash: "hash1".to_string(),
             file_idx: None,
             seeds: 1000, // Many seeds but low quality
             quality: Quality::HD720p,
             size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         },
         StreamSource {
             name: "4K".to_string(),
+            provider: "4K".to_string(),
             title: "4K release low seeds".to_string(),
             info_hash: "hash2".to_string(),
             file_idx: None,
             seeds: 50, // Few seeds but best quality
             quality: Quality::UHD4K,
             size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         },
         StreamSource {
             name: "1080p".to_string(),
+            provider: "1080p".to_string(),
             title: "1080p release".to_string(),
             info_hash: "hash3".to_string(),
             file_idx: None,
             seeds: 500,
             quality: Quality::FHD1080p,
             size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         },
         StreamSource {
             name: "4K".to_string(),
+            provider: "4K".to_string(),
             title: "4K release high seeds".to_string(),
             info_hash: "hash4".to_string(),
             file_idx: None,
             seeds: 200, // More seeds, same quality as hash2
             quality: Quality::UHD4K,
             size_bytes: None,
+            hdr: HdrType::None,
+            codec: VideoCodec::Unknown,
+            release_group: None,
+            direct_url: None,
         },
     ];
 
@@ -31,20 +31,20 @@ use streamtui::{App, AppState, MediaType, Quality, SearchResult};
 fn test_theme_colors_valid_rgb() {
     // All core palette colors should be RGB type
     let colors = [
-        ("BACKGROUND", Theme::BACKGROUND),
-        ("PRIMARY", Theme::PRIMARY),
-        ("SECONDARY", Theme::SECONDARY),
-        ("ACCENT", Theme::ACCENT),
-        ("HIGHLIGHT", Theme::HIGHLIGHT),
-        ("TEXT", Theme::TEXT),
-        ("DIM", Theme::DIM),
-        ("SUCCESS", Theme::SUCCESS),
-        ("WARNING", Theme::WARNING),
-        ("ERROR", Theme::ERROR),
-        ("BACKGROUND_LIGHT", Theme::BACKGROUND_LIGHT),
-        ("BACKGROUND_HOVER", Theme::BACKGROUND_HOVER),
-        ("BORDER", Theme::BORDER),
-        ("BORDER_FOCUSED", Theme::BORDER_FOCUSED),
+        ("BACKGROUND", Theme::NEON.background),
+        ("PRIMARY", Theme::NEON.primary),
+        ("SECONDARY", Theme::NEON.secondary),
+        ("ACCENT", Theme::NEON.accent),
+        ("HIGHLIGHT", Theme::NEON.highlight),
+        ("TEXT", Theme::NEON.text),
+        ("DIM", Theme::NEON.dim),
+        ("SUCCESS", Theme::NEON.success),
+        ("WARNING", Theme::NEON.warning),
+        ("ERROR", Theme::NEON.error),
+        ("BACKGROUND_LIGHT", Theme::NEON.background_light),
+        ("BACKGROUND_HOVER", Theme::NEON.background_hover),
+        ("BORDER", Theme::NEON.border),
+        ("BORDER_FOCUSED", Theme::NEON.border_focused),
     ];
 
     for (name, color) in colors {
@@ -60,25 +60,34 @@ fn test_theme_colors_valid_rgb() {
 #[test]
 fn test_theme_colors_match_spec() {
     // From specs/tui.md:
-    assert_eq!(color_to_rgb(Theme::BACKGROUND), Some((0x0a, 0x0a, 0x0f)));
-    assert_eq!(color_to_rgb(Theme::PRIMARY), Some((0x00, 0xff, 0xf2)));
-    assert_eq!(color_to_rgb(Theme::SECONDARY), Some((0xff, 0x00, 0xff)));
-    assert_eq!(color_to_rgb(Theme::ACCENT), Some((0xff, 0xff, 0x00)));
-    assert_eq!(color_to_rgb(Theme::HIGHLIGHT), Some((0xff, 0x00, 0x80)));
-    assert_eq!(color_to_rgb(Theme::TEXT), Some((0xe0, 0xe0, 0xe0)));
-    assert_eq!(color_to_rgb(Theme::DIM), Some((0x40, 0x40, 0x50)));
-    assert_eq!(color_to_rgb(Theme::SUCCESS), Some((0x00, 0xff, 0x00)));
-    assert_eq!(color_to_rgb(Theme::WARNING), Some((0xff, 0xaa, 0x00)));
-    assert_eq!(color_to_rgb(Theme::ERROR), Some((0xff, 0x00, 0x40)));
+    assert_eq!(
+        color_to_rgb(Theme::NEON.background),
+        Some((0x0a, 0x0a, 0x0f))
+    );
+    assert_eq!(color_to_rgb(Theme::NEON.primary), Some((0x00, 0xff, 0xf2)));
+    assert_eq!(
+        color_to_rgb(Theme::NEON.secondary),
+        Some((0xff, 0x00, 0xff))
+    );
+    assert_eq!(color_to_rgb(Theme::NEON.accent), Some((0xff, 0xff, 0x00)));
+    assert_eq!(
+        color_to_rgb(Theme::NEON.highlight),
+        Some((0xff, 0x00, 0x80))
+    );
+    assert_eq!(color_to_rgb(Theme::NEON.text), Some((0xe0, 0xe0, 0xe0)));
+    assert_eq!(color_to_rgb(Theme::NEON.dim), Some((0x40, 0x40, 0x50)));
+    assert_eq!(color_to_rgb(Theme::NEON.success), Some((0x00, 0xff, 0x00)));
+    assert_eq!(color_to_rgb(Theme::NEON.warning), Some((0xff, 0xaa, 0x00)));
+    assert_eq!(color_to_rgb(Theme::NEON.error), Some((0xff, 0x00, 0x40)));
 }
 
 /// Test contrast ratios meet WCAG AA requirements
 #[test]
 fn test_theme_colors_contrast_ratios() {
-    let bg = color_to_rgb(Theme::BACKGROUND).unwrap();
+    let bg = color_to_rgb(Theme::NEON.background).unwrap();
 
     // Text on background must meet WCAG AA (4.5:1 for normal text)
-    let text = color_to_rgb(Theme::TEXT).unwrap();
+    let text = color_to_rgb(Theme::NEON.text).unwrap();
     let text_ratio = contrast_ratio(text, bg);
     assert!(
         meets_wcag_aa(text, bg),
@@ -87,7 +96,7 @@ fn test_theme_colors_contrast_ratios() {
     );
 
     // Primary, secondary, accent must meet WCAG AA for large text (3:1)
-    let primary = color_to_rgb(Theme::PRIMARY).unwrap();
+    let primary = color_to_rgb(Theme::NEON.primary).unwrap();
     let primary_ratio = contrast_ratio(primary, bg);
     assert!(
         meets_wcag_aa_large(primary, bg),
@@ -95,7 +104,7 @@ fn test_theme_colors_contrast_ratios() {
         primary_ratio
     );
 
-    let secondary = color_to_rgb(Theme::SECONDARY).unwrap();
+    let secondary = color_to_rgb(Theme::NEON.secondary).unwrap();
     let secondary_ratio = contrast_ratio(secondary, bg);
     assert!(
         meets_wcag_aa_large(secondary, bg),
@@ -103,7 +112,7 @@ fn test_theme_colors_contrast_ratios() {
         secondary_ratio
     );
 
-    let highlight = color_to_rgb(Theme::HIGHLIGHT).unwrap();
+    let highlight = color_to_rgb(Theme::NEON.highlight).unwrap();
     let highlight_ratio = contrast_ratio(highlight, bg);
     assert!(
         meets_wcag_aa_large(highlight, bg),
@@ -112,19 +121,19 @@ fn test_theme_colors_contrast_ratios() {
     );
 
     // Success, warning, error must be visible
-    let success = color_to_rgb(Theme::SUCCESS).unwrap();
+    let success = color_to_rgb(Theme::NEON.success).unwrap();
     assert!(
         meets_wcag_aa_large(success, bg),
         "SUCCESS on BACKGROUND must meet large text contrast"
     );
 
-    let warning = color_to_rgb(Theme::WARNING).unwrap();
+    let warning = color_to_rgb(Theme::NEON.warning).unwrap();
     assert!(
         meets_wcag_aa_large(warning, bg),
         "WARNING on BACKGROUND must meet large text contrast"
     );
 
-    let error = color_to_rgb(Theme::ERROR).unwrap();
+    let error = color_to_rgb(Theme::NEON.error).unwrap();
     assert!(
         meets_wcag_aa_large(error, bg),
         "ERROR on BACKGROUND must meet large text contrast"
@@ -134,8 +143,8 @@ fn test_theme_colors_contrast_ratios() {
 /// Test inverted styles still readable (text on primary background)
 #[test]
 fn test_theme_inverted_contrast() {
-    let bg_color = color_to_rgb(Theme::BACKGROUND).unwrap();
-    let primary = color_to_rgb(Theme::PRIMARY).unwrap();
+    let bg_color = color_to_rgb(Theme::NEON.background).unwrap();
+    let primary = color_to_rgb(Theme::NEON.primary).unwrap();
 
     // When highlighted (inverted), background color on primary should be readable
     let ratio = contrast_ratio(bg_color, primary);
@@ -255,7 +264,7 @@ fn test_layout_content_scrollable() {
             // Create scrollable list
             let list = List::new(items.clone())
                 .block(Block::default().borders(Borders::ALL))
-                .highlight_style(Theme::selected());
+                .highlight_style(Theme::NEON.selected());
 
             // Render with state (handles scroll offset automatically)
             frame.render_stateful_widget(list, content, &mut state);
@@ -564,29 +573,29 @@ fn render_content_card(
         Span::styled(
             format!("{}{}", display_title, year_str),
             if selected {
-                Theme::selected()
+                Theme::NEON.selected()
             } else {
-                Theme::text()
+                Theme::NEON.text()
             },
         ),
         Span::raw(" "),
-        Span::styled(format!("{}", quality), Theme::quality_1080p()),
+        Span::styled(format!("{}", quality), Theme::NEON.quality_1080p()),
         Span::raw(" "),
-        Span::styled(size_str, Theme::file_size()),
+        Span::styled(size_str, Theme::NEON.file_size()),
     ]);
 
     let line2 = Line::from(vec![
         Span::raw("  "),
-        Span::styled("Action, Crime • 2h 56m", Theme::dimmed()),
+        Span::styled("Action, Crime • 2h 56m", Theme::NEON.dimmed()),
         Span::raw("    "),
         Span::styled(
             format!("Seeds: {}", seeds),
             if seeds > 100 {
-                Theme::seeds_high()
+                Theme::NEON.seeds_high()
             } else if seeds > 20 {
-                Theme::seeds_medium()
+                Theme::NEON.seeds_medium()
             } else {
-                Theme::seeds_low()
+                Theme::NEON.seeds_low()
             },
         ),
     ]);
@@ -671,7 +680,7 @@ fn test_content_card_render_no_year() {
 fn test_content_card_render_in_terminal() {
     let mut terminal = test_terminal(80, 24);
 
-    let items = vec![
+    let items = [
         SearchResult {
             id: 1,
             media_type: MediaType::Movie,
@@ -702,7 +711,7 @@ fn test_content_card_render_in_terminal() {
             let area = frame.area();
             let list = List::new(list_items)
                 .block(Block::default().title("Results").borders(Borders::ALL))
-                .highlight_style(Theme::list_item_selected());
+                .highlight_style(Theme::NEON.list_item_selected());
 
             frame.render_stateful_widget(list, area, &mut state);
         })
@@ -802,7 +811,7 @@ fn test_now_playing_overlay_progress_bar() {
 
     // Create gauge widget (we just test it compiles and renders correctly)
     let _gauge = Gauge::default()
-        .gauge_style(Theme::progress_bar())
+        .gauge_style(Theme::NEON.progress_bar())
         .ratio(progress)
         .label(format!(
             "{} / {}",
@@ -897,21 +906,21 @@ fn test_now_playing_overlay_full_render() {
 
             // Render overlay block
             let block = Block::default()
-                .title(Span::styled("▶ NOW CASTING", Theme::title()))
+                .title(Span::styled("▶ NOW CASTING", Theme::NEON.title()))
                 .borders(Borders::ALL)
-                .border_style(Theme::border_focused());
+                .border_style(Theme::NEON.border_focused());
 
             // Inner area for content
             let inner = block.inner(overlay_rect);
             frame.render_widget(block, overlay_rect);
 
             // Title
-            let title_para = Paragraph::new(state.title.as_str()).style(Theme::text());
+            let title_para = Paragraph::new(state.title.as_str()).style(Theme::NEON.text());
             frame.render_widget(title_para, Rect::new(inner.x, inner.y, inner.width, 1));
 
             // Progress bar
             let gauge = Gauge::default()
-                .gauge_style(Theme::progress_bar())
+                .gauge_style(Theme::NEON.progress_bar())
                 .ratio(state.progress())
                 .label(format!(
                     "{} / {}",
@@ -921,13 +930,13 @@ fn test_now_playing_overlay_full_render() {
             frame.render_widget(gauge, Rect::new(inner.x, inner.y + 2, inner.width, 1));
 
             // Device name
-            let device =
-                Paragraph::new(format!("📺 {}", state.device_name)).style(Theme::cast_target());
+            let device = Paragraph::new(format!("📺 {}", state.device_name))
+                .style(Theme::NEON.cast_target());
             frame.render_widget(device, Rect::new(inner.x, inner.y + 4, inner.width, 1));
 
             // Controls hint
             let controls =
-                Paragraph::new("[Space] Pause  [s] Stop  [Esc] Close").style(Theme::dimmed());
+                Paragraph::new("[Space] Pause  [s] Stop  [Esc] Close").style(Theme::NEON.dimmed());
             frame.render_widget(controls, Rect::new(inner.x, inner.y + 5, inner.width, 1));
         })
         .unwrap();
@@ -1035,17 +1044,17 @@ fn test_ui_flow_integration() {
 #[test]
 fn test_ui_theme_consistency() {
     // All style helpers should use consistent backgrounds
-    let text_style = Theme::text();
-    let title_style = Theme::title();
-    let selected_style = Theme::selected();
-    let error_style = Theme::error();
+    let text_style = Theme::NEON.text();
+    let title_style = Theme::NEON.title();
+    let selected_style = Theme::NEON.selected();
+    let error_style = Theme::NEON.error();
 
     // Text-based styles should have same background
     // (or no background, defaulting to terminal bg)
-    assert_eq!(text_style.bg, Some(Theme::BACKGROUND));
+    assert_eq!(text_style.bg, Some(Theme::NEON.background));
 
     // All primary styles should use theme foreground colors
-    assert_eq!(title_style.fg, Some(Theme::PRIMARY));
-    assert_eq!(selected_style.fg, Some(Theme::HIGHLIGHT));
-    assert_eq!(error_style.fg, Some(Theme::ERROR));
+    assert_eq!(title_style.fg, Some(Theme::NEON.primary));
+    assert_eq!(selected_style.fg, Some(Theme::NEON.highlight));
+    assert_eq!(error_style.fg, Some(Theme::NEON.error));
 }